@@ -0,0 +1,14331 @@
+/* automatically generated by rust-bindgen 0.72.1 */
+#![allow(warnings)]
+pub use crate::ntddk::*;
+#[repr(C)]
+pub struct __BindgenUnionField<T>(::core::marker::PhantomData<T>);
+impl<T> __BindgenUnionField<T> {
+    #[inline]
+    pub const fn new() -> Self {
+        __BindgenUnionField(::core::marker::PhantomData)
+    }
+    #[inline]
+    pub unsafe fn as_ref(&self) -> &T {
+        ::core::mem::transmute(self)
+    }
+    #[inline]
+    pub unsafe fn as_mut(&mut self) -> &mut T {
+        ::core::mem::transmute(self)
+    }
+}
+impl<T> ::core::default::Default for __BindgenUnionField<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T> ::core::clone::Clone for __BindgenUnionField<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> ::core::marker::Copy for __BindgenUnionField<T> {}
+impl<T> ::core::fmt::Debug for __BindgenUnionField<T> {
+    fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        fmt.write_str("__BindgenUnionField")
+    }
+}
+impl<T> ::core::hash::Hash for __BindgenUnionField<T> {
+    fn hash<H: ::core::hash::Hasher>(&self, _state: &mut H) {}
+}
+impl<T> ::core::cmp::PartialEq for __BindgenUnionField<T> {
+    fn eq(&self, _other: &__BindgenUnionField<T>) -> bool {
+        true
+    }
+}
+impl<T> ::core::cmp::Eq for __BindgenUnionField<T> {}
+pub const MAXIMUM_LEADBYTES: u32 = 12;
+pub const SID_REVISION: u32 = 1;
+pub const SID_MAX_SUB_AUTHORITIES: u32 = 15;
+pub const SID_RECOMMENDED_SUB_AUTHORITIES: u32 = 1;
+pub const SECURITY_MAX_SID_STRING_CHARACTERS: u32 = 187;
+pub const SID_HASH_SIZE: u32 = 32;
+pub const SECURITY_NULL_RID: u32 = 0;
+pub const SECURITY_WORLD_RID: u32 = 0;
+pub const SECURITY_LOCAL_RID: u32 = 0;
+pub const SECURITY_LOCAL_LOGON_RID: u32 = 1;
+pub const SECURITY_CREATOR_OWNER_RID: u32 = 0;
+pub const SECURITY_CREATOR_GROUP_RID: u32 = 1;
+pub const SECURITY_CREATOR_OWNER_SERVER_RID: u32 = 2;
+pub const SECURITY_CREATOR_GROUP_SERVER_RID: u32 = 3;
+pub const SECURITY_CREATOR_OWNER_RIGHTS_RID: u32 = 4;
+pub const SECURITY_DIALUP_RID: u32 = 1;
+pub const SECURITY_NETWORK_RID: u32 = 2;
+pub const SECURITY_BATCH_RID: u32 = 3;
+pub const SECURITY_INTERACTIVE_RID: u32 = 4;
+pub const SECURITY_LOGON_IDS_RID: u32 = 5;
+pub const SECURITY_LOGON_IDS_RID_COUNT: u32 = 3;
+pub const SECURITY_SERVICE_RID: u32 = 6;
+pub const SECURITY_ANONYMOUS_LOGON_RID: u32 = 7;
+pub const SECURITY_PROXY_RID: u32 = 8;
+pub const SECURITY_ENTERPRISE_CONTROLLERS_RID: u32 = 9;
+pub const SECURITY_SERVER_LOGON_RID: u32 = 9;
+pub const SECURITY_PRINCIPAL_SELF_RID: u32 = 10;
+pub const SECURITY_AUTHENTICATED_USER_RID: u32 = 11;
+pub const SECURITY_RESTRICTED_CODE_RID: u32 = 12;
+pub const SECURITY_TERMINAL_SERVER_RID: u32 = 13;
+pub const SECURITY_REMOTE_LOGON_RID: u32 = 14;
+pub const SECURITY_THIS_ORGANIZATION_RID: u32 = 15;
+pub const SECURITY_IUSER_RID: u32 = 17;
+pub const SECURITY_LOCAL_SYSTEM_RID: u32 = 18;
+pub const SECURITY_LOCAL_SERVICE_RID: u32 = 19;
+pub const SECURITY_NETWORK_SERVICE_RID: u32 = 20;
+pub const SECURITY_NT_NON_UNIQUE: u32 = 21;
+pub const SECURITY_NT_NON_UNIQUE_SUB_AUTH_COUNT: u32 = 3;
+pub const SECURITY_ENTERPRISE_READONLY_CONTROLLERS_RID: u32 = 22;
+pub const SECURITY_BUILTIN_DOMAIN_RID: u32 = 32;
+pub const SECURITY_WRITE_RESTRICTED_CODE_RID: u32 = 33;
+pub const SECURITY_PACKAGE_BASE_RID: u32 = 64;
+pub const SECURITY_PACKAGE_RID_COUNT: u32 = 2;
+pub const SECURITY_PACKAGE_NTLM_RID: u32 = 10;
+pub const SECURITY_PACKAGE_SCHANNEL_RID: u32 = 14;
+pub const SECURITY_PACKAGE_DIGEST_RID: u32 = 21;
+pub const SECURITY_CRED_TYPE_BASE_RID: u32 = 65;
+pub const SECURITY_CRED_TYPE_RID_COUNT: u32 = 2;
+pub const SECURITY_CRED_TYPE_THIS_ORG_CERT_RID: u32 = 1;
+pub const SECURITY_MIN_BASE_RID: u32 = 80;
+pub const SECURITY_SERVICE_ID_BASE_RID: u32 = 80;
+pub const SECURITY_SERVICE_ID_RID_COUNT: u32 = 6;
+pub const SECURITY_RESERVED_ID_BASE_RID: u32 = 81;
+pub const SECURITY_APPPOOL_ID_BASE_RID: u32 = 82;
+pub const SECURITY_APPPOOL_ID_RID_COUNT: u32 = 6;
+pub const SECURITY_VIRTUALSERVER_ID_BASE_RID: u32 = 83;
+pub const SECURITY_VIRTUALSERVER_ID_RID_COUNT: u32 = 6;
+pub const SECURITY_USERMODEDRIVERHOST_ID_BASE_RID: u32 = 84;
+pub const SECURITY_USERMODEDRIVERHOST_ID_RID_COUNT: u32 = 6;
+pub const SECURITY_CLOUD_INFRASTRUCTURE_SERVICES_ID_BASE_RID: u32 = 85;
+pub const SECURITY_CLOUD_INFRASTRUCTURE_SERVICES_ID_RID_COUNT: u32 = 6;
+pub const SECURITY_WMIHOST_ID_BASE_RID: u32 = 86;
+pub const SECURITY_WMIHOST_ID_RID_COUNT: u32 = 6;
+pub const SECURITY_TASK_ID_BASE_RID: u32 = 87;
+pub const SECURITY_NFS_ID_BASE_RID: u32 = 88;
+pub const SECURITY_COM_ID_BASE_RID: u32 = 89;
+pub const SECURITY_WINDOW_MANAGER_BASE_RID: u32 = 90;
+pub const SECURITY_RDV_GFX_BASE_RID: u32 = 91;
+pub const SECURITY_DASHOST_ID_BASE_RID: u32 = 92;
+pub const SECURITY_DASHOST_ID_RID_COUNT: u32 = 6;
+pub const SECURITY_USERMANAGER_ID_BASE_RID: u32 = 93;
+pub const SECURITY_USERMANAGER_ID_RID_COUNT: u32 = 6;
+pub const SECURITY_WINRM_ID_BASE_RID: u32 = 94;
+pub const SECURITY_WINRM_ID_RID_COUNT: u32 = 6;
+pub const SECURITY_CCG_ID_BASE_RID: u32 = 95;
+pub const SECURITY_UMFD_BASE_RID: u32 = 96;
+pub const SECURITY_VIRTUALACCOUNT_ID_RID_COUNT: u32 = 6;
+pub const SECURITY_MAX_BASE_RID: u32 = 111;
+pub const SECURITY_MAX_ALWAYS_FILTERED: u32 = 999;
+pub const SECURITY_MIN_NEVER_FILTERED: u32 = 1000;
+pub const SECURITY_OTHER_ORGANIZATION_RID: u32 = 1000;
+pub const SECURITY_WINDOWSMOBILE_ID_BASE_RID: u32 = 112;
+pub const SECURITY_INSTALLER_GROUP_CAPABILITY_BASE: u32 = 32;
+pub const SECURITY_INSTALLER_GROUP_CAPABILITY_RID_COUNT: u32 = 9;
+pub const SECURITY_INSTALLER_CAPABILITY_RID_COUNT: u32 = 10;
+pub const SECURITY_LOCAL_ACCOUNT_RID: u32 = 113;
+pub const SECURITY_LOCAL_ACCOUNT_AND_ADMIN_RID: u32 = 114;
+pub const DOMAIN_GROUP_RID_AUTHORIZATION_DATA_IS_COMPOUNDED: u32 = 496;
+pub const DOMAIN_GROUP_RID_AUTHORIZATION_DATA_CONTAINS_CLAIMS: u32 = 497;
+pub const DOMAIN_GROUP_RID_ENTERPRISE_READONLY_DOMAIN_CONTROLLERS: u32 = 498;
+pub const FOREST_USER_RID_MAX: u32 = 499;
+pub const DOMAIN_USER_RID_ADMIN: u32 = 500;
+pub const DOMAIN_USER_RID_GUEST: u32 = 501;
+pub const DOMAIN_USER_RID_KRBTGT: u32 = 502;
+pub const DOMAIN_USER_RID_DEFAULT_ACCOUNT: u32 = 503;
+pub const DOMAIN_USER_RID_WDAG_ACCOUNT: u32 = 504;
+pub const DOMAIN_USER_RID_MAX: u32 = 999;
+pub const DOMAIN_GROUP_RID_ADMINS: u32 = 512;
+pub const DOMAIN_GROUP_RID_USERS: u32 = 513;
+pub const DOMAIN_GROUP_RID_GUESTS: u32 = 514;
+pub const DOMAIN_GROUP_RID_COMPUTERS: u32 = 515;
+pub const DOMAIN_GROUP_RID_CONTROLLERS: u32 = 516;
+pub const DOMAIN_GROUP_RID_CERT_ADMINS: u32 = 517;
+pub const DOMAIN_GROUP_RID_SCHEMA_ADMINS: u32 = 518;
+pub const DOMAIN_GROUP_RID_ENTERPRISE_ADMINS: u32 = 519;
+pub const DOMAIN_GROUP_RID_POLICY_ADMINS: u32 = 520;
+pub const DOMAIN_GROUP_RID_READONLY_CONTROLLERS: u32 = 521;
+pub const DOMAIN_GROUP_RID_CLONEABLE_CONTROLLERS: u32 = 522;
+pub const DOMAIN_GROUP_RID_CDC_RESERVED: u32 = 524;
+pub const DOMAIN_GROUP_RID_PROTECTED_USERS: u32 = 525;
+pub const DOMAIN_GROUP_RID_KEY_ADMINS: u32 = 526;
+pub const DOMAIN_GROUP_RID_ENTERPRISE_KEY_ADMINS: u32 = 527;
+pub const DOMAIN_ALIAS_RID_ADMINS: u32 = 544;
+pub const DOMAIN_ALIAS_RID_USERS: u32 = 545;
+pub const DOMAIN_ALIAS_RID_GUESTS: u32 = 546;
+pub const DOMAIN_ALIAS_RID_POWER_USERS: u32 = 547;
+pub const DOMAIN_ALIAS_RID_ACCOUNT_OPS: u32 = 548;
+pub const DOMAIN_ALIAS_RID_SYSTEM_OPS: u32 = 549;
+pub const DOMAIN_ALIAS_RID_PRINT_OPS: u32 = 550;
+pub const DOMAIN_ALIAS_RID_BACKUP_OPS: u32 = 551;
+pub const DOMAIN_ALIAS_RID_REPLICATOR: u32 = 552;
+pub const DOMAIN_ALIAS_RID_RAS_SERVERS: u32 = 553;
+pub const DOMAIN_ALIAS_RID_PREW2KCOMPACCESS: u32 = 554;
+pub const DOMAIN_ALIAS_RID_REMOTE_DESKTOP_USERS: u32 = 555;
+pub const DOMAIN_ALIAS_RID_NETWORK_CONFIGURATION_OPS: u32 = 556;
+pub const DOMAIN_ALIAS_RID_INCOMING_FOREST_TRUST_BUILDERS: u32 = 557;
+pub const DOMAIN_ALIAS_RID_MONITORING_USERS: u32 = 558;
+pub const DOMAIN_ALIAS_RID_LOGGING_USERS: u32 = 559;
+pub const DOMAIN_ALIAS_RID_AUTHORIZATIONACCESS: u32 = 560;
+pub const DOMAIN_ALIAS_RID_TS_LICENSE_SERVERS: u32 = 561;
+pub const DOMAIN_ALIAS_RID_DCOM_USERS: u32 = 562;
+pub const DOMAIN_ALIAS_RID_IUSERS: u32 = 568;
+pub const DOMAIN_ALIAS_RID_CRYPTO_OPERATORS: u32 = 569;
+pub const DOMAIN_ALIAS_RID_CACHEABLE_PRINCIPALS_GROUP: u32 = 571;
+pub const DOMAIN_ALIAS_RID_NON_CACHEABLE_PRINCIPALS_GROUP: u32 = 572;
+pub const DOMAIN_ALIAS_RID_EVENT_LOG_READERS_GROUP: u32 = 573;
+pub const DOMAIN_ALIAS_RID_CERTSVC_DCOM_ACCESS_GROUP: u32 = 574;
+pub const DOMAIN_ALIAS_RID_RDS_REMOTE_ACCESS_SERVERS: u32 = 575;
+pub const DOMAIN_ALIAS_RID_RDS_ENDPOINT_SERVERS: u32 = 576;
+pub const DOMAIN_ALIAS_RID_RDS_MANAGEMENT_SERVERS: u32 = 577;
+pub const DOMAIN_ALIAS_RID_HYPER_V_ADMINS: u32 = 578;
+pub const DOMAIN_ALIAS_RID_ACCESS_CONTROL_ASSISTANCE_OPS: u32 = 579;
+pub const DOMAIN_ALIAS_RID_REMOTE_MANAGEMENT_USERS: u32 = 580;
+pub const DOMAIN_ALIAS_RID_DEFAULT_ACCOUNT: u32 = 581;
+pub const DOMAIN_ALIAS_RID_STORAGE_REPLICA_ADMINS: u32 = 582;
+pub const DOMAIN_ALIAS_RID_DEVICE_OWNERS: u32 = 583;
+pub const SECURITY_APP_PACKAGE_BASE_RID: u32 = 2;
+pub const SECURITY_BUILTIN_APP_PACKAGE_RID_COUNT: u32 = 2;
+pub const SECURITY_APP_PACKAGE_RID_COUNT: u32 = 8;
+pub const SECURITY_CAPABILITY_BASE_RID: u32 = 3;
+pub const SECURITY_CAPABILITY_APP_RID: u32 = 1024;
+pub const SECURITY_CAPABILITY_APP_SILO_RID: u32 = 65536;
+pub const SECURITY_BUILTIN_CAPABILITY_RID_COUNT: u32 = 2;
+pub const SECURITY_CAPABILITY_RID_COUNT: u32 = 5;
+pub const SECURITY_PARENT_PACKAGE_RID_COUNT: u32 = 8;
+pub const SECURITY_CHILD_PACKAGE_RID_COUNT: u32 = 12;
+pub const SECURITY_BUILTIN_PACKAGE_ANY_PACKAGE: u32 = 1;
+pub const SECURITY_BUILTIN_PACKAGE_ANY_RESTRICTED_PACKAGE: u32 = 2;
+pub const SECURITY_CAPABILITY_INTERNET_CLIENT: u32 = 1;
+pub const SECURITY_CAPABILITY_INTERNET_CLIENT_SERVER: u32 = 2;
+pub const SECURITY_CAPABILITY_PRIVATE_NETWORK_CLIENT_SERVER: u32 = 3;
+pub const SECURITY_CAPABILITY_PICTURES_LIBRARY: u32 = 4;
+pub const SECURITY_CAPABILITY_VIDEOS_LIBRARY: u32 = 5;
+pub const SECURITY_CAPABILITY_MUSIC_LIBRARY: u32 = 6;
+pub const SECURITY_CAPABILITY_DOCUMENTS_LIBRARY: u32 = 7;
+pub const SECURITY_CAPABILITY_ENTERPRISE_AUTHENTICATION: u32 = 8;
+pub const SECURITY_CAPABILITY_SHARED_USER_CERTIFICATES: u32 = 9;
+pub const SECURITY_CAPABILITY_REMOVABLE_STORAGE: u32 = 10;
+pub const SECURITY_CAPABILITY_APPOINTMENTS: u32 = 11;
+pub const SECURITY_CAPABILITY_CONTACTS: u32 = 12;
+pub const SECURITY_CAPABILITY_INTERNET_EXPLORER: u32 = 4096;
+pub const SECURITY_MANDATORY_UNTRUSTED_RID: u32 = 0;
+pub const SECURITY_MANDATORY_LOW_RID: u32 = 4096;
+pub const SECURITY_MANDATORY_MEDIUM_RID: u32 = 8192;
+pub const SECURITY_MANDATORY_MEDIUM_PLUS_RID: u32 = 8448;
+pub const SECURITY_MANDATORY_HIGH_RID: u32 = 12288;
+pub const SECURITY_MANDATORY_SYSTEM_RID: u32 = 16384;
+pub const SECURITY_MANDATORY_PROTECTED_PROCESS_RID: u32 = 20480;
+pub const SECURITY_MANDATORY_MAXIMUM_USER_RID: u32 = 16384;
+pub const SECURITY_AUTHENTICATION_AUTHORITY_RID_COUNT: u32 = 1;
+pub const SECURITY_AUTHENTICATION_AUTHORITY_ASSERTED_RID: u32 = 1;
+pub const SECURITY_AUTHENTICATION_SERVICE_ASSERTED_RID: u32 = 2;
+pub const SECURITY_AUTHENTICATION_FRESH_KEY_AUTH_RID: u32 = 3;
+pub const SECURITY_AUTHENTICATION_KEY_TRUST_RID: u32 = 4;
+pub const SECURITY_AUTHENTICATION_KEY_PROPERTY_MFA_RID: u32 = 5;
+pub const SECURITY_AUTHENTICATION_KEY_PROPERTY_ATTESTATION_RID: u32 = 6;
+pub const SECURITY_PROCESS_TRUST_AUTHORITY_RID_COUNT: u32 = 2;
+pub const SECURITY_PROCESS_PROTECTION_TYPE_FULL_RID: u32 = 1024;
+pub const SECURITY_PROCESS_PROTECTION_TYPE_LITE_RID: u32 = 512;
+pub const SECURITY_PROCESS_PROTECTION_TYPE_NONE_RID: u32 = 0;
+pub const SECURITY_PROCESS_PROTECTION_LEVEL_WINTCB_RID: u32 = 8192;
+pub const SECURITY_PROCESS_PROTECTION_LEVEL_WINDOWS_RID: u32 = 4096;
+pub const SECURITY_PROCESS_PROTECTION_LEVEL_APP_RID: u32 = 2048;
+pub const SECURITY_PROCESS_PROTECTION_LEVEL_ANTIMALWARE_RID: u32 = 1536;
+pub const SECURITY_PROCESS_PROTECTION_LEVEL_AUTHENTICODE_RID: u32 = 1024;
+pub const SECURITY_PROCESS_PROTECTION_LEVEL_NONE_RID: u32 = 0;
+pub const SECURITY_TRUSTED_INSTALLER_RID1: u32 = 956008885;
+pub const SECURITY_TRUSTED_INSTALLER_RID2: u32 = 3418522649;
+pub const SECURITY_TRUSTED_INSTALLER_RID3: u32 = 1831038044;
+pub const SECURITY_TRUSTED_INSTALLER_RID4: u32 = 1853292631;
+pub const SECURITY_TRUSTED_INSTALLER_RID5: u32 = 2271478464;
+pub const ACCESS_MIN_MS_ACE_TYPE: u32 = 0;
+pub const ACCESS_ALLOWED_ACE_TYPE: u32 = 0;
+pub const ACCESS_DENIED_ACE_TYPE: u32 = 1;
+pub const SYSTEM_AUDIT_ACE_TYPE: u32 = 2;
+pub const SYSTEM_ALARM_ACE_TYPE: u32 = 3;
+pub const ACCESS_MAX_MS_V2_ACE_TYPE: u32 = 3;
+pub const ACCESS_ALLOWED_COMPOUND_ACE_TYPE: u32 = 4;
+pub const ACCESS_MAX_MS_V3_ACE_TYPE: u32 = 4;
+pub const ACCESS_MIN_MS_OBJECT_ACE_TYPE: u32 = 5;
+pub const ACCESS_ALLOWED_OBJECT_ACE_TYPE: u32 = 5;
+pub const ACCESS_DENIED_OBJECT_ACE_TYPE: u32 = 6;
+pub const SYSTEM_AUDIT_OBJECT_ACE_TYPE: u32 = 7;
+pub const SYSTEM_ALARM_OBJECT_ACE_TYPE: u32 = 8;
+pub const ACCESS_MAX_MS_OBJECT_ACE_TYPE: u32 = 8;
+pub const ACCESS_MAX_MS_V4_ACE_TYPE: u32 = 8;
+pub const ACCESS_MAX_MS_ACE_TYPE: u32 = 8;
+pub const ACCESS_ALLOWED_CALLBACK_ACE_TYPE: u32 = 9;
+pub const ACCESS_DENIED_CALLBACK_ACE_TYPE: u32 = 10;
+pub const ACCESS_ALLOWED_CALLBACK_OBJECT_ACE_TYPE: u32 = 11;
+pub const ACCESS_DENIED_CALLBACK_OBJECT_ACE_TYPE: u32 = 12;
+pub const SYSTEM_AUDIT_CALLBACK_ACE_TYPE: u32 = 13;
+pub const SYSTEM_ALARM_CALLBACK_ACE_TYPE: u32 = 14;
+pub const SYSTEM_AUDIT_CALLBACK_OBJECT_ACE_TYPE: u32 = 15;
+pub const SYSTEM_ALARM_CALLBACK_OBJECT_ACE_TYPE: u32 = 16;
+pub const SYSTEM_MANDATORY_LABEL_ACE_TYPE: u32 = 17;
+pub const SYSTEM_RESOURCE_ATTRIBUTE_ACE_TYPE: u32 = 18;
+pub const SYSTEM_SCOPED_POLICY_ID_ACE_TYPE: u32 = 19;
+pub const SYSTEM_PROCESS_TRUST_LABEL_ACE_TYPE: u32 = 20;
+pub const SYSTEM_ACCESS_FILTER_ACE_TYPE: u32 = 21;
+pub const ACCESS_MAX_MS_V5_ACE_TYPE: u32 = 21;
+pub const OBJECT_INHERIT_ACE: u32 = 1;
+pub const CONTAINER_INHERIT_ACE: u32 = 2;
+pub const NO_PROPAGATE_INHERIT_ACE: u32 = 4;
+pub const INHERIT_ONLY_ACE: u32 = 8;
+pub const INHERITED_ACE: u32 = 16;
+pub const VALID_INHERIT_FLAGS: u32 = 31;
+pub const CRITICAL_ACE_FLAG: u32 = 32;
+pub const SUCCESSFUL_ACCESS_ACE_FLAG: u32 = 64;
+pub const FAILED_ACCESS_ACE_FLAG: u32 = 128;
+pub const TRUST_PROTECTED_FILTER_ACE_FLAG: u32 = 64;
+pub const SYSTEM_MANDATORY_LABEL_NO_WRITE_UP: u32 = 1;
+pub const SYSTEM_MANDATORY_LABEL_NO_READ_UP: u32 = 2;
+pub const SYSTEM_MANDATORY_LABEL_NO_EXECUTE_UP: u32 = 4;
+pub const SYSTEM_MANDATORY_LABEL_VALID_MASK: u32 = 7;
+pub const SYSTEM_PROCESS_TRUST_LABEL_VALID_MASK: u32 = 16777215;
+pub const SYSTEM_PROCESS_TRUST_NOCONSTRAINT_MASK: u32 = 4294967295;
+pub const SYSTEM_ACCESS_FILTER_VALID_MASK: u32 = 16777215;
+pub const SYSTEM_ACCESS_FILTER_NOCONSTRAINT_MASK: u32 = 4294967295;
+pub const SE_OWNER_DEFAULTED: u32 = 1;
+pub const SE_GROUP_DEFAULTED: u32 = 2;
+pub const SE_DACL_PRESENT: u32 = 4;
+pub const SE_DACL_DEFAULTED: u32 = 8;
+pub const SE_SACL_PRESENT: u32 = 16;
+pub const SE_SACL_DEFAULTED: u32 = 32;
+pub const SE_DACL_UNTRUSTED: u32 = 64;
+pub const SE_SERVER_SECURITY: u32 = 128;
+pub const SE_DACL_AUTO_INHERIT_REQ: u32 = 256;
+pub const SE_SACL_AUTO_INHERIT_REQ: u32 = 512;
+pub const SE_DACL_AUTO_INHERITED: u32 = 1024;
+pub const SE_SACL_AUTO_INHERITED: u32 = 2048;
+pub const SE_DACL_PROTECTED: u32 = 4096;
+pub const SE_SACL_PROTECTED: u32 = 8192;
+pub const SE_RM_CONTROL_VALID: u32 = 16384;
+pub const SE_SELF_RELATIVE: u32 = 32768;
+pub const ACCESS_OBJECT_GUID: u32 = 0;
+pub const ACCESS_PROPERTY_SET_GUID: u32 = 1;
+pub const ACCESS_PROPERTY_GUID: u32 = 2;
+pub const ACCESS_MAX_LEVEL: u32 = 4;
+pub const AUDIT_ALLOW_NO_PRIVILEGE: u32 = 1;
+pub const ACCESS_DS_SOURCE_A: &[u8; 3] = b"DS\0";
+pub const ACCESS_DS_SOURCE_W: &[u8; 3] = b"DS\0";
+pub const ACCESS_DS_OBJECT_TYPE_NAME_A: &[u8; 25] = b"Directory Service Object\0";
+pub const ACCESS_DS_OBJECT_TYPE_NAME_W: &[u8; 25] = b"Directory Service Object\0";
+pub const ACCESS_REASON_TYPE_MASK: u32 = 16711680;
+pub const ACCESS_REASON_DATA_MASK: u32 = 65535;
+pub const ACCESS_REASON_STAGING_MASK: u32 = 2147483648;
+pub const ACCESS_REASON_EXDATA_MASK: u32 = 2130706432;
+pub const SE_SECURITY_DESCRIPTOR_FLAG_NO_OWNER_ACE: u32 = 1;
+pub const SE_SECURITY_DESCRIPTOR_FLAG_NO_LABEL_ACE: u32 = 2;
+pub const SE_SECURITY_DESCRIPTOR_FLAG_NO_ACCESS_FILTER_ACE: u32 = 4;
+pub const SE_SECURITY_DESCRIPTOR_VALID_FLAGS: u32 = 7;
+pub const SE_ACCESS_CHECK_FLAG_NO_LEARNING_MODE_LOGGING: u32 = 8;
+pub const SE_ACCESS_CHECK_VALID_FLAGS: u32 = 8;
+pub const TOKEN_ASSIGN_PRIMARY: u32 = 1;
+pub const TOKEN_DUPLICATE: u32 = 2;
+pub const TOKEN_IMPERSONATE: u32 = 4;
+pub const TOKEN_QUERY: u32 = 8;
+pub const TOKEN_QUERY_SOURCE: u32 = 16;
+pub const TOKEN_ADJUST_PRIVILEGES: u32 = 32;
+pub const TOKEN_ADJUST_GROUPS: u32 = 64;
+pub const TOKEN_ADJUST_DEFAULT: u32 = 128;
+pub const TOKEN_ADJUST_SESSIONID: u32 = 256;
+pub const TOKEN_ALL_ACCESS_P: u32 = 983295;
+pub const TOKEN_ALL_ACCESS: u32 = 983551;
+pub const TOKEN_READ: u32 = 131080;
+pub const TOKEN_WRITE: u32 = 131296;
+pub const TOKEN_EXECUTE: u32 = 131072;
+pub const TOKEN_TRUST_CONSTRAINT_MASK: u32 = 131096;
+pub const TOKEN_TRUST_ALLOWED_MASK: u32 = 131102;
+pub const TOKEN_ACCESS_PSEUDO_HANDLE_WIN8: u32 = 24;
+pub const TOKEN_ACCESS_PSEUDO_HANDLE: u32 = 24;
+pub const TOKEN_MANDATORY_POLICY_OFF: u32 = 0;
+pub const TOKEN_MANDATORY_POLICY_NO_WRITE_UP: u32 = 1;
+pub const TOKEN_MANDATORY_POLICY_NEW_PROCESS_MIN: u32 = 2;
+pub const TOKEN_MANDATORY_POLICY_VALID_MASK: u32 = 3;
+pub const POLICY_AUDIT_SUBCATEGORY_COUNT: u32 = 59;
+pub const TOKEN_SOURCE_LENGTH: u32 = 8;
+pub const CLAIM_SECURITY_ATTRIBUTE_TYPE_INVALID: u32 = 0;
+pub const CLAIM_SECURITY_ATTRIBUTE_TYPE_INT64: u32 = 1;
+pub const CLAIM_SECURITY_ATTRIBUTE_TYPE_UINT64: u32 = 2;
+pub const CLAIM_SECURITY_ATTRIBUTE_TYPE_STRING: u32 = 3;
+pub const CLAIM_SECURITY_ATTRIBUTE_TYPE_FQBN: u32 = 4;
+pub const CLAIM_SECURITY_ATTRIBUTE_TYPE_SID: u32 = 5;
+pub const CLAIM_SECURITY_ATTRIBUTE_TYPE_BOOLEAN: u32 = 6;
+pub const CLAIM_SECURITY_ATTRIBUTE_TYPE_OCTET_STRING: u32 = 16;
+pub const CLAIM_SECURITY_ATTRIBUTE_NON_INHERITABLE: u32 = 1;
+pub const CLAIM_SECURITY_ATTRIBUTE_VALUE_CASE_SENSITIVE: u32 = 2;
+pub const CLAIM_SECURITY_ATTRIBUTE_USE_FOR_DENY_ONLY: u32 = 4;
+pub const CLAIM_SECURITY_ATTRIBUTE_DISABLED_BY_DEFAULT: u32 = 8;
+pub const CLAIM_SECURITY_ATTRIBUTE_DISABLED: u32 = 16;
+pub const CLAIM_SECURITY_ATTRIBUTE_MANDATORY: u32 = 32;
+pub const CLAIM_SECURITY_ATTRIBUTE_VALID_FLAGS: u32 = 63;
+pub const CLAIM_SECURITY_ATTRIBUTE_CUSTOM_FLAGS: u32 = 4294901760;
+pub const CLAIM_SECURITY_ATTRIBUTES_INFORMATION_VERSION_V1: u32 = 1;
+pub const CLAIM_SECURITY_ATTRIBUTES_INFORMATION_VERSION: u32 = 1;
+pub const SEGMENT_HEAP_PARAMETERS_VERSION: u32 = 3;
+pub const SEGMENT_HEAP_FLG_USE_PAGE_HEAP: u32 = 1;
+pub const SEGMENT_HEAP_PARAMS_VALID_FLAGS: u32 = 1;
+pub const RTL_HEAP_MEMORY_LIMIT_CURRENT_VERSION: u32 = 1;
+pub const HEAP_NO_SERIALIZE: u32 = 1;
+pub const HEAP_GROWABLE: u32 = 2;
+pub const HEAP_GENERATE_EXCEPTIONS: u32 = 4;
+pub const HEAP_ZERO_MEMORY: u32 = 8;
+pub const HEAP_REALLOC_IN_PLACE_ONLY: u32 = 16;
+pub const HEAP_TAIL_CHECKING_ENABLED: u32 = 32;
+pub const HEAP_FREE_CHECKING_ENABLED: u32 = 64;
+pub const HEAP_DISABLE_COALESCE_ON_FREE: u32 = 128;
+pub const HEAP_CREATE_ALIGN_16: u32 = 65536;
+pub const HEAP_CREATE_ENABLE_TRACING: u32 = 131072;
+pub const HEAP_CREATE_ENABLE_EXECUTE: u32 = 262144;
+pub const HEAP_SETTABLE_USER_VALUE: u32 = 256;
+pub const HEAP_SETTABLE_USER_FLAG1: u32 = 512;
+pub const HEAP_SETTABLE_USER_FLAG2: u32 = 1024;
+pub const HEAP_SETTABLE_USER_FLAG3: u32 = 2048;
+pub const HEAP_SETTABLE_USER_FLAGS: u32 = 3584;
+pub const HEAP_CLASS_0: u32 = 0;
+pub const HEAP_CLASS_1: u32 = 4096;
+pub const HEAP_CLASS_2: u32 = 8192;
+pub const HEAP_CLASS_3: u32 = 12288;
+pub const HEAP_CLASS_4: u32 = 16384;
+pub const HEAP_CLASS_5: u32 = 20480;
+pub const HEAP_CLASS_6: u32 = 24576;
+pub const HEAP_CLASS_7: u32 = 28672;
+pub const HEAP_CLASS_8: u32 = 32768;
+pub const HEAP_CLASS_MASK: u32 = 61440;
+pub const HEAP_MAXIMUM_TAG: u32 = 4095;
+pub const HEAP_GLOBAL_TAG: u32 = 2048;
+pub const HEAP_PSEUDO_TAG_FLAG: u32 = 32768;
+pub const HEAP_TAG_SHIFT: u32 = 18;
+pub const HEAP_TAG_MASK: u32 = 1073479680;
+pub const HEAP_CREATE_SEGMENT_HEAP: u32 = 256;
+pub const HEAP_CREATE_HARDENED: u32 = 512;
+pub const HEAP_CREATE_VALID_MASK: u32 = 521215;
+pub const RTL_DUPLICATE_UNICODE_STRING_NULL_TERMINATE: u32 = 1;
+pub const RTL_DUPLICATE_UNICODE_STRING_ALLOCATE_NULL_STRING: u32 = 2;
+pub const COMPRESSION_FORMAT_NONE: u32 = 0;
+pub const COMPRESSION_FORMAT_DEFAULT: u32 = 1;
+pub const COMPRESSION_FORMAT_LZNT1: u32 = 2;
+pub const COMPRESSION_FORMAT_XPRESS: u32 = 3;
+pub const COMPRESSION_FORMAT_XPRESS_HUFF: u32 = 4;
+pub const COMPRESSION_FORMAT_XP10: u32 = 5;
+pub const COMPRESSION_FORMAT_MAX: u32 = 5;
+pub const COMPRESSION_ENGINE_STANDARD: u32 = 0;
+pub const COMPRESSION_ENGINE_MAXIMUM: u32 = 256;
+pub const COMPRESSION_ENGINE_HIBER: u32 = 512;
+pub const COMPRESSION_ENGINE_MAX: u32 = 512;
+pub const COMPRESSION_FORMAT_MASK: u32 = 255;
+pub const COMPRESSION_ENGINE_MASK: u32 = 65280;
+pub const COMPRESSION_FORMAT_ENGINE_MASK: u32 = 65535;
+pub const MAX_UNICODE_STACK_BUFFER_LENGTH: u32 = 256;
+pub const RTL_SYSTEM_VOLUME_INFORMATION_FOLDER: &[u8; 26] = b"System Volume Information\0";
+pub const PSMP_MINIMUM_SYSAPP_CLAIM_VALUES: u32 = 2;
+pub const PSMP_MAXIMUM_SYSAPP_CLAIM_VALUES: u32 = 4;
+pub const MSV1_0_PACKAGE_NAME: &[u8; 38] = b"MICROSOFT_AUTHENTICATION_PACKAGE_V1_0\0";
+pub const MSV1_0_PACKAGE_NAMEW: &[u8; 38] = b"MICROSOFT_AUTHENTICATION_PACKAGE_V1_0\0";
+pub const MSV1_0_SUBAUTHENTICATION_KEY: &[u8; 44] =
+    b"SYSTEM\\CurrentControlSet\\Control\\Lsa\\MSV1_0\0";
+pub const MSV1_0_SUBAUTHENTICATION_VALUE: &[u8; 5] = b"Auth\0";
+pub const MSV1_0_CHALLENGE_LENGTH: u32 = 8;
+pub const MSV1_0_USER_SESSION_KEY_LENGTH: u32 = 16;
+pub const MSV1_0_LANMAN_SESSION_KEY_LENGTH: u32 = 8;
+pub const MSV1_0_CLEARTEXT_PASSWORD_ALLOWED: u32 = 2;
+pub const MSV1_0_UPDATE_LOGON_STATISTICS: u32 = 4;
+pub const MSV1_0_RETURN_USER_PARAMETERS: u32 = 8;
+pub const MSV1_0_DONT_TRY_GUEST_ACCOUNT: u32 = 16;
+pub const MSV1_0_ALLOW_SERVER_TRUST_ACCOUNT: u32 = 32;
+pub const MSV1_0_RETURN_PASSWORD_EXPIRY: u32 = 64;
+pub const MSV1_0_USE_CLIENT_CHALLENGE: u32 = 128;
+pub const MSV1_0_TRY_GUEST_ACCOUNT_ONLY: u32 = 256;
+pub const MSV1_0_RETURN_PROFILE_PATH: u32 = 512;
+pub const MSV1_0_TRY_SPECIFIED_DOMAIN_ONLY: u32 = 1024;
+pub const MSV1_0_ALLOW_WORKSTATION_TRUST_ACCOUNT: u32 = 2048;
+pub const MSV1_0_DISABLE_PERSONAL_FALLBACK: u32 = 4096;
+pub const MSV1_0_ALLOW_FORCE_GUEST: u32 = 8192;
+pub const MSV1_0_CLEARTEXT_PASSWORD_SUPPLIED: u32 = 16384;
+pub const MSV1_0_USE_DOMAIN_FOR_ROUTING_ONLY: u32 = 32768;
+pub const MSV1_0_SUBAUTHENTICATION_DLL_EX: u32 = 1048576;
+pub const MSV1_0_ALLOW_MSVCHAPV2: u32 = 65536;
+pub const MSV1_0_S4U2SELF: u32 = 131072;
+pub const MSV1_0_CHECK_LOGONHOURS_FOR_S4U: u32 = 262144;
+pub const MSV1_0_INTERNET_DOMAIN: u32 = 524288;
+pub const MSV1_0_SUBAUTHENTICATION_DLL: u32 = 4278190080;
+pub const MSV1_0_SUBAUTHENTICATION_DLL_SHIFT: u32 = 24;
+pub const MSV1_0_MNS_LOGON: u32 = 16777216;
+pub const MSV1_0_SUBAUTHENTICATION_DLL_RAS: u32 = 2;
+pub const MSV1_0_SUBAUTHENTICATION_DLL_IIS: u32 = 132;
+pub const MSV1_0_S4U_LOGON_FLAG_CHECK_LOGONHOURS: u32 = 2;
+pub const LOGON_GUEST: u32 = 1;
+pub const LOGON_NOENCRYPTION: u32 = 2;
+pub const LOGON_CACHED_ACCOUNT: u32 = 4;
+pub const LOGON_USED_LM_PASSWORD: u32 = 8;
+pub const LOGON_EXTRA_SIDS: u32 = 32;
+pub const LOGON_SUBAUTH_SESSION_KEY: u32 = 64;
+pub const LOGON_SERVER_TRUST_ACCOUNT: u32 = 128;
+pub const LOGON_NTLMV2_ENABLED: u32 = 256;
+pub const LOGON_RESOURCE_GROUPS: u32 = 512;
+pub const LOGON_PROFILE_PATH_RETURNED: u32 = 1024;
+pub const LOGON_NT_V2: u32 = 2048;
+pub const LOGON_LM_V2: u32 = 4096;
+pub const LOGON_NTLM_V2: u32 = 8192;
+pub const LOGON_OPTIMIZED: u32 = 16384;
+pub const LOGON_WINLOGON: u32 = 32768;
+pub const LOGON_PKINIT: u32 = 65536;
+pub const LOGON_NO_OPTIMIZED: u32 = 131072;
+pub const LOGON_NO_ELEVATION: u32 = 262144;
+pub const LOGON_MANAGED_SERVICE: u32 = 524288;
+pub const MSV1_0_SUBAUTHENTICATION_FLAGS: u32 = 4278190080;
+pub const LOGON_GRACE_LOGON: u32 = 16777216;
+pub const MSV1_0_OWF_PASSWORD_LENGTH: u32 = 16;
+pub const MSV1_0_SHA_PASSWORD_LENGTH: u32 = 20;
+pub const MSV1_0_CREDENTIAL_KEY_LENGTH: u32 = 20;
+pub const MSV1_0_CRED_LM_PRESENT: u32 = 1;
+pub const MSV1_0_CRED_NT_PRESENT: u32 = 2;
+pub const MSV1_0_CRED_REMOVED: u32 = 4;
+pub const MSV1_0_CRED_CREDKEY_PRESENT: u32 = 8;
+pub const MSV1_0_CRED_SHA_PRESENT: u32 = 16;
+pub const MSV1_0_CRED_VERSION: u32 = 0;
+pub const MSV1_0_CRED_VERSION_V2: u32 = 2;
+pub const MSV1_0_CRED_VERSION_V3: u32 = 4;
+pub const MSV1_0_CRED_VERSION_IUM: u32 = 4294901761;
+pub const MSV1_0_CRED_VERSION_REMOTE: u32 = 4294901762;
+pub const MSV1_0_CRED_VERSION_ARSO: u32 = 4294901763;
+pub const MSV1_0_CRED_VERSION_RESERVED_1: u32 = 4294967294;
+pub const MSV1_0_CRED_VERSION_INVALID: u32 = 4294967295;
+pub const MSV1_0_NTLM3_RESPONSE_LENGTH: u32 = 16;
+pub const MSV1_0_NTLM3_OWF_LENGTH: u32 = 16;
+pub const MSV1_0_MAX_NTLM3_LIFE: u32 = 129600;
+pub const MSV1_0_MAX_AVL_SIZE: u32 = 64000;
+pub const MSV1_0_AV_FLAG_FORCE_GUEST: u32 = 1;
+pub const MSV1_0_AV_FLAG_MIC_HANDSHAKE_MESSAGES: u32 = 2;
+pub const MSV1_0_AV_FLAG_UNVERIFIED_TARGET: u32 = 4;
+pub const USE_PRIMARY_PASSWORD: u32 = 1;
+pub const RETURN_PRIMARY_USERNAME: u32 = 2;
+pub const RETURN_PRIMARY_LOGON_DOMAINNAME: u32 = 4;
+pub const RETURN_NON_NT_USER_SESSION_KEY: u32 = 8;
+pub const GENERATE_CLIENT_CHALLENGE: u32 = 16;
+pub const GCR_NTLM3_PARMS: u32 = 32;
+pub const GCR_TARGET_INFO: u32 = 64;
+pub const RETURN_RESERVED_PARAMETER: u32 = 128;
+pub const GCR_ALLOW_NTLM: u32 = 256;
+pub const GCR_USE_OEM_SET: u32 = 512;
+pub const GCR_MACHINE_CREDENTIAL: u32 = 1024;
+pub const GCR_USE_OWF_PASSWORD: u32 = 2048;
+pub const GCR_ALLOW_LM: u32 = 4096;
+pub const GCR_ALLOW_NO_TARGET: u32 = 8192;
+pub const GCR_VSM_PROTECTED_PASSWORD: u32 = 16384;
+pub const FILE_CLEANUP_UNKNOWN: u32 = 0;
+pub const FILE_CLEANUP_WRONG_DEVICE: u32 = 1;
+pub const FILE_CLEANUP_FILE_REMAINS: u32 = 2;
+pub const FILE_CLEANUP_FILE_DELETED: u32 = 4;
+pub const FILE_CLEANUP_LINK_DELETED: u32 = 8;
+pub const FILE_CLEANUP_STREAM_DELETED: u32 = 16;
+pub const FILE_CLEANUP_POSIX_STYLE_DELETE: u32 = 32;
+pub const FILE_OPLOCK_BROKEN_TO_LEVEL_2: u32 = 7;
+pub const FILE_OPLOCK_BROKEN_TO_NONE: u32 = 8;
+pub const FILE_OPBATCH_BREAK_UNDERWAY: u32 = 9;
+pub const FILE_NOTIFY_CHANGE_FILE_NAME: u32 = 1;
+pub const FILE_NOTIFY_CHANGE_DIR_NAME: u32 = 2;
+pub const FILE_NOTIFY_CHANGE_NAME: u32 = 3;
+pub const FILE_NOTIFY_CHANGE_ATTRIBUTES: u32 = 4;
+pub const FILE_NOTIFY_CHANGE_SIZE: u32 = 8;
+pub const FILE_NOTIFY_CHANGE_LAST_WRITE: u32 = 16;
+pub const FILE_NOTIFY_CHANGE_LAST_ACCESS: u32 = 32;
+pub const FILE_NOTIFY_CHANGE_CREATION: u32 = 64;
+pub const FILE_NOTIFY_CHANGE_EA: u32 = 128;
+pub const FILE_NOTIFY_CHANGE_SECURITY: u32 = 256;
+pub const FILE_NOTIFY_CHANGE_STREAM_NAME: u32 = 512;
+pub const FILE_NOTIFY_CHANGE_STREAM_SIZE: u32 = 1024;
+pub const FILE_NOTIFY_CHANGE_STREAM_WRITE: u32 = 2048;
+pub const FILE_NOTIFY_VALID_MASK: u32 = 4095;
+pub const FILE_ACTION_ADDED: u32 = 1;
+pub const FILE_ACTION_REMOVED: u32 = 2;
+pub const FILE_ACTION_MODIFIED: u32 = 3;
+pub const FILE_ACTION_RENAMED_OLD_NAME: u32 = 4;
+pub const FILE_ACTION_RENAMED_NEW_NAME: u32 = 5;
+pub const FILE_ACTION_ADDED_STREAM: u32 = 6;
+pub const FILE_ACTION_REMOVED_STREAM: u32 = 7;
+pub const FILE_ACTION_MODIFIED_STREAM: u32 = 8;
+pub const FILE_ACTION_REMOVED_BY_DELETE: u32 = 9;
+pub const FILE_ACTION_ID_NOT_TUNNELLED: u32 = 10;
+pub const FILE_ACTION_TUNNELLED_ID_COLLISION: u32 = 11;
+pub const FILE_PIPE_BYTE_STREAM_TYPE: u32 = 0;
+pub const FILE_PIPE_MESSAGE_TYPE: u32 = 1;
+pub const FILE_PIPE_ACCEPT_REMOTE_CLIENTS: u32 = 0;
+pub const FILE_PIPE_REJECT_REMOTE_CLIENTS: u32 = 2;
+pub const FILE_PIPE_TYPE_VALID_MASK: u32 = 3;
+pub const FILE_PIPE_QUEUE_OPERATION: u32 = 0;
+pub const FILE_PIPE_COMPLETE_OPERATION: u32 = 1;
+pub const FILE_PIPE_BYTE_STREAM_MODE: u32 = 0;
+pub const FILE_PIPE_MESSAGE_MODE: u32 = 1;
+pub const FILE_PIPE_INBOUND: u32 = 0;
+pub const FILE_PIPE_OUTBOUND: u32 = 1;
+pub const FILE_PIPE_FULL_DUPLEX: u32 = 2;
+pub const FILE_PIPE_DISCONNECTED_STATE: u32 = 1;
+pub const FILE_PIPE_LISTENING_STATE: u32 = 2;
+pub const FILE_PIPE_CONNECTED_STATE: u32 = 3;
+pub const FILE_PIPE_CLOSING_STATE: u32 = 4;
+pub const FILE_PIPE_CLIENT_END: u32 = 0;
+pub const FILE_PIPE_SERVER_END: u32 = 1;
+pub const FILE_CASE_SENSITIVE_SEARCH: u32 = 1;
+pub const FILE_CASE_PRESERVED_NAMES: u32 = 2;
+pub const FILE_UNICODE_ON_DISK: u32 = 4;
+pub const FILE_PERSISTENT_ACLS: u32 = 8;
+pub const FILE_FILE_COMPRESSION: u32 = 16;
+pub const FILE_VOLUME_QUOTAS: u32 = 32;
+pub const FILE_SUPPORTS_SPARSE_FILES: u32 = 64;
+pub const FILE_SUPPORTS_REPARSE_POINTS: u32 = 128;
+pub const FILE_SUPPORTS_REMOTE_STORAGE: u32 = 256;
+pub const FILE_RETURNS_CLEANUP_RESULT_INFO: u32 = 512;
+pub const FILE_SUPPORTS_POSIX_UNLINK_RENAME: u32 = 1024;
+pub const FILE_SUPPORTS_BYPASS_IO: u32 = 2048;
+pub const FILE_SUPPORTS_STREAM_SNAPSHOTS: u32 = 4096;
+pub const FILE_SUPPORTS_CASE_SENSITIVE_DIRS: u32 = 8192;
+pub const FILE_VOLUME_IS_COMPRESSED: u32 = 32768;
+pub const FILE_SUPPORTS_OBJECT_IDS: u32 = 65536;
+pub const FILE_SUPPORTS_ENCRYPTION: u32 = 131072;
+pub const FILE_NAMED_STREAMS: u32 = 262144;
+pub const FILE_READ_ONLY_VOLUME: u32 = 524288;
+pub const FILE_SEQUENTIAL_WRITE_ONCE: u32 = 1048576;
+pub const FILE_SUPPORTS_TRANSACTIONS: u32 = 2097152;
+pub const FILE_SUPPORTS_HARD_LINKS: u32 = 4194304;
+pub const FILE_SUPPORTS_EXTENDED_ATTRIBUTES: u32 = 8388608;
+pub const FILE_SUPPORTS_OPEN_BY_FILE_ID: u32 = 16777216;
+pub const FILE_SUPPORTS_USN_JOURNAL: u32 = 33554432;
+pub const FILE_SUPPORTS_INTEGRITY_STREAMS: u32 = 67108864;
+pub const FILE_SUPPORTS_BLOCK_REFCOUNTING: u32 = 134217728;
+pub const FILE_SUPPORTS_SPARSE_VDL: u32 = 268435456;
+pub const FILE_DAX_VOLUME: u32 = 536870912;
+pub const FILE_SUPPORTS_GHOSTING: u32 = 1073741824;
+pub const FILE_NEED_EA: u32 = 128;
+pub const FILE_EA_TYPE_BINARY: u32 = 65534;
+pub const FILE_EA_TYPE_ASCII: u32 = 65533;
+pub const FILE_EA_TYPE_BITMAP: u32 = 65531;
+pub const FILE_EA_TYPE_METAFILE: u32 = 65530;
+pub const FILE_EA_TYPE_ICON: u32 = 65529;
+pub const FILE_EA_TYPE_EA: u32 = 65518;
+pub const FILE_EA_TYPE_MVMT: u32 = 65503;
+pub const FILE_EA_TYPE_MVST: u32 = 65502;
+pub const FILE_EA_TYPE_ASN1: u32 = 65501;
+pub const FILE_EA_TYPE_FAMILY_IDS: u32 = 65281;
+pub const FILE_NAME_FLAG_HARDLINK: u32 = 0;
+pub const FILE_NAME_FLAG_NTFS: u32 = 1;
+pub const FILE_NAME_FLAG_DOS: u32 = 2;
+pub const FILE_NAME_FLAG_BOTH: u32 = 3;
+pub const FILE_NAME_FLAGS_UNSPECIFIED: u32 = 128;
+pub const FILE_ID_GLOBAL_TX_DIR_INFO_FLAG_WRITELOCKED: u32 = 1;
+pub const FILE_ID_GLOBAL_TX_DIR_INFO_FLAG_VISIBLE_TO_TX: u32 = 2;
+pub const FILE_ID_GLOBAL_TX_DIR_INFO_FLAG_VISIBLE_OUTSIDE_TX: u32 = 4;
+pub const ANSI_DOS_STAR: u8 = 60u8;
+pub const ANSI_DOS_QM: u8 = 62u8;
+pub const ANSI_DOS_DOT: u8 = 34u8;
+pub const DOS_STAR: u8 = 60u8;
+pub const DOS_QM: u8 = 62u8;
+pub const DOS_DOT: u8 = 34u8;
+pub const NO_8DOT3_NAME_PRESENT: u32 = 1;
+pub const REMOVED_8DOT3_NAME: u32 = 2;
+pub const LX_FILE_METADATA_HAS_UID: u32 = 1;
+pub const LX_FILE_METADATA_HAS_GID: u32 = 2;
+pub const LX_FILE_METADATA_HAS_MODE: u32 = 4;
+pub const LX_FILE_METADATA_HAS_DEVICE_ID: u32 = 8;
+pub const LX_FILE_CASE_SENSITIVE_DIR: u32 = 16;
+pub const FILE_CS_FLAG_CASE_SENSITIVE_DIR: u32 = 1;
+pub const FILE_LINK_REPLACE_IF_EXISTS: u32 = 1;
+pub const FILE_LINK_POSIX_SEMANTICS: u32 = 2;
+pub const FILE_LINK_SUPPRESS_STORAGE_RESERVE_INHERITANCE: u32 = 8;
+pub const FILE_LINK_NO_INCREASE_AVAILABLE_SPACE: u32 = 16;
+pub const FILE_LINK_NO_DECREASE_AVAILABLE_SPACE: u32 = 32;
+pub const FILE_LINK_PRESERVE_AVAILABLE_SPACE: u32 = 48;
+pub const FILE_LINK_IGNORE_READONLY_ATTRIBUTE: u32 = 64;
+pub const FILE_LINK_FORCE_RESIZE_TARGET_SR: u32 = 128;
+pub const FILE_LINK_FORCE_RESIZE_SOURCE_SR: u32 = 256;
+pub const FILE_LINK_FORCE_RESIZE_SR: u32 = 384;
+pub const FILE_RENAME_REPLACE_IF_EXISTS: u32 = 1;
+pub const FILE_RENAME_POSIX_SEMANTICS: u32 = 2;
+pub const FILE_RENAME_SUPPRESS_PIN_STATE_INHERITANCE: u32 = 4;
+pub const FILE_RENAME_SUPPRESS_STORAGE_RESERVE_INHERITANCE: u32 = 8;
+pub const FILE_RENAME_NO_INCREASE_AVAILABLE_SPACE: u32 = 16;
+pub const FILE_RENAME_NO_DECREASE_AVAILABLE_SPACE: u32 = 32;
+pub const FILE_RENAME_PRESERVE_AVAILABLE_SPACE: u32 = 48;
+pub const FILE_RENAME_IGNORE_READONLY_ATTRIBUTE: u32 = 64;
+pub const FILE_RENAME_FORCE_RESIZE_TARGET_SR: u32 = 128;
+pub const FILE_RENAME_FORCE_RESIZE_SOURCE_SR: u32 = 256;
+pub const FILE_RENAME_FORCE_RESIZE_SR: u32 = 384;
+pub const REMOTE_PROTOCOL_FLAG_LOOPBACK: u32 = 1;
+pub const REMOTE_PROTOCOL_FLAG_OFFLINE: u32 = 2;
+pub const REMOTE_PROTOCOL_FLAG_PERSISTENT_HANDLE: u32 = 4;
+pub const REMOTE_PROTOCOL_FLAG_PRIVACY: u32 = 8;
+pub const REMOTE_PROTOCOL_FLAG_INTEGRITY: u32 = 16;
+pub const REMOTE_PROTOCOL_FLAG_MUTUAL_AUTH: u32 = 32;
+pub const RPI_SMB2_SHARECAP_TIMEWARP: u32 = 2;
+pub const RPI_SMB2_SHARECAP_DFS: u32 = 8;
+pub const RPI_SMB2_SHARECAP_CONTINUOUS_AVAILABILITY: u32 = 16;
+pub const RPI_SMB2_SHARECAP_SCALEOUT: u32 = 32;
+pub const RPI_SMB2_SHARECAP_CLUSTER: u32 = 64;
+pub const RPI_SMB2_SHARECAP_ENCRYPTED: u32 = 128;
+pub const RPI_SMB2_SHARECAP_ACCESS_BASED_DIRECTORY_ENUM: u32 = 256;
+pub const RPI_SMB2_SHARECAP_IDENTITY_REMOTING: u32 = 512;
+pub const RPI_SMB2_SHARECAP_ASYMMETRIC_SCALEOUT: u32 = 1024;
+pub const RPI_SMB2_SHAREFLAG_ENCRYPT_DATA: u32 = 1;
+pub const RPI_SMB2_SHAREFLAG_COMPRESS_DATA: u32 = 2;
+pub const RPI_SMB2_SHARETYPE_DISK: u32 = 0;
+pub const RPI_SMB2_SHARETYPE_PIPE: u32 = 1;
+pub const RPI_SMB2_SHARETYPE_PRINT: u32 = 2;
+pub const RPI_SMB2_SERVERCAP_DFS: u32 = 1;
+pub const RPI_SMB2_SERVERCAP_LEASING: u32 = 2;
+pub const RPI_SMB2_SERVERCAP_LARGEMTU: u32 = 4;
+pub const RPI_SMB2_SERVERCAP_MULTICHANNEL: u32 = 8;
+pub const RPI_SMB2_SERVERCAP_PERSISTENT_HANDLES: u32 = 16;
+pub const RPI_SMB2_SERVERCAP_DIRECTORY_LEASING: u32 = 32;
+pub const RPI_SMB2_SERVERCAP_ENCRYPTION_AWARE: u32 = 64;
+pub const FILE_VC_QUOTA_NONE: u32 = 0;
+pub const FILE_VC_QUOTA_TRACK: u32 = 1;
+pub const FILE_VC_QUOTA_ENFORCE: u32 = 2;
+pub const FILE_VC_QUOTA_MASK: u32 = 3;
+pub const FILE_VC_CONTENT_INDEX_DISABLED: u32 = 8;
+pub const FILE_VC_LOG_QUOTA_THRESHOLD: u32 = 16;
+pub const FILE_VC_LOG_QUOTA_LIMIT: u32 = 32;
+pub const FILE_VC_LOG_VOLUME_THRESHOLD: u32 = 64;
+pub const FILE_VC_LOG_VOLUME_LIMIT: u32 = 128;
+pub const FILE_VC_QUOTAS_INCOMPLETE: u32 = 256;
+pub const FILE_VC_QUOTAS_REBUILDING: u32 = 512;
+pub const FILE_VC_VALID_MASK: u32 = 1023;
+pub const FLAGS_END_OF_FILE_INFO_EX_EXTEND_PAGING: u32 = 1;
+pub const FLAGS_END_OF_FILE_INFO_EX_NO_EXTRA_PAGING_EXTEND: u32 = 2;
+pub const FLAGS_END_OF_FILE_INFO_EX_TIME_CONSTRAINED: u32 = 4;
+pub const FLAGS_DELAY_REASONS_LOG_FILE_FULL: u32 = 1;
+pub const FLAGS_DELAY_REASONS_BITMAP_SCANNED: u32 = 2;
+pub const GET_VOLUME_BITMAP_FLAG_MASK_METADATA: u32 = 1;
+pub const FLAG_USN_TRACK_MODIFIED_RANGES_ENABLE: u32 = 1;
+pub const USN_PAGE_SIZE: u32 = 4096;
+pub const USN_REASON_DATA_OVERWRITE: u32 = 1;
+pub const USN_REASON_DATA_EXTEND: u32 = 2;
+pub const USN_REASON_DATA_TRUNCATION: u32 = 4;
+pub const USN_REASON_NAMED_DATA_OVERWRITE: u32 = 16;
+pub const USN_REASON_NAMED_DATA_EXTEND: u32 = 32;
+pub const USN_REASON_NAMED_DATA_TRUNCATION: u32 = 64;
+pub const USN_REASON_FILE_CREATE: u32 = 256;
+pub const USN_REASON_FILE_DELETE: u32 = 512;
+pub const USN_REASON_EA_CHANGE: u32 = 1024;
+pub const USN_REASON_SECURITY_CHANGE: u32 = 2048;
+pub const USN_REASON_RENAME_OLD_NAME: u32 = 4096;
+pub const USN_REASON_RENAME_NEW_NAME: u32 = 8192;
+pub const USN_REASON_INDEXABLE_CHANGE: u32 = 16384;
+pub const USN_REASON_BASIC_INFO_CHANGE: u32 = 32768;
+pub const USN_REASON_HARD_LINK_CHANGE: u32 = 65536;
+pub const USN_REASON_COMPRESSION_CHANGE: u32 = 131072;
+pub const USN_REASON_ENCRYPTION_CHANGE: u32 = 262144;
+pub const USN_REASON_OBJECT_ID_CHANGE: u32 = 524288;
+pub const USN_REASON_REPARSE_POINT_CHANGE: u32 = 1048576;
+pub const USN_REASON_STREAM_CHANGE: u32 = 2097152;
+pub const USN_REASON_TRANSACTED_CHANGE: u32 = 4194304;
+pub const USN_REASON_INTEGRITY_CHANGE: u32 = 8388608;
+pub const USN_REASON_DESIRED_STORAGE_CLASS_CHANGE: u32 = 16777216;
+pub const USN_REASON_CLOSE: u32 = 2147483648;
+pub const USN_DELETE_FLAG_DELETE: u32 = 1;
+pub const USN_DELETE_FLAG_NOTIFY: u32 = 2;
+pub const USN_DELETE_VALID_FLAGS: u32 = 3;
+pub const USN_SOURCE_DATA_MANAGEMENT: u32 = 1;
+pub const USN_SOURCE_AUXILIARY_DATA: u32 = 2;
+pub const USN_SOURCE_REPLICATION_MANAGEMENT: u32 = 4;
+pub const USN_SOURCE_CLIENT_REPLICATION_MANAGEMENT: u32 = 8;
+pub const USN_SOURCE_VALID_FLAGS: u32 = 15;
+pub const MARK_HANDLE_PROTECT_CLUSTERS: u32 = 1;
+pub const MARK_HANDLE_TXF_SYSTEM_LOG: u32 = 4;
+pub const MARK_HANDLE_NOT_TXF_SYSTEM_LOG: u32 = 8;
+pub const MARK_HANDLE_REALTIME: u32 = 32;
+pub const MARK_HANDLE_NOT_REALTIME: u32 = 64;
+pub const MARK_HANDLE_CLOUD_SYNC: u32 = 2048;
+pub const MARK_HANDLE_READ_COPY: u32 = 128;
+pub const MARK_HANDLE_NOT_READ_COPY: u32 = 256;
+pub const MARK_HANDLE_FILTER_METADATA: u32 = 512;
+pub const MARK_HANDLE_RETURN_PURGE_FAILURE: u32 = 1024;
+pub const MARK_HANDLE_DISABLE_FILE_METADATA_OPTIMIZATION: u32 = 4096;
+pub const MARK_HANDLE_ENABLE_USN_SOURCE_ON_PAGING_IO: u32 = 8192;
+pub const MARK_HANDLE_SKIP_COHERENCY_SYNC_DISALLOW_WRITES: u32 = 16384;
+pub const MARK_HANDLE_SUPPRESS_VOLUME_OPEN_FLUSH: u32 = 32768;
+pub const MARK_HANDLE_ENABLE_CPU_CACHE: u32 = 268435456;
+pub const VOLUME_IS_DIRTY: u32 = 1;
+pub const VOLUME_UPGRADE_SCHEDULED: u32 = 2;
+pub const VOLUME_SESSION_OPEN: u32 = 4;
+pub const FILE_PREFETCH_TYPE_FOR_CREATE: u32 = 1;
+pub const FILE_PREFETCH_TYPE_FOR_DIRENUM: u32 = 2;
+pub const FILE_PREFETCH_TYPE_FOR_CREATE_EX: u32 = 3;
+pub const FILE_PREFETCH_TYPE_FOR_DIRENUM_EX: u32 = 4;
+pub const FILE_PREFETCH_TYPE_MAX: u32 = 4;
+pub const FILESYSTEM_STATISTICS_TYPE_NTFS: u32 = 1;
+pub const FILESYSTEM_STATISTICS_TYPE_FAT: u32 = 2;
+pub const FILESYSTEM_STATISTICS_TYPE_EXFAT: u32 = 3;
+pub const FILESYSTEM_STATISTICS_TYPE_REFS: u32 = 4;
+pub const FILE_ZERO_DATA_INFORMATION_FLAG_PRESERVE_CACHED_DATA: u32 = 1;
+pub const FILE_SET_ENCRYPTION: u32 = 1;
+pub const FILE_CLEAR_ENCRYPTION: u32 = 2;
+pub const STREAM_SET_ENCRYPTION: u32 = 3;
+pub const STREAM_CLEAR_ENCRYPTION: u32 = 4;
+pub const MAXIMUM_ENCRYPTION_VALUE: u32 = 4;
+pub const ENCRYPTION_FORMAT_DEFAULT: u32 = 1;
+pub const ENCRYPTED_DATA_INFO_SPARSE_FILE: u32 = 1;
+pub const COPYFILE_SIS_LINK: u32 = 1;
+pub const COPYFILE_SIS_REPLACE: u32 = 2;
+pub const COPYFILE_SIS_FLAGS: u32 = 3;
+pub const SET_REPAIR_ENABLED: u32 = 1;
+pub const SET_REPAIR_WARN_ABOUT_DATA_LOSS: u32 = 8;
+pub const SET_REPAIR_DISABLED_AND_BUGCHECK_ON_CORRUPT: u32 = 16;
+pub const SET_REPAIR_VALID_MASK: u32 = 25;
+pub const FILE_INITIATE_REPAIR_HINT1_FILE_RECORD_NOT_IN_USE: u32 = 1;
+pub const FILE_INITIATE_REPAIR_HINT1_FILE_RECORD_REUSED: u32 = 2;
+pub const FILE_INITIATE_REPAIR_HINT1_FILE_RECORD_NOT_EXIST: u32 = 4;
+pub const FILE_INITIATE_REPAIR_HINT1_FILE_RECORD_NOT_BASE_RECORD: u32 = 8;
+pub const FILE_INITIATE_REPAIR_HINT1_SYSTEM_FILE: u32 = 16;
+pub const FILE_INITIATE_REPAIR_HINT1_NOT_IMPLEMENTED: u32 = 32;
+pub const FILE_INITIATE_REPAIR_HINT1_UNABLE_TO_REPAIR: u32 = 64;
+pub const FILE_INITIATE_REPAIR_HINT1_REPAIR_DISABLED: u32 = 128;
+pub const FILE_INITIATE_REPAIR_HINT1_RECURSIVELY_CORRUPTED: u32 = 256;
+pub const FILE_INITIATE_REPAIR_HINT1_ORPHAN_GENERATED: u32 = 512;
+pub const FILE_INITIATE_REPAIR_HINT1_REPAIRED: u32 = 1024;
+pub const FILE_INITIATE_REPAIR_HINT1_NOTHING_WRONG: u32 = 2048;
+pub const FILE_INITIATE_REPAIR_HINT1_ATTRIBUTE_NOT_FOUND: u32 = 4096;
+pub const FILE_INITIATE_REPAIR_HINT1_POTENTIAL_CROSSLINK: u32 = 8192;
+pub const FILE_INITIATE_REPAIR_HINT1_STALE_INFORMATION: u32 = 16384;
+pub const FILE_INITIATE_REPAIR_HINT1_CLUSTERS_ALREADY_IN_USE: u32 = 32768;
+pub const FILE_INITIATE_REPAIR_HINT1_LCN_NOT_EXIST: u32 = 65536;
+pub const FILE_INITIATE_REPAIR_HINT1_INVALID_RUN_LENGTH: u32 = 131072;
+pub const FILE_INITIATE_REPAIR_HINT1_FILE_RECORD_NOT_ORPHAN: u32 = 262144;
+pub const FILE_INITIATE_REPAIR_HINT1_FILE_RECORD_IS_BASE_RECORD: u32 = 524288;
+pub const FILE_INITIATE_REPAIR_HINT1_INVALID_ARRAY_LENGTH_COUNT: u32 = 1048576;
+pub const FILE_INITIATE_REPAIR_HINT1_SID_VALID: u32 = 2097152;
+pub const FILE_INITIATE_REPAIR_HINT1_SID_MISMATCH: u32 = 4194304;
+pub const FILE_INITIATE_REPAIR_HINT1_INVALID_PARENT: u32 = 8388608;
+pub const FILE_INITIATE_REPAIR_HINT1_PARENT_FILE_RECORD_NOT_IN_USE: u32 = 16777216;
+pub const FILE_INITIATE_REPAIR_HINT1_PARENT_FILE_RECORD_REUSED: u32 = 33554432;
+pub const FILE_INITIATE_REPAIR_HINT1_PARENT_FILE_RECORD_NOT_EXIST: u32 = 67108864;
+pub const FILE_INITIATE_REPAIR_HINT1_PARENT_FILE_RECORD_NOT_BASE_RECORD: u32 = 134217728;
+pub const FILE_INITIATE_REPAIR_HINT1_PARENT_FILE_RECORD_NOT_INDEX: u32 = 268435456;
+pub const FILE_INITIATE_REPAIR_HINT1_VALID_INDEX_ENTRY: u32 = 536870912;
+pub const FILE_INITIATE_REPAIR_HINT1_OUT_OF_GENERIC_NAMES: u32 = 1073741824;
+pub const FILE_INITIATE_REPAIR_HINT1_OUT_OF_RESOURCE: u32 = 2147483648;
+pub const FILE_INITIATE_REPAIR_HINT1_INVALID_LCN: u64 = 4294967296;
+pub const FILE_INITIATE_REPAIR_HINT1_INVALID_VCN: u64 = 8589934592;
+pub const FILE_INITIATE_REPAIR_HINT1_NAME_CONFLICT: u64 = 17179869184;
+pub const FILE_INITIATE_REPAIR_HINT1_ORPHAN: u64 = 34359738368;
+pub const FILE_INITIATE_REPAIR_HINT1_ATTRIBUTE_TOO_SMALL: u64 = 68719476736;
+pub const FILE_INITIATE_REPAIR_HINT1_ATTRIBUTE_NON_RESIDENT: u64 = 137438953472;
+pub const FILE_INITIATE_REPAIR_HINT1_DENY_DEFRAG: u64 = 274877906944;
+pub const FILE_INITIATE_REPAIR_HINT1_PREVIOUS_PARENT_STILL_VALID: u64 = 549755813888;
+pub const FILE_INITIATE_REPAIR_HINT1_INDEX_ENTRY_MISMATCH: u64 = 1099511627776;
+pub const FILE_INITIATE_REPAIR_HINT1_INVALID_ORPHAN_RECOVERY_NAME: u64 = 2199023255552;
+pub const FILE_INITIATE_REPAIR_HINT1_MULTIPLE_FILE_NAME_ATTRIBUTES: u64 = 4398046511104;
+pub const TXFS_RM_FLAG_LOGGING_MODE: u32 = 1;
+pub const TXFS_RM_FLAG_RENAME_RM: u32 = 2;
+pub const TXFS_RM_FLAG_LOG_CONTAINER_COUNT_MAX: u32 = 4;
+pub const TXFS_RM_FLAG_LOG_CONTAINER_COUNT_MIN: u32 = 8;
+pub const TXFS_RM_FLAG_LOG_GROWTH_INCREMENT_NUM_CONTAINERS: u32 = 16;
+pub const TXFS_RM_FLAG_LOG_GROWTH_INCREMENT_PERCENT: u32 = 32;
+pub const TXFS_RM_FLAG_LOG_AUTO_SHRINK_PERCENTAGE: u32 = 64;
+pub const TXFS_RM_FLAG_LOG_NO_CONTAINER_COUNT_MAX: u32 = 128;
+pub const TXFS_RM_FLAG_LOG_NO_CONTAINER_COUNT_MIN: u32 = 256;
+pub const TXFS_RM_FLAG_GROW_LOG: u32 = 1024;
+pub const TXFS_RM_FLAG_SHRINK_LOG: u32 = 2048;
+pub const TXFS_RM_FLAG_ENFORCE_MINIMUM_SIZE: u32 = 4096;
+pub const TXFS_RM_FLAG_PRESERVE_CHANGES: u32 = 8192;
+pub const TXFS_RM_FLAG_RESET_RM_AT_NEXT_START: u32 = 16384;
+pub const TXFS_RM_FLAG_DO_NOT_RESET_RM_AT_NEXT_START: u32 = 32768;
+pub const TXFS_RM_FLAG_PREFER_CONSISTENCY: u32 = 65536;
+pub const TXFS_RM_FLAG_PREFER_AVAILABILITY: u32 = 131072;
+pub const TXFS_LOGGING_MODE_SIMPLE: u32 = 1;
+pub const TXFS_LOGGING_MODE_FULL: u32 = 2;
+pub const TXFS_TRANSACTION_STATE_NONE: u32 = 0;
+pub const TXFS_TRANSACTION_STATE_ACTIVE: u32 = 1;
+pub const TXFS_TRANSACTION_STATE_PREPARED: u32 = 2;
+pub const TXFS_TRANSACTION_STATE_NOTACTIVE: u32 = 3;
+pub const TXFS_MODIFY_RM_VALID_FLAGS: u32 = 261631;
+pub const TXFS_RM_STATE_NOT_STARTED: u32 = 0;
+pub const TXFS_RM_STATE_STARTING: u32 = 1;
+pub const TXFS_RM_STATE_ACTIVE: u32 = 2;
+pub const TXFS_RM_STATE_SHUTTING_DOWN: u32 = 3;
+pub const TXFS_QUERY_RM_INFORMATION_VALID_FLAGS: u32 = 246192;
+pub const TXFS_ROLLFORWARD_REDO_FLAG_USE_LAST_REDO_LSN: u32 = 1;
+pub const TXFS_ROLLFORWARD_REDO_FLAG_USE_LAST_VIRTUAL_CLOCK: u32 = 2;
+pub const TXFS_ROLLFORWARD_REDO_VALID_FLAGS: u32 = 3;
+pub const TXFS_START_RM_FLAG_LOG_CONTAINER_COUNT_MAX: u32 = 1;
+pub const TXFS_START_RM_FLAG_LOG_CONTAINER_COUNT_MIN: u32 = 2;
+pub const TXFS_START_RM_FLAG_LOG_CONTAINER_SIZE: u32 = 4;
+pub const TXFS_START_RM_FLAG_LOG_GROWTH_INCREMENT_NUM_CONTAINERS: u32 = 8;
+pub const TXFS_START_RM_FLAG_LOG_GROWTH_INCREMENT_PERCENT: u32 = 16;
+pub const TXFS_START_RM_FLAG_LOG_AUTO_SHRINK_PERCENTAGE: u32 = 32;
+pub const TXFS_START_RM_FLAG_LOG_NO_CONTAINER_COUNT_MAX: u32 = 64;
+pub const TXFS_START_RM_FLAG_LOG_NO_CONTAINER_COUNT_MIN: u32 = 128;
+pub const TXFS_START_RM_FLAG_RECOVER_BEST_EFFORT: u32 = 512;
+pub const TXFS_START_RM_FLAG_LOGGING_MODE: u32 = 1024;
+pub const TXFS_START_RM_FLAG_PRESERVE_CHANGES: u32 = 2048;
+pub const TXFS_START_RM_FLAG_PREFER_CONSISTENCY: u32 = 4096;
+pub const TXFS_START_RM_FLAG_PREFER_AVAILABILITY: u32 = 8192;
+pub const TXFS_START_RM_VALID_FLAGS: u32 = 15999;
+pub const TXFS_LIST_TRANSACTION_LOCKED_FILES_ENTRY_FLAG_CREATED: u32 = 1;
+pub const TXFS_LIST_TRANSACTION_LOCKED_FILES_ENTRY_FLAG_DELETED: u32 = 2;
+pub const TXFS_TRANSACTED_VERSION_NONTRANSACTED: u32 = 4294967294;
+pub const TXFS_TRANSACTED_VERSION_UNCOMMITTED: u32 = 4294967295;
+pub const TXFS_SAVEPOINT_SET: u32 = 1;
+pub const TXFS_SAVEPOINT_ROLLBACK: u32 = 2;
+pub const TXFS_SAVEPOINT_CLEAR: u32 = 4;
+pub const TXFS_SAVEPOINT_CLEAR_ALL: u32 = 16;
+pub const PERSISTENT_VOLUME_STATE_SHORT_NAME_CREATION_DISABLED: u32 = 1;
+pub const PERSISTENT_VOLUME_STATE_VOLUME_SCRUB_DISABLED: u32 = 2;
+pub const PERSISTENT_VOLUME_STATE_GLOBAL_METADATA_NO_SEEK_PENALTY: u32 = 4;
+pub const PERSISTENT_VOLUME_STATE_LOCAL_METADATA_NO_SEEK_PENALTY: u32 = 8;
+pub const PERSISTENT_VOLUME_STATE_NO_HEAT_GATHERING: u32 = 16;
+pub const PERSISTENT_VOLUME_STATE_CONTAINS_BACKING_WIM: u32 = 32;
+pub const PERSISTENT_VOLUME_STATE_BACKED_BY_WIM: u32 = 64;
+pub const PERSISTENT_VOLUME_STATE_NO_WRITE_AUTO_TIERING: u32 = 128;
+pub const PERSISTENT_VOLUME_STATE_TXF_DISABLED: u32 = 256;
+pub const PERSISTENT_VOLUME_STATE_REALLOCATE_ALL_DATA_WRITES: u32 = 512;
+pub const PERSISTENT_VOLUME_STATE_CHKDSK_RAN_ONCE: u32 = 1024;
+pub const PERSISTENT_VOLUME_STATE_MODIFIED_BY_CHKDSK: u32 = 2048;
+pub const PERSISTENT_VOLUME_STATE_DAX_FORMATTED: u32 = 4096;
+pub const OPLOCK_LEVEL_CACHE_READ: u32 = 1;
+pub const OPLOCK_LEVEL_CACHE_HANDLE: u32 = 2;
+pub const OPLOCK_LEVEL_CACHE_WRITE: u32 = 4;
+pub const REQUEST_OPLOCK_INPUT_FLAG_REQUEST: u32 = 1;
+pub const REQUEST_OPLOCK_INPUT_FLAG_ACK: u32 = 2;
+pub const REQUEST_OPLOCK_INPUT_FLAG_COMPLETE_ACK_ON_CLOSE: u32 = 4;
+pub const REQUEST_OPLOCK_CURRENT_VERSION: u32 = 1;
+pub const REQUEST_OPLOCK_OUTPUT_FLAG_ACK_REQUIRED: u32 = 1;
+pub const REQUEST_OPLOCK_OUTPUT_FLAG_MODES_PROVIDED: u32 = 2;
+pub const REQUEST_OPLOCK_OUTPUT_FLAG_WRITABLE_SECTION_PRESENT: u32 = 4;
+pub const QUERY_DEPENDENT_VOLUME_REQUEST_FLAG_HOST_VOLUMES: u32 = 1;
+pub const QUERY_DEPENDENT_VOLUME_REQUEST_FLAG_GUEST_VOLUMES: u32 = 2;
+pub const SD_GLOBAL_CHANGE_TYPE_MACHINE_SID: u32 = 1;
+pub const SD_GLOBAL_CHANGE_TYPE_QUERY_STATS: u32 = 65536;
+pub const SD_GLOBAL_CHANGE_TYPE_ENUM_SDS: u32 = 131072;
+pub const LOOKUP_STREAM_FROM_CLUSTER_ENTRY_FLAG_PAGE_FILE: u32 = 1;
+pub const LOOKUP_STREAM_FROM_CLUSTER_ENTRY_FLAG_DENY_DEFRAG_SET: u32 = 2;
+pub const LOOKUP_STREAM_FROM_CLUSTER_ENTRY_FLAG_FS_SYSTEM_FILE: u32 = 4;
+pub const LOOKUP_STREAM_FROM_CLUSTER_ENTRY_FLAG_TXF_SYSTEM_FILE: u32 = 8;
+pub const LOOKUP_STREAM_FROM_CLUSTER_ENTRY_ATTRIBUTE_MASK: u32 = 4278190080;
+pub const LOOKUP_STREAM_FROM_CLUSTER_ENTRY_ATTRIBUTE_DATA: u32 = 16777216;
+pub const LOOKUP_STREAM_FROM_CLUSTER_ENTRY_ATTRIBUTE_INDEX: u32 = 33554432;
+pub const LOOKUP_STREAM_FROM_CLUSTER_ENTRY_ATTRIBUTE_SYSTEM: u32 = 50331648;
+pub const FILE_TYPE_NOTIFICATION_FLAG_USAGE_BEGIN: u32 = 1;
+pub const FILE_TYPE_NOTIFICATION_FLAG_USAGE_END: u32 = 2;
+pub const CSV_MGMTLOCK_CHECK_VOLUME_REDIRECTED: u32 = 1;
+pub const CSV_INVALID_DEVICE_NUMBER: u32 = 4294967295;
+pub const CSV_QUERY_MDS_PATH_V2_VERSION_1: u32 = 1;
+pub const CSV_QUERY_MDS_PATH_FLAG_STORAGE_ON_THIS_NODE_IS_CONNECTED: u32 = 1;
+pub const CSV_QUERY_MDS_PATH_FLAG_CSV_DIRECT_IO_ENABLED: u32 = 2;
+pub const CSV_QUERY_MDS_PATH_FLAG_SMB_BYPASS_CSV_ENABLED: u32 = 4;
+pub const QUERY_FILE_LAYOUT_RESTART: u32 = 1;
+pub const QUERY_FILE_LAYOUT_INCLUDE_NAMES: u32 = 2;
+pub const QUERY_FILE_LAYOUT_INCLUDE_STREAMS: u32 = 4;
+pub const QUERY_FILE_LAYOUT_INCLUDE_EXTENTS: u32 = 8;
+pub const QUERY_FILE_LAYOUT_INCLUDE_EXTRA_INFO: u32 = 16;
+pub const QUERY_FILE_LAYOUT_INCLUDE_STREAMS_WITH_NO_CLUSTERS_ALLOCATED: u32 = 32;
+pub const QUERY_FILE_LAYOUT_INCLUDE_FULL_PATH_IN_NAMES: u32 = 64;
+pub const QUERY_FILE_LAYOUT_INCLUDE_STREAM_INFORMATION: u32 = 128;
+pub const QUERY_FILE_LAYOUT_INCLUDE_STREAM_INFORMATION_FOR_DSC_ATTRIBUTE: u32 = 256;
+pub const QUERY_FILE_LAYOUT_INCLUDE_STREAM_INFORMATION_FOR_TXF_ATTRIBUTE: u32 = 512;
+pub const QUERY_FILE_LAYOUT_INCLUDE_STREAM_INFORMATION_FOR_EFS_ATTRIBUTE: u32 = 1024;
+pub const QUERY_FILE_LAYOUT_INCLUDE_ONLY_FILES_WITH_SPECIFIC_ATTRIBUTES: u32 = 2048;
+pub const QUERY_FILE_LAYOUT_INCLUDE_FILES_WITH_DSC_ATTRIBUTE: u32 = 4096;
+pub const QUERY_FILE_LAYOUT_INCLUDE_STREAM_INFORMATION_FOR_DATA_ATTRIBUTE: u32 = 8192;
+pub const QUERY_FILE_LAYOUT_INCLUDE_STREAM_INFORMATION_FOR_REPARSE_ATTRIBUTE: u32 = 16384;
+pub const QUERY_FILE_LAYOUT_INCLUDE_STREAM_INFORMATION_FOR_EA_ATTRIBUTE: u32 = 32768;
+pub const QUERY_FILE_LAYOUT_SINGLE_INSTANCED: u32 = 1;
+pub const FILE_LAYOUT_NAME_ENTRY_PRIMARY: u32 = 1;
+pub const FILE_LAYOUT_NAME_ENTRY_DOS: u32 = 2;
+pub const STREAM_LAYOUT_ENTRY_IMMOVABLE: u32 = 1;
+pub const STREAM_LAYOUT_ENTRY_PINNED: u32 = 2;
+pub const STREAM_LAYOUT_ENTRY_RESIDENT: u32 = 4;
+pub const STREAM_LAYOUT_ENTRY_NO_CLUSTERS_ALLOCATED: u32 = 8;
+pub const STREAM_LAYOUT_ENTRY_HAS_INFORMATION: u32 = 16;
+pub const STREAM_EXTENT_ENTRY_AS_RETRIEVAL_POINTERS: u32 = 1;
+pub const STREAM_EXTENT_ENTRY_ALL_EXTENTS: u32 = 2;
+pub const CHECKSUM_TYPE_NONE: u32 = 0;
+pub const CHECKSUM_TYPE_CRC32: u32 = 1;
+pub const CHECKSUM_TYPE_CRC64: u32 = 2;
+pub const CHECKSUM_TYPE_ECC: u32 = 3;
+pub const CHECKSUM_TYPE_SHA256: u32 = 4;
+pub const CHECKSUM_TYPE_FIRST_UNUSED_TYPE: u32 = 5;
+pub const FSCTL_INTEGRITY_FLAG_CHECKSUM_ENFORCEMENT_OFF: u32 = 1;
+pub const OFFLOAD_READ_FLAG_ALL_ZERO_BEYOND_CURRENT_RANGE: u32 = 1;
+pub const SET_PURGE_FAILURE_MODE_ENABLED: u32 = 1;
+pub const SET_PURGE_FAILURE_MODE_DISABLED: u32 = 2;
+pub const FILE_REGION_USAGE_VALID_CACHED_DATA: u32 = 1;
+pub const FILE_REGION_USAGE_VALID_NONCACHED_DATA: u32 = 2;
+pub const FILE_REGION_USAGE_OTHER_PAGE_ALIGNMENT: u32 = 4;
+pub const FILE_REGION_USAGE_LARGE_PAGE_ALIGNMENT: u32 = 8;
+pub const FILE_REGION_USAGE_HUGE_PAGE_ALIGNMENT: u32 = 16;
+pub const FILE_REGION_USAGE_QUERY_ALIGNMENT: u32 = 24;
+pub const VALID_WRITE_USN_REASON_MASK: u32 = 2147483649;
+pub const FILE_STORAGE_TIER_NAME_LENGTH: u32 = 256;
+pub const FILE_STORAGE_TIER_DESCRIPTION_LENGTH: u32 = 512;
+pub const FILE_STORAGE_TIER_FLAG_NO_SEEK_PENALTY: u32 = 131072;
+pub const FILE_STORAGE_TIER_FLAG_WRITE_BACK_CACHE: u32 = 2097152;
+pub const FILE_STORAGE_TIER_FLAG_READ_CACHE: u32 = 4194304;
+pub const FILE_STORAGE_TIER_FLAG_PARITY: u32 = 8388608;
+pub const FILE_STORAGE_TIER_FLAG_SMR: u32 = 16777216;
+pub const QUERY_STORAGE_CLASSES_FLAGS_MEASURE_WRITE: u32 = 2147483648;
+pub const QUERY_STORAGE_CLASSES_FLAGS_MEASURE_READ: u32 = 1073741824;
+pub const QUERY_STORAGE_CLASSES_FLAGS_NO_DEFRAG_VOLUME: u32 = 536870912;
+pub const QUERY_FILE_LAYOUT_REPARSE_DATA_INVALID: u32 = 1;
+pub const QUERY_FILE_LAYOUT_REPARSE_TAG_INVALID: u32 = 2;
+pub const DUPLICATE_EXTENTS_DATA_EX_SOURCE_ATOMIC: u32 = 1;
+pub const DUPLICATE_EXTENTS_DATA_EX_ASYNC: u32 = 2;
+pub const REFS_SMR_VOLUME_INFO_OUTPUT_VERSION_V0: u32 = 0;
+pub const REFS_SMR_VOLUME_INFO_OUTPUT_VERSION_V1: u32 = 1;
+pub const REFS_SMR_VOLUME_GC_PARAMETERS_VERSION_V1: u32 = 1;
+pub const STREAMS_INVALID_ID: u32 = 0;
+pub const STREAMS_MAX_ID: u32 = 65535;
+pub const STREAMS_ASSOCIATE_ID_CLEAR: u32 = 1;
+pub const STREAMS_ASSOCIATE_ID_SET: u32 = 2;
+pub const DAX_ALLOC_ALIGNMENT_FLAG_MANDATORY: u32 = 1;
+pub const DAX_ALLOC_ALIGNMENT_FLAG_FALLBACK_SPECIFIED: u32 = 2;
+pub const WOF_CURRENT_VERSION: u32 = 1;
+pub const WOF_PROVIDER_WIM: u32 = 1;
+pub const WOF_PROVIDER_FILE: u32 = 2;
+pub const WOF_PROVIDER_CLOUD: u32 = 3;
+pub const WIM_PROVIDER_HASH_SIZE: u32 = 20;
+pub const WIM_PROVIDER_CURRENT_VERSION: u32 = 1;
+pub const WIM_PROVIDER_EXTERNAL_FLAG_NOT_ACTIVE: u32 = 1;
+pub const WIM_PROVIDER_EXTERNAL_FLAG_SUSPENDED: u32 = 2;
+pub const WIM_BOOT_OS_WIM: u32 = 1;
+pub const WIM_BOOT_NOT_OS_WIM: u32 = 0;
+pub const FILE_PROVIDER_CURRENT_VERSION: u32 = 1;
+pub const FILE_PROVIDER_SINGLE_FILE: u32 = 1;
+pub const FILE_PROVIDER_COMPRESSION_XPRESS4K: u32 = 0;
+pub const FILE_PROVIDER_COMPRESSION_LZX: u32 = 1;
+pub const FILE_PROVIDER_COMPRESSION_XPRESS8K: u32 = 2;
+pub const FILE_PROVIDER_COMPRESSION_XPRESS16K: u32 = 3;
+pub const FILE_PROVIDER_COMPRESSION_MAXIMUM: u32 = 4;
+pub const FILE_PROVIDER_FLAG_COMPRESS_ON_WRITE: u32 = 1;
+pub const CONTAINER_VOLUME_STATE_HOSTING_CONTAINER: u32 = 1;
+pub const CONTAINER_ROOT_INFO_FLAG_SCRATCH_ROOT: u32 = 1;
+pub const CONTAINER_ROOT_INFO_FLAG_LAYER_ROOT: u32 = 2;
+pub const CONTAINER_ROOT_INFO_FLAG_VIRTUALIZATION_ROOT: u32 = 4;
+pub const CONTAINER_ROOT_INFO_FLAG_VIRTUALIZATION_TARGET_ROOT: u32 = 8;
+pub const CONTAINER_ROOT_INFO_FLAG_VIRTUALIZATION_EXCEPTION_ROOT: u32 = 16;
+pub const CONTAINER_ROOT_INFO_FLAG_BIND_ROOT: u32 = 32;
+pub const CONTAINER_ROOT_INFO_FLAG_BIND_TARGET_ROOT: u32 = 64;
+pub const CONTAINER_ROOT_INFO_FLAG_BIND_EXCEPTION_ROOT: u32 = 128;
+pub const CONTAINER_ROOT_INFO_FLAG_BIND_DO_NOT_MAP_NAME: u32 = 256;
+pub const CONTAINER_ROOT_INFO_FLAG_UNION_LAYER_ROOT: u32 = 512;
+pub const CONTAINER_ROOT_INFO_VALID_FLAGS: u32 = 1023;
+pub const PROJFS_PROTOCOL_VERSION: u32 = 3;
+pub const SYMLINK_FLAG_RELATIVE: u32 = 1;
+pub const SYMLINK_DIRECTORY: u32 = 2147483648;
+pub const SYMLINK_FILE: u32 = 1073741824;
+pub const SYMLINK_RESERVED_MASK: u32 = 4026531840;
+pub const MAXIMUM_REPARSE_DATA_BUFFER_SIZE: u32 = 16384;
+pub const IO_REPARSE_TAG_RESERVED_ZERO: u32 = 0;
+pub const IO_REPARSE_TAG_RESERVED_ONE: u32 = 1;
+pub const IO_REPARSE_TAG_RESERVED_TWO: u32 = 2;
+pub const IO_REPARSE_TAG_RESERVED_RANGE: u32 = 2;
+pub const IO_REPARSE_TAG_VALID_VALUES: u32 = 4026597375;
+pub const IO_REPARSE_TAG_RESERVED_INVALID: u32 = 3221258240;
+pub const IO_REPARSE_TAG_MOUNT_POINT: u32 = 2684354563;
+pub const IO_REPARSE_TAG_HSM: u32 = 3221225476;
+pub const IO_REPARSE_TAG_DRIVE_EXTENDER: u32 = 2147483653;
+pub const IO_REPARSE_TAG_HSM2: u32 = 2147483654;
+pub const IO_REPARSE_TAG_SIS: u32 = 2147483655;
+pub const IO_REPARSE_TAG_WIM: u32 = 2147483656;
+pub const IO_REPARSE_TAG_CSV: u32 = 2147483657;
+pub const IO_REPARSE_TAG_DFS: u32 = 2147483658;
+pub const IO_REPARSE_TAG_FILTER_MANAGER: u32 = 2147483659;
+pub const IO_REPARSE_TAG_SYMLINK: u32 = 2684354572;
+pub const IO_REPARSE_TAG_IIS_CACHE: u32 = 2684354576;
+pub const IO_REPARSE_TAG_DFSR: u32 = 2147483666;
+pub const IO_REPARSE_TAG_DEDUP: u32 = 2147483667;
+pub const IO_REPARSE_TAG_APPXSTRM: u32 = 3221225492;
+pub const IO_REPARSE_TAG_NFS: u32 = 2147483668;
+pub const IO_REPARSE_TAG_FILE_PLACEHOLDER: u32 = 2147483669;
+pub const IO_REPARSE_TAG_DFM: u32 = 2147483670;
+pub const IO_REPARSE_TAG_WOF: u32 = 2147483671;
+pub const IO_REPARSE_TAG_WCI: u32 = 2147483672;
+pub const IO_REPARSE_TAG_WCI_1: u32 = 2415923224;
+pub const IO_REPARSE_TAG_GLOBAL_REPARSE: u32 = 2684354585;
+pub const IO_REPARSE_TAG_CLOUD: u32 = 2415919130;
+pub const IO_REPARSE_TAG_CLOUD_1: u32 = 2415923226;
+pub const IO_REPARSE_TAG_CLOUD_2: u32 = 2415927322;
+pub const IO_REPARSE_TAG_CLOUD_3: u32 = 2415931418;
+pub const IO_REPARSE_TAG_CLOUD_4: u32 = 2415935514;
+pub const IO_REPARSE_TAG_CLOUD_5: u32 = 2415939610;
+pub const IO_REPARSE_TAG_CLOUD_6: u32 = 2415943706;
+pub const IO_REPARSE_TAG_CLOUD_7: u32 = 2415947802;
+pub const IO_REPARSE_TAG_CLOUD_8: u32 = 2415951898;
+pub const IO_REPARSE_TAG_CLOUD_9: u32 = 2415955994;
+pub const IO_REPARSE_TAG_CLOUD_A: u32 = 2415960090;
+pub const IO_REPARSE_TAG_CLOUD_B: u32 = 2415964186;
+pub const IO_REPARSE_TAG_CLOUD_C: u32 = 2415968282;
+pub const IO_REPARSE_TAG_CLOUD_D: u32 = 2415972378;
+pub const IO_REPARSE_TAG_CLOUD_E: u32 = 2415976474;
+pub const IO_REPARSE_TAG_CLOUD_F: u32 = 2415980570;
+pub const IO_REPARSE_TAG_CLOUD_MASK: u32 = 61440;
+pub const IO_REPARSE_TAG_APPEXECLINK: u32 = 2147483675;
+pub const IO_REPARSE_TAG_PROJFS: u32 = 2415919132;
+pub const IO_REPARSE_TAG_LX_SYMLINK: u32 = 2684354589;
+pub const IO_REPARSE_TAG_STORAGE_SYNC: u32 = 2147483678;
+pub const IO_REPARSE_TAG_WCI_TOMBSTONE: u32 = 2684354591;
+pub const IO_REPARSE_TAG_UNHANDLED: u32 = 2147483680;
+pub const IO_REPARSE_TAG_ONEDRIVE: u32 = 2147483681;
+pub const IO_REPARSE_TAG_PROJFS_TOMBSTONE: u32 = 2684354594;
+pub const IO_REPARSE_TAG_AF_UNIX: u32 = 2147483683;
+pub const IO_REPARSE_TAG_LX_FIFO: u32 = 2147483684;
+pub const IO_REPARSE_TAG_LX_CHR: u32 = 2147483685;
+pub const IO_REPARSE_TAG_LX_BLK: u32 = 2147483686;
+pub const IO_REPARSE_TAG_WCI_LINK: u32 = 2684354599;
+pub const IO_REPARSE_TAG_WCI_LINK_1: u32 = 2684358695;
+pub const IO_REPARSE_TAG_DATALESS_CIM: u32 = 2684354600;
+pub const IO_REPARSE_TAG_IFSTEST_CONGRUENT: u32 = 9;
+pub const IO_REPARSE_TAG_MOONWALK_HSM: u32 = 10;
+pub const IO_REPARSE_TAG_TSINGHUA_UNIVERSITY_RESEARCH: u32 = 11;
+pub const IO_REPARSE_TAG_ARKIVIO: u32 = 12;
+pub const IO_REPARSE_TAG_SOLUTIONSOFT: u32 = 536870925;
+pub const IO_REPARSE_TAG_COMMVAULT: u32 = 14;
+pub const IO_REPARSE_TAG_OVERTONE: u32 = 15;
+pub const IO_REPARSE_TAG_SYMANTEC_HSM2: u32 = 16;
+pub const IO_REPARSE_TAG_ENIGMA_HSM: u32 = 17;
+pub const IO_REPARSE_TAG_SYMANTEC_HSM: u32 = 18;
+pub const IO_REPARSE_TAG_INTERCOPE_HSM: u32 = 19;
+pub const IO_REPARSE_TAG_KOM_NETWORKS_HSM: u32 = 20;
+pub const IO_REPARSE_TAG_MEMORY_TECH_HSM: u32 = 21;
+pub const IO_REPARSE_TAG_BRIDGEHEAD_HSM: u32 = 22;
+pub const IO_REPARSE_TAG_OSR_SAMPLE: u32 = 536870935;
+pub const IO_REPARSE_TAG_GLOBAL360_HSM: u32 = 24;
+pub const IO_REPARSE_TAG_ALTIRIS_HSM: u32 = 25;
+pub const IO_REPARSE_TAG_HERMES_HSM: u32 = 26;
+pub const IO_REPARSE_TAG_POINTSOFT_HSM: u32 = 27;
+pub const IO_REPARSE_TAG_GRAU_DATASTORAGE_HSM: u32 = 28;
+pub const IO_REPARSE_TAG_COMMVAULT_HSM: u32 = 29;
+pub const IO_REPARSE_TAG_DATASTOR_SIS: u32 = 30;
+pub const IO_REPARSE_TAG_EDSI_HSM: u32 = 31;
+pub const IO_REPARSE_TAG_HP_HSM: u32 = 32;
+pub const IO_REPARSE_TAG_SER_HSM: u32 = 33;
+pub const IO_REPARSE_TAG_DOUBLE_TAKE_HSM: u32 = 34;
+pub const IO_REPARSE_TAG_WISDATA_HSM: u32 = 35;
+pub const IO_REPARSE_TAG_MIMOSA_HSM: u32 = 36;
+pub const IO_REPARSE_TAG_HSAG_HSM: u32 = 37;
+pub const IO_REPARSE_TAG_ADA_HSM: u32 = 38;
+pub const IO_REPARSE_TAG_AUTN_HSM: u32 = 39;
+pub const IO_REPARSE_TAG_NEXSAN_HSM: u32 = 40;
+pub const IO_REPARSE_TAG_DOUBLE_TAKE_SIS: u32 = 41;
+pub const IO_REPARSE_TAG_SONY_HSM: u32 = 42;
+pub const IO_REPARSE_TAG_ELTAN_HSM: u32 = 43;
+pub const IO_REPARSE_TAG_UTIXO_HSM: u32 = 44;
+pub const IO_REPARSE_TAG_QUEST_HSM: u32 = 45;
+pub const IO_REPARSE_TAG_DATAGLOBAL_HSM: u32 = 46;
+pub const IO_REPARSE_TAG_QI_TECH_HSM: u32 = 536870959;
+pub const IO_REPARSE_TAG_DATAFIRST_HSM: u32 = 48;
+pub const IO_REPARSE_TAG_C2CSYSTEMS_HSM: u32 = 49;
+pub const IO_REPARSE_TAG_WATERFORD: u32 = 50;
+pub const IO_REPARSE_TAG_RIVERBED_HSM: u32 = 51;
+pub const IO_REPARSE_TAG_CARINGO_HSM: u32 = 52;
+pub const IO_REPARSE_TAG_MAXISCALE_HSM: u32 = 536870965;
+pub const IO_REPARSE_TAG_CITRIX_PM: u32 = 54;
+pub const IO_REPARSE_TAG_OPENAFS_DFS: u32 = 55;
+pub const IO_REPARSE_TAG_ZLTI_HSM: u32 = 56;
+pub const IO_REPARSE_TAG_EMC_HSM: u32 = 57;
+pub const IO_REPARSE_TAG_VMWARE_PM: u32 = 58;
+pub const IO_REPARSE_TAG_ARCO_BACKUP: u32 = 59;
+pub const IO_REPARSE_TAG_CARROLL_HSM: u32 = 60;
+pub const IO_REPARSE_TAG_COMTRADE_HSM: u32 = 61;
+pub const IO_REPARSE_TAG_EASEVAULT_HSM: u32 = 62;
+pub const IO_REPARSE_TAG_HDS_HSM: u32 = 63;
+pub const IO_REPARSE_TAG_MAGINATICS_RDR: u32 = 64;
+pub const IO_REPARSE_TAG_GOOGLE_HSM: u32 = 65;
+pub const IO_REPARSE_TAG_QUADDRA_HSM: u32 = 66;
+pub const IO_REPARSE_TAG_HP_BACKUP: u32 = 67;
+pub const IO_REPARSE_TAG_DROPBOX_HSM: u32 = 68;
+pub const IO_REPARSE_TAG_ADOBE_HSM: u32 = 69;
+pub const IO_REPARSE_TAG_HP_DATA_PROTECT: u32 = 70;
+pub const IO_REPARSE_TAG_ACTIVISION_HSM: u32 = 71;
+pub const IO_REPARSE_TAG_HDS_HCP_HSM: u32 = 72;
+pub const IO_REPARSE_TAG_AURISTOR_FS: u32 = 73;
+pub const IO_REPARSE_TAG_ITSTATION: u32 = 74;
+pub const IO_REPARSE_TAG_SPHARSOFT: u32 = 75;
+pub const IO_REPARSE_TAG_ALERTBOOT: u32 = 536870988;
+pub const IO_REPARSE_TAG_MTALOS: u32 = 77;
+pub const IO_REPARSE_TAG_CTERA_HSM: u32 = 78;
+pub const IO_REPARSE_TAG_NIPPON_HSM: u32 = 79;
+pub const IO_REPARSE_TAG_REDSTOR_HSM: u32 = 80;
+pub const IO_REPARSE_TAG_NEUSHIELD: u32 = 81;
+pub const IO_REPARSE_TAG_DOR_HSM: u32 = 82;
+pub const IO_REPARSE_TAG_SHX_BACKUP: u32 = 83;
+pub const IO_REPARSE_TAG_NVIDIA_UNIONFS: u32 = 536870996;
+pub const IO_REPARSE_TAG_HUBSTOR_HSM: u32 = 85;
+pub const IO_REPARSE_TAG_IMANAGE_HSM: u32 = 536870998;
+pub const IO_REPARSE_TAG_EASEFILTER_HSM: u32 = 87;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_0: u32 = 96;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_1: u32 = 97;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_2: u32 = 98;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_3: u32 = 99;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_4: u32 = 100;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_5: u32 = 101;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_6: u32 = 102;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_7: u32 = 103;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_8: u32 = 104;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_9: u32 = 105;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_A: u32 = 106;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_B: u32 = 107;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_C: u32 = 108;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_D: u32 = 109;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_E: u32 = 110;
+pub const IO_REPARSE_TAG_ACRONIS_HSM_F: u32 = 111;
+pub const REPARSE_DATA_EX_FLAG_GIVEN_TAG_OR_NONE: u32 = 1;
+pub const SUPPORTED_FS_FEATURES_OFFLOAD_READ: u32 = 1;
+pub const SUPPORTED_FS_FEATURES_OFFLOAD_WRITE: u32 = 2;
+pub const SUPPORTED_FS_FEATURES_QUERY_OPEN: u32 = 4;
+pub const SUPPORTED_FS_FEATURES_BYPASS_IO: u32 = 8;
+pub const SUPPORTED_FS_FEATURES_VALID_MASK: u32 = 15;
+pub const SCRUB_DATA_INPUT_FLAG_RESUME: u32 = 1;
+pub const SCRUB_DATA_INPUT_FLAG_SKIP_IN_SYNC: u32 = 2;
+pub const SCRUB_DATA_INPUT_FLAG_SKIP_NON_INTEGRITY_DATA: u32 = 4;
+pub const SCRUB_DATA_INPUT_FLAG_IGNORE_REDUNDANCY: u32 = 8;
+pub const SCRUB_DATA_INPUT_FLAG_SKIP_DATA: u32 = 16;
+pub const SCRUB_DATA_INPUT_FLAG_SCRUB_BY_OBJECT_ID: u32 = 32;
+pub const SCRUB_DATA_INPUT_FLAG_OPLOCK_NOT_ACQUIRED: u32 = 64;
+pub const SCRUB_DATA_OUTPUT_FLAG_INCOMPLETE: u32 = 1;
+pub const SCRUB_DATA_OUTPUT_FLAG_NON_USER_DATA_RANGE: u32 = 65536;
+pub const SCRUB_DATA_OUTPUT_FLAG_PARITY_EXTENT_DATA_RETURNED: u32 = 131072;
+pub const SCRUB_DATA_OUTPUT_FLAG_RESUME_CONTEXT_LENGTH_SPECIFIED: u32 = 262144;
+pub const SHUFFLE_FILE_FLAG_SKIP_INITIALIZING_NEW_CLUSTERS: u32 = 1;
+pub const FILE_PIPE_READ_DATA: u32 = 0;
+pub const FILE_PIPE_WRITE_SPACE: u32 = 1;
+pub const FILE_PIPE_COMPUTER_NAME_LENGTH: u32 = 15;
+pub const FILE_PIPE_SYMLINK_FLAG_GLOBAL: u32 = 1;
+pub const FILE_PIPE_SYMLINK_FLAG_RELATIVE: u32 = 2;
+pub const FILE_PIPE_SYMLINK_VALID_FLAGS: u32 = 3;
+pub const QUERY_DIRECT_ACCESS_IMAGE_EXTENTS: u32 = 1;
+pub const QUERY_DIRECT_ACCESS_DATA_EXTENTS: u32 = 2;
+pub const IO_QOS_MAX_RESERVATION: u32 = 1000000000;
+pub const SMB_CCF_APP_INSTANCE_EA_NAME: &[u8; 29] = b"ClusteredApplicationInstance\0";
+pub const NETWORK_APP_INSTANCE_CSV_FLAGS_VALID_ONLY_IF_CSV_COORDINATOR: u32 = 1;
+pub const LX_FILE_METADATA_UID_EA_NAME: &[u8; 7] = b"$LXUID\0";
+pub const LX_FILE_METADATA_GID_EA_NAME: &[u8; 7] = b"$LXGID\0";
+pub const LX_FILE_METADATA_MODE_EA_NAME: &[u8; 7] = b"$LXMOD\0";
+pub const LX_FILE_METADATA_DEVICE_ID_EA_NAME: &[u8; 7] = b"$LXDEV\0";
+pub const VALID_COPY_FILE_CHUNK_FLAGS: u32 = 0;
+pub const SYSTEM_PAGE_PRIORITY_BITS: u32 = 3;
+pub const SYSTEM_PAGE_PRIORITY_LEVELS: u32 = 8;
+pub const TOKEN_HAS_TRAVERSE_PRIVILEGE: u32 = 1;
+pub const TOKEN_HAS_BACKUP_PRIVILEGE: u32 = 2;
+pub const TOKEN_HAS_RESTORE_PRIVILEGE: u32 = 4;
+pub const TOKEN_WRITE_RESTRICTED: u32 = 8;
+pub const TOKEN_IS_RESTRICTED: u32 = 16;
+pub const TOKEN_SESSION_NOT_REFERENCED: u32 = 32;
+pub const TOKEN_SANDBOX_INERT: u32 = 64;
+pub const TOKEN_HAS_IMPERSONATE_PRIVILEGE: u32 = 128;
+pub const SE_BACKUP_PRIVILEGES_CHECKED: u32 = 256;
+pub const TOKEN_VIRTUALIZE_ALLOWED: u32 = 512;
+pub const TOKEN_VIRTUALIZE_ENABLED: u32 = 1024;
+pub const TOKEN_IS_FILTERED: u32 = 2048;
+pub const TOKEN_UIACCESS: u32 = 4096;
+pub const TOKEN_NOT_LOW: u32 = 8192;
+pub const TOKEN_LOWBOX: u32 = 16384;
+pub const TOKEN_HAS_OWN_CLAIM_ATTRIBUTES: u32 = 32768;
+pub const TOKEN_PRIVATE_NAMESPACE: u32 = 65536;
+pub const TOKEN_DO_NOT_USE_GLOBAL_ATTRIBS_FOR_QUERY: u32 = 131072;
+pub const SPECIAL_ENCRYPTED_OPEN: u32 = 262144;
+pub const TOKEN_NO_CHILD_PROCESS: u32 = 524288;
+pub const TOKEN_NO_CHILD_PROCESS_UNLESS_SECURE: u32 = 1048576;
+pub const TOKEN_AUDIT_NO_CHILD_PROCESS: u32 = 2097152;
+pub const TOKEN_ENFORCE_REDIRECTION_TRUST: u32 = 4194304;
+pub const TOKEN_AUDIT_REDIRECTION_TRUST: u32 = 8388608;
+pub const TOKEN_LEARNING_MODE_LOGGING: u32 = 16777216;
+pub const TOKEN_PERMISSIVE_LEARNING_MODE: u32 = 50331648;
+pub const TOKEN_INHERIT_SECURITY_FLAGS: u32 = 3670016;
+pub const SECURITY_DESCRIPTOR_DO_NOT_FREE: u32 = 67108864;
+pub const IO_OPEN_PAGING_FILE: u32 = 2;
+pub const IO_OPEN_TARGET_DIRECTORY: u32 = 4;
+pub const IO_STOP_ON_SYMLINK: u32 = 8;
+pub const IO_MM_PAGING_FILE: u32 = 16;
+pub const IO_IGNORE_READONLY_ATTRIBUTE: u32 = 64;
+pub const FS_FILTER_SECTION_SYNC_IN_FLAG_DONT_UPDATE_LAST_ACCESS: u32 = 1;
+pub const FS_FILTER_SECTION_SYNC_IN_FLAG_DONT_UPDATE_LAST_WRITE: u32 = 2;
+pub const FS_FILTER_SECTION_SYNC_SUPPORTS_ASYNC_PARALLEL_IO: u32 = 1;
+pub const FS_FILTER_SECTION_SYNC_SUPPORTS_DIRECT_MAP_DATA: u32 = 2;
+pub const FS_FILTER_SECTION_SYNC_SUPPORTS_DIRECT_MAP_IMAGE: u32 = 4;
+pub const FS_FILTER_SECTION_SYNC_IMAGE_EXTENTS_ARE_NOT_RVA: u32 = 8;
+pub const DO_SUPPORTS_PERSISTENT_ACLS: u32 = 131072;
+pub const IO_FILE_OBJECT_NON_PAGED_POOL_CHARGE: u32 = 64;
+pub const IO_FILE_OBJECT_PAGED_POOL_CHARGE: u32 = 1024;
+pub const IO_CREATE_STREAM_FILE_RAISE_ON_ERROR: u32 = 1;
+pub const IO_CREATE_STREAM_FILE_LITE: u32 = 2;
+pub const EFS_TRACKED_OFFSET_HEADER_FLAG: u32 = 1;
+pub const SPACES_TRACKED_OFFSET_HEADER_FLAG: u32 = 2;
+pub const MM_FORCE_CLOSED_DATA: u32 = 1;
+pub const MM_FORCE_CLOSED_IMAGE: u32 = 2;
+pub const MM_FORCE_CLOSED_LATER_OK: u32 = 4;
+pub const MM_IS_FILE_SECTION_ACTIVE_IMAGE: u32 = 1;
+pub const MM_IS_FILE_SECTION_ACTIVE_DATA: u32 = 2;
+pub const MM_IS_FILE_SECTION_ACTIVE_USER: u32 = 4;
+pub const MM_PREFETCH_FLAGS_MASK: u32 = 127;
+pub const DEVICE_RESET_RESERVED_0: u32 = 1;
+pub const DEVICE_RESET_RESERVED_1: u32 = 2;
+pub const DEVICE_RESET_KEEP_STACK: u32 = 4;
+pub const FSRTL_FCB_HEADER_V0: u32 = 0;
+pub const FSRTL_FCB_HEADER_V1: u32 = 1;
+pub const FSRTL_FCB_HEADER_V2: u32 = 2;
+pub const FSRTL_FCB_HEADER_V3: u32 = 3;
+pub const FSRTL_FCB_HEADER_V4: u32 = 4;
+pub const FSRTL_FLAG_FILE_MODIFIED: u32 = 1;
+pub const FSRTL_FLAG_FILE_LENGTH_CHANGED: u32 = 2;
+pub const FSRTL_FLAG_LIMIT_MODIFIED_PAGES: u32 = 4;
+pub const FSRTL_FLAG_ACQUIRE_MAIN_RSRC_EX: u32 = 8;
+pub const FSRTL_FLAG_ACQUIRE_MAIN_RSRC_SH: u32 = 16;
+pub const FSRTL_FLAG_USER_MAPPED_FILE: u32 = 32;
+pub const FSRTL_FLAG_ADVANCED_HEADER: u32 = 64;
+pub const FSRTL_FLAG_EOF_ADVANCE_ACTIVE: u32 = 128;
+pub const FSRTL_FLAG2_DO_MODIFIED_WRITE: u32 = 1;
+pub const FSRTL_FLAG2_SUPPORTS_FILTER_CONTEXTS: u32 = 2;
+pub const FSRTL_FLAG2_PURGE_WHEN_MAPPED: u32 = 4;
+pub const FSRTL_FLAG2_IS_PAGING_FILE: u32 = 8;
+pub const FSRTL_FLAG2_WRITABLE_USER_MAPPED_FILE: u32 = 16;
+pub const FSRTL_FLAG2_BYPASSIO_STREAM_PAUSED: u32 = 32;
+pub const FSRTL_AUXILIARY_FLAG_DEALLOCATE: u32 = 1;
+pub const FSRTL_ADD_TC_CASE_SENSITIVE: u32 = 1;
+pub const FSRTL_ADD_TC_KEY_BY_SHORT_NAME: u32 = 2;
+pub const FSRTL_FIND_TC_CASE_SENSITIVE: u32 = 1;
+pub const FSRTL_FAT_LEGAL: u32 = 1;
+pub const FSRTL_HPFS_LEGAL: u32 = 2;
+pub const FSRTL_NTFS_LEGAL: u32 = 4;
+pub const FSRTL_WILD_CHARACTER: u32 = 8;
+pub const FSRTL_OLE_LEGAL: u32 = 16;
+pub const FSRTL_NTFS_STREAM_LEGAL: u32 = 20;
+pub const MCB_FLAG_RAISE_ON_ALLOCATION_FAILURE: u32 = 1;
+pub const OPLOCK_FLAG_COMPLETE_IF_OPLOCKED: u32 = 1;
+pub const OPLOCK_FLAG_OPLOCK_KEY_CHECK_ONLY: u32 = 2;
+pub const OPLOCK_FLAG_BACK_OUT_ATOMIC_OPLOCK: u32 = 4;
+pub const OPLOCK_FLAG_IGNORE_OPLOCK_KEYS: u32 = 8;
+pub const OPLOCK_FLAG_PARENT_OBJECT: u32 = 16;
+pub const OPLOCK_FLAG_CLOSING_DELETE_ON_CLOSE: u32 = 32;
+pub const OPLOCK_FLAG_REMOVING_FILE_OR_LINK: u32 = 64;
+pub const OPLOCK_FLAG_BREAKING_FOR_SHARING_VIOLATION: u32 = 128;
+pub const OPLOCK_UPPER_FLAG_CHECK_NO_BREAK: u32 = 65536;
+pub const OPLOCK_UPPER_FLAG_NOTIFY_REFRESH_READ: u32 = 131072;
+pub const OPLOCK_FSCTRL_FLAG_ALL_KEYS_MATCH: u32 = 1;
+pub const FSRTL_VOLUME_DISMOUNT: u32 = 1;
+pub const FSRTL_VOLUME_DISMOUNT_FAILED: u32 = 2;
+pub const FSRTL_VOLUME_LOCK: u32 = 3;
+pub const FSRTL_VOLUME_LOCK_FAILED: u32 = 4;
+pub const FSRTL_VOLUME_UNLOCK: u32 = 5;
+pub const FSRTL_VOLUME_MOUNT: u32 = 6;
+pub const FSRTL_VOLUME_NEEDS_CHKDSK: u32 = 7;
+pub const FSRTL_VOLUME_WORM_NEAR_FULL: u32 = 8;
+pub const FSRTL_VOLUME_WEARING_OUT: u32 = 9;
+pub const FSRTL_VOLUME_FORCED_CLOSED: u32 = 10;
+pub const FSRTL_VOLUME_INFO_MAKE_COMPAT: u32 = 11;
+pub const FSRTL_VOLUME_PREPARING_EJECT: u32 = 12;
+pub const FSRTL_VOLUME_CHANGE_SIZE: u32 = 13;
+pub const FSRTL_VOLUME_BACKGROUND_FORMAT: u32 = 14;
+pub const FSRTL_UNC_PROVIDER_FLAGS_MAILSLOTS_SUPPORTED: u32 = 1;
+pub const FSRTL_UNC_PROVIDER_FLAGS_CSC_ENABLED: u32 = 2;
+pub const FSRTL_UNC_PROVIDER_FLAGS_DOMAIN_SVC_AWARE: u32 = 4;
+pub const FSRTL_UNC_PROVIDER_FLAGS_CONTAINER_AWARE: u32 = 8;
+pub const FSRTL_UNC_REGISTRATION_VERSION_0200: u32 = 512;
+pub const FSRTL_UNC_REGISTRATION_VERSION_0201: u32 = 513;
+pub const FSRTL_UNC_REGISTRATION_CURRENT_VERSION: u32 = 513;
+pub const FSRTL_UNC_HARDENING_CAPABILITIES_MUTUAL_AUTH: u32 = 1;
+pub const FSRTL_UNC_HARDENING_CAPABILITIES_INTEGRITY: u32 = 2;
+pub const FSRTL_UNC_HARDENING_CAPABILITIES_PRIVACY: u32 = 4;
+pub const FSRTL_ALLOCATE_ECPLIST_FLAG_CHARGE_QUOTA: u32 = 1;
+pub const FSRTL_ALLOCATE_ECP_FLAG_CHARGE_QUOTA: u32 = 1;
+pub const FSRTL_ALLOCATE_ECP_FLAG_NONPAGED_POOL: u32 = 2;
+pub const FSRTL_ECP_LOOKASIDE_FLAG_NONPAGED_POOL: u32 = 2;
+pub const NETWORK_OPEN_ECP_IN_FLAG_DISABLE_HANDLE_COLLAPSING: u32 = 1;
+pub const NETWORK_OPEN_ECP_IN_FLAG_DISABLE_HANDLE_DURABILITY: u32 = 2;
+pub const NETWORK_OPEN_ECP_IN_FLAG_FORCE_MAX_EOF_HACK: u32 = 1073741824;
+pub const NETWORK_OPEN_ECP_IN_FLAG_FORCE_BUFFERED_SYNCHRONOUS_IO_HACK: u32 = 2147483648;
+pub const NETWORK_OPEN_ECP_IN_FLAG_DISABLE_OPLOCKS: u32 = 4;
+pub const NETWORK_OPEN_ECP_IN_FLAG_REQ_MUTUAL_AUTH: u32 = 8;
+pub const NETWORK_OPEN_ECP_OUT_FLAG_RET_MUTUAL_AUTH: u32 = 8;
+pub const SRV_OPEN_ECP_CONTEXT_VERSION_2: u32 = 2;
+pub const CSV_SET_HANDLE_PROPERTIES_ECP_CONTEXT_FLAGS_VALID_ONLY_IF_CSV_COORDINATOR: u32 = 1;
+pub const OPEN_REPARSE_POINT_TAG_ENCOUNTERED: u32 = 1;
+pub const OPEN_REPARSE_POINT_REPARSE_IF_CHILD_EXISTS: u32 = 2;
+pub const OPEN_REPARSE_POINT_REPARSE_IF_CHILD_NOT_EXISTS: u32 = 4;
+pub const OPEN_REPARSE_POINT_REPARSE_IF_DIRECTORY_FINAL_COMPONENT: u32 = 8;
+pub const OPEN_REPARSE_POINT_REPARSE_IF_NON_DIRECTORY_NON_FINAL_COMPONENT: u32 = 16;
+pub const OPEN_REPARSE_POINT_REPARSE_IF_NON_DIRECTORY_FINAL_COMPONENT: u32 = 32;
+pub const OPEN_REPARSE_POINT_OVERRIDE_CREATE_OPTION: u32 = 64;
+pub const OPEN_REPARSE_POINT_RETURN_REPARSE_DATA_BUFFER: u32 = 128;
+pub const OPEN_REPARSE_POINT_VERSION_EX: u32 = 2147483648;
+pub const OPEN_REPARSE_POINT_REPARSE_IF_NON_FINAL_COMPONENT: u32 = 22;
+pub const OPEN_REPARSE_POINT_REPARSE_IF_DIRECTORY_FINAL_COMPONENT_ALWAYS: u32 = 72;
+pub const OPEN_REPARSE_POINT_REPARSE_IF_NON_DIRECTORY_NON_FINAL_COMPONENT_ALWAYS: u32 = 80;
+pub const OPEN_REPARSE_POINT_REPARSE_IF_NON_DIRECTORY_FINAL_COMPONENT_ALWAYS: u32 = 96;
+pub const OPEN_REPARSE_POINT_REPARSE_IF_FINAL_COMPONENT: u32 = 40;
+pub const OPEN_REPARSE_POINT_REPARSE_IF_FINAL_COMPONENT_ALWAYS: u32 = 104;
+pub const OPEN_REPARSE_POINT_REPARSE_ALWAYS: u32 = 126;
+pub const CREATE_REDIRECTION_FLAGS_SERVICED_FROM_LAYER: u32 = 1;
+pub const CREATE_REDIRECTION_FLAGS_SERVICED_FROM_SCRATCH: u32 = 2;
+pub const CREATE_REDIRECTION_FLAGS_SERVICED_FROM_REGISTERED_LAYER: u32 = 4;
+pub const CREATE_REDIRECTION_FLAGS_SERVICED_FROM_REMOTE_LAYER: u32 = 8;
+pub const CREATE_REDIRECTION_FLAGS_SERVICED_FROM_USER_MODE: u32 = 16;
+pub const WCIFS_REDIRECTION_FLAGS_CREATE_SERVICED_FROM_LAYER: u32 = 1;
+pub const WCIFS_REDIRECTION_FLAGS_CREATE_SERVICED_FROM_SCRATCH: u32 = 2;
+pub const WCIFS_REDIRECTION_FLAGS_CREATE_SERVICED_FROM_REGISTERED_LAYER: u32 = 4;
+pub const WCIFS_REDIRECTION_FLAGS_CREATE_SERVICED_FROM_REMOTE_LAYER: u32 = 8;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_SPARSE_SPECIFIED: u32 = 1;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_REPARSE_POINT_SPECIFIED: u32 = 2;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_EOF_SPECIFIED: u32 = 4;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_VDL_SPECIFIED: u32 = 8;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_TIMESTAMPS_SPECIFIED: u32 = 16;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_FILE_ATTRIBUTES_SPECIFIED: u32 = 32;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_SUPPRESS_FILE_ATTRIBUTE_INHERITANCE: u32 = 64;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_OP_FLAGS_SPECIFIED: u32 = 128;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_OPERATION_MASK: u32 = 255;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_BEST_EFFORT: u32 = 256;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_SUPPRESS_PARENT_TIMESTAMPS_UPDATE: u32 = 512;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_SUPPRESS_DIR_CHANGE_NOTIFY: u32 = 1024;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_MARK_USN_SOURCE_INFO: u32 = 2048;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_WRITE_USN_CLOSE_RECORD: u32 = 4096;
+pub const ATOMIC_CREATE_ECP_IN_FLAG_GEN_FLAGS_SPECIFIED: u32 = 32768;
+pub const ATOMIC_CREATE_ECP_IN_OP_FLAG_CASE_SENSITIVE_FLAGS_SPECIFIED: u32 = 1;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_SPARSE_SET: u32 = 1;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_REPARSE_POINT_SET: u32 = 2;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_EOF_SET: u32 = 4;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_VDL_SET: u32 = 8;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_TIMESTAMPS_SET: u32 = 16;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_FILE_ATTRIBUTES_SET: u32 = 32;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_FILE_ATTRIBUTE_INHERITANCE_SUPPRESSED: u32 = 64;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_OP_FLAGS_HONORED: u32 = 128;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_OPERATION_MASK: u32 = 255;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_TIMESTAMPS_RETURNED: u32 = 256;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_FILE_ATTRIBUTES_RETURNED: u32 = 512;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_USN_SOURCE_INFO_MARKED: u32 = 1024;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_USN_CLOSE_RECORD_WRITTEN: u32 = 2048;
+pub const ATOMIC_CREATE_ECP_OUT_FLAG_USN_RETURNED: u32 = 4096;
+pub const ATOMIC_CREATE_ECP_OUT_OP_FLAG_CASE_SENSITIVE_FLAGS_SET: u32 = 1;
+pub const ECP_OPEN_PARAMETERS_FLAG_OPEN_FOR_READ: u32 = 1;
+pub const ECP_OPEN_PARAMETERS_FLAG_OPEN_FOR_WRITE: u32 = 2;
+pub const ECP_OPEN_PARAMETERS_FLAG_OPEN_FOR_DELETE: u32 = 4;
+pub const ECP_OPEN_PARAMETERS_FLAG_IGNORE_DIR_CASE_SENSITIVITY: u32 = 8;
+pub const ECP_OPEN_PARAMETERS_FLAG_FAIL_ON_CASE_SENSITIVE_DIR: u32 = 16;
+pub const OPLOCK_FS_FILTER_FLAGS_MASK: u32 = 8;
+pub const QoCFileStatInformation: u32 = 1;
+pub const QoCFileLxInformation: u32 = 2;
+pub const QoCFileEaInformation: u32 = 4;
+pub const FSRTL_CC_FLUSH_ERROR_FLAG_NO_HARD_ERROR: u32 = 1;
+pub const FSRTL_CC_FLUSH_ERROR_FLAG_NO_LOG_ENTRY: u32 = 2;
+pub const FSRTL_VIRTDISK_FULLY_ALLOCATED: u32 = 1;
+pub const FSRTL_VIRTDISK_NO_DRIVE_LETTER: u32 = 2;
+pub const FSRTL_DRIVER_BACKING_FLAG_USE_PAGE_FILE: u32 = 1;
+pub const VACB_MAPPING_GRANULARITY: u32 = 262144;
+pub const VACB_OFFSET_SHIFT: u32 = 18;
+pub const CACHE_MANAGER_CALLBACKS_EX_V1: u32 = 1;
+pub const CC_ACQUIRE_DONT_WAIT: u32 = 1;
+pub const CC_ACQUIRE_SUPPORTS_ASYNC_LAZYWRITE: u32 = 1;
+pub const UNINITIALIZE_CACHE_MAPS: u32 = 1;
+pub const DO_NOT_RETRY_PURGE: u32 = 2;
+pub const DO_NOT_PURGE_DIRTY_PAGES: u32 = 4;
+pub const PURGE_WITH_ACTIVE_VIEWS: u32 = 8;
+pub const CC_FLUSH_AND_PURGE_NO_PURGE: u32 = 1;
+pub const CC_FLUSH_AND_PURGE_GATHER_DIRTY_BITS: u32 = 2;
+pub const CC_FLUSH_AND_PURGE_WRITEABLE_VIEWS_NOTSEEN: u32 = 4;
+pub const PIN_WAIT: u32 = 1;
+pub const PIN_EXCLUSIVE: u32 = 2;
+pub const PIN_NO_READ: u32 = 4;
+pub const PIN_IF_BCB: u32 = 8;
+pub const PIN_CALLER_TRACKS_DIRTY_DATA: u32 = 32;
+pub const PIN_HIGH_PRIORITY: u32 = 64;
+pub const PIN_VERIFY_REQUIRED: u32 = 128;
+pub const MAP_WAIT: u32 = 1;
+pub const MAP_NO_READ: u32 = 16;
+pub const MAP_HIGH_PRIORITY: u32 = 64;
+pub const MAP_DISABLE_PAGEFAULT_CLUSTERING: u32 = 256;
+pub const CACHE_USE_DIRECT_ACCESS_MAPPING: u32 = 1;
+pub const CACHE_VALID_FLAGS: u32 = 1;
+pub const CC_AGGRESSIVE_UNMAP_BEHIND: u32 = 1;
+pub const CC_DISABLE_READ_AHEAD: u32 = 2;
+pub const CC_DISABLE_WRITE_BEHIND: u32 = 4;
+pub const CC_DISABLE_DIRTY_PAGE_TRACKING: u32 = 8;
+pub const CC_ENABLE_DISK_IO_ACCOUNTING: u32 = 16;
+pub const CC_DISABLE_UNMAP_BEHIND: u32 = 32;
+pub const CC_ENABLE_CPU_CACHE: u32 = 268435456;
+pub const ISSP_LEVEL: u32 = 32;
+pub const ISSP_MODE: u32 = 0;
+pub const SECPKG_FLAG_INTEGRITY: u32 = 1;
+pub const SECPKG_FLAG_PRIVACY: u32 = 2;
+pub const SECPKG_FLAG_TOKEN_ONLY: u32 = 4;
+pub const SECPKG_FLAG_DATAGRAM: u32 = 8;
+pub const SECPKG_FLAG_CONNECTION: u32 = 16;
+pub const SECPKG_FLAG_MULTI_REQUIRED: u32 = 32;
+pub const SECPKG_FLAG_CLIENT_ONLY: u32 = 64;
+pub const SECPKG_FLAG_EXTENDED_ERROR: u32 = 128;
+pub const SECPKG_FLAG_IMPERSONATION: u32 = 256;
+pub const SECPKG_FLAG_ACCEPT_WIN32_NAME: u32 = 512;
+pub const SECPKG_FLAG_STREAM: u32 = 1024;
+pub const SECPKG_FLAG_NEGOTIABLE: u32 = 2048;
+pub const SECPKG_FLAG_GSS_COMPATIBLE: u32 = 4096;
+pub const SECPKG_FLAG_LOGON: u32 = 8192;
+pub const SECPKG_FLAG_ASCII_BUFFERS: u32 = 16384;
+pub const SECPKG_FLAG_FRAGMENT: u32 = 32768;
+pub const SECPKG_FLAG_MUTUAL_AUTH: u32 = 65536;
+pub const SECPKG_FLAG_DELEGATION: u32 = 131072;
+pub const SECPKG_FLAG_READONLY_WITH_CHECKSUM: u32 = 262144;
+pub const SECPKG_FLAG_RESTRICTED_TOKENS: u32 = 524288;
+pub const SECPKG_FLAG_NEGO_EXTENDER: u32 = 1048576;
+pub const SECPKG_FLAG_NEGOTIABLE2: u32 = 2097152;
+pub const SECPKG_FLAG_APPCONTAINER_PASSTHROUGH: u32 = 4194304;
+pub const SECPKG_FLAG_APPCONTAINER_CHECKS: u32 = 8388608;
+pub const SECPKG_FLAG_CREDENTIAL_ISOLATION_ENABLED: u32 = 16777216;
+pub const SECPKG_FLAG_APPLY_LOOPBACK: u32 = 33554432;
+pub const SECPKG_ID_NONE: u32 = 65535;
+pub const SECPKG_CALLFLAGS_APPCONTAINER: u32 = 1;
+pub const SECPKG_CALLFLAGS_APPCONTAINER_AUTHCAPABLE: u32 = 2;
+pub const SECPKG_CALLFLAGS_FORCE_SUPPLIED: u32 = 4;
+pub const SECPKG_CALLFLAGS_APPCONTAINER_UPNCAPABLE: u32 = 8;
+pub const SECBUFFER_VERSION: u32 = 0;
+pub const SECBUFFER_EMPTY: u32 = 0;
+pub const SECBUFFER_DATA: u32 = 1;
+pub const SECBUFFER_TOKEN: u32 = 2;
+pub const SECBUFFER_PKG_PARAMS: u32 = 3;
+pub const SECBUFFER_MISSING: u32 = 4;
+pub const SECBUFFER_EXTRA: u32 = 5;
+pub const SECBUFFER_STREAM_TRAILER: u32 = 6;
+pub const SECBUFFER_STREAM_HEADER: u32 = 7;
+pub const SECBUFFER_NEGOTIATION_INFO: u32 = 8;
+pub const SECBUFFER_PADDING: u32 = 9;
+pub const SECBUFFER_STREAM: u32 = 10;
+pub const SECBUFFER_MECHLIST: u32 = 11;
+pub const SECBUFFER_MECHLIST_SIGNATURE: u32 = 12;
+pub const SECBUFFER_TARGET: u32 = 13;
+pub const SECBUFFER_CHANNEL_BINDINGS: u32 = 14;
+pub const SECBUFFER_CHANGE_PASS_RESPONSE: u32 = 15;
+pub const SECBUFFER_TARGET_HOST: u32 = 16;
+pub const SECBUFFER_ALERT: u32 = 17;
+pub const SECBUFFER_APPLICATION_PROTOCOLS: u32 = 18;
+pub const SECBUFFER_SRTP_PROTECTION_PROFILES: u32 = 19;
+pub const SECBUFFER_SRTP_MASTER_KEY_IDENTIFIER: u32 = 20;
+pub const SECBUFFER_TOKEN_BINDING: u32 = 21;
+pub const SECBUFFER_PRESHARED_KEY: u32 = 22;
+pub const SECBUFFER_PRESHARED_KEY_IDENTITY: u32 = 23;
+pub const SECBUFFER_DTLS_MTU: u32 = 24;
+pub const SECBUFFER_SEND_GENERIC_TLS_EXTENSION: u32 = 25;
+pub const SECBUFFER_SUBSCRIBE_GENERIC_TLS_EXTENSION: u32 = 26;
+pub const SECBUFFER_FLAGS: u32 = 27;
+pub const SECBUFFER_TRAFFIC_SECRETS: u32 = 28;
+pub const SECBUFFER_CERTIFICATE_REQUEST_CONTEXT: u32 = 29;
+pub const SECBUFFER_CHANNEL_BINDINGS_RESULT: u32 = 30;
+pub const SECBUFFER_ATTRMASK: u32 = 4026531840;
+pub const SECBUFFER_READONLY: u32 = 2147483648;
+pub const SECBUFFER_READONLY_WITH_CHECKSUM: u32 = 268435456;
+pub const SECBUFFER_RESERVED: u32 = 1610612736;
+pub const SEC_CHANNEL_BINDINGS_AUDIT_BINDINGS: u32 = 1;
+pub const SEC_CHANNEL_BINDINGS_VALID_FLAGS: u32 = 1;
+pub const SEC_CHANNEL_BINDINGS_RESULT_CLIENT_SUPPORT: u32 = 1;
+pub const SEC_CHANNEL_BINDINGS_RESULT_ABSENT: u32 = 2;
+pub const SEC_CHANNEL_BINDINGS_RESULT_NOTVALID_MISMATCH: u32 = 4;
+pub const SEC_CHANNEL_BINDINGS_RESULT_NOTVALID_MISSING: u32 = 8;
+pub const SEC_CHANNEL_BINDINGS_RESULT_VALID_MATCHED: u32 = 16;
+pub const SEC_CHANNEL_BINDINGS_RESULT_VALID_PROXY: u32 = 32;
+pub const SEC_CHANNEL_BINDINGS_RESULT_VALID_MISSING: u32 = 64;
+pub const SEC_CHANNEL_BINDINGS_RESULT_VALID: u32 = 112;
+pub const SEC_CHANNEL_BINDINGS_RESULT_NOTVALID: u32 = 12;
+pub const SZ_ALG_MAX_SIZE: u32 = 64;
+pub const SECURITY_NATIVE_DREP: u32 = 16;
+pub const SECURITY_NETWORK_DREP: u32 = 0;
+pub const SECPKG_CRED_INBOUND: u32 = 1;
+pub const SECPKG_CRED_OUTBOUND: u32 = 2;
+pub const SECPKG_CRED_BOTH: u32 = 3;
+pub const SECPKG_CRED_DEFAULT: u32 = 4;
+pub const SECPKG_CRED_RESERVED: u32 = 4026531840;
+pub const SECPKG_CRED_AUTOLOGON_RESTRICTED: u32 = 16;
+pub const SECPKG_CRED_PROCESS_POLICY_ONLY: u32 = 32;
+pub const ISC_REQ_DELEGATE: u32 = 1;
+pub const ISC_REQ_MUTUAL_AUTH: u32 = 2;
+pub const ISC_REQ_REPLAY_DETECT: u32 = 4;
+pub const ISC_REQ_SEQUENCE_DETECT: u32 = 8;
+pub const ISC_REQ_CONFIDENTIALITY: u32 = 16;
+pub const ISC_REQ_USE_SESSION_KEY: u32 = 32;
+pub const ISC_REQ_PROMPT_FOR_CREDS: u32 = 64;
+pub const ISC_REQ_USE_SUPPLIED_CREDS: u32 = 128;
+pub const ISC_REQ_ALLOCATE_MEMORY: u32 = 256;
+pub const ISC_REQ_USE_DCE_STYLE: u32 = 512;
+pub const ISC_REQ_DATAGRAM: u32 = 1024;
+pub const ISC_REQ_CONNECTION: u32 = 2048;
+pub const ISC_REQ_CALL_LEVEL: u32 = 4096;
+pub const ISC_REQ_FRAGMENT_SUPPLIED: u32 = 8192;
+pub const ISC_REQ_EXTENDED_ERROR: u32 = 16384;
+pub const ISC_REQ_STREAM: u32 = 32768;
+pub const ISC_REQ_INTEGRITY: u32 = 65536;
+pub const ISC_REQ_IDENTIFY: u32 = 131072;
+pub const ISC_REQ_NULL_SESSION: u32 = 262144;
+pub const ISC_REQ_MANUAL_CRED_VALIDATION: u32 = 524288;
+pub const ISC_REQ_RESERVED1: u32 = 1048576;
+pub const ISC_REQ_FRAGMENT_TO_FIT: u32 = 2097152;
+pub const ISC_REQ_FORWARD_CREDENTIALS: u32 = 4194304;
+pub const ISC_REQ_NO_INTEGRITY: u32 = 8388608;
+pub const ISC_REQ_USE_HTTP_STYLE: u32 = 16777216;
+pub const ISC_REQ_UNVERIFIED_TARGET_NAME: u32 = 536870912;
+pub const ISC_REQ_CONFIDENTIALITY_ONLY: u32 = 1073741824;
+pub const ISC_REQ_MESSAGES: u64 = 4294967296;
+pub const ISC_REQ_DEFERRED_CRED_VALIDATION: u64 = 8589934592;
+pub const ISC_REQ_NO_POST_HANDSHAKE_AUTH: u64 = 17179869184;
+pub const ISC_RET_DELEGATE: u32 = 1;
+pub const ISC_RET_MUTUAL_AUTH: u32 = 2;
+pub const ISC_RET_REPLAY_DETECT: u32 = 4;
+pub const ISC_RET_SEQUENCE_DETECT: u32 = 8;
+pub const ISC_RET_CONFIDENTIALITY: u32 = 16;
+pub const ISC_RET_USE_SESSION_KEY: u32 = 32;
+pub const ISC_RET_USED_COLLECTED_CREDS: u32 = 64;
+pub const ISC_RET_USED_SUPPLIED_CREDS: u32 = 128;
+pub const ISC_RET_ALLOCATED_MEMORY: u32 = 256;
+pub const ISC_RET_USED_DCE_STYLE: u32 = 512;
+pub const ISC_RET_DATAGRAM: u32 = 1024;
+pub const ISC_RET_CONNECTION: u32 = 2048;
+pub const ISC_RET_INTERMEDIATE_RETURN: u32 = 4096;
+pub const ISC_RET_CALL_LEVEL: u32 = 8192;
+pub const ISC_RET_EXTENDED_ERROR: u32 = 16384;
+pub const ISC_RET_STREAM: u32 = 32768;
+pub const ISC_RET_INTEGRITY: u32 = 65536;
+pub const ISC_RET_IDENTIFY: u32 = 131072;
+pub const ISC_RET_NULL_SESSION: u32 = 262144;
+pub const ISC_RET_MANUAL_CRED_VALIDATION: u32 = 524288;
+pub const ISC_RET_RESERVED1: u32 = 1048576;
+pub const ISC_RET_FRAGMENT_ONLY: u32 = 2097152;
+pub const ISC_RET_FORWARD_CREDENTIALS: u32 = 4194304;
+pub const ISC_RET_USED_HTTP_STYLE: u32 = 16777216;
+pub const ISC_RET_NO_ADDITIONAL_TOKEN: u32 = 33554432;
+pub const ISC_RET_REAUTHENTICATION: u32 = 134217728;
+pub const ISC_RET_CONFIDENTIALITY_ONLY: u32 = 1073741824;
+pub const ISC_RET_MESSAGES: u64 = 4294967296;
+pub const ISC_RET_DEFERRED_CRED_VALIDATION: u64 = 8589934592;
+pub const ISC_RET_NO_POST_HANDSHAKE_AUTH: u64 = 17179869184;
+pub const ASC_REQ_DELEGATE: u32 = 1;
+pub const ASC_REQ_MUTUAL_AUTH: u32 = 2;
+pub const ASC_REQ_REPLAY_DETECT: u32 = 4;
+pub const ASC_REQ_SEQUENCE_DETECT: u32 = 8;
+pub const ASC_REQ_CONFIDENTIALITY: u32 = 16;
+pub const ASC_REQ_USE_SESSION_KEY: u32 = 32;
+pub const ASC_REQ_SESSION_TICKET: u32 = 64;
+pub const ASC_REQ_ALLOCATE_MEMORY: u32 = 256;
+pub const ASC_REQ_USE_DCE_STYLE: u32 = 512;
+pub const ASC_REQ_DATAGRAM: u32 = 1024;
+pub const ASC_REQ_CONNECTION: u32 = 2048;
+pub const ASC_REQ_CALL_LEVEL: u32 = 4096;
+pub const ASC_REQ_FRAGMENT_SUPPLIED: u32 = 8192;
+pub const ASC_REQ_EXTENDED_ERROR: u32 = 32768;
+pub const ASC_REQ_STREAM: u32 = 65536;
+pub const ASC_REQ_INTEGRITY: u32 = 131072;
+pub const ASC_REQ_LICENSING: u32 = 262144;
+pub const ASC_REQ_IDENTIFY: u32 = 524288;
+pub const ASC_REQ_ALLOW_NULL_SESSION: u32 = 1048576;
+pub const ASC_REQ_ALLOW_NON_USER_LOGONS: u32 = 2097152;
+pub const ASC_REQ_ALLOW_CONTEXT_REPLAY: u32 = 4194304;
+pub const ASC_REQ_FRAGMENT_TO_FIT: u32 = 8388608;
+pub const ASC_REQ_NO_TOKEN: u32 = 16777216;
+pub const ASC_REQ_PROXY_BINDINGS: u32 = 67108864;
+pub const ASC_REQ_ALLOW_MISSING_BINDINGS: u32 = 268435456;
+pub const ASC_REQ_MESSAGES: u64 = 4294967296;
+pub const ASC_RET_DELEGATE: u32 = 1;
+pub const ASC_RET_MUTUAL_AUTH: u32 = 2;
+pub const ASC_RET_REPLAY_DETECT: u32 = 4;
+pub const ASC_RET_SEQUENCE_DETECT: u32 = 8;
+pub const ASC_RET_CONFIDENTIALITY: u32 = 16;
+pub const ASC_RET_USE_SESSION_KEY: u32 = 32;
+pub const ASC_RET_SESSION_TICKET: u32 = 64;
+pub const ASC_RET_ALLOCATED_MEMORY: u32 = 256;
+pub const ASC_RET_USED_DCE_STYLE: u32 = 512;
+pub const ASC_RET_DATAGRAM: u32 = 1024;
+pub const ASC_RET_CONNECTION: u32 = 2048;
+pub const ASC_RET_CALL_LEVEL: u32 = 8192;
+pub const ASC_RET_THIRD_LEG_FAILED: u32 = 16384;
+pub const ASC_RET_EXTENDED_ERROR: u32 = 32768;
+pub const ASC_RET_STREAM: u32 = 65536;
+pub const ASC_RET_INTEGRITY: u32 = 131072;
+pub const ASC_RET_LICENSING: u32 = 262144;
+pub const ASC_RET_IDENTIFY: u32 = 524288;
+pub const ASC_RET_NULL_SESSION: u32 = 1048576;
+pub const ASC_RET_ALLOW_NON_USER_LOGONS: u32 = 2097152;
+pub const ASC_RET_ALLOW_CONTEXT_REPLAY: u32 = 4194304;
+pub const ASC_RET_FRAGMENT_ONLY: u32 = 8388608;
+pub const ASC_RET_NO_TOKEN: u32 = 16777216;
+pub const ASC_RET_NO_ADDITIONAL_TOKEN: u32 = 33554432;
+pub const ASC_RET_MESSAGES: u64 = 4294967296;
+pub const SECPKG_CRED_ATTR_NAMES: u32 = 1;
+pub const SECPKG_CRED_ATTR_SSI_PROVIDER: u32 = 2;
+pub const SECPKG_CRED_ATTR_KDC_PROXY_SETTINGS: u32 = 3;
+pub const SECPKG_CRED_ATTR_CERT: u32 = 4;
+pub const SECPKG_CRED_ATTR_PAC_BYPASS: u32 = 5;
+pub const KDC_PROXY_SETTINGS_V1: u32 = 1;
+pub const KDC_PROXY_SETTINGS_FLAGS_FORCEPROXY: u32 = 1;
+pub const SECPKG_ATTR_SIZES: u32 = 0;
+pub const SECPKG_ATTR_NAMES: u32 = 1;
+pub const SECPKG_ATTR_LIFESPAN: u32 = 2;
+pub const SECPKG_ATTR_DCE_INFO: u32 = 3;
+pub const SECPKG_ATTR_STREAM_SIZES: u32 = 4;
+pub const SECPKG_ATTR_KEY_INFO: u32 = 5;
+pub const SECPKG_ATTR_AUTHORITY: u32 = 6;
+pub const SECPKG_ATTR_PROTO_INFO: u32 = 7;
+pub const SECPKG_ATTR_PASSWORD_EXPIRY: u32 = 8;
+pub const SECPKG_ATTR_SESSION_KEY: u32 = 9;
+pub const SECPKG_ATTR_PACKAGE_INFO: u32 = 10;
+pub const SECPKG_ATTR_USER_FLAGS: u32 = 11;
+pub const SECPKG_ATTR_NEGOTIATION_INFO: u32 = 12;
+pub const SECPKG_ATTR_NATIVE_NAMES: u32 = 13;
+pub const SECPKG_ATTR_FLAGS: u32 = 14;
+pub const SECPKG_ATTR_USE_VALIDATED: u32 = 15;
+pub const SECPKG_ATTR_CREDENTIAL_NAME: u32 = 16;
+pub const SECPKG_ATTR_TARGET_INFORMATION: u32 = 17;
+pub const SECPKG_ATTR_ACCESS_TOKEN: u32 = 18;
+pub const SECPKG_ATTR_TARGET: u32 = 19;
+pub const SECPKG_ATTR_AUTHENTICATION_ID: u32 = 20;
+pub const SECPKG_ATTR_LOGOFF_TIME: u32 = 21;
+pub const SECPKG_ATTR_NEGO_KEYS: u32 = 22;
+pub const SECPKG_ATTR_PROMPTING_NEEDED: u32 = 24;
+pub const SECPKG_ATTR_UNIQUE_BINDINGS: u32 = 25;
+pub const SECPKG_ATTR_ENDPOINT_BINDINGS: u32 = 26;
+pub const SECPKG_ATTR_CLIENT_SPECIFIED_TARGET: u32 = 27;
+pub const SECPKG_ATTR_LAST_CLIENT_TOKEN_STATUS: u32 = 30;
+pub const SECPKG_ATTR_NEGO_PKG_INFO: u32 = 31;
+pub const SECPKG_ATTR_NEGO_STATUS: u32 = 32;
+pub const SECPKG_ATTR_CONTEXT_DELETED: u32 = 33;
+pub const SECPKG_ATTR_DTLS_MTU: u32 = 34;
+pub const SECPKG_ATTR_DATAGRAM_SIZES: u32 = 4;
+pub const SECPKG_ATTR_SUBJECT_SECURITY_ATTRIBUTES: u32 = 128;
+pub const SECPKG_ATTR_APPLICATION_PROTOCOL: u32 = 35;
+pub const SECPKG_ATTR_NEGOTIATED_TLS_EXTENSIONS: u32 = 36;
+pub const SECPKG_ATTR_IS_LOOPBACK: u32 = 37;
+pub const SECPKG_ATTR_NEGO_INFO_FLAG_NO_KERBEROS: u32 = 1;
+pub const SECPKG_ATTR_NEGO_INFO_FLAG_NO_NTLM: u32 = 2;
+pub const SECPKG_CONTEXT_EXPORT_RESET_NEW: u32 = 1;
+pub const SECPKG_CONTEXT_EXPORT_DELETE_OLD: u32 = 2;
+pub const SECPKG_CONTEXT_EXPORT_TO_KERNEL: u32 = 4;
+pub const SECQOP_WRAP_NO_ENCRYPT: u32 = 2147483649;
+pub const SECQOP_WRAP_OOB_DATA: u32 = 1073741824;
+pub const SECURITY_SUPPORT_PROVIDER_INTERFACE_VERSION: u32 = 1;
+pub const SECURITY_SUPPORT_PROVIDER_INTERFACE_VERSION_2: u32 = 2;
+pub const SECURITY_SUPPORT_PROVIDER_INTERFACE_VERSION_3: u32 = 3;
+pub const SECURITY_SUPPORT_PROVIDER_INTERFACE_VERSION_4: u32 = 4;
+pub const SECURITY_SUPPORT_PROVIDER_INTERFACE_VERSION_5: u32 = 5;
+pub const SEC_WINNT_AUTH_IDENTITY_VERSION_2: u32 = 513;
+pub const SEC_WINNT_AUTH_IDENTITY_ANSI: u32 = 1;
+pub const SEC_WINNT_AUTH_IDENTITY_UNICODE: u32 = 2;
+pub const SEC_WINNT_AUTH_IDENTITY_VERSION: u32 = 512;
+pub const SEC_WINNT_AUTH_IDENTITY_MARSHALLED: u32 = 4;
+pub const SEC_WINNT_AUTH_IDENTITY_ONLY: u32 = 8;
+pub const UNDERSTANDS_LONG_NAMES: u32 = 1;
+pub const NO_LONG_NAMES: u32 = 2;
+pub const DD_MUP_DEVICE_NAME: &[u8; 12] = b"\\Device\\Mup\0";
+pub const EA_NAME_NETWORK_OPEN_ECP_PRIVACY: &[u8; 52] =
+    b"ECP{c584edbf-00df-4d28-00b8-8435baca8911e8}-PRIVACY\0";
+pub const EA_NAME_NETWORK_OPEN_ECP_PRIVACY_U: &[u8; 52] =
+    b"ECP{c584edbf-00df-4d28-00b8-8435baca8911e8}-PRIVACY\0";
+pub const EA_NAME_NETWORK_OPEN_ECP_INTEGRITY: &[u8; 54] =
+    b"ECP{c584edbf-00df-4d28-00b8-8435baca8911e8}-INTEGRITY\0";
+pub const EA_NAME_NETWORK_OPEN_ECP_INTEGRITY_U: &[u8; 54] =
+    b"ECP{c584edbf-00df-4d28-00b8-8435baca8911e8}-INTEGRITY\0";
+pub const WNNC_NET_MSNET: u32 = 65536;
+pub const WNNC_NET_SMB: u32 = 131072;
+pub const WNNC_NET_NETWARE: u32 = 196608;
+pub const WNNC_NET_VINES: u32 = 262144;
+pub const WNNC_NET_10NET: u32 = 327680;
+pub const WNNC_NET_LOCUS: u32 = 393216;
+pub const WNNC_NET_SUN_PC_NFS: u32 = 458752;
+pub const WNNC_NET_LANSTEP: u32 = 524288;
+pub const WNNC_NET_9TILES: u32 = 589824;
+pub const WNNC_NET_LANTASTIC: u32 = 655360;
+pub const WNNC_NET_AS400: u32 = 720896;
+pub const WNNC_NET_FTP_NFS: u32 = 786432;
+pub const WNNC_NET_PATHWORKS: u32 = 851968;
+pub const WNNC_NET_LIFENET: u32 = 917504;
+pub const WNNC_NET_POWERLAN: u32 = 983040;
+pub const WNNC_NET_BWNFS: u32 = 1048576;
+pub const WNNC_NET_COGENT: u32 = 1114112;
+pub const WNNC_NET_FARALLON: u32 = 1179648;
+pub const WNNC_NET_APPLETALK: u32 = 1245184;
+pub const WNNC_NET_INTERGRAPH: u32 = 1310720;
+pub const WNNC_NET_SYMFONET: u32 = 1376256;
+pub const WNNC_NET_CLEARCASE: u32 = 1441792;
+pub const WNNC_NET_FRONTIER: u32 = 1507328;
+pub const WNNC_NET_BMC: u32 = 1572864;
+pub const WNNC_NET_DCE: u32 = 1638400;
+pub const WNNC_NET_AVID: u32 = 1703936;
+pub const WNNC_NET_DOCUSPACE: u32 = 1769472;
+pub const WNNC_NET_MANGOSOFT: u32 = 1835008;
+pub const WNNC_NET_SERNET: u32 = 1900544;
+pub const WNNC_NET_RIVERFRONT1: u32 = 1966080;
+pub const WNNC_NET_RIVERFRONT2: u32 = 2031616;
+pub const WNNC_NET_DECORB: u32 = 2097152;
+pub const WNNC_NET_PROTSTOR: u32 = 2162688;
+pub const WNNC_NET_FJ_REDIR: u32 = 2228224;
+pub const WNNC_NET_DISTINCT: u32 = 2293760;
+pub const WNNC_NET_TWINS: u32 = 2359296;
+pub const WNNC_NET_RDR2SAMPLE: u32 = 2424832;
+pub const WNNC_NET_CSC: u32 = 2490368;
+pub const WNNC_NET_3IN1: u32 = 2555904;
+pub const WNNC_NET_EXTENDNET: u32 = 2686976;
+pub const WNNC_NET_STAC: u32 = 2752512;
+pub const WNNC_NET_FOXBAT: u32 = 2818048;
+pub const WNNC_NET_YAHOO: u32 = 2883584;
+pub const WNNC_NET_EXIFS: u32 = 2949120;
+pub const WNNC_NET_DAV: u32 = 3014656;
+pub const WNNC_NET_KNOWARE: u32 = 3080192;
+pub const WNNC_NET_OBJECT_DIRE: u32 = 3145728;
+pub const WNNC_NET_MASFAX: u32 = 3211264;
+pub const WNNC_NET_HOB_NFS: u32 = 3276800;
+pub const WNNC_NET_SHIVA: u32 = 3342336;
+pub const WNNC_NET_IBMAL: u32 = 3407872;
+pub const WNNC_NET_LOCK: u32 = 3473408;
+pub const WNNC_NET_TERMSRV: u32 = 3538944;
+pub const WNNC_NET_SRT: u32 = 3604480;
+pub const WNNC_NET_QUINCY: u32 = 3670016;
+pub const WNNC_NET_OPENAFS: u32 = 3735552;
+pub const WNNC_NET_AVID1: u32 = 3801088;
+pub const WNNC_NET_DFS: u32 = 3866624;
+pub const WNNC_NET_KWNP: u32 = 3932160;
+pub const WNNC_NET_ZENWORKS: u32 = 3997696;
+pub const WNNC_NET_DRIVEONWEB: u32 = 4063232;
+pub const WNNC_NET_VMWARE: u32 = 4128768;
+pub const WNNC_NET_RSFX: u32 = 4194304;
+pub const WNNC_NET_MFILES: u32 = 4259840;
+pub const WNNC_NET_MS_NFS: u32 = 4325376;
+pub const WNNC_NET_GOOGLE: u32 = 4390912;
+pub const WNNC_NET_NDFS: u32 = 4456448;
+pub const WNNC_NET_DOCUSHARE: u32 = 4521984;
+pub const WNNC_NET_AURISTOR_FS: u32 = 4587520;
+pub const WNNC_NET_SECUREAGENT: u32 = 4653056;
+pub const WNNC_NET_9P: u32 = 4718592;
+pub const WNNC_CRED_MANAGER: u32 = 4294901760;
+pub const WNNC_NET_LANMAN: u32 = 131072;
+pub const VOLSNAPCONTROLTYPE: u32 = 83;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CPTABLEINFO {
+    pub CodePage: USHORT,
+    pub MaximumCharacterSize: USHORT,
+    pub DefaultChar: USHORT,
+    pub UniDefaultChar: USHORT,
+    pub TransDefaultChar: USHORT,
+    pub TransUniDefaultChar: USHORT,
+    pub DBCSCodePage: USHORT,
+    pub LeadByte: [UCHAR; 12usize],
+    pub MultiByteTable: PUSHORT,
+    pub WideCharTable: PVOID,
+    pub DBCSRanges: PUSHORT,
+    pub DBCSOffsets: PUSHORT,
+}
+pub type CPTABLEINFO = _CPTABLEINFO;
+pub type PCPTABLEINFO = *mut _CPTABLEINFO;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NLSTABLEINFO {
+    pub OemTableInfo: CPTABLEINFO,
+    pub AnsiTableInfo: CPTABLEINFO,
+    pub UpperCaseTable: PUSHORT,
+    pub LowerCaseTable: PUSHORT,
+}
+pub type NLSTABLEINFO = _NLSTABLEINFO;
+pub type PNLSTABLEINFO = *mut _NLSTABLEINFO;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _RTL_NLS_STATE {
+    pub DefaultAcpTableInfo: CPTABLEINFO,
+    pub DefaultOemTableInfo: CPTABLEINFO,
+    pub ActiveCodePageData: PUSHORT,
+    pub OemCodePageData: PUSHORT,
+    pub LeadByteInfo: PUSHORT,
+    pub OemLeadByteInfo: PUSHORT,
+    pub CaseMappingData: PUSHORT,
+    pub UnicodeUpcaseTable844: PUSHORT,
+    pub UnicodeLowercaseTable844: PUSHORT,
+}
+pub type RTL_NLS_STATE = _RTL_NLS_STATE;
+pub type PRTL_NLS_STATE = *mut _RTL_NLS_STATE;
+pub type LSA_UNICODE_STRING = UNICODE_STRING;
+pub type PLSA_UNICODE_STRING = *mut UNICODE_STRING;
+pub type LSA_STRING = STRING;
+pub type PLSA_STRING = *mut STRING;
+pub type LSA_OBJECT_ATTRIBUTES = OBJECT_ATTRIBUTES;
+pub type PLSA_OBJECT_ATTRIBUTES = *mut OBJECT_ATTRIBUTES;
+pub type PSID_IDENTIFIER_AUTHORITY = *mut _SID_IDENTIFIER_AUTHORITY;
+pub type PISID = *mut _SID;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _SE_SID {
+    pub Sid: SID,
+    pub Buffer: [UCHAR; 68usize],
+}
+pub type SE_SID = _SE_SID;
+pub type PSE_SID = *mut _SE_SID;
+pub const _SID_NAME_USE_SidTypeUser: _SID_NAME_USE = 1;
+pub const _SID_NAME_USE_SidTypeGroup: _SID_NAME_USE = 2;
+pub const _SID_NAME_USE_SidTypeDomain: _SID_NAME_USE = 3;
+pub const _SID_NAME_USE_SidTypeAlias: _SID_NAME_USE = 4;
+pub const _SID_NAME_USE_SidTypeWellKnownGroup: _SID_NAME_USE = 5;
+pub const _SID_NAME_USE_SidTypeDeletedAccount: _SID_NAME_USE = 6;
+pub const _SID_NAME_USE_SidTypeInvalid: _SID_NAME_USE = 7;
+pub const _SID_NAME_USE_SidTypeUnknown: _SID_NAME_USE = 8;
+pub const _SID_NAME_USE_SidTypeComputer: _SID_NAME_USE = 9;
+pub const _SID_NAME_USE_SidTypeLabel: _SID_NAME_USE = 10;
+pub const _SID_NAME_USE_SidTypeLogonSession: _SID_NAME_USE = 11;
+pub type _SID_NAME_USE = core::ffi::c_int;
+pub use self::_SID_NAME_USE as SID_NAME_USE;
+pub type PSID_NAME_USE = *mut _SID_NAME_USE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SID_AND_ATTRIBUTES {
+    pub Sid: PSID,
+    pub Attributes: ULONG,
+}
+pub type SID_AND_ATTRIBUTES = _SID_AND_ATTRIBUTES;
+pub type PSID_AND_ATTRIBUTES = *mut _SID_AND_ATTRIBUTES;
+pub type SID_AND_ATTRIBUTES_ARRAY = [SID_AND_ATTRIBUTES; 1usize];
+pub type PSID_AND_ATTRIBUTES_ARRAY = *mut SID_AND_ATTRIBUTES_ARRAY;
+pub type SID_HASH_ENTRY = ULONG_PTR;
+pub type PSID_HASH_ENTRY = *mut ULONG_PTR;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SID_AND_ATTRIBUTES_HASH {
+    pub SidCount: ULONG,
+    pub SidAttr: PSID_AND_ATTRIBUTES,
+    pub Hash: [SID_HASH_ENTRY; 32usize],
+}
+pub type SID_AND_ATTRIBUTES_HASH = _SID_AND_ATTRIBUTES_HASH;
+pub type PSID_AND_ATTRIBUTES_HASH = *mut _SID_AND_ATTRIBUTES_HASH;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ACE_HEADER {
+    pub AceType: UCHAR,
+    pub AceFlags: UCHAR,
+    pub AceSize: USHORT,
+}
+pub type ACE_HEADER = _ACE_HEADER;
+pub type PACE_HEADER = *mut ACE_HEADER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ACCESS_ALLOWED_ACE {
+    pub Header: ACE_HEADER,
+    pub Mask: ACCESS_MASK,
+    pub SidStart: ULONG,
+}
+pub type ACCESS_ALLOWED_ACE = _ACCESS_ALLOWED_ACE;
+pub type PACCESS_ALLOWED_ACE = *mut ACCESS_ALLOWED_ACE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ACCESS_DENIED_ACE {
+    pub Header: ACE_HEADER,
+    pub Mask: ACCESS_MASK,
+    pub SidStart: ULONG,
+}
+pub type ACCESS_DENIED_ACE = _ACCESS_DENIED_ACE;
+pub type PACCESS_DENIED_ACE = *mut ACCESS_DENIED_ACE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SYSTEM_AUDIT_ACE {
+    pub Header: ACE_HEADER,
+    pub Mask: ACCESS_MASK,
+    pub SidStart: ULONG,
+}
+pub type SYSTEM_AUDIT_ACE = _SYSTEM_AUDIT_ACE;
+pub type PSYSTEM_AUDIT_ACE = *mut SYSTEM_AUDIT_ACE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SYSTEM_ALARM_ACE {
+    pub Header: ACE_HEADER,
+    pub Mask: ACCESS_MASK,
+    pub SidStart: ULONG,
+}
+pub type SYSTEM_ALARM_ACE = _SYSTEM_ALARM_ACE;
+pub type PSYSTEM_ALARM_ACE = *mut SYSTEM_ALARM_ACE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SYSTEM_RESOURCE_ATTRIBUTE_ACE {
+    pub Header: ACE_HEADER,
+    pub Mask: ACCESS_MASK,
+    pub SidStart: ULONG,
+}
+pub type SYSTEM_RESOURCE_ATTRIBUTE_ACE = _SYSTEM_RESOURCE_ATTRIBUTE_ACE;
+pub type PSYSTEM_RESOURCE_ATTRIBUTE_ACE = *mut _SYSTEM_RESOURCE_ATTRIBUTE_ACE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SYSTEM_SCOPED_POLICY_ID_ACE {
+    pub Header: ACE_HEADER,
+    pub Mask: ACCESS_MASK,
+    pub SidStart: ULONG,
+}
+pub type SYSTEM_SCOPED_POLICY_ID_ACE = _SYSTEM_SCOPED_POLICY_ID_ACE;
+pub type PSYSTEM_SCOPED_POLICY_ID_ACE = *mut _SYSTEM_SCOPED_POLICY_ID_ACE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SYSTEM_MANDATORY_LABEL_ACE {
+    pub Header: ACE_HEADER,
+    pub Mask: ACCESS_MASK,
+    pub SidStart: ULONG,
+}
+pub type SYSTEM_MANDATORY_LABEL_ACE = _SYSTEM_MANDATORY_LABEL_ACE;
+pub type PSYSTEM_MANDATORY_LABEL_ACE = *mut _SYSTEM_MANDATORY_LABEL_ACE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SYSTEM_PROCESS_TRUST_LABEL_ACE {
+    pub Header: ACE_HEADER,
+    pub Mask: ACCESS_MASK,
+    pub SidStart: ULONG,
+}
+pub type SYSTEM_PROCESS_TRUST_LABEL_ACE = _SYSTEM_PROCESS_TRUST_LABEL_ACE;
+pub type PSYSTEM_PROCESS_TRUST_LABEL_ACE = *mut _SYSTEM_PROCESS_TRUST_LABEL_ACE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SYSTEM_ACCESS_FILTER_ACE {
+    pub Header: ACE_HEADER,
+    pub Mask: ACCESS_MASK,
+    pub SidStart: ULONG,
+}
+pub type SYSTEM_ACCESS_FILTER_ACE = _SYSTEM_ACCESS_FILTER_ACE;
+pub type PSYSTEM_ACCESS_FILTER_ACE = *mut _SYSTEM_ACCESS_FILTER_ACE;
+pub type SECURITY_DESCRIPTOR_CONTROL = USHORT;
+pub type PSECURITY_DESCRIPTOR_CONTROL = *mut USHORT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SECURITY_DESCRIPTOR_RELATIVE {
+    pub Revision: UCHAR,
+    pub Sbz1: UCHAR,
+    pub Control: SECURITY_DESCRIPTOR_CONTROL,
+    pub Owner: ULONG,
+    pub Group: ULONG,
+    pub Sacl: ULONG,
+    pub Dacl: ULONG,
+}
+pub type SECURITY_DESCRIPTOR_RELATIVE = _SECURITY_DESCRIPTOR_RELATIVE;
+pub type PISECURITY_DESCRIPTOR_RELATIVE = *mut _SECURITY_DESCRIPTOR_RELATIVE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SECURITY_DESCRIPTOR {
+    pub Revision: UCHAR,
+    pub Sbz1: UCHAR,
+    pub Control: SECURITY_DESCRIPTOR_CONTROL,
+    pub Owner: PSID,
+    pub Group: PSID,
+    pub Sacl: PACL,
+    pub Dacl: PACL,
+}
+pub type SECURITY_DESCRIPTOR = _SECURITY_DESCRIPTOR;
+pub type PISECURITY_DESCRIPTOR = *mut _SECURITY_DESCRIPTOR;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SECURITY_OBJECT_AI_PARAMS {
+    pub Size: ULONG,
+    pub ConstraintMask: ULONG,
+}
+pub type SECURITY_OBJECT_AI_PARAMS = _SECURITY_OBJECT_AI_PARAMS;
+pub type PSECURITY_OBJECT_AI_PARAMS = *mut _SECURITY_OBJECT_AI_PARAMS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _OBJECT_TYPE_LIST {
+    pub Level: USHORT,
+    pub Sbz: USHORT,
+    pub ObjectType: *mut GUID,
+}
+pub type OBJECT_TYPE_LIST = _OBJECT_TYPE_LIST;
+pub type POBJECT_TYPE_LIST = *mut _OBJECT_TYPE_LIST;
+pub const _AUDIT_EVENT_TYPE_AuditEventObjectAccess: _AUDIT_EVENT_TYPE = 0;
+pub const _AUDIT_EVENT_TYPE_AuditEventDirectoryServiceAccess: _AUDIT_EVENT_TYPE = 1;
+pub type _AUDIT_EVENT_TYPE = core::ffi::c_int;
+pub use self::_AUDIT_EVENT_TYPE as AUDIT_EVENT_TYPE;
+pub type PAUDIT_EVENT_TYPE = *mut _AUDIT_EVENT_TYPE;
+pub const _ACCESS_REASON_TYPE_AccessReasonNone: _ACCESS_REASON_TYPE = 0;
+pub const _ACCESS_REASON_TYPE_AccessReasonAllowedAce: _ACCESS_REASON_TYPE = 65536;
+pub const _ACCESS_REASON_TYPE_AccessReasonDeniedAce: _ACCESS_REASON_TYPE = 131072;
+pub const _ACCESS_REASON_TYPE_AccessReasonAllowedParentAce: _ACCESS_REASON_TYPE = 196608;
+pub const _ACCESS_REASON_TYPE_AccessReasonDeniedParentAce: _ACCESS_REASON_TYPE = 262144;
+pub const _ACCESS_REASON_TYPE_AccessReasonNotGrantedByCape: _ACCESS_REASON_TYPE = 327680;
+pub const _ACCESS_REASON_TYPE_AccessReasonNotGrantedByParentCape: _ACCESS_REASON_TYPE = 393216;
+pub const _ACCESS_REASON_TYPE_AccessReasonNotGrantedToAppContainer: _ACCESS_REASON_TYPE = 458752;
+pub const _ACCESS_REASON_TYPE_AccessReasonMissingPrivilege: _ACCESS_REASON_TYPE = 1048576;
+pub const _ACCESS_REASON_TYPE_AccessReasonFromPrivilege: _ACCESS_REASON_TYPE = 2097152;
+pub const _ACCESS_REASON_TYPE_AccessReasonIntegrityLevel: _ACCESS_REASON_TYPE = 3145728;
+pub const _ACCESS_REASON_TYPE_AccessReasonOwnership: _ACCESS_REASON_TYPE = 4194304;
+pub const _ACCESS_REASON_TYPE_AccessReasonNullDacl: _ACCESS_REASON_TYPE = 5242880;
+pub const _ACCESS_REASON_TYPE_AccessReasonEmptyDacl: _ACCESS_REASON_TYPE = 6291456;
+pub const _ACCESS_REASON_TYPE_AccessReasonNoSD: _ACCESS_REASON_TYPE = 7340032;
+pub const _ACCESS_REASON_TYPE_AccessReasonNoGrant: _ACCESS_REASON_TYPE = 8388608;
+pub const _ACCESS_REASON_TYPE_AccessReasonTrustLabel: _ACCESS_REASON_TYPE = 9437184;
+pub const _ACCESS_REASON_TYPE_AccessReasonFilterAce: _ACCESS_REASON_TYPE = 10485760;
+pub type _ACCESS_REASON_TYPE = core::ffi::c_int;
+pub use self::_ACCESS_REASON_TYPE as ACCESS_REASON_TYPE;
+pub type ACCESS_REASON = ULONG;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ACCESS_REASONS {
+    pub Data: [ACCESS_REASON; 32usize],
+}
+pub type ACCESS_REASONS = _ACCESS_REASONS;
+pub type PACCESS_REASONS = *mut _ACCESS_REASONS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SE_SECURITY_DESCRIPTOR {
+    pub Size: ULONG,
+    pub Flags: ULONG,
+    pub SecurityDescriptor: PSECURITY_DESCRIPTOR,
+}
+pub type SE_SECURITY_DESCRIPTOR = _SE_SECURITY_DESCRIPTOR;
+pub type PSE_SECURITY_DESCRIPTOR = *mut _SE_SECURITY_DESCRIPTOR;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SE_ACCESS_REQUEST {
+    pub Size: ULONG,
+    pub SeSecurityDescriptor: PSE_SECURITY_DESCRIPTOR,
+    pub DesiredAccess: ACCESS_MASK,
+    pub PreviouslyGrantedAccess: ACCESS_MASK,
+    pub PrincipalSelfSid: PSID,
+    pub GenericMapping: PGENERIC_MAPPING,
+    pub ObjectTypeListCount: ULONG,
+    pub ObjectTypeList: POBJECT_TYPE_LIST,
+}
+pub type SE_ACCESS_REQUEST = _SE_ACCESS_REQUEST;
+pub type PSE_ACCESS_REQUEST = *mut _SE_ACCESS_REQUEST;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SE_ACCESS_REPLY {
+    pub Size: ULONG,
+    pub ResultListCount: ULONG,
+    pub GrantedAccess: PACCESS_MASK,
+    pub AccessStatus: PNTSTATUS,
+    pub AccessReason: PACCESS_REASONS,
+    pub Privileges: *mut PPRIVILEGE_SET,
+}
+pub type SE_ACCESS_REPLY = _SE_ACCESS_REPLY;
+pub type PSE_ACCESS_REPLY = *mut _SE_ACCESS_REPLY;
+pub const _SE_AUDIT_OPERATION_AuditPrivilegeObject: _SE_AUDIT_OPERATION = 0;
+pub const _SE_AUDIT_OPERATION_AuditPrivilegeService: _SE_AUDIT_OPERATION = 1;
+pub const _SE_AUDIT_OPERATION_AuditAccessCheck: _SE_AUDIT_OPERATION = 2;
+pub const _SE_AUDIT_OPERATION_AuditOpenObject: _SE_AUDIT_OPERATION = 3;
+pub const _SE_AUDIT_OPERATION_AuditOpenObjectWithTransaction: _SE_AUDIT_OPERATION = 4;
+pub const _SE_AUDIT_OPERATION_AuditCloseObject: _SE_AUDIT_OPERATION = 5;
+pub const _SE_AUDIT_OPERATION_AuditDeleteObject: _SE_AUDIT_OPERATION = 6;
+pub const _SE_AUDIT_OPERATION_AuditOpenObjectForDelete: _SE_AUDIT_OPERATION = 7;
+pub const _SE_AUDIT_OPERATION_AuditOpenObjectForDeleteWithTransaction: _SE_AUDIT_OPERATION = 8;
+pub const _SE_AUDIT_OPERATION_AuditCloseNonObject: _SE_AUDIT_OPERATION = 9;
+pub const _SE_AUDIT_OPERATION_AuditOpenNonObject: _SE_AUDIT_OPERATION = 10;
+pub const _SE_AUDIT_OPERATION_AuditObjectReference: _SE_AUDIT_OPERATION = 11;
+pub const _SE_AUDIT_OPERATION_AuditHandleCreation: _SE_AUDIT_OPERATION = 12;
+pub type _SE_AUDIT_OPERATION = core::ffi::c_int;
+pub use self::_SE_AUDIT_OPERATION as SE_AUDIT_OPERATION;
+pub type PSE_AUDIT_OPERATION = *mut _SE_AUDIT_OPERATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SE_AUDIT_INFO {
+    pub Size: ULONG,
+    pub AuditType: AUDIT_EVENT_TYPE,
+    pub AuditOperation: SE_AUDIT_OPERATION,
+    pub AuditFlags: ULONG,
+    pub SubsystemName: UNICODE_STRING,
+    pub ObjectTypeName: UNICODE_STRING,
+    pub ObjectName: UNICODE_STRING,
+    pub HandleId: PVOID,
+    pub TransactionId: *mut GUID,
+    pub OperationId: *mut LUID,
+    pub ObjectCreation: BOOLEAN,
+    pub GenerateOnClose: BOOLEAN,
+}
+pub type SE_AUDIT_INFO = _SE_AUDIT_INFO;
+pub type PSE_AUDIT_INFO = *mut _SE_AUDIT_INFO;
+pub const _TOKEN_TYPE_TokenPrimary: _TOKEN_TYPE = 1;
+pub const _TOKEN_TYPE_TokenImpersonation: _TOKEN_TYPE = 2;
+pub type _TOKEN_TYPE = core::ffi::c_int;
+pub use self::_TOKEN_TYPE as TOKEN_TYPE;
+pub type PTOKEN_TYPE = *mut TOKEN_TYPE;
+pub const _TOKEN_ELEVATION_TYPE_TokenElevationTypeDefault: _TOKEN_ELEVATION_TYPE = 1;
+pub const _TOKEN_ELEVATION_TYPE_TokenElevationTypeFull: _TOKEN_ELEVATION_TYPE = 2;
+pub const _TOKEN_ELEVATION_TYPE_TokenElevationTypeLimited: _TOKEN_ELEVATION_TYPE = 3;
+pub type _TOKEN_ELEVATION_TYPE = core::ffi::c_int;
+pub use self::_TOKEN_ELEVATION_TYPE as TOKEN_ELEVATION_TYPE;
+pub type PTOKEN_ELEVATION_TYPE = *mut _TOKEN_ELEVATION_TYPE;
+pub const _TOKEN_INFORMATION_CLASS_TokenUser: _TOKEN_INFORMATION_CLASS = 1;
+pub const _TOKEN_INFORMATION_CLASS_TokenGroups: _TOKEN_INFORMATION_CLASS = 2;
+pub const _TOKEN_INFORMATION_CLASS_TokenPrivileges: _TOKEN_INFORMATION_CLASS = 3;
+pub const _TOKEN_INFORMATION_CLASS_TokenOwner: _TOKEN_INFORMATION_CLASS = 4;
+pub const _TOKEN_INFORMATION_CLASS_TokenPrimaryGroup: _TOKEN_INFORMATION_CLASS = 5;
+pub const _TOKEN_INFORMATION_CLASS_TokenDefaultDacl: _TOKEN_INFORMATION_CLASS = 6;
+pub const _TOKEN_INFORMATION_CLASS_TokenSource: _TOKEN_INFORMATION_CLASS = 7;
+pub const _TOKEN_INFORMATION_CLASS_TokenType: _TOKEN_INFORMATION_CLASS = 8;
+pub const _TOKEN_INFORMATION_CLASS_TokenImpersonationLevel: _TOKEN_INFORMATION_CLASS = 9;
+pub const _TOKEN_INFORMATION_CLASS_TokenStatistics: _TOKEN_INFORMATION_CLASS = 10;
+pub const _TOKEN_INFORMATION_CLASS_TokenRestrictedSids: _TOKEN_INFORMATION_CLASS = 11;
+pub const _TOKEN_INFORMATION_CLASS_TokenSessionId: _TOKEN_INFORMATION_CLASS = 12;
+pub const _TOKEN_INFORMATION_CLASS_TokenGroupsAndPrivileges: _TOKEN_INFORMATION_CLASS = 13;
+pub const _TOKEN_INFORMATION_CLASS_TokenSessionReference: _TOKEN_INFORMATION_CLASS = 14;
+pub const _TOKEN_INFORMATION_CLASS_TokenSandBoxInert: _TOKEN_INFORMATION_CLASS = 15;
+pub const _TOKEN_INFORMATION_CLASS_TokenAuditPolicy: _TOKEN_INFORMATION_CLASS = 16;
+pub const _TOKEN_INFORMATION_CLASS_TokenOrigin: _TOKEN_INFORMATION_CLASS = 17;
+pub const _TOKEN_INFORMATION_CLASS_TokenElevationType: _TOKEN_INFORMATION_CLASS = 18;
+pub const _TOKEN_INFORMATION_CLASS_TokenLinkedToken: _TOKEN_INFORMATION_CLASS = 19;
+pub const _TOKEN_INFORMATION_CLASS_TokenElevation: _TOKEN_INFORMATION_CLASS = 20;
+pub const _TOKEN_INFORMATION_CLASS_TokenHasRestrictions: _TOKEN_INFORMATION_CLASS = 21;
+pub const _TOKEN_INFORMATION_CLASS_TokenAccessInformation: _TOKEN_INFORMATION_CLASS = 22;
+pub const _TOKEN_INFORMATION_CLASS_TokenVirtualizationAllowed: _TOKEN_INFORMATION_CLASS = 23;
+pub const _TOKEN_INFORMATION_CLASS_TokenVirtualizationEnabled: _TOKEN_INFORMATION_CLASS = 24;
+pub const _TOKEN_INFORMATION_CLASS_TokenIntegrityLevel: _TOKEN_INFORMATION_CLASS = 25;
+pub const _TOKEN_INFORMATION_CLASS_TokenUIAccess: _TOKEN_INFORMATION_CLASS = 26;
+pub const _TOKEN_INFORMATION_CLASS_TokenMandatoryPolicy: _TOKEN_INFORMATION_CLASS = 27;
+pub const _TOKEN_INFORMATION_CLASS_TokenLogonSid: _TOKEN_INFORMATION_CLASS = 28;
+pub const _TOKEN_INFORMATION_CLASS_TokenIsAppContainer: _TOKEN_INFORMATION_CLASS = 29;
+pub const _TOKEN_INFORMATION_CLASS_TokenCapabilities: _TOKEN_INFORMATION_CLASS = 30;
+pub const _TOKEN_INFORMATION_CLASS_TokenAppContainerSid: _TOKEN_INFORMATION_CLASS = 31;
+pub const _TOKEN_INFORMATION_CLASS_TokenAppContainerNumber: _TOKEN_INFORMATION_CLASS = 32;
+pub const _TOKEN_INFORMATION_CLASS_TokenUserClaimAttributes: _TOKEN_INFORMATION_CLASS = 33;
+pub const _TOKEN_INFORMATION_CLASS_TokenDeviceClaimAttributes: _TOKEN_INFORMATION_CLASS = 34;
+pub const _TOKEN_INFORMATION_CLASS_TokenRestrictedUserClaimAttributes: _TOKEN_INFORMATION_CLASS =
+    35;
+pub const _TOKEN_INFORMATION_CLASS_TokenRestrictedDeviceClaimAttributes: _TOKEN_INFORMATION_CLASS =
+    36;
+pub const _TOKEN_INFORMATION_CLASS_TokenDeviceGroups: _TOKEN_INFORMATION_CLASS = 37;
+pub const _TOKEN_INFORMATION_CLASS_TokenRestrictedDeviceGroups: _TOKEN_INFORMATION_CLASS = 38;
+pub const _TOKEN_INFORMATION_CLASS_TokenSecurityAttributes: _TOKEN_INFORMATION_CLASS = 39;
+pub const _TOKEN_INFORMATION_CLASS_TokenIsRestricted: _TOKEN_INFORMATION_CLASS = 40;
+pub const _TOKEN_INFORMATION_CLASS_TokenProcessTrustLevel: _TOKEN_INFORMATION_CLASS = 41;
+pub const _TOKEN_INFORMATION_CLASS_TokenPrivateNameSpace: _TOKEN_INFORMATION_CLASS = 42;
+pub const _TOKEN_INFORMATION_CLASS_TokenSingletonAttributes: _TOKEN_INFORMATION_CLASS = 43;
+pub const _TOKEN_INFORMATION_CLASS_TokenBnoIsolation: _TOKEN_INFORMATION_CLASS = 44;
+pub const _TOKEN_INFORMATION_CLASS_TokenChildProcessFlags: _TOKEN_INFORMATION_CLASS = 45;
+pub const _TOKEN_INFORMATION_CLASS_TokenIsLessPrivilegedAppContainer: _TOKEN_INFORMATION_CLASS = 46;
+pub const _TOKEN_INFORMATION_CLASS_TokenIsSandboxed: _TOKEN_INFORMATION_CLASS = 47;
+pub const _TOKEN_INFORMATION_CLASS_TokenIsAppSilo: _TOKEN_INFORMATION_CLASS = 48;
+pub const _TOKEN_INFORMATION_CLASS_MaxTokenInfoClass: _TOKEN_INFORMATION_CLASS = 49;
+pub type _TOKEN_INFORMATION_CLASS = core::ffi::c_int;
+pub use self::_TOKEN_INFORMATION_CLASS as TOKEN_INFORMATION_CLASS;
+pub type PTOKEN_INFORMATION_CLASS = *mut _TOKEN_INFORMATION_CLASS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_USER {
+    pub User: SID_AND_ATTRIBUTES,
+}
+pub type TOKEN_USER = _TOKEN_USER;
+pub type PTOKEN_USER = *mut _TOKEN_USER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _SE_TOKEN_USER {
+    pub __bindgen_anon_1: _SE_TOKEN_USER__bindgen_ty_1,
+    pub __bindgen_anon_2: _SE_TOKEN_USER__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _SE_TOKEN_USER__bindgen_ty_1 {
+    pub TokenUser: TOKEN_USER,
+    pub User: SID_AND_ATTRIBUTES,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _SE_TOKEN_USER__bindgen_ty_2 {
+    pub Sid: SID,
+    pub Buffer: [UCHAR; 68usize],
+}
+pub type SE_TOKEN_USER = _SE_TOKEN_USER;
+pub type PSE_TOKEN_USER = _SE_TOKEN_USER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_GROUPS {
+    pub GroupCount: ULONG,
+    pub Groups: [SID_AND_ATTRIBUTES; 1usize],
+}
+pub type TOKEN_GROUPS = _TOKEN_GROUPS;
+pub type PTOKEN_GROUPS = *mut _TOKEN_GROUPS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_PRIVILEGES {
+    pub PrivilegeCount: ULONG,
+    pub Privileges: [LUID_AND_ATTRIBUTES; 1usize],
+}
+pub type TOKEN_PRIVILEGES = _TOKEN_PRIVILEGES;
+pub type PTOKEN_PRIVILEGES = *mut _TOKEN_PRIVILEGES;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_OWNER {
+    pub Owner: PSID,
+}
+pub type TOKEN_OWNER = _TOKEN_OWNER;
+pub type PTOKEN_OWNER = *mut _TOKEN_OWNER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_PRIMARY_GROUP {
+    pub PrimaryGroup: PSID,
+}
+pub type TOKEN_PRIMARY_GROUP = _TOKEN_PRIMARY_GROUP;
+pub type PTOKEN_PRIMARY_GROUP = *mut _TOKEN_PRIMARY_GROUP;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_DEFAULT_DACL {
+    pub DefaultDacl: PACL,
+}
+pub type TOKEN_DEFAULT_DACL = _TOKEN_DEFAULT_DACL;
+pub type PTOKEN_DEFAULT_DACL = *mut _TOKEN_DEFAULT_DACL;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_USER_CLAIMS {
+    pub UserClaims: PCLAIMS_BLOB,
+}
+pub type TOKEN_USER_CLAIMS = _TOKEN_USER_CLAIMS;
+pub type PTOKEN_USER_CLAIMS = *mut _TOKEN_USER_CLAIMS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_DEVICE_CLAIMS {
+    pub DeviceClaims: PCLAIMS_BLOB,
+}
+pub type TOKEN_DEVICE_CLAIMS = _TOKEN_DEVICE_CLAIMS;
+pub type PTOKEN_DEVICE_CLAIMS = *mut _TOKEN_DEVICE_CLAIMS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_GROUPS_AND_PRIVILEGES {
+    pub SidCount: ULONG,
+    pub SidLength: ULONG,
+    pub Sids: PSID_AND_ATTRIBUTES,
+    pub RestrictedSidCount: ULONG,
+    pub RestrictedSidLength: ULONG,
+    pub RestrictedSids: PSID_AND_ATTRIBUTES,
+    pub PrivilegeCount: ULONG,
+    pub PrivilegeLength: ULONG,
+    pub Privileges: PLUID_AND_ATTRIBUTES,
+    pub AuthenticationId: LUID,
+}
+pub type TOKEN_GROUPS_AND_PRIVILEGES = _TOKEN_GROUPS_AND_PRIVILEGES;
+pub type PTOKEN_GROUPS_AND_PRIVILEGES = *mut _TOKEN_GROUPS_AND_PRIVILEGES;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_LINKED_TOKEN {
+    pub LinkedToken: HANDLE,
+}
+pub type TOKEN_LINKED_TOKEN = _TOKEN_LINKED_TOKEN;
+pub type PTOKEN_LINKED_TOKEN = *mut _TOKEN_LINKED_TOKEN;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_ELEVATION {
+    pub TokenIsElevated: ULONG,
+}
+pub type TOKEN_ELEVATION = _TOKEN_ELEVATION;
+pub type PTOKEN_ELEVATION = *mut _TOKEN_ELEVATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_MANDATORY_LABEL {
+    pub Label: SID_AND_ATTRIBUTES,
+}
+pub type TOKEN_MANDATORY_LABEL = _TOKEN_MANDATORY_LABEL;
+pub type PTOKEN_MANDATORY_LABEL = *mut _TOKEN_MANDATORY_LABEL;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_MANDATORY_POLICY {
+    pub Policy: ULONG,
+}
+pub type TOKEN_MANDATORY_POLICY = _TOKEN_MANDATORY_POLICY;
+pub type PTOKEN_MANDATORY_POLICY = *mut _TOKEN_MANDATORY_POLICY;
+pub type PSECURITY_ATTRIBUTES_OPAQUE = PVOID;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_ACCESS_INFORMATION {
+    pub SidHash: PSID_AND_ATTRIBUTES_HASH,
+    pub RestrictedSidHash: PSID_AND_ATTRIBUTES_HASH,
+    pub Privileges: PTOKEN_PRIVILEGES,
+    pub AuthenticationId: LUID,
+    pub TokenType: TOKEN_TYPE,
+    pub ImpersonationLevel: SECURITY_IMPERSONATION_LEVEL,
+    pub MandatoryPolicy: TOKEN_MANDATORY_POLICY,
+    pub Flags: ULONG,
+    pub AppContainerNumber: ULONG,
+    pub PackageSid: PSID,
+    pub CapabilitiesHash: PSID_AND_ATTRIBUTES_HASH,
+    pub TrustLevelSid: PSID,
+    pub SecurityAttributes: PSECURITY_ATTRIBUTES_OPAQUE,
+}
+pub type TOKEN_ACCESS_INFORMATION = _TOKEN_ACCESS_INFORMATION;
+pub type PTOKEN_ACCESS_INFORMATION = *mut _TOKEN_ACCESS_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_AUDIT_POLICY {
+    pub PerUserPolicy: [UCHAR; 30usize],
+}
+pub type TOKEN_AUDIT_POLICY = _TOKEN_AUDIT_POLICY;
+pub type PTOKEN_AUDIT_POLICY = *mut _TOKEN_AUDIT_POLICY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_SOURCE {
+    pub SourceName: [CHAR; 8usize],
+    pub SourceIdentifier: LUID,
+}
+pub type TOKEN_SOURCE = _TOKEN_SOURCE;
+pub type PTOKEN_SOURCE = *mut _TOKEN_SOURCE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _TOKEN_STATISTICS {
+    pub TokenId: LUID,
+    pub AuthenticationId: LUID,
+    pub ExpirationTime: LARGE_INTEGER,
+    pub TokenType: TOKEN_TYPE,
+    pub ImpersonationLevel: SECURITY_IMPERSONATION_LEVEL,
+    pub DynamicCharged: ULONG,
+    pub DynamicAvailable: ULONG,
+    pub GroupCount: ULONG,
+    pub PrivilegeCount: ULONG,
+    pub ModifiedId: LUID,
+}
+pub type TOKEN_STATISTICS = _TOKEN_STATISTICS;
+pub type PTOKEN_STATISTICS = *mut _TOKEN_STATISTICS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_CONTROL {
+    pub TokenId: LUID,
+    pub AuthenticationId: LUID,
+    pub ModifiedId: LUID,
+    pub TokenSource: TOKEN_SOURCE,
+}
+pub type TOKEN_CONTROL = _TOKEN_CONTROL;
+pub type PTOKEN_CONTROL = *mut _TOKEN_CONTROL;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_ORIGIN {
+    pub OriginatingLogonSession: LUID,
+}
+pub type TOKEN_ORIGIN = _TOKEN_ORIGIN;
+pub type PTOKEN_ORIGIN = *mut _TOKEN_ORIGIN;
+pub const _MANDATORY_LEVEL_MandatoryLevelUntrusted: _MANDATORY_LEVEL = 0;
+pub const _MANDATORY_LEVEL_MandatoryLevelLow: _MANDATORY_LEVEL = 1;
+pub const _MANDATORY_LEVEL_MandatoryLevelMedium: _MANDATORY_LEVEL = 2;
+pub const _MANDATORY_LEVEL_MandatoryLevelHigh: _MANDATORY_LEVEL = 3;
+pub const _MANDATORY_LEVEL_MandatoryLevelSystem: _MANDATORY_LEVEL = 4;
+pub const _MANDATORY_LEVEL_MandatoryLevelSecureProcess: _MANDATORY_LEVEL = 5;
+pub const _MANDATORY_LEVEL_MandatoryLevelCount: _MANDATORY_LEVEL = 6;
+pub type _MANDATORY_LEVEL = core::ffi::c_int;
+pub use self::_MANDATORY_LEVEL as MANDATORY_LEVEL;
+pub type PMANDATORY_LEVEL = *mut _MANDATORY_LEVEL;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_APPCONTAINER_INFORMATION {
+    pub TokenAppContainer: PSID,
+}
+pub type TOKEN_APPCONTAINER_INFORMATION = _TOKEN_APPCONTAINER_INFORMATION;
+pub type PTOKEN_APPCONTAINER_INFORMATION = *mut _TOKEN_APPCONTAINER_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_SID_INFORMATION {
+    pub Sid: PSID,
+}
+pub type TOKEN_SID_INFORMATION = _TOKEN_SID_INFORMATION;
+pub type PTOKEN_SID_INFORMATION = *mut _TOKEN_SID_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TOKEN_BNO_ISOLATION_INFORMATION {
+    pub IsolationPrefix: PWSTR,
+    pub IsolationEnabled: BOOLEAN,
+}
+pub type TOKEN_BNO_ISOLATION_INFORMATION = _TOKEN_BNO_ISOLATION_INFORMATION;
+pub type PTOKEN_BNO_ISOLATION_INFORMATION = *mut _TOKEN_BNO_ISOLATION_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CLAIM_SECURITY_ATTRIBUTE_FQBN_VALUE {
+    pub Version: ULONG64,
+    pub Name: PWSTR,
+}
+pub type CLAIM_SECURITY_ATTRIBUTE_FQBN_VALUE = _CLAIM_SECURITY_ATTRIBUTE_FQBN_VALUE;
+pub type PCLAIM_SECURITY_ATTRIBUTE_FQBN_VALUE = *mut _CLAIM_SECURITY_ATTRIBUTE_FQBN_VALUE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CLAIM_SECURITY_ATTRIBUTE_OCTET_STRING_VALUE {
+    pub pValue: PVOID,
+    pub ValueLength: ULONG,
+}
+pub type CLAIM_SECURITY_ATTRIBUTE_OCTET_STRING_VALUE = _CLAIM_SECURITY_ATTRIBUTE_OCTET_STRING_VALUE;
+pub type PCLAIM_SECURITY_ATTRIBUTE_OCTET_STRING_VALUE =
+*mut _CLAIM_SECURITY_ATTRIBUTE_OCTET_STRING_VALUE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _CLAIM_SECURITY_ATTRIBUTE_V1 {
+    pub Name: PWSTR,
+    pub ValueType: USHORT,
+    pub Reserved: USHORT,
+    pub Flags: ULONG,
+    pub ValueCount: ULONG,
+    pub Values: _CLAIM_SECURITY_ATTRIBUTE_V1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _CLAIM_SECURITY_ATTRIBUTE_V1__bindgen_ty_1 {
+    pub pInt64: PLONG64,
+    pub pUint64: PULONG64,
+    pub ppString: *mut PWSTR,
+    pub pFqbn: PCLAIM_SECURITY_ATTRIBUTE_FQBN_VALUE,
+    pub pOctetString: PCLAIM_SECURITY_ATTRIBUTE_OCTET_STRING_VALUE,
+}
+pub type CLAIM_SECURITY_ATTRIBUTE_V1 = _CLAIM_SECURITY_ATTRIBUTE_V1;
+pub type PCLAIM_SECURITY_ATTRIBUTE_V1 = *mut _CLAIM_SECURITY_ATTRIBUTE_V1;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1 {
+    pub Name: ULONG,
+    pub ValueType: USHORT,
+    pub Reserved: USHORT,
+    pub Flags: ULONG,
+    pub ValueCount: ULONG,
+    pub Values: _CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1__bindgen_ty_1 {
+    pub pInt64: [ULONG; 1usize],
+    pub pUint64: [ULONG; 1usize],
+    pub ppString: [ULONG; 1usize],
+    pub pFqbn: [ULONG; 1usize],
+    pub pOctetString: [ULONG; 1usize],
+}
+pub type CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1 = _CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1;
+pub type PCLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1 = *mut _CLAIM_SECURITY_ATTRIBUTE_RELATIVE_V1;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _CLAIM_SECURITY_ATTRIBUTES_INFORMATION {
+    pub Version: USHORT,
+    pub Reserved: USHORT,
+    pub AttributeCount: ULONG,
+    pub Attribute: _CLAIM_SECURITY_ATTRIBUTES_INFORMATION__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _CLAIM_SECURITY_ATTRIBUTES_INFORMATION__bindgen_ty_1 {
+    pub pAttributeV1: PCLAIM_SECURITY_ATTRIBUTE_V1,
+}
+pub type CLAIM_SECURITY_ATTRIBUTES_INFORMATION = _CLAIM_SECURITY_ATTRIBUTES_INFORMATION;
+pub type PCLAIM_SECURITY_ATTRIBUTES_INFORMATION = *mut _CLAIM_SECURITY_ATTRIBUTES_INFORMATION;
+unsafe extern "C" {
+    pub fn NtOpenThreadToken(
+        ThreadHandle: HANDLE,
+        DesiredAccess: ACCESS_MASK,
+        OpenAsSelf: BOOLEAN,
+        TokenHandle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtOpenThreadTokenEx(
+        ThreadHandle: HANDLE,
+        DesiredAccess: ACCESS_MASK,
+        OpenAsSelf: BOOLEAN,
+        HandleAttributes: ULONG,
+        TokenHandle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtOpenProcessToken(
+        ProcessHandle: HANDLE,
+        DesiredAccess: ACCESS_MASK,
+        TokenHandle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtOpenProcessTokenEx(
+        ProcessHandle: HANDLE,
+        DesiredAccess: ACCESS_MASK,
+        HandleAttributes: ULONG,
+        TokenHandle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtOpenJobObjectToken(
+        JobHandle: HANDLE,
+        DesiredAccess: ACCESS_MASK,
+        TokenHandle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtDuplicateToken(
+        ExistingTokenHandle: HANDLE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectAttributes: POBJECT_ATTRIBUTES,
+        EffectiveOnly: BOOLEAN,
+        TokenType: TOKEN_TYPE,
+        NewTokenHandle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtFilterToken(
+        ExistingTokenHandle: HANDLE,
+        Flags: ULONG,
+        SidsToDisable: PTOKEN_GROUPS,
+        PrivilegesToDelete: PTOKEN_PRIVILEGES,
+        RestrictedSids: PTOKEN_GROUPS,
+        NewTokenHandle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtImpersonateAnonymousToken(ThreadHandle: HANDLE) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtQueryInformationToken(
+        TokenHandle: HANDLE,
+        TokenInformationClass: TOKEN_INFORMATION_CLASS,
+        TokenInformation: PVOID,
+        TokenInformationLength: ULONG,
+        ReturnLength: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtSetInformationToken(
+        TokenHandle: HANDLE,
+        TokenInformationClass: TOKEN_INFORMATION_CLASS,
+        TokenInformation: PVOID,
+        TokenInformationLength: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtAdjustPrivilegesToken(
+        TokenHandle: HANDLE,
+        DisableAllPrivileges: BOOLEAN,
+        NewState: PTOKEN_PRIVILEGES,
+        BufferLength: ULONG,
+        PreviousState: PTOKEN_PRIVILEGES,
+        ReturnLength: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtAdjustGroupsToken(
+        TokenHandle: HANDLE,
+        ResetToDefault: BOOLEAN,
+        NewState: PTOKEN_GROUPS,
+        BufferLength: ULONG,
+        PreviousState: PTOKEN_GROUPS,
+        ReturnLength: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtPrivilegeCheck(
+        ClientToken: HANDLE,
+        RequiredPrivileges: PPRIVILEGE_SET,
+        Result: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtAccessCheckAndAuditAlarm(
+        SubsystemName: PUNICODE_STRING,
+        HandleId: PVOID,
+        ObjectTypeName: PUNICODE_STRING,
+        ObjectName: PUNICODE_STRING,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        DesiredAccess: ACCESS_MASK,
+        GenericMapping: PGENERIC_MAPPING,
+        ObjectCreation: BOOLEAN,
+        GrantedAccess: PACCESS_MASK,
+        AccessStatus: PNTSTATUS,
+        GenerateOnClose: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtAccessCheckByTypeAndAuditAlarm(
+        SubsystemName: PUNICODE_STRING,
+        HandleId: PVOID,
+        ObjectTypeName: PUNICODE_STRING,
+        ObjectName: PUNICODE_STRING,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        PrincipalSelfSid: PSID,
+        DesiredAccess: ACCESS_MASK,
+        AuditType: AUDIT_EVENT_TYPE,
+        Flags: ULONG,
+        ObjectTypeList: POBJECT_TYPE_LIST,
+        ObjectTypeListLength: ULONG,
+        GenericMapping: PGENERIC_MAPPING,
+        ObjectCreation: BOOLEAN,
+        GrantedAccess: PACCESS_MASK,
+        AccessStatus: PNTSTATUS,
+        GenerateOnClose: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtAccessCheckByTypeResultListAndAuditAlarm(
+        SubsystemName: PUNICODE_STRING,
+        HandleId: PVOID,
+        ObjectTypeName: PUNICODE_STRING,
+        ObjectName: PUNICODE_STRING,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        PrincipalSelfSid: PSID,
+        DesiredAccess: ACCESS_MASK,
+        AuditType: AUDIT_EVENT_TYPE,
+        Flags: ULONG,
+        ObjectTypeList: POBJECT_TYPE_LIST,
+        ObjectTypeListLength: ULONG,
+        GenericMapping: PGENERIC_MAPPING,
+        ObjectCreation: BOOLEAN,
+        GrantedAccess: PACCESS_MASK,
+        AccessStatus: PNTSTATUS,
+        GenerateOnClose: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtAccessCheckByTypeResultListAndAuditAlarmByHandle(
+        SubsystemName: PUNICODE_STRING,
+        HandleId: PVOID,
+        ClientToken: HANDLE,
+        ObjectTypeName: PUNICODE_STRING,
+        ObjectName: PUNICODE_STRING,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        PrincipalSelfSid: PSID,
+        DesiredAccess: ACCESS_MASK,
+        AuditType: AUDIT_EVENT_TYPE,
+        Flags: ULONG,
+        ObjectTypeList: POBJECT_TYPE_LIST,
+        ObjectTypeListLength: ULONG,
+        GenericMapping: PGENERIC_MAPPING,
+        ObjectCreation: BOOLEAN,
+        GrantedAccess: PACCESS_MASK,
+        AccessStatus: PNTSTATUS,
+        GenerateOnClose: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtOpenObjectAuditAlarm(
+        SubsystemName: PUNICODE_STRING,
+        HandleId: PVOID,
+        ObjectTypeName: PUNICODE_STRING,
+        ObjectName: PUNICODE_STRING,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        ClientToken: HANDLE,
+        DesiredAccess: ACCESS_MASK,
+        GrantedAccess: ACCESS_MASK,
+        Privileges: PPRIVILEGE_SET,
+        ObjectCreation: BOOLEAN,
+        AccessGranted: BOOLEAN,
+        GenerateOnClose: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtPrivilegeObjectAuditAlarm(
+        SubsystemName: PUNICODE_STRING,
+        HandleId: PVOID,
+        ClientToken: HANDLE,
+        DesiredAccess: ACCESS_MASK,
+        Privileges: PPRIVILEGE_SET,
+        AccessGranted: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtCloseObjectAuditAlarm(
+        SubsystemName: PUNICODE_STRING,
+        HandleId: PVOID,
+        GenerateOnClose: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtDeleteObjectAuditAlarm(
+        SubsystemName: PUNICODE_STRING,
+        HandleId: PVOID,
+        GenerateOnClose: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtPrivilegedServiceAuditAlarm(
+        SubsystemName: PUNICODE_STRING,
+        ServiceName: PUNICODE_STRING,
+        ClientToken: HANDLE,
+        Privileges: PPRIVILEGE_SET,
+        AccessGranted: BOOLEAN,
+    ) -> NTSTATUS;
+}
+pub type PRTL_HEAP_COMMIT_ROUTINE = ::core::option::Option<
+    unsafe extern "C" fn(arg1: PVOID, arg2: *mut PVOID, arg3: PSIZE_T) -> NTSTATUS,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _RTL_HEAP_PARAMETERS {
+    pub Length: ULONG,
+    pub SegmentReserve: SIZE_T,
+    pub SegmentCommit: SIZE_T,
+    pub DeCommitFreeBlockThreshold: SIZE_T,
+    pub DeCommitTotalFreeThreshold: SIZE_T,
+    pub MaximumAllocationSize: SIZE_T,
+    pub VirtualMemoryThreshold: SIZE_T,
+    pub InitialCommit: SIZE_T,
+    pub InitialReserve: SIZE_T,
+    pub CommitRoutine: PRTL_HEAP_COMMIT_ROUTINE,
+    pub Reserved: [SIZE_T; 2usize],
+}
+pub type RTL_HEAP_PARAMETERS = _RTL_HEAP_PARAMETERS;
+pub type PRTL_HEAP_PARAMETERS = *mut _RTL_HEAP_PARAMETERS;
+pub const _RTL_MEMORY_TYPE_MemoryTypePaged: _RTL_MEMORY_TYPE = 0;
+pub const _RTL_MEMORY_TYPE_MemoryTypeNonPaged: _RTL_MEMORY_TYPE = 1;
+pub const _RTL_MEMORY_TYPE_MemoryType64KPage: _RTL_MEMORY_TYPE = 2;
+pub const _RTL_MEMORY_TYPE_MemoryTypeLargePage: _RTL_MEMORY_TYPE = 3;
+pub const _RTL_MEMORY_TYPE_MemoryTypeHugePage: _RTL_MEMORY_TYPE = 4;
+pub const _RTL_MEMORY_TYPE_MemoryTypeCustom: _RTL_MEMORY_TYPE = 5;
+pub const _RTL_MEMORY_TYPE_MemoryTypeMax: _RTL_MEMORY_TYPE = 6;
+pub type _RTL_MEMORY_TYPE = core::ffi::c_int;
+pub use self::_RTL_MEMORY_TYPE as RTL_MEMORY_TYPE;
+pub type PRTL_MEMORY_TYPE = *mut _RTL_MEMORY_TYPE;
+pub const _HEAP_MEMORY_INFO_CLASS_HeapMemoryBasicInformation: _HEAP_MEMORY_INFO_CLASS = 0;
+pub type _HEAP_MEMORY_INFO_CLASS = core::ffi::c_int;
+pub use self::_HEAP_MEMORY_INFO_CLASS as HEAP_MEMORY_INFO_CLASS;
+pub type ALLOCATE_VIRTUAL_MEMORY_EX_CALLBACK = ::core::option::Option<
+    unsafe extern "C" fn(
+        CallbackContext: HANDLE,
+        ProcessHandle: HANDLE,
+        BaseAddress: *mut PVOID,
+        RegionSize: PSIZE_T,
+        AllocationType: ULONG,
+        PageProtection: ULONG,
+        ExtendedParameters: PMEM_EXTENDED_PARAMETER,
+        ExtendedParameterCount: ULONG,
+    ) -> NTSTATUS,
+>;
+pub type PALLOCATE_VIRTUAL_MEMORY_EX_CALLBACK = ALLOCATE_VIRTUAL_MEMORY_EX_CALLBACK;
+pub type FREE_VIRTUAL_MEMORY_EX_CALLBACK = ::core::option::Option<
+    unsafe extern "C" fn(
+        CallbackContext: HANDLE,
+        ProcessHandle: HANDLE,
+        BaseAddress: *mut PVOID,
+        RegionSize: PSIZE_T,
+        FreeType: ULONG,
+    ) -> NTSTATUS,
+>;
+pub type PFREE_VIRTUAL_MEMORY_EX_CALLBACK = FREE_VIRTUAL_MEMORY_EX_CALLBACK;
+pub type QUERY_VIRTUAL_MEMORY_CALLBACK = ::core::option::Option<
+    unsafe extern "C" fn(
+        CallbackContext: HANDLE,
+        ProcessHandle: HANDLE,
+        BaseAddress: PVOID,
+        MemoryInformationClass: HEAP_MEMORY_INFO_CLASS,
+        MemoryInformation: PVOID,
+        MemoryInformationLength: SIZE_T,
+        ReturnLength: PSIZE_T,
+    ) -> NTSTATUS,
+>;
+pub type PQUERY_VIRTUAL_MEMORY_CALLBACK = QUERY_VIRTUAL_MEMORY_CALLBACK;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _RTL_SEGMENT_HEAP_VA_CALLBACKS {
+    pub CallbackContext: HANDLE,
+    pub AllocateVirtualMemory: PALLOCATE_VIRTUAL_MEMORY_EX_CALLBACK,
+    pub FreeVirtualMemory: PFREE_VIRTUAL_MEMORY_EX_CALLBACK,
+    pub QueryVirtualMemory: PQUERY_VIRTUAL_MEMORY_CALLBACK,
+}
+pub type RTL_SEGMENT_HEAP_VA_CALLBACKS = _RTL_SEGMENT_HEAP_VA_CALLBACKS;
+pub type PRTL_SEGMENT_HEAP_VA_CALLBACKS = *mut _RTL_SEGMENT_HEAP_VA_CALLBACKS;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _RTL_SEGMENT_HEAP_MEMORY_SOURCE {
+    pub Flags: ULONG,
+    pub MemoryTypeMask: ULONG,
+    pub NumaNode: ULONG,
+    pub __bindgen_anon_1: _RTL_SEGMENT_HEAP_MEMORY_SOURCE__bindgen_ty_1,
+    pub Reserved: [SIZE_T; 2usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _RTL_SEGMENT_HEAP_MEMORY_SOURCE__bindgen_ty_1 {
+    pub PartitionHandle: HANDLE,
+    pub Callbacks: *mut RTL_SEGMENT_HEAP_VA_CALLBACKS,
+}
+pub type RTL_SEGMENT_HEAP_MEMORY_SOURCE = _RTL_SEGMENT_HEAP_MEMORY_SOURCE;
+pub type PRTL_SEGMENT_HEAP_MEMORY_SOURCE = *mut _RTL_SEGMENT_HEAP_MEMORY_SOURCE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _RTL_SEGMENT_HEAP_PARAMETERS {
+    pub Version: USHORT,
+    pub Size: USHORT,
+    pub Flags: ULONG,
+    pub MemorySource: RTL_SEGMENT_HEAP_MEMORY_SOURCE,
+    pub Reserved: [SIZE_T; 4usize],
+}
+pub type RTL_SEGMENT_HEAP_PARAMETERS = _RTL_SEGMENT_HEAP_PARAMETERS;
+pub type PRTL_SEGMENT_HEAP_PARAMETERS = *mut _RTL_SEGMENT_HEAP_PARAMETERS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _RTL_HEAP_MEMORY_LIMIT_DATA {
+    pub CommitLimitBytes: SIZE_T,
+    pub CommitLimitFailureCode: ULONG_PTR,
+    pub MaxAllocationSizeBytes: SIZE_T,
+    pub AllocationLimitFailureCode: ULONG_PTR,
+}
+pub type RTL_HEAP_MEMORY_LIMIT_DATA = _RTL_HEAP_MEMORY_LIMIT_DATA;
+pub type PRTL_HEAP_MEMORY_LIMIT_DATA = *mut _RTL_HEAP_MEMORY_LIMIT_DATA;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _RTL_HEAP_MEMORY_LIMIT_INFO {
+    pub Version: ULONG,
+    pub Data: RTL_HEAP_MEMORY_LIMIT_DATA,
+}
+pub type RTL_HEAP_MEMORY_LIMIT_INFO = _RTL_HEAP_MEMORY_LIMIT_INFO;
+pub type PRTL_HEAP_MEMORY_LIMIT_INFO = *mut _RTL_HEAP_MEMORY_LIMIT_INFO;
+unsafe extern "C" {
+    pub fn RtlCreateHeap(
+        Flags: ULONG,
+        HeapBase: PVOID,
+        ReserveSize: SIZE_T,
+        CommitSize: SIZE_T,
+        Lock: PVOID,
+        Parameters: PRTL_HEAP_PARAMETERS,
+    ) -> PVOID;
+}
+unsafe extern "C" {
+    pub fn RtlDestroyHeap(HeapHandle: PVOID) -> PVOID;
+}
+unsafe extern "C" {
+    pub fn RtlAllocateHeap(HeapHandle: PVOID, Flags: ULONG, Size: SIZE_T) -> PVOID;
+}
+unsafe extern "C" {
+    pub fn RtlFreeHeap(HeapHandle: PVOID, Flags: ULONG, BaseAddress: PVOID) -> LOGICAL;
+}
+unsafe extern "C" {
+    pub fn RtlRandom(Seed: PULONG) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn RtlRandomEx(Seed: PULONG) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn RtlInitUnicodeStringEx(
+        DestinationString: PUNICODE_STRING,
+        SourceString: PCWSTR,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlCreateUnicodeString(
+        DestinationString: PUNICODE_STRING,
+        SourceString: PCWSTR,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn RtlPrefixString(
+        String1: *const STRING,
+        String2: *const STRING,
+        CaseInSensitive: BOOLEAN,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn RtlAppendStringToString(Destination: PSTRING, Source: *const STRING) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlOemStringToUnicodeString(
+        DestinationString: PUNICODE_STRING,
+        SourceString: PCOEM_STRING,
+        AllocateDestinationString: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlUnicodeStringToOemString(
+        DestinationString: POEM_STRING,
+        SourceString: PCUNICODE_STRING,
+        AllocateDestinationString: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlUpcaseUnicodeStringToOemString(
+        DestinationString: POEM_STRING,
+        SourceString: PCUNICODE_STRING,
+        AllocateDestinationString: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlOemStringToCountedUnicodeString(
+        DestinationString: PUNICODE_STRING,
+        SourceString: PCOEM_STRING,
+        AllocateDestinationString: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlUnicodeStringToCountedOemString(
+        DestinationString: POEM_STRING,
+        SourceString: PCUNICODE_STRING,
+        AllocateDestinationString: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlUpcaseUnicodeStringToCountedOemString(
+        DestinationString: POEM_STRING,
+        SourceString: PCUNICODE_STRING,
+        AllocateDestinationString: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlValidateUnicodeString(Flags: ULONG, String: PCUNICODE_STRING) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlDuplicateUnicodeString(
+        Flags: ULONG,
+        StringIn: PCUNICODE_STRING,
+        StringOut: PUNICODE_STRING,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlDowncaseUnicodeString(
+        DestinationString: PUNICODE_STRING,
+        SourceString: PCUNICODE_STRING,
+        AllocateDestinationString: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlFreeOemString(OemString: POEM_STRING);
+}
+unsafe extern "C" {
+    pub fn RtlxUnicodeStringToOemSize(UnicodeString: PCUNICODE_STRING) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn RtlxOemStringToUnicodeSize(OemString: PCOEM_STRING) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn RtlMultiByteToUnicodeN(
+        UnicodeString: PWCH,
+        MaxBytesInUnicodeString: ULONG,
+        BytesInUnicodeString: PULONG,
+        MultiByteString: *const CHAR,
+        BytesInMultiByteString: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlMultiByteToUnicodeSize(
+        BytesInUnicodeString: PULONG,
+        MultiByteString: *const CHAR,
+        BytesInMultiByteString: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlUnicodeToMultiByteSize(
+        BytesInMultiByteString: PULONG,
+        UnicodeString: PCWCH,
+        BytesInUnicodeString: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlUnicodeToMultiByteN(
+        MultiByteString: PCHAR,
+        MaxBytesInMultiByteString: ULONG,
+        BytesInMultiByteString: PULONG,
+        UnicodeString: PCWCH,
+        BytesInUnicodeString: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlUpcaseUnicodeToMultiByteN(
+        MultiByteString: PCHAR,
+        MaxBytesInMultiByteString: ULONG,
+        BytesInMultiByteString: PULONG,
+        UnicodeString: PCWCH,
+        BytesInUnicodeString: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlOemToUnicodeN(
+        UnicodeString: PWCH,
+        MaxBytesInUnicodeString: ULONG,
+        BytesInUnicodeString: PULONG,
+        OemString: PCCH,
+        BytesInOemString: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlUnicodeToOemN(
+        OemString: PCHAR,
+        MaxBytesInOemString: ULONG,
+        BytesInOemString: PULONG,
+        UnicodeString: PCWCH,
+        BytesInUnicodeString: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlUpcaseUnicodeToOemN(
+        OemString: PCHAR,
+        MaxBytesInOemString: ULONG,
+        BytesInOemString: PULONG,
+        UnicodeString: PCWCH,
+        BytesInUnicodeString: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlNormalizeString(
+        NormForm: ULONG,
+        SourceString: PCWSTR,
+        SourceStringLength: LONG,
+        DestinationString: PWSTR,
+        DestinationStringLength: PLONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlIsNormalizedString(
+        NormForm: ULONG,
+        SourceString: PCWSTR,
+        SourceStringLength: LONG,
+        Normalized: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlIdnToAscii(
+        Flags: ULONG,
+        SourceString: PCWSTR,
+        SourceStringLength: LONG,
+        DestinationString: PWSTR,
+        DestinationStringLength: PLONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlIdnToUnicode(
+        Flags: ULONG,
+        SourceString: PCWSTR,
+        SourceStringLength: LONG,
+        DestinationString: PWSTR,
+        DestinationStringLength: PLONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlIdnToNameprepUnicode(
+        Flags: ULONG,
+        SourceString: PCWSTR,
+        SourceStringLength: LONG,
+        DestinationString: PWSTR,
+        DestinationStringLength: PLONG,
+    ) -> NTSTATUS;
+}
+pub type PRTL_ALLOCATE_STRING_ROUTINE =
+::core::option::Option<unsafe extern "C" fn(arg1: SIZE_T) -> PVOID>;
+pub type PRTL_REALLOCATE_STRING_ROUTINE =
+::core::option::Option<unsafe extern "C" fn(arg1: SIZE_T, arg2: PVOID) -> PVOID>;
+pub type PRTL_FREE_STRING_ROUTINE = ::core::option::Option<unsafe extern "C" fn(arg1: PVOID)>;
+unsafe extern "C" {
+    pub static RtlAllocateStringRoutine: PRTL_ALLOCATE_STRING_ROUTINE;
+}
+unsafe extern "C" {
+    pub static RtlFreeStringRoutine: PRTL_FREE_STRING_ROUTINE;
+}
+unsafe extern "C" {
+    pub static RtlReallocateStringRoutine: PRTL_REALLOCATE_STRING_ROUTINE;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _GENERATE_NAME_CONTEXT {
+    pub Checksum: USHORT,
+    pub ChecksumInserted: BOOLEAN,
+    pub NameLength: UCHAR,
+    pub NameBuffer: [WCHAR; 8usize],
+    pub ExtensionLength: ULONG,
+    pub ExtensionBuffer: [WCHAR; 4usize],
+    pub LastIndexValue: ULONG,
+}
+pub type GENERATE_NAME_CONTEXT = _GENERATE_NAME_CONTEXT;
+pub type PGENERATE_NAME_CONTEXT = *mut GENERATE_NAME_CONTEXT;
+unsafe extern "C" {
+    pub fn RtlGenerate8dot3Name(
+        Name: PCUNICODE_STRING,
+        AllowExtendedCharacters: BOOLEAN,
+        Context: PGENERATE_NAME_CONTEXT,
+        Name8dot3: PUNICODE_STRING,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlIsNameLegalDOS8Dot3(
+        Name: PCUNICODE_STRING,
+        OemName: POEM_STRING,
+        NameContainsSpaces: PBOOLEAN,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn RtlIsValidOemCharacter(Char: PWCHAR) -> BOOLEAN;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _PREFIX_TABLE_ENTRY {
+    pub NodeTypeCode: CSHORT,
+    pub NameLength: CSHORT,
+    pub NextPrefixTree: *mut _PREFIX_TABLE_ENTRY,
+    pub Links: RTL_SPLAY_LINKS,
+    pub Prefix: PSTRING,
+}
+pub type PREFIX_TABLE_ENTRY = _PREFIX_TABLE_ENTRY;
+pub type PPREFIX_TABLE_ENTRY = *mut PREFIX_TABLE_ENTRY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _PREFIX_TABLE {
+    pub NodeTypeCode: CSHORT,
+    pub NameLength: CSHORT,
+    pub NextPrefixTree: PPREFIX_TABLE_ENTRY,
+}
+pub type PREFIX_TABLE = _PREFIX_TABLE;
+pub type PPREFIX_TABLE = *mut PREFIX_TABLE;
+unsafe extern "C" {
+    pub fn PfxInitialize(PrefixTable: PPREFIX_TABLE);
+}
+unsafe extern "C" {
+    pub fn PfxInsertPrefix(
+        PrefixTable: PPREFIX_TABLE,
+        Prefix: PSTRING,
+        PrefixTableEntry: PPREFIX_TABLE_ENTRY,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn PfxRemovePrefix(PrefixTable: PPREFIX_TABLE, PrefixTableEntry: PPREFIX_TABLE_ENTRY);
+}
+unsafe extern "C" {
+    pub fn PfxFindPrefix(PrefixTable: PPREFIX_TABLE, FullName: PSTRING) -> PPREFIX_TABLE_ENTRY;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _UNICODE_PREFIX_TABLE_ENTRY {
+    pub NodeTypeCode: CSHORT,
+    pub NameLength: CSHORT,
+    pub NextPrefixTree: *mut _UNICODE_PREFIX_TABLE_ENTRY,
+    pub CaseMatch: *mut _UNICODE_PREFIX_TABLE_ENTRY,
+    pub Links: RTL_SPLAY_LINKS,
+    pub Prefix: PUNICODE_STRING,
+}
+pub type UNICODE_PREFIX_TABLE_ENTRY = _UNICODE_PREFIX_TABLE_ENTRY;
+pub type PUNICODE_PREFIX_TABLE_ENTRY = *mut UNICODE_PREFIX_TABLE_ENTRY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _UNICODE_PREFIX_TABLE {
+    pub NodeTypeCode: CSHORT,
+    pub NameLength: CSHORT,
+    pub NextPrefixTree: PUNICODE_PREFIX_TABLE_ENTRY,
+    pub LastNextEntry: PUNICODE_PREFIX_TABLE_ENTRY,
+}
+pub type UNICODE_PREFIX_TABLE = _UNICODE_PREFIX_TABLE;
+pub type PUNICODE_PREFIX_TABLE = *mut UNICODE_PREFIX_TABLE;
+unsafe extern "C" {
+    pub fn RtlInitializeUnicodePrefix(PrefixTable: PUNICODE_PREFIX_TABLE);
+}
+unsafe extern "C" {
+    pub fn RtlInsertUnicodePrefix(
+        PrefixTable: PUNICODE_PREFIX_TABLE,
+        Prefix: PUNICODE_STRING,
+        PrefixTableEntry: PUNICODE_PREFIX_TABLE_ENTRY,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn RtlRemoveUnicodePrefix(
+        PrefixTable: PUNICODE_PREFIX_TABLE,
+        PrefixTableEntry: PUNICODE_PREFIX_TABLE_ENTRY,
+    );
+}
+unsafe extern "C" {
+    pub fn RtlFindUnicodePrefix(
+        PrefixTable: PUNICODE_PREFIX_TABLE,
+        FullName: PCUNICODE_STRING,
+        CaseInsensitiveIndex: ULONG,
+    ) -> PUNICODE_PREFIX_TABLE_ENTRY;
+}
+unsafe extern "C" {
+    pub fn RtlNextUnicodePrefix(
+        PrefixTable: PUNICODE_PREFIX_TABLE,
+        Restart: BOOLEAN,
+    ) -> PUNICODE_PREFIX_TABLE_ENTRY;
+}
+pub type COMPRESSED_DATA_INFO = _COMPRESSED_DATA_INFO;
+pub type PCOMPRESSED_DATA_INFO = *mut COMPRESSED_DATA_INFO;
+unsafe extern "C" {
+    pub fn RtlGetCompressionWorkSpaceSize(
+        CompressionFormatAndEngine: USHORT,
+        CompressBufferWorkSpaceSize: PULONG,
+        CompressFragmentWorkSpaceSize: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlCompressBuffer(
+        CompressionFormatAndEngine: USHORT,
+        UncompressedBuffer: PUCHAR,
+        UncompressedBufferSize: ULONG,
+        CompressedBuffer: PUCHAR,
+        CompressedBufferSize: ULONG,
+        UncompressedChunkSize: ULONG,
+        FinalCompressedSize: PULONG,
+        WorkSpace: PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlDecompressBuffer(
+        CompressionFormat: USHORT,
+        UncompressedBuffer: PUCHAR,
+        UncompressedBufferSize: ULONG,
+        CompressedBuffer: PUCHAR,
+        CompressedBufferSize: ULONG,
+        FinalUncompressedSize: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlDecompressBufferEx(
+        CompressionFormat: USHORT,
+        UncompressedBuffer: PUCHAR,
+        UncompressedBufferSize: ULONG,
+        CompressedBuffer: PUCHAR,
+        CompressedBufferSize: ULONG,
+        FinalUncompressedSize: PULONG,
+        WorkSpace: PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlDecompressBufferEx2(
+        CompressionFormat: USHORT,
+        UncompressedBuffer: PUCHAR,
+        UncompressedBufferSize: ULONG,
+        CompressedBuffer: PUCHAR,
+        CompressedBufferSize: ULONG,
+        UncompressedChunkSize: ULONG,
+        FinalUncompressedSize: PULONG,
+        WorkSpace: PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlDecompressFragment(
+        CompressionFormat: USHORT,
+        UncompressedFragment: PUCHAR,
+        UncompressedFragmentSize: ULONG,
+        CompressedBuffer: PUCHAR,
+        CompressedBufferSize: ULONG,
+        FragmentOffset: ULONG,
+        FinalUncompressedSize: PULONG,
+        WorkSpace: PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlDecompressFragmentEx(
+        CompressionFormat: USHORT,
+        UncompressedFragment: PUCHAR,
+        UncompressedFragmentSize: ULONG,
+        CompressedBuffer: PUCHAR,
+        CompressedBufferSize: ULONG,
+        FragmentOffset: ULONG,
+        UncompressedChunkSize: ULONG,
+        FinalUncompressedSize: PULONG,
+        WorkSpace: PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlDescribeChunk(
+        CompressionFormat: USHORT,
+        CompressedBuffer: *mut PUCHAR,
+        EndOfCompressedBufferPlus1: PUCHAR,
+        ChunkBuffer: *mut PUCHAR,
+        ChunkSize: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlReserveChunk(
+        CompressionFormat: USHORT,
+        CompressedBuffer: *mut PUCHAR,
+        EndOfCompressedBufferPlus1: PUCHAR,
+        ChunkBuffer: *mut PUCHAR,
+        ChunkSize: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlDecompressChunks(
+        UncompressedBuffer: PUCHAR,
+        UncompressedBufferSize: ULONG,
+        CompressedBuffer: PUCHAR,
+        CompressedBufferSize: ULONG,
+        CompressedTail: PUCHAR,
+        CompressedTailSize: ULONG,
+        CompressedDataInfo: PCOMPRESSED_DATA_INFO,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlCompressChunks(
+        UncompressedBuffer: PUCHAR,
+        UncompressedBufferSize: ULONG,
+        CompressedBuffer: PUCHAR,
+        CompressedBufferSize: ULONG,
+        CompressedDataInfo: PCOMPRESSED_DATA_INFO,
+        CompressedDataInfoLength: ULONG,
+        WorkSpace: PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlCompareMemoryUlong(Source: PVOID, Length: SIZE_T, Pattern: ULONG) -> SIZE_T;
+}
+unsafe extern "C" {
+    pub fn RtlTimeToSecondsSince1980(Time: PLARGE_INTEGER, ElapsedSeconds: PULONG) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn RtlSecondsSince1980ToTime(ElapsedSeconds: ULONG, Time: PLARGE_INTEGER);
+}
+unsafe extern "C" {
+    pub fn RtlTimeToSecondsSince1970(Time: PLARGE_INTEGER, ElapsedSeconds: PULONG) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn RtlSecondsSince1970ToTime(ElapsedSeconds: ULONG, Time: PLARGE_INTEGER);
+}
+unsafe extern "C" {
+    pub fn RtlValidSid(Sid: PSID) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn RtlEqualSid(Sid1: PSID, Sid2: PSID) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn RtlEqualPrefixSid(Sid1: PSID, Sid2: PSID) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn RtlLengthRequiredSid(SubAuthorityCount: ULONG) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn RtlFreeSid(Sid: PSID) -> PVOID;
+}
+unsafe extern "C" {
+    pub fn RtlAllocateAndInitializeSid(
+        IdentifierAuthority: PSID_IDENTIFIER_AUTHORITY,
+        SubAuthorityCount: UCHAR,
+        SubAuthority0: ULONG,
+        SubAuthority1: ULONG,
+        SubAuthority2: ULONG,
+        SubAuthority3: ULONG,
+        SubAuthority4: ULONG,
+        SubAuthority5: ULONG,
+        SubAuthority6: ULONG,
+        SubAuthority7: ULONG,
+        Sid: *mut PSID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlAllocateAndInitializeSidEx(
+        IdentifierAuthority: PSID_IDENTIFIER_AUTHORITY,
+        SubAuthorityCount: UCHAR,
+        SubAuthorities: PULONG,
+        Sid: *mut PSID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlInitializeSid(
+        Sid: PSID,
+        IdentifierAuthority: PSID_IDENTIFIER_AUTHORITY,
+        SubAuthorityCount: UCHAR,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlInitializeSidEx(
+        Sid: PSID,
+        IdentifierAuthority: PSID_IDENTIFIER_AUTHORITY,
+        SubAuthorityCount: UCHAR,
+        ...
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlIdentifierAuthoritySid(Sid: PSID) -> PSID_IDENTIFIER_AUTHORITY;
+}
+unsafe extern "C" {
+    pub fn RtlSubAuthoritySid(Sid: PSID, SubAuthority: ULONG) -> PULONG;
+}
+unsafe extern "C" {
+    pub fn RtlSubAuthorityCountSid(Sid: PSID) -> PUCHAR;
+}
+unsafe extern "C" {
+    pub fn RtlLengthSid(Sid: PSID) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn RtlCopySid(
+        DestinationSidLength: ULONG,
+        DestinationSid: PSID,
+        SourceSid: PSID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlCreateServiceSid(
+        ServiceName: PUNICODE_STRING,
+        ServiceSid: PSID,
+        ServiceSidLength: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlGetSaclSecurityDescriptor(
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        SaclPresent: PBOOLEAN,
+        Sacl: *mut PACL,
+        SaclDefaulted: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlReplaceSidInSd(
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        OldSid: PSID,
+        NewSid: PSID,
+        NumChanges: *mut ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlCreateVirtualAccountSid(
+        Name: PCUNICODE_STRING,
+        BaseSubAuthority: ULONG,
+        Sid: PSID,
+        SidLength: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlConvertSidToUnicodeString(
+        UnicodeString: PUNICODE_STRING,
+        Sid: PSID,
+        AllocateDestinationString: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlCopyLuid(DestinationLuid: PLUID, SourceLuid: PLUID);
+}
+unsafe extern "C" {
+    pub fn RtlCreateAcl(Acl: PACL, AclLength: ULONG, AclRevision: ULONG) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlAddAce(
+        Acl: PACL,
+        AceRevision: ULONG,
+        StartingAceIndex: ULONG,
+        AceList: PVOID,
+        AceListLength: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlDeleteAce(Acl: PACL, AceIndex: ULONG) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlGetAce(Acl: PACL, AceIndex: ULONG, Ace: *mut PVOID) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlAddAccessAllowedAce(
+        Acl: PACL,
+        AceRevision: ULONG,
+        AccessMask: ACCESS_MASK,
+        Sid: PSID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlAddAccessAllowedAceEx(
+        Acl: PACL,
+        AceRevision: ULONG,
+        AceFlags: ULONG,
+        AccessMask: ACCESS_MASK,
+        Sid: PSID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlCreateSecurityDescriptorRelative(
+        SecurityDescriptor: PISECURITY_DESCRIPTOR_RELATIVE,
+        Revision: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlGetDaclSecurityDescriptor(
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        DaclPresent: PBOOLEAN,
+        Dacl: *mut PACL,
+        DaclDefaulted: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlSetOwnerSecurityDescriptor(
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        Owner: PSID,
+        OwnerDefaulted: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlSetGroupSecurityDescriptor(
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        Group: PSID,
+        GroupDefaulted: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlGetGroupSecurityDescriptor(
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        Group: *mut PSID,
+        GroupDefaulted: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlAbsoluteToSelfRelativeSD(
+        AbsoluteSecurityDescriptor: PSECURITY_DESCRIPTOR,
+        SelfRelativeSecurityDescriptor: PSECURITY_DESCRIPTOR,
+        BufferLength: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlSelfRelativeToAbsoluteSD(
+        SelfRelativeSecurityDescriptor: PSECURITY_DESCRIPTOR,
+        AbsoluteSecurityDescriptor: PSECURITY_DESCRIPTOR,
+        AbsoluteSecurityDescriptorSize: PULONG,
+        Dacl: PACL,
+        DaclSize: PULONG,
+        Sacl: PACL,
+        SaclSize: PULONG,
+        Owner: PSID,
+        OwnerSize: PULONG,
+        PrimaryGroup: PSID,
+        PrimaryGroupSize: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlGetOwnerSecurityDescriptor(
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        Owner: *mut PSID,
+        OwnerDefaulted: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlNtStatusToDosError(Status: NTSTATUS) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn RtlNtStatusToDosErrorNoTeb(Status: NTSTATUS) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn RtlCustomCPToUnicodeN(
+        CustomCP: PCPTABLEINFO,
+        UnicodeString: PWCH,
+        MaxBytesInUnicodeString: ULONG,
+        BytesInUnicodeString: PULONG,
+        CustomCPString: PCH,
+        BytesInCustomCPString: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlUnicodeToCustomCPN(
+        CustomCP: PCPTABLEINFO,
+        CustomCPString: PCH,
+        MaxBytesInCustomCPString: ULONG,
+        BytesInCustomCPString: PULONG,
+        UnicodeString: PWCH,
+        BytesInUnicodeString: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlUpcaseUnicodeToCustomCPN(
+        CustomCP: PCPTABLEINFO,
+        CustomCPString: PCH,
+        MaxBytesInCustomCPString: ULONG,
+        BytesInCustomCPString: PULONG,
+        UnicodeString: PWCH,
+        BytesInUnicodeString: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlInitCodePageTable(TableBase: PUSHORT, CodePageTable: PCPTABLEINFO);
+}
+unsafe extern "C" {
+    pub fn RtlCreateSystemVolumeInformationFolder(VolumeRootPath: PCUNICODE_STRING) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlCompareAltitudes(Altitude1: PCUNICODE_STRING, Altitude2: PCUNICODE_STRING) -> LONG;
+}
+unsafe extern "C" {
+    pub fn RtlQueryPackageIdentity(
+        TokenObject: PVOID,
+        PackageFullName: PWSTR,
+        PackageSize: PSIZE_T,
+        AppId: PWSTR,
+        AppIdSize: PSIZE_T,
+        Packaged: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlQueryPackageIdentityEx(
+        TokenObject: PVOID,
+        PackageFullName: PWSTR,
+        PackageSize: PSIZE_T,
+        AppId: PWSTR,
+        AppIdSize: PSIZE_T,
+        DynamicId: LPGUID,
+        Flags: PULONG64,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlIsNonEmptyDirectoryReparsePointAllowed(ReparseTag: ULONG) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn RtlIsCloudFilesPlaceholder(FileAttributes: ULONG, ReparseTag: ULONG) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn RtlIsPartialPlaceholder(FileAttributes: ULONG, ReparseTag: ULONG) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn RtlIsPartialPlaceholderFileHandle(
+        FileHandle: HANDLE,
+        IsPartialPlaceholder: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlIsPartialPlaceholderFileInfo(
+        InfoBuffer: *const core::ffi::c_void,
+        InfoClass: FILE_INFORMATION_CLASS,
+        IsPartialPlaceholder: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn RtlQueryThreadPlaceholderCompatibilityMode() -> CHAR;
+}
+unsafe extern "C" {
+    pub fn RtlSetThreadPlaceholderCompatibilityMode(Mode: CHAR) -> CHAR;
+}
+unsafe extern "C" {
+    pub fn RtlQueryProcessPlaceholderCompatibilityMode() -> CHAR;
+}
+unsafe extern "C" {
+    pub fn RtlSetProcessPlaceholderCompatibilityMode(Mode: CHAR) -> CHAR;
+}
+unsafe extern "C" {
+    pub fn RtlCaptureStackBackTrace(
+        FramesToSkip: ULONG,
+        FramesToCapture: ULONG,
+        BackTrace: *mut PVOID,
+        BackTraceHash: PULONG,
+    ) -> USHORT;
+}
+unsafe extern "C" {
+    pub fn RtlCaptureContext(ContextRecord: PCONTEXT);
+}
+unsafe extern "C" {
+    pub fn RtlCaptureContext2(ContextRecord: PCONTEXT);
+}
+unsafe extern "C" {
+    pub fn NtSetInformationThread(
+        ThreadHandle: HANDLE,
+        ThreadInformationClass: THREADINFOCLASS,
+        ThreadInformation: PVOID,
+        ThreadInformationLength: ULONG,
+    ) -> NTSTATUS;
+}
+pub type LSA_OPERATIONAL_MODE = ULONG;
+pub type PLSA_OPERATIONAL_MODE = *mut ULONG;
+pub const _SECURITY_LOGON_TYPE_UndefinedLogonType: _SECURITY_LOGON_TYPE = 0;
+pub const _SECURITY_LOGON_TYPE_Interactive: _SECURITY_LOGON_TYPE = 2;
+pub const _SECURITY_LOGON_TYPE_Network: _SECURITY_LOGON_TYPE = 3;
+pub const _SECURITY_LOGON_TYPE_Batch: _SECURITY_LOGON_TYPE = 4;
+pub const _SECURITY_LOGON_TYPE_Service: _SECURITY_LOGON_TYPE = 5;
+pub const _SECURITY_LOGON_TYPE_Proxy: _SECURITY_LOGON_TYPE = 6;
+pub const _SECURITY_LOGON_TYPE_Unlock: _SECURITY_LOGON_TYPE = 7;
+pub const _SECURITY_LOGON_TYPE_NetworkCleartext: _SECURITY_LOGON_TYPE = 8;
+pub const _SECURITY_LOGON_TYPE_NewCredentials: _SECURITY_LOGON_TYPE = 9;
+pub const _SECURITY_LOGON_TYPE_RemoteInteractive: _SECURITY_LOGON_TYPE = 10;
+pub const _SECURITY_LOGON_TYPE_CachedInteractive: _SECURITY_LOGON_TYPE = 11;
+pub const _SECURITY_LOGON_TYPE_CachedRemoteInteractive: _SECURITY_LOGON_TYPE = 12;
+pub const _SECURITY_LOGON_TYPE_CachedUnlock: _SECURITY_LOGON_TYPE = 13;
+pub type _SECURITY_LOGON_TYPE = core::ffi::c_int;
+pub use self::_SECURITY_LOGON_TYPE as SECURITY_LOGON_TYPE;
+pub type PSECURITY_LOGON_TYPE = *mut _SECURITY_LOGON_TYPE;
+unsafe extern "C" {
+    pub fn LsaRegisterLogonProcess(
+        LogonProcessName: PLSA_STRING,
+        LsaHandle: PHANDLE,
+        SecurityMode: PLSA_OPERATIONAL_MODE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn LsaLogonUser(
+        LsaHandle: HANDLE,
+        OriginName: PLSA_STRING,
+        LogonType: SECURITY_LOGON_TYPE,
+        AuthenticationPackage: ULONG,
+        AuthenticationInformation: PVOID,
+        AuthenticationInformationLength: ULONG,
+        LocalGroups: PTOKEN_GROUPS,
+        SourceContext: PTOKEN_SOURCE,
+        ProfileBuffer: *mut PVOID,
+        ProfileBufferLength: PULONG,
+        LogonId: PLUID,
+        Token: PHANDLE,
+        Quotas: PQUOTA_LIMITS,
+        SubStatus: PNTSTATUS,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn LsaFreeReturnBuffer(Buffer: PVOID) -> NTSTATUS;
+}
+pub const _MSV1_0_LOGON_SUBMIT_TYPE_MsV1_0InteractiveLogon: _MSV1_0_LOGON_SUBMIT_TYPE = 2;
+pub const _MSV1_0_LOGON_SUBMIT_TYPE_MsV1_0Lm20Logon: _MSV1_0_LOGON_SUBMIT_TYPE = 3;
+pub const _MSV1_0_LOGON_SUBMIT_TYPE_MsV1_0NetworkLogon: _MSV1_0_LOGON_SUBMIT_TYPE = 4;
+pub const _MSV1_0_LOGON_SUBMIT_TYPE_MsV1_0SubAuthLogon: _MSV1_0_LOGON_SUBMIT_TYPE = 5;
+pub const _MSV1_0_LOGON_SUBMIT_TYPE_MsV1_0WorkstationUnlockLogon: _MSV1_0_LOGON_SUBMIT_TYPE = 7;
+pub const _MSV1_0_LOGON_SUBMIT_TYPE_MsV1_0S4ULogon: _MSV1_0_LOGON_SUBMIT_TYPE = 12;
+pub const _MSV1_0_LOGON_SUBMIT_TYPE_MsV1_0VirtualLogon: _MSV1_0_LOGON_SUBMIT_TYPE = 82;
+pub const _MSV1_0_LOGON_SUBMIT_TYPE_MsV1_0NoElevationLogon: _MSV1_0_LOGON_SUBMIT_TYPE = 83;
+pub const _MSV1_0_LOGON_SUBMIT_TYPE_MsV1_0LuidLogon: _MSV1_0_LOGON_SUBMIT_TYPE = 84;
+pub type _MSV1_0_LOGON_SUBMIT_TYPE = core::ffi::c_int;
+pub use self::_MSV1_0_LOGON_SUBMIT_TYPE as MSV1_0_LOGON_SUBMIT_TYPE;
+pub type PMSV1_0_LOGON_SUBMIT_TYPE = *mut _MSV1_0_LOGON_SUBMIT_TYPE;
+pub const _MSV1_0_PROFILE_BUFFER_TYPE_MsV1_0InteractiveProfile: _MSV1_0_PROFILE_BUFFER_TYPE = 2;
+pub const _MSV1_0_PROFILE_BUFFER_TYPE_MsV1_0Lm20LogonProfile: _MSV1_0_PROFILE_BUFFER_TYPE = 3;
+pub const _MSV1_0_PROFILE_BUFFER_TYPE_MsV1_0SmartCardProfile: _MSV1_0_PROFILE_BUFFER_TYPE = 4;
+pub type _MSV1_0_PROFILE_BUFFER_TYPE = core::ffi::c_int;
+pub use self::_MSV1_0_PROFILE_BUFFER_TYPE as MSV1_0_PROFILE_BUFFER_TYPE;
+pub type PMSV1_0_PROFILE_BUFFER_TYPE = *mut _MSV1_0_PROFILE_BUFFER_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_INTERACTIVE_LOGON {
+    pub MessageType: MSV1_0_LOGON_SUBMIT_TYPE,
+    pub LogonDomainName: UNICODE_STRING,
+    pub UserName: UNICODE_STRING,
+    pub Password: UNICODE_STRING,
+}
+pub type MSV1_0_INTERACTIVE_LOGON = _MSV1_0_INTERACTIVE_LOGON;
+pub type PMSV1_0_INTERACTIVE_LOGON = *mut _MSV1_0_INTERACTIVE_LOGON;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _MSV1_0_INTERACTIVE_PROFILE {
+    pub MessageType: MSV1_0_PROFILE_BUFFER_TYPE,
+    pub LogonCount: USHORT,
+    pub BadPasswordCount: USHORT,
+    pub LogonTime: LARGE_INTEGER,
+    pub LogoffTime: LARGE_INTEGER,
+    pub KickOffTime: LARGE_INTEGER,
+    pub PasswordLastSet: LARGE_INTEGER,
+    pub PasswordCanChange: LARGE_INTEGER,
+    pub PasswordMustChange: LARGE_INTEGER,
+    pub LogonScript: UNICODE_STRING,
+    pub HomeDirectory: UNICODE_STRING,
+    pub FullName: UNICODE_STRING,
+    pub ProfilePath: UNICODE_STRING,
+    pub HomeDirectoryDrive: UNICODE_STRING,
+    pub LogonServer: UNICODE_STRING,
+    pub UserFlags: ULONG,
+}
+pub type MSV1_0_INTERACTIVE_PROFILE = _MSV1_0_INTERACTIVE_PROFILE;
+pub type PMSV1_0_INTERACTIVE_PROFILE = *mut _MSV1_0_INTERACTIVE_PROFILE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_LM20_LOGON {
+    pub MessageType: MSV1_0_LOGON_SUBMIT_TYPE,
+    pub LogonDomainName: UNICODE_STRING,
+    pub UserName: UNICODE_STRING,
+    pub Workstation: UNICODE_STRING,
+    pub ChallengeToClient: [UCHAR; 8usize],
+    pub CaseSensitiveChallengeResponse: STRING,
+    pub CaseInsensitiveChallengeResponse: STRING,
+    pub ParameterControl: ULONG,
+}
+pub type MSV1_0_LM20_LOGON = _MSV1_0_LM20_LOGON;
+pub type PMSV1_0_LM20_LOGON = *mut _MSV1_0_LM20_LOGON;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_SUBAUTH_LOGON {
+    pub MessageType: MSV1_0_LOGON_SUBMIT_TYPE,
+    pub LogonDomainName: UNICODE_STRING,
+    pub UserName: UNICODE_STRING,
+    pub Workstation: UNICODE_STRING,
+    pub ChallengeToClient: [UCHAR; 8usize],
+    pub AuthenticationInfo1: STRING,
+    pub AuthenticationInfo2: STRING,
+    pub ParameterControl: ULONG,
+    pub SubAuthPackageId: ULONG,
+}
+pub type MSV1_0_SUBAUTH_LOGON = _MSV1_0_SUBAUTH_LOGON;
+pub type PMSV1_0_SUBAUTH_LOGON = *mut _MSV1_0_SUBAUTH_LOGON;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_S4U_LOGON {
+    pub MessageType: MSV1_0_LOGON_SUBMIT_TYPE,
+    pub Flags: ULONG,
+    pub UserPrincipalName: UNICODE_STRING,
+    pub DomainName: UNICODE_STRING,
+}
+pub type MSV1_0_S4U_LOGON = _MSV1_0_S4U_LOGON;
+pub type PMSV1_0_S4U_LOGON = *mut _MSV1_0_S4U_LOGON;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _MSV1_0_LM20_LOGON_PROFILE {
+    pub MessageType: MSV1_0_PROFILE_BUFFER_TYPE,
+    pub KickOffTime: LARGE_INTEGER,
+    pub LogoffTime: LARGE_INTEGER,
+    pub UserFlags: ULONG,
+    pub UserSessionKey: [UCHAR; 16usize],
+    pub LogonDomainName: UNICODE_STRING,
+    pub LanmanSessionKey: [UCHAR; 8usize],
+    pub LogonServer: UNICODE_STRING,
+    pub UserParameters: UNICODE_STRING,
+}
+pub type MSV1_0_LM20_LOGON_PROFILE = _MSV1_0_LM20_LOGON_PROFILE;
+pub type PMSV1_0_LM20_LOGON_PROFILE = *mut _MSV1_0_LM20_LOGON_PROFILE;
+pub const _MSV1_0_CREDENTIAL_KEY_TYPE_InvalidCredKey: _MSV1_0_CREDENTIAL_KEY_TYPE = 0;
+pub const _MSV1_0_CREDENTIAL_KEY_TYPE_DeprecatedIUMCredKey: _MSV1_0_CREDENTIAL_KEY_TYPE = 1;
+pub const _MSV1_0_CREDENTIAL_KEY_TYPE_DomainUserCredKey: _MSV1_0_CREDENTIAL_KEY_TYPE = 2;
+pub const _MSV1_0_CREDENTIAL_KEY_TYPE_LocalUserCredKey: _MSV1_0_CREDENTIAL_KEY_TYPE = 3;
+pub const _MSV1_0_CREDENTIAL_KEY_TYPE_ExternallySuppliedCredKey: _MSV1_0_CREDENTIAL_KEY_TYPE = 4;
+pub type _MSV1_0_CREDENTIAL_KEY_TYPE = core::ffi::c_int;
+pub use self::_MSV1_0_CREDENTIAL_KEY_TYPE as MSV1_0_CREDENTIAL_KEY_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_CREDENTIAL_KEY {
+    pub Data: [UCHAR; 20usize],
+}
+pub type MSV1_0_CREDENTIAL_KEY = _MSV1_0_CREDENTIAL_KEY;
+pub type PMSV1_0_CREDENTIAL_KEY = *mut _MSV1_0_CREDENTIAL_KEY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_SUPPLEMENTAL_CREDENTIAL {
+    pub Version: ULONG,
+    pub Flags: ULONG,
+    pub LmPassword: [UCHAR; 16usize],
+    pub NtPassword: [UCHAR; 16usize],
+}
+pub type MSV1_0_SUPPLEMENTAL_CREDENTIAL = _MSV1_0_SUPPLEMENTAL_CREDENTIAL;
+pub type PMSV1_0_SUPPLEMENTAL_CREDENTIAL = *mut _MSV1_0_SUPPLEMENTAL_CREDENTIAL;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_SUPPLEMENTAL_CREDENTIAL_V2 {
+    pub Version: ULONG,
+    pub Flags: ULONG,
+    pub NtPassword: [UCHAR; 16usize],
+    pub CredentialKey: MSV1_0_CREDENTIAL_KEY,
+}
+pub type MSV1_0_SUPPLEMENTAL_CREDENTIAL_V2 = _MSV1_0_SUPPLEMENTAL_CREDENTIAL_V2;
+pub type PMSV1_0_SUPPLEMENTAL_CREDENTIAL_V2 = *mut _MSV1_0_SUPPLEMENTAL_CREDENTIAL_V2;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_SUPPLEMENTAL_CREDENTIAL_V3 {
+    pub Version: ULONG,
+    pub Flags: ULONG,
+    pub CredentialKeyType: MSV1_0_CREDENTIAL_KEY_TYPE,
+    pub NtPassword: [UCHAR; 16usize],
+    pub CredentialKey: MSV1_0_CREDENTIAL_KEY,
+    pub ShaPassword: [UCHAR; 20usize],
+}
+pub type MSV1_0_SUPPLEMENTAL_CREDENTIAL_V3 = _MSV1_0_SUPPLEMENTAL_CREDENTIAL_V3;
+pub type PMSV1_0_SUPPLEMENTAL_CREDENTIAL_V3 = *mut _MSV1_0_SUPPLEMENTAL_CREDENTIAL_V3;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_IUM_SUPPLEMENTAL_CREDENTIAL {
+    pub Version: ULONG,
+    pub EncryptedCredsSize: ULONG,
+    pub EncryptedCreds: [UCHAR; 1usize],
+}
+pub type MSV1_0_IUM_SUPPLEMENTAL_CREDENTIAL = _MSV1_0_IUM_SUPPLEMENTAL_CREDENTIAL;
+pub type PMSV1_0_IUM_SUPPLEMENTAL_CREDENTIAL = *mut _MSV1_0_IUM_SUPPLEMENTAL_CREDENTIAL;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_REMOTE_SUPPLEMENTAL_CREDENTIAL {
+    pub Version: ULONG,
+    pub Flags: ULONG,
+    pub CredentialKey: MSV1_0_CREDENTIAL_KEY,
+    pub CredentialKeyType: MSV1_0_CREDENTIAL_KEY_TYPE,
+    pub EncryptedCredsSize: ULONG,
+    pub EncryptedCreds: [UCHAR; 1usize],
+}
+pub type MSV1_0_REMOTE_SUPPLEMENTAL_CREDENTIAL = _MSV1_0_REMOTE_SUPPLEMENTAL_CREDENTIAL;
+pub type PMSV1_0_REMOTE_SUPPLEMENTAL_CREDENTIAL = *mut _MSV1_0_REMOTE_SUPPLEMENTAL_CREDENTIAL;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_NTLM3_RESPONSE {
+    pub Response: [UCHAR; 16usize],
+    pub RespType: UCHAR,
+    pub HiRespType: UCHAR,
+    pub Flags: USHORT,
+    pub MsgWord: ULONG,
+    pub TimeStamp: ULONGLONG,
+    pub ChallengeFromClient: [UCHAR; 8usize],
+    pub AvPairsOff: ULONG,
+    pub Buffer: [UCHAR; 1usize],
+}
+pub type MSV1_0_NTLM3_RESPONSE = _MSV1_0_NTLM3_RESPONSE;
+pub type PMSV1_0_NTLM3_RESPONSE = *mut _MSV1_0_NTLM3_RESPONSE;
+pub const MSV1_0_AVID_MsvAvEOL: MSV1_0_AVID = 0;
+pub const MSV1_0_AVID_MsvAvNbComputerName: MSV1_0_AVID = 1;
+pub const MSV1_0_AVID_MsvAvNbDomainName: MSV1_0_AVID = 2;
+pub const MSV1_0_AVID_MsvAvDnsComputerName: MSV1_0_AVID = 3;
+pub const MSV1_0_AVID_MsvAvDnsDomainName: MSV1_0_AVID = 4;
+pub const MSV1_0_AVID_MsvAvDnsTreeName: MSV1_0_AVID = 5;
+pub const MSV1_0_AVID_MsvAvFlags: MSV1_0_AVID = 6;
+pub const MSV1_0_AVID_MsvAvTimestamp: MSV1_0_AVID = 7;
+pub const MSV1_0_AVID_MsvAvRestrictions: MSV1_0_AVID = 8;
+pub const MSV1_0_AVID_MsvAvTargetName: MSV1_0_AVID = 9;
+pub const MSV1_0_AVID_MsvAvChannelBindings: MSV1_0_AVID = 10;
+pub type MSV1_0_AVID = core::ffi::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_AV_PAIR {
+    pub AvId: USHORT,
+    pub AvLen: USHORT,
+}
+pub type MSV1_0_AV_PAIR = _MSV1_0_AV_PAIR;
+pub type PMSV1_0_AV_PAIR = *mut _MSV1_0_AV_PAIR;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0Lm20ChallengeRequest: _MSV1_0_PROTOCOL_MESSAGE_TYPE =
+    0;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0Lm20GetChallengeResponse:
+_MSV1_0_PROTOCOL_MESSAGE_TYPE = 1;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0EnumerateUsers: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 2;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0GetUserInfo: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 3;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0ReLogonUsers: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 4;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0ChangePassword: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 5;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0ChangeCachedPassword: _MSV1_0_PROTOCOL_MESSAGE_TYPE =
+    6;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0GenericPassthrough: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 7;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0CacheLogon: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 8;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0SubAuth: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 9;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0DeriveCredential: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 10;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0CacheLookup: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 11;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0SetProcessOption: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 12;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0ConfigLocalAliases: _MSV1_0_PROTOCOL_MESSAGE_TYPE =
+    13;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0ClearCachedCredentials:
+_MSV1_0_PROTOCOL_MESSAGE_TYPE = 14;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0LookupToken: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 15;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0ValidateAuth: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 16;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0CacheLookupEx: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 17;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0GetCredentialKey: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 18;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0SetThreadOption: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 19;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0DecryptDpapiMasterKey: _MSV1_0_PROTOCOL_MESSAGE_TYPE =
+    20;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0GetStrongCredentialKey:
+_MSV1_0_PROTOCOL_MESSAGE_TYPE = 21;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0TransferCred: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 22;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0ProvisionTbal: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 23;
+pub const _MSV1_0_PROTOCOL_MESSAGE_TYPE_MsV1_0DeleteTbalSecrets: _MSV1_0_PROTOCOL_MESSAGE_TYPE = 24;
+pub type _MSV1_0_PROTOCOL_MESSAGE_TYPE = core::ffi::c_int;
+pub use self::_MSV1_0_PROTOCOL_MESSAGE_TYPE as MSV1_0_PROTOCOL_MESSAGE_TYPE;
+pub type PMSV1_0_PROTOCOL_MESSAGE_TYPE = *mut _MSV1_0_PROTOCOL_MESSAGE_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_LM20_CHALLENGE_REQUEST {
+    pub MessageType: MSV1_0_PROTOCOL_MESSAGE_TYPE,
+}
+pub type MSV1_0_LM20_CHALLENGE_REQUEST = _MSV1_0_LM20_CHALLENGE_REQUEST;
+pub type PMSV1_0_LM20_CHALLENGE_REQUEST = *mut _MSV1_0_LM20_CHALLENGE_REQUEST;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_LM20_CHALLENGE_RESPONSE {
+    pub MessageType: MSV1_0_PROTOCOL_MESSAGE_TYPE,
+    pub ChallengeToClient: [UCHAR; 8usize],
+}
+pub type MSV1_0_LM20_CHALLENGE_RESPONSE = _MSV1_0_LM20_CHALLENGE_RESPONSE;
+pub type PMSV1_0_LM20_CHALLENGE_RESPONSE = *mut _MSV1_0_LM20_CHALLENGE_RESPONSE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_GETCHALLENRESP_REQUEST_V1 {
+    pub MessageType: MSV1_0_PROTOCOL_MESSAGE_TYPE,
+    pub ParameterControl: ULONG,
+    pub LogonId: LUID,
+    pub Password: UNICODE_STRING,
+    pub ChallengeToClient: [UCHAR; 8usize],
+}
+pub type MSV1_0_GETCHALLENRESP_REQUEST_V1 = _MSV1_0_GETCHALLENRESP_REQUEST_V1;
+pub type PMSV1_0_GETCHALLENRESP_REQUEST_V1 = *mut _MSV1_0_GETCHALLENRESP_REQUEST_V1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_GETCHALLENRESP_REQUEST {
+    pub MessageType: MSV1_0_PROTOCOL_MESSAGE_TYPE,
+    pub ParameterControl: ULONG,
+    pub LogonId: LUID,
+    pub Password: UNICODE_STRING,
+    pub ChallengeToClient: [UCHAR; 8usize],
+    pub UserName: UNICODE_STRING,
+    pub LogonDomainName: UNICODE_STRING,
+    pub ServerName: UNICODE_STRING,
+}
+pub type MSV1_0_GETCHALLENRESP_REQUEST = _MSV1_0_GETCHALLENRESP_REQUEST;
+pub type PMSV1_0_GETCHALLENRESP_REQUEST = *mut _MSV1_0_GETCHALLENRESP_REQUEST;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_GETCHALLENRESP_RESPONSE {
+    pub MessageType: MSV1_0_PROTOCOL_MESSAGE_TYPE,
+    pub CaseSensitiveChallengeResponse: STRING,
+    pub CaseInsensitiveChallengeResponse: STRING,
+    pub UserName: UNICODE_STRING,
+    pub LogonDomainName: UNICODE_STRING,
+    pub UserSessionKey: [UCHAR; 16usize],
+    pub LanmanSessionKey: [UCHAR; 8usize],
+}
+pub type MSV1_0_GETCHALLENRESP_RESPONSE = _MSV1_0_GETCHALLENRESP_RESPONSE;
+pub type PMSV1_0_GETCHALLENRESP_RESPONSE = *mut _MSV1_0_GETCHALLENRESP_RESPONSE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_ENUMUSERS_REQUEST {
+    pub MessageType: MSV1_0_PROTOCOL_MESSAGE_TYPE,
+}
+pub type MSV1_0_ENUMUSERS_REQUEST = _MSV1_0_ENUMUSERS_REQUEST;
+pub type PMSV1_0_ENUMUSERS_REQUEST = *mut _MSV1_0_ENUMUSERS_REQUEST;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_ENUMUSERS_RESPONSE {
+    pub MessageType: MSV1_0_PROTOCOL_MESSAGE_TYPE,
+    pub NumberOfLoggedOnUsers: ULONG,
+    pub LogonIds: PLUID,
+    pub EnumHandles: PULONG,
+}
+pub type MSV1_0_ENUMUSERS_RESPONSE = _MSV1_0_ENUMUSERS_RESPONSE;
+pub type PMSV1_0_ENUMUSERS_RESPONSE = *mut _MSV1_0_ENUMUSERS_RESPONSE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_GETUSERINFO_REQUEST {
+    pub MessageType: MSV1_0_PROTOCOL_MESSAGE_TYPE,
+    pub LogonId: LUID,
+}
+pub type MSV1_0_GETUSERINFO_REQUEST = _MSV1_0_GETUSERINFO_REQUEST;
+pub type PMSV1_0_GETUSERINFO_REQUEST = *mut _MSV1_0_GETUSERINFO_REQUEST;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MSV1_0_GETUSERINFO_RESPONSE {
+    pub MessageType: MSV1_0_PROTOCOL_MESSAGE_TYPE,
+    pub UserSid: PSID,
+    pub UserName: UNICODE_STRING,
+    pub LogonDomainName: UNICODE_STRING,
+    pub LogonServer: UNICODE_STRING,
+    pub LogonType: SECURITY_LOGON_TYPE,
+}
+pub type MSV1_0_GETUSERINFO_RESPONSE = _MSV1_0_GETUSERINFO_RESPONSE;
+pub type PMSV1_0_GETUSERINFO_RESPONSE = *mut _MSV1_0_GETUSERINFO_RESPONSE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_ID_128 {
+    pub Identifier: [UCHAR; 16usize],
+}
+pub type FILE_ID_128 = _FILE_ID_128;
+pub type PFILE_ID_128 = *mut _FILE_ID_128;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_NOTIFY_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub Action: ULONG,
+    pub FileNameLength: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_NOTIFY_INFORMATION = _FILE_NOTIFY_INFORMATION;
+pub type PFILE_NOTIFY_INFORMATION = *mut _FILE_NOTIFY_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_NOTIFY_EXTENDED_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub Action: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastModificationTime: LARGE_INTEGER,
+    pub LastChangeTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub AllocatedLength: LARGE_INTEGER,
+    pub FileSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub __bindgen_anon_1: _FILE_NOTIFY_EXTENDED_INFORMATION__bindgen_ty_1,
+    pub FileId: LARGE_INTEGER,
+    pub ParentFileId: LARGE_INTEGER,
+    pub FileNameLength: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _FILE_NOTIFY_EXTENDED_INFORMATION__bindgen_ty_1 {
+    pub ReparsePointTag: ULONG,
+    pub EaSize: ULONG,
+}
+pub type FILE_NOTIFY_EXTENDED_INFORMATION = _FILE_NOTIFY_EXTENDED_INFORMATION;
+pub type PFILE_NOTIFY_EXTENDED_INFORMATION = *mut _FILE_NOTIFY_EXTENDED_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_NOTIFY_FULL_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub Action: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastModificationTime: LARGE_INTEGER,
+    pub LastChangeTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub AllocatedLength: LARGE_INTEGER,
+    pub FileSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub __bindgen_anon_1: _FILE_NOTIFY_FULL_INFORMATION__bindgen_ty_1,
+    pub FileId: LARGE_INTEGER,
+    pub ParentFileId: LARGE_INTEGER,
+    pub FileNameLength: USHORT,
+    pub FileNameFlags: UCHAR,
+    pub Reserved: UCHAR,
+    pub FileName: [WCHAR; 1usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _FILE_NOTIFY_FULL_INFORMATION__bindgen_ty_1 {
+    pub ReparsePointTag: ULONG,
+    pub EaSize: ULONG,
+}
+pub type FILE_NOTIFY_FULL_INFORMATION = _FILE_NOTIFY_FULL_INFORMATION;
+pub type PFILE_NOTIFY_FULL_INFORMATION = *mut _FILE_NOTIFY_FULL_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_INFORMATION_DEFINITION {
+    pub Class: FILE_INFORMATION_CLASS,
+    pub NextEntryOffset: ULONG,
+    pub FileNameLengthOffset: ULONG,
+    pub FileNameOffset: ULONG,
+}
+pub type FILE_INFORMATION_DEFINITION = _FILE_INFORMATION_DEFINITION;
+pub type PFILE_INFORMATION_DEFINITION = *mut _FILE_INFORMATION_DEFINITION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_DIRECTORY_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_DIRECTORY_INFORMATION = _FILE_DIRECTORY_INFORMATION;
+pub type PFILE_DIRECTORY_INFORMATION = *mut _FILE_DIRECTORY_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_FULL_DIR_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: ULONG,
+    pub EaSize: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_FULL_DIR_INFORMATION = _FILE_FULL_DIR_INFORMATION;
+pub type PFILE_FULL_DIR_INFORMATION = *mut _FILE_FULL_DIR_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ID_FULL_DIR_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: ULONG,
+    pub EaSize: ULONG,
+    pub FileId: LARGE_INTEGER,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_ID_FULL_DIR_INFORMATION = _FILE_ID_FULL_DIR_INFORMATION;
+pub type PFILE_ID_FULL_DIR_INFORMATION = *mut _FILE_ID_FULL_DIR_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_BOTH_DIR_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: ULONG,
+    pub EaSize: ULONG,
+    pub ShortNameLength: CCHAR,
+    pub ShortName: [WCHAR; 12usize],
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_BOTH_DIR_INFORMATION = _FILE_BOTH_DIR_INFORMATION;
+pub type PFILE_BOTH_DIR_INFORMATION = *mut _FILE_BOTH_DIR_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ID_BOTH_DIR_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: ULONG,
+    pub EaSize: ULONG,
+    pub ShortNameLength: CCHAR,
+    pub ShortName: [WCHAR; 12usize],
+    pub FileId: LARGE_INTEGER,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_ID_BOTH_DIR_INFORMATION = _FILE_ID_BOTH_DIR_INFORMATION;
+pub type PFILE_ID_BOTH_DIR_INFORMATION = *mut _FILE_ID_BOTH_DIR_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_NAMES_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub FileNameLength: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_NAMES_INFORMATION = _FILE_NAMES_INFORMATION;
+pub type PFILE_NAMES_INFORMATION = *mut _FILE_NAMES_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ID_GLOBAL_TX_DIR_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: ULONG,
+    pub FileId: LARGE_INTEGER,
+    pub LockingTransactionId: GUID,
+    pub TxInfoFlags: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_ID_GLOBAL_TX_DIR_INFORMATION = _FILE_ID_GLOBAL_TX_DIR_INFORMATION;
+pub type PFILE_ID_GLOBAL_TX_DIR_INFORMATION = *mut _FILE_ID_GLOBAL_TX_DIR_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ID_EXTD_DIR_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: ULONG,
+    pub EaSize: ULONG,
+    pub ReparsePointTag: ULONG,
+    pub FileId: FILE_ID_128,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_ID_EXTD_DIR_INFORMATION = _FILE_ID_EXTD_DIR_INFORMATION;
+pub type PFILE_ID_EXTD_DIR_INFORMATION = *mut _FILE_ID_EXTD_DIR_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ID_EXTD_BOTH_DIR_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: ULONG,
+    pub EaSize: ULONG,
+    pub ReparsePointTag: ULONG,
+    pub FileId: FILE_ID_128,
+    pub ShortNameLength: CCHAR,
+    pub ShortName: [WCHAR; 12usize],
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_ID_EXTD_BOTH_DIR_INFORMATION = _FILE_ID_EXTD_BOTH_DIR_INFORMATION;
+pub type PFILE_ID_EXTD_BOTH_DIR_INFORMATION = *mut _FILE_ID_EXTD_BOTH_DIR_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ID_64_EXTD_DIR_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: ULONG,
+    pub EaSize: ULONG,
+    pub ReparsePointTag: ULONG,
+    pub FileId: LARGE_INTEGER,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_ID_64_EXTD_DIR_INFORMATION = _FILE_ID_64_EXTD_DIR_INFORMATION;
+pub type PFILE_ID_64_EXTD_DIR_INFORMATION = *mut _FILE_ID_64_EXTD_DIR_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ID_64_EXTD_BOTH_DIR_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: ULONG,
+    pub EaSize: ULONG,
+    pub ReparsePointTag: ULONG,
+    pub FileId: LARGE_INTEGER,
+    pub ShortNameLength: CCHAR,
+    pub ShortName: [WCHAR; 12usize],
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_ID_64_EXTD_BOTH_DIR_INFORMATION = _FILE_ID_64_EXTD_BOTH_DIR_INFORMATION;
+pub type PFILE_ID_64_EXTD_BOTH_DIR_INFORMATION = *mut _FILE_ID_64_EXTD_BOTH_DIR_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ID_ALL_EXTD_DIR_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: ULONG,
+    pub EaSize: ULONG,
+    pub ReparsePointTag: ULONG,
+    pub FileId: LARGE_INTEGER,
+    pub FileId128: FILE_ID_128,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_ID_ALL_EXTD_DIR_INFORMATION = _FILE_ID_ALL_EXTD_DIR_INFORMATION;
+pub type PFILE_ID_ALL_EXTD_DIR_INFORMATION = *mut _FILE_ID_ALL_EXTD_DIR_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ID_ALL_EXTD_BOTH_DIR_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: ULONG,
+    pub EaSize: ULONG,
+    pub ReparsePointTag: ULONG,
+    pub FileId: LARGE_INTEGER,
+    pub FileId128: FILE_ID_128,
+    pub ShortNameLength: CCHAR,
+    pub ShortName: [WCHAR; 12usize],
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_ID_ALL_EXTD_BOTH_DIR_INFORMATION = _FILE_ID_ALL_EXTD_BOTH_DIR_INFORMATION;
+pub type PFILE_ID_ALL_EXTD_BOTH_DIR_INFORMATION = *mut _FILE_ID_ALL_EXTD_BOTH_DIR_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_OBJECTID_INFORMATION {
+    pub FileReference: LONGLONG,
+    pub ObjectId: [UCHAR; 16usize],
+    pub __bindgen_anon_1: _FILE_OBJECTID_INFORMATION__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _FILE_OBJECTID_INFORMATION__bindgen_ty_1 {
+    pub __bindgen_anon_1: _FILE_OBJECTID_INFORMATION__bindgen_ty_1__bindgen_ty_1,
+    pub ExtendedInfo: [UCHAR; 48usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_OBJECTID_INFORMATION__bindgen_ty_1__bindgen_ty_1 {
+    pub BirthVolumeId: [UCHAR; 16usize],
+    pub BirthObjectId: [UCHAR; 16usize],
+    pub DomainId: [UCHAR; 16usize],
+}
+pub type FILE_OBJECTID_INFORMATION = _FILE_OBJECTID_INFORMATION;
+pub type PFILE_OBJECTID_INFORMATION = *mut _FILE_OBJECTID_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_INTERNAL_INFORMATION {
+    pub IndexNumber: LARGE_INTEGER,
+}
+pub type FILE_INTERNAL_INFORMATION = _FILE_INTERNAL_INFORMATION;
+pub type PFILE_INTERNAL_INFORMATION = *mut _FILE_INTERNAL_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_ID_INFORMATION {
+    pub VolumeSerialNumber: ULONGLONG,
+    pub FileId: FILE_ID_128,
+}
+pub type FILE_ID_INFORMATION = _FILE_ID_INFORMATION;
+pub type PFILE_ID_INFORMATION = *mut _FILE_ID_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_EA_INFORMATION {
+    pub EaSize: ULONG,
+}
+pub type FILE_EA_INFORMATION = _FILE_EA_INFORMATION;
+pub type PFILE_EA_INFORMATION = *mut _FILE_EA_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_ACCESS_INFORMATION {
+    pub AccessFlags: ACCESS_MASK,
+}
+pub type FILE_ACCESS_INFORMATION = _FILE_ACCESS_INFORMATION;
+pub type PFILE_ACCESS_INFORMATION = *mut _FILE_ACCESS_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_MODE_INFORMATION {
+    pub Mode: ULONG,
+}
+pub type FILE_MODE_INFORMATION = _FILE_MODE_INFORMATION;
+pub type PFILE_MODE_INFORMATION = *mut _FILE_MODE_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ALL_INFORMATION {
+    pub BasicInformation: FILE_BASIC_INFORMATION,
+    pub StandardInformation: FILE_STANDARD_INFORMATION,
+    pub InternalInformation: FILE_INTERNAL_INFORMATION,
+    pub EaInformation: FILE_EA_INFORMATION,
+    pub AccessInformation: FILE_ACCESS_INFORMATION,
+    pub PositionInformation: FILE_POSITION_INFORMATION,
+    pub ModeInformation: FILE_MODE_INFORMATION,
+    pub AlignmentInformation: FILE_ALIGNMENT_INFORMATION,
+    pub NameInformation: FILE_NAME_INFORMATION,
+}
+pub type FILE_ALL_INFORMATION = _FILE_ALL_INFORMATION;
+pub type PFILE_ALL_INFORMATION = *mut _FILE_ALL_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_STAT_INFORMATION {
+    pub FileId: LARGE_INTEGER,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub ReparseTag: ULONG,
+    pub NumberOfLinks: ULONG,
+    pub EffectiveAccess: ACCESS_MASK,
+}
+pub type FILE_STAT_INFORMATION = _FILE_STAT_INFORMATION;
+pub type PFILE_STAT_INFORMATION = *mut _FILE_STAT_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_STAT_LX_INFORMATION {
+    pub FileId: LARGE_INTEGER,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub ReparseTag: ULONG,
+    pub NumberOfLinks: ULONG,
+    pub EffectiveAccess: ACCESS_MASK,
+    pub LxFlags: ULONG,
+    pub LxUid: ULONG,
+    pub LxGid: ULONG,
+    pub LxMode: ULONG,
+    pub LxDeviceIdMajor: ULONG,
+    pub LxDeviceIdMinor: ULONG,
+}
+pub type FILE_STAT_LX_INFORMATION = _FILE_STAT_LX_INFORMATION;
+pub type PFILE_STAT_LX_INFORMATION = *mut _FILE_STAT_LX_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_CASE_SENSITIVE_INFORMATION {
+    pub Flags: ULONG,
+}
+pub type FILE_CASE_SENSITIVE_INFORMATION = _FILE_CASE_SENSITIVE_INFORMATION;
+pub type PFILE_CASE_SENSITIVE_INFORMATION = *mut _FILE_CASE_SENSITIVE_INFORMATION;
+pub const _FILE_KNOWN_FOLDER_TYPE_KnownFolderNone: _FILE_KNOWN_FOLDER_TYPE = 0;
+pub const _FILE_KNOWN_FOLDER_TYPE_KnownFolderDesktop: _FILE_KNOWN_FOLDER_TYPE = 1;
+pub const _FILE_KNOWN_FOLDER_TYPE_KnownFolderDocuments: _FILE_KNOWN_FOLDER_TYPE = 2;
+pub const _FILE_KNOWN_FOLDER_TYPE_KnownFolderDownloads: _FILE_KNOWN_FOLDER_TYPE = 3;
+pub const _FILE_KNOWN_FOLDER_TYPE_KnownFolderMusic: _FILE_KNOWN_FOLDER_TYPE = 4;
+pub const _FILE_KNOWN_FOLDER_TYPE_KnownFolderPictures: _FILE_KNOWN_FOLDER_TYPE = 5;
+pub const _FILE_KNOWN_FOLDER_TYPE_KnownFolderVideos: _FILE_KNOWN_FOLDER_TYPE = 6;
+pub const _FILE_KNOWN_FOLDER_TYPE_KnownFolderOther: _FILE_KNOWN_FOLDER_TYPE = 7;
+pub const _FILE_KNOWN_FOLDER_TYPE_KnownFolderMax: _FILE_KNOWN_FOLDER_TYPE = 7;
+pub type _FILE_KNOWN_FOLDER_TYPE = core::ffi::c_int;
+pub use self::_FILE_KNOWN_FOLDER_TYPE as FILE_KNOWN_FOLDER_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_KNOWN_FOLDER_INFORMATION {
+    pub Type: FILE_KNOWN_FOLDER_TYPE,
+}
+pub type FILE_KNOWN_FOLDER_INFORMATION = _FILE_KNOWN_FOLDER_INFORMATION;
+pub type PFILE_KNOWN_FOLDER_INFORMATION = *mut _FILE_KNOWN_FOLDER_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ALLOCATION_INFORMATION {
+    pub AllocationSize: LARGE_INTEGER,
+}
+pub type FILE_ALLOCATION_INFORMATION = _FILE_ALLOCATION_INFORMATION;
+pub type PFILE_ALLOCATION_INFORMATION = *mut _FILE_ALLOCATION_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_COMPRESSION_INFORMATION {
+    pub CompressedFileSize: LARGE_INTEGER,
+    pub CompressionFormat: USHORT,
+    pub CompressionUnitShift: UCHAR,
+    pub ChunkShift: UCHAR,
+    pub ClusterShift: UCHAR,
+    pub Reserved: [UCHAR; 3usize],
+}
+pub type FILE_COMPRESSION_INFORMATION = _FILE_COMPRESSION_INFORMATION;
+pub type PFILE_COMPRESSION_INFORMATION = *mut _FILE_COMPRESSION_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_LINK_INFORMATION {
+    pub __bindgen_anon_1: _FILE_LINK_INFORMATION__bindgen_ty_1,
+    pub RootDirectory: HANDLE,
+    pub FileNameLength: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _FILE_LINK_INFORMATION__bindgen_ty_1 {
+    pub ReplaceIfExists: BOOLEAN,
+    pub Flags: ULONG,
+}
+pub type FILE_LINK_INFORMATION = _FILE_LINK_INFORMATION;
+pub type PFILE_LINK_INFORMATION = *mut _FILE_LINK_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_MOVE_CLUSTER_INFORMATION {
+    pub ClusterCount: ULONG,
+    pub RootDirectory: HANDLE,
+    pub FileNameLength: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_MOVE_CLUSTER_INFORMATION = _FILE_MOVE_CLUSTER_INFORMATION;
+pub type PFILE_MOVE_CLUSTER_INFORMATION = *mut _FILE_MOVE_CLUSTER_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_RENAME_INFORMATION {
+    pub __bindgen_anon_1: _FILE_RENAME_INFORMATION__bindgen_ty_1,
+    pub RootDirectory: HANDLE,
+    pub FileNameLength: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _FILE_RENAME_INFORMATION__bindgen_ty_1 {
+    pub ReplaceIfExists: BOOLEAN,
+    pub Flags: ULONG,
+}
+pub type FILE_RENAME_INFORMATION = _FILE_RENAME_INFORMATION;
+pub type PFILE_RENAME_INFORMATION = *mut _FILE_RENAME_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_STREAM_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub StreamNameLength: ULONG,
+    pub StreamSize: LARGE_INTEGER,
+    pub StreamAllocationSize: LARGE_INTEGER,
+    pub StreamName: [WCHAR; 1usize],
+}
+pub type FILE_STREAM_INFORMATION = _FILE_STREAM_INFORMATION;
+pub type PFILE_STREAM_INFORMATION = *mut _FILE_STREAM_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_TRACKING_INFORMATION {
+    pub DestinationFile: HANDLE,
+    pub ObjectInformationLength: ULONG,
+    pub ObjectInformation: [CHAR; 1usize],
+}
+pub type FILE_TRACKING_INFORMATION = _FILE_TRACKING_INFORMATION;
+pub type PFILE_TRACKING_INFORMATION = *mut _FILE_TRACKING_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_COMPLETION_INFORMATION {
+    pub Port: HANDLE,
+    pub Key: PVOID,
+}
+pub type FILE_COMPLETION_INFORMATION = _FILE_COMPLETION_INFORMATION;
+pub type PFILE_COMPLETION_INFORMATION = *mut _FILE_COMPLETION_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PIPE_INFORMATION {
+    pub ReadMode: ULONG,
+    pub CompletionMode: ULONG,
+}
+pub type FILE_PIPE_INFORMATION = _FILE_PIPE_INFORMATION;
+pub type PFILE_PIPE_INFORMATION = *mut _FILE_PIPE_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PIPE_LOCAL_INFORMATION {
+    pub NamedPipeType: ULONG,
+    pub NamedPipeConfiguration: ULONG,
+    pub MaximumInstances: ULONG,
+    pub CurrentInstances: ULONG,
+    pub InboundQuota: ULONG,
+    pub ReadDataAvailable: ULONG,
+    pub OutboundQuota: ULONG,
+    pub WriteQuotaAvailable: ULONG,
+    pub NamedPipeState: ULONG,
+    pub NamedPipeEnd: ULONG,
+}
+pub type FILE_PIPE_LOCAL_INFORMATION = _FILE_PIPE_LOCAL_INFORMATION;
+pub type PFILE_PIPE_LOCAL_INFORMATION = *mut _FILE_PIPE_LOCAL_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_PIPE_REMOTE_INFORMATION {
+    pub CollectDataTime: LARGE_INTEGER,
+    pub MaximumCollectionCount: ULONG,
+}
+pub type FILE_PIPE_REMOTE_INFORMATION = _FILE_PIPE_REMOTE_INFORMATION;
+pub type PFILE_PIPE_REMOTE_INFORMATION = *mut _FILE_PIPE_REMOTE_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_MAILSLOT_QUERY_INFORMATION {
+    pub MaximumMessageSize: ULONG,
+    pub MailslotQuota: ULONG,
+    pub NextMessageSize: ULONG,
+    pub MessagesAvailable: ULONG,
+    pub ReadTimeout: LARGE_INTEGER,
+}
+pub type FILE_MAILSLOT_QUERY_INFORMATION = _FILE_MAILSLOT_QUERY_INFORMATION;
+pub type PFILE_MAILSLOT_QUERY_INFORMATION = *mut _FILE_MAILSLOT_QUERY_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_MAILSLOT_SET_INFORMATION {
+    pub ReadTimeout: PLARGE_INTEGER,
+}
+pub type FILE_MAILSLOT_SET_INFORMATION = _FILE_MAILSLOT_SET_INFORMATION;
+pub type PFILE_MAILSLOT_SET_INFORMATION = *mut _FILE_MAILSLOT_SET_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_REPARSE_POINT_INFORMATION {
+    pub FileReference: LONGLONG,
+    pub Tag: ULONG,
+}
+pub type FILE_REPARSE_POINT_INFORMATION = _FILE_REPARSE_POINT_INFORMATION;
+pub type PFILE_REPARSE_POINT_INFORMATION = *mut _FILE_REPARSE_POINT_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_LINK_ENTRY_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub ParentFileId: LONGLONG,
+    pub FileNameLength: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_LINK_ENTRY_INFORMATION = _FILE_LINK_ENTRY_INFORMATION;
+pub type PFILE_LINK_ENTRY_INFORMATION = *mut _FILE_LINK_ENTRY_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_LINKS_INFORMATION {
+    pub BytesNeeded: ULONG,
+    pub EntriesReturned: ULONG,
+    pub Entry: FILE_LINK_ENTRY_INFORMATION,
+}
+pub type FILE_LINKS_INFORMATION = _FILE_LINKS_INFORMATION;
+pub type PFILE_LINKS_INFORMATION = *mut _FILE_LINKS_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_LINK_ENTRY_FULL_ID_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub ParentFileId: FILE_ID_128,
+    pub FileNameLength: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_LINK_ENTRY_FULL_ID_INFORMATION = _FILE_LINK_ENTRY_FULL_ID_INFORMATION;
+pub type PFILE_LINK_ENTRY_FULL_ID_INFORMATION = *mut _FILE_LINK_ENTRY_FULL_ID_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_LINKS_FULL_ID_INFORMATION {
+    pub BytesNeeded: ULONG,
+    pub EntriesReturned: ULONG,
+    pub Entry: FILE_LINK_ENTRY_FULL_ID_INFORMATION,
+}
+pub type FILE_LINKS_FULL_ID_INFORMATION = _FILE_LINKS_FULL_ID_INFORMATION;
+pub type PFILE_LINKS_FULL_ID_INFORMATION = *mut _FILE_LINKS_FULL_ID_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_NETWORK_PHYSICAL_NAME_INFORMATION {
+    pub FileNameLength: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_NETWORK_PHYSICAL_NAME_INFORMATION = _FILE_NETWORK_PHYSICAL_NAME_INFORMATION;
+pub type PFILE_NETWORK_PHYSICAL_NAME_INFORMATION = *mut _FILE_NETWORK_PHYSICAL_NAME_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_STANDARD_LINK_INFORMATION {
+    pub NumberOfAccessibleLinks: ULONG,
+    pub TotalNumberOfLinks: ULONG,
+    pub DeletePending: BOOLEAN,
+    pub Directory: BOOLEAN,
+}
+pub type FILE_STANDARD_LINK_INFORMATION = _FILE_STANDARD_LINK_INFORMATION;
+pub type PFILE_STANDARD_LINK_INFORMATION = *mut _FILE_STANDARD_LINK_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_GET_EA_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub EaNameLength: UCHAR,
+    pub EaName: [CHAR; 1usize],
+}
+pub type FILE_GET_EA_INFORMATION = _FILE_GET_EA_INFORMATION;
+pub type PFILE_GET_EA_INFORMATION = *mut _FILE_GET_EA_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_REMOTE_PROTOCOL_INFORMATION {
+    pub StructureVersion: USHORT,
+    pub StructureSize: USHORT,
+    pub Protocol: ULONG,
+    pub ProtocolMajorVersion: USHORT,
+    pub ProtocolMinorVersion: USHORT,
+    pub ProtocolRevision: USHORT,
+    pub Reserved: USHORT,
+    pub Flags: ULONG,
+    pub GenericReserved: _FILE_REMOTE_PROTOCOL_INFORMATION__bindgen_ty_1,
+    pub ProtocolSpecific: _FILE_REMOTE_PROTOCOL_INFORMATION__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_REMOTE_PROTOCOL_INFORMATION__bindgen_ty_1 {
+    pub Reserved: [ULONG; 8usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _FILE_REMOTE_PROTOCOL_INFORMATION__bindgen_ty_2 {
+    pub Smb2: _FILE_REMOTE_PROTOCOL_INFORMATION__bindgen_ty_2__bindgen_ty_1,
+    pub Reserved: [ULONG; 16usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_REMOTE_PROTOCOL_INFORMATION__bindgen_ty_2__bindgen_ty_1 {
+    pub Server: _FILE_REMOTE_PROTOCOL_INFORMATION__bindgen_ty_2__bindgen_ty_1__bindgen_ty_1,
+    pub Share: _FILE_REMOTE_PROTOCOL_INFORMATION__bindgen_ty_2__bindgen_ty_1__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_REMOTE_PROTOCOL_INFORMATION__bindgen_ty_2__bindgen_ty_1__bindgen_ty_1 {
+    pub Capabilities: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_REMOTE_PROTOCOL_INFORMATION__bindgen_ty_2__bindgen_ty_1__bindgen_ty_2 {
+    pub Capabilities: ULONG,
+    pub CachingFlags: ULONG,
+    pub ShareType: UCHAR,
+    pub Reserved0: [UCHAR; 3usize],
+    pub Reserved1: ULONG,
+}
+pub type FILE_REMOTE_PROTOCOL_INFORMATION = _FILE_REMOTE_PROTOCOL_INFORMATION;
+pub type PFILE_REMOTE_PROTOCOL_INFORMATION = *mut _FILE_REMOTE_PROTOCOL_INFORMATION;
+pub type FILE_GET_QUOTA_INFORMATION = _FILE_GET_QUOTA_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_QUOTA_INFORMATION {
+    pub NextEntryOffset: ULONG,
+    pub SidLength: ULONG,
+    pub ChangeTime: LARGE_INTEGER,
+    pub QuotaUsed: LARGE_INTEGER,
+    pub QuotaThreshold: LARGE_INTEGER,
+    pub QuotaLimit: LARGE_INTEGER,
+    pub Sid: SID,
+}
+pub type FILE_QUOTA_INFORMATION = _FILE_QUOTA_INFORMATION;
+pub type PFILE_QUOTA_INFORMATION = *mut _FILE_QUOTA_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_VOLUME_NAME_INFORMATION {
+    pub DeviceNameLength: ULONG,
+    pub DeviceName: [WCHAR; 1usize],
+}
+pub type FILE_VOLUME_NAME_INFORMATION = _FILE_VOLUME_NAME_INFORMATION;
+pub type PFILE_VOLUME_NAME_INFORMATION = *mut _FILE_VOLUME_NAME_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_FS_ATTRIBUTE_INFORMATION {
+    pub FileSystemAttributes: ULONG,
+    pub MaximumComponentNameLength: LONG,
+    pub FileSystemNameLength: ULONG,
+    pub FileSystemName: [WCHAR; 1usize],
+}
+pub type FILE_FS_ATTRIBUTE_INFORMATION = _FILE_FS_ATTRIBUTE_INFORMATION;
+pub type PFILE_FS_ATTRIBUTE_INFORMATION = *mut _FILE_FS_ATTRIBUTE_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_FS_DRIVER_PATH_INFORMATION {
+    pub DriverInPath: BOOLEAN,
+    pub DriverNameLength: ULONG,
+    pub DriverName: [WCHAR; 1usize],
+}
+pub type FILE_FS_DRIVER_PATH_INFORMATION = _FILE_FS_DRIVER_PATH_INFORMATION;
+pub type PFILE_FS_DRIVER_PATH_INFORMATION = *mut _FILE_FS_DRIVER_PATH_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_FS_VOLUME_FLAGS_INFORMATION {
+    pub Flags: ULONG,
+}
+pub type FILE_FS_VOLUME_FLAGS_INFORMATION = _FILE_FS_VOLUME_FLAGS_INFORMATION;
+pub type PFILE_FS_VOLUME_FLAGS_INFORMATION = *mut _FILE_FS_VOLUME_FLAGS_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_FS_CONTROL_INFORMATION {
+    pub FreeSpaceStartFiltering: LARGE_INTEGER,
+    pub FreeSpaceThreshold: LARGE_INTEGER,
+    pub FreeSpaceStopFiltering: LARGE_INTEGER,
+    pub DefaultQuotaThreshold: LARGE_INTEGER,
+    pub DefaultQuotaLimit: LARGE_INTEGER,
+    pub FileSystemControlFlags: ULONG,
+}
+pub type FILE_FS_CONTROL_INFORMATION = _FILE_FS_CONTROL_INFORMATION;
+pub type PFILE_FS_CONTROL_INFORMATION = *mut _FILE_FS_CONTROL_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_FS_DATA_COPY_INFORMATION {
+    pub NumberOfCopies: ULONG,
+}
+pub type FILE_FS_DATA_COPY_INFORMATION = _FILE_FS_DATA_COPY_INFORMATION;
+pub type PFILE_FS_DATA_COPY_INFORMATION = *mut _FILE_FS_DATA_COPY_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_END_OF_FILE_INFORMATION_EX {
+    pub EndOfFile: LARGE_INTEGER,
+    pub PagingFileSizeInMM: LARGE_INTEGER,
+    pub PagingFileMaxSize: LARGE_INTEGER,
+    pub Flags: ULONG,
+}
+pub type FILE_END_OF_FILE_INFORMATION_EX = _FILE_END_OF_FILE_INFORMATION_EX;
+pub type PFILE_END_OF_FILE_INFORMATION_EX = *mut _FILE_END_OF_FILE_INFORMATION_EX;
+unsafe extern "C" {
+    pub fn NtCreateFile(
+        FileHandle: PHANDLE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectAttributes: POBJECT_ATTRIBUTES,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        AllocationSize: PLARGE_INTEGER,
+        FileAttributes: ULONG,
+        ShareAccess: ULONG,
+        CreateDisposition: ULONG,
+        CreateOptions: ULONG,
+        EaBuffer: PVOID,
+        EaLength: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtDeviceIoControlFile(
+        FileHandle: HANDLE,
+        Event: HANDLE,
+        ApcRoutine: PIO_APC_ROUTINE,
+        ApcContext: PVOID,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        IoControlCode: ULONG,
+        InputBuffer: PVOID,
+        InputBufferLength: ULONG,
+        OutputBuffer: PVOID,
+        OutputBufferLength: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtFsControlFile(
+        FileHandle: HANDLE,
+        Event: HANDLE,
+        ApcRoutine: PIO_APC_ROUTINE,
+        ApcContext: PVOID,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        FsControlCode: ULONG,
+        InputBuffer: PVOID,
+        InputBufferLength: ULONG,
+        OutputBuffer: PVOID,
+        OutputBufferLength: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtLockFile(
+        FileHandle: HANDLE,
+        Event: HANDLE,
+        ApcRoutine: PIO_APC_ROUTINE,
+        ApcContext: PVOID,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        ByteOffset: PLARGE_INTEGER,
+        Length: PLARGE_INTEGER,
+        Key: ULONG,
+        FailImmediately: BOOLEAN,
+        ExclusiveLock: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtOpenFile(
+        FileHandle: PHANDLE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectAttributes: POBJECT_ATTRIBUTES,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        ShareAccess: ULONG,
+        OpenOptions: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtQueryDirectoryFile(
+        FileHandle: HANDLE,
+        Event: HANDLE,
+        ApcRoutine: PIO_APC_ROUTINE,
+        ApcContext: PVOID,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        FileInformation: PVOID,
+        Length: ULONG,
+        FileInformationClass: FILE_INFORMATION_CLASS,
+        ReturnSingleEntry: BOOLEAN,
+        FileName: PUNICODE_STRING,
+        RestartScan: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtQueryDirectoryFileEx(
+        FileHandle: HANDLE,
+        Event: HANDLE,
+        ApcRoutine: PIO_APC_ROUTINE,
+        ApcContext: PVOID,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        FileInformation: PVOID,
+        Length: ULONG,
+        FileInformationClass: FILE_INFORMATION_CLASS,
+        QueryFlags: ULONG,
+        FileName: PUNICODE_STRING,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtQueryInformationByName(
+        ObjectAttributes: POBJECT_ATTRIBUTES,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        FileInformation: PVOID,
+        Length: ULONG,
+        FileInformationClass: FILE_INFORMATION_CLASS,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtQueryInformationFile(
+        FileHandle: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        FileInformation: PVOID,
+        Length: ULONG,
+        FileInformationClass: FILE_INFORMATION_CLASS,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtQueryQuotaInformationFile(
+        FileHandle: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        Buffer: PVOID,
+        Length: ULONG,
+        ReturnSingleEntry: BOOLEAN,
+        SidList: PVOID,
+        SidListLength: ULONG,
+        StartSid: PSID,
+        RestartScan: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtQueryVolumeInformationFile(
+        FileHandle: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        FsInformation: PVOID,
+        Length: ULONG,
+        FsInformationClass: FS_INFORMATION_CLASS,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtReadFile(
+        FileHandle: HANDLE,
+        Event: HANDLE,
+        ApcRoutine: PIO_APC_ROUTINE,
+        ApcContext: PVOID,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        Buffer: PVOID,
+        Length: ULONG,
+        ByteOffset: PLARGE_INTEGER,
+        Key: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtSetInformationFile(
+        FileHandle: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        FileInformation: PVOID,
+        Length: ULONG,
+        FileInformationClass: FILE_INFORMATION_CLASS,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtSetQuotaInformationFile(
+        FileHandle: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        Buffer: PVOID,
+        Length: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtSetVolumeInformationFile(
+        FileHandle: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        FsInformation: PVOID,
+        Length: ULONG,
+        FsInformationClass: FS_INFORMATION_CLASS,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtWriteFile(
+        FileHandle: HANDLE,
+        Event: HANDLE,
+        ApcRoutine: PIO_APC_ROUTINE,
+        ApcContext: PVOID,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        Buffer: PVOID,
+        Length: ULONG,
+        ByteOffset: PLARGE_INTEGER,
+        Key: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtUnlockFile(
+        FileHandle: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        ByteOffset: PLARGE_INTEGER,
+        Length: PLARGE_INTEGER,
+        Key: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtFlushBuffersFileEx(
+        FileHandle: HANDLE,
+        Flags: ULONG,
+        Parameters: PVOID,
+        ParametersSize: ULONG,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+    ) -> NTSTATUS;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _PATHNAME_BUFFER {
+    pub PathNameLength: ULONG,
+    pub Name: [WCHAR; 1usize],
+}
+pub type PATHNAME_BUFFER = _PATHNAME_BUFFER;
+pub type PPATHNAME_BUFFER = *mut _PATHNAME_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSCTL_QUERY_FAT_BPB_BUFFER {
+    pub First0x24BytesOfBootSector: [UCHAR; 36usize],
+}
+pub type FSCTL_QUERY_FAT_BPB_BUFFER = _FSCTL_QUERY_FAT_BPB_BUFFER;
+pub type PFSCTL_QUERY_FAT_BPB_BUFFER = *mut _FSCTL_QUERY_FAT_BPB_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct NTFS_VOLUME_DATA_BUFFER {
+    pub VolumeSerialNumber: LARGE_INTEGER,
+    pub NumberSectors: LARGE_INTEGER,
+    pub TotalClusters: LARGE_INTEGER,
+    pub FreeClusters: LARGE_INTEGER,
+    pub TotalReserved: LARGE_INTEGER,
+    pub BytesPerSector: ULONG,
+    pub BytesPerCluster: ULONG,
+    pub BytesPerFileRecordSegment: ULONG,
+    pub ClustersPerFileRecordSegment: ULONG,
+    pub MftValidDataLength: LARGE_INTEGER,
+    pub MftStartLcn: LARGE_INTEGER,
+    pub Mft2StartLcn: LARGE_INTEGER,
+    pub MftZoneStart: LARGE_INTEGER,
+    pub MftZoneEnd: LARGE_INTEGER,
+}
+pub type PNTFS_VOLUME_DATA_BUFFER = *mut NTFS_VOLUME_DATA_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct NTFS_EXTENDED_VOLUME_DATA {
+    pub ByteCount: ULONG,
+    pub MajorVersion: USHORT,
+    pub MinorVersion: USHORT,
+    pub BytesPerPhysicalSector: ULONG,
+    pub LfsMajorVersion: USHORT,
+    pub LfsMinorVersion: USHORT,
+    pub MaxDeviceTrimExtentCount: ULONG,
+    pub MaxDeviceTrimByteCount: ULONG,
+    pub MaxVolumeTrimExtentCount: ULONG,
+    pub MaxVolumeTrimByteCount: ULONG,
+}
+pub type PNTFS_EXTENDED_VOLUME_DATA = *mut NTFS_EXTENDED_VOLUME_DATA;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct REFS_VOLUME_DATA_BUFFER {
+    pub ByteCount: ULONG,
+    pub MajorVersion: ULONG,
+    pub MinorVersion: ULONG,
+    pub BytesPerPhysicalSector: ULONG,
+    pub VolumeSerialNumber: LARGE_INTEGER,
+    pub NumberSectors: LARGE_INTEGER,
+    pub TotalClusters: LARGE_INTEGER,
+    pub FreeClusters: LARGE_INTEGER,
+    pub TotalReserved: LARGE_INTEGER,
+    pub BytesPerSector: ULONG,
+    pub BytesPerCluster: ULONG,
+    pub MaximumSizeOfResidentFile: LARGE_INTEGER,
+    pub FastTierDataFillRatio: USHORT,
+    pub SlowTierDataFillRatio: USHORT,
+    pub DestagesFastTierToSlowTierRate: ULONG,
+    pub MetadataChecksumType: USHORT,
+    pub Reserved0: [UCHAR; 6usize],
+    pub Reserved: [LARGE_INTEGER; 8usize],
+}
+pub type PREFS_VOLUME_DATA_BUFFER = *mut REFS_VOLUME_DATA_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct STARTING_LCN_INPUT_BUFFER {
+    pub StartingLcn: LARGE_INTEGER,
+}
+pub type PSTARTING_LCN_INPUT_BUFFER = *mut STARTING_LCN_INPUT_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct STARTING_LCN_INPUT_BUFFER_EX {
+    pub StartingLcn: LARGE_INTEGER,
+    pub Flags: ULONG,
+}
+pub type PSTARTING_LCN_INPUT_BUFFER_EX = *mut STARTING_LCN_INPUT_BUFFER_EX;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct VOLUME_BITMAP_BUFFER {
+    pub StartingLcn: LARGE_INTEGER,
+    pub BitmapSize: LARGE_INTEGER,
+    pub Buffer: [UCHAR; 1usize],
+}
+pub type PVOLUME_BITMAP_BUFFER = *mut VOLUME_BITMAP_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct STARTING_VCN_INPUT_BUFFER {
+    pub StartingVcn: LARGE_INTEGER,
+}
+pub type PSTARTING_VCN_INPUT_BUFFER = *mut STARTING_VCN_INPUT_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RETRIEVAL_POINTERS_BUFFER {
+    pub ExtentCount: ULONG,
+    pub StartingVcn: LARGE_INTEGER,
+    pub Extents: [RETRIEVAL_POINTERS_BUFFER__bindgen_ty_1; 1usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RETRIEVAL_POINTERS_BUFFER__bindgen_ty_1 {
+    pub NextVcn: LARGE_INTEGER,
+    pub Lcn: LARGE_INTEGER,
+}
+pub type PRETRIEVAL_POINTERS_BUFFER = *mut RETRIEVAL_POINTERS_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RETRIEVAL_POINTERS_AND_REFCOUNT_BUFFER {
+    pub ExtentCount: ULONG,
+    pub StartingVcn: LARGE_INTEGER,
+    pub Extents: [RETRIEVAL_POINTERS_AND_REFCOUNT_BUFFER__bindgen_ty_1; 1usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct RETRIEVAL_POINTERS_AND_REFCOUNT_BUFFER__bindgen_ty_1 {
+    pub NextVcn: LARGE_INTEGER,
+    pub Lcn: LARGE_INTEGER,
+    pub ReferenceCount: ULONG,
+}
+pub type PRETRIEVAL_POINTERS_AND_REFCOUNT_BUFFER = *mut RETRIEVAL_POINTERS_AND_REFCOUNT_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct RETRIEVAL_POINTER_COUNT {
+    pub ExtentCount: ULONG,
+}
+pub type PRETRIEVAL_POINTER_COUNT = *mut RETRIEVAL_POINTER_COUNT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct NTFS_FILE_RECORD_INPUT_BUFFER {
+    pub FileReferenceNumber: LARGE_INTEGER,
+}
+pub type PNTFS_FILE_RECORD_INPUT_BUFFER = *mut NTFS_FILE_RECORD_INPUT_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct NTFS_FILE_RECORD_OUTPUT_BUFFER {
+    pub FileReferenceNumber: LARGE_INTEGER,
+    pub FileRecordLength: ULONG,
+    pub FileRecordBuffer: [UCHAR; 1usize],
+}
+pub type PNTFS_FILE_RECORD_OUTPUT_BUFFER = *mut NTFS_FILE_RECORD_OUTPUT_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MOVE_FILE_DATA {
+    pub FileHandle: HANDLE,
+    pub StartingVcn: LARGE_INTEGER,
+    pub StartingLcn: LARGE_INTEGER,
+    pub ClusterCount: ULONG,
+}
+pub type PMOVE_FILE_DATA = *mut MOVE_FILE_DATA;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MOVE_FILE_RECORD_DATA {
+    pub FileHandle: HANDLE,
+    pub SourceFileRecord: LARGE_INTEGER,
+    pub TargetFileRecord: LARGE_INTEGER,
+}
+pub type PMOVE_FILE_RECORD_DATA = *mut MOVE_FILE_RECORD_DATA;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _MOVE_FILE_DATA32 {
+    pub FileHandle: UINT32,
+    pub StartingVcn: LARGE_INTEGER,
+    pub StartingLcn: LARGE_INTEGER,
+    pub ClusterCount: ULONG,
+}
+pub type MOVE_FILE_DATA32 = _MOVE_FILE_DATA32;
+pub type PMOVE_FILE_DATA32 = *mut _MOVE_FILE_DATA32;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FIND_BY_SID_DATA {
+    pub Restart: ULONG,
+    pub Sid: SID,
+}
+pub type PFIND_BY_SID_DATA = *mut FIND_BY_SID_DATA;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FIND_BY_SID_OUTPUT {
+    pub NextEntryOffset: ULONG,
+    pub FileIndex: ULONG,
+    pub FileNameLength: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type PFIND_BY_SID_OUTPUT = *mut FIND_BY_SID_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct MFT_ENUM_DATA_V0 {
+    pub StartFileReferenceNumber: ULONGLONG,
+    pub LowUsn: USN,
+    pub HighUsn: USN,
+}
+pub type PMFT_ENUM_DATA_V0 = *mut MFT_ENUM_DATA_V0;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct MFT_ENUM_DATA_V1 {
+    pub StartFileReferenceNumber: ULONGLONG,
+    pub LowUsn: USN,
+    pub HighUsn: USN,
+    pub MinMajorVersion: USHORT,
+    pub MaxMajorVersion: USHORT,
+}
+pub type PMFT_ENUM_DATA_V1 = *mut MFT_ENUM_DATA_V1;
+pub type MFT_ENUM_DATA = MFT_ENUM_DATA_V1;
+pub type PMFT_ENUM_DATA = *mut MFT_ENUM_DATA_V1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CREATE_USN_JOURNAL_DATA {
+    pub MaximumSize: ULONGLONG,
+    pub AllocationDelta: ULONGLONG,
+}
+pub type PCREATE_USN_JOURNAL_DATA = *mut CREATE_USN_JOURNAL_DATA;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct READ_FILE_USN_DATA {
+    pub MinMajorVersion: USHORT,
+    pub MaxMajorVersion: USHORT,
+}
+pub type PREAD_FILE_USN_DATA = *mut READ_FILE_USN_DATA;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct READ_USN_JOURNAL_DATA_V0 {
+    pub StartUsn: USN,
+    pub ReasonMask: ULONG,
+    pub ReturnOnlyOnClose: ULONG,
+    pub Timeout: ULONGLONG,
+    pub BytesToWaitFor: ULONGLONG,
+    pub UsnJournalID: ULONGLONG,
+}
+pub type PREAD_USN_JOURNAL_DATA_V0 = *mut READ_USN_JOURNAL_DATA_V0;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct READ_USN_JOURNAL_DATA_V1 {
+    pub StartUsn: USN,
+    pub ReasonMask: ULONG,
+    pub ReturnOnlyOnClose: ULONG,
+    pub Timeout: ULONGLONG,
+    pub BytesToWaitFor: ULONGLONG,
+    pub UsnJournalID: ULONGLONG,
+    pub MinMajorVersion: USHORT,
+    pub MaxMajorVersion: USHORT,
+}
+pub type PREAD_USN_JOURNAL_DATA_V1 = *mut READ_USN_JOURNAL_DATA_V1;
+pub type READ_USN_JOURNAL_DATA = READ_USN_JOURNAL_DATA_V1;
+pub type PREAD_USN_JOURNAL_DATA = *mut READ_USN_JOURNAL_DATA_V1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct USN_TRACK_MODIFIED_RANGES {
+    pub Flags: ULONG,
+    pub Unused: ULONG,
+    pub ChunkSize: ULONGLONG,
+    pub FileSizeThreshold: LONGLONG,
+}
+pub type PUSN_TRACK_MODIFIED_RANGES = *mut USN_TRACK_MODIFIED_RANGES;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct USN_RANGE_TRACK_OUTPUT {
+    pub Usn: USN,
+}
+pub type PUSN_RANGE_TRACK_OUTPUT = *mut USN_RANGE_TRACK_OUTPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct USN_RECORD_V2 {
+    pub RecordLength: ULONG,
+    pub MajorVersion: USHORT,
+    pub MinorVersion: USHORT,
+    pub FileReferenceNumber: ULONGLONG,
+    pub ParentFileReferenceNumber: ULONGLONG,
+    pub Usn: USN,
+    pub TimeStamp: LARGE_INTEGER,
+    pub Reason: ULONG,
+    pub SourceInfo: ULONG,
+    pub SecurityId: ULONG,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: USHORT,
+    pub FileNameOffset: USHORT,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type PUSN_RECORD_V2 = *mut USN_RECORD_V2;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct USN_RECORD_V3 {
+    pub RecordLength: ULONG,
+    pub MajorVersion: USHORT,
+    pub MinorVersion: USHORT,
+    pub FileReferenceNumber: FILE_ID_128,
+    pub ParentFileReferenceNumber: FILE_ID_128,
+    pub Usn: USN,
+    pub TimeStamp: LARGE_INTEGER,
+    pub Reason: ULONG,
+    pub SourceInfo: ULONG,
+    pub SecurityId: ULONG,
+    pub FileAttributes: ULONG,
+    pub FileNameLength: USHORT,
+    pub FileNameOffset: USHORT,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type PUSN_RECORD_V3 = *mut USN_RECORD_V3;
+pub type USN_RECORD = USN_RECORD_V2;
+pub type PUSN_RECORD = *mut USN_RECORD_V2;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct USN_RECORD_COMMON_HEADER {
+    pub RecordLength: ULONG,
+    pub MajorVersion: USHORT,
+    pub MinorVersion: USHORT,
+}
+pub type PUSN_RECORD_COMMON_HEADER = *mut USN_RECORD_COMMON_HEADER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct USN_RECORD_EXTENT {
+    pub Offset: LONGLONG,
+    pub Length: LONGLONG,
+}
+pub type PUSN_RECORD_EXTENT = *mut USN_RECORD_EXTENT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct USN_RECORD_V4 {
+    pub Header: USN_RECORD_COMMON_HEADER,
+    pub FileReferenceNumber: FILE_ID_128,
+    pub ParentFileReferenceNumber: FILE_ID_128,
+    pub Usn: USN,
+    pub Reason: ULONG,
+    pub SourceInfo: ULONG,
+    pub RemainingExtents: ULONG,
+    pub NumberOfExtents: USHORT,
+    pub ExtentSize: USHORT,
+    pub Extents: [USN_RECORD_EXTENT; 1usize],
+}
+pub type PUSN_RECORD_V4 = *mut USN_RECORD_V4;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union USN_RECORD_UNION {
+    pub Header: USN_RECORD_COMMON_HEADER,
+    pub V2: USN_RECORD_V2,
+    pub V3: USN_RECORD_V3,
+    pub V4: USN_RECORD_V4,
+}
+pub type PUSN_RECORD_UNION = *mut USN_RECORD_UNION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct USN_JOURNAL_DATA_V0 {
+    pub UsnJournalID: ULONGLONG,
+    pub FirstUsn: USN,
+    pub NextUsn: USN,
+    pub LowestValidUsn: USN,
+    pub MaxUsn: USN,
+    pub MaximumSize: ULONGLONG,
+    pub AllocationDelta: ULONGLONG,
+}
+pub type PUSN_JOURNAL_DATA_V0 = *mut USN_JOURNAL_DATA_V0;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct USN_JOURNAL_DATA_V1 {
+    pub UsnJournalID: ULONGLONG,
+    pub FirstUsn: USN,
+    pub NextUsn: USN,
+    pub LowestValidUsn: USN,
+    pub MaxUsn: USN,
+    pub MaximumSize: ULONGLONG,
+    pub AllocationDelta: ULONGLONG,
+    pub MinSupportedMajorVersion: USHORT,
+    pub MaxSupportedMajorVersion: USHORT,
+}
+pub type PUSN_JOURNAL_DATA_V1 = *mut USN_JOURNAL_DATA_V1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct USN_JOURNAL_DATA_V2 {
+    pub UsnJournalID: ULONGLONG,
+    pub FirstUsn: USN,
+    pub NextUsn: USN,
+    pub LowestValidUsn: USN,
+    pub MaxUsn: USN,
+    pub MaximumSize: ULONGLONG,
+    pub AllocationDelta: ULONGLONG,
+    pub MinSupportedMajorVersion: USHORT,
+    pub MaxSupportedMajorVersion: USHORT,
+    pub Flags: ULONG,
+    pub RangeTrackChunkSize: ULONGLONG,
+    pub RangeTrackFileSizeThreshold: LONGLONG,
+}
+pub type PUSN_JOURNAL_DATA_V2 = *mut USN_JOURNAL_DATA_V2;
+pub type USN_JOURNAL_DATA = USN_JOURNAL_DATA_V1;
+pub type PUSN_JOURNAL_DATA = *mut USN_JOURNAL_DATA_V1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DELETE_USN_JOURNAL_DATA {
+    pub UsnJournalID: ULONGLONG,
+    pub DeleteFlags: ULONG,
+}
+pub type PDELETE_USN_JOURNAL_DATA = *mut DELETE_USN_JOURNAL_DATA;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _MARK_HANDLE_INFO {
+    pub __bindgen_anon_1: _MARK_HANDLE_INFO__bindgen_ty_1,
+    pub VolumeHandle: HANDLE,
+    pub HandleInfo: ULONG,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _MARK_HANDLE_INFO__bindgen_ty_1 {
+    pub UsnSourceInfo: ULONG,
+    pub CopyNumber: ULONG,
+}
+pub type MARK_HANDLE_INFO = _MARK_HANDLE_INFO;
+pub type PMARK_HANDLE_INFO = *mut _MARK_HANDLE_INFO;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _MARK_HANDLE_INFO32 {
+    pub __bindgen_anon_1: _MARK_HANDLE_INFO32__bindgen_ty_1,
+    pub VolumeHandle: UINT32,
+    pub HandleInfo: ULONG,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _MARK_HANDLE_INFO32__bindgen_ty_1 {
+    pub UsnSourceInfo: ULONG,
+    pub CopyNumber: ULONG,
+}
+pub type MARK_HANDLE_INFO32 = _MARK_HANDLE_INFO32;
+pub type PMARK_HANDLE_INFO32 = *mut _MARK_HANDLE_INFO32;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct BULK_SECURITY_TEST_DATA {
+    pub DesiredAccess: ACCESS_MASK,
+    pub SecurityIds: [ULONG; 1usize],
+}
+pub type PBULK_SECURITY_TEST_DATA = *mut BULK_SECURITY_TEST_DATA;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PREFETCH {
+    pub Type: ULONG,
+    pub Count: ULONG,
+    pub Prefetch: [ULONGLONG; 1usize],
+}
+pub type FILE_PREFETCH = _FILE_PREFETCH;
+pub type PFILE_PREFETCH = *mut _FILE_PREFETCH;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PREFETCH_EX {
+    pub Type: ULONG,
+    pub Count: ULONG,
+    pub Context: PVOID,
+    pub Prefetch: [ULONGLONG; 1usize],
+}
+pub type FILE_PREFETCH_EX = _FILE_PREFETCH_EX;
+pub type PFILE_PREFETCH_EX = *mut _FILE_PREFETCH_EX;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILESYSTEM_STATISTICS {
+    pub FileSystemType: USHORT,
+    pub Version: USHORT,
+    pub SizeOfCompleteStructure: ULONG,
+    pub UserFileReads: ULONG,
+    pub UserFileReadBytes: ULONG,
+    pub UserDiskReads: ULONG,
+    pub UserFileWrites: ULONG,
+    pub UserFileWriteBytes: ULONG,
+    pub UserDiskWrites: ULONG,
+    pub MetaDataReads: ULONG,
+    pub MetaDataReadBytes: ULONG,
+    pub MetaDataDiskReads: ULONG,
+    pub MetaDataWrites: ULONG,
+    pub MetaDataWriteBytes: ULONG,
+    pub MetaDataDiskWrites: ULONG,
+}
+pub type FILESYSTEM_STATISTICS = _FILESYSTEM_STATISTICS;
+pub type PFILESYSTEM_STATISTICS = *mut _FILESYSTEM_STATISTICS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FAT_STATISTICS {
+    pub CreateHits: ULONG,
+    pub SuccessfulCreates: ULONG,
+    pub FailedCreates: ULONG,
+    pub NonCachedReads: ULONG,
+    pub NonCachedReadBytes: ULONG,
+    pub NonCachedWrites: ULONG,
+    pub NonCachedWriteBytes: ULONG,
+    pub NonCachedDiskReads: ULONG,
+    pub NonCachedDiskWrites: ULONG,
+}
+pub type FAT_STATISTICS = _FAT_STATISTICS;
+pub type PFAT_STATISTICS = *mut _FAT_STATISTICS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _EXFAT_STATISTICS {
+    pub CreateHits: ULONG,
+    pub SuccessfulCreates: ULONG,
+    pub FailedCreates: ULONG,
+    pub NonCachedReads: ULONG,
+    pub NonCachedReadBytes: ULONG,
+    pub NonCachedWrites: ULONG,
+    pub NonCachedWriteBytes: ULONG,
+    pub NonCachedDiskReads: ULONG,
+    pub NonCachedDiskWrites: ULONG,
+}
+pub type EXFAT_STATISTICS = _EXFAT_STATISTICS;
+pub type PEXFAT_STATISTICS = *mut _EXFAT_STATISTICS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NTFS_STATISTICS {
+    pub LogFileFullExceptions: ULONG,
+    pub OtherExceptions: ULONG,
+    pub MftReads: ULONG,
+    pub MftReadBytes: ULONG,
+    pub MftWrites: ULONG,
+    pub MftWriteBytes: ULONG,
+    pub MftWritesUserLevel: _NTFS_STATISTICS__bindgen_ty_1,
+    pub MftWritesFlushForLogFileFull: USHORT,
+    pub MftWritesLazyWriter: USHORT,
+    pub MftWritesUserRequest: USHORT,
+    pub Mft2Writes: ULONG,
+    pub Mft2WriteBytes: ULONG,
+    pub Mft2WritesUserLevel: _NTFS_STATISTICS__bindgen_ty_2,
+    pub Mft2WritesFlushForLogFileFull: USHORT,
+    pub Mft2WritesLazyWriter: USHORT,
+    pub Mft2WritesUserRequest: USHORT,
+    pub RootIndexReads: ULONG,
+    pub RootIndexReadBytes: ULONG,
+    pub RootIndexWrites: ULONG,
+    pub RootIndexWriteBytes: ULONG,
+    pub BitmapReads: ULONG,
+    pub BitmapReadBytes: ULONG,
+    pub BitmapWrites: ULONG,
+    pub BitmapWriteBytes: ULONG,
+    pub BitmapWritesFlushForLogFileFull: USHORT,
+    pub BitmapWritesLazyWriter: USHORT,
+    pub BitmapWritesUserRequest: USHORT,
+    pub BitmapWritesUserLevel: _NTFS_STATISTICS__bindgen_ty_3,
+    pub MftBitmapReads: ULONG,
+    pub MftBitmapReadBytes: ULONG,
+    pub MftBitmapWrites: ULONG,
+    pub MftBitmapWriteBytes: ULONG,
+    pub MftBitmapWritesFlushForLogFileFull: USHORT,
+    pub MftBitmapWritesLazyWriter: USHORT,
+    pub MftBitmapWritesUserRequest: USHORT,
+    pub MftBitmapWritesUserLevel: _NTFS_STATISTICS__bindgen_ty_4,
+    pub UserIndexReads: ULONG,
+    pub UserIndexReadBytes: ULONG,
+    pub UserIndexWrites: ULONG,
+    pub UserIndexWriteBytes: ULONG,
+    pub LogFileReads: ULONG,
+    pub LogFileReadBytes: ULONG,
+    pub LogFileWrites: ULONG,
+    pub LogFileWriteBytes: ULONG,
+    pub Allocate: _NTFS_STATISTICS__bindgen_ty_5,
+    pub DiskResourcesExhausted: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NTFS_STATISTICS__bindgen_ty_1 {
+    pub Write: USHORT,
+    pub Create: USHORT,
+    pub SetInfo: USHORT,
+    pub Flush: USHORT,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NTFS_STATISTICS__bindgen_ty_2 {
+    pub Write: USHORT,
+    pub Create: USHORT,
+    pub SetInfo: USHORT,
+    pub Flush: USHORT,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NTFS_STATISTICS__bindgen_ty_3 {
+    pub Write: USHORT,
+    pub Create: USHORT,
+    pub SetInfo: USHORT,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NTFS_STATISTICS__bindgen_ty_4 {
+    pub Write: USHORT,
+    pub Create: USHORT,
+    pub SetInfo: USHORT,
+    pub Flush: USHORT,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NTFS_STATISTICS__bindgen_ty_5 {
+    pub Calls: ULONG,
+    pub Clusters: ULONG,
+    pub Hints: ULONG,
+    pub RunsReturned: ULONG,
+    pub HintsHonored: ULONG,
+    pub HintsClusters: ULONG,
+    pub Cache: ULONG,
+    pub CacheClusters: ULONG,
+    pub CacheMiss: ULONG,
+    pub CacheMissClusters: ULONG,
+}
+pub type NTFS_STATISTICS = _NTFS_STATISTICS;
+pub type PNTFS_STATISTICS = *mut _NTFS_STATISTICS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILESYSTEM_STATISTICS_EX {
+    pub FileSystemType: USHORT,
+    pub Version: USHORT,
+    pub SizeOfCompleteStructure: ULONG,
+    pub UserFileReads: ULONGLONG,
+    pub UserFileReadBytes: ULONGLONG,
+    pub UserDiskReads: ULONGLONG,
+    pub UserFileWrites: ULONGLONG,
+    pub UserFileWriteBytes: ULONGLONG,
+    pub UserDiskWrites: ULONGLONG,
+    pub MetaDataReads: ULONGLONG,
+    pub MetaDataReadBytes: ULONGLONG,
+    pub MetaDataDiskReads: ULONGLONG,
+    pub MetaDataWrites: ULONGLONG,
+    pub MetaDataWriteBytes: ULONGLONG,
+    pub MetaDataDiskWrites: ULONGLONG,
+}
+pub type FILESYSTEM_STATISTICS_EX = _FILESYSTEM_STATISTICS_EX;
+pub type PFILESYSTEM_STATISTICS_EX = *mut _FILESYSTEM_STATISTICS_EX;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NTFS_STATISTICS_EX {
+    pub LogFileFullExceptions: ULONG,
+    pub OtherExceptions: ULONG,
+    pub MftReads: ULONGLONG,
+    pub MftReadBytes: ULONGLONG,
+    pub MftWrites: ULONGLONG,
+    pub MftWriteBytes: ULONGLONG,
+    pub MftWritesUserLevel: _NTFS_STATISTICS_EX__bindgen_ty_1,
+    pub MftWritesFlushForLogFileFull: ULONG,
+    pub MftWritesLazyWriter: ULONG,
+    pub MftWritesUserRequest: ULONG,
+    pub Mft2Writes: ULONGLONG,
+    pub Mft2WriteBytes: ULONGLONG,
+    pub Mft2WritesUserLevel: _NTFS_STATISTICS_EX__bindgen_ty_2,
+    pub Mft2WritesFlushForLogFileFull: ULONG,
+    pub Mft2WritesLazyWriter: ULONG,
+    pub Mft2WritesUserRequest: ULONG,
+    pub RootIndexReads: ULONGLONG,
+    pub RootIndexReadBytes: ULONGLONG,
+    pub RootIndexWrites: ULONGLONG,
+    pub RootIndexWriteBytes: ULONGLONG,
+    pub BitmapReads: ULONGLONG,
+    pub BitmapReadBytes: ULONGLONG,
+    pub BitmapWrites: ULONGLONG,
+    pub BitmapWriteBytes: ULONGLONG,
+    pub BitmapWritesFlushForLogFileFull: ULONG,
+    pub BitmapWritesLazyWriter: ULONG,
+    pub BitmapWritesUserRequest: ULONG,
+    pub BitmapWritesUserLevel: _NTFS_STATISTICS_EX__bindgen_ty_3,
+    pub MftBitmapReads: ULONGLONG,
+    pub MftBitmapReadBytes: ULONGLONG,
+    pub MftBitmapWrites: ULONGLONG,
+    pub MftBitmapWriteBytes: ULONGLONG,
+    pub MftBitmapWritesFlushForLogFileFull: ULONG,
+    pub MftBitmapWritesLazyWriter: ULONG,
+    pub MftBitmapWritesUserRequest: ULONG,
+    pub MftBitmapWritesUserLevel: _NTFS_STATISTICS_EX__bindgen_ty_4,
+    pub UserIndexReads: ULONGLONG,
+    pub UserIndexReadBytes: ULONGLONG,
+    pub UserIndexWrites: ULONGLONG,
+    pub UserIndexWriteBytes: ULONGLONG,
+    pub LogFileReads: ULONGLONG,
+    pub LogFileReadBytes: ULONGLONG,
+    pub LogFileWrites: ULONGLONG,
+    pub LogFileWriteBytes: ULONGLONG,
+    pub Allocate: _NTFS_STATISTICS_EX__bindgen_ty_5,
+    pub DiskResourcesExhausted: ULONG,
+    pub VolumeTrimCount: ULONGLONG,
+    pub VolumeTrimTime: ULONGLONG,
+    pub VolumeTrimByteCount: ULONGLONG,
+    pub FileLevelTrimCount: ULONGLONG,
+    pub FileLevelTrimTime: ULONGLONG,
+    pub FileLevelTrimByteCount: ULONGLONG,
+    pub VolumeTrimSkippedCount: ULONGLONG,
+    pub VolumeTrimSkippedByteCount: ULONGLONG,
+    pub NtfsFillStatInfoFromMftRecordCalledCount: ULONGLONG,
+    pub NtfsFillStatInfoFromMftRecordBailedBecauseOfAttributeListCount: ULONGLONG,
+    pub NtfsFillStatInfoFromMftRecordBailedBecauseOfNonResReparsePointCount: ULONGLONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NTFS_STATISTICS_EX__bindgen_ty_1 {
+    pub Write: ULONG,
+    pub Create: ULONG,
+    pub SetInfo: ULONG,
+    pub Flush: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NTFS_STATISTICS_EX__bindgen_ty_2 {
+    pub Write: ULONG,
+    pub Create: ULONG,
+    pub SetInfo: ULONG,
+    pub Flush: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NTFS_STATISTICS_EX__bindgen_ty_3 {
+    pub Write: ULONG,
+    pub Create: ULONG,
+    pub SetInfo: ULONG,
+    pub Flush: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NTFS_STATISTICS_EX__bindgen_ty_4 {
+    pub Write: ULONG,
+    pub Create: ULONG,
+    pub SetInfo: ULONG,
+    pub Flush: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NTFS_STATISTICS_EX__bindgen_ty_5 {
+    pub Calls: ULONG,
+    pub RunsReturned: ULONG,
+    pub Hints: ULONG,
+    pub HintsHonored: ULONG,
+    pub Cache: ULONG,
+    pub CacheMiss: ULONG,
+    pub Clusters: ULONGLONG,
+    pub HintsClusters: ULONGLONG,
+    pub CacheClusters: ULONGLONG,
+    pub CacheMissClusters: ULONGLONG,
+}
+pub type NTFS_STATISTICS_EX = _NTFS_STATISTICS_EX;
+pub type PNTFS_STATISTICS_EX = *mut _NTFS_STATISTICS_EX;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_OBJECTID_BUFFER {
+    pub ObjectId: [UCHAR; 16usize],
+    pub __bindgen_anon_1: _FILE_OBJECTID_BUFFER__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _FILE_OBJECTID_BUFFER__bindgen_ty_1 {
+    pub __bindgen_anon_1: _FILE_OBJECTID_BUFFER__bindgen_ty_1__bindgen_ty_1,
+    pub ExtendedInfo: [UCHAR; 48usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_OBJECTID_BUFFER__bindgen_ty_1__bindgen_ty_1 {
+    pub BirthVolumeId: [UCHAR; 16usize],
+    pub BirthObjectId: [UCHAR; 16usize],
+    pub DomainId: [UCHAR; 16usize],
+}
+pub type FILE_OBJECTID_BUFFER = _FILE_OBJECTID_BUFFER;
+pub type PFILE_OBJECTID_BUFFER = *mut _FILE_OBJECTID_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_SET_SPARSE_BUFFER {
+    pub SetSparse: BOOLEAN,
+}
+pub type FILE_SET_SPARSE_BUFFER = _FILE_SET_SPARSE_BUFFER;
+pub type PFILE_SET_SPARSE_BUFFER = *mut _FILE_SET_SPARSE_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ZERO_DATA_INFORMATION {
+    pub FileOffset: LARGE_INTEGER,
+    pub BeyondFinalZero: LARGE_INTEGER,
+}
+pub type FILE_ZERO_DATA_INFORMATION = _FILE_ZERO_DATA_INFORMATION;
+pub type PFILE_ZERO_DATA_INFORMATION = *mut _FILE_ZERO_DATA_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ZERO_DATA_INFORMATION_EX {
+    pub FileOffset: LARGE_INTEGER,
+    pub BeyondFinalZero: LARGE_INTEGER,
+    pub Flags: ULONG,
+}
+pub type FILE_ZERO_DATA_INFORMATION_EX = _FILE_ZERO_DATA_INFORMATION_EX;
+pub type PFILE_ZERO_DATA_INFORMATION_EX = *mut _FILE_ZERO_DATA_INFORMATION_EX;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_ALLOCATED_RANGE_BUFFER {
+    pub FileOffset: LARGE_INTEGER,
+    pub Length: LARGE_INTEGER,
+}
+pub type FILE_ALLOCATED_RANGE_BUFFER = _FILE_ALLOCATED_RANGE_BUFFER;
+pub type PFILE_ALLOCATED_RANGE_BUFFER = *mut _FILE_ALLOCATED_RANGE_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENCRYPTION_BUFFER {
+    pub EncryptionOperation: ULONG,
+    pub Private: [UCHAR; 1usize],
+}
+pub type ENCRYPTION_BUFFER = _ENCRYPTION_BUFFER;
+pub type PENCRYPTION_BUFFER = *mut _ENCRYPTION_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _DECRYPTION_STATUS_BUFFER {
+    pub NoEncryptedStreams: BOOLEAN,
+}
+pub type DECRYPTION_STATUS_BUFFER = _DECRYPTION_STATUS_BUFFER;
+pub type PDECRYPTION_STATUS_BUFFER = *mut _DECRYPTION_STATUS_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REQUEST_RAW_ENCRYPTED_DATA {
+    pub FileOffset: LONGLONG,
+    pub Length: ULONG,
+}
+pub type REQUEST_RAW_ENCRYPTED_DATA = _REQUEST_RAW_ENCRYPTED_DATA;
+pub type PREQUEST_RAW_ENCRYPTED_DATA = *mut _REQUEST_RAW_ENCRYPTED_DATA;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENCRYPTED_DATA_INFO {
+    pub StartingFileOffset: ULONGLONG,
+    pub OutputBufferOffset: ULONG,
+    pub BytesWithinFileSize: ULONG,
+    pub BytesWithinValidDataLength: ULONG,
+    pub CompressionFormat: USHORT,
+    pub DataUnitShift: UCHAR,
+    pub ChunkShift: UCHAR,
+    pub ClusterShift: UCHAR,
+    pub EncryptionFormat: UCHAR,
+    pub NumberOfDataBlocks: USHORT,
+    pub DataBlockSize: [ULONG; 1usize],
+}
+pub type ENCRYPTED_DATA_INFO = _ENCRYPTED_DATA_INFO;
+pub type PENCRYPTED_DATA_INFO = *mut _ENCRYPTED_DATA_INFO;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _EXTENDED_ENCRYPTED_DATA_INFO {
+    pub ExtendedCode: ULONG,
+    pub Length: ULONG,
+    pub Flags: ULONG,
+    pub Reserved: ULONG,
+}
+pub type EXTENDED_ENCRYPTED_DATA_INFO = _EXTENDED_ENCRYPTED_DATA_INFO;
+pub type PEXTENDED_ENCRYPTED_DATA_INFO = *mut _EXTENDED_ENCRYPTED_DATA_INFO;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _PLEX_READ_DATA_REQUEST {
+    pub ByteOffset: LARGE_INTEGER,
+    pub ByteLength: ULONG,
+    pub PlexNumber: ULONG,
+}
+pub type PLEX_READ_DATA_REQUEST = _PLEX_READ_DATA_REQUEST;
+pub type PPLEX_READ_DATA_REQUEST = *mut _PLEX_READ_DATA_REQUEST;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SI_COPYFILE {
+    pub SourceFileNameLength: ULONG,
+    pub DestinationFileNameLength: ULONG,
+    pub Flags: ULONG,
+    pub FileNameBuffer: [WCHAR; 1usize],
+}
+pub type SI_COPYFILE = _SI_COPYFILE;
+pub type PSI_COPYFILE = *mut _SI_COPYFILE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_MAKE_COMPATIBLE_BUFFER {
+    pub CloseDisc: BOOLEAN,
+}
+pub type FILE_MAKE_COMPATIBLE_BUFFER = _FILE_MAKE_COMPATIBLE_BUFFER;
+pub type PFILE_MAKE_COMPATIBLE_BUFFER = *mut _FILE_MAKE_COMPATIBLE_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_SET_DEFECT_MGMT_BUFFER {
+    pub Disable: BOOLEAN,
+}
+pub type FILE_SET_DEFECT_MGMT_BUFFER = _FILE_SET_DEFECT_MGMT_BUFFER;
+pub type PFILE_SET_DEFECT_MGMT_BUFFER = *mut _FILE_SET_DEFECT_MGMT_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_QUERY_SPARING_BUFFER {
+    pub SparingUnitBytes: ULONG,
+    pub SoftwareSparing: BOOLEAN,
+    pub TotalSpareBlocks: ULONG,
+    pub FreeSpareBlocks: ULONG,
+}
+pub type FILE_QUERY_SPARING_BUFFER = _FILE_QUERY_SPARING_BUFFER;
+pub type PFILE_QUERY_SPARING_BUFFER = *mut _FILE_QUERY_SPARING_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_QUERY_ON_DISK_VOL_INFO_BUFFER {
+    pub DirectoryCount: LARGE_INTEGER,
+    pub FileCount: LARGE_INTEGER,
+    pub FsFormatMajVersion: USHORT,
+    pub FsFormatMinVersion: USHORT,
+    pub FsFormatName: [WCHAR; 12usize],
+    pub FormatTime: LARGE_INTEGER,
+    pub LastUpdateTime: LARGE_INTEGER,
+    pub CopyrightInfo: [WCHAR; 34usize],
+    pub AbstractInfo: [WCHAR; 34usize],
+    pub FormattingImplementationInfo: [WCHAR; 34usize],
+    pub LastModifyingImplementationInfo: [WCHAR; 34usize],
+}
+pub type FILE_QUERY_ON_DISK_VOL_INFO_BUFFER = _FILE_QUERY_ON_DISK_VOL_INFO_BUFFER;
+pub type PFILE_QUERY_ON_DISK_VOL_INFO_BUFFER = *mut _FILE_QUERY_ON_DISK_VOL_INFO_BUFFER;
+pub type CLSN = ULONGLONG;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_INITIATE_REPAIR_OUTPUT_BUFFER {
+    pub Hint1: ULONGLONG,
+    pub Hint2: ULONGLONG,
+    pub Clsn: CLSN,
+    pub Status: NTSTATUS,
+}
+pub type FILE_INITIATE_REPAIR_OUTPUT_BUFFER = _FILE_INITIATE_REPAIR_OUTPUT_BUFFER;
+pub type PFILE_INITIATE_REPAIR_OUTPUT_BUFFER = *mut _FILE_INITIATE_REPAIR_OUTPUT_BUFFER;
+pub const _SHRINK_VOLUME_REQUEST_TYPES_ShrinkPrepare: _SHRINK_VOLUME_REQUEST_TYPES = 1;
+pub const _SHRINK_VOLUME_REQUEST_TYPES_ShrinkCommit: _SHRINK_VOLUME_REQUEST_TYPES = 2;
+pub const _SHRINK_VOLUME_REQUEST_TYPES_ShrinkAbort: _SHRINK_VOLUME_REQUEST_TYPES = 3;
+pub type _SHRINK_VOLUME_REQUEST_TYPES = core::ffi::c_int;
+pub use self::_SHRINK_VOLUME_REQUEST_TYPES as SHRINK_VOLUME_REQUEST_TYPES;
+pub type PSHRINK_VOLUME_REQUEST_TYPES = *mut _SHRINK_VOLUME_REQUEST_TYPES;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SHRINK_VOLUME_INFORMATION {
+    pub ShrinkRequestType: SHRINK_VOLUME_REQUEST_TYPES,
+    pub Flags: ULONGLONG,
+    pub NewNumberOfSectors: LONGLONG,
+}
+pub type SHRINK_VOLUME_INFORMATION = _SHRINK_VOLUME_INFORMATION;
+pub type PSHRINK_VOLUME_INFORMATION = *mut _SHRINK_VOLUME_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TXFS_MODIFY_RM {
+    pub Flags: ULONG,
+    pub LogContainerCountMax: ULONG,
+    pub LogContainerCountMin: ULONG,
+    pub LogContainerCount: ULONG,
+    pub LogGrowthIncrement: ULONG,
+    pub LogAutoShrinkPercentage: ULONG,
+    pub Reserved: ULONGLONG,
+    pub LoggingMode: USHORT,
+}
+pub type TXFS_MODIFY_RM = _TXFS_MODIFY_RM;
+pub type PTXFS_MODIFY_RM = *mut _TXFS_MODIFY_RM;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _TXFS_QUERY_RM_INFORMATION {
+    pub BytesRequired: ULONG,
+    pub TailLsn: ULONGLONG,
+    pub CurrentLsn: ULONGLONG,
+    pub ArchiveTailLsn: ULONGLONG,
+    pub LogContainerSize: ULONGLONG,
+    pub HighestVirtualClock: LARGE_INTEGER,
+    pub LogContainerCount: ULONG,
+    pub LogContainerCountMax: ULONG,
+    pub LogContainerCountMin: ULONG,
+    pub LogGrowthIncrement: ULONG,
+    pub LogAutoShrinkPercentage: ULONG,
+    pub Flags: ULONG,
+    pub LoggingMode: USHORT,
+    pub Reserved: USHORT,
+    pub RmState: ULONG,
+    pub LogCapacity: ULONGLONG,
+    pub LogFree: ULONGLONG,
+    pub TopsSize: ULONGLONG,
+    pub TopsUsed: ULONGLONG,
+    pub TransactionCount: ULONGLONG,
+    pub OnePCCount: ULONGLONG,
+    pub TwoPCCount: ULONGLONG,
+    pub NumberLogFileFull: ULONGLONG,
+    pub OldestTransactionAge: ULONGLONG,
+    pub RMName: GUID,
+    pub TmLogPathOffset: ULONG,
+}
+pub type TXFS_QUERY_RM_INFORMATION = _TXFS_QUERY_RM_INFORMATION;
+pub type PTXFS_QUERY_RM_INFORMATION = *mut _TXFS_QUERY_RM_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _TXFS_ROLLFORWARD_REDO_INFORMATION {
+    pub LastVirtualClock: LARGE_INTEGER,
+    pub LastRedoLsn: ULONGLONG,
+    pub HighestRecoveryLsn: ULONGLONG,
+    pub Flags: ULONG,
+}
+pub type TXFS_ROLLFORWARD_REDO_INFORMATION = _TXFS_ROLLFORWARD_REDO_INFORMATION;
+pub type PTXFS_ROLLFORWARD_REDO_INFORMATION = *mut _TXFS_ROLLFORWARD_REDO_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TXFS_START_RM_INFORMATION {
+    pub Flags: ULONG,
+    pub LogContainerSize: ULONGLONG,
+    pub LogContainerCountMin: ULONG,
+    pub LogContainerCountMax: ULONG,
+    pub LogGrowthIncrement: ULONG,
+    pub LogAutoShrinkPercentage: ULONG,
+    pub TmLogPathOffset: ULONG,
+    pub TmLogPathLength: USHORT,
+    pub LoggingMode: USHORT,
+    pub LogPathLength: USHORT,
+    pub Reserved: USHORT,
+    pub LogPath: [WCHAR; 1usize],
+}
+pub type TXFS_START_RM_INFORMATION = _TXFS_START_RM_INFORMATION;
+pub type PTXFS_START_RM_INFORMATION = *mut _TXFS_START_RM_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TXFS_GET_METADATA_INFO_OUT {
+    pub TxfFileId: _TXFS_GET_METADATA_INFO_OUT__bindgen_ty_1,
+    pub LockingTransaction: GUID,
+    pub LastLsn: ULONGLONG,
+    pub TransactionState: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TXFS_GET_METADATA_INFO_OUT__bindgen_ty_1 {
+    pub LowPart: LONGLONG,
+    pub HighPart: LONGLONG,
+}
+pub type TXFS_GET_METADATA_INFO_OUT = _TXFS_GET_METADATA_INFO_OUT;
+pub type PTXFS_GET_METADATA_INFO_OUT = *mut _TXFS_GET_METADATA_INFO_OUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TXFS_LIST_TRANSACTION_LOCKED_FILES_ENTRY {
+    pub Offset: ULONGLONG,
+    pub NameFlags: ULONG,
+    pub FileId: LONGLONG,
+    pub Reserved1: ULONG,
+    pub Reserved2: ULONG,
+    pub Reserved3: LONGLONG,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type TXFS_LIST_TRANSACTION_LOCKED_FILES_ENTRY = _TXFS_LIST_TRANSACTION_LOCKED_FILES_ENTRY;
+pub type PTXFS_LIST_TRANSACTION_LOCKED_FILES_ENTRY = *mut _TXFS_LIST_TRANSACTION_LOCKED_FILES_ENTRY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TXFS_LIST_TRANSACTION_LOCKED_FILES {
+    pub KtmTransaction: GUID,
+    pub NumberOfFiles: ULONGLONG,
+    pub BufferSizeRequired: ULONGLONG,
+    pub Offset: ULONGLONG,
+}
+pub type TXFS_LIST_TRANSACTION_LOCKED_FILES = _TXFS_LIST_TRANSACTION_LOCKED_FILES;
+pub type PTXFS_LIST_TRANSACTION_LOCKED_FILES = *mut _TXFS_LIST_TRANSACTION_LOCKED_FILES;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TXFS_LIST_TRANSACTIONS_ENTRY {
+    pub TransactionId: GUID,
+    pub TransactionState: ULONG,
+    pub Reserved1: ULONG,
+    pub Reserved2: ULONG,
+    pub Reserved3: LONGLONG,
+}
+pub type TXFS_LIST_TRANSACTIONS_ENTRY = _TXFS_LIST_TRANSACTIONS_ENTRY;
+pub type PTXFS_LIST_TRANSACTIONS_ENTRY = *mut _TXFS_LIST_TRANSACTIONS_ENTRY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TXFS_LIST_TRANSACTIONS {
+    pub NumberOfTransactions: ULONGLONG,
+    pub BufferSizeRequired: ULONGLONG,
+}
+pub type TXFS_LIST_TRANSACTIONS = _TXFS_LIST_TRANSACTIONS;
+pub type PTXFS_LIST_TRANSACTIONS = *mut _TXFS_LIST_TRANSACTIONS;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _TXFS_READ_BACKUP_INFORMATION_OUT {
+    pub __bindgen_anon_1: _TXFS_READ_BACKUP_INFORMATION_OUT__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _TXFS_READ_BACKUP_INFORMATION_OUT__bindgen_ty_1 {
+    pub BufferLength: ULONG,
+    pub Buffer: [UCHAR; 1usize],
+}
+pub type TXFS_READ_BACKUP_INFORMATION_OUT = _TXFS_READ_BACKUP_INFORMATION_OUT;
+pub type PTXFS_READ_BACKUP_INFORMATION_OUT = *mut _TXFS_READ_BACKUP_INFORMATION_OUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TXFS_WRITE_BACKUP_INFORMATION {
+    pub Buffer: [UCHAR; 1usize],
+}
+pub type TXFS_WRITE_BACKUP_INFORMATION = _TXFS_WRITE_BACKUP_INFORMATION;
+pub type PTXFS_WRITE_BACKUP_INFORMATION = *mut _TXFS_WRITE_BACKUP_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TXFS_GET_TRANSACTED_VERSION {
+    pub ThisBaseVersion: ULONG,
+    pub LatestVersion: ULONG,
+    pub ThisMiniVersion: USHORT,
+    pub FirstMiniVersion: USHORT,
+    pub LatestMiniVersion: USHORT,
+}
+pub type TXFS_GET_TRANSACTED_VERSION = _TXFS_GET_TRANSACTED_VERSION;
+pub type PTXFS_GET_TRANSACTED_VERSION = *mut _TXFS_GET_TRANSACTED_VERSION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TXFS_SAVEPOINT_INFORMATION {
+    pub KtmTransaction: HANDLE,
+    pub ActionCode: ULONG,
+    pub SavepointId: ULONG,
+}
+pub type TXFS_SAVEPOINT_INFORMATION = _TXFS_SAVEPOINT_INFORMATION;
+pub type PTXFS_SAVEPOINT_INFORMATION = *mut _TXFS_SAVEPOINT_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TXFS_CREATE_MINIVERSION_INFO {
+    pub StructureVersion: USHORT,
+    pub StructureLength: USHORT,
+    pub BaseVersion: ULONG,
+    pub MiniVersion: USHORT,
+}
+pub type TXFS_CREATE_MINIVERSION_INFO = _TXFS_CREATE_MINIVERSION_INFO;
+pub type PTXFS_CREATE_MINIVERSION_INFO = *mut _TXFS_CREATE_MINIVERSION_INFO;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _TXFS_TRANSACTION_ACTIVE_INFO {
+    pub TransactionsActiveAtSnapshot: BOOLEAN,
+}
+pub type TXFS_TRANSACTION_ACTIVE_INFO = _TXFS_TRANSACTION_ACTIVE_INFO;
+pub type PTXFS_TRANSACTION_ACTIVE_INFO = *mut _TXFS_TRANSACTION_ACTIVE_INFO;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _BOOT_AREA_INFO {
+    pub BootSectorCount: ULONG,
+    pub BootSectors: [_BOOT_AREA_INFO__bindgen_ty_1; 2usize],
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _BOOT_AREA_INFO__bindgen_ty_1 {
+    pub Offset: LARGE_INTEGER,
+}
+pub type BOOT_AREA_INFO = _BOOT_AREA_INFO;
+pub type PBOOT_AREA_INFO = *mut _BOOT_AREA_INFO;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _RETRIEVAL_POINTER_BASE {
+    pub FileAreaOffset: LARGE_INTEGER,
+}
+pub type RETRIEVAL_POINTER_BASE = _RETRIEVAL_POINTER_BASE;
+pub type PRETRIEVAL_POINTER_BASE = *mut _RETRIEVAL_POINTER_BASE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_FS_PERSISTENT_VOLUME_INFORMATION {
+    pub VolumeFlags: ULONG,
+    pub FlagMask: ULONG,
+    pub Version: ULONG,
+    pub Reserved: ULONG,
+}
+pub type FILE_FS_PERSISTENT_VOLUME_INFORMATION = _FILE_FS_PERSISTENT_VOLUME_INFORMATION;
+pub type PFILE_FS_PERSISTENT_VOLUME_INFORMATION = *mut _FILE_FS_PERSISTENT_VOLUME_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_SYSTEM_RECOGNITION_INFORMATION {
+    pub FileSystem: [CHAR; 9usize],
+}
+pub type FILE_SYSTEM_RECOGNITION_INFORMATION = _FILE_SYSTEM_RECOGNITION_INFORMATION;
+pub type PFILE_SYSTEM_RECOGNITION_INFORMATION = *mut _FILE_SYSTEM_RECOGNITION_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REQUEST_OPLOCK_INPUT_BUFFER {
+    pub StructureVersion: USHORT,
+    pub StructureLength: USHORT,
+    pub RequestedOplockLevel: ULONG,
+    pub Flags: ULONG,
+}
+pub type REQUEST_OPLOCK_INPUT_BUFFER = _REQUEST_OPLOCK_INPUT_BUFFER;
+pub type PREQUEST_OPLOCK_INPUT_BUFFER = *mut _REQUEST_OPLOCK_INPUT_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REQUEST_OPLOCK_OUTPUT_BUFFER {
+    pub StructureVersion: USHORT,
+    pub StructureLength: USHORT,
+    pub OriginalOplockLevel: ULONG,
+    pub NewOplockLevel: ULONG,
+    pub Flags: ULONG,
+    pub AccessMode: ACCESS_MASK,
+    pub ShareMode: USHORT,
+}
+pub type REQUEST_OPLOCK_OUTPUT_BUFFER = _REQUEST_OPLOCK_OUTPUT_BUFFER;
+pub type PREQUEST_OPLOCK_OUTPUT_BUFFER = *mut _REQUEST_OPLOCK_OUTPUT_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _VIRTUAL_STORAGE_TYPE {
+    pub DeviceId: ULONG,
+    pub VendorId: GUID,
+}
+pub type VIRTUAL_STORAGE_TYPE = _VIRTUAL_STORAGE_TYPE;
+pub type PVIRTUAL_STORAGE_TYPE = *mut _VIRTUAL_STORAGE_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STORAGE_QUERY_DEPENDENT_VOLUME_REQUEST {
+    pub RequestLevel: ULONG,
+    pub RequestFlags: ULONG,
+}
+pub type STORAGE_QUERY_DEPENDENT_VOLUME_REQUEST = _STORAGE_QUERY_DEPENDENT_VOLUME_REQUEST;
+pub type PSTORAGE_QUERY_DEPENDENT_VOLUME_REQUEST = *mut _STORAGE_QUERY_DEPENDENT_VOLUME_REQUEST;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STORAGE_QUERY_DEPENDENT_VOLUME_LEV1_ENTRY {
+    pub EntryLength: ULONG,
+    pub DependencyTypeFlags: ULONG,
+    pub ProviderSpecificFlags: ULONG,
+    pub VirtualStorageType: VIRTUAL_STORAGE_TYPE,
+}
+pub type STORAGE_QUERY_DEPENDENT_VOLUME_LEV1_ENTRY = _STORAGE_QUERY_DEPENDENT_VOLUME_LEV1_ENTRY;
+pub type PSTORAGE_QUERY_DEPENDENT_VOLUME_LEV1_ENTRY =
+*mut _STORAGE_QUERY_DEPENDENT_VOLUME_LEV1_ENTRY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STORAGE_QUERY_DEPENDENT_VOLUME_LEV2_ENTRY {
+    pub EntryLength: ULONG,
+    pub DependencyTypeFlags: ULONG,
+    pub ProviderSpecificFlags: ULONG,
+    pub VirtualStorageType: VIRTUAL_STORAGE_TYPE,
+    pub AncestorLevel: ULONG,
+    pub HostVolumeNameOffset: ULONG,
+    pub HostVolumeNameSize: ULONG,
+    pub DependentVolumeNameOffset: ULONG,
+    pub DependentVolumeNameSize: ULONG,
+    pub RelativePathOffset: ULONG,
+    pub RelativePathSize: ULONG,
+    pub DependentDeviceNameOffset: ULONG,
+    pub DependentDeviceNameSize: ULONG,
+}
+pub type STORAGE_QUERY_DEPENDENT_VOLUME_LEV2_ENTRY = _STORAGE_QUERY_DEPENDENT_VOLUME_LEV2_ENTRY;
+pub type PSTORAGE_QUERY_DEPENDENT_VOLUME_LEV2_ENTRY =
+*mut _STORAGE_QUERY_DEPENDENT_VOLUME_LEV2_ENTRY;
+#[repr(C)]
+pub struct _STORAGE_QUERY_DEPENDENT_VOLUME_RESPONSE {
+    pub ResponseLevel: ULONG,
+    pub NumberEntries: ULONG,
+    pub __bindgen_anon_1: _STORAGE_QUERY_DEPENDENT_VOLUME_RESPONSE__bindgen_ty_1,
+}
+#[repr(C)]
+pub struct _STORAGE_QUERY_DEPENDENT_VOLUME_RESPONSE__bindgen_ty_1 {
+    pub Lev1Depends: __BindgenUnionField<[STORAGE_QUERY_DEPENDENT_VOLUME_LEV1_ENTRY; 0usize]>,
+    pub Lev2Depends: __BindgenUnionField<[STORAGE_QUERY_DEPENDENT_VOLUME_LEV2_ENTRY; 0usize]>,
+    pub bindgen_union_field: u32,
+}
+pub type STORAGE_QUERY_DEPENDENT_VOLUME_RESPONSE = _STORAGE_QUERY_DEPENDENT_VOLUME_RESPONSE;
+pub type PSTORAGE_QUERY_DEPENDENT_VOLUME_RESPONSE = *mut _STORAGE_QUERY_DEPENDENT_VOLUME_RESPONSE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SD_CHANGE_MACHINE_SID_INPUT {
+    pub CurrentMachineSIDOffset: USHORT,
+    pub CurrentMachineSIDLength: USHORT,
+    pub NewMachineSIDOffset: USHORT,
+    pub NewMachineSIDLength: USHORT,
+}
+pub type SD_CHANGE_MACHINE_SID_INPUT = _SD_CHANGE_MACHINE_SID_INPUT;
+pub type PSD_CHANGE_MACHINE_SID_INPUT = *mut _SD_CHANGE_MACHINE_SID_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SD_CHANGE_MACHINE_SID_OUTPUT {
+    pub NumSDChangedSuccess: ULONGLONG,
+    pub NumSDChangedFail: ULONGLONG,
+    pub NumSDUnused: ULONGLONG,
+    pub NumSDTotal: ULONGLONG,
+    pub NumMftSDChangedSuccess: ULONGLONG,
+    pub NumMftSDChangedFail: ULONGLONG,
+    pub NumMftSDTotal: ULONGLONG,
+}
+pub type SD_CHANGE_MACHINE_SID_OUTPUT = _SD_CHANGE_MACHINE_SID_OUTPUT;
+pub type PSD_CHANGE_MACHINE_SID_OUTPUT = *mut _SD_CHANGE_MACHINE_SID_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SD_QUERY_STATS_INPUT {
+    pub Reserved: ULONG,
+}
+pub type SD_QUERY_STATS_INPUT = _SD_QUERY_STATS_INPUT;
+pub type PSD_QUERY_STATS_INPUT = *mut _SD_QUERY_STATS_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SD_QUERY_STATS_OUTPUT {
+    pub SdsStreamSize: ULONGLONG,
+    pub SdsAllocationSize: ULONGLONG,
+    pub SiiStreamSize: ULONGLONG,
+    pub SiiAllocationSize: ULONGLONG,
+    pub SdhStreamSize: ULONGLONG,
+    pub SdhAllocationSize: ULONGLONG,
+    pub NumSDTotal: ULONGLONG,
+    pub NumSDUnused: ULONGLONG,
+}
+pub type SD_QUERY_STATS_OUTPUT = _SD_QUERY_STATS_OUTPUT;
+pub type PSD_QUERY_STATS_OUTPUT = *mut _SD_QUERY_STATS_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SD_ENUM_SDS_INPUT {
+    pub StartingOffset: ULONGLONG,
+    pub MaxSDEntriesToReturn: ULONGLONG,
+}
+pub type SD_ENUM_SDS_INPUT = _SD_ENUM_SDS_INPUT;
+pub type PSD_ENUM_SDS_INPUT = *mut _SD_ENUM_SDS_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SD_ENUM_SDS_ENTRY {
+    pub Hash: ULONG,
+    pub SecurityId: ULONG,
+    pub Offset: ULONGLONG,
+    pub Length: ULONG,
+    pub Descriptor: [UCHAR; 1usize],
+}
+pub type SD_ENUM_SDS_ENTRY = _SD_ENUM_SDS_ENTRY;
+pub type PSD_ENUM_SDS_ENTRY = *mut _SD_ENUM_SDS_ENTRY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SD_ENUM_SDS_OUTPUT {
+    pub NextOffset: ULONGLONG,
+    pub NumSDEntriesReturned: ULONGLONG,
+    pub NumSDBytesReturned: ULONGLONG,
+    pub SDEntry: [SD_ENUM_SDS_ENTRY; 1usize],
+}
+pub type SD_ENUM_SDS_OUTPUT = _SD_ENUM_SDS_OUTPUT;
+pub type PSD_ENUM_SDS_OUTPUT = *mut _SD_ENUM_SDS_OUTPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _SD_GLOBAL_CHANGE_INPUT {
+    pub Flags: ULONG,
+    pub ChangeType: ULONG,
+    pub __bindgen_anon_1: _SD_GLOBAL_CHANGE_INPUT__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _SD_GLOBAL_CHANGE_INPUT__bindgen_ty_1 {
+    pub SdChange: SD_CHANGE_MACHINE_SID_INPUT,
+    pub SdQueryStats: SD_QUERY_STATS_INPUT,
+    pub SdEnumSds: SD_ENUM_SDS_INPUT,
+}
+pub type SD_GLOBAL_CHANGE_INPUT = _SD_GLOBAL_CHANGE_INPUT;
+pub type PSD_GLOBAL_CHANGE_INPUT = *mut _SD_GLOBAL_CHANGE_INPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _SD_GLOBAL_CHANGE_OUTPUT {
+    pub Flags: ULONG,
+    pub ChangeType: ULONG,
+    pub __bindgen_anon_1: _SD_GLOBAL_CHANGE_OUTPUT__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _SD_GLOBAL_CHANGE_OUTPUT__bindgen_ty_1 {
+    pub SdChange: SD_CHANGE_MACHINE_SID_OUTPUT,
+    pub SdQueryStats: SD_QUERY_STATS_OUTPUT,
+    pub SdEnumSds: SD_ENUM_SDS_OUTPUT,
+}
+pub type SD_GLOBAL_CHANGE_OUTPUT = _SD_GLOBAL_CHANGE_OUTPUT;
+pub type PSD_GLOBAL_CHANGE_OUTPUT = *mut _SD_GLOBAL_CHANGE_OUTPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LOOKUP_STREAM_FROM_CLUSTER_INPUT {
+    pub Flags: ULONG,
+    pub NumberOfClusters: ULONG,
+    pub Cluster: [LARGE_INTEGER; 1usize],
+}
+pub type LOOKUP_STREAM_FROM_CLUSTER_INPUT = _LOOKUP_STREAM_FROM_CLUSTER_INPUT;
+pub type PLOOKUP_STREAM_FROM_CLUSTER_INPUT = *mut _LOOKUP_STREAM_FROM_CLUSTER_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LOOKUP_STREAM_FROM_CLUSTER_OUTPUT {
+    pub Offset: ULONG,
+    pub NumberOfMatches: ULONG,
+    pub BufferSizeRequired: ULONG,
+}
+pub type LOOKUP_STREAM_FROM_CLUSTER_OUTPUT = _LOOKUP_STREAM_FROM_CLUSTER_OUTPUT;
+pub type PLOOKUP_STREAM_FROM_CLUSTER_OUTPUT = *mut _LOOKUP_STREAM_FROM_CLUSTER_OUTPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _LOOKUP_STREAM_FROM_CLUSTER_ENTRY {
+    pub OffsetToNext: ULONG,
+    pub Flags: ULONG,
+    pub Reserved: LARGE_INTEGER,
+    pub Cluster: LARGE_INTEGER,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type LOOKUP_STREAM_FROM_CLUSTER_ENTRY = _LOOKUP_STREAM_FROM_CLUSTER_ENTRY;
+pub type PLOOKUP_STREAM_FROM_CLUSTER_ENTRY = *mut _LOOKUP_STREAM_FROM_CLUSTER_ENTRY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_TYPE_NOTIFICATION_INPUT {
+    pub Flags: ULONG,
+    pub NumFileTypeIDs: ULONG,
+    pub FileTypeID: [GUID; 1usize],
+}
+pub type FILE_TYPE_NOTIFICATION_INPUT = _FILE_TYPE_NOTIFICATION_INPUT;
+pub type PFILE_TYPE_NOTIFICATION_INPUT = *mut _FILE_TYPE_NOTIFICATION_INPUT;
+unsafe extern "C" {
+    pub static FILE_TYPE_NOTIFICATION_GUID_PAGE_FILE: GUID;
+}
+unsafe extern "C" {
+    pub static FILE_TYPE_NOTIFICATION_GUID_HIBERNATION_FILE: GUID;
+}
+unsafe extern "C" {
+    pub static FILE_TYPE_NOTIFICATION_GUID_CRASHDUMP_FILE: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_MGMT_LOCK {
+    pub Flags: ULONG,
+}
+pub type CSV_MGMT_LOCK = _CSV_MGMT_LOCK;
+pub type PCSV_MGMT_LOCK = *mut _CSV_MGMT_LOCK;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _CSV_NAMESPACE_INFO {
+    pub Version: ULONG,
+    pub DeviceNumber: ULONG,
+    pub StartingOffset: LARGE_INTEGER,
+    pub SectorSize: ULONG,
+}
+pub type CSV_NAMESPACE_INFO = _CSV_NAMESPACE_INFO;
+pub type PCSV_NAMESPACE_INFO = *mut _CSV_NAMESPACE_INFO;
+pub const _CSV_CONTROL_OP_CsvControlStartRedirectFile: _CSV_CONTROL_OP = 2;
+pub const _CSV_CONTROL_OP_CsvControlStopRedirectFile: _CSV_CONTROL_OP = 3;
+pub const _CSV_CONTROL_OP_CsvControlQueryRedirectState: _CSV_CONTROL_OP = 4;
+pub const _CSV_CONTROL_OP_CsvControlQueryFileRevision: _CSV_CONTROL_OP = 6;
+pub const _CSV_CONTROL_OP_CsvControlQueryMdsPath: _CSV_CONTROL_OP = 8;
+pub const _CSV_CONTROL_OP_CsvControlQueryFileRevisionFileId128: _CSV_CONTROL_OP = 9;
+pub const _CSV_CONTROL_OP_CsvControlQueryVolumeRedirectState: _CSV_CONTROL_OP = 10;
+pub const _CSV_CONTROL_OP_CsvControlEnableUSNRangeModificationTracking: _CSV_CONTROL_OP = 13;
+pub const _CSV_CONTROL_OP_CsvControlMarkHandleLocalVolumeMount: _CSV_CONTROL_OP = 14;
+pub const _CSV_CONTROL_OP_CsvControlUnmarkHandleLocalVolumeMount: _CSV_CONTROL_OP = 15;
+pub const _CSV_CONTROL_OP_CsvControlGetCsvFsMdsPathV2: _CSV_CONTROL_OP = 18;
+pub const _CSV_CONTROL_OP_CsvControlDisableCaching: _CSV_CONTROL_OP = 19;
+pub const _CSV_CONTROL_OP_CsvControlEnableCaching: _CSV_CONTROL_OP = 20;
+pub const _CSV_CONTROL_OP_CsvControlStartForceDFO: _CSV_CONTROL_OP = 21;
+pub const _CSV_CONTROL_OP_CsvControlStopForceDFO: _CSV_CONTROL_OP = 22;
+pub const _CSV_CONTROL_OP_CsvControlQueryMdsPathNoPause: _CSV_CONTROL_OP = 23;
+pub const _CSV_CONTROL_OP_CsvControlSetVolumeId: _CSV_CONTROL_OP = 24;
+pub const _CSV_CONTROL_OP_CsvControlQueryVolumeId: _CSV_CONTROL_OP = 25;
+pub type _CSV_CONTROL_OP = core::ffi::c_int;
+pub use self::_CSV_CONTROL_OP as CSV_CONTROL_OP;
+pub type PCSV_CONTROL_OP = *mut _CSV_CONTROL_OP;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_CONTROL_PARAM {
+    pub Operation: CSV_CONTROL_OP,
+    pub Unused: LONGLONG,
+}
+pub type CSV_CONTROL_PARAM = _CSV_CONTROL_PARAM;
+pub type PCSV_CONTROL_PARAM = *mut _CSV_CONTROL_PARAM;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_QUERY_REDIRECT_STATE {
+    pub MdsNodeId: ULONG,
+    pub DsNodeId: ULONG,
+    pub FileRedirected: BOOLEAN,
+}
+pub type CSV_QUERY_REDIRECT_STATE = _CSV_QUERY_REDIRECT_STATE;
+pub type PCSV_QUERY_REDIRECT_STATE = *mut _CSV_QUERY_REDIRECT_STATE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_QUERY_FILE_REVISION {
+    pub FileId: LONGLONG,
+    pub FileRevision: [LONGLONG; 3usize],
+}
+pub type CSV_QUERY_FILE_REVISION = _CSV_QUERY_FILE_REVISION;
+pub type PCSV_QUERY_FILE_REVISION = *mut _CSV_QUERY_FILE_REVISION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_QUERY_FILE_REVISION_FILE_ID_128 {
+    pub FileId: FILE_ID_128,
+    pub FileRevision: [LONGLONG; 3usize],
+}
+pub type CSV_QUERY_FILE_REVISION_FILE_ID_128 = _CSV_QUERY_FILE_REVISION_FILE_ID_128;
+pub type PCSV_QUERY_FILE_REVISION_FILE_ID_128 = *mut _CSV_QUERY_FILE_REVISION_FILE_ID_128;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_QUERY_MDS_PATH {
+    pub MdsNodeId: ULONG,
+    pub DsNodeId: ULONG,
+    pub PathLength: ULONG,
+    pub Path: [WCHAR; 1usize],
+}
+pub type CSV_QUERY_MDS_PATH = _CSV_QUERY_MDS_PATH;
+pub type PCSV_QUERY_MDS_PATH = *mut _CSV_QUERY_MDS_PATH;
+pub const _CSVFS_DISK_CONNECTIVITY_CsvFsDiskConnectivityNone: _CSVFS_DISK_CONNECTIVITY = 0;
+pub const _CSVFS_DISK_CONNECTIVITY_CsvFsDiskConnectivityMdsNodeOnly: _CSVFS_DISK_CONNECTIVITY = 1;
+pub const _CSVFS_DISK_CONNECTIVITY_CsvFsDiskConnectivitySubsetOfNodes: _CSVFS_DISK_CONNECTIVITY = 2;
+pub const _CSVFS_DISK_CONNECTIVITY_CsvFsDiskConnectivityAllNodes: _CSVFS_DISK_CONNECTIVITY = 3;
+pub type _CSVFS_DISK_CONNECTIVITY = core::ffi::c_int;
+pub use self::_CSVFS_DISK_CONNECTIVITY as CSVFS_DISK_CONNECTIVITY;
+pub type PCSVFS_DISK_CONNECTIVITY = *mut _CSVFS_DISK_CONNECTIVITY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_QUERY_VOLUME_REDIRECT_STATE {
+    pub MdsNodeId: ULONG,
+    pub DsNodeId: ULONG,
+    pub IsDiskConnected: BOOLEAN,
+    pub ClusterEnableDirectIo: BOOLEAN,
+    pub DiskConnectivity: CSVFS_DISK_CONNECTIVITY,
+}
+pub type CSV_QUERY_VOLUME_REDIRECT_STATE = _CSV_QUERY_VOLUME_REDIRECT_STATE;
+pub type PCSV_QUERY_VOLUME_REDIRECT_STATE = *mut _CSV_QUERY_VOLUME_REDIRECT_STATE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_QUERY_MDS_PATH_V2 {
+    pub Version: LONGLONG,
+    pub RequiredSize: ULONG,
+    pub MdsNodeId: ULONG,
+    pub DsNodeId: ULONG,
+    pub Flags: ULONG,
+    pub DiskConnectivity: CSVFS_DISK_CONNECTIVITY,
+    pub VolumeId: GUID,
+    pub IpAddressOffset: ULONG,
+    pub IpAddressLength: ULONG,
+    pub PathOffset: ULONG,
+    pub PathLength: ULONG,
+}
+pub type CSV_QUERY_MDS_PATH_V2 = _CSV_QUERY_MDS_PATH_V2;
+pub type PCSV_QUERY_MDS_PATH_V2 = *mut _CSV_QUERY_MDS_PATH_V2;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_SET_VOLUME_ID {
+    pub VolumeId: GUID,
+}
+pub type CSV_SET_VOLUME_ID = _CSV_SET_VOLUME_ID;
+pub type PCSV_SET_VOLUME_ID = *mut _CSV_SET_VOLUME_ID;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_QUERY_VOLUME_ID {
+    pub VolumeId: GUID,
+}
+pub type CSV_QUERY_VOLUME_ID = _CSV_QUERY_VOLUME_ID;
+pub type PCSV_QUERY_VOLUME_ID = *mut _CSV_QUERY_VOLUME_ID;
+pub const _LMR_QUERY_INFO_CLASS_LMRQuerySessionInfo: _LMR_QUERY_INFO_CLASS = 1;
+pub type _LMR_QUERY_INFO_CLASS = core::ffi::c_int;
+pub use self::_LMR_QUERY_INFO_CLASS as LMR_QUERY_INFO_CLASS;
+pub type PLMR_QUERY_INFO_CLASS = *mut _LMR_QUERY_INFO_CLASS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LMR_QUERY_INFO_PARAM {
+    pub Operation: LMR_QUERY_INFO_CLASS,
+}
+pub type LMR_QUERY_INFO_PARAM = _LMR_QUERY_INFO_PARAM;
+pub type PLMR_QUERY_INFO_PARAM = *mut _LMR_QUERY_INFO_PARAM;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LMR_QUERY_SESSION_INFO {
+    pub SessionId: UINT64,
+}
+pub type LMR_QUERY_SESSION_INFO = _LMR_QUERY_SESSION_INFO;
+pub type PLMR_QUERY_SESSION_INFO = *mut _LMR_QUERY_SESSION_INFO;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_QUERY_VETO_FILE_DIRECT_IO_OUTPUT {
+    pub VetoedFromAltitudeIntegral: ULONGLONG,
+    pub VetoedFromAltitudeDecimal: ULONGLONG,
+    pub Reason: [WCHAR; 256usize],
+}
+pub type CSV_QUERY_VETO_FILE_DIRECT_IO_OUTPUT = _CSV_QUERY_VETO_FILE_DIRECT_IO_OUTPUT;
+pub type PCSV_QUERY_VETO_FILE_DIRECT_IO_OUTPUT = *mut _CSV_QUERY_VETO_FILE_DIRECT_IO_OUTPUT;
+pub const _STORAGE_RESERVE_ID_StorageReserveIdNone: _STORAGE_RESERVE_ID = 0;
+pub const _STORAGE_RESERVE_ID_StorageReserveIdHard: _STORAGE_RESERVE_ID = 1;
+pub const _STORAGE_RESERVE_ID_StorageReserveIdSoft: _STORAGE_RESERVE_ID = 2;
+pub const _STORAGE_RESERVE_ID_StorageReserveIdUpdateScratch: _STORAGE_RESERVE_ID = 3;
+pub const _STORAGE_RESERVE_ID_StorageReserveIdMax: _STORAGE_RESERVE_ID = 4;
+pub type _STORAGE_RESERVE_ID = core::ffi::c_int;
+pub use self::_STORAGE_RESERVE_ID as STORAGE_RESERVE_ID;
+pub type PSTORAGE_RESERVE_ID = *mut _STORAGE_RESERVE_ID;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_IS_OWNED_BY_CSVFS {
+    pub OwnedByCSVFS: BOOLEAN,
+}
+pub type CSV_IS_OWNED_BY_CSVFS = _CSV_IS_OWNED_BY_CSVFS;
+pub type PCSV_IS_OWNED_BY_CSVFS = *mut _CSV_IS_OWNED_BY_CSVFS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_LEVEL_TRIM_RANGE {
+    pub Offset: ULONGLONG,
+    pub Length: ULONGLONG,
+}
+pub type FILE_LEVEL_TRIM_RANGE = _FILE_LEVEL_TRIM_RANGE;
+pub type PFILE_LEVEL_TRIM_RANGE = *mut _FILE_LEVEL_TRIM_RANGE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_LEVEL_TRIM {
+    pub Key: ULONG,
+    pub NumRanges: ULONG,
+    pub Ranges: [FILE_LEVEL_TRIM_RANGE; 1usize],
+}
+pub type FILE_LEVEL_TRIM = _FILE_LEVEL_TRIM;
+pub type PFILE_LEVEL_TRIM = *mut _FILE_LEVEL_TRIM;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_LEVEL_TRIM_OUTPUT {
+    pub NumRangesProcessed: ULONG,
+}
+pub type FILE_LEVEL_TRIM_OUTPUT = _FILE_LEVEL_TRIM_OUTPUT;
+pub type PFILE_LEVEL_TRIM_OUTPUT = *mut _FILE_LEVEL_TRIM_OUTPUT;
+pub const _QUERY_FILE_LAYOUT_FILTER_TYPE_QUERY_FILE_LAYOUT_FILTER_TYPE_NONE:
+_QUERY_FILE_LAYOUT_FILTER_TYPE = 0;
+pub const _QUERY_FILE_LAYOUT_FILTER_TYPE_QUERY_FILE_LAYOUT_FILTER_TYPE_CLUSTERS:
+_QUERY_FILE_LAYOUT_FILTER_TYPE = 1;
+pub const _QUERY_FILE_LAYOUT_FILTER_TYPE_QUERY_FILE_LAYOUT_FILTER_TYPE_FILEID:
+_QUERY_FILE_LAYOUT_FILTER_TYPE = 2;
+pub const _QUERY_FILE_LAYOUT_FILTER_TYPE_QUERY_FILE_LAYOUT_FILTER_TYPE_STORAGE_RESERVE_ID:
+_QUERY_FILE_LAYOUT_FILTER_TYPE = 3;
+pub const _QUERY_FILE_LAYOUT_FILTER_TYPE_QUERY_FILE_LAYOUT_NUM_FILTER_TYPES:
+_QUERY_FILE_LAYOUT_FILTER_TYPE = 4;
+pub type _QUERY_FILE_LAYOUT_FILTER_TYPE = core::ffi::c_int;
+pub use self::_QUERY_FILE_LAYOUT_FILTER_TYPE as QUERY_FILE_LAYOUT_FILTER_TYPE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _CLUSTER_RANGE {
+    pub StartingCluster: LARGE_INTEGER,
+    pub ClusterCount: LARGE_INTEGER,
+}
+pub type CLUSTER_RANGE = _CLUSTER_RANGE;
+pub type PCLUSTER_RANGE = *mut _CLUSTER_RANGE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_REFERENCE_RANGE {
+    pub StartingFileReferenceNumber: ULONGLONG,
+    pub EndingFileReferenceNumber: ULONGLONG,
+}
+pub type FILE_REFERENCE_RANGE = _FILE_REFERENCE_RANGE;
+pub type PFILE_REFERENCE_RANGE = *mut _FILE_REFERENCE_RANGE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _QUERY_FILE_LAYOUT_INPUT {
+    pub __bindgen_anon_1: _QUERY_FILE_LAYOUT_INPUT__bindgen_ty_1,
+    pub Flags: ULONG,
+    pub FilterType: QUERY_FILE_LAYOUT_FILTER_TYPE,
+    pub Reserved: ULONG,
+    pub Filter: _QUERY_FILE_LAYOUT_INPUT__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _QUERY_FILE_LAYOUT_INPUT__bindgen_ty_1 {
+    pub FilterEntryCount: ULONG,
+    pub NumberOfPairs: ULONG,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _QUERY_FILE_LAYOUT_INPUT__bindgen_ty_2 {
+    pub ClusterRanges: [CLUSTER_RANGE; 1usize],
+    pub FileReferenceRanges: [FILE_REFERENCE_RANGE; 1usize],
+    pub StorageReserveIds: [STORAGE_RESERVE_ID; 1usize],
+}
+pub type QUERY_FILE_LAYOUT_INPUT = _QUERY_FILE_LAYOUT_INPUT;
+pub type PQUERY_FILE_LAYOUT_INPUT = *mut _QUERY_FILE_LAYOUT_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _QUERY_FILE_LAYOUT_OUTPUT {
+    pub FileEntryCount: ULONG,
+    pub FirstFileOffset: ULONG,
+    pub Flags: ULONG,
+    pub Reserved: ULONG,
+}
+pub type QUERY_FILE_LAYOUT_OUTPUT = _QUERY_FILE_LAYOUT_OUTPUT;
+pub type PQUERY_FILE_LAYOUT_OUTPUT = *mut _QUERY_FILE_LAYOUT_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_LAYOUT_ENTRY {
+    pub Version: ULONG,
+    pub NextFileOffset: ULONG,
+    pub Flags: ULONG,
+    pub FileAttributes: ULONG,
+    pub FileReferenceNumber: ULONGLONG,
+    pub FirstNameOffset: ULONG,
+    pub FirstStreamOffset: ULONG,
+    pub ExtraInfoOffset: ULONG,
+    pub ExtraInfoLength: ULONG,
+}
+pub type FILE_LAYOUT_ENTRY = _FILE_LAYOUT_ENTRY;
+pub type PFILE_LAYOUT_ENTRY = *mut _FILE_LAYOUT_ENTRY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_LAYOUT_NAME_ENTRY {
+    pub NextNameOffset: ULONG,
+    pub Flags: ULONG,
+    pub ParentFileReferenceNumber: ULONGLONG,
+    pub FileNameLength: ULONG,
+    pub Reserved: ULONG,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type FILE_LAYOUT_NAME_ENTRY = _FILE_LAYOUT_NAME_ENTRY;
+pub type PFILE_LAYOUT_NAME_ENTRY = *mut _FILE_LAYOUT_NAME_ENTRY;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_LAYOUT_INFO_ENTRY {
+    pub BasicInformation: _FILE_LAYOUT_INFO_ENTRY__bindgen_ty_1,
+    pub OwnerId: ULONG,
+    pub SecurityId: ULONG,
+    pub Usn: USN,
+    pub StorageReserveId: STORAGE_RESERVE_ID,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_LAYOUT_INFO_ENTRY__bindgen_ty_1 {
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+}
+pub type FILE_LAYOUT_INFO_ENTRY = _FILE_LAYOUT_INFO_ENTRY;
+pub type PFILE_LAYOUT_INFO_ENTRY = *mut _FILE_LAYOUT_INFO_ENTRY;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _STREAM_LAYOUT_ENTRY {
+    pub Version: ULONG,
+    pub NextStreamOffset: ULONG,
+    pub Flags: ULONG,
+    pub ExtentInformationOffset: ULONG,
+    pub AllocationSize: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub StreamInformationOffset: ULONG,
+    pub AttributeTypeCode: ULONG,
+    pub AttributeFlags: ULONG,
+    pub StreamIdentifierLength: ULONG,
+    pub StreamIdentifier: [WCHAR; 1usize],
+}
+pub type STREAM_LAYOUT_ENTRY = _STREAM_LAYOUT_ENTRY;
+pub type PSTREAM_LAYOUT_ENTRY = *mut _STREAM_LAYOUT_ENTRY;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _STREAM_EXTENT_ENTRY {
+    pub Flags: ULONG,
+    pub ExtentInformation: _STREAM_EXTENT_ENTRY__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _STREAM_EXTENT_ENTRY__bindgen_ty_1 {
+    pub RetrievalPointers: RETRIEVAL_POINTERS_BUFFER,
+}
+pub type STREAM_EXTENT_ENTRY = _STREAM_EXTENT_ENTRY;
+pub type PSTREAM_EXTENT_ENTRY = *mut _STREAM_EXTENT_ENTRY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSCTL_GET_INTEGRITY_INFORMATION_BUFFER {
+    pub ChecksumAlgorithm: USHORT,
+    pub Reserved: USHORT,
+    pub Flags: ULONG,
+    pub ChecksumChunkSizeInBytes: ULONG,
+    pub ClusterSizeInBytes: ULONG,
+}
+pub type FSCTL_GET_INTEGRITY_INFORMATION_BUFFER = _FSCTL_GET_INTEGRITY_INFORMATION_BUFFER;
+pub type PFSCTL_GET_INTEGRITY_INFORMATION_BUFFER = *mut _FSCTL_GET_INTEGRITY_INFORMATION_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSCTL_SET_INTEGRITY_INFORMATION_BUFFER {
+    pub ChecksumAlgorithm: USHORT,
+    pub Reserved: USHORT,
+    pub Flags: ULONG,
+}
+pub type FSCTL_SET_INTEGRITY_INFORMATION_BUFFER = _FSCTL_SET_INTEGRITY_INFORMATION_BUFFER;
+pub type PFSCTL_SET_INTEGRITY_INFORMATION_BUFFER = *mut _FSCTL_SET_INTEGRITY_INFORMATION_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSCTL_SET_INTEGRITY_INFORMATION_BUFFER_EX {
+    pub EnableIntegrity: UCHAR,
+    pub KeepIntegrityStateUnchanged: UCHAR,
+    pub Reserved: USHORT,
+    pub Flags: ULONG,
+    pub Version: UCHAR,
+    pub Reserved2: [UCHAR; 7usize],
+}
+pub type FSCTL_SET_INTEGRITY_INFORMATION_BUFFER_EX = _FSCTL_SET_INTEGRITY_INFORMATION_BUFFER_EX;
+pub type PFSCTL_SET_INTEGRITY_INFORMATION_BUFFER_EX =
+*mut _FSCTL_SET_INTEGRITY_INFORMATION_BUFFER_EX;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSCTL_OFFLOAD_READ_INPUT {
+    pub Size: ULONG,
+    pub Flags: ULONG,
+    pub TokenTimeToLive: ULONG,
+    pub Reserved: ULONG,
+    pub FileOffset: ULONGLONG,
+    pub CopyLength: ULONGLONG,
+}
+pub type FSCTL_OFFLOAD_READ_INPUT = _FSCTL_OFFLOAD_READ_INPUT;
+pub type PFSCTL_OFFLOAD_READ_INPUT = *mut _FSCTL_OFFLOAD_READ_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSCTL_OFFLOAD_READ_OUTPUT {
+    pub Size: ULONG,
+    pub Flags: ULONG,
+    pub TransferLength: ULONGLONG,
+    pub Token: [UCHAR; 512usize],
+}
+pub type FSCTL_OFFLOAD_READ_OUTPUT = _FSCTL_OFFLOAD_READ_OUTPUT;
+pub type PFSCTL_OFFLOAD_READ_OUTPUT = *mut _FSCTL_OFFLOAD_READ_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSCTL_OFFLOAD_WRITE_INPUT {
+    pub Size: ULONG,
+    pub Flags: ULONG,
+    pub FileOffset: ULONGLONG,
+    pub CopyLength: ULONGLONG,
+    pub TransferOffset: ULONGLONG,
+    pub Token: [UCHAR; 512usize],
+}
+pub type FSCTL_OFFLOAD_WRITE_INPUT = _FSCTL_OFFLOAD_WRITE_INPUT;
+pub type PFSCTL_OFFLOAD_WRITE_INPUT = *mut _FSCTL_OFFLOAD_WRITE_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSCTL_OFFLOAD_WRITE_OUTPUT {
+    pub Size: ULONG,
+    pub Flags: ULONG,
+    pub LengthWritten: ULONGLONG,
+}
+pub type FSCTL_OFFLOAD_WRITE_OUTPUT = _FSCTL_OFFLOAD_WRITE_OUTPUT;
+pub type PFSCTL_OFFLOAD_WRITE_OUTPUT = *mut _FSCTL_OFFLOAD_WRITE_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SET_PURGE_FAILURE_MODE_INPUT {
+    pub Flags: ULONG,
+}
+pub type SET_PURGE_FAILURE_MODE_INPUT = _SET_PURGE_FAILURE_MODE_INPUT;
+pub type PSET_PURGE_FAILURE_MODE_INPUT = *mut _SET_PURGE_FAILURE_MODE_INPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _REPAIR_COPIES_INPUT {
+    pub Size: ULONG,
+    pub Flags: ULONG,
+    pub FileOffset: LARGE_INTEGER,
+    pub Length: ULONG,
+    pub SourceCopy: ULONG,
+    pub NumberOfRepairCopies: ULONG,
+    pub RepairCopies: [ULONG; 1usize],
+}
+pub type REPAIR_COPIES_INPUT = _REPAIR_COPIES_INPUT;
+pub type PREPAIR_COPIES_INPUT = *mut _REPAIR_COPIES_INPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _REPAIR_COPIES_OUTPUT {
+    pub Size: ULONG,
+    pub Status: NTSTATUS,
+    pub ResumeFileOffset: LARGE_INTEGER,
+}
+pub type REPAIR_COPIES_OUTPUT = _REPAIR_COPIES_OUTPUT;
+pub type PREPAIR_COPIES_OUTPUT = *mut _REPAIR_COPIES_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_REGION_INFO {
+    pub FileOffset: LONGLONG,
+    pub Length: LONGLONG,
+    pub Usage: ULONG,
+    pub Reserved: ULONG,
+}
+pub type FILE_REGION_INFO = _FILE_REGION_INFO;
+pub type PFILE_REGION_INFO = *mut _FILE_REGION_INFO;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_REGION_OUTPUT {
+    pub Flags: ULONG,
+    pub TotalRegionEntryCount: ULONG,
+    pub RegionEntryCount: ULONG,
+    pub Reserved: ULONG,
+    pub Region: [FILE_REGION_INFO; 1usize],
+}
+pub type FILE_REGION_OUTPUT = _FILE_REGION_OUTPUT;
+pub type PFILE_REGION_OUTPUT = *mut _FILE_REGION_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_REGION_INPUT {
+    pub FileOffset: LONGLONG,
+    pub Length: LONGLONG,
+    pub DesiredUsage: ULONG,
+}
+pub type FILE_REGION_INPUT = _FILE_REGION_INPUT;
+pub type PFILE_REGION_INPUT = *mut _FILE_REGION_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WRITE_USN_REASON_INPUT {
+    pub Flags: ULONG,
+    pub UsnReasonToWrite: ULONG,
+}
+pub type WRITE_USN_REASON_INPUT = _WRITE_USN_REASON_INPUT;
+pub type PWRITE_USN_REASON_INPUT = *mut _WRITE_USN_REASON_INPUT;
+pub const _FILE_STORAGE_TIER_MEDIA_TYPE_FileStorageTierMediaTypeUnspecified:
+_FILE_STORAGE_TIER_MEDIA_TYPE = 0;
+pub const _FILE_STORAGE_TIER_MEDIA_TYPE_FileStorageTierMediaTypeDisk:
+_FILE_STORAGE_TIER_MEDIA_TYPE = 1;
+pub const _FILE_STORAGE_TIER_MEDIA_TYPE_FileStorageTierMediaTypeSsd: _FILE_STORAGE_TIER_MEDIA_TYPE =
+    2;
+pub const _FILE_STORAGE_TIER_MEDIA_TYPE_FileStorageTierMediaTypeScm: _FILE_STORAGE_TIER_MEDIA_TYPE =
+    4;
+pub const _FILE_STORAGE_TIER_MEDIA_TYPE_FileStorageTierMediaTypeMax: _FILE_STORAGE_TIER_MEDIA_TYPE =
+    5;
+pub type _FILE_STORAGE_TIER_MEDIA_TYPE = core::ffi::c_int;
+pub use self::_FILE_STORAGE_TIER_MEDIA_TYPE as FILE_STORAGE_TIER_MEDIA_TYPE;
+pub type PFILE_STORAGE_TIER_MEDIA_TYPE = *mut _FILE_STORAGE_TIER_MEDIA_TYPE;
+pub const _FILE_STORAGE_TIER_CLASS_FileStorageTierClassUnspecified: _FILE_STORAGE_TIER_CLASS = 0;
+pub const _FILE_STORAGE_TIER_CLASS_FileStorageTierClassCapacity: _FILE_STORAGE_TIER_CLASS = 1;
+pub const _FILE_STORAGE_TIER_CLASS_FileStorageTierClassPerformance: _FILE_STORAGE_TIER_CLASS = 2;
+pub const _FILE_STORAGE_TIER_CLASS_FileStorageTierClassMax: _FILE_STORAGE_TIER_CLASS = 3;
+pub type _FILE_STORAGE_TIER_CLASS = core::ffi::c_int;
+pub use self::_FILE_STORAGE_TIER_CLASS as FILE_STORAGE_TIER_CLASS;
+pub type PFILE_STORAGE_TIER_CLASS = *mut _FILE_STORAGE_TIER_CLASS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_STORAGE_TIER {
+    pub Id: GUID,
+    pub Name: [WCHAR; 256usize],
+    pub Description: [WCHAR; 256usize],
+    pub Flags: ULONGLONG,
+    pub ProvisionedCapacity: ULONGLONG,
+    pub MediaType: FILE_STORAGE_TIER_MEDIA_TYPE,
+    pub Class: FILE_STORAGE_TIER_CLASS,
+}
+pub type FILE_STORAGE_TIER = _FILE_STORAGE_TIER;
+pub type PFILE_STORAGE_TIER = *mut _FILE_STORAGE_TIER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSCTL_QUERY_STORAGE_CLASSES_OUTPUT {
+    pub Version: ULONG,
+    pub Size: ULONG,
+    pub Flags: ULONG,
+    pub TotalNumberOfTiers: ULONG,
+    pub NumberOfTiersReturned: ULONG,
+    pub Tiers: [FILE_STORAGE_TIER; 1usize],
+}
+pub type FSCTL_QUERY_STORAGE_CLASSES_OUTPUT = _FSCTL_QUERY_STORAGE_CLASSES_OUTPUT;
+pub type PFSCTL_QUERY_STORAGE_CLASSES_OUTPUT = *mut _FSCTL_QUERY_STORAGE_CLASSES_OUTPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _STREAM_INFORMATION_ENTRY {
+    pub Version: ULONG,
+    pub Flags: ULONG,
+    pub StreamInformation: _STREAM_INFORMATION_ENTRY__StreamInformation,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _STREAM_INFORMATION_ENTRY__StreamInformation {
+    pub DesiredStorageClass: _STREAM_INFORMATION_ENTRY__StreamInformation__DesiredStorageClass,
+    pub DataStream: _STREAM_INFORMATION_ENTRY__StreamInformation__DataStream,
+    pub Reparse: _STREAM_INFORMATION_ENTRY__StreamInformation__Reparse,
+    pub Ea: _STREAM_INFORMATION_ENTRY__StreamInformation__Ea,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STREAM_INFORMATION_ENTRY__StreamInformation__DesiredStorageClass {
+    pub Class: FILE_STORAGE_TIER_CLASS,
+    pub Flags: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STREAM_INFORMATION_ENTRY__StreamInformation__DataStream {
+    pub Length: USHORT,
+    pub Flags: USHORT,
+    pub Reserved: ULONG,
+    pub Vdl: ULONGLONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STREAM_INFORMATION_ENTRY__StreamInformation__Reparse {
+    pub Length: USHORT,
+    pub Flags: USHORT,
+    pub ReparseDataSize: ULONG,
+    pub ReparseDataOffset: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STREAM_INFORMATION_ENTRY__StreamInformation__Ea {
+    pub Length: USHORT,
+    pub Flags: USHORT,
+    pub EaSize: ULONG,
+    pub EaInformationOffset: ULONG,
+}
+pub type STREAM_INFORMATION_ENTRY = _STREAM_INFORMATION_ENTRY;
+pub type PSTREAM_INFORMATION_ENTRY = *mut _STREAM_INFORMATION_ENTRY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSCTL_QUERY_REGION_INFO_INPUT {
+    pub Version: ULONG,
+    pub Size: ULONG,
+    pub Flags: ULONG,
+    pub NumberOfTierIds: ULONG,
+    pub TierIds: [GUID; 1usize],
+}
+pub type FSCTL_QUERY_REGION_INFO_INPUT = _FSCTL_QUERY_REGION_INFO_INPUT;
+pub type PFSCTL_QUERY_REGION_INFO_INPUT = *mut _FSCTL_QUERY_REGION_INFO_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_STORAGE_TIER_REGION {
+    pub TierId: GUID,
+    pub Offset: ULONGLONG,
+    pub Length: ULONGLONG,
+}
+pub type FILE_STORAGE_TIER_REGION = _FILE_STORAGE_TIER_REGION;
+pub type PFILE_STORAGE_TIER_REGION = *mut _FILE_STORAGE_TIER_REGION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSCTL_QUERY_REGION_INFO_OUTPUT {
+    pub Version: ULONG,
+    pub Size: ULONG,
+    pub Flags: ULONG,
+    pub Reserved: ULONG,
+    pub Alignment: ULONGLONG,
+    pub TotalNumberOfRegions: ULONG,
+    pub NumberOfRegionsReturned: ULONG,
+    pub Regions: [FILE_STORAGE_TIER_REGION; 1usize],
+}
+pub type FSCTL_QUERY_REGION_INFO_OUTPUT = _FSCTL_QUERY_REGION_INFO_OUTPUT;
+pub type PFSCTL_QUERY_REGION_INFO_OUTPUT = *mut _FSCTL_QUERY_REGION_INFO_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_DESIRED_STORAGE_CLASS_INFORMATION {
+    pub Class: FILE_STORAGE_TIER_CLASS,
+    pub Flags: ULONG,
+}
+pub type FILE_DESIRED_STORAGE_CLASS_INFORMATION = _FILE_DESIRED_STORAGE_CLASS_INFORMATION;
+pub type PFILE_DESIRED_STORAGE_CLASS_INFORMATION = *mut _FILE_DESIRED_STORAGE_CLASS_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _DUPLICATE_EXTENTS_DATA {
+    pub FileHandle: HANDLE,
+    pub SourceFileOffset: LARGE_INTEGER,
+    pub TargetFileOffset: LARGE_INTEGER,
+    pub ByteCount: LARGE_INTEGER,
+}
+pub type DUPLICATE_EXTENTS_DATA = _DUPLICATE_EXTENTS_DATA;
+pub type PDUPLICATE_EXTENTS_DATA = *mut _DUPLICATE_EXTENTS_DATA;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _DUPLICATE_EXTENTS_DATA32 {
+    pub FileHandle: UINT32,
+    pub SourceFileOffset: LARGE_INTEGER,
+    pub TargetFileOffset: LARGE_INTEGER,
+    pub ByteCount: LARGE_INTEGER,
+}
+pub type DUPLICATE_EXTENTS_DATA32 = _DUPLICATE_EXTENTS_DATA32;
+pub type PDUPLICATE_EXTENTS_DATA32 = *mut _DUPLICATE_EXTENTS_DATA32;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _DUPLICATE_EXTENTS_DATA_EX {
+    pub Size: SIZE_T,
+    pub FileHandle: HANDLE,
+    pub SourceFileOffset: LARGE_INTEGER,
+    pub TargetFileOffset: LARGE_INTEGER,
+    pub ByteCount: LARGE_INTEGER,
+    pub Flags: ULONG,
+}
+pub type DUPLICATE_EXTENTS_DATA_EX = _DUPLICATE_EXTENTS_DATA_EX;
+pub type PDUPLICATE_EXTENTS_DATA_EX = *mut _DUPLICATE_EXTENTS_DATA_EX;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _DUPLICATE_EXTENTS_DATA_EX32 {
+    pub Size: ULONG32,
+    pub FileHandle: ULONG32,
+    pub SourceFileOffset: LARGE_INTEGER,
+    pub TargetFileOffset: LARGE_INTEGER,
+    pub ByteCount: LARGE_INTEGER,
+    pub Flags: ULONG,
+}
+pub type DUPLICATE_EXTENTS_DATA_EX32 = _DUPLICATE_EXTENTS_DATA_EX32;
+pub type PDUPLICATE_EXTENTS_DATA_EX32 = *mut _DUPLICATE_EXTENTS_DATA_EX32;
+pub const _DUPLICATE_EXTENTS_STATE_FileSnapStateInactive: _DUPLICATE_EXTENTS_STATE = 0;
+pub const _DUPLICATE_EXTENTS_STATE_FileSnapStateSource: _DUPLICATE_EXTENTS_STATE = 1;
+pub const _DUPLICATE_EXTENTS_STATE_FileSnapStateTarget: _DUPLICATE_EXTENTS_STATE = 2;
+pub type _DUPLICATE_EXTENTS_STATE = core::ffi::c_int;
+pub use self::_DUPLICATE_EXTENTS_STATE as DUPLICATE_EXTENTS_STATE;
+pub type PDUPLICATE_EXTENTS_STATE = *mut _DUPLICATE_EXTENTS_STATE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ASYNC_DUPLICATE_EXTENTS_STATUS {
+    pub Version: ULONG,
+    pub State: DUPLICATE_EXTENTS_STATE,
+    pub SourceFileOffset: ULONGLONG,
+    pub TargetFileOffset: ULONGLONG,
+    pub ByteCount: ULONGLONG,
+    pub BytesDuplicated: ULONGLONG,
+}
+pub type ASYNC_DUPLICATE_EXTENTS_STATUS = _ASYNC_DUPLICATE_EXTENTS_STATUS;
+pub type PASYNC_DUPLICATE_EXTENTS_STATUS = *mut _ASYNC_DUPLICATE_EXTENTS_STATUS;
+pub const _REFS_SMR_VOLUME_GC_STATE_SmrGcStateInactive: _REFS_SMR_VOLUME_GC_STATE = 0;
+pub const _REFS_SMR_VOLUME_GC_STATE_SmrGcStatePaused: _REFS_SMR_VOLUME_GC_STATE = 1;
+pub const _REFS_SMR_VOLUME_GC_STATE_SmrGcStateActive: _REFS_SMR_VOLUME_GC_STATE = 2;
+pub const _REFS_SMR_VOLUME_GC_STATE_SmrGcStateActiveFullSpeed: _REFS_SMR_VOLUME_GC_STATE = 3;
+pub type _REFS_SMR_VOLUME_GC_STATE = core::ffi::c_int;
+pub use self::_REFS_SMR_VOLUME_GC_STATE as REFS_SMR_VOLUME_GC_STATE;
+pub type PREFS_SMR_VOLUME_GC_STATE = *mut _REFS_SMR_VOLUME_GC_STATE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _REFS_SMR_VOLUME_INFO_OUTPUT {
+    pub Version: ULONG,
+    pub Flags: ULONG,
+    pub SizeOfRandomlyWritableTier: LARGE_INTEGER,
+    pub FreeSpaceInRandomlyWritableTier: LARGE_INTEGER,
+    pub SizeofSMRTier: LARGE_INTEGER,
+    pub FreeSpaceInSMRTier: LARGE_INTEGER,
+    pub UsableFreeSpaceInSMRTier: LARGE_INTEGER,
+    pub VolumeGcState: REFS_SMR_VOLUME_GC_STATE,
+    pub VolumeGcLastStatus: NTSTATUS,
+    pub CurrentGcBandFillPercentage: ULONG,
+    pub Unused: [ULONGLONG; 6usize],
+}
+pub type REFS_SMR_VOLUME_INFO_OUTPUT = _REFS_SMR_VOLUME_INFO_OUTPUT;
+pub type PREFS_SMR_VOLUME_INFO_OUTPUT = *mut _REFS_SMR_VOLUME_INFO_OUTPUT;
+pub const _REFS_SMR_VOLUME_GC_ACTION_SmrGcActionStart: _REFS_SMR_VOLUME_GC_ACTION = 1;
+pub const _REFS_SMR_VOLUME_GC_ACTION_SmrGcActionStartFullSpeed: _REFS_SMR_VOLUME_GC_ACTION = 2;
+pub const _REFS_SMR_VOLUME_GC_ACTION_SmrGcActionPause: _REFS_SMR_VOLUME_GC_ACTION = 3;
+pub const _REFS_SMR_VOLUME_GC_ACTION_SmrGcActionStop: _REFS_SMR_VOLUME_GC_ACTION = 4;
+pub type _REFS_SMR_VOLUME_GC_ACTION = core::ffi::c_int;
+pub use self::_REFS_SMR_VOLUME_GC_ACTION as REFS_SMR_VOLUME_GC_ACTION;
+pub type PREFS_SMR_VOLUME_GC_ACTION = *mut _REFS_SMR_VOLUME_GC_ACTION;
+pub const _REFS_SMR_VOLUME_GC_METHOD_SmrGcMethodCompaction: _REFS_SMR_VOLUME_GC_METHOD = 1;
+pub const _REFS_SMR_VOLUME_GC_METHOD_SmrGcMethodCompression: _REFS_SMR_VOLUME_GC_METHOD = 2;
+pub const _REFS_SMR_VOLUME_GC_METHOD_SmrGcMethodRotation: _REFS_SMR_VOLUME_GC_METHOD = 3;
+pub type _REFS_SMR_VOLUME_GC_METHOD = core::ffi::c_int;
+pub use self::_REFS_SMR_VOLUME_GC_METHOD as REFS_SMR_VOLUME_GC_METHOD;
+pub type PREFS_SMR_VOLUME_GC_METHOD = *mut _REFS_SMR_VOLUME_GC_METHOD;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REFS_SMR_VOLUME_GC_PARAMETERS {
+    pub Version: ULONG,
+    pub Flags: ULONG,
+    pub Action: REFS_SMR_VOLUME_GC_ACTION,
+    pub Method: REFS_SMR_VOLUME_GC_METHOD,
+    pub IoGranularity: ULONG,
+    pub CompressionFormat: ULONG,
+    pub Unused: [ULONGLONG; 8usize],
+}
+pub type REFS_SMR_VOLUME_GC_PARAMETERS = _REFS_SMR_VOLUME_GC_PARAMETERS;
+pub type PREFS_SMR_VOLUME_GC_PARAMETERS = *mut _REFS_SMR_VOLUME_GC_PARAMETERS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STREAMS_QUERY_PARAMETERS_OUTPUT_BUFFER {
+    pub OptimalWriteSize: ULONG,
+    pub StreamGranularitySize: ULONG,
+    pub StreamIdMin: ULONG,
+    pub StreamIdMax: ULONG,
+}
+pub type STREAMS_QUERY_PARAMETERS_OUTPUT_BUFFER = _STREAMS_QUERY_PARAMETERS_OUTPUT_BUFFER;
+pub type PSTREAMS_QUERY_PARAMETERS_OUTPUT_BUFFER = *mut _STREAMS_QUERY_PARAMETERS_OUTPUT_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STREAMS_ASSOCIATE_ID_INPUT_BUFFER {
+    pub Flags: ULONG,
+    pub StreamId: ULONG,
+}
+pub type STREAMS_ASSOCIATE_ID_INPUT_BUFFER = _STREAMS_ASSOCIATE_ID_INPUT_BUFFER;
+pub type PSTREAMS_ASSOCIATE_ID_INPUT_BUFFER = *mut _STREAMS_ASSOCIATE_ID_INPUT_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _STREAMS_QUERY_ID_OUTPUT_BUFFER {
+    pub StreamId: ULONG,
+}
+pub type STREAMS_QUERY_ID_OUTPUT_BUFFER = _STREAMS_QUERY_ID_OUTPUT_BUFFER;
+pub type PSTREAMS_QUERY_ID_OUTPUT_BUFFER = *mut _STREAMS_QUERY_ID_OUTPUT_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _QUERY_BAD_RANGES_INPUT_RANGE {
+    pub StartOffset: ULONGLONG,
+    pub LengthInBytes: ULONGLONG,
+}
+pub type QUERY_BAD_RANGES_INPUT_RANGE = _QUERY_BAD_RANGES_INPUT_RANGE;
+pub type PQUERY_BAD_RANGES_INPUT_RANGE = *mut _QUERY_BAD_RANGES_INPUT_RANGE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _QUERY_BAD_RANGES_INPUT {
+    pub Flags: ULONG,
+    pub NumRanges: ULONG,
+    pub Ranges: [QUERY_BAD_RANGES_INPUT_RANGE; 1usize],
+}
+pub type QUERY_BAD_RANGES_INPUT = _QUERY_BAD_RANGES_INPUT;
+pub type PQUERY_BAD_RANGES_INPUT = *mut _QUERY_BAD_RANGES_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _QUERY_BAD_RANGES_OUTPUT_RANGE {
+    pub Flags: ULONG,
+    pub Reserved: ULONG,
+    pub StartOffset: ULONGLONG,
+    pub LengthInBytes: ULONGLONG,
+}
+pub type QUERY_BAD_RANGES_OUTPUT_RANGE = _QUERY_BAD_RANGES_OUTPUT_RANGE;
+pub type PQUERY_BAD_RANGES_OUTPUT_RANGE = *mut _QUERY_BAD_RANGES_OUTPUT_RANGE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _QUERY_BAD_RANGES_OUTPUT {
+    pub Flags: ULONG,
+    pub NumBadRanges: ULONG,
+    pub NextOffsetToLookUp: ULONGLONG,
+    pub BadRanges: [QUERY_BAD_RANGES_OUTPUT_RANGE; 1usize],
+}
+pub type QUERY_BAD_RANGES_OUTPUT = _QUERY_BAD_RANGES_OUTPUT;
+pub type PQUERY_BAD_RANGES_OUTPUT = *mut _QUERY_BAD_RANGES_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SET_DAX_ALLOC_ALIGNMENT_HINT_INPUT {
+    pub Flags: ULONG,
+    pub AlignmentShift: ULONG,
+    pub FileOffsetToAlign: ULONGLONG,
+    pub FallbackAlignmentShift: ULONG,
+}
+pub type SET_DAX_ALLOC_ALIGNMENT_HINT_INPUT = _SET_DAX_ALLOC_ALIGNMENT_HINT_INPUT;
+pub type PSET_DAX_ALLOC_ALIGNMENT_HINT_INPUT = *mut _SET_DAX_ALLOC_ALIGNMENT_HINT_INPUT;
+pub const _VIRTUAL_STORAGE_BEHAVIOR_CODE_VirtualStorageBehaviorUndefined:
+_VIRTUAL_STORAGE_BEHAVIOR_CODE = 0;
+pub const _VIRTUAL_STORAGE_BEHAVIOR_CODE_VirtualStorageBehaviorCacheWriteThrough:
+_VIRTUAL_STORAGE_BEHAVIOR_CODE = 1;
+pub const _VIRTUAL_STORAGE_BEHAVIOR_CODE_VirtualStorageBehaviorCacheWriteBack:
+_VIRTUAL_STORAGE_BEHAVIOR_CODE = 2;
+pub const _VIRTUAL_STORAGE_BEHAVIOR_CODE_VirtualStorageBehaviorStopIoProcessing:
+_VIRTUAL_STORAGE_BEHAVIOR_CODE = 3;
+pub const _VIRTUAL_STORAGE_BEHAVIOR_CODE_VirtualStorageBehaviorRestartIoProcessing:
+_VIRTUAL_STORAGE_BEHAVIOR_CODE = 4;
+pub type _VIRTUAL_STORAGE_BEHAVIOR_CODE = core::ffi::c_int;
+pub use self::_VIRTUAL_STORAGE_BEHAVIOR_CODE as VIRTUAL_STORAGE_BEHAVIOR_CODE;
+pub type PVIRTUAL_STORAGE_BEHAVIOR_CODE = *mut _VIRTUAL_STORAGE_BEHAVIOR_CODE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _VIRTUAL_STORAGE_SET_BEHAVIOR_INPUT {
+    pub Size: ULONG,
+    pub BehaviorCode: VIRTUAL_STORAGE_BEHAVIOR_CODE,
+}
+pub type VIRTUAL_STORAGE_SET_BEHAVIOR_INPUT = _VIRTUAL_STORAGE_SET_BEHAVIOR_INPUT;
+pub type PVIRTUAL_STORAGE_SET_BEHAVIOR_INPUT = *mut _VIRTUAL_STORAGE_SET_BEHAVIOR_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ENCRYPTION_KEY_CTRL_INPUT {
+    pub HeaderSize: ULONG,
+    pub StructureSize: ULONG,
+    pub KeyOffset: USHORT,
+    pub KeySize: USHORT,
+    pub DplLock: ULONG,
+    pub DplUserId: ULONGLONG,
+    pub DplCredentialId: ULONGLONG,
+}
+pub type ENCRYPTION_KEY_CTRL_INPUT = _ENCRYPTION_KEY_CTRL_INPUT;
+pub type PENCRYPTION_KEY_CTRL_INPUT = *mut _ENCRYPTION_KEY_CTRL_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WOF_EXTERNAL_INFO {
+    pub Version: ULONG,
+    pub Provider: ULONG,
+}
+pub type WOF_EXTERNAL_INFO = _WOF_EXTERNAL_INFO;
+pub type PWOF_EXTERNAL_INFO = *mut _WOF_EXTERNAL_INFO;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WOF_EXTERNAL_FILE_ID {
+    pub FileId: FILE_ID_128,
+}
+pub type WOF_EXTERNAL_FILE_ID = _WOF_EXTERNAL_FILE_ID;
+pub type PWOF_EXTERNAL_FILE_ID = *mut _WOF_EXTERNAL_FILE_ID;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WOF_VERSION_INFO {
+    pub WofVersion: ULONG,
+}
+pub type WOF_VERSION_INFO = _WOF_VERSION_INFO;
+pub type PWOF_VERSION_INFO = *mut _WOF_VERSION_INFO;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _WIM_PROVIDER_EXTERNAL_INFO {
+    pub Version: ULONG,
+    pub Flags: ULONG,
+    pub DataSourceId: LARGE_INTEGER,
+    pub ResourceHash: [UCHAR; 20usize],
+}
+pub type WIM_PROVIDER_EXTERNAL_INFO = _WIM_PROVIDER_EXTERNAL_INFO;
+pub type PWIM_PROVIDER_EXTERNAL_INFO = *mut _WIM_PROVIDER_EXTERNAL_INFO;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _WIM_PROVIDER_ADD_OVERLAY_INPUT {
+    pub WimType: ULONG,
+    pub WimIndex: ULONG,
+    pub WimFileNameOffset: ULONG,
+    pub WimFileNameLength: ULONG,
+}
+pub type WIM_PROVIDER_ADD_OVERLAY_INPUT = _WIM_PROVIDER_ADD_OVERLAY_INPUT;
+pub type PWIM_PROVIDER_ADD_OVERLAY_INPUT = *mut _WIM_PROVIDER_ADD_OVERLAY_INPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _WIM_PROVIDER_UPDATE_OVERLAY_INPUT {
+    pub DataSourceId: LARGE_INTEGER,
+    pub WimFileNameOffset: ULONG,
+    pub WimFileNameLength: ULONG,
+}
+pub type WIM_PROVIDER_UPDATE_OVERLAY_INPUT = _WIM_PROVIDER_UPDATE_OVERLAY_INPUT;
+pub type PWIM_PROVIDER_UPDATE_OVERLAY_INPUT = *mut _WIM_PROVIDER_UPDATE_OVERLAY_INPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _WIM_PROVIDER_REMOVE_OVERLAY_INPUT {
+    pub DataSourceId: LARGE_INTEGER,
+}
+pub type WIM_PROVIDER_REMOVE_OVERLAY_INPUT = _WIM_PROVIDER_REMOVE_OVERLAY_INPUT;
+pub type PWIM_PROVIDER_REMOVE_OVERLAY_INPUT = *mut _WIM_PROVIDER_REMOVE_OVERLAY_INPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _WIM_PROVIDER_SUSPEND_OVERLAY_INPUT {
+    pub DataSourceId: LARGE_INTEGER,
+}
+pub type WIM_PROVIDER_SUSPEND_OVERLAY_INPUT = _WIM_PROVIDER_SUSPEND_OVERLAY_INPUT;
+pub type PWIM_PROVIDER_SUSPEND_OVERLAY_INPUT = *mut _WIM_PROVIDER_SUSPEND_OVERLAY_INPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _WIM_PROVIDER_OVERLAY_ENTRY {
+    pub NextEntryOffset: ULONG,
+    pub DataSourceId: LARGE_INTEGER,
+    pub WimGuid: GUID,
+    pub WimFileNameOffset: ULONG,
+    pub WimType: ULONG,
+    pub WimIndex: ULONG,
+    pub Flags: ULONG,
+}
+pub type WIM_PROVIDER_OVERLAY_ENTRY = _WIM_PROVIDER_OVERLAY_ENTRY;
+pub type PWIM_PROVIDER_OVERLAY_ENTRY = *mut _WIM_PROVIDER_OVERLAY_ENTRY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PROVIDER_EXTERNAL_INFO_V0 {
+    pub Version: ULONG,
+    pub Algorithm: ULONG,
+}
+pub type FILE_PROVIDER_EXTERNAL_INFO_V0 = _FILE_PROVIDER_EXTERNAL_INFO_V0;
+pub type PFILE_PROVIDER_EXTERNAL_INFO_V0 = *mut _FILE_PROVIDER_EXTERNAL_INFO_V0;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PROVIDER_EXTERNAL_INFO_V1 {
+    pub Version: ULONG,
+    pub Algorithm: ULONG,
+    pub Flags: ULONG,
+}
+pub type FILE_PROVIDER_EXTERNAL_INFO_V1 = _FILE_PROVIDER_EXTERNAL_INFO_V1;
+pub type PFILE_PROVIDER_EXTERNAL_INFO_V1 = *mut _FILE_PROVIDER_EXTERNAL_INFO_V1;
+pub type FILE_PROVIDER_EXTERNAL_INFO = FILE_PROVIDER_EXTERNAL_INFO_V1;
+pub type PFILE_PROVIDER_EXTERNAL_INFO = PFILE_PROVIDER_EXTERNAL_INFO_V1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CONTAINER_VOLUME_STATE {
+    pub Flags: ULONG,
+}
+pub type CONTAINER_VOLUME_STATE = _CONTAINER_VOLUME_STATE;
+pub type PCONTAINER_VOLUME_STATE = *mut _CONTAINER_VOLUME_STATE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CONTAINER_ROOT_INFO_INPUT {
+    pub Flags: ULONG,
+}
+pub type CONTAINER_ROOT_INFO_INPUT = _CONTAINER_ROOT_INFO_INPUT;
+pub type PCONTAINER_ROOT_INFO_INPUT = *mut _CONTAINER_ROOT_INFO_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CONTAINER_ROOT_INFO_OUTPUT {
+    pub ContainerRootIdLength: USHORT,
+    pub ContainerRootId: [UCHAR; 1usize],
+}
+pub type CONTAINER_ROOT_INFO_OUTPUT = _CONTAINER_ROOT_INFO_OUTPUT;
+pub type PCONTAINER_ROOT_INFO_OUTPUT = *mut _CONTAINER_ROOT_INFO_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _VIRTUALIZATION_INSTANCE_INFO_INPUT {
+    pub NumberOfWorkerThreads: ULONG,
+    pub Flags: ULONG,
+}
+pub type VIRTUALIZATION_INSTANCE_INFO_INPUT = _VIRTUALIZATION_INSTANCE_INFO_INPUT;
+pub type PVIRTUALIZATION_INSTANCE_INFO_INPUT = *mut _VIRTUALIZATION_INSTANCE_INFO_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _VIRTUALIZATION_INSTANCE_INFO_INPUT_EX {
+    pub HeaderSize: USHORT,
+    pub Flags: ULONG,
+    pub NotificationInfoSize: ULONG,
+    pub NotificationInfoOffset: USHORT,
+    pub ProviderMajorVersion: USHORT,
+}
+pub type VIRTUALIZATION_INSTANCE_INFO_INPUT_EX = _VIRTUALIZATION_INSTANCE_INFO_INPUT_EX;
+pub type PVIRTUALIZATION_INSTANCE_INFO_INPUT_EX = *mut _VIRTUALIZATION_INSTANCE_INFO_INPUT_EX;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _VIRTUALIZATION_INSTANCE_INFO_OUTPUT {
+    pub VirtualizationInstanceID: GUID,
+}
+pub type VIRTUALIZATION_INSTANCE_INFO_OUTPUT = _VIRTUALIZATION_INSTANCE_INFO_OUTPUT;
+pub type PVIRTUALIZATION_INSTANCE_INFO_OUTPUT = *mut _VIRTUALIZATION_INSTANCE_INFO_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _GET_FILTER_FILE_IDENTIFIER_INPUT {
+    pub AltitudeLength: USHORT,
+    pub Altitude: [WCHAR; 1usize],
+}
+pub type GET_FILTER_FILE_IDENTIFIER_INPUT = _GET_FILTER_FILE_IDENTIFIER_INPUT;
+pub type PGET_FILTER_FILE_IDENTIFIER_INPUT = *mut _GET_FILTER_FILE_IDENTIFIER_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _GET_FILTER_FILE_IDENTIFIER_OUTPUT {
+    pub FilterFileIdentifierLength: USHORT,
+    pub FilterFileIdentifier: [UCHAR; 1usize],
+}
+pub type GET_FILTER_FILE_IDENTIFIER_OUTPUT = _GET_FILTER_FILE_IDENTIFIER_OUTPUT;
+pub type PGET_FILTER_FILE_IDENTIFIER_OUTPUT = *mut _GET_FILTER_FILE_IDENTIFIER_OUTPUT;
+pub const _FS_BPIO_OPERATIONS_FS_BPIO_OP_ENABLE: _FS_BPIO_OPERATIONS = 1;
+pub const _FS_BPIO_OPERATIONS_FS_BPIO_OP_DISABLE: _FS_BPIO_OPERATIONS = 2;
+pub const _FS_BPIO_OPERATIONS_FS_BPIO_OP_QUERY: _FS_BPIO_OPERATIONS = 3;
+pub const _FS_BPIO_OPERATIONS_FS_BPIO_OP_VOLUME_STACK_PAUSE: _FS_BPIO_OPERATIONS = 4;
+pub const _FS_BPIO_OPERATIONS_FS_BPIO_OP_VOLUME_STACK_RESUME: _FS_BPIO_OPERATIONS = 5;
+pub const _FS_BPIO_OPERATIONS_FS_BPIO_OP_STREAM_PAUSE: _FS_BPIO_OPERATIONS = 6;
+pub const _FS_BPIO_OPERATIONS_FS_BPIO_OP_STREAM_RESUME: _FS_BPIO_OPERATIONS = 7;
+pub const _FS_BPIO_OPERATIONS_FS_BPIO_OP_GET_INFO: _FS_BPIO_OPERATIONS = 8;
+pub const _FS_BPIO_OPERATIONS_FS_BPIO_OP_MAX_OPERATION: _FS_BPIO_OPERATIONS = 9;
+pub type _FS_BPIO_OPERATIONS = core::ffi::c_int;
+pub use self::_FS_BPIO_OPERATIONS as FS_BPIO_OPERATIONS;
+pub const _FS_BPIO_INFLAGS_FSBPIO_INFL_None: _FS_BPIO_INFLAGS = 0;
+pub const _FS_BPIO_INFLAGS_FSBPIO_INFL_SKIP_STORAGE_STACK_QUERY: _FS_BPIO_INFLAGS = 1;
+pub type _FS_BPIO_INFLAGS = core::ffi::c_int;
+pub use self::_FS_BPIO_INFLAGS as FS_BPIO_INFLAGS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FS_BPIO_INPUT {
+    pub Operation: FS_BPIO_OPERATIONS,
+    pub InFlags: FS_BPIO_INFLAGS,
+    pub Reserved1: ULONGLONG,
+    pub Reserved2: ULONGLONG,
+}
+pub type FS_BPIO_INPUT = _FS_BPIO_INPUT;
+pub type PFS_BPIO_INPUT = *mut _FS_BPIO_INPUT;
+pub const _FS_BPIO_OUTFLAGS_FSBPIO_OUTFL_None: _FS_BPIO_OUTFLAGS = 0;
+pub const _FS_BPIO_OUTFLAGS_FSBPIO_OUTFL_VOLUME_STACK_BYPASS_PAUSED: _FS_BPIO_OUTFLAGS = 1;
+pub const _FS_BPIO_OUTFLAGS_FSBPIO_OUTFL_STREAM_BYPASS_PAUSED: _FS_BPIO_OUTFLAGS = 2;
+pub const _FS_BPIO_OUTFLAGS_FSBPIO_OUTFL_FILTER_ATTACH_BLOCKED: _FS_BPIO_OUTFLAGS = 4;
+pub const _FS_BPIO_OUTFLAGS_FSBPIO_OUTFL_COMPATIBLE_STORAGE_DRIVER: _FS_BPIO_OUTFLAGS = 8;
+pub type _FS_BPIO_OUTFLAGS = core::ffi::c_int;
+pub use self::_FS_BPIO_OUTFLAGS as FS_BPIO_OUTFLAGS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FS_BPIO_RESULTS {
+    pub OpStatus: NTSTATUS,
+    pub FailingDriverNameLen: USHORT,
+    pub FailingDriverName: [WCHAR; 32usize],
+    pub FailureReasonLen: USHORT,
+    pub FailureReason: [WCHAR; 128usize],
+}
+pub type FS_BPIO_RESULTS = _FS_BPIO_RESULTS;
+pub type PFS_BPIO_RESULTS = *mut _FS_BPIO_RESULTS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FS_BPIO_INFO {
+    pub ActiveBypassIoCount: ULONG,
+    pub StorageDriverNameLen: USHORT,
+    pub StorageDriverName: [WCHAR; 32usize],
+}
+pub type FS_BPIO_INFO = _FS_BPIO_INFO;
+pub type PFS_BPIO_INFO = *mut _FS_BPIO_INFO;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FS_BPIO_OUTPUT {
+    pub Operation: FS_BPIO_OPERATIONS,
+    pub OutFlags: FS_BPIO_OUTFLAGS,
+    pub Reserved1: ULONGLONG,
+    pub Reserved2: ULONGLONG,
+    pub __bindgen_anon_1: _FS_BPIO_OUTPUT__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _FS_BPIO_OUTPUT__bindgen_ty_1 {
+    pub Enable: FS_BPIO_RESULTS,
+    pub Query: FS_BPIO_RESULTS,
+    pub VolumeStackResume: FS_BPIO_RESULTS,
+    pub StreamResume: FS_BPIO_RESULTS,
+    pub GetInfo: FS_BPIO_INFO,
+}
+pub type FS_BPIO_OUTPUT = _FS_BPIO_OUTPUT;
+pub type PFS_BPIO_OUTPUT = *mut _FS_BPIO_OUTPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _REPARSE_DATA_BUFFER {
+    pub ReparseTag: ULONG,
+    pub ReparseDataLength: USHORT,
+    pub Reserved: USHORT,
+    pub __bindgen_anon_1: _REPARSE_DATA_BUFFER__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _REPARSE_DATA_BUFFER__bindgen_ty_1 {
+    pub SymbolicLinkReparseBuffer: _REPARSE_DATA_BUFFER__bindgen_ty_1__bindgen_ty_1,
+    pub MountPointReparseBuffer: _REPARSE_DATA_BUFFER__bindgen_ty_1__bindgen_ty_2,
+    pub GenericReparseBuffer: _REPARSE_DATA_BUFFER__bindgen_ty_1__bindgen_ty_3,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REPARSE_DATA_BUFFER__bindgen_ty_1__bindgen_ty_1 {
+    pub SubstituteNameOffset: USHORT,
+    pub SubstituteNameLength: USHORT,
+    pub PrintNameOffset: USHORT,
+    pub PrintNameLength: USHORT,
+    pub Flags: ULONG,
+    pub PathBuffer: [WCHAR; 1usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REPARSE_DATA_BUFFER__bindgen_ty_1__bindgen_ty_2 {
+    pub SubstituteNameOffset: USHORT,
+    pub SubstituteNameLength: USHORT,
+    pub PrintNameOffset: USHORT,
+    pub PrintNameLength: USHORT,
+    pub PathBuffer: [WCHAR; 1usize],
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REPARSE_DATA_BUFFER__bindgen_ty_1__bindgen_ty_3 {
+    pub DataBuffer: [UCHAR; 1usize],
+}
+pub type REPARSE_DATA_BUFFER = _REPARSE_DATA_BUFFER;
+pub type PREPARSE_DATA_BUFFER = *mut _REPARSE_DATA_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REPARSE_GUID_DATA_BUFFER {
+    pub ReparseTag: ULONG,
+    pub ReparseDataLength: USHORT,
+    pub Reserved: USHORT,
+    pub ReparseGuid: GUID,
+    pub GenericReparseBuffer: _REPARSE_GUID_DATA_BUFFER__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REPARSE_GUID_DATA_BUFFER__bindgen_ty_1 {
+    pub DataBuffer: [UCHAR; 1usize],
+}
+pub type REPARSE_GUID_DATA_BUFFER = _REPARSE_GUID_DATA_BUFFER;
+pub type PREPARSE_GUID_DATA_BUFFER = *mut _REPARSE_GUID_DATA_BUFFER;
+#[repr(C, packed(4))]
+#[derive(Copy, Clone)]
+pub struct _REPARSE_INDEX_KEY {
+    pub FileReparseTag: ULONG,
+    pub FileId: LARGE_INTEGER,
+}
+pub type REPARSE_INDEX_KEY = _REPARSE_INDEX_KEY;
+pub type PREPARSE_INDEX_KEY = *mut _REPARSE_INDEX_KEY;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _REPARSE_DATA_BUFFER_EX {
+    pub Flags: ULONG,
+    pub ExistingReparseTag: ULONG,
+    pub ExistingReparseGuid: GUID,
+    pub Reserved: ULONGLONG,
+    pub __bindgen_anon_1: _REPARSE_DATA_BUFFER_EX__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _REPARSE_DATA_BUFFER_EX__bindgen_ty_1 {
+    pub ReparseDataBuffer: REPARSE_DATA_BUFFER,
+    pub ReparseGuidDataBuffer: REPARSE_GUID_DATA_BUFFER,
+}
+pub type REPARSE_DATA_BUFFER_EX = _REPARSE_DATA_BUFFER_EX;
+pub type PREPARSE_DATA_BUFFER_EX = *mut _REPARSE_DATA_BUFFER_EX;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SCRUB_DATA_INPUT {
+    pub Size: ULONG,
+    pub Flags: ULONG,
+    pub MaximumIos: ULONG,
+    pub ObjectId: [ULONG; 4usize],
+    pub Reserved: [ULONG; 41usize],
+    pub ResumeContext: [UCHAR; 1040usize],
+}
+pub type SCRUB_DATA_INPUT = _SCRUB_DATA_INPUT;
+pub type PSCRUB_DATA_INPUT = *mut _SCRUB_DATA_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SCRUB_PARITY_EXTENT {
+    pub Offset: LONGLONG,
+    pub Length: ULONGLONG,
+}
+pub type SCRUB_PARITY_EXTENT = _SCRUB_PARITY_EXTENT;
+pub type PSCRUB_PARITY_EXTENT = *mut _SCRUB_PARITY_EXTENT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SCRUB_PARITY_EXTENT_DATA {
+    pub Size: USHORT,
+    pub Flags: USHORT,
+    pub NumberOfParityExtents: USHORT,
+    pub MaximumNumberOfParityExtents: USHORT,
+    pub ParityExtents: [SCRUB_PARITY_EXTENT; 1usize],
+}
+pub type SCRUB_PARITY_EXTENT_DATA = _SCRUB_PARITY_EXTENT_DATA;
+pub type PSCRUB_PARITY_EXTENT_DATA = *mut _SCRUB_PARITY_EXTENT_DATA;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SCRUB_DATA_OUTPUT {
+    pub Size: ULONG,
+    pub Flags: ULONG,
+    pub Status: ULONG,
+    pub ErrorFileOffset: ULONGLONG,
+    pub ErrorLength: ULONGLONG,
+    pub NumberOfBytesRepaired: ULONGLONG,
+    pub NumberOfBytesFailed: ULONGLONG,
+    pub InternalFileReference: ULONGLONG,
+    pub ResumeContextLength: USHORT,
+    pub ParityExtentDataOffset: USHORT,
+    pub Reserved: [ULONG; 9usize],
+    pub NumberOfMetadataBytesProcessed: ULONGLONG,
+    pub NumberOfDataBytesProcessed: ULONGLONG,
+    pub TotalNumberOfMetadataBytesInUse: ULONGLONG,
+    pub TotalNumberOfDataBytesInUse: ULONGLONG,
+    pub DataBytesSkippedDueToNoAllocation: ULONGLONG,
+    pub DataBytesSkippedDueToInvalidRun: ULONGLONG,
+    pub DataBytesSkippedDueToIntegrityStream: ULONGLONG,
+    pub DataBytesSkippedDueToRegionBeingClean: ULONGLONG,
+    pub DataBytesSkippedDueToLockConflict: ULONGLONG,
+    pub DataBytesSkippedDueToNoScrubDataFlag: ULONGLONG,
+    pub DataBytesSkippedDueToNoScrubNonIntegrityStreamFlag: ULONGLONG,
+    pub DataBytesScrubbed: ULONGLONG,
+    pub ResumeContext: [UCHAR; 1040usize],
+}
+pub type SCRUB_DATA_OUTPUT = _SCRUB_DATA_OUTPUT;
+pub type PSCRUB_DATA_OUTPUT = *mut _SCRUB_DATA_OUTPUT;
+pub const _SharedVirtualDiskSupportType_SharedVirtualDisksUnsupported:
+_SharedVirtualDiskSupportType = 0;
+pub const _SharedVirtualDiskSupportType_SharedVirtualDisksSupported: _SharedVirtualDiskSupportType =
+    1;
+pub const _SharedVirtualDiskSupportType_SharedVirtualDiskSnapshotsSupported:
+_SharedVirtualDiskSupportType = 3;
+pub const _SharedVirtualDiskSupportType_SharedVirtualDiskCDPSnapshotsSupported:
+_SharedVirtualDiskSupportType = 7;
+pub type _SharedVirtualDiskSupportType = core::ffi::c_int;
+pub use self::_SharedVirtualDiskSupportType as SharedVirtualDiskSupportType;
+pub const _SharedVirtualDiskHandleState_SharedVirtualDiskHandleStateNone:
+_SharedVirtualDiskHandleState = 0;
+pub const _SharedVirtualDiskHandleState_SharedVirtualDiskHandleStateFileShared:
+_SharedVirtualDiskHandleState = 1;
+pub const _SharedVirtualDiskHandleState_SharedVirtualDiskHandleStateHandleShared:
+_SharedVirtualDiskHandleState = 3;
+pub type _SharedVirtualDiskHandleState = core::ffi::c_int;
+pub use self::_SharedVirtualDiskHandleState as SharedVirtualDiskHandleState;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SHARED_VIRTUAL_DISK_SUPPORT {
+    pub SharedVirtualDiskSupport: SharedVirtualDiskSupportType,
+    pub HandleState: SharedVirtualDiskHandleState,
+}
+pub type SHARED_VIRTUAL_DISK_SUPPORT = _SHARED_VIRTUAL_DISK_SUPPORT;
+pub type PSHARED_VIRTUAL_DISK_SUPPORT = *mut _SHARED_VIRTUAL_DISK_SUPPORT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REARRANGE_FILE_DATA {
+    pub SourceStartingOffset: ULONGLONG,
+    pub TargetOffset: ULONGLONG,
+    pub SourceFileHandle: HANDLE,
+    pub Length: ULONG,
+    pub Flags: ULONG,
+}
+pub type REARRANGE_FILE_DATA = _REARRANGE_FILE_DATA;
+pub type PREARRANGE_FILE_DATA = *mut _REARRANGE_FILE_DATA;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REARRANGE_FILE_DATA32 {
+    pub SourceStartingOffset: ULONGLONG,
+    pub TargetOffset: ULONGLONG,
+    pub SourceFileHandle: UINT32,
+    pub Length: ULONG,
+    pub Flags: ULONG,
+}
+pub type REARRANGE_FILE_DATA32 = _REARRANGE_FILE_DATA32;
+pub type PREARRANGE_FILE_DATA32 = *mut _REARRANGE_FILE_DATA32;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SHUFFLE_FILE_DATA {
+    pub StartingOffset: LONGLONG,
+    pub Length: LONGLONG,
+    pub Flags: ULONG,
+}
+pub type SHUFFLE_FILE_DATA = _SHUFFLE_FILE_DATA;
+pub type PSHUFFLE_FILE_DATA = *mut _SHUFFLE_FILE_DATA;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PIPE_ASSIGN_EVENT_BUFFER {
+    pub EventHandle: HANDLE,
+    pub KeyValue: ULONG,
+}
+pub type FILE_PIPE_ASSIGN_EVENT_BUFFER = _FILE_PIPE_ASSIGN_EVENT_BUFFER;
+pub type PFILE_PIPE_ASSIGN_EVENT_BUFFER = *mut _FILE_PIPE_ASSIGN_EVENT_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PIPE_PEEK_BUFFER {
+    pub NamedPipeState: ULONG,
+    pub ReadDataAvailable: ULONG,
+    pub NumberOfMessages: ULONG,
+    pub MessageLength: ULONG,
+    pub Data: [CHAR; 1usize],
+}
+pub type FILE_PIPE_PEEK_BUFFER = _FILE_PIPE_PEEK_BUFFER;
+pub type PFILE_PIPE_PEEK_BUFFER = *mut _FILE_PIPE_PEEK_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PIPE_EVENT_BUFFER {
+    pub NamedPipeState: ULONG,
+    pub EntryType: ULONG,
+    pub ByteCount: ULONG,
+    pub KeyValue: ULONG,
+    pub NumberRequests: ULONG,
+}
+pub type FILE_PIPE_EVENT_BUFFER = _FILE_PIPE_EVENT_BUFFER;
+pub type PFILE_PIPE_EVENT_BUFFER = *mut _FILE_PIPE_EVENT_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_PIPE_WAIT_FOR_BUFFER {
+    pub Timeout: LARGE_INTEGER,
+    pub NameLength: ULONG,
+    pub TimeoutSpecified: BOOLEAN,
+    pub Name: [WCHAR; 1usize],
+}
+pub type FILE_PIPE_WAIT_FOR_BUFFER = _FILE_PIPE_WAIT_FOR_BUFFER;
+pub type PFILE_PIPE_WAIT_FOR_BUFFER = *mut _FILE_PIPE_WAIT_FOR_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PIPE_CLIENT_PROCESS_BUFFER {
+    pub ClientSession: PVOID,
+    pub ClientProcess: PVOID,
+}
+pub type FILE_PIPE_CLIENT_PROCESS_BUFFER = _FILE_PIPE_CLIENT_PROCESS_BUFFER;
+pub type PFILE_PIPE_CLIENT_PROCESS_BUFFER = *mut _FILE_PIPE_CLIENT_PROCESS_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PIPE_CLIENT_PROCESS_BUFFER_V2 {
+    pub ClientSession: ULONGLONG,
+    pub ClientProcess: PVOID,
+}
+pub type FILE_PIPE_CLIENT_PROCESS_BUFFER_V2 = _FILE_PIPE_CLIENT_PROCESS_BUFFER_V2;
+pub type PFILE_PIPE_CLIENT_PROCESS_BUFFER_V2 = *mut _FILE_PIPE_CLIENT_PROCESS_BUFFER_V2;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PIPE_CLIENT_PROCESS_BUFFER_EX {
+    pub ClientSession: PVOID,
+    pub ClientProcess: PVOID,
+    pub ClientComputerNameLength: USHORT,
+    pub ClientComputerBuffer: [WCHAR; 16usize],
+}
+pub type FILE_PIPE_CLIENT_PROCESS_BUFFER_EX = _FILE_PIPE_CLIENT_PROCESS_BUFFER_EX;
+pub type PFILE_PIPE_CLIENT_PROCESS_BUFFER_EX = *mut _FILE_PIPE_CLIENT_PROCESS_BUFFER_EX;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PIPE_SILO_ARRIVAL_INPUT {
+    pub JobHandle: HANDLE,
+}
+pub type FILE_PIPE_SILO_ARRIVAL_INPUT = _FILE_PIPE_SILO_ARRIVAL_INPUT;
+pub type PFILE_PIPE_SILO_ARRIVAL_INPUT = *mut _FILE_PIPE_SILO_ARRIVAL_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PIPE_CREATE_SYMLINK_INPUT {
+    pub NameOffset: USHORT,
+    pub NameLength: USHORT,
+    pub SubstituteNameOffset: USHORT,
+    pub SubstituteNameLength: USHORT,
+    pub Flags: ULONG,
+}
+pub type FILE_PIPE_CREATE_SYMLINK_INPUT = _FILE_PIPE_CREATE_SYMLINK_INPUT;
+pub type PFILE_PIPE_CREATE_SYMLINK_INPUT = *mut _FILE_PIPE_CREATE_SYMLINK_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_PIPE_DELETE_SYMLINK_INPUT {
+    pub NameOffset: USHORT,
+    pub NameLength: USHORT,
+}
+pub type FILE_PIPE_DELETE_SYMLINK_INPUT = _FILE_PIPE_DELETE_SYMLINK_INPUT;
+pub type PFILE_PIPE_DELETE_SYMLINK_INPUT = *mut _FILE_PIPE_DELETE_SYMLINK_INPUT;
+pub const _LINK_TRACKING_INFORMATION_TYPE_NtfsLinkTrackingInformation:
+_LINK_TRACKING_INFORMATION_TYPE = 0;
+pub const _LINK_TRACKING_INFORMATION_TYPE_DfsLinkTrackingInformation:
+_LINK_TRACKING_INFORMATION_TYPE = 1;
+pub type _LINK_TRACKING_INFORMATION_TYPE = core::ffi::c_int;
+pub use self::_LINK_TRACKING_INFORMATION_TYPE as LINK_TRACKING_INFORMATION_TYPE;
+pub type PLINK_TRACKING_INFORMATION_TYPE = *mut _LINK_TRACKING_INFORMATION_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LINK_TRACKING_INFORMATION {
+    pub Type: LINK_TRACKING_INFORMATION_TYPE,
+    pub VolumeId: [UCHAR; 16usize],
+}
+pub type LINK_TRACKING_INFORMATION = _LINK_TRACKING_INFORMATION;
+pub type PLINK_TRACKING_INFORMATION = *mut _LINK_TRACKING_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REMOTE_LINK_TRACKING_INFORMATION_ {
+    pub TargetFileObject: PVOID,
+    pub TargetLinkTrackingInformationLength: ULONG,
+    pub TargetLinkTrackingInformationBuffer: [UCHAR; 1usize],
+}
+pub type REMOTE_LINK_TRACKING_INFORMATION = _REMOTE_LINK_TRACKING_INFORMATION_;
+pub type PREMOTE_LINK_TRACKING_INFORMATION = *mut _REMOTE_LINK_TRACKING_INFORMATION_;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _VCN_RANGE_INPUT_BUFFER {
+    pub StartingVcn: LARGE_INTEGER,
+    pub ClusterCount: LARGE_INTEGER,
+}
+pub type VCN_RANGE_INPUT_BUFFER = _VCN_RANGE_INPUT_BUFFER;
+pub type PVCN_RANGE_INPUT_BUFFER = *mut _VCN_RANGE_INPUT_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _EXTENT_READ_CACHE_INFO_BUFFER {
+    pub AllocatedCache: LARGE_INTEGER,
+    pub PopulatedCache: LARGE_INTEGER,
+    pub InErrorCache: LARGE_INTEGER,
+}
+pub type EXTENT_READ_CACHE_INFO_BUFFER = _EXTENT_READ_CACHE_INFO_BUFFER;
+pub type PEXTENT_READ_CACHE_INFO_BUFFER = *mut _EXTENT_READ_CACHE_INFO_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REFS_VOLUME_COUNTER_INFO_INPUT_BUFFER {
+    pub ResetCounters: BOOLEAN,
+}
+pub type REFS_VOLUME_COUNTER_INFO_INPUT_BUFFER = _REFS_VOLUME_COUNTER_INFO_INPUT_BUFFER;
+pub type PREFS_VOLUME_COUNTER_INFO_INPUT_BUFFER = *mut _REFS_VOLUME_COUNTER_INFO_INPUT_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _VOLUME_REFS_INFO_BUFFER {
+    pub CacheSizeInBytes: LARGE_INTEGER,
+    pub AllocatedCacheInBytes: LARGE_INTEGER,
+    pub PopulatedCacheInBytes: LARGE_INTEGER,
+    pub InErrorCacheInBytes: LARGE_INTEGER,
+    pub MemoryUsedForCacheMetadata: LARGE_INTEGER,
+    pub CacheLineSize: ULONG,
+    pub CacheTransactionsOutstanding: LONG,
+    pub CacheLinesFree: LONG,
+    pub CacheLinesInError: LONG,
+    pub CacheHitsInBytes: LARGE_INTEGER,
+    pub CacheMissesInBytes: LARGE_INTEGER,
+    pub CachePopulationUpdatesInBytes: LARGE_INTEGER,
+    pub CacheWriteThroughUpdatesInBytes: LARGE_INTEGER,
+    pub CacheInvalidationsInBytes: LARGE_INTEGER,
+    pub CacheOverReadsInBytes: LARGE_INTEGER,
+    pub MetadataWrittenBytes: LARGE_INTEGER,
+    pub CacheHitCounter: LONG,
+    pub CacheMissCounter: LONG,
+    pub CacheLineAllocationCounter: LONG,
+    pub CacheInvalidationsCounter: LONG,
+    pub CachePopulationUpdatesCounter: LONG,
+    pub CacheWriteThroughUpdatesCounter: LONG,
+    pub MaxCacheTransactionsOutstanding: LONG,
+    pub DataWritesReallocationCount: LONGLONG,
+    pub DataInPlaceWriteCount: LONGLONG,
+    pub MetadataAllocationsFastTierCount: LONGLONG,
+    pub MetadataAllocationsSlowTierCount: LONGLONG,
+    pub DataAllocationsFastTierCount: LONGLONG,
+    pub DataAllocationsSlowTierCount: LONGLONG,
+    pub DestagesSlowTierToFastTier: LONGLONG,
+    pub DestagesFastTierToSlowTier: LONGLONG,
+    pub SlowTierDataFillRatio: LONG,
+    pub FastTierDataFillRatio: LONG,
+    pub SlowTierMetadataFillRatio: LONG,
+    pub FastTierMetadataFillRatio: LONG,
+    pub SlowToFastDestageReadLatency: LONGLONG,
+    pub SlowToFastDestageReadLatencyBase: LONG,
+    pub SlowToFastDestageWriteLatency: LONGLONG,
+    pub SlowToFastDestageWriteLatencyBase: LONG,
+    pub FastToSlowDestageReadLatency: LONGLONG,
+    pub FastToSlowDestageReadLatencyBase: LONG,
+    pub FastToSlowDestageWriteLatency: LONGLONG,
+    pub FastToSlowDestageWriteLatencyBase: LONG,
+    pub SlowTierContainerFillRatio: LONGLONG,
+    pub SlowTierContainerFillRatioBase: LONG,
+    pub FastTierContainerFillRatio: LONGLONG,
+    pub FastTierContainerFillRatioBase: LONG,
+    pub Unused1: LONG,
+    pub Unused2: LONG,
+    pub Unused3: LONG,
+    pub Unused4: LONG,
+    pub TreeUpdateCount: LONGLONG,
+    pub CheckpointCount: LONGLONG,
+    pub LogWriteCount: LONGLONG,
+    pub LogFillRatio: LONG,
+    pub ReadCacheInvalidationsForOverwrite: LONG,
+    pub ReadCacheInvalidationsForReuse: LONG,
+    pub ReadCacheInvalidationsGeneral: LONG,
+    pub ReadCacheChecksOnMount: LONG,
+    pub ReadCacheIssuesOnMount: LONG,
+    pub TrimLatency: LONGLONG,
+    pub TrimLatencyBase: LONG,
+    pub DataCompactionCount: LONGLONG,
+    pub CompactionReadLatency: LONGLONG,
+    pub CompactionReadLatencyBase: LONG,
+    pub CompactionWriteLatency: LONGLONG,
+    pub CompactionWriteLatencyBase: LONG,
+    pub DataInPlaceWriteClusterCount: LARGE_INTEGER,
+    pub CompactionFailedDueToIneligibleContainer: LONG,
+    pub CompactionFailedDueToMaxFragmentation: LONG,
+    pub CompactedContainerFillRatio: LONGLONG,
+    pub CompactedContainerFillRatioBase: LONG,
+    pub ContainerMoveRetryCount: LONG,
+    pub ContainerMoveFailedDueToIneligibleContainer: LONG,
+    pub CompactionFailureCount: LONG,
+    pub ContainerMoveFailureCount: LONG,
+    pub NumberOfDirtyMetadataPages: LARGE_INTEGER,
+    pub NumberOfDirtyTableListEntries: LONG,
+    pub NumberOfDeleteQueueEntries: LONG,
+    pub MAAFilteredViewSize: LONG,
+    pub MAAFilteredViewInsertions: LONG,
+    pub MAAFilteredViewDeletions: LONG,
+    pub MAAFilteredViewCollisions: LONG,
+    pub MAAFilteredViewPurges: LONG,
+    pub MAARegionsVisitedPerAllocationSum: LONGLONG,
+    pub MAARegionsVisitedPerAllocationBase: LONG,
+    pub MAAMaxRegionsVisitedPerAllocation: LONG,
+    pub TreeUpdateLatencyExclusive: LONGLONG,
+    pub TreeUpdateLatencyTotal: LONGLONG,
+    pub TreeUpdateLatencyBase: LONG,
+    pub CheckpointLatencyTreeUpdateExclusive: LONGLONG,
+    pub CheckpointLatencyTreeUpdateTotal: LONGLONG,
+    pub CheckpointLatencyTreeUpdateBase: LONG,
+    pub CheckpointLatencyTotal: LONGLONG,
+    pub CheckpointLatencyTotalBase: LONG,
+}
+pub type VOLUME_REFS_INFO_BUFFER = _VOLUME_REFS_INFO_BUFFER;
+pub type PVOLUME_REFS_INFO_BUFFER = *mut _VOLUME_REFS_INFO_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _QUERY_DIRECT_ACCESS_EXTENTS {
+    pub FileOffset: LONGLONG,
+    pub Length: LONGLONG,
+    pub Flags: ULONG,
+    pub Reserved: ULONG,
+}
+pub type QUERY_DIRECT_ACCESS_EXTENTS = _QUERY_DIRECT_ACCESS_EXTENTS;
+pub type PQUERY_DIRECT_ACCESS_EXTENTS = *mut _QUERY_DIRECT_ACCESS_EXTENTS;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FSCTL_GHOST_FILE_EXTENTS_INPUT_BUFFER {
+    pub FileOffset: LARGE_INTEGER,
+    pub ByteCount: LARGE_INTEGER,
+    pub RecallOwnerGuid: GUID,
+    pub RecallMetadataBufferSize: ULONG,
+    pub RecallMetadataBuffer: [UCHAR; 1usize],
+}
+pub type FSCTL_GHOST_FILE_EXTENTS_INPUT_BUFFER = _FSCTL_GHOST_FILE_EXTENTS_INPUT_BUFFER;
+pub type PFSCTL_GHOST_FILE_EXTENTS_INPUT_BUFFER = *mut _FSCTL_GHOST_FILE_EXTENTS_INPUT_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FSCTL_QUERY_GHOSTED_FILE_EXTENTS_INPUT_RANGE {
+    pub FileOffset: LARGE_INTEGER,
+    pub ByteCount: LARGE_INTEGER,
+}
+pub type FSCTL_QUERY_GHOSTED_FILE_EXTENTS_INPUT_RANGE =
+_FSCTL_QUERY_GHOSTED_FILE_EXTENTS_INPUT_RANGE;
+pub type PFSCTL_QUERY_GHOSTED_FILE_EXTENTS_INPUT_RANGE =
+*mut _FSCTL_QUERY_GHOSTED_FILE_EXTENTS_INPUT_RANGE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _GHOSTED_FILE_EXTENT {
+    pub FileOffset: LARGE_INTEGER,
+    pub ByteCount: LARGE_INTEGER,
+    pub RecallOwnerGuid: GUID,
+    pub NextEntryOffset: ULONG,
+    pub RecallMetadataBufferSize: ULONG,
+    pub RecallMetadataBuffer: [UCHAR; 1usize],
+}
+pub type GHOSTED_FILE_EXTENT = _GHOSTED_FILE_EXTENT;
+pub type PGHOSTED_FILE_EXTENT = *mut _GHOSTED_FILE_EXTENT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSCTL_QUERY_GHOSTED_FILE_EXTENTS_OUTPUT {
+    pub ExtentCount: ULONG,
+    pub TotalExtentCount: ULONG,
+    pub Extents: [UCHAR; 1usize],
+}
+pub type FSCTL_QUERY_GHOSTED_FILE_EXTENTS_OUTPUT = _FSCTL_QUERY_GHOSTED_FILE_EXTENTS_OUTPUT;
+pub type PFSCTL_QUERY_GHOSTED_FILE_EXTENTS_OUTPUT = *mut _FSCTL_QUERY_GHOSTED_FILE_EXTENTS_OUTPUT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FSCTL_UNMAP_SPACE_INPUT_BUFFER {
+    pub BytesToUnmap: LARGE_INTEGER,
+}
+pub type FSCTL_UNMAP_SPACE_INPUT_BUFFER = _FSCTL_UNMAP_SPACE_INPUT_BUFFER;
+pub type PFSCTL_UNMAP_SPACE_INPUT_BUFFER = *mut _FSCTL_UNMAP_SPACE_INPUT_BUFFER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FSCTL_UNMAP_SPACE_OUTPUT {
+    pub BytesUnmapped: LARGE_INTEGER,
+}
+pub type FSCTL_UNMAP_SPACE_OUTPUT = _FSCTL_UNMAP_SPACE_OUTPUT;
+pub type PFSCTL_UNMAP_SPACE_OUTPUT = *mut _FSCTL_UNMAP_SPACE_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSCTL_QUERY_VOLUME_NUMA_INFO_OUTPUT {
+    pub NumaNode: ULONG,
+}
+pub type FSCTL_QUERY_VOLUME_NUMA_INFO_OUTPUT = _FSCTL_QUERY_VOLUME_NUMA_INFO_OUTPUT;
+pub type PFSCTL_QUERY_VOLUME_NUMA_INFO_OUTPUT = *mut _FSCTL_QUERY_VOLUME_NUMA_INFO_OUTPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REFS_DEALLOCATE_RANGES_RANGE {
+    pub StartOfRange: ULONGLONG,
+    pub CountOfRange: ULONGLONG,
+}
+pub type REFS_DEALLOCATE_RANGES_RANGE = _REFS_DEALLOCATE_RANGES_RANGE;
+pub type PREFS_DEALLOCATE_RANGES_RANGE = *mut _REFS_DEALLOCATE_RANGES_RANGE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REFS_DEALLOCATE_RANGES_INPUT_BUFFER {
+    pub RangeCount: ULONG,
+    pub Ranges: [REFS_DEALLOCATE_RANGES_RANGE; 1usize],
+}
+pub type REFS_DEALLOCATE_RANGES_INPUT_BUFFER = _REFS_DEALLOCATE_RANGES_INPUT_BUFFER;
+pub type PREFS_DEALLOCATE_RANGES_INPUT_BUFFER = *mut _REFS_DEALLOCATE_RANGES_INPUT_BUFFER;
+pub const _REFS_DEALLOCATE_RANGES_ALLOCATOR_REFS_DEALLOCATE_RANGES_ALLOCATOR_NONE:
+_REFS_DEALLOCATE_RANGES_ALLOCATOR = 0;
+pub const _REFS_DEALLOCATE_RANGES_ALLOCATOR_REFS_DEALLOCATE_RANGES_ALLOCATOR_SAA:
+_REFS_DEALLOCATE_RANGES_ALLOCATOR = 1;
+pub const _REFS_DEALLOCATE_RANGES_ALLOCATOR_REFS_DEALLOCATE_RANGES_ALLOCATOR_CAA:
+_REFS_DEALLOCATE_RANGES_ALLOCATOR = 2;
+pub const _REFS_DEALLOCATE_RANGES_ALLOCATOR_REFS_DEALLOCATE_RANGES_ALLOCATOR_MAA:
+_REFS_DEALLOCATE_RANGES_ALLOCATOR = 3;
+pub type _REFS_DEALLOCATE_RANGES_ALLOCATOR = core::ffi::c_int;
+pub use self::_REFS_DEALLOCATE_RANGES_ALLOCATOR as REFS_DEALLOCATE_RANGES_ALLOCATOR;
+pub type PREFS_DEALLOCATE_RANGES_ALLOCATOR = *mut _REFS_DEALLOCATE_RANGES_ALLOCATOR;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REFS_DEALLOCATE_RANGES_INPUT_BUFFER_EX {
+    pub RangeCount: ULONG,
+    pub Allocator: REFS_DEALLOCATE_RANGES_ALLOCATOR,
+    pub StreamReserveUpdateCount: LONGLONG,
+    pub OffsetToRanges: ULONG,
+    pub OffsetToLeakCounts: ULONG,
+    pub Reserved: [ULONGLONG; 2usize],
+}
+pub type REFS_DEALLOCATE_RANGES_INPUT_BUFFER_EX = _REFS_DEALLOCATE_RANGES_INPUT_BUFFER_EX;
+pub type PREFS_DEALLOCATE_RANGES_INPUT_BUFFER_EX = *mut _REFS_DEALLOCATE_RANGES_INPUT_BUFFER_EX;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REFS_REMOVE_HARDLINK_BACKPOINTER {
+    pub ParentDirectory: ULONGLONG,
+    pub Reserved: ULONGLONG,
+    pub FileName: [WCHAR; 1usize],
+}
+pub type REFS_REMOVE_HARDLINK_BACKPOINTER = _REFS_REMOVE_HARDLINK_BACKPOINTER;
+pub type PREFS_REMOVE_HARDLINK_BACKPOINTER = *mut _REFS_REMOVE_HARDLINK_BACKPOINTER;
+pub const _REFS_STREAM_EXTENT_PROPERTIES_REFS_STREAM_EXTENT_PROPERTY_VALID:
+_REFS_STREAM_EXTENT_PROPERTIES = 16;
+pub const _REFS_STREAM_EXTENT_PROPERTIES_REFS_STREAM_EXTENT_PROPERTY_STREAM_RESERVED:
+_REFS_STREAM_EXTENT_PROPERTIES = 32;
+pub const _REFS_STREAM_EXTENT_PROPERTIES_REFS_STREAM_EXTENT_PROPERTY_CRC32:
+_REFS_STREAM_EXTENT_PROPERTIES = 128;
+pub const _REFS_STREAM_EXTENT_PROPERTIES_REFS_STREAM_EXTENT_PROPERTY_CRC64:
+_REFS_STREAM_EXTENT_PROPERTIES = 256;
+pub const _REFS_STREAM_EXTENT_PROPERTIES_REFS_STREAM_EXTENT_PROPERTY_GHOSTED:
+_REFS_STREAM_EXTENT_PROPERTIES = 512;
+pub const _REFS_STREAM_EXTENT_PROPERTIES_REFS_STREAM_EXTENT_PROPERTY_READONLY:
+_REFS_STREAM_EXTENT_PROPERTIES = 1024;
+pub const _REFS_STREAM_EXTENT_PROPERTIES_REFS_STREAM_EXTENT_PROPERTY_SPARSE:
+_REFS_STREAM_EXTENT_PROPERTIES = 8;
+pub type _REFS_STREAM_EXTENT_PROPERTIES = core::ffi::c_int;
+pub type REFS_STREAM_EXTENT_PROPERTIES = USHORT;
+pub type PREFS_STREAM_EXTENT_PROPERTIES = *mut REFS_STREAM_EXTENT_PROPERTIES;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REFS_STREAM_EXTENT {
+    pub Vcn: LONGLONG,
+    pub Lcn: LONGLONG,
+    pub Length: LONGLONG,
+    pub Properties: REFS_STREAM_EXTENT_PROPERTIES,
+}
+pub type REFS_STREAM_EXTENT = _REFS_STREAM_EXTENT;
+pub type PREFS_STREAM_EXTENT = *mut _REFS_STREAM_EXTENT;
+pub const _REFS_STREAM_SNAPSHOT_OPERATION_REFS_STREAM_SNAPSHOT_OPERATION_INVALID:
+_REFS_STREAM_SNAPSHOT_OPERATION = 0;
+pub const _REFS_STREAM_SNAPSHOT_OPERATION_REFS_STREAM_SNAPSHOT_OPERATION_CREATE:
+_REFS_STREAM_SNAPSHOT_OPERATION = 1;
+pub const _REFS_STREAM_SNAPSHOT_OPERATION_REFS_STREAM_SNAPSHOT_OPERATION_LIST:
+_REFS_STREAM_SNAPSHOT_OPERATION = 2;
+pub const _REFS_STREAM_SNAPSHOT_OPERATION_REFS_STREAM_SNAPSHOT_OPERATION_QUERY_DELTAS:
+_REFS_STREAM_SNAPSHOT_OPERATION = 3;
+pub const _REFS_STREAM_SNAPSHOT_OPERATION_REFS_STREAM_SNAPSHOT_OPERATION_REVERT:
+_REFS_STREAM_SNAPSHOT_OPERATION = 4;
+pub const _REFS_STREAM_SNAPSHOT_OPERATION_REFS_STREAM_SNAPSHOT_OPERATION_SET_SHADOW_BTREE:
+_REFS_STREAM_SNAPSHOT_OPERATION = 5;
+pub const _REFS_STREAM_SNAPSHOT_OPERATION_REFS_STREAM_SNAPSHOT_OPERATION_CLEAR_SHADOW_BTREE:
+_REFS_STREAM_SNAPSHOT_OPERATION = 6;
+pub const _REFS_STREAM_SNAPSHOT_OPERATION_REFS_STREAM_SNAPSHOT_OPERATION_MAX:
+_REFS_STREAM_SNAPSHOT_OPERATION = 6;
+pub type _REFS_STREAM_SNAPSHOT_OPERATION = core::ffi::c_int;
+pub use self::_REFS_STREAM_SNAPSHOT_OPERATION as REFS_STREAM_SNAPSHOT_OPERATION;
+pub type PREFS_STREAM_SNAPSHOT_OPERATION = *mut _REFS_STREAM_SNAPSHOT_OPERATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REFS_STREAM_SNAPSHOT_MANAGEMENT_INPUT_BUFFER {
+    pub Operation: REFS_STREAM_SNAPSHOT_OPERATION,
+    pub SnapshotNameLength: USHORT,
+    pub OperationInputBufferLength: USHORT,
+    pub Reserved: [ULONGLONG; 2usize],
+    pub NameAndInputBuffer: [USHORT; 1usize],
+}
+pub type REFS_STREAM_SNAPSHOT_MANAGEMENT_INPUT_BUFFER =
+_REFS_STREAM_SNAPSHOT_MANAGEMENT_INPUT_BUFFER;
+pub type PREFS_STREAM_SNAPSHOT_MANAGEMENT_INPUT_BUFFER =
+*mut _REFS_STREAM_SNAPSHOT_MANAGEMENT_INPUT_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REFS_STREAM_SNAPSHOT_LIST_OUTPUT_BUFFER_ENTRY {
+    pub NextEntryOffset: ULONG,
+    pub SnapshotNameLength: USHORT,
+    pub SnapshotCreationTime: ULONGLONG,
+    pub StreamSize: ULONGLONG,
+    pub StreamAllocationSize: ULONGLONG,
+    pub Reserved: [ULONGLONG; 2usize],
+    pub SnapshotName: [WCHAR; 1usize],
+}
+pub type REFS_STREAM_SNAPSHOT_LIST_OUTPUT_BUFFER_ENTRY =
+_REFS_STREAM_SNAPSHOT_LIST_OUTPUT_BUFFER_ENTRY;
+pub type PREFS_STREAM_SNAPSHOT_LIST_OUTPUT_BUFFER_ENTRY =
+*mut _REFS_STREAM_SNAPSHOT_LIST_OUTPUT_BUFFER_ENTRY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REFS_STREAM_SNAPSHOT_LIST_OUTPUT_BUFFER {
+    pub EntryCount: ULONG,
+    pub BufferSizeRequiredForQuery: ULONG,
+    pub Reserved: [ULONG; 2usize],
+    pub Entries: [REFS_STREAM_SNAPSHOT_LIST_OUTPUT_BUFFER_ENTRY; 1usize],
+}
+pub type REFS_STREAM_SNAPSHOT_LIST_OUTPUT_BUFFER = _REFS_STREAM_SNAPSHOT_LIST_OUTPUT_BUFFER;
+pub type PREFS_STREAM_SNAPSHOT_LIST_OUTPUT_BUFFER = *mut _REFS_STREAM_SNAPSHOT_LIST_OUTPUT_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REFS_STREAM_SNAPSHOT_QUERY_DELTAS_INPUT_BUFFER {
+    pub StartingVcn: LONGLONG,
+    pub Flags: ULONG,
+    pub Reserved: ULONG,
+}
+pub type REFS_STREAM_SNAPSHOT_QUERY_DELTAS_INPUT_BUFFER =
+_REFS_STREAM_SNAPSHOT_QUERY_DELTAS_INPUT_BUFFER;
+pub type PREFS_STREAM_SNAPSHOT_QUERY_DELTAS_INPUT_BUFFER =
+*mut _REFS_STREAM_SNAPSHOT_QUERY_DELTAS_INPUT_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REFS_STREAM_SNAPSHOT_QUERY_DELTAS_OUTPUT_BUFFER {
+    pub ExtentCount: ULONG,
+    pub Reserved: [ULONG; 2usize],
+    pub Extents: [REFS_STREAM_EXTENT; 1usize],
+}
+pub type REFS_STREAM_SNAPSHOT_QUERY_DELTAS_OUTPUT_BUFFER =
+_REFS_STREAM_SNAPSHOT_QUERY_DELTAS_OUTPUT_BUFFER;
+pub type PREFS_STREAM_SNAPSHOT_QUERY_DELTAS_OUTPUT_BUFFER =
+*mut _REFS_STREAM_SNAPSHOT_QUERY_DELTAS_OUTPUT_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SET_CACHED_RUNS_STATE_INPUT_BUFFER {
+    pub Enable: BOOLEAN,
+}
+pub type SET_CACHED_RUNS_STATE_INPUT_BUFFER = _SET_CACHED_RUNS_STATE_INPUT_BUFFER;
+pub type PSET_CACHED_RUNS_STATE_INPUT_BUFFER = *mut _SET_CACHED_RUNS_STATE_INPUT_BUFFER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FILE_STORAGE_RESERVE_ID_INFORMATION {
+    pub StorageReserveId: STORAGE_RESERVE_ID,
+}
+pub type FILE_STORAGE_RESERVE_ID_INFORMATION = _FILE_STORAGE_RESERVE_ID_INFORMATION;
+pub type PFILE_STORAGE_RESERVE_ID_INFORMATION = *mut _FILE_STORAGE_RESERVE_ID_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NETWORK_APP_INSTANCE_EA {
+    pub AppInstanceID: GUID,
+    pub CsvFlags: ULONG,
+}
+pub type NETWORK_APP_INSTANCE_EA = _NETWORK_APP_INSTANCE_EA;
+pub type PNETWORK_APP_INSTANCE_EA = *mut _NETWORK_APP_INSTANCE_EA;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SMB_SHARE_FLUSH_AND_PURGE_INPUT {
+    pub Version: USHORT,
+}
+pub type SMB_SHARE_FLUSH_AND_PURGE_INPUT = _SMB_SHARE_FLUSH_AND_PURGE_INPUT;
+pub type PSMB_SHARE_FLUSH_AND_PURGE_INPUT = *mut _SMB_SHARE_FLUSH_AND_PURGE_INPUT;
+pub type PCSMB_SHARE_FLUSH_AND_PURGE_INPUT = *const _SMB_SHARE_FLUSH_AND_PURGE_INPUT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SMB_SHARE_FLUSH_AND_PURGE_OUTPUT {
+    pub cEntriesPurged: ULONG,
+}
+pub type SMB_SHARE_FLUSH_AND_PURGE_OUTPUT = _SMB_SHARE_FLUSH_AND_PURGE_OUTPUT;
+pub type PSMB_SHARE_FLUSH_AND_PURGE_OUTPUT = *mut _SMB_SHARE_FLUSH_AND_PURGE_OUTPUT;
+pub type PCSMB_SHARE_FLUSH_AND_PURGE_OUTPUT = *const _SMB_SHARE_FLUSH_AND_PURGE_OUTPUT;
+unsafe extern "C" {
+    pub fn NtCopyFileChunk(
+        SourceHandle: HANDLE,
+        DestHandle: HANDLE,
+        Event: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        Length: ULONG,
+        SourceOffset: PLARGE_INTEGER,
+        DestOffset: PLARGE_INTEGER,
+        SourceKey: PULONG,
+        DestKey: PULONG,
+        Flags: ULONG,
+    ) -> NTSTATUS;
+}
+pub const _OBJECT_INFORMATION_CLASS_ObjectBasicInformation: _OBJECT_INFORMATION_CLASS = 0;
+pub const _OBJECT_INFORMATION_CLASS_ObjectTypeInformation: _OBJECT_INFORMATION_CLASS = 2;
+pub type _OBJECT_INFORMATION_CLASS = core::ffi::c_int;
+pub use self::_OBJECT_INFORMATION_CLASS as OBJECT_INFORMATION_CLASS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _PUBLIC_OBJECT_BASIC_INFORMATION {
+    pub Attributes: ULONG,
+    pub GrantedAccess: ACCESS_MASK,
+    pub HandleCount: ULONG,
+    pub PointerCount: ULONG,
+    pub Reserved: [ULONG; 10usize],
+}
+pub type PUBLIC_OBJECT_BASIC_INFORMATION = _PUBLIC_OBJECT_BASIC_INFORMATION;
+pub type PPUBLIC_OBJECT_BASIC_INFORMATION = *mut _PUBLIC_OBJECT_BASIC_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct __PUBLIC_OBJECT_TYPE_INFORMATION {
+    pub TypeName: UNICODE_STRING,
+    pub Reserved: [ULONG; 22usize],
+}
+pub type PUBLIC_OBJECT_TYPE_INFORMATION = __PUBLIC_OBJECT_TYPE_INFORMATION;
+pub type PPUBLIC_OBJECT_TYPE_INFORMATION = *mut __PUBLIC_OBJECT_TYPE_INFORMATION;
+unsafe extern "C" {
+    pub fn NtQueryObject(
+        Handle: HANDLE,
+        ObjectInformationClass: OBJECT_INFORMATION_CLASS,
+        ObjectInformation: PVOID,
+        ObjectInformationLength: ULONG,
+        ReturnLength: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtSetSecurityObject(
+        Handle: HANDLE,
+        SecurityInformation: SECURITY_INFORMATION,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtQuerySecurityObject(
+        Handle: HANDLE,
+        SecurityInformation: SECURITY_INFORMATION,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        Length: ULONG,
+        LengthNeeded: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtClose(Handle: HANDLE) -> NTSTATUS;
+}
+pub const _MEMORY_INFORMATION_CLASS_MemoryBasicInformation: _MEMORY_INFORMATION_CLASS = 0;
+pub type _MEMORY_INFORMATION_CLASS = core::ffi::c_int;
+pub use self::_MEMORY_INFORMATION_CLASS as MEMORY_INFORMATION_CLASS;
+pub const _VIRTUAL_MEMORY_INFORMATION_CLASS_VmPrefetchInformation:
+_VIRTUAL_MEMORY_INFORMATION_CLASS = 0;
+pub type _VIRTUAL_MEMORY_INFORMATION_CLASS = core::ffi::c_int;
+pub use self::_VIRTUAL_MEMORY_INFORMATION_CLASS as VIRTUAL_MEMORY_INFORMATION_CLASS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MEMORY_BASIC_INFORMATION {
+    pub BaseAddress: PVOID,
+    pub AllocationBase: PVOID,
+    pub AllocationProtect: ULONG,
+    pub PartitionId: USHORT,
+    pub RegionSize: SIZE_T,
+    pub State: ULONG,
+    pub Protect: ULONG,
+    pub Type: ULONG,
+}
+pub type MEMORY_BASIC_INFORMATION = _MEMORY_BASIC_INFORMATION;
+pub type PMEMORY_BASIC_INFORMATION = *mut _MEMORY_BASIC_INFORMATION;
+unsafe extern "C" {
+    pub fn NtCreateSection(
+        SectionHandle: PHANDLE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectAttributes: POBJECT_ATTRIBUTES,
+        MaximumSize: PLARGE_INTEGER,
+        SectionPageProtection: ULONG,
+        AllocationAttributes: ULONG,
+        FileHandle: HANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtCreateSectionEx(
+        SectionHandle: PHANDLE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectAttributes: POBJECT_ATTRIBUTES,
+        MaximumSize: PLARGE_INTEGER,
+        SectionPageProtection: ULONG,
+        AllocationAttributes: ULONG,
+        FileHandle: HANDLE,
+        ExtendedParameters: PMEM_EXTENDED_PARAMETER,
+        ExtendedParameterCount: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtAllocateVirtualMemory(
+        ProcessHandle: HANDLE,
+        BaseAddress: *mut PVOID,
+        ZeroBits: ULONG_PTR,
+        RegionSize: PSIZE_T,
+        AllocationType: ULONG,
+        Protect: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtFreeVirtualMemory(
+        ProcessHandle: HANDLE,
+        BaseAddress: *mut PVOID,
+        RegionSize: PSIZE_T,
+        FreeType: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn NtQueryVirtualMemory(
+        ProcessHandle: HANDLE,
+        BaseAddress: PVOID,
+        MemoryInformationClass: MEMORY_INFORMATION_CLASS,
+        MemoryInformation: PVOID,
+        MemoryInformationLength: SIZE_T,
+        ReturnLength: PSIZE_T,
+    ) -> NTSTATUS;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MEMORY_RANGE_ENTRY {
+    pub VirtualAddress: PVOID,
+    pub NumberOfBytes: SIZE_T,
+}
+pub type MEMORY_RANGE_ENTRY = _MEMORY_RANGE_ENTRY;
+pub type PMEMORY_RANGE_ENTRY = *mut _MEMORY_RANGE_ENTRY;
+unsafe extern "C" {
+    pub fn NtSetInformationVirtualMemory(
+        ProcessHandle: HANDLE,
+        VmInformationClass: VIRTUAL_MEMORY_INFORMATION_CLASS,
+        NumberOfEntries: ULONG_PTR,
+        VirtualAddresses: PMEMORY_RANGE_ENTRY,
+        VmInformation: PVOID,
+        VmInformationLength: ULONG,
+    ) -> NTSTATUS;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SECURITY_CLIENT_CONTEXT {
+    pub SecurityQos: SECURITY_QUALITY_OF_SERVICE,
+    pub ClientToken: PACCESS_TOKEN,
+    pub DirectlyAccessClientToken: BOOLEAN,
+    pub DirectAccessEffectiveOnly: BOOLEAN,
+    pub ServerIsRemote: BOOLEAN,
+    pub ClientTokenControl: TOKEN_CONTROL,
+}
+pub type SECURITY_CLIENT_CONTEXT = _SECURITY_CLIENT_CONTEXT;
+pub type PSECURITY_CLIENT_CONTEXT = *mut _SECURITY_CLIENT_CONTEXT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _KAPC_STATE {
+    pub ApcListHead: [LIST_ENTRY; 2usize],
+    pub Process: *mut _KPROCESS,
+    pub __bindgen_anon_1: _KAPC_STATE__bindgen_ty_1,
+    pub KernelApcPending: BOOLEAN,
+    pub __bindgen_anon_2: _KAPC_STATE__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _KAPC_STATE__bindgen_ty_1 {
+    pub InProgressFlags: UCHAR,
+    pub __bindgen_anon_1: _KAPC_STATE__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _KAPC_STATE__bindgen_ty_1__bindgen_ty_1 {
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+}
+impl _KAPC_STATE__bindgen_ty_1__bindgen_ty_1 {
+    #[inline]
+    pub fn KernelApcInProgress(&self) -> BOOLEAN {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u8) }
+    }
+    #[inline]
+    pub fn set_KernelApcInProgress(&mut self, val: BOOLEAN) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn KernelApcInProgress_raw(this: *const Self) -> BOOLEAN {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                1u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_KernelApcInProgress_raw(this: *mut Self, val: BOOLEAN) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn SpecialApcInProgress(&self) -> BOOLEAN {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u8) }
+    }
+    #[inline]
+    pub fn set_SpecialApcInProgress(&mut self, val: BOOLEAN) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(1usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn SpecialApcInProgress_raw(this: *const Self) -> BOOLEAN {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                1usize,
+                1u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_SpecialApcInProgress_raw(this: *mut Self, val: BOOLEAN) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                1usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        KernelApcInProgress: BOOLEAN,
+        SpecialApcInProgress: BOOLEAN,
+    ) -> __BindgenBitfieldUnit<[u8; 1usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 1u8, {
+            let KernelApcInProgress: u8 = unsafe { ::core::mem::transmute(KernelApcInProgress) };
+            KernelApcInProgress as u64
+        });
+        __bindgen_bitfield_unit.set(1usize, 1u8, {
+            let SpecialApcInProgress: u8 = unsafe { ::core::mem::transmute(SpecialApcInProgress) };
+            SpecialApcInProgress as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _KAPC_STATE__bindgen_ty_2 {
+    pub UserApcPendingAll: BOOLEAN,
+    pub __bindgen_anon_1: _KAPC_STATE__bindgen_ty_2__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _KAPC_STATE__bindgen_ty_2__bindgen_ty_1 {
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+}
+impl _KAPC_STATE__bindgen_ty_2__bindgen_ty_1 {
+    #[inline]
+    pub fn SpecialUserApcPending(&self) -> BOOLEAN {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u8) }
+    }
+    #[inline]
+    pub fn set_SpecialUserApcPending(&mut self, val: BOOLEAN) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn SpecialUserApcPending_raw(this: *const Self) -> BOOLEAN {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                1u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_SpecialUserApcPending_raw(this: *mut Self, val: BOOLEAN) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn UserApcPending(&self) -> BOOLEAN {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u8) }
+    }
+    #[inline]
+    pub fn set_UserApcPending(&mut self, val: BOOLEAN) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(1usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn UserApcPending_raw(this: *const Self) -> BOOLEAN {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                1usize,
+                1u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_UserApcPending_raw(this: *mut Self, val: BOOLEAN) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                1usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        SpecialUserApcPending: BOOLEAN,
+        UserApcPending: BOOLEAN,
+    ) -> __BindgenBitfieldUnit<[u8; 1usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 1u8, {
+            let SpecialUserApcPending: u8 =
+                unsafe { ::core::mem::transmute(SpecialUserApcPending) };
+            SpecialUserApcPending as u64
+        });
+        __bindgen_bitfield_unit.set(1usize, 1u8, {
+            let UserApcPending: u8 = unsafe { ::core::mem::transmute(UserApcPending) };
+            UserApcPending as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+pub type KAPC_STATE = _KAPC_STATE;
+pub type PKAPC_STATE = *mut _KAPC_STATE;
+pub type PRKAPC_STATE = *mut _KAPC_STATE;
+pub type KQUEUE = _KQUEUE;
+pub type PKQUEUE = *mut _KQUEUE;
+pub type PRKQUEUE = *mut _KQUEUE;
+unsafe extern "C" {
+    pub fn KeInitializeMutant(Mutant: PRKMUTANT, InitialOwner: BOOLEAN);
+}
+unsafe extern "C" {
+    pub fn KeQueryOwnerMutant(Mutant: PKMUTANT, ClientId: PCLIENT_ID);
+}
+unsafe extern "C" {
+    pub fn KeReadStateMutant(Mutant: PRKMUTANT) -> LONG;
+}
+unsafe extern "C" {
+    pub fn KeReleaseMutant(
+        Mutant: PRKMUTANT,
+        Increment: KPRIORITY,
+        Abandoned: BOOLEAN,
+        Wait: BOOLEAN,
+    ) -> LONG;
+}
+unsafe extern "C" {
+    pub fn KeInitializeQueue(Queue: PRKQUEUE, Count: ULONG);
+}
+unsafe extern "C" {
+    pub fn KeReadStateQueue(Queue: PRKQUEUE) -> LONG;
+}
+unsafe extern "C" {
+    pub fn KeInsertQueue(Queue: PRKQUEUE, Entry: PLIST_ENTRY) -> LONG;
+}
+unsafe extern "C" {
+    pub fn KeInsertHeadQueue(Queue: PRKQUEUE, Entry: PLIST_ENTRY) -> LONG;
+}
+unsafe extern "C" {
+    pub fn KeRemoveQueue(
+        Queue: PRKQUEUE,
+        WaitMode: KPROCESSOR_MODE,
+        Timeout: PLARGE_INTEGER,
+    ) -> PLIST_ENTRY;
+}
+unsafe extern "C" {
+    pub fn KeRemoveQueueEx(
+        Queue: PKQUEUE,
+        WaitMode: KPROCESSOR_MODE,
+        Alertable: BOOLEAN,
+        Timeout: PLARGE_INTEGER,
+        EntryArray: *mut PLIST_ENTRY,
+        Count: ULONG,
+    ) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn KeRundownQueue(Queue: PRKQUEUE) -> PLIST_ENTRY;
+}
+unsafe extern "C" {
+    pub fn KeAttachProcess(Process: PRKPROCESS);
+}
+unsafe extern "C" {
+    pub fn KeDetachProcess();
+}
+unsafe extern "C" {
+    pub fn KeStackAttachProcess(PROCESS: PRKPROCESS, ApcState: PRKAPC_STATE);
+}
+unsafe extern "C" {
+    pub fn KeUnstackDetachProcess(ApcState: PRKAPC_STATE);
+}
+unsafe extern "C" {
+    pub fn KeSetIdealProcessorThread(Thread: PKTHREAD, Processor: UCHAR) -> UCHAR;
+}
+unsafe extern "C" {
+    pub fn KeSetKernelStackSwapEnable(Enable: BOOLEAN) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn KeAcquireQueuedSpinLock(Number: KSPIN_LOCK_QUEUE_NUMBER) -> KIRQL;
+}
+unsafe extern "C" {
+    pub fn KeReleaseQueuedSpinLock(Number: KSPIN_LOCK_QUEUE_NUMBER, OldIrql: KIRQL);
+}
+unsafe extern "C" {
+    pub fn KeTryToAcquireQueuedSpinLock(
+        Number: KSPIN_LOCK_QUEUE_NUMBER,
+        OldIrql: PKIRQL,
+    ) -> LOGICAL;
+}
+unsafe extern "C" {
+    pub fn KeAcquireSpinLockRaiseToSynch(SpinLock: PKSPIN_LOCK) -> KIRQL;
+}
+unsafe extern "C" {
+    pub fn ExQueryPoolBlockSize(PoolBlock: PVOID, QuotaCharged: PBOOLEAN) -> SIZE_T;
+}
+unsafe extern "C" {
+    pub fn InterlockedPushListSList(
+        ListHead: PSLIST_HEADER,
+        List: PSLIST_ENTRY,
+        ListEnd: PSLIST_ENTRY,
+        Count: ULONG,
+    ) -> PSLIST_ENTRY;
+}
+unsafe extern "C" {
+    pub fn ExAdjustLookasideDepth();
+}
+unsafe extern "C" {
+    pub fn ExDisableResourceBoostLite(Resource: PERESOURCE);
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SE_EXPORTS {
+    pub SeCreateTokenPrivilege: LUID,
+    pub SeAssignPrimaryTokenPrivilege: LUID,
+    pub SeLockMemoryPrivilege: LUID,
+    pub SeIncreaseQuotaPrivilege: LUID,
+    pub SeUnsolicitedInputPrivilege: LUID,
+    pub SeTcbPrivilege: LUID,
+    pub SeSecurityPrivilege: LUID,
+    pub SeTakeOwnershipPrivilege: LUID,
+    pub SeLoadDriverPrivilege: LUID,
+    pub SeCreatePagefilePrivilege: LUID,
+    pub SeIncreaseBasePriorityPrivilege: LUID,
+    pub SeSystemProfilePrivilege: LUID,
+    pub SeSystemtimePrivilege: LUID,
+    pub SeProfileSingleProcessPrivilege: LUID,
+    pub SeCreatePermanentPrivilege: LUID,
+    pub SeBackupPrivilege: LUID,
+    pub SeRestorePrivilege: LUID,
+    pub SeShutdownPrivilege: LUID,
+    pub SeDebugPrivilege: LUID,
+    pub SeAuditPrivilege: LUID,
+    pub SeSystemEnvironmentPrivilege: LUID,
+    pub SeChangeNotifyPrivilege: LUID,
+    pub SeRemoteShutdownPrivilege: LUID,
+    pub SeNullSid: PSID,
+    pub SeWorldSid: PSID,
+    pub SeLocalSid: PSID,
+    pub SeCreatorOwnerSid: PSID,
+    pub SeCreatorGroupSid: PSID,
+    pub SeNtAuthoritySid: PSID,
+    pub SeDialupSid: PSID,
+    pub SeNetworkSid: PSID,
+    pub SeBatchSid: PSID,
+    pub SeInteractiveSid: PSID,
+    pub SeLocalSystemSid: PSID,
+    pub SeAliasAdminsSid: PSID,
+    pub SeAliasUsersSid: PSID,
+    pub SeAliasGuestsSid: PSID,
+    pub SeAliasPowerUsersSid: PSID,
+    pub SeAliasAccountOpsSid: PSID,
+    pub SeAliasSystemOpsSid: PSID,
+    pub SeAliasPrintOpsSid: PSID,
+    pub SeAliasBackupOpsSid: PSID,
+    pub SeAuthenticatedUsersSid: PSID,
+    pub SeRestrictedSid: PSID,
+    pub SeAnonymousLogonSid: PSID,
+    pub SeUndockPrivilege: LUID,
+    pub SeSyncAgentPrivilege: LUID,
+    pub SeEnableDelegationPrivilege: LUID,
+    pub SeLocalServiceSid: PSID,
+    pub SeNetworkServiceSid: PSID,
+    pub SeManageVolumePrivilege: LUID,
+    pub SeImpersonatePrivilege: LUID,
+    pub SeCreateGlobalPrivilege: LUID,
+    pub SeTrustedCredManAccessPrivilege: LUID,
+    pub SeRelabelPrivilege: LUID,
+    pub SeIncreaseWorkingSetPrivilege: LUID,
+    pub SeTimeZonePrivilege: LUID,
+    pub SeCreateSymbolicLinkPrivilege: LUID,
+    pub SeIUserSid: PSID,
+    pub SeUntrustedMandatorySid: PSID,
+    pub SeLowMandatorySid: PSID,
+    pub SeMediumMandatorySid: PSID,
+    pub SeHighMandatorySid: PSID,
+    pub SeSystemMandatorySid: PSID,
+    pub SeOwnerRightsSid: PSID,
+    pub SeAllAppPackagesSid: PSID,
+    pub SeUserModeDriversSid: PSID,
+    pub SeProcTrustWinTcbSid: PSID,
+    pub SeTrustedInstallerSid: PSID,
+    pub SeDelegateSessionUserImpersonatePrivilege: LUID,
+    pub SeAppSiloSid: PSID,
+    pub SeAppSiloVolumeRootMinimalCapabilitySid: PSID,
+    pub SeAppSiloProfilesRootMinimalCapabilitySid: PSID,
+}
+pub type SE_EXPORTS = _SE_EXPORTS;
+pub type PSE_EXPORTS = *mut _SE_EXPORTS;
+pub type SE_LOGON_SESSION_TERMINATED_ROUTINE =
+::core::option::Option<unsafe extern "C" fn(LogonId: PLUID) -> NTSTATUS>;
+pub type PSE_LOGON_SESSION_TERMINATED_ROUTINE = SE_LOGON_SESSION_TERMINATED_ROUTINE;
+pub type SE_LOGON_SESSION_TERMINATED_ROUTINE_EX = ::core::option::Option<
+    unsafe extern "C" fn(LogonId: PLUID, pServerSilo: PESILO, Context: PVOID) -> NTSTATUS,
+>;
+pub type PSE_LOGON_SESSION_TERMINATED_ROUTINE_EX = SE_LOGON_SESSION_TERMINATED_ROUTINE_EX;
+unsafe extern "C" {
+    pub fn SeDeleteClientSecurity(ClientContext: PSECURITY_CLIENT_CONTEXT);
+}
+unsafe extern "C" {
+    pub fn SeCaptureSubjectContextEx(
+        Thread: PETHREAD,
+        Process: PEPROCESS,
+        SubjectContext: PSECURITY_SUBJECT_CONTEXT,
+    );
+}
+unsafe extern "C" {
+    pub fn SeReportSecurityEventWithSubCategory(
+        Flags: ULONG,
+        SourceName: PUNICODE_STRING,
+        UserSid: PSID,
+        AuditParameters: PSE_ADT_PARAMETER_ARRAY,
+        AuditSubcategoryId: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeAccessCheckFromState(
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        PrimaryTokenInformation: PTOKEN_ACCESS_INFORMATION,
+        ClientTokenInformation: PTOKEN_ACCESS_INFORMATION,
+        DesiredAccess: ACCESS_MASK,
+        PreviouslyGrantedAccess: ACCESS_MASK,
+        Privileges: *mut PPRIVILEGE_SET,
+        GenericMapping: PGENERIC_MAPPING,
+        AccessMode: KPROCESSOR_MODE,
+        GrantedAccess: PACCESS_MASK,
+        AccessStatus: PNTSTATUS,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeAccessCheckFromStateEx(
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        PrimaryToken: PACCESS_TOKEN,
+        ClientToken: PACCESS_TOKEN,
+        DesiredAccess: ACCESS_MASK,
+        PreviouslyGrantedAccess: ACCESS_MASK,
+        Privileges: *mut PPRIVILEGE_SET,
+        GenericMapping: PGENERIC_MAPPING,
+        AccessMode: KPROCESSOR_MODE,
+        GrantedAccess: PACCESS_MASK,
+        AccessStatus: PNTSTATUS,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeTokenFromAccessInformation(
+        AccessInformation: PTOKEN_ACCESS_INFORMATION,
+        Token: PACCESS_TOKEN,
+        Length: ULONG,
+        RequiredLength: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SePrivilegeCheck(
+        RequiredPrivileges: PPRIVILEGE_SET,
+        SubjectSecurityContext: PSECURITY_SUBJECT_CONTEXT,
+        AccessMode: KPROCESSOR_MODE,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeFreePrivileges(Privileges: PPRIVILEGE_SET);
+}
+unsafe extern "C" {
+    pub fn SeOpenObjectAuditAlarm(
+        ObjectTypeName: PUNICODE_STRING,
+        Object: PVOID,
+        AbsoluteObjectName: PUNICODE_STRING,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        AccessState: PACCESS_STATE,
+        ObjectCreated: BOOLEAN,
+        AccessGranted: BOOLEAN,
+        AccessMode: KPROCESSOR_MODE,
+        GenerateOnClose: PBOOLEAN,
+    );
+}
+unsafe extern "C" {
+    pub fn SeOpenObjectAuditAlarmWithTransaction(
+        ObjectTypeName: PUNICODE_STRING,
+        Object: PVOID,
+        AbsoluteObjectName: PUNICODE_STRING,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        AccessState: PACCESS_STATE,
+        ObjectCreated: BOOLEAN,
+        AccessGranted: BOOLEAN,
+        AccessMode: KPROCESSOR_MODE,
+        TransactionId: *mut GUID,
+        GenerateOnClose: PBOOLEAN,
+    );
+}
+unsafe extern "C" {
+    pub fn SeOpenObjectForDeleteAuditAlarm(
+        ObjectTypeName: PUNICODE_STRING,
+        Object: PVOID,
+        AbsoluteObjectName: PUNICODE_STRING,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        AccessState: PACCESS_STATE,
+        ObjectCreated: BOOLEAN,
+        AccessGranted: BOOLEAN,
+        AccessMode: KPROCESSOR_MODE,
+        GenerateOnClose: PBOOLEAN,
+    );
+}
+unsafe extern "C" {
+    pub fn SeOpenObjectForDeleteAuditAlarmWithTransaction(
+        ObjectTypeName: PUNICODE_STRING,
+        Object: PVOID,
+        AbsoluteObjectName: PUNICODE_STRING,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        AccessState: PACCESS_STATE,
+        ObjectCreated: BOOLEAN,
+        AccessGranted: BOOLEAN,
+        AccessMode: KPROCESSOR_MODE,
+        TransactionId: *mut GUID,
+        GenerateOnClose: PBOOLEAN,
+    );
+}
+unsafe extern "C" {
+    pub fn SeExamineSacl(
+        Sacl: PACL,
+        ResourceSacl: PACL,
+        Token: PACCESS_TOKEN,
+        DesiredAccess: ACCESS_MASK,
+        AccessGranted: BOOLEAN,
+        GenerateAudit: PBOOLEAN,
+        GenerateAlarm: PBOOLEAN,
+    );
+}
+unsafe extern "C" {
+    pub fn SeDeleteObjectAuditAlarm(Object: PVOID, Handle: HANDLE);
+}
+unsafe extern "C" {
+    pub fn SeDeleteObjectAuditAlarmWithTransaction(
+        Object: PVOID,
+        Handle: HANDLE,
+        TransactionId: *mut GUID,
+    );
+}
+unsafe extern "C" {
+    pub fn SeTokenType(Token: PACCESS_TOKEN) -> TOKEN_TYPE;
+}
+unsafe extern "C" {
+    pub fn SeTokenIsAdmin(Token: PACCESS_TOKEN) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeTokenIsRestricted(Token: PACCESS_TOKEN) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeTokenIsWriteRestricted(Token: PACCESS_TOKEN) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeTokenIsNoChildProcessRestrictionEnforced(
+        Token: PACCESS_TOKEN,
+        UnlessSecure: PBOOLEAN,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeTokenSetNoChildProcessRestricted(
+        Token: PACCESS_TOKEN,
+        UnlessSecure: BOOLEAN,
+        AuditOnly: BOOLEAN,
+    );
+}
+unsafe extern "C" {
+    pub fn SeTokenGetNoChildProcessRestricted(
+        Token: PACCESS_TOKEN,
+        Enforced: PBOOLEAN,
+        UnlessSecure: PBOOLEAN,
+        AuditOnly: PBOOLEAN,
+    );
+}
+unsafe extern "C" {
+    pub fn SeTokenSetRedirectionTrustPolicy(Token: PACCESS_TOKEN, AuditOnly: BOOLEAN);
+}
+unsafe extern "C" {
+    pub fn SeTokenGetRedirectionTrustPolicy(
+        Token: PACCESS_TOKEN,
+        Enforced: PBOOLEAN,
+        AuditOnly: PBOOLEAN,
+    );
+}
+unsafe extern "C" {
+    pub fn SeFilterToken(
+        ExistingToken: PACCESS_TOKEN,
+        Flags: ULONG,
+        SidsToDisable: PTOKEN_GROUPS,
+        PrivilegesToDelete: PTOKEN_PRIVILEGES,
+        RestrictedSids: PTOKEN_GROUPS,
+        FilteredToken: *mut PACCESS_TOKEN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeQueryAuthenticationIdToken(Token: PACCESS_TOKEN, AuthenticationId: PLUID) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeQueryTokenIntegrity(Token: PACCESS_TOKEN, IntegritySA: PSID_AND_ATTRIBUTES);
+}
+unsafe extern "C" {
+    pub fn SeQuerySessionIdToken(Token: PACCESS_TOKEN, SessionId: PULONG) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeQuerySessionIdTokenEx(
+        Token: PACCESS_TOKEN,
+        SessionId: PULONG,
+        IsServiceSession: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeQueryServerSiloToken(Token: PACCESS_TOKEN, pServerSilo: *mut PESILO) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeSetSessionIdToken(Token: PACCESS_TOKEN, SessionId: ULONG) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeCreateClientSecurity(
+        ClientThread: PETHREAD,
+        ClientSecurityQos: PSECURITY_QUALITY_OF_SERVICE,
+        RemoteSession: BOOLEAN,
+        ClientContext: PSECURITY_CLIENT_CONTEXT,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeImpersonateClient(ClientContext: PSECURITY_CLIENT_CONTEXT, ServerThread: PETHREAD);
+}
+unsafe extern "C" {
+    pub fn SeImpersonateClientEx(
+        ClientContext: PSECURITY_CLIENT_CONTEXT,
+        ServerThread: PETHREAD,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeCreateClientSecurityFromSubjectContext(
+        SubjectContext: PSECURITY_SUBJECT_CONTEXT,
+        ClientSecurityQos: PSECURITY_QUALITY_OF_SERVICE,
+        ServerIsRemote: BOOLEAN,
+        ClientContext: PSECURITY_CLIENT_CONTEXT,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeQuerySecurityDescriptorInfo(
+        SecurityInformation: PSECURITY_INFORMATION,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        Length: PULONG,
+        ObjectsSecurityDescriptor: *mut PSECURITY_DESCRIPTOR,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeSetSecurityDescriptorInfo(
+        Object: PVOID,
+        SecurityInformation: PSECURITY_INFORMATION,
+        ModificationDescriptor: PSECURITY_DESCRIPTOR,
+        ObjectsSecurityDescriptor: *mut PSECURITY_DESCRIPTOR,
+        PoolType: POOL_TYPE,
+        GenericMapping: PGENERIC_MAPPING,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeSetSecurityDescriptorInfoEx(
+        Object: PVOID,
+        SecurityInformation: PSECURITY_INFORMATION,
+        ModificationDescriptor: PSECURITY_DESCRIPTOR,
+        ObjectsSecurityDescriptor: *mut PSECURITY_DESCRIPTOR,
+        AutoInheritFlags: ULONG,
+        PoolType: POOL_TYPE,
+        GenericMapping: PGENERIC_MAPPING,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeAppendPrivileges(AccessState: PACCESS_STATE, Privileges: PPRIVILEGE_SET) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeAuditHardLinkCreation(
+        FileName: PUNICODE_STRING,
+        LinkName: PUNICODE_STRING,
+        bSuccess: BOOLEAN,
+    );
+}
+unsafe extern "C" {
+    pub fn SeAuditHardLinkCreationWithTransaction(
+        FileName: PUNICODE_STRING,
+        LinkName: PUNICODE_STRING,
+        bSuccess: BOOLEAN,
+        TransactionId: *mut GUID,
+    );
+}
+unsafe extern "C" {
+    pub fn SeAuditFipsCryptoSelftests(bSuccess: BOOLEAN, SelftestCode: ULONG);
+}
+unsafe extern "C" {
+    pub fn SeAuditTransactionStateChange(
+        TransactionId: *mut GUID,
+        ResourceManagerId: *mut GUID,
+        NewTransactionState: ULONG,
+    );
+}
+unsafe extern "C" {
+    pub fn SeAuditingFileEvents(
+        AccessGranted: BOOLEAN,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeAuditingFileEventsWithContext(
+        AccessGranted: BOOLEAN,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        SubjectSecurityContext: PSECURITY_SUBJECT_CONTEXT,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeAuditingAnyFileEventsWithContext(
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        SubjectSecurityContext: PSECURITY_SUBJECT_CONTEXT,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeAuditingFileEventsWithContextEx(
+        AccessGranted: BOOLEAN,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        SubjectSecurityContext: PSECURITY_SUBJECT_CONTEXT,
+        StagingEnabled: PBOOLEAN,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeAuditingAnyFileEventsWithContextEx(
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        SubjectSecurityContext: PSECURITY_SUBJECT_CONTEXT,
+        StagingEnabled: PBOOLEAN,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeAdjustAccessStateForTrustLabel(
+        ObjectType: PVOID,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        AccessState: PACCESS_STATE,
+    );
+}
+unsafe extern "C" {
+    pub fn SeAdjustAccessStateForAccessConstraints(
+        ObjectType: PVOID,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        AccessState: PACCESS_STATE,
+    );
+}
+unsafe extern "C" {
+    pub fn SeShouldCheckForAccessRightsFromParent(
+        ObjectType: PVOID,
+        ChildDescriptor: PSECURITY_DESCRIPTOR,
+        AccessState: PACCESS_STATE,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeAuditingHardLinkEvents(
+        AccessGranted: BOOLEAN,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeAuditingHardLinkEventsWithContext(
+        AccessGranted: BOOLEAN,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        SubjectSecurityContext: PSECURITY_SUBJECT_CONTEXT,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeAuditingFileOrGlobalEvents(
+        AccessGranted: BOOLEAN,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        SubjectSecurityContext: PSECURITY_SUBJECT_CONTEXT,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeSetAccessStateGenericMapping(
+        AccessState: PACCESS_STATE,
+        GenericMapping: PGENERIC_MAPPING,
+    );
+}
+unsafe extern "C" {
+    pub fn SeRegisterLogonSessionTerminatedRoutine(
+        CallbackRoutine: PSE_LOGON_SESSION_TERMINATED_ROUTINE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeUnregisterLogonSessionTerminatedRoutine(
+        CallbackRoutine: PSE_LOGON_SESSION_TERMINATED_ROUTINE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeRegisterLogonSessionTerminatedRoutineEx(
+        CallbackRoutine: PSE_LOGON_SESSION_TERMINATED_ROUTINE_EX,
+        Context: PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeUnregisterLogonSessionTerminatedRoutineEx(
+        CallbackRoutine: PSE_LOGON_SESSION_TERMINATED_ROUTINE_EX,
+        Context: PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeMarkLogonSessionForTerminationNotification(LogonId: PLUID) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeMarkLogonSessionForTerminationNotificationEx(
+        LogonId: PLUID,
+        pServerSilo: PESILO,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeQueryInformationToken(
+        Token: PACCESS_TOKEN,
+        TokenInformationClass: TOKEN_INFORMATION_CLASS,
+        TokenInformation: *mut PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SeLocateProcessImageName(
+        Process: PEPROCESS,
+        pImageFileName: *mut PUNICODE_STRING,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub static mut SeExports: PSE_EXPORTS;
+}
+unsafe extern "C" {
+    pub fn SeExamineGlobalSacl(
+        ObjectType: PUNICODE_STRING,
+        ResourceSacl: PACL,
+        Token: PACCESS_TOKEN,
+        DesiredAccess: ACCESS_MASK,
+        AccessGranted: BOOLEAN,
+        GenerateAudit: PBOOLEAN,
+        GenerateAlarm: PBOOLEAN,
+    );
+}
+unsafe extern "C" {
+    pub fn SeMaximumAuditMaskFromGlobalSacl(
+        ObjectTypeName: PUNICODE_STRING,
+        GrantedAccess: ACCESS_MASK,
+        Token: PACCESS_TOKEN,
+        AuditMask: PACCESS_MASK,
+    );
+}
+unsafe extern "C" {
+    pub fn RtlIsSandboxedTokenHandle(TokenHandle: HANDLE, PreviousMode: KPROCESSOR_MODE)
+                                     -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn RtlIsSandboxedToken(
+        Context: PSECURITY_SUBJECT_CONTEXT,
+        PreviousMode: KPROCESSOR_MODE,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SeCheckForCriticalAceRemoval(
+        CurrentDescriptor: PSECURITY_DESCRIPTOR,
+        NewDescriptor: PSECURITY_DESCRIPTOR,
+        SubjectSecurityContext: PSECURITY_SUBJECT_CONTEXT,
+        AceRemoved: PBOOLEAN,
+    );
+}
+unsafe extern "C" {
+    pub fn SeAdjustObjectSecurity(
+        ObjectName: PUNICODE_STRING,
+        OriginalDescriptor: PSECURITY_DESCRIPTOR,
+        ProposedDescriptor: PSECURITY_DESCRIPTOR,
+        SubjectSecurityContext: PSECURITY_SUBJECT_CONTEXT,
+        AdjustedDescriptor: *mut PSECURITY_DESCRIPTOR,
+        ApplyAdjustedDescriptor: PBOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn PsAssignImpersonationToken(Thread: PETHREAD, Token: HANDLE) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn PsReferencePrimaryToken(Process: PEPROCESS) -> PACCESS_TOKEN;
+}
+unsafe extern "C" {
+    pub fn PsDereferencePrimaryToken(PrimaryToken: PACCESS_TOKEN);
+}
+unsafe extern "C" {
+    pub fn PsDereferenceImpersonationToken(ImpersonationToken: PACCESS_TOKEN);
+}
+unsafe extern "C" {
+    pub fn PsReferenceImpersonationToken(
+        Thread: PETHREAD,
+        CopyOnOpen: PBOOLEAN,
+        EffectiveOnly: PBOOLEAN,
+        ImpersonationLevel: PSECURITY_IMPERSONATION_LEVEL,
+    ) -> PACCESS_TOKEN;
+}
+unsafe extern "C" {
+    pub fn PsGetProcessExitTime() -> LARGE_INTEGER;
+}
+unsafe extern "C" {
+    pub fn PsIsThreadTerminating(Thread: PETHREAD) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn PsImpersonateClient(
+        Thread: PETHREAD,
+        Token: PACCESS_TOKEN,
+        CopyOnOpen: BOOLEAN,
+        EffectiveOnly: BOOLEAN,
+        ImpersonationLevel: SECURITY_IMPERSONATION_LEVEL,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn PsDisableImpersonation(
+        Thread: PETHREAD,
+        ImpersonationState: PSE_IMPERSONATION_STATE,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn PsRestoreImpersonation(Thread: PETHREAD, ImpersonationState: PSE_IMPERSONATION_STATE);
+}
+unsafe extern "C" {
+    pub fn PsRevertToSelf();
+}
+unsafe extern "C" {
+    pub fn PsLookupProcessByProcessId(ProcessId: HANDLE, Process: *mut PEPROCESS) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn PsLookupThreadByThreadId(ThreadId: HANDLE, Thread: *mut PETHREAD) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn PsChargePoolQuota(Process: PEPROCESS, PoolType: POOL_TYPE, Amount: ULONG_PTR);
+}
+unsafe extern "C" {
+    pub fn PsChargeProcessPoolQuota(
+        Process: PEPROCESS,
+        PoolType: POOL_TYPE,
+        Amount: ULONG_PTR,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn PsReturnPoolQuota(Process: PEPROCESS, PoolType: POOL_TYPE, Amount: ULONG_PTR);
+}
+unsafe extern "C" {
+    pub fn PsGetThreadProcess(Thread: PETHREAD) -> PEPROCESS;
+}
+unsafe extern "C" {
+    pub fn PsIsSystemThread(Thread: PETHREAD) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn PsUpdateDiskCounters(
+        Process: PEPROCESS,
+        BytesRead: ULONG64,
+        BytesWritten: ULONG64,
+        ReadOperationCount: ULONG,
+        WriteOperationCount: ULONG,
+        FlushOperationCount: ULONG,
+    );
+}
+unsafe extern "C" {
+    pub fn PsIsDiskCountersEnabled() -> BOOLEAN;
+}
+pub type DRIVER_FS_NOTIFICATION = ::core::option::Option<
+    unsafe extern "C" fn(DeviceObject: *mut _DEVICE_OBJECT, FsActive: BOOLEAN),
+>;
+pub type PDRIVER_FS_NOTIFICATION = DRIVER_FS_NOTIFICATION;
+pub const _FS_FILTER_SECTION_SYNC_TYPE_SyncTypeOther: _FS_FILTER_SECTION_SYNC_TYPE = 0;
+pub const _FS_FILTER_SECTION_SYNC_TYPE_SyncTypeCreateSection: _FS_FILTER_SECTION_SYNC_TYPE = 1;
+pub type _FS_FILTER_SECTION_SYNC_TYPE = core::ffi::c_int;
+pub use self::_FS_FILTER_SECTION_SYNC_TYPE as FS_FILTER_SECTION_SYNC_TYPE;
+pub type PFS_FILTER_SECTION_SYNC_TYPE = *mut _FS_FILTER_SECTION_SYNC_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FS_FILTER_SECTION_SYNC_OUTPUT {
+    pub StructureSize: ULONG,
+    pub SizeReturned: ULONG,
+    pub Flags: ULONG,
+    pub DesiredReadAlignment: ULONG,
+}
+pub type FS_FILTER_SECTION_SYNC_OUTPUT = _FS_FILTER_SECTION_SYNC_OUTPUT;
+pub type PFS_FILTER_SECTION_SYNC_OUTPUT = *mut _FS_FILTER_SECTION_SYNC_OUTPUT;
+pub const _FS_FILTER_STREAM_FO_NOTIFICATION_TYPE_NotifyTypeCreate:
+_FS_FILTER_STREAM_FO_NOTIFICATION_TYPE = 0;
+pub const _FS_FILTER_STREAM_FO_NOTIFICATION_TYPE_NotifyTypeRetired:
+_FS_FILTER_STREAM_FO_NOTIFICATION_TYPE = 1;
+pub type _FS_FILTER_STREAM_FO_NOTIFICATION_TYPE = core::ffi::c_int;
+pub use self::_FS_FILTER_STREAM_FO_NOTIFICATION_TYPE as FS_FILTER_STREAM_FO_NOTIFICATION_TYPE;
+pub type PFS_FILTER_STREAM_FO_NOTIFICATION_TYPE = *mut _FS_FILTER_STREAM_FO_NOTIFICATION_TYPE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _FS_FILTER_PARAMETERS {
+    pub AcquireForModifiedPageWriter: _FS_FILTER_PARAMETERS__bindgen_ty_1,
+    pub ReleaseForModifiedPageWriter: _FS_FILTER_PARAMETERS__bindgen_ty_2,
+    pub AcquireForSectionSynchronization: _FS_FILTER_PARAMETERS__bindgen_ty_3,
+    pub QueryOpen: _FS_FILTER_PARAMETERS__bindgen_ty_4,
+    pub Others: _FS_FILTER_PARAMETERS__bindgen_ty_5,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FS_FILTER_PARAMETERS__bindgen_ty_1 {
+    pub EndingOffset: PLARGE_INTEGER,
+    pub ResourceToRelease: *mut PERESOURCE,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FS_FILTER_PARAMETERS__bindgen_ty_2 {
+    pub ResourceToRelease: PERESOURCE,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FS_FILTER_PARAMETERS__bindgen_ty_3 {
+    pub SyncType: FS_FILTER_SECTION_SYNC_TYPE,
+    pub PageProtection: ULONG,
+    pub OutputInformation: PFS_FILTER_SECTION_SYNC_OUTPUT,
+    pub Flags: ULONG,
+    pub AllocationAttributes: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FS_FILTER_PARAMETERS__bindgen_ty_4 {
+    pub Irp: PIRP,
+    pub FileInformation: PVOID,
+    pub Length: PULONG,
+    pub FileInformationClass: FILE_INFORMATION_CLASS,
+    pub CompletionStatus: NTSTATUS,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FS_FILTER_PARAMETERS__bindgen_ty_5 {
+    pub Argument1: PVOID,
+    pub Argument2: PVOID,
+    pub Argument3: PVOID,
+    pub Argument4: PVOID,
+    pub Argument5: PVOID,
+}
+pub type FS_FILTER_PARAMETERS = _FS_FILTER_PARAMETERS;
+pub type PFS_FILTER_PARAMETERS = *mut _FS_FILTER_PARAMETERS;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FS_FILTER_CALLBACK_DATA {
+    pub SizeOfFsFilterCallbackData: ULONG,
+    pub Operation: UCHAR,
+    pub Reserved: UCHAR,
+    pub DeviceObject: *mut _DEVICE_OBJECT,
+    pub FileObject: *mut _FILE_OBJECT,
+    pub Parameters: FS_FILTER_PARAMETERS,
+}
+pub type FS_FILTER_CALLBACK_DATA = _FS_FILTER_CALLBACK_DATA;
+pub type PFS_FILTER_CALLBACK_DATA = *mut _FS_FILTER_CALLBACK_DATA;
+pub type PFS_FILTER_CALLBACK = ::core::option::Option<
+    unsafe extern "C" fn(Data: PFS_FILTER_CALLBACK_DATA, CompletionContext: *mut PVOID) -> NTSTATUS,
+>;
+pub type PFS_FILTER_COMPLETION_CALLBACK = ::core::option::Option<
+    unsafe extern "C" fn(
+        Data: PFS_FILTER_CALLBACK_DATA,
+        OperationStatus: NTSTATUS,
+        CompletionContext: PVOID,
+    ),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FS_FILTER_CALLBACKS {
+    pub SizeOfFsFilterCallbacks: ULONG,
+    pub Reserved: ULONG,
+    pub PreAcquireForSectionSynchronization: PFS_FILTER_CALLBACK,
+    pub PostAcquireForSectionSynchronization: PFS_FILTER_COMPLETION_CALLBACK,
+    pub PreReleaseForSectionSynchronization: PFS_FILTER_CALLBACK,
+    pub PostReleaseForSectionSynchronization: PFS_FILTER_COMPLETION_CALLBACK,
+    pub PreAcquireForCcFlush: PFS_FILTER_CALLBACK,
+    pub PostAcquireForCcFlush: PFS_FILTER_COMPLETION_CALLBACK,
+    pub PreReleaseForCcFlush: PFS_FILTER_CALLBACK,
+    pub PostReleaseForCcFlush: PFS_FILTER_COMPLETION_CALLBACK,
+    pub PreAcquireForModifiedPageWriter: PFS_FILTER_CALLBACK,
+    pub PostAcquireForModifiedPageWriter: PFS_FILTER_COMPLETION_CALLBACK,
+    pub PreReleaseForModifiedPageWriter: PFS_FILTER_CALLBACK,
+    pub PostReleaseForModifiedPageWriter: PFS_FILTER_COMPLETION_CALLBACK,
+    pub PreQueryOpen: PFS_FILTER_CALLBACK,
+    pub PostQueryOpen: PFS_FILTER_COMPLETION_CALLBACK,
+}
+pub type FS_FILTER_CALLBACKS = _FS_FILTER_CALLBACKS;
+pub type PFS_FILTER_CALLBACKS = *mut _FS_FILTER_CALLBACKS;
+unsafe extern "C" {
+    pub fn FsRtlRegisterFileSystemFilterCallbacks(
+        FilterDriverObject: *mut _DRIVER_OBJECT,
+        Callbacks: PFS_FILTER_CALLBACKS,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlNotifyStreamFileObject(
+        StreamFileObject: *mut _FILE_OBJECT,
+        DeviceObjectHint: *mut _DEVICE_OBJECT,
+        NotificationType: FS_FILTER_STREAM_FO_NOTIFICATION_TYPE,
+        SafeToRecurse: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub static mut IoStatisticsLock: KSPIN_LOCK;
+}
+unsafe extern "C" {
+    pub static mut IoReadOperationCount: ULONG;
+}
+unsafe extern "C" {
+    pub static mut IoWriteOperationCount: ULONG;
+}
+unsafe extern "C" {
+    pub static mut IoOtherOperationCount: ULONG;
+}
+unsafe extern "C" {
+    pub static mut IoReadTransferCount: LARGE_INTEGER;
+}
+unsafe extern "C" {
+    pub static mut IoWriteTransferCount: LARGE_INTEGER;
+}
+unsafe extern "C" {
+    pub static mut IoOtherTransferCount: LARGE_INTEGER;
+}
+unsafe extern "C" {
+    pub fn IoAcquireVpbSpinLock(Irql: PKIRQL);
+}
+unsafe extern "C" {
+    pub fn IoCheckDesiredAccess(
+        DesiredAccess: PACCESS_MASK,
+        GrantedAccess: ACCESS_MASK,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoCheckEaBufferValidity(
+        EaBuffer: PFILE_FULL_EA_INFORMATION,
+        EaLength: ULONG,
+        ErrorOffset: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoCheckFunctionAccess(
+        GrantedAccess: ACCESS_MASK,
+        MajorFunction: UCHAR,
+        MinorFunction: UCHAR,
+        IoControlCode: ULONG,
+        Arg1: PVOID,
+        Arg2: PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoCheckQuerySetFileInformation(
+        FileInformationClass: FILE_INFORMATION_CLASS,
+        Length: ULONG,
+        SetOperation: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoCheckQuerySetVolumeInformation(
+        FsInformationClass: FS_INFORMATION_CLASS,
+        Length: ULONG,
+        SetOperation: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoCheckQuotaBufferValidity(
+        QuotaBuffer: PFILE_QUOTA_INFORMATION,
+        QuotaLength: ULONG,
+        ErrorOffset: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoCreateStreamFileObject(
+        FileObject: PFILE_OBJECT,
+        DeviceObject: PDEVICE_OBJECT,
+    ) -> PFILE_OBJECT;
+}
+unsafe extern "C" {
+    pub fn IoCreateStreamFileObjectEx(
+        FileObject: PFILE_OBJECT,
+        DeviceObject: PDEVICE_OBJECT,
+        FileHandle: PHANDLE,
+    ) -> PFILE_OBJECT;
+}
+unsafe extern "C" {
+    pub fn IoCreateStreamFileObjectLite(
+        FileObject: PFILE_OBJECT,
+        DeviceObject: PDEVICE_OBJECT,
+    ) -> PFILE_OBJECT;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_CREATE_STREAM_FILE_OPTIONS {
+    pub Size: USHORT,
+    pub Flags: USHORT,
+    pub TargetDeviceObject: PDEVICE_OBJECT,
+}
+pub type IO_CREATE_STREAM_FILE_OPTIONS = _IO_CREATE_STREAM_FILE_OPTIONS;
+pub type PIO_CREATE_STREAM_FILE_OPTIONS = *mut _IO_CREATE_STREAM_FILE_OPTIONS;
+unsafe extern "C" {
+    pub fn IoCreateStreamFileObjectEx2(
+        CreateOptions: PIO_CREATE_STREAM_FILE_OPTIONS,
+        FileObject: PFILE_OBJECT,
+        DeviceObject: PDEVICE_OBJECT,
+        StreamFileObject: *mut PFILE_OBJECT,
+        FileHandle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoFastQueryNetworkAttributes(
+        ObjectAttributes: POBJECT_ATTRIBUTES,
+        DesiredAccess: ACCESS_MASK,
+        OpenOptions: ULONG,
+        IoStatus: PIO_STATUS_BLOCK,
+        Buffer: PFILE_NETWORK_OPEN_INFORMATION,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn IoPageRead(
+        FileObject: PFILE_OBJECT,
+        MemoryDescriptorList: PMDL,
+        StartingOffset: PLARGE_INTEGER,
+        Event: PKEVENT,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoGetAttachedDevice(DeviceObject: PDEVICE_OBJECT) -> PDEVICE_OBJECT;
+}
+unsafe extern "C" {
+    pub fn IoGetBaseFileSystemDeviceObject(FileObject: PFILE_OBJECT) -> PDEVICE_OBJECT;
+}
+unsafe extern "C" {
+    pub fn IoGetDeviceToVerify(Thread: PETHREAD) -> PDEVICE_OBJECT;
+}
+unsafe extern "C" {
+    pub fn IoGetRequestorProcessId(Irp: PIRP) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn IoGetRequestorProcess(Irp: PIRP) -> PEPROCESS;
+}
+unsafe extern "C" {
+    pub fn IoIsOperationSynchronous(Irp: PIRP) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn IoIsSystemThread(Thread: PETHREAD) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn IoIsValidNameGraftingBuffer(Irp: PIRP, ReparseBuffer: PREPARSE_DATA_BUFFER) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn IoQueryFileDosDeviceName(
+        FileObject: PFILE_OBJECT,
+        ObjectNameInformation: *mut POBJECT_NAME_INFORMATION,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoQueryFileInformation(
+        FileObject: PFILE_OBJECT,
+        FileInformationClass: FILE_INFORMATION_CLASS,
+        Length: ULONG,
+        FileInformation: PVOID,
+        ReturnedLength: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoQueryVolumeInformation(
+        FileObject: PFILE_OBJECT,
+        FsInformationClass: FS_INFORMATION_CLASS,
+        Length: ULONG,
+        FsInformation: PVOID,
+        ReturnedLength: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoQueueThreadIrp(Irp: PIRP);
+}
+unsafe extern "C" {
+    pub fn IoRegisterFileSystem(DeviceObject: PDEVICE_OBJECT);
+}
+unsafe extern "C" {
+    pub fn IoRegisterFsRegistrationChange(
+        DriverObject: PDRIVER_OBJECT,
+        DriverNotificationRoutine: PDRIVER_FS_NOTIFICATION,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoRegisterFsRegistrationChangeMountAware(
+        DriverObject: PDRIVER_OBJECT,
+        DriverNotificationRoutine: PDRIVER_FS_NOTIFICATION,
+        SynchronizeWithMounts: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoEnumerateRegisteredFiltersList(
+        DriverObjectList: *mut PDRIVER_OBJECT,
+        DriverObjectListSize: ULONG,
+        ActualNumberDriverObjects: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoReplaceFileObjectName(
+        FileObject: PFILE_OBJECT,
+        NewFileName: PWSTR,
+        FileNameLength: USHORT,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoReleaseVpbSpinLock(Irql: KIRQL);
+}
+unsafe extern "C" {
+    pub fn IoSetDeviceToVerify(Thread: PETHREAD, DeviceObject: PDEVICE_OBJECT);
+}
+unsafe extern "C" {
+    pub fn IoSetInformation(
+        FileObject: PFILE_OBJECT,
+        FileInformationClass: FILE_INFORMATION_CLASS,
+        Length: ULONG,
+        FileInformation: PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoSynchronousPageWrite(
+        FileObject: PFILE_OBJECT,
+        MemoryDescriptorList: PMDL,
+        StartingOffset: PLARGE_INTEGER,
+        Event: PKEVENT,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoThreadToProcess(Thread: PETHREAD) -> PEPROCESS;
+}
+unsafe extern "C" {
+    pub fn IoUnregisterFileSystem(DeviceObject: PDEVICE_OBJECT);
+}
+unsafe extern "C" {
+    pub fn IoUnregisterFsRegistrationChange(
+        DriverObject: PDRIVER_OBJECT,
+        DriverNotificationRoutine: PDRIVER_FS_NOTIFICATION,
+    );
+}
+unsafe extern "C" {
+    pub fn IoVerifyVolume(DeviceObject: PDEVICE_OBJECT, AllowRawMount: BOOLEAN) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoGetRequestorSessionId(Irp: PIRP, pSessionId: PULONG) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoEnumerateDeviceObjectList(
+        DriverObject: PDRIVER_OBJECT,
+        DeviceObjectList: *mut PDEVICE_OBJECT,
+        DeviceObjectListSize: ULONG,
+        ActualNumberDeviceObjects: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoGetLowerDeviceObject(DeviceObject: PDEVICE_OBJECT) -> PDEVICE_OBJECT;
+}
+unsafe extern "C" {
+    pub fn IoGetDeviceAttachmentBaseRef(DeviceObject: PDEVICE_OBJECT) -> PDEVICE_OBJECT;
+}
+unsafe extern "C" {
+    pub fn IoGetDiskDeviceObject(
+        FileSystemDeviceObject: PDEVICE_OBJECT,
+        DiskDeviceObject: *mut PDEVICE_OBJECT,
+    ) -> NTSTATUS;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_PRIORITY_INFO {
+    pub Size: ULONG,
+    pub ThreadPriority: ULONG,
+    pub PagePriority: ULONG,
+    pub IoPriority: IO_PRIORITY_HINT,
+}
+pub type IO_PRIORITY_INFO = _IO_PRIORITY_INFO;
+pub type PIO_PRIORITY_INFO = *mut _IO_PRIORITY_INFO;
+unsafe extern "C" {
+    pub fn IoRetrievePriorityInfo(
+        Irp: PIRP,
+        FileObject: PFILE_OBJECT,
+        Thread: PETHREAD,
+        PriorityInfo: PIO_PRIORITY_INFO,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoApplyPriorityInfoThread(
+        InputPriorityInfo: PIO_PRIORITY_INFO,
+        OutputPriorityInfo: PIO_PRIORITY_INFO,
+        Thread: PETHREAD,
+    ) -> NTSTATUS;
+}
+pub type PIO_IRP_EXT_PROCESS_TRACKED_OFFSET_CALLBACK = ::core::option::Option<
+    unsafe extern "C" fn(
+        SourceContext: *mut _IO_IRP_EXT_TRACK_OFFSET_HEADER,
+        TargetContext: *mut _IO_IRP_EXT_TRACK_OFFSET_HEADER,
+        RelativeOffset: LONGLONG,
+    ),
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_IRP_EXT_TRACK_OFFSET_HEADER {
+    pub Validation: USHORT,
+    pub Flags: USHORT,
+    pub TrackedOffsetCallback: PIO_IRP_EXT_PROCESS_TRACKED_OFFSET_CALLBACK,
+}
+pub type IO_IRP_EXT_TRACK_OFFSET_HEADER = _IO_IRP_EXT_TRACK_OFFSET_HEADER;
+pub type PIO_IRP_EXT_TRACK_OFFSET_HEADER = *mut _IO_IRP_EXT_TRACK_OFFSET_HEADER;
+unsafe extern "C" {
+    pub fn IoGetFsTrackOffsetState(
+        Irp: PIRP,
+        RetFsTrackOffsetBlob: *mut PIO_IRP_EXT_TRACK_OFFSET_HEADER,
+        RetTrackedOffset: *mut LONGLONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoSetFsTrackOffsetState(
+        Irp: PIRP,
+        FsTrackOffsetBlob: PIO_IRP_EXT_TRACK_OFFSET_HEADER,
+        TrackedOffset: LONGLONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoClearFsTrackOffsetState(Irp: PIRP) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoIrpHasFsTrackOffsetExtensionType(Irp: PIRP) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn PoQueueShutdownWorkItem(WorkItem: PWORK_QUEUE_ITEM) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn MmIsRecursiveIoFault() -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn MmForceSectionClosed(
+        SectionObjectPointer: PSECTION_OBJECT_POINTERS,
+        DelayClose: BOOLEAN,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn MmForceSectionClosedEx(
+        SectionObjectPointer: PSECTION_OBJECT_POINTERS,
+        ForceCloseFlags: ULONG,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn MmGetMaximumFileSectionSize() -> ULONGLONG;
+}
+pub const _MMFLUSH_TYPE_MmFlushForDelete: _MMFLUSH_TYPE = 0;
+pub const _MMFLUSH_TYPE_MmFlushForWrite: _MMFLUSH_TYPE = 1;
+pub type _MMFLUSH_TYPE = core::ffi::c_int;
+pub use self::_MMFLUSH_TYPE as MMFLUSH_TYPE;
+unsafe extern "C" {
+    pub fn MmFlushImageSection(
+        SectionObjectPointer: PSECTION_OBJECT_POINTERS,
+        FlushType: MMFLUSH_TYPE,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn MmCanFileBeTruncated(
+        SectionPointer: PSECTION_OBJECT_POINTERS,
+        NewFileSize: PLARGE_INTEGER,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn MmSetAddressRangeModified(Address: PVOID, Length: SIZE_T) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn MmIsFileSectionActive(
+        FsSectionPointer: PSECTION_OBJECT_POINTERS,
+        Flags: ULONG,
+        SectionIsActive: PLOGICAL,
+    ) -> NTSTATUS;
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _READ_LIST {
+    pub FileObject: PFILE_OBJECT,
+    pub NumberOfEntries: ULONG,
+    pub IsImage: LOGICAL,
+    pub List: [FILE_SEGMENT_ELEMENT; 1usize],
+}
+pub type READ_LIST = _READ_LIST;
+pub type PREAD_LIST = *mut _READ_LIST;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _MM_PREFETCH_FLAGS {
+    pub Flags: _MM_PREFETCH_FLAGS__bindgen_ty_1,
+    pub AllFlags: ULONG,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MM_PREFETCH_FLAGS__bindgen_ty_1 {
+    pub _bitfield_align_1: [u32; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
+}
+impl _MM_PREFETCH_FLAGS__bindgen_ty_1 {
+    #[inline]
+    pub fn Priority(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 3u8) as u32) }
+    }
+    #[inline]
+    pub fn set_Priority(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 3u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn Priority_raw(this: *const Self) -> ULONG {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                3u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_Priority_raw(this: *mut Self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                3u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn RepurposePriority(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(3usize, 3u8) as u32) }
+    }
+    #[inline]
+    pub fn set_RepurposePriority(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(3usize, 3u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn RepurposePriority_raw(this: *const Self) -> ULONG {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                3usize,
+                3u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_RepurposePriority_raw(this: *mut Self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                3usize,
+                3u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn PriorityProtection(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(6usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_PriorityProtection(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(6usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn PriorityProtection_raw(this: *const Self) -> ULONG {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                6usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_PriorityProtection_raw(this: *mut Self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                6usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn MustBeZero(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(7usize, 2u8) as u32) }
+    }
+    #[inline]
+    pub fn set_MustBeZero(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(7usize, 2u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn MustBeZero_raw(this: *const Self) -> ULONG {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                7usize,
+                2u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_MustBeZero_raw(this: *mut Self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                7usize,
+                2u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn CannotBeUsedAsFlags(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(9usize, 23u8) as u32) }
+    }
+    #[inline]
+    pub fn set_CannotBeUsedAsFlags(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(9usize, 23u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn CannotBeUsedAsFlags_raw(this: *const Self) -> ULONG {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                9usize,
+                23u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_CannotBeUsedAsFlags_raw(this: *mut Self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                9usize,
+                23u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        Priority: ULONG,
+        RepurposePriority: ULONG,
+        PriorityProtection: ULONG,
+        MustBeZero: ULONG,
+        CannotBeUsedAsFlags: ULONG,
+    ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 3u8, {
+            let Priority: u32 = unsafe { ::core::mem::transmute(Priority) };
+            Priority as u64
+        });
+        __bindgen_bitfield_unit.set(3usize, 3u8, {
+            let RepurposePriority: u32 = unsafe { ::core::mem::transmute(RepurposePriority) };
+            RepurposePriority as u64
+        });
+        __bindgen_bitfield_unit.set(6usize, 1u8, {
+            let PriorityProtection: u32 = unsafe { ::core::mem::transmute(PriorityProtection) };
+            PriorityProtection as u64
+        });
+        __bindgen_bitfield_unit.set(7usize, 2u8, {
+            let MustBeZero: u32 = unsafe { ::core::mem::transmute(MustBeZero) };
+            MustBeZero as u64
+        });
+        __bindgen_bitfield_unit.set(9usize, 23u8, {
+            let CannotBeUsedAsFlags: u32 = unsafe { ::core::mem::transmute(CannotBeUsedAsFlags) };
+            CannotBeUsedAsFlags as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+pub type MM_PREFETCH_FLAGS = _MM_PREFETCH_FLAGS;
+pub type PMM_PREFETCH_FLAGS = *mut _MM_PREFETCH_FLAGS;
+unsafe extern "C" {
+    pub fn MmPrefetchPages(NumberOfLists: ULONG, ReadLists: *mut PREAD_LIST) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn MmDoesFileHaveUserWritableReferences(SectionPointer: PSECTION_OBJECT_POINTERS) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn MmMdlPagesAreZero(Mdl: PMDL) -> LOGICAL;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _PHYSICAL_MEMORY_RUN {
+    pub BasePage: PFN_NUMBER,
+    pub PageCount: PFN_NUMBER,
+}
+pub type PHYSICAL_MEMORY_RUN = _PHYSICAL_MEMORY_RUN;
+pub type PPHYSICAL_MEMORY_RUN = *mut _PHYSICAL_MEMORY_RUN;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _PHYSICAL_MEMORY_DESCRIPTOR {
+    pub NumberOfRuns: ULONG,
+    pub NumberOfPages: PFN_NUMBER,
+    pub Run: [PHYSICAL_MEMORY_RUN; 1usize],
+}
+pub type PHYSICAL_MEMORY_DESCRIPTOR = _PHYSICAL_MEMORY_DESCRIPTOR;
+pub type PPHYSICAL_MEMORY_DESCRIPTOR = *mut _PHYSICAL_MEMORY_DESCRIPTOR;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _PHYSICAL_EXTENTS_DESCRIPTOR {
+    pub NumberOfRuns: ULONG,
+    pub NumberOfValidRuns: ULONG,
+    pub Run: [PHYSICAL_MEMORY_RUN; 1usize],
+}
+pub type PHYSICAL_EXTENTS_DESCRIPTOR = _PHYSICAL_EXTENTS_DESCRIPTOR;
+pub type PPHYSICAL_EXTENTS_DESCRIPTOR = *mut _PHYSICAL_EXTENTS_DESCRIPTOR;
+unsafe extern "C" {
+    pub fn ObInsertObject(
+        Object: PVOID,
+        PassedAccessState: PACCESS_STATE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectPointerBias: ULONG,
+        NewObject: *mut PVOID,
+        Handle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ObOpenObjectByPointer(
+        Object: PVOID,
+        HandleAttributes: ULONG,
+        PassedAccessState: PACCESS_STATE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectType: POBJECT_TYPE,
+        AccessMode: KPROCESSOR_MODE,
+        Handle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ObOpenObjectByPointerWithTag(
+        Object: PVOID,
+        HandleAttributes: ULONG,
+        PassedAccessState: PACCESS_STATE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectType: POBJECT_TYPE,
+        AccessMode: KPROCESSOR_MODE,
+        Tag: ULONG,
+        Handle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ObMakeTemporaryObject(Object: PVOID);
+}
+unsafe extern "C" {
+    pub fn ObQueryNameString(
+        Object: PVOID,
+        ObjectNameInfo: POBJECT_NAME_INFORMATION,
+        Length: ULONG,
+        ReturnLength: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ObIsKernelHandle(Handle: HANDLE) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn ObQueryObjectAuditingByHandle(Handle: HANDLE, GenerateOnClose: PBOOLEAN) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn IoRequestDeviceRemovalForReset(
+        PhysicalDeviceObject: PDEVICE_OBJECT,
+        Flags: ULONG,
+    ) -> NTSTATUS;
+}
+pub type LBN = ULONG;
+pub type PLBN = *mut LBN;
+pub type VBN = ULONG;
+pub type PVBN = *mut VBN;
+pub type OPLOCK = PVOID;
+pub type POPLOCK = *mut PVOID;
+pub const _FAST_IO_POSSIBLE_FastIoIsNotPossible: _FAST_IO_POSSIBLE = 0;
+pub const _FAST_IO_POSSIBLE_FastIoIsPossible: _FAST_IO_POSSIBLE = 1;
+pub const _FAST_IO_POSSIBLE_FastIoIsQuestionable: _FAST_IO_POSSIBLE = 2;
+pub type _FAST_IO_POSSIBLE = core::ffi::c_int;
+pub use self::_FAST_IO_POSSIBLE as FAST_IO_POSSIBLE;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FSRTL_COMMON_FCB_HEADER {
+    pub NodeTypeCode: CSHORT,
+    pub NodeByteSize: CSHORT,
+    pub Flags: UCHAR,
+    pub IsFastIoPossible: UCHAR,
+    pub Flags2: UCHAR,
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+    pub Resource: PERESOURCE,
+    pub PagingIoResource: PERESOURCE,
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileSize: LARGE_INTEGER,
+    pub ValidDataLength: LARGE_INTEGER,
+}
+impl _FSRTL_COMMON_FCB_HEADER {
+    #[inline]
+    pub fn Reserved(&self) -> UCHAR {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 4u8) as u8) }
+    }
+    #[inline]
+    pub fn set_Reserved(&mut self, val: UCHAR) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn Reserved_raw(this: *const Self) -> UCHAR {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                4u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_Reserved_raw(this: *mut Self, val: UCHAR) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                4u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn Version(&self) -> UCHAR {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 4u8) as u8) }
+    }
+    #[inline]
+    pub fn set_Version(&mut self, val: UCHAR) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(4usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn Version_raw(this: *const Self) -> UCHAR {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                4usize,
+                4u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_Version_raw(this: *mut Self, val: UCHAR) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                4usize,
+                4u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(Reserved: UCHAR, Version: UCHAR) -> __BindgenBitfieldUnit<[u8; 1usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 4u8, {
+            let Reserved: u8 = unsafe { ::core::mem::transmute(Reserved) };
+            Reserved as u64
+        });
+        __bindgen_bitfield_unit.set(4usize, 4u8, {
+            let Version: u8 = unsafe { ::core::mem::transmute(Version) };
+            Version as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+pub type FSRTL_COMMON_FCB_HEADER = _FSRTL_COMMON_FCB_HEADER;
+pub type PFSRTL_COMMON_FCB_HEADER = *mut FSRTL_COMMON_FCB_HEADER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FSRTL_ADVANCED_FCB_HEADER {
+    pub __bindgen_padding_0: [u64; 6usize],
+    pub FastMutex: PFAST_MUTEX,
+    pub FilterContexts: LIST_ENTRY,
+    pub PushLock: ULONG_PTR,
+    pub FileContextSupportPointer: *mut PVOID,
+    pub __bindgen_anon_1: _FSRTL_ADVANCED_FCB_HEADER__bindgen_ty_1,
+    pub AePushLock: PVOID,
+    pub BypassIoOpenCount: ULONG,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _FSRTL_ADVANCED_FCB_HEADER__bindgen_ty_1 {
+    pub Oplock: OPLOCK,
+    pub ReservedForRemote: PVOID,
+}
+pub type FSRTL_ADVANCED_FCB_HEADER = _FSRTL_ADVANCED_FCB_HEADER;
+pub type PFSRTL_ADVANCED_FCB_HEADER = *mut FSRTL_ADVANCED_FCB_HEADER;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _EOF_WAIT_BLOCK {
+    pub EofWaitLinks: LIST_ENTRY,
+    pub Event: KEVENT,
+}
+pub type EOF_WAIT_BLOCK = _EOF_WAIT_BLOCK;
+pub type PEOF_WAIT_BLOCK = *mut EOF_WAIT_BLOCK;
+unsafe extern "C" {
+    pub fn FsRtlCopyRead(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        Wait: BOOLEAN,
+        LockKey: ULONG,
+        Buffer: PVOID,
+        IoStatus: PIO_STATUS_BLOCK,
+        DeviceObject: PDEVICE_OBJECT,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlCopyWrite(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        Wait: BOOLEAN,
+        LockKey: ULONG,
+        Buffer: PVOID,
+        IoStatus: PIO_STATUS_BLOCK,
+        DeviceObject: PDEVICE_OBJECT,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlMdlReadEx(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        LockKey: ULONG,
+        MdlChain: *mut PMDL,
+        IoStatus: PIO_STATUS_BLOCK,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlMdlReadDev(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        LockKey: ULONG,
+        MdlChain: *mut PMDL,
+        IoStatus: PIO_STATUS_BLOCK,
+        DeviceObject: PDEVICE_OBJECT,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlMdlReadCompleteDev(
+        FileObject: PFILE_OBJECT,
+        MdlChain: PMDL,
+        DeviceObject: PDEVICE_OBJECT,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlPrepareMdlWriteEx(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        LockKey: ULONG,
+        MdlChain: *mut PMDL,
+        IoStatus: PIO_STATUS_BLOCK,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlPrepareMdlWriteDev(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        LockKey: ULONG,
+        MdlChain: *mut PMDL,
+        IoStatus: PIO_STATUS_BLOCK,
+        DeviceObject: PDEVICE_OBJECT,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlMdlWriteCompleteDev(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        MdlChain: PMDL,
+        DeviceObject: PDEVICE_OBJECT,
+    ) -> BOOLEAN;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSRTL_AUXILIARY_BUFFER {
+    pub Buffer: PVOID,
+    pub Length: ULONG,
+    pub Flags: ULONG,
+    pub Mdl: PMDL,
+}
+pub type FSRTL_AUXILIARY_BUFFER = _FSRTL_AUXILIARY_BUFFER;
+pub type PFSRTL_AUXILIARY_BUFFER = *mut FSRTL_AUXILIARY_BUFFER;
+unsafe extern "C" {
+    pub fn FsRtlAcquireFileExclusive(FileObject: PFILE_OBJECT);
+}
+unsafe extern "C" {
+    pub fn FsRtlReleaseFile(FileObject: PFILE_OBJECT);
+}
+unsafe extern "C" {
+    pub fn FsRtlGetFileSize(FileObject: PFILE_OBJECT, FileSize: PLARGE_INTEGER) -> NTSTATUS;
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_LOCK_INFO {
+    pub StartingByte: LARGE_INTEGER,
+    pub Length: LARGE_INTEGER,
+    pub ExclusiveLock: BOOLEAN,
+    pub Key: ULONG,
+    pub FileObject: PFILE_OBJECT,
+    pub ProcessId: PVOID,
+    pub EndingByte: LARGE_INTEGER,
+}
+pub type FILE_LOCK_INFO = _FILE_LOCK_INFO;
+pub type PFILE_LOCK_INFO = *mut FILE_LOCK_INFO;
+pub type PCOMPLETE_LOCK_IRP_ROUTINE =
+::core::option::Option<unsafe extern "C" fn(Context: PVOID, Irp: PIRP) -> NTSTATUS>;
+pub type PUNLOCK_ROUTINE =
+::core::option::Option<unsafe extern "C" fn(Context: PVOID, FileLockInfo: PFILE_LOCK_INFO)>;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_LOCK {
+    pub CompleteLockIrpRoutine: PCOMPLETE_LOCK_IRP_ROUTINE,
+    pub UnlockRoutine: PUNLOCK_ROUTINE,
+    pub FastIoIsQuestionable: BOOLEAN,
+    pub SpareC: [BOOLEAN; 3usize],
+    pub LockInformation: PVOID,
+    pub LastReturnedLockInfo: FILE_LOCK_INFO,
+    pub LastReturnedLock: PVOID,
+    pub LockRequestsInProgress: LONG,
+}
+pub type FILE_LOCK = _FILE_LOCK;
+pub type PFILE_LOCK = *mut FILE_LOCK;
+unsafe extern "C" {
+    pub fn FsRtlAllocateFileLock(
+        CompleteLockIrpRoutine: PCOMPLETE_LOCK_IRP_ROUTINE,
+        UnlockRoutine: PUNLOCK_ROUTINE,
+    ) -> PFILE_LOCK;
+}
+unsafe extern "C" {
+    pub fn FsRtlFreeFileLock(FileLock: PFILE_LOCK);
+}
+unsafe extern "C" {
+    pub fn FsRtlInitializeFileLock(
+        FileLock: PFILE_LOCK,
+        CompleteLockIrpRoutine: PCOMPLETE_LOCK_IRP_ROUTINE,
+        UnlockRoutine: PUNLOCK_ROUTINE,
+    );
+}
+unsafe extern "C" {
+    pub fn FsRtlUninitializeFileLock(FileLock: PFILE_LOCK);
+}
+unsafe extern "C" {
+    pub fn FsRtlProcessFileLock(FileLock: PFILE_LOCK, Irp: PIRP, Context: PVOID) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlCheckLockForReadAccess(FileLock: PFILE_LOCK, Irp: PIRP) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlCheckLockForWriteAccess(FileLock: PFILE_LOCK, Irp: PIRP) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlCheckLockForOplockRequest(
+        FileLock: PFILE_LOCK,
+        AllocationSize: PLARGE_INTEGER,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlFastCheckLockForRead(
+        FileLock: PFILE_LOCK,
+        StartingByte: PLARGE_INTEGER,
+        Length: PLARGE_INTEGER,
+        Key: ULONG,
+        FileObject: PFILE_OBJECT,
+        ProcessId: PVOID,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlFastCheckLockForWrite(
+        FileLock: PFILE_LOCK,
+        StartingByte: PLARGE_INTEGER,
+        Length: PLARGE_INTEGER,
+        Key: ULONG,
+        FileObject: PVOID,
+        ProcessId: PVOID,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlGetNextFileLock(FileLock: PFILE_LOCK, Restart: BOOLEAN) -> PFILE_LOCK_INFO;
+}
+unsafe extern "C" {
+    pub fn FsRtlAreThereCurrentOrInProgressFileLocks(FileLock: PFILE_LOCK) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlAreThereWaitingFileLocks(FileLock: PFILE_LOCK) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlFastUnlockSingle(
+        FileLock: PFILE_LOCK,
+        FileObject: PFILE_OBJECT,
+        FileOffset: *mut LARGE_INTEGER,
+        Length: PLARGE_INTEGER,
+        ProcessId: PEPROCESS,
+        Key: ULONG,
+        Context: PVOID,
+        AlreadySynchronized: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlFastUnlockAll(
+        FileLock: PFILE_LOCK,
+        FileObject: PFILE_OBJECT,
+        ProcessId: PEPROCESS,
+        Context: PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlFastUnlockAllByKey(
+        FileLock: PFILE_LOCK,
+        FileObject: PFILE_OBJECT,
+        ProcessId: PEPROCESS,
+        Key: ULONG,
+        Context: PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlPrivateLock(
+        FileLock: PFILE_LOCK,
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: PLARGE_INTEGER,
+        ProcessId: PEPROCESS,
+        Key: ULONG,
+        FailImmediately: BOOLEAN,
+        ExclusiveLock: BOOLEAN,
+        Iosb: PIO_STATUS_BLOCK,
+        Irp: PIRP,
+        Context: PVOID,
+        AlreadySynchronized: BOOLEAN,
+    ) -> BOOLEAN;
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct TUNNEL {
+    pub Mutex: FAST_MUTEX,
+    pub Cache: PRTL_SPLAY_LINKS,
+    pub TimerQueue: LIST_ENTRY,
+    pub NumEntries: USHORT,
+}
+pub type PTUNNEL = *mut TUNNEL;
+unsafe extern "C" {
+    pub fn FsRtlInitializeTunnelCache(Cache: *mut TUNNEL);
+}
+unsafe extern "C" {
+    pub fn FsRtlAddToTunnelCache(
+        Cache: *mut TUNNEL,
+        DirectoryKey: ULONGLONG,
+        ShortName: *mut UNICODE_STRING,
+        LongName: *mut UNICODE_STRING,
+        KeyByShortName: BOOLEAN,
+        DataLength: ULONG,
+        Data: *mut core::ffi::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn FsRtlFindInTunnelCache(
+        Cache: *mut TUNNEL,
+        DirectoryKey: ULONGLONG,
+        Name: *mut UNICODE_STRING,
+        ShortName: *mut UNICODE_STRING,
+        LongName: *mut UNICODE_STRING,
+        DataLength: *mut ULONG,
+        Data: *mut core::ffi::c_void,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlAddToTunnelCacheEx(
+        Cache: *mut TUNNEL,
+        DirectoryKey: ULONGLONG,
+        ShortName: *mut UNICODE_STRING,
+        LongName: *mut UNICODE_STRING,
+        Flags: ULONG,
+        DataLength: ULONG,
+        Data: *mut core::ffi::c_void,
+    );
+}
+unsafe extern "C" {
+    pub fn FsRtlFindInTunnelCacheEx(
+        Cache: *mut TUNNEL,
+        DirectoryKey: ULONGLONG,
+        Name: *mut UNICODE_STRING,
+        ShortName: *mut UNICODE_STRING,
+        LongName: *mut UNICODE_STRING,
+        Flags: ULONG,
+        DataLength: *mut ULONG,
+        Data: *mut core::ffi::c_void,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlDeleteKeyFromTunnelCache(Cache: *mut TUNNEL, DirectoryKey: ULONGLONG);
+}
+unsafe extern "C" {
+    pub fn FsRtlDeleteTunnelCache(Cache: *mut TUNNEL);
+}
+pub const _FSRTL_COMPARISON_RESULT_LessThan: _FSRTL_COMPARISON_RESULT = -1;
+pub const _FSRTL_COMPARISON_RESULT_EqualTo: _FSRTL_COMPARISON_RESULT = 0;
+pub const _FSRTL_COMPARISON_RESULT_GreaterThan: _FSRTL_COMPARISON_RESULT = 1;
+pub type _FSRTL_COMPARISON_RESULT = core::ffi::c_int;
+pub use self::_FSRTL_COMPARISON_RESULT as FSRTL_COMPARISON_RESULT;
+unsafe extern "C" {
+    pub static mut FsRtlLegalAnsiCharacterArray: *const *const UCHAR;
+}
+unsafe extern "C" {
+    pub static mut NlsOemLeadByteInfo: *mut PUSHORT;
+}
+unsafe extern "C" {
+    pub fn FsRtlDissectDbcs(
+        Path: ANSI_STRING,
+        FirstName: PANSI_STRING,
+        RemainingName: PANSI_STRING,
+    );
+}
+unsafe extern "C" {
+    pub fn FsRtlDoesDbcsContainWildCards(Name: PANSI_STRING) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlIsDbcsInExpression(Expression: PANSI_STRING, Name: PANSI_STRING) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlIsFatDbcsLegal(
+        DbcsName: ANSI_STRING,
+        WildCardsPermissible: BOOLEAN,
+        PathNamePermissible: BOOLEAN,
+        LeadingBackslashPermissible: BOOLEAN,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlIsHpfsDbcsLegal(
+        DbcsName: ANSI_STRING,
+        WildCardsPermissible: BOOLEAN,
+        PathNamePermissible: BOOLEAN,
+        LeadingBackslashPermissible: BOOLEAN,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlNormalizeNtstatus(Exception: NTSTATUS, GenericException: NTSTATUS) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlIsNtstatusExpected(Exception: NTSTATUS) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlAllocateResource() -> PERESOURCE;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _BASE_MCB {
+    pub MaximumPairCount: ULONG,
+    pub PairCount: ULONG,
+    pub PoolType: USHORT,
+    pub Flags: USHORT,
+    pub Mapping: PVOID,
+}
+pub type BASE_MCB = _BASE_MCB;
+pub type PBASE_MCB = *mut _BASE_MCB;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _LARGE_MCB {
+    pub GuardedMutex: PKGUARDED_MUTEX,
+    pub BaseMcb: BASE_MCB,
+}
+pub type LARGE_MCB = _LARGE_MCB;
+pub type PLARGE_MCB = *mut _LARGE_MCB;
+unsafe extern "C" {
+    pub fn FsRtlInitializeLargeMcb(Mcb: PLARGE_MCB, PoolType: POOL_TYPE);
+}
+unsafe extern "C" {
+    pub fn FsRtlUninitializeLargeMcb(Mcb: PLARGE_MCB);
+}
+unsafe extern "C" {
+    pub fn FsRtlResetLargeMcb(Mcb: PLARGE_MCB, SelfSynchronized: BOOLEAN);
+}
+unsafe extern "C" {
+    pub fn FsRtlTruncateLargeMcb(Mcb: PLARGE_MCB, Vbn: LONGLONG);
+}
+unsafe extern "C" {
+    pub fn FsRtlAddLargeMcbEntry(
+        Mcb: PLARGE_MCB,
+        Vbn: LONGLONG,
+        Lbn: LONGLONG,
+        SectorCount: LONGLONG,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlRemoveLargeMcbEntry(Mcb: PLARGE_MCB, Vbn: LONGLONG, SectorCount: LONGLONG);
+}
+unsafe extern "C" {
+    pub fn FsRtlLookupLargeMcbEntry(
+        Mcb: PLARGE_MCB,
+        Vbn: LONGLONG,
+        Lbn: PLONGLONG,
+        SectorCountFromLbn: PLONGLONG,
+        StartingLbn: PLONGLONG,
+        SectorCountFromStartingLbn: PLONGLONG,
+        Index: PULONG,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlLookupLastLargeMcbEntry(Mcb: PLARGE_MCB, Vbn: PLONGLONG, Lbn: PLONGLONG)
+                                        -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlLookupLastLargeMcbEntryAndIndex(
+        OpaqueMcb: PLARGE_MCB,
+        LargeVbn: PLONGLONG,
+        LargeLbn: PLONGLONG,
+        Index: PULONG,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlNumberOfRunsInLargeMcb(Mcb: PLARGE_MCB) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn FsRtlGetNextLargeMcbEntry(
+        Mcb: PLARGE_MCB,
+        RunIndex: ULONG,
+        Vbn: PLONGLONG,
+        Lbn: PLONGLONG,
+        SectorCount: PLONGLONG,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlSplitLargeMcb(Mcb: PLARGE_MCB, Vbn: LONGLONG, Amount: LONGLONG) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlInitializeBaseMcb(Mcb: PBASE_MCB, PoolType: POOL_TYPE);
+}
+unsafe extern "C" {
+    pub fn FsRtlInitializeBaseMcbEx(Mcb: PBASE_MCB, PoolType: POOL_TYPE, Flags: USHORT) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlUninitializeBaseMcb(Mcb: PBASE_MCB);
+}
+unsafe extern "C" {
+    pub fn FsRtlResetBaseMcb(Mcb: PBASE_MCB);
+}
+unsafe extern "C" {
+    pub fn FsRtlTruncateBaseMcb(Mcb: PBASE_MCB, Vbn: LONGLONG);
+}
+unsafe extern "C" {
+    pub fn FsRtlAddBaseMcbEntry(
+        Mcb: PBASE_MCB,
+        Vbn: LONGLONG,
+        Lbn: LONGLONG,
+        SectorCount: LONGLONG,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlAddBaseMcbEntryEx(
+        Mcb: PBASE_MCB,
+        Vbn: LONGLONG,
+        Lbn: LONGLONG,
+        SectorCount: LONGLONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlRemoveBaseMcbEntry(Mcb: PBASE_MCB, Vbn: LONGLONG, SectorCount: LONGLONG)
+                                   -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlLookupBaseMcbEntry(
+        Mcb: PBASE_MCB,
+        Vbn: LONGLONG,
+        Lbn: PLONGLONG,
+        SectorCountFromLbn: PLONGLONG,
+        StartingLbn: PLONGLONG,
+        SectorCountFromStartingLbn: PLONGLONG,
+        Index: PULONG,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlLookupLastBaseMcbEntry(Mcb: PBASE_MCB, Vbn: PLONGLONG, Lbn: PLONGLONG) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlLookupLastBaseMcbEntryAndIndex(
+        OpaqueMcb: PBASE_MCB,
+        LargeVbn: PLONGLONG,
+        LargeLbn: PLONGLONG,
+        Index: PULONG,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlNumberOfRunsInBaseMcb(Mcb: PBASE_MCB) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn FsRtlGetNextBaseMcbEntry(
+        Mcb: PBASE_MCB,
+        RunIndex: ULONG,
+        Vbn: PLONGLONG,
+        Lbn: PLONGLONG,
+        SectorCount: PLONGLONG,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlSplitBaseMcb(Mcb: PBASE_MCB, Vbn: LONGLONG, Amount: LONGLONG) -> BOOLEAN;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _MCB {
+    pub DummyFieldThatSizesThisStructureCorrectly: LARGE_MCB,
+}
+pub type MCB = _MCB;
+pub type PMCB = *mut MCB;
+unsafe extern "C" {
+    pub fn FsRtlInitializeMcb(Mcb: PMCB, PoolType: POOL_TYPE);
+}
+unsafe extern "C" {
+    pub fn FsRtlUninitializeMcb(Mcb: PMCB);
+}
+unsafe extern "C" {
+    pub fn FsRtlTruncateMcb(Mcb: PMCB, Vbn: VBN);
+}
+unsafe extern "C" {
+    pub fn FsRtlAddMcbEntry(Mcb: PMCB, Vbn: VBN, Lbn: LBN, SectorCount: ULONG) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlRemoveMcbEntry(Mcb: PMCB, Vbn: VBN, SectorCount: ULONG);
+}
+unsafe extern "C" {
+    pub fn FsRtlLookupMcbEntry(
+        Mcb: PMCB,
+        Vbn: VBN,
+        Lbn: PLBN,
+        SectorCount: PULONG,
+        Index: PULONG,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlLookupLastMcbEntry(Mcb: PMCB, Vbn: PVBN, Lbn: PLBN) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlNumberOfRunsInMcb(Mcb: PMCB) -> ULONG;
+}
+unsafe extern "C" {
+    pub fn FsRtlGetNextMcbEntry(
+        Mcb: PMCB,
+        RunIndex: ULONG,
+        Vbn: PVBN,
+        Lbn: PLBN,
+        SectorCount: PULONG,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlBalanceReads(TargetDevice: PDEVICE_OBJECT) -> NTSTATUS;
+}
+pub type POPLOCK_WAIT_COMPLETE_ROUTINE =
+::core::option::Option<unsafe extern "C" fn(Context: PVOID, Irp: PIRP)>;
+pub type POPLOCK_FS_PREPOST_IRP =
+::core::option::Option<unsafe extern "C" fn(Context: PVOID, Irp: PIRP)>;
+unsafe extern "C" {
+    pub fn FsRtlInitializeOplock(Oplock: POPLOCK);
+}
+unsafe extern "C" {
+    pub fn FsRtlUninitializeOplock(Oplock: POPLOCK);
+}
+unsafe extern "C" {
+    pub fn FsRtlOplockFsctrl(Oplock: POPLOCK, Irp: PIRP, OpenCount: ULONG) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlCheckOplock(
+        Oplock: POPLOCK,
+        Irp: PIRP,
+        Context: PVOID,
+        CompletionRoutine: POPLOCK_WAIT_COMPLETE_ROUTINE,
+        PostIrpRoutine: POPLOCK_FS_PREPOST_IRP,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlCheckOplockEx(
+        Oplock: POPLOCK,
+        Irp: PIRP,
+        Flags: ULONG,
+        Context: PVOID,
+        CompletionRoutine: POPLOCK_WAIT_COMPLETE_ROUTINE,
+        PostIrpRoutine: POPLOCK_FS_PREPOST_IRP,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlCheckUpperOplock(
+        Oplock: POPLOCK,
+        NewLowerOplockState: ULONG,
+        CompletionRoutineContext: PVOID,
+        CompletionRoutine: POPLOCK_WAIT_COMPLETE_ROUTINE,
+        PrePendRoutine: POPLOCK_FS_PREPOST_IRP,
+        Flags: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlUpperOplockFsctrl(
+        Oplock: POPLOCK,
+        Irp: PIRP,
+        OpenCount: ULONG,
+        LowerOplockState: ULONG,
+        Flags: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlOplockIsFastIoPossible(Oplock: POPLOCK) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlCurrentBatchOplock(Oplock: POPLOCK) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlCurrentOplock(Oplock: POPLOCK) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlOplockBreakToNone(
+        Oplock: POPLOCK,
+        IrpSp: PIO_STACK_LOCATION,
+        Irp: PIRP,
+        Context: PVOID,
+        CompletionRoutine: POPLOCK_WAIT_COMPLETE_ROUTINE,
+        PostIrpRoutine: POPLOCK_FS_PREPOST_IRP,
+    ) -> NTSTATUS;
+}
+pub type OPLOCK_KEY_ECP_CONTEXT = _OPLOCK_KEY_ECP_CONTEXT;
+unsafe extern "C" {
+    pub static GUID_ECP_OPLOCK_KEY: GUID;
+}
+unsafe extern "C" {
+    pub fn FsRtlOplockIsSharedRequest(Irp: PIRP) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlOplockBreakH(
+        Oplock: POPLOCK,
+        Irp: PIRP,
+        Flags: ULONG,
+        Context: PVOID,
+        CompletionRoutine: POPLOCK_WAIT_COMPLETE_ROUTINE,
+        PostIrpRoutine: POPLOCK_FS_PREPOST_IRP,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlOplockBreakH2(
+        Oplock: POPLOCK,
+        Irp: PIRP,
+        Flags: ULONG,
+        Context: PVOID,
+        CompletionRoutine: POPLOCK_WAIT_COMPLETE_ROUTINE,
+        PostIrpRoutine: POPLOCK_FS_PREPOST_IRP,
+        GrantedAccess: PACCESS_MASK,
+        ShareAccess: PUSHORT,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlCurrentOplockH(Oplock: POPLOCK) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlOplockBreakToNoneEx(
+        Oplock: POPLOCK,
+        Irp: PIRP,
+        Flags: ULONG,
+        Context: PVOID,
+        CompletionRoutine: POPLOCK_WAIT_COMPLETE_ROUTINE,
+        PostIrpRoutine: POPLOCK_FS_PREPOST_IRP,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlOplockFsctrlEx(
+        Oplock: POPLOCK,
+        Irp: PIRP,
+        OpenCount: ULONG,
+        Flags: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlOplockKeysEqual(Fo1: PFILE_OBJECT, Fo2: PFILE_OBJECT) -> BOOLEAN;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _DUAL_OPLOCK_KEY_ECP_CONTEXT {
+    pub ParentOplockKey: GUID,
+    pub TargetOplockKey: GUID,
+    pub ParentOplockKeySet: BOOLEAN,
+    pub TargetOplockKeySet: BOOLEAN,
+}
+pub type DUAL_OPLOCK_KEY_ECP_CONTEXT = _DUAL_OPLOCK_KEY_ECP_CONTEXT;
+pub type PDUAL_OPLOCK_KEY_ECP_CONTEXT = *mut _DUAL_OPLOCK_KEY_ECP_CONTEXT;
+unsafe extern "C" {
+    pub static GUID_ECP_DUAL_OPLOCK_KEY: GUID;
+}
+unsafe extern "C" {
+    pub fn FsRtlNotifyVolumeEvent(FileObject: PFILE_OBJECT, EventCode: ULONG) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlNotifyVolumeEventEx(
+        FileObject: PFILE_OBJECT,
+        EventCode: ULONG,
+        Event: PTARGET_DEVICE_CUSTOM_NOTIFICATION,
+    ) -> NTSTATUS;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _REAL_NOTIFY_SYNC {
+    _unused: [u8; 0],
+}
+pub type PNOTIFY_SYNC = *mut _REAL_NOTIFY_SYNC;
+pub type PCHECK_FOR_TRAVERSE_ACCESS = ::core::option::Option<
+    unsafe extern "C" fn(
+        NotifyContext: PVOID,
+        TargetContext: PVOID,
+        SubjectContext: PSECURITY_SUBJECT_CONTEXT,
+    ) -> BOOLEAN,
+>;
+pub type PFILTER_REPORT_CHANGE = ::core::option::Option<
+    unsafe extern "C" fn(NotifyContext: PVOID, FilterContext: PVOID) -> BOOLEAN,
+>;
+unsafe extern "C" {
+    pub fn FsRtlNotifyInitializeSync(NotifySync: *mut PNOTIFY_SYNC);
+}
+unsafe extern "C" {
+    pub fn FsRtlNotifyUninitializeSync(NotifySync: *mut PNOTIFY_SYNC);
+}
+unsafe extern "C" {
+    pub fn FsRtlNotifyFullChangeDirectory(
+        NotifySync: PNOTIFY_SYNC,
+        NotifyList: PLIST_ENTRY,
+        FsContext: PVOID,
+        FullDirectoryName: PSTRING,
+        WatchTree: BOOLEAN,
+        IgnoreBuffer: BOOLEAN,
+        CompletionFilter: ULONG,
+        NotifyIrp: PIRP,
+        TraverseCallback: PCHECK_FOR_TRAVERSE_ACCESS,
+        SubjectContext: PSECURITY_SUBJECT_CONTEXT,
+    );
+}
+unsafe extern "C" {
+    pub fn FsRtlNotifyFilterChangeDirectory(
+        NotifySync: PNOTIFY_SYNC,
+        NotifyList: PLIST_ENTRY,
+        FsContext: PVOID,
+        FullDirectoryName: PSTRING,
+        WatchTree: BOOLEAN,
+        IgnoreBuffer: BOOLEAN,
+        CompletionFilter: ULONG,
+        NotifyIrp: PIRP,
+        TraverseCallback: PCHECK_FOR_TRAVERSE_ACCESS,
+        SubjectContext: PSECURITY_SUBJECT_CONTEXT,
+        FilterCallback: PFILTER_REPORT_CHANGE,
+    );
+}
+unsafe extern "C" {
+    pub fn FsRtlNotifyFilterReportChange(
+        NotifySync: PNOTIFY_SYNC,
+        NotifyList: PLIST_ENTRY,
+        FullTargetName: PSTRING,
+        TargetNameOffset: USHORT,
+        StreamName: PSTRING,
+        NormalizedParentName: PSTRING,
+        FilterMatch: ULONG,
+        Action: ULONG,
+        TargetContext: PVOID,
+        FilterContext: PVOID,
+    );
+}
+unsafe extern "C" {
+    pub fn FsRtlNotifyFullReportChange(
+        NotifySync: PNOTIFY_SYNC,
+        NotifyList: PLIST_ENTRY,
+        FullTargetName: PSTRING,
+        TargetNameOffset: USHORT,
+        StreamName: PSTRING,
+        NormalizedParentName: PSTRING,
+        FilterMatch: ULONG,
+        Action: ULONG,
+        TargetContext: PVOID,
+    );
+}
+unsafe extern "C" {
+    pub fn FsRtlNotifyCleanup(NotifySync: PNOTIFY_SYNC, NotifyList: PLIST_ENTRY, FsContext: PVOID);
+}
+unsafe extern "C" {
+    pub fn FsRtlNotifyCleanupAll(NotifySync: PNOTIFY_SYNC, NotifyList: PLIST_ENTRY);
+}
+unsafe extern "C" {
+    pub fn FsRtlDissectName(
+        Path: UNICODE_STRING,
+        FirstName: PUNICODE_STRING,
+        RemainingName: PUNICODE_STRING,
+    );
+}
+unsafe extern "C" {
+    pub fn FsRtlDoesNameContainWildCards(Name: PUNICODE_STRING) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlAreNamesEqual(
+        ConstantNameA: PCUNICODE_STRING,
+        ConstantNameB: PCUNICODE_STRING,
+        IgnoreCase: BOOLEAN,
+        UpcaseTable: PCWCH,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlIsNameInExpression(
+        Expression: PUNICODE_STRING,
+        Name: PUNICODE_STRING,
+        IgnoreCase: BOOLEAN,
+        UpcaseTable: PWCH,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlIsNameInUnUpcasedExpression(
+        Expression: PUNICODE_STRING,
+        Name: PUNICODE_STRING,
+        IgnoreCase: BOOLEAN,
+        UpcaseTable: PWCH,
+    ) -> BOOLEAN;
+}
+pub type PFSRTL_STACK_OVERFLOW_ROUTINE =
+::core::option::Option<unsafe extern "C" fn(Context: PVOID, Event: PKEVENT)>;
+unsafe extern "C" {
+    pub fn FsRtlPostStackOverflow(
+        Context: PVOID,
+        Event: PKEVENT,
+        StackOverflowRoutine: PFSRTL_STACK_OVERFLOW_ROUTINE,
+    );
+}
+unsafe extern "C" {
+    pub fn FsRtlPostPagingFileStackOverflow(
+        Context: PVOID,
+        Event: PKEVENT,
+        StackOverflowRoutine: PFSRTL_STACK_OVERFLOW_ROUTINE,
+    );
+}
+unsafe extern "C" {
+    pub fn FsRtlRegisterUncProvider(
+        MupHandle: PHANDLE,
+        RedirectorDeviceName: PCUNICODE_STRING,
+        MailslotsSupported: BOOLEAN,
+    ) -> NTSTATUS;
+}
+pub type FSRTL_UNC_PROVIDER_FLAGS = ULONG;
+unsafe extern "C" {
+    pub fn FsRtlRegisterUncProviderEx(
+        MupHandle: PHANDLE,
+        RedirDevName: PCUNICODE_STRING,
+        DeviceObject: PDEVICE_OBJECT,
+        Flags: FSRTL_UNC_PROVIDER_FLAGS,
+    ) -> NTSTATUS;
+}
+pub type FSRTL_UNC_HARDENING_CAPABILITIES = ULONG;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FSRTL_UNC_PROVIDER_REGISTRATION {
+    pub Size: USHORT,
+    pub Version: USHORT,
+    pub __bindgen_anon_1: _FSRTL_UNC_PROVIDER_REGISTRATION__bindgen_ty_1,
+    pub __bindgen_anon_2: _FSRTL_UNC_PROVIDER_REGISTRATION__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _FSRTL_UNC_PROVIDER_REGISTRATION__bindgen_ty_1 {
+    pub ProviderFlags: FSRTL_UNC_PROVIDER_FLAGS,
+    pub __bindgen_anon_1: _FSRTL_UNC_PROVIDER_REGISTRATION__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C)]
+#[repr(align(4))]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSRTL_UNC_PROVIDER_REGISTRATION__bindgen_ty_1__bindgen_ty_1 {
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+    pub __bindgen_padding_0: [u8; 3usize],
+}
+impl _FSRTL_UNC_PROVIDER_REGISTRATION__bindgen_ty_1__bindgen_ty_1 {
+    #[inline]
+    pub fn MailslotsSupported(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_MailslotsSupported(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn MailslotsSupported_raw(this: *const Self) -> ULONG {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_MailslotsSupported_raw(this: *mut Self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn CscEnabled(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_CscEnabled(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(1usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn CscEnabled_raw(this: *const Self) -> ULONG {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                1usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_CscEnabled_raw(this: *mut Self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                1usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn DomainSvcAware(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_DomainSvcAware(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(2usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn DomainSvcAware_raw(this: *const Self) -> ULONG {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                2usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_DomainSvcAware_raw(this: *mut Self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                2usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn ContainersAware(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_ContainersAware(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(3usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn ContainersAware_raw(this: *const Self) -> ULONG {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                3usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_ContainersAware_raw(this: *mut Self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                3usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        MailslotsSupported: ULONG,
+        CscEnabled: ULONG,
+        DomainSvcAware: ULONG,
+        ContainersAware: ULONG,
+    ) -> __BindgenBitfieldUnit<[u8; 1usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 1u8, {
+            let MailslotsSupported: u32 = unsafe { ::core::mem::transmute(MailslotsSupported) };
+            MailslotsSupported as u64
+        });
+        __bindgen_bitfield_unit.set(1usize, 1u8, {
+            let CscEnabled: u32 = unsafe { ::core::mem::transmute(CscEnabled) };
+            CscEnabled as u64
+        });
+        __bindgen_bitfield_unit.set(2usize, 1u8, {
+            let DomainSvcAware: u32 = unsafe { ::core::mem::transmute(DomainSvcAware) };
+            DomainSvcAware as u64
+        });
+        __bindgen_bitfield_unit.set(3usize, 1u8, {
+            let ContainersAware: u32 = unsafe { ::core::mem::transmute(ContainersAware) };
+            ContainersAware as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union _FSRTL_UNC_PROVIDER_REGISTRATION__bindgen_ty_2 {
+    pub HardeningCapabilities: FSRTL_UNC_HARDENING_CAPABILITIES,
+    pub __bindgen_anon_1: _FSRTL_UNC_PROVIDER_REGISTRATION__bindgen_ty_2__bindgen_ty_1,
+}
+#[repr(C)]
+#[repr(align(4))]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSRTL_UNC_PROVIDER_REGISTRATION__bindgen_ty_2__bindgen_ty_1 {
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+    pub __bindgen_padding_0: [u8; 3usize],
+}
+impl _FSRTL_UNC_PROVIDER_REGISTRATION__bindgen_ty_2__bindgen_ty_1 {
+    #[inline]
+    pub fn SupportsMutualAuth(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_SupportsMutualAuth(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn SupportsMutualAuth_raw(this: *const Self) -> ULONG {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_SupportsMutualAuth_raw(this: *mut Self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn SupportsIntegrity(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_SupportsIntegrity(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(1usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn SupportsIntegrity_raw(this: *const Self) -> ULONG {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                1usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_SupportsIntegrity_raw(this: *mut Self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                1usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn SupportsPrivacy(&self) -> ULONG {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_SupportsPrivacy(&mut self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(2usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn SupportsPrivacy_raw(this: *const Self) -> ULONG {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                2usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_SupportsPrivacy_raw(this: *mut Self, val: ULONG) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                2usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        SupportsMutualAuth: ULONG,
+        SupportsIntegrity: ULONG,
+        SupportsPrivacy: ULONG,
+    ) -> __BindgenBitfieldUnit<[u8; 1usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 1u8, {
+            let SupportsMutualAuth: u32 = unsafe { ::core::mem::transmute(SupportsMutualAuth) };
+            SupportsMutualAuth as u64
+        });
+        __bindgen_bitfield_unit.set(1usize, 1u8, {
+            let SupportsIntegrity: u32 = unsafe { ::core::mem::transmute(SupportsIntegrity) };
+            SupportsIntegrity as u64
+        });
+        __bindgen_bitfield_unit.set(2usize, 1u8, {
+            let SupportsPrivacy: u32 = unsafe { ::core::mem::transmute(SupportsPrivacy) };
+            SupportsPrivacy as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+pub type FSRTL_UNC_PROVIDER_REGISTRATION = _FSRTL_UNC_PROVIDER_REGISTRATION;
+pub type PFSRTL_UNC_PROVIDER_REGISTRATION = *mut _FSRTL_UNC_PROVIDER_REGISTRATION;
+unsafe extern "C" {
+    pub fn FsRtlRegisterUncProviderEx2(
+        RedirDevName: PCUNICODE_STRING,
+        DeviceObject: PDEVICE_OBJECT,
+        Registration: *const FSRTL_UNC_PROVIDER_REGISTRATION,
+        MupHandle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlDeregisterUncProvider(Handle: HANDLE);
+}
+unsafe extern "C" {
+    pub fn FsRtlCancellableWaitForSingleObject(
+        Object: PVOID,
+        Timeout: PLARGE_INTEGER,
+        Irp: PIRP,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlCancellableWaitForMultipleObjects(
+        Count: ULONG,
+        ObjectArray: *mut PVOID,
+        WaitType: WAIT_TYPE,
+        Timeout: PLARGE_INTEGER,
+        WaitBlockArray: PKWAIT_BLOCK,
+        Irp: PIRP,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlMupGetProviderInfoFromFileObject(
+        pFileObject: PFILE_OBJECT,
+        Level: ULONG,
+        pBuffer: PVOID,
+        pBufferSize: PULONG,
+    ) -> NTSTATUS;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSRTL_MUP_PROVIDER_INFO_LEVEL_1 {
+    pub ProviderId: ULONG32,
+}
+pub type FSRTL_MUP_PROVIDER_INFO_LEVEL_1 = _FSRTL_MUP_PROVIDER_INFO_LEVEL_1;
+pub type PFSRTL_MUP_PROVIDER_INFO_LEVEL_1 = *mut _FSRTL_MUP_PROVIDER_INFO_LEVEL_1;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSRTL_MUP_PROVIDER_INFO_LEVEL_2 {
+    pub ProviderId: ULONG32,
+    pub ProviderName: UNICODE_STRING,
+}
+pub type FSRTL_MUP_PROVIDER_INFO_LEVEL_2 = _FSRTL_MUP_PROVIDER_INFO_LEVEL_2;
+pub type PFSRTL_MUP_PROVIDER_INFO_LEVEL_2 = *mut _FSRTL_MUP_PROVIDER_INFO_LEVEL_2;
+unsafe extern "C" {
+    pub fn FsRtlMupGetProviderIdFromName(
+        pProviderName: PCUNICODE_STRING,
+        pProviderId: PULONG32,
+    ) -> NTSTATUS;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSRTL_PER_FILE_CONTEXT {
+    pub Links: LIST_ENTRY,
+    pub OwnerId: PVOID,
+    pub InstanceId: PVOID,
+    pub FreeCallback: PFREE_FUNCTION,
+}
+pub type FSRTL_PER_FILE_CONTEXT = _FSRTL_PER_FILE_CONTEXT;
+pub type PFSRTL_PER_FILE_CONTEXT = *mut _FSRTL_PER_FILE_CONTEXT;
+unsafe extern "C" {
+    pub fn FsRtlInsertPerFileContext(
+        PerFileContextPointer: *mut PVOID,
+        Ptr: PFSRTL_PER_FILE_CONTEXT,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlLookupPerFileContext(
+        PerFileContextPointer: *mut PVOID,
+        OwnerId: PVOID,
+        InstanceId: PVOID,
+    ) -> PFSRTL_PER_FILE_CONTEXT;
+}
+unsafe extern "C" {
+    pub fn FsRtlRemovePerFileContext(
+        PerFileContextPointer: *mut PVOID,
+        OwnerId: PVOID,
+        InstanceId: PVOID,
+    ) -> PFSRTL_PER_FILE_CONTEXT;
+}
+unsafe extern "C" {
+    pub fn FsRtlTeardownPerFileContexts(PerFileContextPointer: *mut PVOID);
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSRTL_PER_STREAM_CONTEXT {
+    pub Links: LIST_ENTRY,
+    pub OwnerId: PVOID,
+    pub InstanceId: PVOID,
+    pub FreeCallback: PFREE_FUNCTION,
+}
+pub type FSRTL_PER_STREAM_CONTEXT = _FSRTL_PER_STREAM_CONTEXT;
+pub type PFSRTL_PER_STREAM_CONTEXT = *mut _FSRTL_PER_STREAM_CONTEXT;
+unsafe extern "C" {
+    pub fn FsRtlInsertPerStreamContext(
+        PerStreamContext: PFSRTL_ADVANCED_FCB_HEADER,
+        Ptr: PFSRTL_PER_STREAM_CONTEXT,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlLookupPerStreamContextInternal(
+        StreamContext: PFSRTL_ADVANCED_FCB_HEADER,
+        OwnerId: PVOID,
+        InstanceId: PVOID,
+    ) -> PFSRTL_PER_STREAM_CONTEXT;
+}
+unsafe extern "C" {
+    pub fn FsRtlRemovePerStreamContext(
+        StreamContext: PFSRTL_ADVANCED_FCB_HEADER,
+        OwnerId: PVOID,
+        InstanceId: PVOID,
+    ) -> PFSRTL_PER_STREAM_CONTEXT;
+}
+unsafe extern "C" {
+    pub fn FsRtlAllocateAePushLock(PoolType: POOL_TYPE, Tag: ULONG) -> PVOID;
+}
+unsafe extern "C" {
+    pub fn FsRtlFreeAePushLock(AePushLock: PVOID);
+}
+unsafe extern "C" {
+    pub fn FsRtlTeardownPerStreamContexts(AdvancedHeader: PFSRTL_ADVANCED_FCB_HEADER);
+}
+pub type PFN_FSRTLTEARDOWNPERSTREAMCONTEXTS =
+::core::option::Option<unsafe extern "C" fn(AdvancedHeader: PFSRTL_ADVANCED_FCB_HEADER)>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _FSRTL_PER_FILEOBJECT_CONTEXT {
+    pub Links: LIST_ENTRY,
+    pub OwnerId: PVOID,
+    pub InstanceId: PVOID,
+}
+pub type FSRTL_PER_FILEOBJECT_CONTEXT = _FSRTL_PER_FILEOBJECT_CONTEXT;
+pub type PFSRTL_PER_FILEOBJECT_CONTEXT = *mut _FSRTL_PER_FILEOBJECT_CONTEXT;
+unsafe extern "C" {
+    pub fn FsRtlInsertPerFileObjectContext(
+        FileObject: PFILE_OBJECT,
+        Ptr: PFSRTL_PER_FILEOBJECT_CONTEXT,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlLookupPerFileObjectContext(
+        FileObject: PFILE_OBJECT,
+        OwnerId: PVOID,
+        InstanceId: PVOID,
+    ) -> PFSRTL_PER_FILEOBJECT_CONTEXT;
+}
+unsafe extern "C" {
+    pub fn FsRtlRemovePerFileObjectContext(
+        FileObject: PFILE_OBJECT,
+        OwnerId: PVOID,
+        InstanceId: PVOID,
+    ) -> PFSRTL_PER_FILEOBJECT_CONTEXT;
+}
+unsafe extern "C" {
+    pub fn FsRtlIncrementCcFastReadNotPossible();
+}
+unsafe extern "C" {
+    pub fn FsRtlIncrementCcFastReadWait();
+}
+unsafe extern "C" {
+    pub fn FsRtlIncrementCcFastReadNoWait();
+}
+unsafe extern "C" {
+    pub fn FsRtlIncrementCcFastReadResourceMiss();
+}
+unsafe extern "C" {
+    pub fn FsRtlIncrementCcFastMdlReadWait();
+}
+unsafe extern "C" {
+    pub fn FsRtlIsPagingFile(FileObject: PFILE_OBJECT) -> LOGICAL;
+}
+unsafe extern "C" {
+    pub fn FsRtlIsSystemPagingFile(FileObject: PFILE_OBJECT) -> LOGICAL;
+}
+unsafe extern "C" {
+    pub fn FsRtlCreateSectionForDataScan(
+        SectionHandle: PHANDLE,
+        SectionObject: *mut PVOID,
+        SectionFileSize: PLARGE_INTEGER,
+        FileObject: PFILE_OBJECT,
+        DesiredAccess: ACCESS_MASK,
+        ObjectAttributes: POBJECT_ATTRIBUTES,
+        MaximumSize: PLARGE_INTEGER,
+        SectionPageProtection: ULONG,
+        AllocationAttributes: ULONG,
+        Flags: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlValidateReparsePointBuffer(
+        BufferLength: ULONG,
+        ReparseBuffer: PREPARSE_DATA_BUFFER,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlRemoveDotsFromPath(
+        OriginalString: PWSTR,
+        PathLength: USHORT,
+        NewLength: *mut USHORT,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlIsNonEmptyDirectoryReparsePointAllowed(ReparseTag: ULONG) -> BOOLEAN;
+}
+pub type ECP_LIST = _ECP_LIST;
+pub type PECP_LIST = *mut _ECP_LIST;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ECP_HEADER {
+    _unused: [u8; 0],
+}
+pub type ECP_HEADER = _ECP_HEADER;
+pub type PECP_HEADER = *mut _ECP_HEADER;
+pub type PFSRTL_EXTRA_CREATE_PARAMETER_CLEANUP_CALLBACK =
+::core::option::Option<unsafe extern "C" fn(EcpContext: PVOID, EcpType: LPCGUID)>;
+pub type FSRTL_ALLOCATE_ECPLIST_FLAGS = ULONG;
+unsafe extern "C" {
+    pub fn FsRtlAllocateExtraCreateParameterList(
+        Flags: FSRTL_ALLOCATE_ECPLIST_FLAGS,
+        EcpList: *mut PECP_LIST,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlFreeExtraCreateParameterList(EcpList: PECP_LIST);
+}
+unsafe extern "C" {
+    pub fn FsRtlInitializeExtraCreateParameterList(EcpList: PECP_LIST) -> NTSTATUS;
+}
+pub type FSRTL_ALLOCATE_ECP_FLAGS = ULONG;
+unsafe extern "C" {
+    pub fn FsRtlAllocateExtraCreateParameter(
+        EcpType: LPCGUID,
+        SizeOfContext: ULONG,
+        Flags: FSRTL_ALLOCATE_ECP_FLAGS,
+        CleanupCallback: PFSRTL_EXTRA_CREATE_PARAMETER_CLEANUP_CALLBACK,
+        PoolTag: ULONG,
+        EcpContext: *mut PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlFreeExtraCreateParameter(EcpContext: PVOID);
+}
+unsafe extern "C" {
+    pub fn FsRtlInitializeExtraCreateParameter(
+        Ecp: PECP_HEADER,
+        EcpFlags: ULONG,
+        CleanupCallback: PFSRTL_EXTRA_CREATE_PARAMETER_CLEANUP_CALLBACK,
+        TotalSize: ULONG,
+        EcpType: LPCGUID,
+        ListAllocatedFrom: PVOID,
+    );
+}
+pub type FSRTL_ECP_LOOKASIDE_FLAGS = ULONG;
+unsafe extern "C" {
+    pub fn FsRtlInitExtraCreateParameterLookasideList(
+        Lookaside: PVOID,
+        Flags: FSRTL_ECP_LOOKASIDE_FLAGS,
+        Size: SIZE_T,
+        Tag: ULONG,
+    );
+}
+unsafe extern "C" {
+    pub fn FsRtlDeleteExtraCreateParameterLookasideList(
+        Lookaside: PVOID,
+        Flags: FSRTL_ECP_LOOKASIDE_FLAGS,
+    );
+}
+unsafe extern "C" {
+    pub fn FsRtlAllocateExtraCreateParameterFromLookasideList(
+        EcpType: LPCGUID,
+        SizeOfContext: ULONG,
+        Flags: FSRTL_ALLOCATE_ECP_FLAGS,
+        CleanupCallback: PFSRTL_EXTRA_CREATE_PARAMETER_CLEANUP_CALLBACK,
+        LookasideList: PVOID,
+        EcpContext: *mut PVOID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlInsertExtraCreateParameter(EcpList: PECP_LIST, EcpContext: PVOID) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlFindExtraCreateParameter(
+        EcpList: PECP_LIST,
+        EcpType: LPCGUID,
+        EcpContext: *mut PVOID,
+        EcpContextSize: *mut ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlRemoveExtraCreateParameter(
+        EcpList: PECP_LIST,
+        EcpType: LPCGUID,
+        EcpContext: *mut PVOID,
+        EcpContextSize: *mut ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlGetEcpListFromIrp(Irp: PIRP, EcpList: *mut PECP_LIST) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlSetEcpListIntoIrp(Irp: PIRP, EcpList: PECP_LIST) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlGetNextExtraCreateParameter(
+        EcpList: PECP_LIST,
+        CurrentEcpContext: PVOID,
+        NextEcpType: LPGUID,
+        NextEcpContext: *mut PVOID,
+        NextEcpContextSize: *mut ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlAcknowledgeEcp(EcpContext: PVOID);
+}
+unsafe extern "C" {
+    pub fn FsRtlPrepareToReuseEcp(EcpContext: PVOID);
+}
+unsafe extern "C" {
+    pub fn FsRtlIsEcpAcknowledged(EcpContext: PVOID) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlIsEcpFromUserMode(EcpContext: PVOID) -> BOOLEAN;
+}
+pub const NETWORK_OPEN_LOCATION_QUALIFIER_NetworkOpenLocationAny: NETWORK_OPEN_LOCATION_QUALIFIER =
+    0;
+pub const NETWORK_OPEN_LOCATION_QUALIFIER_NetworkOpenLocationRemote:
+NETWORK_OPEN_LOCATION_QUALIFIER = 1;
+pub const NETWORK_OPEN_LOCATION_QUALIFIER_NetworkOpenLocationLoopback:
+NETWORK_OPEN_LOCATION_QUALIFIER = 2;
+pub type NETWORK_OPEN_LOCATION_QUALIFIER = core::ffi::c_int;
+pub const NETWORK_OPEN_INTEGRITY_QUALIFIER_NetworkOpenIntegrityAny:
+NETWORK_OPEN_INTEGRITY_QUALIFIER = 0;
+pub const NETWORK_OPEN_INTEGRITY_QUALIFIER_NetworkOpenIntegrityNone:
+NETWORK_OPEN_INTEGRITY_QUALIFIER = 1;
+pub const NETWORK_OPEN_INTEGRITY_QUALIFIER_NetworkOpenIntegritySigned:
+NETWORK_OPEN_INTEGRITY_QUALIFIER = 2;
+pub const NETWORK_OPEN_INTEGRITY_QUALIFIER_NetworkOpenIntegrityEncrypted:
+NETWORK_OPEN_INTEGRITY_QUALIFIER = 3;
+pub const NETWORK_OPEN_INTEGRITY_QUALIFIER_NetworkOpenIntegrityMaximum:
+NETWORK_OPEN_INTEGRITY_QUALIFIER = 4;
+pub type NETWORK_OPEN_INTEGRITY_QUALIFIER = core::ffi::c_int;
+pub type NETWORK_OPEN_IN_FLAGS = ULONG;
+pub type NETWORK_OPEN_OUT_FLAGS = ULONG;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NETWORK_OPEN_ECP_CONTEXT {
+    pub Size: USHORT,
+    pub Reserved: USHORT,
+    pub __bindgen_anon_1: _NETWORK_OPEN_ECP_CONTEXT__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NETWORK_OPEN_ECP_CONTEXT__bindgen_ty_1 {
+    pub in_: _NETWORK_OPEN_ECP_CONTEXT__bindgen_ty_1__bindgen_ty_1,
+    pub out: _NETWORK_OPEN_ECP_CONTEXT__bindgen_ty_1__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NETWORK_OPEN_ECP_CONTEXT__bindgen_ty_1__bindgen_ty_1 {
+    pub Location: NETWORK_OPEN_LOCATION_QUALIFIER,
+    pub Integrity: NETWORK_OPEN_INTEGRITY_QUALIFIER,
+    pub Flags: NETWORK_OPEN_IN_FLAGS,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NETWORK_OPEN_ECP_CONTEXT__bindgen_ty_1__bindgen_ty_2 {
+    pub Location: NETWORK_OPEN_LOCATION_QUALIFIER,
+    pub Integrity: NETWORK_OPEN_INTEGRITY_QUALIFIER,
+    pub Flags: NETWORK_OPEN_OUT_FLAGS,
+}
+pub type NETWORK_OPEN_ECP_CONTEXT = _NETWORK_OPEN_ECP_CONTEXT;
+pub type PNETWORK_OPEN_ECP_CONTEXT = *mut _NETWORK_OPEN_ECP_CONTEXT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NETWORK_OPEN_ECP_CONTEXT_V0 {
+    pub Size: USHORT,
+    pub Reserved: USHORT,
+    pub __bindgen_anon_1: _NETWORK_OPEN_ECP_CONTEXT_V0__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NETWORK_OPEN_ECP_CONTEXT_V0__bindgen_ty_1 {
+    pub in_: _NETWORK_OPEN_ECP_CONTEXT_V0__bindgen_ty_1__bindgen_ty_1,
+    pub out: _NETWORK_OPEN_ECP_CONTEXT_V0__bindgen_ty_1__bindgen_ty_2,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NETWORK_OPEN_ECP_CONTEXT_V0__bindgen_ty_1__bindgen_ty_1 {
+    pub Location: NETWORK_OPEN_LOCATION_QUALIFIER,
+    pub Integrity: NETWORK_OPEN_INTEGRITY_QUALIFIER,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NETWORK_OPEN_ECP_CONTEXT_V0__bindgen_ty_1__bindgen_ty_2 {
+    pub Location: NETWORK_OPEN_LOCATION_QUALIFIER,
+    pub Integrity: NETWORK_OPEN_INTEGRITY_QUALIFIER,
+}
+pub type NETWORK_OPEN_ECP_CONTEXT_V0 = _NETWORK_OPEN_ECP_CONTEXT_V0;
+pub type PNETWORK_OPEN_ECP_CONTEXT_V0 = *mut _NETWORK_OPEN_ECP_CONTEXT_V0;
+unsafe extern "C" {
+    pub static GUID_ECP_NETWORK_OPEN_CONTEXT: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NETWORK_APP_INSTANCE_ECP_CONTEXT {
+    pub Size: USHORT,
+    pub Reserved: USHORT,
+    pub AppInstanceID: GUID,
+}
+pub type NETWORK_APP_INSTANCE_ECP_CONTEXT = _NETWORK_APP_INSTANCE_ECP_CONTEXT;
+pub type PNETWORK_APP_INSTANCE_ECP_CONTEXT = *mut _NETWORK_APP_INSTANCE_ECP_CONTEXT;
+unsafe extern "C" {
+    pub static GUID_ECP_NETWORK_APP_INSTANCE: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NETWORK_APP_INSTANCE_VERSION_ECP_CONTEXT {
+    pub Size: USHORT,
+    pub Reserved: USHORT,
+    pub VersionHigh: UINT64,
+    pub VersionLow: UINT64,
+}
+pub type NETWORK_APP_INSTANCE_VERSION_ECP_CONTEXT = _NETWORK_APP_INSTANCE_VERSION_ECP_CONTEXT;
+pub type PNETWORK_APP_INSTANCE_VERSION_ECP_CONTEXT = *mut _NETWORK_APP_INSTANCE_VERSION_ECP_CONTEXT;
+unsafe extern "C" {
+    pub static GUID_ECP_NETWORK_APP_INSTANCE_VERSION: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _PREFETCH_OPEN_ECP_CONTEXT {
+    pub Context: PVOID,
+}
+pub type PREFETCH_OPEN_ECP_CONTEXT = _PREFETCH_OPEN_ECP_CONTEXT;
+pub type PPREFETCH_OPEN_ECP_CONTEXT = *mut _PREFETCH_OPEN_ECP_CONTEXT;
+unsafe extern "C" {
+    pub static GUID_ECP_PREFETCH_OPEN: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct sockaddr_storage {
+    _unused: [u8; 0],
+}
+pub type PSOCKADDR_STORAGE_NFS = *mut sockaddr_storage;
+unsafe extern "C" {
+    pub static GUID_ECP_NFS_OPEN: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _NFS_OPEN_ECP_CONTEXT {
+    pub ExportAlias: PUNICODE_STRING,
+    pub ClientSocketAddress: PSOCKADDR_STORAGE_NFS,
+}
+pub type NFS_OPEN_ECP_CONTEXT = _NFS_OPEN_ECP_CONTEXT;
+pub type PNFS_OPEN_ECP_CONTEXT = *mut _NFS_OPEN_ECP_CONTEXT;
+pub type PPNFS_OPEN_ECP_CONTEXT = *mut *mut _NFS_OPEN_ECP_CONTEXT;
+unsafe extern "C" {
+    pub static GUID_ECP_SRV_OPEN: GUID;
+}
+pub const _SRV_INSTANCE_TYPE_SrvInstanceTypeUndefined: _SRV_INSTANCE_TYPE = 0;
+pub const _SRV_INSTANCE_TYPE_SrvInstanceTypePrimary: _SRV_INSTANCE_TYPE = 1;
+pub const _SRV_INSTANCE_TYPE_SrvInstanceTypeCsv: _SRV_INSTANCE_TYPE = 2;
+pub const _SRV_INSTANCE_TYPE_SrvInstanceTypeSBL: _SRV_INSTANCE_TYPE = 3;
+pub const _SRV_INSTANCE_TYPE_SrvInstanceTypeSR: _SRV_INSTANCE_TYPE = 4;
+pub const _SRV_INSTANCE_TYPE_SrvInstanceTypeVSMB: _SRV_INSTANCE_TYPE = 5;
+pub type _SRV_INSTANCE_TYPE = core::ffi::c_int;
+pub use self::_SRV_INSTANCE_TYPE as SRV_INSTANCE_TYPE;
+pub type PSRV_INSTANCE_TYPE = *mut _SRV_INSTANCE_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SRV_OPEN_ECP_CONTEXT {
+    pub ShareName: PUNICODE_STRING,
+    pub SocketAddress: PSOCKADDR_STORAGE_NFS,
+    pub OplockBlockState: BOOLEAN,
+    pub OplockAppState: BOOLEAN,
+    pub OplockFinalState: BOOLEAN,
+    pub Version: USHORT,
+    pub InstanceType: SRV_INSTANCE_TYPE,
+}
+pub type SRV_OPEN_ECP_CONTEXT = _SRV_OPEN_ECP_CONTEXT;
+pub type PSRV_OPEN_ECP_CONTEXT = *mut _SRV_OPEN_ECP_CONTEXT;
+unsafe extern "C" {
+    pub static GUID_ECP_RKF_BYPASS: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _RKF_BYPASS_ECP_CONTEXT {
+    pub Reserved: INT,
+    pub Version: INT,
+}
+pub type RKF_BYPASS_ECP_CONTEXT = _RKF_BYPASS_ECP_CONTEXT;
+pub type PRKF_BYPASS_ECP_CONTEXT = *mut _RKF_BYPASS_ECP_CONTEXT;
+unsafe extern "C" {
+    pub static GUID_ECP_IO_DEVICE_HINT: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_DEVICE_HINT_ECP_CONTEXT {
+    pub TargetDevice: PDEVICE_OBJECT,
+    pub RemainingName: UNICODE_STRING,
+}
+pub type IO_DEVICE_HINT_ECP_CONTEXT = _IO_DEVICE_HINT_ECP_CONTEXT;
+pub type PIO_DEVICE_HINT_ECP_CONTEXT = *mut _IO_DEVICE_HINT_ECP_CONTEXT;
+unsafe extern "C" {
+    pub static GUID_ECP_CSV_DOWN_LEVEL_OPEN: GUID;
+}
+pub const _CSV_DOWN_LEVEL_FILE_TYPE_CsvDownLevelFileObject: _CSV_DOWN_LEVEL_FILE_TYPE = 0;
+pub const _CSV_DOWN_LEVEL_FILE_TYPE_CsvCsvFsInternalFileObject: _CSV_DOWN_LEVEL_FILE_TYPE = 1;
+pub type _CSV_DOWN_LEVEL_FILE_TYPE = core::ffi::c_int;
+pub use self::_CSV_DOWN_LEVEL_FILE_TYPE as CSV_DOWN_LEVEL_FILE_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_DOWN_LEVEL_OPEN_ECP_CONTEXT {
+    pub Version: ULONG,
+    pub IsResume: BOOLEAN,
+    pub FileType: CSV_DOWN_LEVEL_FILE_TYPE,
+    pub SourceNodeId: ULONG,
+    pub DestinationNodeId: ULONG,
+}
+pub type CSV_DOWN_LEVEL_OPEN_ECP_CONTEXT = _CSV_DOWN_LEVEL_OPEN_ECP_CONTEXT;
+pub type PCSV_DOWN_LEVEL_OPEN_ECP_CONTEXT = *mut _CSV_DOWN_LEVEL_OPEN_ECP_CONTEXT;
+unsafe extern "C" {
+    pub static GUID_ECP_CSV_QUERY_FILE_REVISION: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_QUERY_FILE_REVISION_ECP_CONTEXT {
+    pub FileId: LONGLONG,
+    pub FileRevision: [LONGLONG; 3usize],
+}
+pub type CSV_QUERY_FILE_REVISION_ECP_CONTEXT = _CSV_QUERY_FILE_REVISION_ECP_CONTEXT;
+pub type PCSV_QUERY_FILE_REVISION_ECP_CONTEXT = *mut _CSV_QUERY_FILE_REVISION_ECP_CONTEXT;
+unsafe extern "C" {
+    pub static GUID_ECP_CSV_QUERY_FILE_REVISION_FILE_ID_128: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_QUERY_FILE_REVISION_ECP_CONTEXT_FILE_ID_128 {
+    pub FileId: FILE_ID_128,
+    pub FileRevision: [LONGLONG; 3usize],
+}
+pub type CSV_QUERY_FILE_REVISION_ECP_CONTEXT_FILE_ID_128 =
+_CSV_QUERY_FILE_REVISION_ECP_CONTEXT_FILE_ID_128;
+pub type PCSV_QUERY_FILE_REVISION_ECP_CONTEXT_FILE_ID_128 =
+*mut _CSV_QUERY_FILE_REVISION_ECP_CONTEXT_FILE_ID_128;
+unsafe extern "C" {
+    pub static GUID_ECP_CSV_SET_HANDLE_PROPERTIES: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CSV_SET_HANDLE_PROPERTIES_ECP_CONTEXT {
+    pub Size: SIZE_T,
+    pub PauseTimeoutInSeconds: ULONG,
+    pub Flags: ULONG,
+}
+pub type CSV_SET_HANDLE_PROPERTIES_ECP_CONTEXT = _CSV_SET_HANDLE_PROPERTIES_ECP_CONTEXT;
+pub type PCSV_SET_HANDLE_PROPERTIES_ECP_CONTEXT = *mut _CSV_SET_HANDLE_PROPERTIES_ECP_CONTEXT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _OPEN_REPARSE_LIST_ENTRY {
+    pub OpenReparseListEntry: LIST_ENTRY,
+    pub ReparseTag: ULONG,
+    pub Flags: ULONG,
+    pub ReparseGuid: GUID,
+    pub Size: USHORT,
+    pub RemainingLength: USHORT,
+}
+pub type OPEN_REPARSE_LIST_ENTRY = _OPEN_REPARSE_LIST_ENTRY;
+pub type POPEN_REPARSE_LIST_ENTRY = *mut _OPEN_REPARSE_LIST_ENTRY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _OPEN_REPARSE_LIST {
+    pub OpenReparseList: LIST_ENTRY,
+}
+pub type OPEN_REPARSE_LIST = _OPEN_REPARSE_LIST;
+pub type POPEN_REPARSE_LIST = *mut _OPEN_REPARSE_LIST;
+unsafe extern "C" {
+    pub static ECP_TYPE_OPEN_REPARSE_GUID: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_STOP_ON_SYMLINK_FILTER_ECP_v0 {
+    pub Out: _IO_STOP_ON_SYMLINK_FILTER_ECP_v0__bindgen_ty_1,
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _IO_STOP_ON_SYMLINK_FILTER_ECP_v0__bindgen_ty_1 {
+    pub ReparseCount: ULONG,
+    pub RemainingPathLength: ULONG,
+}
+pub type IO_STOP_ON_SYMLINK_FILTER_ECP_v0 = _IO_STOP_ON_SYMLINK_FILTER_ECP_v0;
+pub type PIO_STOP_ON_SYMLINK_FILTER_ECP_v0 = *mut _IO_STOP_ON_SYMLINK_FILTER_ECP_v0;
+unsafe extern "C" {
+    pub static ECP_TYPE_IO_STOP_ON_SYMLINK_FILTER_GUID: GUID;
+}
+unsafe extern "C" {
+    pub static ECP_TYPE_CLFS_CREATE_CONTAINER: GUID;
+}
+unsafe extern "C" {
+    pub static GUID_ECP_CREATE_REDIRECTION: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CREATE_REDIRECTION_ECP_CONTEXT {
+    pub Size: USHORT,
+    pub Flags: USHORT,
+    pub FileId: FILE_ID_128,
+    pub VolumeGuid: GUID,
+}
+pub type CREATE_REDIRECTION_ECP_CONTEXT = _CREATE_REDIRECTION_ECP_CONTEXT;
+pub type PCREATE_REDIRECTION_ECP_CONTEXT = *mut _CREATE_REDIRECTION_ECP_CONTEXT;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _FILE_TIMESTAMPS {
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+}
+pub type FILE_TIMESTAMPS = _FILE_TIMESTAMPS;
+pub type PFILE_TIMESTAMPS = *mut _FILE_TIMESTAMPS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOMIC_CREATE_ECP_CONTEXT {
+    pub Size: USHORT,
+    pub InFlags: USHORT,
+    pub OutFlags: USHORT,
+    pub ReparseBufferLength: USHORT,
+    pub ReparseBuffer: PREPARSE_DATA_BUFFER,
+    pub FileSize: LONGLONG,
+    pub ValidDataLength: LONGLONG,
+    pub FileTimestamps: PFILE_TIMESTAMPS,
+    pub FileAttributes: ULONG,
+    pub UsnSourceInfo: ULONG,
+    pub Usn: USN,
+    pub SuppressFileAttributeInheritanceMask: ULONG,
+    pub InOpFlags: ULONG,
+    pub OutOpFlags: ULONG,
+    pub InGenFlags: ULONG,
+    pub OutGenFlags: ULONG,
+    pub CaseSensitiveFlagsMask: ULONG,
+    pub InCaseSensitiveFlags: ULONG,
+    pub OutCaseSensitiveFlags: ULONG,
+}
+pub type ATOMIC_CREATE_ECP_CONTEXT = _ATOMIC_CREATE_ECP_CONTEXT;
+pub type PATOMIC_CREATE_ECP_CONTEXT = *mut _ATOMIC_CREATE_ECP_CONTEXT;
+unsafe extern "C" {
+    pub static GUID_ECP_ATOMIC_CREATE: GUID;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ECP_OPEN_PARAMETERS {
+    pub Size: USHORT,
+    pub Reserved: USHORT,
+    pub Flags: ULONG,
+}
+pub type ECP_OPEN_PARAMETERS = _ECP_OPEN_PARAMETERS;
+pub type PECP_OPEN_PARAMETERS = *mut _ECP_OPEN_PARAMETERS;
+unsafe extern "C" {
+    pub static GUID_ECP_OPEN_PARAMETERS: GUID;
+}
+unsafe extern "C" {
+    pub fn FsRtlOplockGetAnyBreakOwnerProcess(Oplock: POPLOCK) -> PEPROCESS;
+}
+pub const OPLOCK_NOTIFY_REASON_OPLOCK_NOTIFY_BREAK_WAIT_INTERIM_TIMEOUT: OPLOCK_NOTIFY_REASON = 0;
+pub const OPLOCK_NOTIFY_REASON_OPLOCK_NOTIFY_BREAK_WAIT_TERMINATED: OPLOCK_NOTIFY_REASON = 1;
+pub type OPLOCK_NOTIFY_REASON = core::ffi::c_int;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _OPLOCK_NOTIFY_PARAMS {
+    pub NotifyReason: OPLOCK_NOTIFY_REASON,
+    pub NotifyContext: PVOID,
+    pub Irp: PIRP,
+    pub Status: NTSTATUS,
+}
+pub type OPLOCK_NOTIFY_PARAMS = _OPLOCK_NOTIFY_PARAMS;
+pub type POPLOCK_NOTIFY_PARAMS = *mut _OPLOCK_NOTIFY_PARAMS;
+pub type POPLOCK_NOTIFY_ROUTINE =
+::core::option::Option<unsafe extern "C" fn(NotifyParams: POPLOCK_NOTIFY_PARAMS) -> NTSTATUS>;
+unsafe extern "C" {
+    pub fn FsRtlCheckOplockEx2(
+        Oplock: POPLOCK,
+        Irp: PIRP,
+        Flags: ULONG,
+        FlagsEx2: ULONG,
+        CompletionRoutineContext: PVOID,
+        CompletionRoutine: POPLOCK_WAIT_COMPLETE_ROUTINE,
+        PostIrpRoutine: POPLOCK_FS_PREPOST_IRP,
+        Timeout: ULONGLONG,
+        NotifyContext: PVOID,
+        NotifyRoutine: POPLOCK_NOTIFY_ROUTINE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlCheckOplockForFsFilterCallback(
+        Oplock: POPLOCK,
+        CallbackData: PVOID,
+        Flags: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlGetCurrentProcessLoaderList() -> PLIST_ENTRY;
+}
+unsafe extern "C" {
+    pub fn FsRtlIs32BitProcess(Process: PEPROCESS) -> BOOLEAN;
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _QUERY_ON_CREATE_FILE_STAT_INFORMATION {
+    pub FileId: LARGE_INTEGER,
+    pub CreationTime: LARGE_INTEGER,
+    pub LastAccessTime: LARGE_INTEGER,
+    pub LastWriteTime: LARGE_INTEGER,
+    pub ChangeTime: LARGE_INTEGER,
+    pub AllocationSize: LARGE_INTEGER,
+    pub EndOfFile: LARGE_INTEGER,
+    pub FileAttributes: ULONG,
+    pub ReparseTag: ULONG,
+    pub NumberOfLinks: ULONG,
+}
+pub type QUERY_ON_CREATE_FILE_STAT_INFORMATION = _QUERY_ON_CREATE_FILE_STAT_INFORMATION;
+pub type PQUERY_ON_CREATE_FILE_STAT_INFORMATION = *mut _QUERY_ON_CREATE_FILE_STAT_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _QUERY_ON_CREATE_FILE_LX_INFORMATION {
+    pub EffectiveAccess: ACCESS_MASK,
+    pub LxFlags: ULONG,
+    pub LxUid: ULONG,
+    pub LxGid: ULONG,
+    pub LxMode: ULONG,
+    pub LxDeviceIdMajor: ULONG,
+    pub LxDeviceIdMinor: ULONG,
+}
+pub type QUERY_ON_CREATE_FILE_LX_INFORMATION = _QUERY_ON_CREATE_FILE_LX_INFORMATION;
+pub type PQUERY_ON_CREATE_FILE_LX_INFORMATION = *mut _QUERY_ON_CREATE_FILE_LX_INFORMATION;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _QUERY_ON_CREATE_EA_INFORMATION {
+    pub EaBufferSize: ULONG,
+    pub EaBuffer: PFILE_FULL_EA_INFORMATION,
+}
+pub type QUERY_ON_CREATE_EA_INFORMATION = _QUERY_ON_CREATE_EA_INFORMATION;
+pub type PQUERY_ON_CREATE_EA_INFORMATION = *mut _QUERY_ON_CREATE_EA_INFORMATION;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _QUERY_ON_CREATE_ECP_CONTEXT {
+    pub RequestedClasses: ULONG,
+    pub ClassesProcessed: ULONG,
+    pub ClassesWithErrors: ULONG,
+    pub ClassesWithNoData: ULONG,
+    pub StatInformation: QUERY_ON_CREATE_FILE_STAT_INFORMATION,
+    pub LxInformation: QUERY_ON_CREATE_FILE_LX_INFORMATION,
+    pub EaInformation: QUERY_ON_CREATE_EA_INFORMATION,
+}
+pub type QUERY_ON_CREATE_ECP_CONTEXT = _QUERY_ON_CREATE_ECP_CONTEXT;
+pub type PQUERY_ON_CREATE_ECP_CONTEXT = *mut _QUERY_ON_CREATE_ECP_CONTEXT;
+unsafe extern "C" {
+    pub static GUID_ECP_QUERY_ON_CREATE: GUID;
+}
+unsafe extern "C" {
+    pub static GUID_ECP_CLOUDFILES_ATTRIBUTION: GUID;
+}
+pub const _FSRTL_CHANGE_BACKING_TYPE_ChangeDataControlArea: _FSRTL_CHANGE_BACKING_TYPE = 0;
+pub const _FSRTL_CHANGE_BACKING_TYPE_ChangeImageControlArea: _FSRTL_CHANGE_BACKING_TYPE = 1;
+pub const _FSRTL_CHANGE_BACKING_TYPE_ChangeSharedCacheMap: _FSRTL_CHANGE_BACKING_TYPE = 2;
+pub type _FSRTL_CHANGE_BACKING_TYPE = core::ffi::c_int;
+pub use self::_FSRTL_CHANGE_BACKING_TYPE as FSRTL_CHANGE_BACKING_TYPE;
+pub type PFSRTL_CHANGE_BACKING_TYPE = *mut _FSRTL_CHANGE_BACKING_TYPE;
+unsafe extern "C" {
+    pub fn FsRtlChangeBackingFileObject(
+        CurrentFileObject: PFILE_OBJECT,
+        NewFileObject: PFILE_OBJECT,
+        ChangeBackingType: FSRTL_CHANGE_BACKING_TYPE,
+        Flags: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlLogCcFlushError(
+        FileName: PUNICODE_STRING,
+        DeviceObject: PDEVICE_OBJECT,
+        SectionObjectPointer: PSECTION_OBJECT_POINTERS,
+        FlushError: NTSTATUS,
+        Flags: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlAreVolumeStartupApplicationsComplete() -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlQueryMaximumVirtualDiskNestingLevel() -> ULONG;
+}
+unsafe extern "C" {
+    pub fn FsRtlGetVirtualDiskNestingLevel(
+        DeviceObject: PDEVICE_OBJECT,
+        NestingLevel: PULONG,
+        NestingFlags: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlVolumeDeviceToCorrelationId(
+        VolumeDeviceObject: PDEVICE_OBJECT,
+        Guid: *mut GUID,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlIssueDeviceIoControl(
+        DeviceObject: PDEVICE_OBJECT,
+        IoCtl: ULONG,
+        Flags: UCHAR,
+        InputBuffer: PVOID,
+        InputBufferLength: ULONG,
+        OutputBuffer: PVOID,
+        OutputBufferLength: ULONG,
+        IosbInformation: PULONG_PTR,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlGetSectorSizeInformation(
+        DeviceObject: PDEVICE_OBJECT,
+        SectorSizeInfo: PFILE_FS_SECTOR_SIZE_INFORMATION,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlGetSupportedFeatures(
+        DeviceObject: PDEVICE_OBJECT,
+        SupportedFeatures: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlKernelFsControlFile(
+        FileObject: PFILE_OBJECT,
+        FsControlCode: ULONG,
+        InputBuffer: PVOID,
+        InputBufferLength: ULONG,
+        OutputBuffer: PVOID,
+        OutputBufferLength: ULONG,
+        RetOutputBufferSize: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlQueryKernelEaFile(
+        FileObject: PFILE_OBJECT,
+        ReturnedEaData: PVOID,
+        Length: ULONG,
+        ReturnSingleEntry: BOOLEAN,
+        EaList: PVOID,
+        EaListLength: ULONG,
+        EaIndex: PULONG,
+        RestartScan: BOOLEAN,
+        LengthReturned: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlSetKernelEaFile(
+        FileObject: PFILE_OBJECT,
+        EaBuffer: PVOID,
+        Length: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlQueryInformationFile(
+        FileObject: PFILE_OBJECT,
+        FileInformation: PVOID,
+        Length: ULONG,
+        FileInformationClass: FILE_INFORMATION_CLASS,
+        RetFileInformationSize: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlQueryCachedVdl(FileObject: PFILE_OBJECT, Vdl: PLONGLONG) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlUpdateDiskCounters(BytesRead: ULONG64, BytesWritten: ULONG64);
+}
+unsafe extern "C" {
+    pub fn FsRtlDismountComplete(DeviceObject: PDEVICE_OBJECT, DismountStatus: NTSTATUS);
+}
+unsafe extern "C" {
+    pub fn FsRtlSetDriverBacking(DriverObj: PDRIVER_OBJECT, Flags: ULONG) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn FsRtlIsMobileOS() -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn FsRtlIsExtentDangling(
+        StartPage: PFN_NUMBER,
+        NumberOfPages: PFN_NUMBER,
+        Flags: ULONG,
+    ) -> PFN_NUMBER;
+}
+unsafe extern "C" {
+    pub fn FsRtlIsDaxVolume(FileObject: PFILE_OBJECT) -> BOOLEAN;
+}
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _PUBLIC_BCB {
+    pub NodeTypeCode: CSHORT,
+    pub NodeByteSize: CSHORT,
+    pub MappedLength: ULONG,
+    pub MappedFileOffset: LARGE_INTEGER,
+}
+pub type PUBLIC_BCB = _PUBLIC_BCB;
+pub type PPUBLIC_BCB = *mut _PUBLIC_BCB;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _CC_FILE_SIZES {
+    pub AllocationSize: LARGE_INTEGER,
+    pub FileSize: LARGE_INTEGER,
+    pub ValidDataLength: LARGE_INTEGER,
+}
+pub type CC_FILE_SIZES = _CC_FILE_SIZES;
+pub type PCC_FILE_SIZES = *mut _CC_FILE_SIZES;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _READ_AHEAD_PARAMETERS {
+    pub NodeByteSize: CSHORT,
+    pub Granularity: ULONG,
+    pub PipelinedRequestSize: ULONG,
+    pub ReadAheadGrowthPercentage: ULONG,
+}
+pub type READ_AHEAD_PARAMETERS = _READ_AHEAD_PARAMETERS;
+pub type PREAD_AHEAD_PARAMETERS = *mut _READ_AHEAD_PARAMETERS;
+pub type PACQUIRE_FOR_LAZY_WRITE =
+::core::option::Option<unsafe extern "C" fn(Context: PVOID, Wait: BOOLEAN) -> BOOLEAN>;
+pub type PRELEASE_FROM_LAZY_WRITE = ::core::option::Option<unsafe extern "C" fn(Context: PVOID)>;
+pub type PACQUIRE_FOR_READ_AHEAD =
+::core::option::Option<unsafe extern "C" fn(Context: PVOID, Wait: BOOLEAN) -> BOOLEAN>;
+pub type PRELEASE_FROM_READ_AHEAD = ::core::option::Option<unsafe extern "C" fn(Context: PVOID)>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CACHE_MANAGER_CALLBACKS {
+    pub AcquireForLazyWrite: PACQUIRE_FOR_LAZY_WRITE,
+    pub ReleaseFromLazyWrite: PRELEASE_FROM_LAZY_WRITE,
+    pub AcquireForReadAhead: PACQUIRE_FOR_READ_AHEAD,
+    pub ReleaseFromReadAhead: PRELEASE_FROM_READ_AHEAD,
+}
+pub type CACHE_MANAGER_CALLBACKS = _CACHE_MANAGER_CALLBACKS;
+pub type PCACHE_MANAGER_CALLBACKS = *mut _CACHE_MANAGER_CALLBACKS;
+pub type PACQUIRE_FOR_LAZY_WRITE_EX = ::core::option::Option<
+    unsafe extern "C" fn(Context: PVOID, InFlags: ULONG, OutFlags: PULONG) -> BOOLEAN,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CACHE_MANAGER_CALLBACK_FUNCTIONS {
+    pub AcquireForLazyWriteEx: PACQUIRE_FOR_LAZY_WRITE_EX,
+    pub ReleaseFromLazyWrite: PRELEASE_FROM_LAZY_WRITE,
+    pub AcquireForReadAhead: PACQUIRE_FOR_READ_AHEAD,
+    pub ReleaseFromReadAhead: PRELEASE_FROM_READ_AHEAD,
+}
+pub type CACHE_MANAGER_CALLBACK_FUNCTIONS = _CACHE_MANAGER_CALLBACK_FUNCTIONS;
+pub type PCACHE_MANAGER_CALLBACK_FUNCTIONS = *mut _CACHE_MANAGER_CALLBACK_FUNCTIONS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CACHE_MANAGER_CALLBACKS_EX {
+    pub Version: USHORT,
+    pub Size: USHORT,
+    pub Functions: CACHE_MANAGER_CALLBACK_FUNCTIONS,
+}
+pub type CACHE_MANAGER_CALLBACKS_EX = _CACHE_MANAGER_CALLBACKS_EX;
+pub type PCACHE_MANAGER_CALLBACKS_EX = *mut _CACHE_MANAGER_CALLBACKS_EX;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _CACHE_UNINITIALIZE_EVENT {
+    pub Next: *mut _CACHE_UNINITIALIZE_EVENT,
+    pub Event: KEVENT,
+}
+pub type CACHE_UNINITIALIZE_EVENT = _CACHE_UNINITIALIZE_EVENT;
+pub type PCACHE_UNINITIALIZE_EVENT = *mut _CACHE_UNINITIALIZE_EVENT;
+pub type PDIRTY_PAGE_ROUTINE = ::core::option::Option<
+    unsafe extern "C" fn(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        OldestLsn: PLARGE_INTEGER,
+        NewestLsn: PLARGE_INTEGER,
+        Context1: PVOID,
+        Context2: PVOID,
+    ),
+>;
+pub type PFLUSH_TO_LSN =
+::core::option::Option<unsafe extern "C" fn(LogHandle: PVOID, Lsn: LARGE_INTEGER)>;
+pub type PQUERY_LOG_USAGE =
+::core::option::Option<unsafe extern "C" fn(LogHandle: PVOID, PercentageFull: PUSHORT)>;
+pub type PASYNC_READ_COMPLETION_CALLBACK =
+::core::option::Option<unsafe extern "C" fn(Context: PVOID) -> BOOLEAN>;
+unsafe extern "C" {
+    pub static mut CcFastMdlReadWait: ULONG;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CC_ASYNC_READ_CONTEXT {
+    pub CompletionRoutine: PASYNC_READ_COMPLETION_CALLBACK,
+    pub Context: PVOID,
+    pub Mdl: PMDL,
+    pub RequestorMode: KPROCESSOR_MODE,
+    pub NestingLevel: ULONG,
+}
+pub type CC_ASYNC_READ_CONTEXT = _CC_ASYNC_READ_CONTEXT;
+pub type PCC_ASYNC_READ_CONTEXT = *mut _CC_ASYNC_READ_CONTEXT;
+unsafe extern "C" {
+    pub fn CcInitializeCacheMap(
+        FileObject: PFILE_OBJECT,
+        FileSizes: PCC_FILE_SIZES,
+        PinAccess: BOOLEAN,
+        Callbacks: PCACHE_MANAGER_CALLBACKS,
+        LazyWriteContext: PVOID,
+    );
+}
+unsafe extern "C" {
+    pub fn CcUninitializeCacheMap(
+        FileObject: PFILE_OBJECT,
+        TruncateSize: PLARGE_INTEGER,
+        UninitializeEvent: PCACHE_UNINITIALIZE_EVENT,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcSetFileSizes(FileObject: PFILE_OBJECT, FileSizes: PCC_FILE_SIZES);
+}
+unsafe extern "C" {
+    pub fn CcSetFileSizesEx(FileObject: PFILE_OBJECT, FileSizes: PCC_FILE_SIZES) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn CcPurgeCacheSection(
+        SectionObjectPointer: PSECTION_OBJECT_POINTERS,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        Flags: ULONG,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcCoherencyFlushAndPurgeCache(
+        SectionObjectPointer: PSECTION_OBJECT_POINTERS,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        IoStatus: PIO_STATUS_BLOCK,
+        Flags: ULONG,
+    );
+}
+unsafe extern "C" {
+    pub fn CcSetDirtyPageThreshold(FileObject: PFILE_OBJECT, DirtyPageThreshold: ULONG);
+}
+unsafe extern "C" {
+    pub fn CcFlushCache(
+        SectionObjectPointer: PSECTION_OBJECT_POINTERS,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        IoStatus: PIO_STATUS_BLOCK,
+    );
+}
+unsafe extern "C" {
+    pub fn CcGetFlushedValidData(
+        SectionObjectPointer: PSECTION_OBJECT_POINTERS,
+        BcbListHeld: BOOLEAN,
+    ) -> LARGE_INTEGER;
+}
+unsafe extern "C" {
+    pub fn CcZeroData(
+        FileObject: PFILE_OBJECT,
+        StartOffset: PLARGE_INTEGER,
+        EndOffset: PLARGE_INTEGER,
+        Wait: BOOLEAN,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcRemapBcb(Bcb: PVOID) -> PVOID;
+}
+unsafe extern "C" {
+    pub fn CcRepinBcb(Bcb: PVOID);
+}
+unsafe extern "C" {
+    pub fn CcUnpinRepinnedBcb(Bcb: PVOID, WriteThrough: BOOLEAN, IoStatus: PIO_STATUS_BLOCK);
+}
+unsafe extern "C" {
+    pub fn CcGetFileObjectFromSectionPtrs(
+        SectionObjectPointer: PSECTION_OBJECT_POINTERS,
+    ) -> PFILE_OBJECT;
+}
+unsafe extern "C" {
+    pub fn CcGetFileObjectFromSectionPtrsRef(
+        SectionObjectPointer: PSECTION_OBJECT_POINTERS,
+    ) -> PFILE_OBJECT;
+}
+unsafe extern "C" {
+    pub fn CcGetFileObjectFromBcb(Bcb: PVOID) -> PFILE_OBJECT;
+}
+unsafe extern "C" {
+    pub fn CcCopyWriteWontFlush(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcCanIWrite(
+        FileObject: PFILE_OBJECT,
+        BytesToWrite: ULONG,
+        Wait: BOOLEAN,
+        Retrying: UCHAR,
+    ) -> BOOLEAN;
+}
+pub type PCC_POST_DEFERRED_WRITE =
+::core::option::Option<unsafe extern "C" fn(Context1: PVOID, Context2: PVOID)>;
+unsafe extern "C" {
+    pub fn CcDeferWrite(
+        FileObject: PFILE_OBJECT,
+        PostRoutine: PCC_POST_DEFERRED_WRITE,
+        Context1: PVOID,
+        Context2: PVOID,
+        BytesToWrite: ULONG,
+        Retrying: BOOLEAN,
+    );
+}
+unsafe extern "C" {
+    pub fn CcCopyRead(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        Wait: BOOLEAN,
+        Buffer: PVOID,
+        IoStatus: PIO_STATUS_BLOCK,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcFastCopyRead(
+        FileObject: PFILE_OBJECT,
+        FileOffset: ULONG,
+        Length: ULONG,
+        PageCount: ULONG,
+        Buffer: PVOID,
+        IoStatus: PIO_STATUS_BLOCK,
+    );
+}
+unsafe extern "C" {
+    pub fn CcCopyWrite(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        Wait: BOOLEAN,
+        Buffer: PVOID,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcFastCopyWrite(
+        FileObject: PFILE_OBJECT,
+        FileOffset: ULONG,
+        Length: ULONG,
+        Buffer: PVOID,
+    );
+}
+unsafe extern "C" {
+    pub fn CcMdlRead(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        MdlChain: *mut PMDL,
+        IoStatus: PIO_STATUS_BLOCK,
+    );
+}
+unsafe extern "C" {
+    pub fn CcMdlReadComplete(FileObject: PFILE_OBJECT, MdlChain: PMDL);
+}
+unsafe extern "C" {
+    pub fn CcPrepareMdlWrite(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        MdlChain: *mut PMDL,
+        IoStatus: PIO_STATUS_BLOCK,
+    );
+}
+unsafe extern "C" {
+    pub fn CcMdlWriteComplete(FileObject: PFILE_OBJECT, FileOffset: PLARGE_INTEGER, MdlChain: PMDL);
+}
+unsafe extern "C" {
+    pub fn CcMdlWriteAbort(FileObject: PFILE_OBJECT, MdlChain: PMDL);
+}
+unsafe extern "C" {
+    pub fn CcScheduleReadAhead(FileObject: PFILE_OBJECT, FileOffset: PLARGE_INTEGER, Length: ULONG);
+}
+unsafe extern "C" {
+    pub fn CcWaitForCurrentLazyWriterActivity() -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn CcSetReadAheadGranularity(FileObject: PFILE_OBJECT, Granularity: ULONG);
+}
+unsafe extern "C" {
+    pub fn CcCopyWriteEx(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        Wait: BOOLEAN,
+        Buffer: PVOID,
+        IoIssuerThread: PETHREAD,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcCopyReadEx(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        Wait: BOOLEAN,
+        Buffer: PVOID,
+        IoStatus: PIO_STATUS_BLOCK,
+        IoIssuerThread: PETHREAD,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcAsyncCopyRead(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        Wait: BOOLEAN,
+        Buffer: PVOID,
+        IoStatus: PIO_STATUS_BLOCK,
+        IoIssuerThread: PETHREAD,
+        AsyncReadContext: PCC_ASYNC_READ_CONTEXT,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcScheduleReadAheadEx(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        IoIssuerThread: PETHREAD,
+    );
+}
+unsafe extern "C" {
+    pub fn CcInitializeCacheMapEx(
+        FileObject: PFILE_OBJECT,
+        FileSizes: PCC_FILE_SIZES,
+        PinAccess: BOOLEAN,
+        Callbacks: PCACHE_MANAGER_CALLBACKS,
+        LazyWriteContext: PVOID,
+        Flags: ULONG,
+    );
+}
+unsafe extern "C" {
+    pub fn CcPinRead(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        Flags: ULONG,
+        Bcb: *mut PVOID,
+        Buffer: *mut PVOID,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcMapData(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        Flags: ULONG,
+        Bcb: *mut PVOID,
+        Buffer: *mut PVOID,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcPinMappedData(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        Flags: ULONG,
+        Bcb: *mut PVOID,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcPreparePinWrite(
+        FileObject: PFILE_OBJECT,
+        FileOffset: PLARGE_INTEGER,
+        Length: ULONG,
+        Zero: BOOLEAN,
+        Flags: ULONG,
+        Bcb: *mut PVOID,
+        Buffer: *mut PVOID,
+    ) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcSetDirtyPinnedData(BcbVoid: PVOID, Lsn: PLARGE_INTEGER);
+}
+unsafe extern "C" {
+    pub fn CcUnpinData(Bcb: PVOID);
+}
+unsafe extern "C" {
+    pub fn CcSetBcbOwnerPointer(Bcb: PVOID, OwnerPointer: PVOID);
+}
+unsafe extern "C" {
+    pub fn CcUnpinDataForThread(Bcb: PVOID, ResourceThreadId: ERESOURCE_THREAD);
+}
+unsafe extern "C" {
+    pub fn CcSetAdditionalCacheAttributes(
+        FileObject: PFILE_OBJECT,
+        DisableReadAhead: BOOLEAN,
+        DisableWriteBehind: BOOLEAN,
+    );
+}
+unsafe extern "C" {
+    pub fn CcSetAdditionalCacheAttributesEx(FileObject: PFILE_OBJECT, Flags: ULONG);
+}
+unsafe extern "C" {
+    pub fn CcSetParallelFlushFile(FileObject: PFILE_OBJECT, EnableParallelFlush: BOOLEAN);
+}
+unsafe extern "C" {
+    pub fn CcSetLogHandleForFile(
+        FileObject: PFILE_OBJECT,
+        LogHandle: PVOID,
+        FlushToLsnRoutine: PFLUSH_TO_LSN,
+    );
+}
+unsafe extern "C" {
+    pub fn CcGetDirtyPages(
+        LogHandle: PVOID,
+        DirtyPageRoutine: PDIRTY_PAGE_ROUTINE,
+        Context1: PVOID,
+        Context2: PVOID,
+    ) -> LARGE_INTEGER;
+}
+unsafe extern "C" {
+    pub fn CcIsThereDirtyData(Vpb: PVPB) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcIsThereDirtyDataEx(Vpb: PVPB, NumberOfDirtyPages: PULONG) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn CcIsCacheManagerCallbackNeeded(Status: NTSTATUS) -> BOOLEAN;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _CC_ERROR_CALLBACK_CONTEXT {
+    pub NodeByteSize: CSHORT,
+    pub ErrorCode: NTSTATUS,
+}
+pub type CC_ERROR_CALLBACK_CONTEXT = _CC_ERROR_CALLBACK_CONTEXT;
+pub type PCC_ERROR_CALLBACK_CONTEXT = *mut _CC_ERROR_CALLBACK_CONTEXT;
+unsafe extern "C" {
+    pub fn CcErrorCallbackRoutine(Context: PCC_ERROR_CALLBACK_CONTEXT) -> NTSTATUS;
+}
+pub type SEC_WCHAR = WCHAR;
+pub type SEC_CHAR = CHAR;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecHandle {
+    pub dwLower: ULONG_PTR,
+    pub dwUpper: ULONG_PTR,
+}
+pub type SecHandle = _SecHandle;
+pub type PSecHandle = *mut _SecHandle;
+pub type CredHandle = SecHandle;
+pub type PCredHandle = PSecHandle;
+pub type CtxtHandle = SecHandle;
+pub type PCtxtHandle = PSecHandle;
+pub type _SECURITY_INTEGER = LARGE_INTEGER;
+pub type SECURITY_INTEGER = LARGE_INTEGER;
+pub type PSECURITY_INTEGER = *mut LARGE_INTEGER;
+pub type TimeStamp = SECURITY_INTEGER;
+pub type PTimeStamp = *mut SECURITY_INTEGER;
+pub type SECURITY_STRING = UNICODE_STRING;
+pub type PSECURITY_STRING = *mut UNICODE_STRING;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgInfoW {
+    pub fCapabilities: core::ffi::c_ulong,
+    pub wVersion: core::ffi::c_ushort,
+    pub wRPCID: core::ffi::c_ushort,
+    pub cbMaxToken: core::ffi::c_ulong,
+    pub Name: *mut SEC_WCHAR,
+    pub Comment: *mut SEC_WCHAR,
+}
+pub type SecPkgInfoW = _SecPkgInfoW;
+pub type PSecPkgInfoW = *mut _SecPkgInfoW;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecBuffer {
+    pub cbBuffer: core::ffi::c_ulong,
+    pub BufferType: core::ffi::c_ulong,
+    pub pvBuffer: *mut core::ffi::c_void,
+}
+pub type SecBuffer = _SecBuffer;
+pub type PSecBuffer = *mut _SecBuffer;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecBufferDesc {
+    pub ulVersion: core::ffi::c_ulong,
+    pub cBuffers: core::ffi::c_ulong,
+    pub pBuffers: PSecBuffer,
+}
+pub type SecBufferDesc = _SecBufferDesc;
+pub type PSecBufferDesc = *mut _SecBufferDesc;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_NEGOTIATION_INFO {
+    pub Size: core::ffi::c_ulong,
+    pub NameLength: core::ffi::c_ulong,
+    pub Name: *mut SEC_WCHAR,
+    pub Reserved: *mut core::ffi::c_void,
+}
+pub type SEC_NEGOTIATION_INFO = _SEC_NEGOTIATION_INFO;
+pub type PSEC_NEGOTIATION_INFO = *mut _SEC_NEGOTIATION_INFO;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_CHANNEL_BINDINGS {
+    pub dwInitiatorAddrType: core::ffi::c_ulong,
+    pub cbInitiatorLength: core::ffi::c_ulong,
+    pub dwInitiatorOffset: core::ffi::c_ulong,
+    pub dwAcceptorAddrType: core::ffi::c_ulong,
+    pub cbAcceptorLength: core::ffi::c_ulong,
+    pub dwAcceptorOffset: core::ffi::c_ulong,
+    pub cbApplicationDataLength: core::ffi::c_ulong,
+    pub dwApplicationDataOffset: core::ffi::c_ulong,
+}
+pub type SEC_CHANNEL_BINDINGS = _SEC_CHANNEL_BINDINGS;
+pub type PSEC_CHANNEL_BINDINGS = *mut _SEC_CHANNEL_BINDINGS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_CHANNEL_BINDINGS_EX {
+    pub magicNumber: core::ffi::c_ulong,
+    pub flags: core::ffi::c_ulong,
+    pub cbHeaderLength: core::ffi::c_ulong,
+    pub cbStructureLength: core::ffi::c_ulong,
+    pub dwInitiatorAddrType: core::ffi::c_ulong,
+    pub cbInitiatorLength: core::ffi::c_ulong,
+    pub dwInitiatorOffset: core::ffi::c_ulong,
+    pub dwAcceptorAddrType: core::ffi::c_ulong,
+    pub cbAcceptorLength: core::ffi::c_ulong,
+    pub dwAcceptorOffset: core::ffi::c_ulong,
+    pub cbApplicationDataLength: core::ffi::c_ulong,
+    pub dwApplicationDataOffset: core::ffi::c_ulong,
+}
+pub type SEC_CHANNEL_BINDINGS_EX = _SEC_CHANNEL_BINDINGS_EX;
+pub type PSEC_CHANNEL_BINDINGS_EX = *mut _SEC_CHANNEL_BINDINGS_EX;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_CHANNEL_BINDINGS_RESULT {
+    pub flags: core::ffi::c_ulong,
+}
+pub type SEC_CHANNEL_BINDINGS_RESULT = _SEC_CHANNEL_BINDINGS_RESULT;
+pub type PSEC_CHANNEL_BINDINGS_RESULT = *mut _SEC_CHANNEL_BINDINGS_RESULT;
+pub const _SEC_APPLICATION_PROTOCOL_NEGOTIATION_EXT_SecApplicationProtocolNegotiationExt_None:
+_SEC_APPLICATION_PROTOCOL_NEGOTIATION_EXT = 0;
+pub const _SEC_APPLICATION_PROTOCOL_NEGOTIATION_EXT_SecApplicationProtocolNegotiationExt_NPN:
+_SEC_APPLICATION_PROTOCOL_NEGOTIATION_EXT = 1;
+pub const _SEC_APPLICATION_PROTOCOL_NEGOTIATION_EXT_SecApplicationProtocolNegotiationExt_ALPN:
+_SEC_APPLICATION_PROTOCOL_NEGOTIATION_EXT = 2;
+pub type _SEC_APPLICATION_PROTOCOL_NEGOTIATION_EXT = core::ffi::c_int;
+pub use self::_SEC_APPLICATION_PROTOCOL_NEGOTIATION_EXT as SEC_APPLICATION_PROTOCOL_NEGOTIATION_EXT;
+pub type PSEC_APPLICATION_PROTOCOL_NEGOTIATION_EXT = *mut _SEC_APPLICATION_PROTOCOL_NEGOTIATION_EXT;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_APPLICATION_PROTOCOL_LIST {
+    pub ProtoNegoExt: SEC_APPLICATION_PROTOCOL_NEGOTIATION_EXT,
+    pub ProtocolListSize: core::ffi::c_ushort,
+    pub ProtocolList: [core::ffi::c_uchar; 1usize],
+}
+pub type SEC_APPLICATION_PROTOCOL_LIST = _SEC_APPLICATION_PROTOCOL_LIST;
+pub type PSEC_APPLICATION_PROTOCOL_LIST = *mut _SEC_APPLICATION_PROTOCOL_LIST;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_APPLICATION_PROTOCOLS {
+    pub ProtocolListsSize: core::ffi::c_ulong,
+    pub ProtocolLists: [SEC_APPLICATION_PROTOCOL_LIST; 1usize],
+}
+pub type SEC_APPLICATION_PROTOCOLS = _SEC_APPLICATION_PROTOCOLS;
+pub type PSEC_APPLICATION_PROTOCOLS = *mut _SEC_APPLICATION_PROTOCOLS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_SRTP_PROTECTION_PROFILES {
+    pub ProfilesSize: core::ffi::c_ushort,
+    pub ProfilesList: [core::ffi::c_ushort; 1usize],
+}
+pub type SEC_SRTP_PROTECTION_PROFILES = _SEC_SRTP_PROTECTION_PROFILES;
+pub type PSEC_SRTP_PROTECTION_PROFILES = *mut _SEC_SRTP_PROTECTION_PROFILES;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_SRTP_MASTER_KEY_IDENTIFIER {
+    pub MasterKeyIdentifierSize: core::ffi::c_uchar,
+    pub MasterKeyIdentifier: [core::ffi::c_uchar; 1usize],
+}
+pub type SEC_SRTP_MASTER_KEY_IDENTIFIER = _SEC_SRTP_MASTER_KEY_IDENTIFIER;
+pub type PSEC_SRTP_MASTER_KEY_IDENTIFIER = *mut _SEC_SRTP_MASTER_KEY_IDENTIFIER;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_TOKEN_BINDING {
+    pub MajorVersion: core::ffi::c_uchar,
+    pub MinorVersion: core::ffi::c_uchar,
+    pub KeyParametersSize: core::ffi::c_ushort,
+    pub KeyParameters: [core::ffi::c_uchar; 1usize],
+}
+pub type SEC_TOKEN_BINDING = _SEC_TOKEN_BINDING;
+pub type PSEC_TOKEN_BINDING = *mut _SEC_TOKEN_BINDING;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_PRESHAREDKEY {
+    pub KeySize: core::ffi::c_ushort,
+    pub Key: [core::ffi::c_uchar; 1usize],
+}
+pub type SEC_PRESHAREDKEY = _SEC_PRESHAREDKEY;
+pub type PSEC_PRESHAREDKEY = *mut _SEC_PRESHAREDKEY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_PRESHAREDKEY_IDENTITY {
+    pub KeyIdentitySize: core::ffi::c_ushort,
+    pub KeyIdentity: [core::ffi::c_uchar; 1usize],
+}
+pub type SEC_PRESHAREDKEY_IDENTITY = _SEC_PRESHAREDKEY_IDENTITY;
+pub type PSEC_PRESHAREDKEY_IDENTITY = *mut _SEC_PRESHAREDKEY_IDENTITY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_DTLS_MTU {
+    pub PathMTU: core::ffi::c_ushort,
+}
+pub type SEC_DTLS_MTU = _SEC_DTLS_MTU;
+pub type PSEC_DTLS_MTU = *mut _SEC_DTLS_MTU;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_FLAGS {
+    pub Flags: core::ffi::c_ulonglong,
+}
+pub type SEC_FLAGS = _SEC_FLAGS;
+pub type PSEC_FLAGS = *mut _SEC_FLAGS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_CERTIFICATE_REQUEST_CONTEXT {
+    pub cbCertificateRequestContext: core::ffi::c_uchar,
+    pub rgCertificateRequestContext: [core::ffi::c_uchar; 1usize],
+}
+pub type SEC_CERTIFICATE_REQUEST_CONTEXT = _SEC_CERTIFICATE_REQUEST_CONTEXT;
+pub type PSEC_CERTIFICATE_REQUEST_CONTEXT = *mut _SEC_CERTIFICATE_REQUEST_CONTEXT;
+pub const _SEC_TRAFFIC_SECRET_TYPE_SecTrafficSecret_None: _SEC_TRAFFIC_SECRET_TYPE = 0;
+pub const _SEC_TRAFFIC_SECRET_TYPE_SecTrafficSecret_Client: _SEC_TRAFFIC_SECRET_TYPE = 1;
+pub const _SEC_TRAFFIC_SECRET_TYPE_SecTrafficSecret_Server: _SEC_TRAFFIC_SECRET_TYPE = 2;
+pub type _SEC_TRAFFIC_SECRET_TYPE = core::ffi::c_int;
+pub use self::_SEC_TRAFFIC_SECRET_TYPE as SEC_TRAFFIC_SECRET_TYPE;
+pub type PSEC_TRAFFIC_SECRET_TYPE = *mut _SEC_TRAFFIC_SECRET_TYPE;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_TRAFFIC_SECRETS {
+    pub SymmetricAlgId: [wchar_t; 64usize],
+    pub ChainingMode: [wchar_t; 64usize],
+    pub HashAlgId: [wchar_t; 64usize],
+    pub KeySize: core::ffi::c_ushort,
+    pub IvSize: core::ffi::c_ushort,
+    pub MsgSequenceStart: core::ffi::c_ushort,
+    pub MsgSequenceEnd: core::ffi::c_ushort,
+    pub TrafficSecretType: SEC_TRAFFIC_SECRET_TYPE,
+    pub TrafficSecretSize: core::ffi::c_ushort,
+    pub TrafficSecret: [core::ffi::c_uchar; 1usize],
+}
+pub type SEC_TRAFFIC_SECRETS = _SEC_TRAFFIC_SECRETS;
+pub type PSEC_TRAFFIC_SECRETS = *mut _SEC_TRAFFIC_SECRETS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgCredentials_NamesW {
+    pub sUserName: *mut SEC_WCHAR,
+}
+pub type SecPkgCredentials_NamesW = _SecPkgCredentials_NamesW;
+pub type PSecPkgCredentials_NamesW = *mut _SecPkgCredentials_NamesW;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgCredentials_SSIProviderW {
+    pub sProviderName: *mut SEC_WCHAR,
+    pub ProviderInfoLength: core::ffi::c_ulong,
+    pub ProviderInfo: *mut core::ffi::c_char,
+}
+pub type SecPkgCredentials_SSIProviderW = _SecPkgCredentials_SSIProviderW;
+pub type PSecPkgCredentials_SSIProviderW = *mut _SecPkgCredentials_SSIProviderW;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgCredentials_KdcProxySettingsW {
+    pub Version: ULONG,
+    pub Flags: ULONG,
+    pub ProxyServerOffset: USHORT,
+    pub ProxyServerLength: USHORT,
+    pub ClientTlsCredOffset: USHORT,
+    pub ClientTlsCredLength: USHORT,
+}
+pub type SecPkgCredentials_KdcProxySettingsW = _SecPkgCredentials_KdcProxySettingsW;
+pub type PSecPkgCredentials_KdcProxySettingsW = *mut _SecPkgCredentials_KdcProxySettingsW;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgCredentials_Cert {
+    pub EncodedCertSize: core::ffi::c_ulong,
+    pub EncodedCert: *mut core::ffi::c_uchar,
+}
+pub type SecPkgCredentials_Cert = _SecPkgCredentials_Cert;
+pub type PSecPkgCredentials_Cert = *mut _SecPkgCredentials_Cert;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_SubjectAttributes {
+    pub AttributeInfo: *mut core::ffi::c_void,
+}
+pub type SecPkgContext_SubjectAttributes = _SecPkgContext_SubjectAttributes;
+pub type PSecPkgContext_SubjectAttributes = *mut _SecPkgContext_SubjectAttributes;
+pub const _SECPKG_CRED_CLASS_SecPkgCredClass_None: _SECPKG_CRED_CLASS = 0;
+pub const _SECPKG_CRED_CLASS_SecPkgCredClass_Ephemeral: _SECPKG_CRED_CLASS = 10;
+pub const _SECPKG_CRED_CLASS_SecPkgCredClass_PersistedGeneric: _SECPKG_CRED_CLASS = 20;
+pub const _SECPKG_CRED_CLASS_SecPkgCredClass_PersistedSpecific: _SECPKG_CRED_CLASS = 30;
+pub const _SECPKG_CRED_CLASS_SecPkgCredClass_Explicit: _SECPKG_CRED_CLASS = 40;
+pub type _SECPKG_CRED_CLASS = core::ffi::c_int;
+pub use self::_SECPKG_CRED_CLASS as SECPKG_CRED_CLASS;
+pub type PSECPKG_CRED_CLASS = *mut _SECPKG_CRED_CLASS;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_CredInfo {
+    pub CredClass: SECPKG_CRED_CLASS,
+    pub IsPromptingNeeded: core::ffi::c_ulong,
+}
+pub type SecPkgContext_CredInfo = _SecPkgContext_CredInfo;
+pub type PSecPkgContext_CredInfo = *mut _SecPkgContext_CredInfo;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_NegoPackageInfo {
+    pub PackageMask: core::ffi::c_ulong,
+}
+pub type SecPkgContext_NegoPackageInfo = _SecPkgContext_NegoPackageInfo;
+pub type PSecPkgContext_NegoPackageInfo = *mut _SecPkgContext_NegoPackageInfo;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_NegoStatus {
+    pub LastStatus: core::ffi::c_ulong,
+}
+pub type SecPkgContext_NegoStatus = _SecPkgContext_NegoStatus;
+pub type PSecPkgContext_NegoStatus = *mut _SecPkgContext_NegoStatus;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_Sizes {
+    pub cbMaxToken: core::ffi::c_ulong,
+    pub cbMaxSignature: core::ffi::c_ulong,
+    pub cbBlockSize: core::ffi::c_ulong,
+    pub cbSecurityTrailer: core::ffi::c_ulong,
+}
+pub type SecPkgContext_Sizes = _SecPkgContext_Sizes;
+pub type PSecPkgContext_Sizes = *mut _SecPkgContext_Sizes;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_StreamSizes {
+    pub cbHeader: core::ffi::c_ulong,
+    pub cbTrailer: core::ffi::c_ulong,
+    pub cbMaximumMessage: core::ffi::c_ulong,
+    pub cBuffers: core::ffi::c_ulong,
+    pub cbBlockSize: core::ffi::c_ulong,
+}
+pub type SecPkgContext_StreamSizes = _SecPkgContext_StreamSizes;
+pub type PSecPkgContext_StreamSizes = *mut _SecPkgContext_StreamSizes;
+pub type SecPkgContext_DatagramSizes = SecPkgContext_StreamSizes;
+pub type PSecPkgContext_DatagramSizes = PSecPkgContext_StreamSizes;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_NamesW {
+    pub sUserName: *mut SEC_WCHAR,
+}
+pub type SecPkgContext_NamesW = _SecPkgContext_NamesW;
+pub type PSecPkgContext_NamesW = *mut _SecPkgContext_NamesW;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _SecPkgContext_Lifespan {
+    pub tsStart: TimeStamp,
+    pub tsExpiry: TimeStamp,
+}
+pub type SecPkgContext_Lifespan = _SecPkgContext_Lifespan;
+pub type PSecPkgContext_Lifespan = *mut _SecPkgContext_Lifespan;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_DceInfo {
+    pub AuthzSvc: core::ffi::c_ulong,
+    pub pPac: *mut core::ffi::c_void,
+}
+pub type SecPkgContext_DceInfo = _SecPkgContext_DceInfo;
+pub type PSecPkgContext_DceInfo = *mut _SecPkgContext_DceInfo;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_KeyInfoW {
+    pub sSignatureAlgorithmName: *mut SEC_WCHAR,
+    pub sEncryptAlgorithmName: *mut SEC_WCHAR,
+    pub KeySize: core::ffi::c_ulong,
+    pub SignatureAlgorithm: core::ffi::c_ulong,
+    pub EncryptAlgorithm: core::ffi::c_ulong,
+}
+pub type SecPkgContext_KeyInfoW = _SecPkgContext_KeyInfoW;
+pub type PSecPkgContext_KeyInfoW = *mut _SecPkgContext_KeyInfoW;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_AuthorityW {
+    pub sAuthorityName: *mut SEC_WCHAR,
+}
+pub type SecPkgContext_AuthorityW = _SecPkgContext_AuthorityW;
+pub type PSecPkgContext_AuthorityW = *mut _SecPkgContext_AuthorityW;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_ProtoInfoW {
+    pub sProtocolName: *mut SEC_WCHAR,
+    pub majorVersion: core::ffi::c_ulong,
+    pub minorVersion: core::ffi::c_ulong,
+}
+pub type SecPkgContext_ProtoInfoW = _SecPkgContext_ProtoInfoW;
+pub type PSecPkgContext_ProtoInfoW = *mut _SecPkgContext_ProtoInfoW;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _SecPkgContext_PasswordExpiry {
+    pub tsPasswordExpires: TimeStamp,
+}
+pub type SecPkgContext_PasswordExpiry = _SecPkgContext_PasswordExpiry;
+pub type PSecPkgContext_PasswordExpiry = *mut _SecPkgContext_PasswordExpiry;
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct _SecPkgContext_LogoffTime {
+    pub tsLogoffTime: TimeStamp,
+}
+pub type SecPkgContext_LogoffTime = _SecPkgContext_LogoffTime;
+pub type PSecPkgContext_LogoffTime = *mut _SecPkgContext_LogoffTime;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_SessionKey {
+    pub SessionKeyLength: core::ffi::c_ulong,
+    pub SessionKey: *mut core::ffi::c_uchar,
+}
+pub type SecPkgContext_SessionKey = _SecPkgContext_SessionKey;
+pub type PSecPkgContext_SessionKey = *mut _SecPkgContext_SessionKey;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_NegoKeys {
+    pub KeyType: core::ffi::c_ulong,
+    pub KeyLength: core::ffi::c_ushort,
+    pub KeyValue: *mut core::ffi::c_uchar,
+    pub VerifyKeyType: core::ffi::c_ulong,
+    pub VerifyKeyLength: core::ffi::c_ushort,
+    pub VerifyKeyValue: *mut core::ffi::c_uchar,
+}
+pub type SecPkgContext_NegoKeys = _SecPkgContext_NegoKeys;
+pub type PSecPkgContext_NegoKeys = *mut _SecPkgContext_NegoKeys;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_PackageInfoW {
+    pub PackageInfo: PSecPkgInfoW,
+}
+pub type SecPkgContext_PackageInfoW = _SecPkgContext_PackageInfoW;
+pub type PSecPkgContext_PackageInfoW = *mut _SecPkgContext_PackageInfoW;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_UserFlags {
+    pub UserFlags: core::ffi::c_ulong,
+}
+pub type SecPkgContext_UserFlags = _SecPkgContext_UserFlags;
+pub type PSecPkgContext_UserFlags = *mut _SecPkgContext_UserFlags;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_Flags {
+    pub Flags: core::ffi::c_ulong,
+}
+pub type SecPkgContext_Flags = _SecPkgContext_Flags;
+pub type PSecPkgContext_Flags = *mut _SecPkgContext_Flags;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_NegotiationInfoW {
+    pub PackageInfo: PSecPkgInfoW,
+    pub NegotiationState: core::ffi::c_ulong,
+}
+pub type SecPkgContext_NegotiationInfoW = _SecPkgContext_NegotiationInfoW;
+pub type PSecPkgContext_NegotiationInfoW = *mut _SecPkgContext_NegotiationInfoW;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SecPkgContext_CredentialNameW {
+    pub CredentialType: core::ffi::c_ulong,
+    pub sCredentialName: *mut SEC_WCHAR,
+}
+pub type SecPkgContext_CredentialNameW = _SecPkgContext_CredentialNameW;
+pub type PSecPkgContext_CredentialNameW = *mut _SecPkgContext_CredentialNameW;
+pub type SEC_GET_KEY_FN = ::core::option::Option<
+    unsafe extern "C" fn(
+        Arg: *mut core::ffi::c_void,
+        Principal: *mut core::ffi::c_void,
+        KeyVer: core::ffi::c_ulong,
+        Key: *mut *mut core::ffi::c_void,
+        Status: *mut SECURITY_STATUS,
+    ),
+>;
+unsafe extern "C" {
+    pub fn AcquireCredentialsHandleW(
+        pPrincipal: PSECURITY_STRING,
+        pPackage: PSECURITY_STRING,
+        fCredentialUse: core::ffi::c_ulong,
+        pvLogonId: *mut core::ffi::c_void,
+        pAuthData: *mut core::ffi::c_void,
+        pGetKeyFn: SEC_GET_KEY_FN,
+        pvGetKeyArgument: *mut core::ffi::c_void,
+        phCredential: PCredHandle,
+        ptsExpiry: PTimeStamp,
+    ) -> SECURITY_STATUS;
+}
+pub type ACQUIRE_CREDENTIALS_HANDLE_FN_W = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PSECURITY_STRING,
+        arg2: PSECURITY_STRING,
+        arg3: core::ffi::c_ulong,
+        arg4: *mut core::ffi::c_void,
+        arg5: *mut core::ffi::c_void,
+        arg6: SEC_GET_KEY_FN,
+        arg7: *mut core::ffi::c_void,
+        arg8: PCredHandle,
+        arg9: PTimeStamp,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn FreeCredentialsHandle(phCredential: PCredHandle) -> SECURITY_STATUS;
+}
+pub type FREE_CREDENTIALS_HANDLE_FN =
+::core::option::Option<unsafe extern "C" fn(arg1: PCredHandle) -> SECURITY_STATUS>;
+unsafe extern "C" {
+    pub fn AddCredentialsW(
+        hCredentials: PCredHandle,
+        pPrincipal: PSECURITY_STRING,
+        pPackage: PSECURITY_STRING,
+        fCredentialUse: core::ffi::c_ulong,
+        pAuthData: *mut core::ffi::c_void,
+        pGetKeyFn: SEC_GET_KEY_FN,
+        pvGetKeyArgument: *mut core::ffi::c_void,
+        ptsExpiry: PTimeStamp,
+    ) -> SECURITY_STATUS;
+}
+pub type ADD_CREDENTIALS_FN_W = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCredHandle,
+        arg2: PSECURITY_STRING,
+        arg3: PSECURITY_STRING,
+        arg4: core::ffi::c_ulong,
+        arg5: *mut core::ffi::c_void,
+        arg6: SEC_GET_KEY_FN,
+        arg7: *mut core::ffi::c_void,
+        arg8: PTimeStamp,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn AddCredentialsA(
+        hCredentials: PCredHandle,
+        pszPrincipal: LPSTR,
+        pszPackage: LPSTR,
+        fCredentialUse: core::ffi::c_ulong,
+        pAuthData: *mut core::ffi::c_void,
+        pGetKeyFn: SEC_GET_KEY_FN,
+        pvGetKeyArgument: *mut core::ffi::c_void,
+        ptsExpiry: PTimeStamp,
+    ) -> SECURITY_STATUS;
+}
+pub type ADD_CREDENTIALS_FN_A = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCredHandle,
+        arg2: *mut SEC_CHAR,
+        arg3: *mut SEC_CHAR,
+        arg4: core::ffi::c_ulong,
+        arg5: *mut core::ffi::c_void,
+        arg6: SEC_GET_KEY_FN,
+        arg7: *mut core::ffi::c_void,
+        arg8: PTimeStamp,
+    ) -> SECURITY_STATUS,
+>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SspiAsyncContext {
+    _unused: [u8; 0],
+}
+pub type SspiAsyncContext = _SspiAsyncContext;
+pub type SspiAsyncNotifyCallback = ::core::option::Option<
+    unsafe extern "C" fn(Handle: *mut SspiAsyncContext, CallbackData: PVOID),
+>;
+unsafe extern "C" {
+    pub fn SspiCreateAsyncContext() -> *mut SspiAsyncContext;
+}
+unsafe extern "C" {
+    pub fn SspiFreeAsyncContext(Handle: *mut SspiAsyncContext);
+}
+unsafe extern "C" {
+    pub fn SspiReinitAsyncContext(Handle: *mut SspiAsyncContext) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SspiSetAsyncNotifyCallback(
+        Context: *mut SspiAsyncContext,
+        Callback: SspiAsyncNotifyCallback,
+        CallbackData: *mut core::ffi::c_void,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiAsyncContextRequiresNotify(AsyncContext: *mut SspiAsyncContext) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SspiGetAsyncCallStatus(Handle: *mut SspiAsyncContext) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiAcquireCredentialsHandleAsyncW(
+        AsyncContext: *mut SspiAsyncContext,
+        pszPrincipal: PSECURITY_STRING,
+        pszPackage: PSECURITY_STRING,
+        fCredentialUse: core::ffi::c_ulong,
+        pvLogonId: *mut core::ffi::c_void,
+        pAuthData: *mut core::ffi::c_void,
+        pGetKeyFn: SEC_GET_KEY_FN,
+        pvGetKeyArgument: *mut core::ffi::c_void,
+        phCredential: PCredHandle,
+        ptsExpiry: PTimeStamp,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiAcquireCredentialsHandleAsyncA(
+        AsyncContext: *mut SspiAsyncContext,
+        pszPrincipal: LPSTR,
+        pszPackage: LPSTR,
+        fCredentialUse: core::ffi::c_ulong,
+        pvLogonId: *mut core::ffi::c_void,
+        pAuthData: *mut core::ffi::c_void,
+        pGetKeyFn: SEC_GET_KEY_FN,
+        pvGetKeyArgument: *mut core::ffi::c_void,
+        phCredential: PCredHandle,
+        ptsExpiry: PTimeStamp,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiInitializeSecurityContextAsyncW(
+        AsyncContext: *mut SspiAsyncContext,
+        phCredential: PCredHandle,
+        phContext: PCtxtHandle,
+        pszTargetName: PSECURITY_STRING,
+        fContextReq: core::ffi::c_ulong,
+        Reserved1: core::ffi::c_ulong,
+        TargetDataRep: core::ffi::c_ulong,
+        pInput: PSecBufferDesc,
+        Reserved2: core::ffi::c_ulong,
+        phNewContext: PCtxtHandle,
+        pOutput: PSecBufferDesc,
+        pfContextAttr: *mut core::ffi::c_ulong,
+        ptsExpiry: PTimeStamp,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiInitializeSecurityContextAsyncA(
+        AsyncContext: *mut SspiAsyncContext,
+        phCredential: PCredHandle,
+        phContext: PCtxtHandle,
+        pszTargetName: LPSTR,
+        fContextReq: core::ffi::c_ulong,
+        Reserved1: core::ffi::c_ulong,
+        TargetDataRep: core::ffi::c_ulong,
+        pInput: PSecBufferDesc,
+        Reserved2: core::ffi::c_ulong,
+        phNewContext: PCtxtHandle,
+        pOutput: PSecBufferDesc,
+        pfContextAttr: *mut core::ffi::c_ulong,
+        ptsExpiry: PTimeStamp,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiAcceptSecurityContextAsync(
+        AsyncContext: *mut SspiAsyncContext,
+        phCredential: PCredHandle,
+        phContext: PCtxtHandle,
+        pInput: PSecBufferDesc,
+        fContextReq: core::ffi::c_ulong,
+        TargetDataRep: core::ffi::c_ulong,
+        phNewContext: PCtxtHandle,
+        pOutput: PSecBufferDesc,
+        pfContextAttr: *mut core::ffi::c_ulong,
+        ptsExpiry: PTimeStamp,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiFreeCredentialsHandleAsync(
+        AsyncContext: *mut SspiAsyncContext,
+        phCredential: PCredHandle,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiDeleteSecurityContextAsync(
+        AsyncContext: *mut SspiAsyncContext,
+        phContext: PCtxtHandle,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn InitializeSecurityContextW(
+        phCredential: PCredHandle,
+        phContext: PCtxtHandle,
+        pTargetName: PSECURITY_STRING,
+        fContextReq: core::ffi::c_ulong,
+        Reserved1: core::ffi::c_ulong,
+        TargetDataRep: core::ffi::c_ulong,
+        pInput: PSecBufferDesc,
+        Reserved2: core::ffi::c_ulong,
+        phNewContext: PCtxtHandle,
+        pOutput: PSecBufferDesc,
+        pfContextAttr: *mut core::ffi::c_ulong,
+        ptsExpiry: PTimeStamp,
+    ) -> SECURITY_STATUS;
+}
+pub type INITIALIZE_SECURITY_CONTEXT_FN_W = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCredHandle,
+        arg2: PCtxtHandle,
+        arg3: PSECURITY_STRING,
+        arg4: core::ffi::c_ulong,
+        arg5: core::ffi::c_ulong,
+        arg6: core::ffi::c_ulong,
+        arg7: PSecBufferDesc,
+        arg8: core::ffi::c_ulong,
+        arg9: PCtxtHandle,
+        arg10: PSecBufferDesc,
+        arg11: *mut core::ffi::c_ulong,
+        arg12: PTimeStamp,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn AcceptSecurityContext(
+        phCredential: PCredHandle,
+        phContext: PCtxtHandle,
+        pInput: PSecBufferDesc,
+        fContextReq: core::ffi::c_ulong,
+        TargetDataRep: core::ffi::c_ulong,
+        phNewContext: PCtxtHandle,
+        pOutput: PSecBufferDesc,
+        pfContextAttr: *mut core::ffi::c_ulong,
+        ptsExpiry: PTimeStamp,
+    ) -> SECURITY_STATUS;
+}
+pub type ACCEPT_SECURITY_CONTEXT_FN = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCredHandle,
+        arg2: PCtxtHandle,
+        arg3: PSecBufferDesc,
+        arg4: core::ffi::c_ulong,
+        arg5: core::ffi::c_ulong,
+        arg6: PCtxtHandle,
+        arg7: PSecBufferDesc,
+        arg8: *mut core::ffi::c_ulong,
+        arg9: PTimeStamp,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn CompleteAuthToken(phContext: PCtxtHandle, pToken: PSecBufferDesc) -> SECURITY_STATUS;
+}
+pub type COMPLETE_AUTH_TOKEN_FN = ::core::option::Option<
+    unsafe extern "C" fn(arg1: PCtxtHandle, arg2: PSecBufferDesc) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn ImpersonateSecurityContext(phContext: PCtxtHandle) -> SECURITY_STATUS;
+}
+pub type IMPERSONATE_SECURITY_CONTEXT_FN =
+::core::option::Option<unsafe extern "C" fn(arg1: PCtxtHandle) -> SECURITY_STATUS>;
+unsafe extern "C" {
+    pub fn RevertSecurityContext(phContext: PCtxtHandle) -> SECURITY_STATUS;
+}
+pub type REVERT_SECURITY_CONTEXT_FN =
+::core::option::Option<unsafe extern "C" fn(arg1: PCtxtHandle) -> SECURITY_STATUS>;
+unsafe extern "C" {
+    pub fn QuerySecurityContextToken(
+        phContext: PCtxtHandle,
+        Token: *mut *mut core::ffi::c_void,
+    ) -> SECURITY_STATUS;
+}
+pub type QUERY_SECURITY_CONTEXT_TOKEN_FN = ::core::option::Option<
+    unsafe extern "C" fn(arg1: PCtxtHandle, arg2: *mut *mut core::ffi::c_void) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn DeleteSecurityContext(phContext: PCtxtHandle) -> SECURITY_STATUS;
+}
+pub type DELETE_SECURITY_CONTEXT_FN =
+::core::option::Option<unsafe extern "C" fn(arg1: PCtxtHandle) -> SECURITY_STATUS>;
+unsafe extern "C" {
+    pub fn ApplyControlToken(phContext: PCtxtHandle, pInput: PSecBufferDesc) -> SECURITY_STATUS;
+}
+pub type APPLY_CONTROL_TOKEN_FN = ::core::option::Option<
+    unsafe extern "C" fn(arg1: PCtxtHandle, arg2: PSecBufferDesc) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn QueryContextAttributesW(
+        phContext: PCtxtHandle,
+        ulAttribute: core::ffi::c_ulong,
+        pBuffer: *mut core::ffi::c_void,
+    ) -> SECURITY_STATUS;
+}
+pub type QUERY_CONTEXT_ATTRIBUTES_FN_W = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCtxtHandle,
+        arg2: core::ffi::c_ulong,
+        arg3: *mut core::ffi::c_void,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn QueryContextAttributesExW(
+        phContext: PCtxtHandle,
+        ulAttribute: core::ffi::c_ulong,
+        pBuffer: *mut core::ffi::c_void,
+        cbBuffer: core::ffi::c_ulong,
+    ) -> SECURITY_STATUS;
+}
+pub type QUERY_CONTEXT_ATTRIBUTES_EX_FN_W = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCtxtHandle,
+        arg2: core::ffi::c_ulong,
+        arg3: *mut core::ffi::c_void,
+        arg4: core::ffi::c_ulong,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn SetContextAttributesW(
+        phContext: PCtxtHandle,
+        ulAttribute: core::ffi::c_ulong,
+        pBuffer: *mut core::ffi::c_void,
+        cbBuffer: core::ffi::c_ulong,
+    ) -> SECURITY_STATUS;
+}
+pub type SET_CONTEXT_ATTRIBUTES_FN_W = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCtxtHandle,
+        arg2: core::ffi::c_ulong,
+        arg3: *mut core::ffi::c_void,
+        arg4: core::ffi::c_ulong,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn QueryCredentialsAttributesW(
+        phCredential: PCredHandle,
+        ulAttribute: core::ffi::c_ulong,
+        pBuffer: *mut core::ffi::c_void,
+    ) -> SECURITY_STATUS;
+}
+pub type QUERY_CREDENTIALS_ATTRIBUTES_FN_W = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCredHandle,
+        arg2: core::ffi::c_ulong,
+        arg3: *mut core::ffi::c_void,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn QueryCredentialsAttributesExW(
+        phCredential: PCredHandle,
+        ulAttribute: core::ffi::c_ulong,
+        pBuffer: *mut core::ffi::c_void,
+        cbBuffer: core::ffi::c_ulong,
+    ) -> SECURITY_STATUS;
+}
+pub type QUERY_CREDENTIALS_ATTRIBUTES_EX_FN_W = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCredHandle,
+        arg2: core::ffi::c_ulong,
+        arg3: *mut core::ffi::c_void,
+        arg4: core::ffi::c_ulong,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn SetCredentialsAttributesW(
+        phCredential: PCredHandle,
+        ulAttribute: core::ffi::c_ulong,
+        pBuffer: *mut core::ffi::c_void,
+        cbBuffer: core::ffi::c_ulong,
+    ) -> SECURITY_STATUS;
+}
+pub type SET_CREDENTIALS_ATTRIBUTES_FN_W = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCredHandle,
+        arg2: core::ffi::c_ulong,
+        arg3: *mut core::ffi::c_void,
+        arg4: core::ffi::c_ulong,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn FreeContextBuffer(pvContextBuffer: PVOID) -> SECURITY_STATUS;
+}
+pub type FREE_CONTEXT_BUFFER_FN =
+::core::option::Option<unsafe extern "C" fn(arg1: PVOID) -> SECURITY_STATUS>;
+unsafe extern "C" {
+    pub fn MakeSignature(
+        phContext: PCtxtHandle,
+        fQOP: core::ffi::c_ulong,
+        pMessage: PSecBufferDesc,
+        MessageSeqNo: core::ffi::c_ulong,
+    ) -> SECURITY_STATUS;
+}
+pub type MAKE_SIGNATURE_FN = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCtxtHandle,
+        arg2: core::ffi::c_ulong,
+        arg3: PSecBufferDesc,
+        arg4: core::ffi::c_ulong,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn VerifySignature(
+        phContext: PCtxtHandle,
+        pMessage: PSecBufferDesc,
+        MessageSeqNo: core::ffi::c_ulong,
+        pfQOP: *mut core::ffi::c_ulong,
+    ) -> SECURITY_STATUS;
+}
+pub type VERIFY_SIGNATURE_FN = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCtxtHandle,
+        arg2: PSecBufferDesc,
+        arg3: core::ffi::c_ulong,
+        arg4: *mut core::ffi::c_ulong,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn EncryptMessage(
+        phContext: PCtxtHandle,
+        fQOP: core::ffi::c_ulong,
+        pMessage: PSecBufferDesc,
+        MessageSeqNo: core::ffi::c_ulong,
+    ) -> SECURITY_STATUS;
+}
+pub type ENCRYPT_MESSAGE_FN = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCtxtHandle,
+        arg2: core::ffi::c_ulong,
+        arg3: PSecBufferDesc,
+        arg4: core::ffi::c_ulong,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn DecryptMessage(
+        phContext: PCtxtHandle,
+        pMessage: PSecBufferDesc,
+        MessageSeqNo: core::ffi::c_ulong,
+        pfQOP: *mut core::ffi::c_ulong,
+    ) -> SECURITY_STATUS;
+}
+pub type DECRYPT_MESSAGE_FN = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCtxtHandle,
+        arg2: PSecBufferDesc,
+        arg3: core::ffi::c_ulong,
+        arg4: *mut core::ffi::c_ulong,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn EnumerateSecurityPackagesW(
+        pcPackages: *mut core::ffi::c_ulong,
+        ppPackageInfo: *mut PSecPkgInfoW,
+    ) -> SECURITY_STATUS;
+}
+pub type ENUMERATE_SECURITY_PACKAGES_FN_W = ::core::option::Option<
+    unsafe extern "C" fn(arg1: *mut core::ffi::c_ulong, arg2: *mut PSecPkgInfoW) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn QuerySecurityPackageInfoW(
+        pPackageName: PSECURITY_STRING,
+        ppPackageInfo: *mut PSecPkgInfoW,
+    ) -> SECURITY_STATUS;
+}
+pub type QUERY_SECURITY_PACKAGE_INFO_FN_W = ::core::option::Option<
+    unsafe extern "C" fn(arg1: PSECURITY_STRING, arg2: *mut PSecPkgInfoW) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn ExportSecurityContext(
+        phContext: PCtxtHandle,
+        fFlags: ULONG,
+        pPackedContext: PSecBuffer,
+        pToken: *mut *mut core::ffi::c_void,
+    ) -> SECURITY_STATUS;
+}
+pub type EXPORT_SECURITY_CONTEXT_FN = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PCtxtHandle,
+        arg2: ULONG,
+        arg3: PSecBuffer,
+        arg4: *mut *mut core::ffi::c_void,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn ImportSecurityContextW(
+        pszPackage: PSECURITY_STRING,
+        pPackedContext: PSecBuffer,
+        Token: *mut core::ffi::c_void,
+        phContext: PCtxtHandle,
+    ) -> SECURITY_STATUS;
+}
+pub type IMPORT_SECURITY_CONTEXT_FN_W = ::core::option::Option<
+    unsafe extern "C" fn(
+        arg1: PSECURITY_STRING,
+        arg2: PSecBuffer,
+        arg3: *mut core::ffi::c_void,
+        arg4: PCtxtHandle,
+    ) -> SECURITY_STATUS,
+>;
+unsafe extern "C" {
+    pub fn SecMakeSPN(
+        ServiceClass: PUNICODE_STRING,
+        ServiceName: PUNICODE_STRING,
+        InstanceName: PUNICODE_STRING,
+        InstancePort: USHORT,
+        Referrer: PUNICODE_STRING,
+        Spn: PUNICODE_STRING,
+        Length: PULONG,
+        Allocate: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SecMakeSPNEx(
+        ServiceClass: PUNICODE_STRING,
+        ServiceName: PUNICODE_STRING,
+        InstanceName: PUNICODE_STRING,
+        InstancePort: USHORT,
+        Referrer: PUNICODE_STRING,
+        TargetInfo: PUNICODE_STRING,
+        Spn: PUNICODE_STRING,
+        Length: PULONG,
+        Allocate: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SecMakeSPNEx2(
+        ServiceClass: PUNICODE_STRING,
+        ServiceName: PUNICODE_STRING,
+        InstanceName: PUNICODE_STRING,
+        InstancePort: USHORT,
+        Referrer: PUNICODE_STRING,
+        InTargetInfo: PUNICODE_STRING,
+        Spn: PUNICODE_STRING,
+        TotalSize: PULONG,
+        Allocate: BOOLEAN,
+        IsTargetInfoMarshaled: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SecLookupAccountSid(
+        Sid: PSID,
+        NameSize: PULONG,
+        NameBuffer: PUNICODE_STRING,
+        DomainSize: PULONG,
+        DomainBuffer: PUNICODE_STRING,
+        NameUse: PSID_NAME_USE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SecLookupAccountName(
+        Name: PUNICODE_STRING,
+        SidSize: PULONG,
+        Sid: PSID,
+        NameUse: PSID_NAME_USE,
+        DomainSize: PULONG,
+        ReferencedDomain: PUNICODE_STRING,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn SecLookupWellKnownSid(
+        SidType: WELL_KNOWN_SID_TYPE,
+        Sid: PSID,
+        SidBufferSize: ULONG,
+        SidSize: PULONG,
+    ) -> NTSTATUS;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SECURITY_FUNCTION_TABLE_W {
+    pub dwVersion: core::ffi::c_ulong,
+    pub EnumerateSecurityPackagesW: ENUMERATE_SECURITY_PACKAGES_FN_W,
+    pub QueryCredentialsAttributesW: QUERY_CREDENTIALS_ATTRIBUTES_FN_W,
+    pub AcquireCredentialsHandleW: ACQUIRE_CREDENTIALS_HANDLE_FN_W,
+    pub FreeCredentialsHandle: FREE_CREDENTIALS_HANDLE_FN,
+    pub Reserved2: *mut core::ffi::c_void,
+    pub InitializeSecurityContextW: INITIALIZE_SECURITY_CONTEXT_FN_W,
+    pub AcceptSecurityContext: ACCEPT_SECURITY_CONTEXT_FN,
+    pub CompleteAuthToken: COMPLETE_AUTH_TOKEN_FN,
+    pub DeleteSecurityContext: DELETE_SECURITY_CONTEXT_FN,
+    pub ApplyControlToken: APPLY_CONTROL_TOKEN_FN,
+    pub QueryContextAttributesW: QUERY_CONTEXT_ATTRIBUTES_FN_W,
+    pub ImpersonateSecurityContext: IMPERSONATE_SECURITY_CONTEXT_FN,
+    pub RevertSecurityContext: REVERT_SECURITY_CONTEXT_FN,
+    pub MakeSignature: MAKE_SIGNATURE_FN,
+    pub VerifySignature: VERIFY_SIGNATURE_FN,
+    pub FreeContextBuffer: FREE_CONTEXT_BUFFER_FN,
+    pub QuerySecurityPackageInfoW: QUERY_SECURITY_PACKAGE_INFO_FN_W,
+    pub Reserved3: *mut core::ffi::c_void,
+    pub Reserved4: *mut core::ffi::c_void,
+    pub ExportSecurityContext: EXPORT_SECURITY_CONTEXT_FN,
+    pub ImportSecurityContextW: IMPORT_SECURITY_CONTEXT_FN_W,
+    pub AddCredentialsW: ADD_CREDENTIALS_FN_W,
+    pub Reserved8: *mut core::ffi::c_void,
+    pub QuerySecurityContextToken: QUERY_SECURITY_CONTEXT_TOKEN_FN,
+    pub EncryptMessage: ENCRYPT_MESSAGE_FN,
+    pub DecryptMessage: DECRYPT_MESSAGE_FN,
+    pub SetContextAttributesW: SET_CONTEXT_ATTRIBUTES_FN_W,
+    pub SetCredentialsAttributesW: SET_CREDENTIALS_ATTRIBUTES_FN_W,
+    pub Reserved9: *mut core::ffi::c_void,
+    pub QueryContextAttributesExW: QUERY_CONTEXT_ATTRIBUTES_EX_FN_W,
+    pub QueryCredentialsAttributesExW: QUERY_CREDENTIALS_ATTRIBUTES_EX_FN_W,
+}
+pub type SecurityFunctionTableW = _SECURITY_FUNCTION_TABLE_W;
+pub type PSecurityFunctionTableW = *mut _SECURITY_FUNCTION_TABLE_W;
+unsafe extern "C" {
+    pub fn InitSecurityInterfaceW() -> PSecurityFunctionTableW;
+}
+pub type INIT_SECURITY_INTERFACE_W =
+::core::option::Option<unsafe extern "C" fn() -> PSecurityFunctionTableW>;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_WINNT_AUTH_IDENTITY_EX2 {
+    pub Version: core::ffi::c_ulong,
+    pub cbHeaderLength: core::ffi::c_ushort,
+    pub cbStructureLength: core::ffi::c_ulong,
+    pub UserOffset: core::ffi::c_ulong,
+    pub UserLength: core::ffi::c_ushort,
+    pub DomainOffset: core::ffi::c_ulong,
+    pub DomainLength: core::ffi::c_ushort,
+    pub PackedCredentialsOffset: core::ffi::c_ulong,
+    pub PackedCredentialsLength: core::ffi::c_ushort,
+    pub Flags: core::ffi::c_ulong,
+    pub PackageListOffset: core::ffi::c_ulong,
+    pub PackageListLength: core::ffi::c_ushort,
+}
+pub type SEC_WINNT_AUTH_IDENTITY_EX2 = _SEC_WINNT_AUTH_IDENTITY_EX2;
+pub type PSEC_WINNT_AUTH_IDENTITY_EX2 = *mut _SEC_WINNT_AUTH_IDENTITY_EX2;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_WINNT_AUTH_IDENTITY_W {
+    pub User: *mut core::ffi::c_ushort,
+    pub UserLength: core::ffi::c_ulong,
+    pub Domain: *mut core::ffi::c_ushort,
+    pub DomainLength: core::ffi::c_ulong,
+    pub Password: *mut core::ffi::c_ushort,
+    pub PasswordLength: core::ffi::c_ulong,
+    pub Flags: core::ffi::c_ulong,
+}
+pub type SEC_WINNT_AUTH_IDENTITY_W = _SEC_WINNT_AUTH_IDENTITY_W;
+pub type PSEC_WINNT_AUTH_IDENTITY_W = *mut _SEC_WINNT_AUTH_IDENTITY_W;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SEC_WINNT_AUTH_IDENTITY_EXW {
+    pub Version: core::ffi::c_ulong,
+    pub Length: core::ffi::c_ulong,
+    pub User: *mut core::ffi::c_ushort,
+    pub UserLength: core::ffi::c_ulong,
+    pub Domain: *mut core::ffi::c_ushort,
+    pub DomainLength: core::ffi::c_ulong,
+    pub Password: *mut core::ffi::c_ushort,
+    pub PasswordLength: core::ffi::c_ulong,
+    pub Flags: core::ffi::c_ulong,
+    pub PackageList: *mut core::ffi::c_ushort,
+    pub PackageListLength: core::ffi::c_ulong,
+}
+pub type SEC_WINNT_AUTH_IDENTITY_EXW = _SEC_WINNT_AUTH_IDENTITY_EXW;
+pub type PSEC_WINNT_AUTH_IDENTITY_EXW = *mut _SEC_WINNT_AUTH_IDENTITY_EXW;
+pub type PSEC_WINNT_AUTH_IDENTITY_OPAQUE = PVOID;
+unsafe extern "C" {
+    pub fn SspiEncodeAuthIdentityAsStrings(
+        pAuthIdentity: PSEC_WINNT_AUTH_IDENTITY_OPAQUE,
+        ppszUserName: *mut PCWSTR,
+        ppszDomainName: *mut PCWSTR,
+        ppszPackedCredentialsString: *mut PCWSTR,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiValidateAuthIdentity(AuthData: PSEC_WINNT_AUTH_IDENTITY_OPAQUE) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiCopyAuthIdentity(
+        AuthData: PSEC_WINNT_AUTH_IDENTITY_OPAQUE,
+        AuthDataCopy: *mut PSEC_WINNT_AUTH_IDENTITY_OPAQUE,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiFreeAuthIdentity(AuthData: PSEC_WINNT_AUTH_IDENTITY_OPAQUE);
+}
+unsafe extern "C" {
+    pub fn SspiZeroAuthIdentity(AuthData: PSEC_WINNT_AUTH_IDENTITY_OPAQUE);
+}
+unsafe extern "C" {
+    pub fn SspiLocalFree(DataBuffer: PVOID);
+}
+unsafe extern "C" {
+    pub fn SspiEncodeStringsAsAuthIdentity(
+        pszUserName: PCWSTR,
+        pszDomainName: PCWSTR,
+        pszPackedCredentialsString: PCWSTR,
+        ppAuthIdentity: *mut PSEC_WINNT_AUTH_IDENTITY_OPAQUE,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiCompareAuthIdentities(
+        AuthIdentity1: PSEC_WINNT_AUTH_IDENTITY_OPAQUE,
+        AuthIdentity2: PSEC_WINNT_AUTH_IDENTITY_OPAQUE,
+        SameSuppliedUser: PBOOLEAN,
+        SameSuppliedIdentity: PBOOLEAN,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiMarshalAuthIdentity(
+        AuthIdentity: PSEC_WINNT_AUTH_IDENTITY_OPAQUE,
+        AuthIdentityLength: *mut core::ffi::c_ulong,
+        AuthIdentityByteArray: *mut *mut core::ffi::c_char,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiUnmarshalAuthIdentity(
+        AuthIdentityLength: core::ffi::c_ulong,
+        AuthIdentityByteArray: *mut core::ffi::c_char,
+        ppAuthIdentity: *mut PSEC_WINNT_AUTH_IDENTITY_OPAQUE,
+    ) -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiIsPromptingNeeded(ErrorOrNtStatus: core::ffi::c_ulong) -> BOOLEAN;
+}
+unsafe extern "C" {
+    pub fn SspiGetTargetHostName(pszTargetName: PCWSTR, pszHostName: *mut PWSTR)
+                                 -> SECURITY_STATUS;
+}
+unsafe extern "C" {
+    pub fn SspiExcludePackage(
+        AuthIdentity: PSEC_WINNT_AUTH_IDENTITY_OPAQUE,
+        pszPackageName: PCWSTR,
+        ppNewAuthIdentity: *mut PSEC_WINNT_AUTH_IDENTITY_OPAQUE,
+    ) -> SECURITY_STATUS;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SECURITY_USER_DATA {
+    pub UserName: SECURITY_STRING,
+    pub LogonDomainName: SECURITY_STRING,
+    pub LogonServer: SECURITY_STRING,
+    pub pSid: PSID,
+}
+pub type SECURITY_USER_DATA = _SECURITY_USER_DATA;
+pub type PSECURITY_USER_DATA = *mut _SECURITY_USER_DATA;
+pub type SecurityUserData = SECURITY_USER_DATA;
+pub type PSecurityUserData = *mut SECURITY_USER_DATA;
+unsafe extern "C" {
+    pub fn GetSecurityUserInfo(
+        LogonId: PLUID,
+        Flags: ULONG,
+        UserInformation: *mut PSecurityUserData,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn MapSecurityError(SecStatus: SECURITY_STATUS) -> NTSTATUS;
+}
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _QUERY_PATH_REQUEST {
+    pub PathNameLength: ULONG,
+    pub SecurityContext: PIO_SECURITY_CONTEXT,
+    pub FilePathName: [WCHAR; 1usize],
+}
+pub type QUERY_PATH_REQUEST = _QUERY_PATH_REQUEST;
+pub type PQUERY_PATH_REQUEST = *mut _QUERY_PATH_REQUEST;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _QUERY_PATH_REQUEST_EX {
+    pub pSecurityContext: PIO_SECURITY_CONTEXT,
+    pub EaLength: ULONG,
+    pub pEaBuffer: PVOID,
+    pub PathName: UNICODE_STRING,
+    pub DomainServiceName: UNICODE_STRING,
+    pub EcpList: PECP_LIST,
+    pub Silo: PESILO,
+    pub Reserved: ULONG_PTR,
+}
+pub type QUERY_PATH_REQUEST_EX = _QUERY_PATH_REQUEST_EX;
+pub type PQUERY_PATH_REQUEST_EX = *mut _QUERY_PATH_REQUEST_EX;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _QUERY_PATH_RESPONSE {
+    pub LengthAccepted: ULONG,
+}
+pub type QUERY_PATH_RESPONSE = _QUERY_PATH_RESPONSE;
+pub type PQUERY_PATH_RESPONSE = *mut _QUERY_PATH_RESPONSE;
+unsafe extern "C" {
+    pub fn ZwQueryObject(
+        Handle: HANDLE,
+        ObjectInformationClass: OBJECT_INFORMATION_CLASS,
+        ObjectInformation: PVOID,
+        ObjectInformationLength: ULONG,
+        ReturnLength: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwNotifyChangeKey(
+        KeyHandle: HANDLE,
+        Event: HANDLE,
+        ApcRoutine: PIO_APC_ROUTINE,
+        ApcContext: PVOID,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        CompletionFilter: ULONG,
+        WatchTree: BOOLEAN,
+        Buffer: PVOID,
+        BufferSize: ULONG,
+        Asynchronous: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwCreateEvent(
+        EventHandle: PHANDLE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectAttributes: POBJECT_ATTRIBUTES,
+        EventType: EVENT_TYPE,
+        InitialState: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwDeleteFile(ObjectAttributes: POBJECT_ATTRIBUTES) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwQueryDirectoryFile(
+        FileHandle: HANDLE,
+        Event: HANDLE,
+        ApcRoutine: PIO_APC_ROUTINE,
+        ApcContext: PVOID,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        FileInformation: PVOID,
+        Length: ULONG,
+        FileInformationClass: FILE_INFORMATION_CLASS,
+        ReturnSingleEntry: BOOLEAN,
+        FileName: PUNICODE_STRING,
+        RestartScan: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwQueryDirectoryFileEx(
+        FileHandle: HANDLE,
+        Event: HANDLE,
+        ApcRoutine: PIO_APC_ROUTINE,
+        ApcContext: PVOID,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        FileInformation: PVOID,
+        Length: ULONG,
+        FileInformationClass: FILE_INFORMATION_CLASS,
+        QueryFlags: ULONG,
+        FileName: PUNICODE_STRING,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwSetVolumeInformationFile(
+        FileHandle: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        FsInformation: PVOID,
+        Length: ULONG,
+        FsInformationClass: FS_INFORMATION_CLASS,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwFsControlFile(
+        FileHandle: HANDLE,
+        Event: HANDLE,
+        ApcRoutine: PIO_APC_ROUTINE,
+        ApcContext: PVOID,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        FsControlCode: ULONG,
+        InputBuffer: PVOID,
+        InputBufferLength: ULONG,
+        OutputBuffer: PVOID,
+        OutputBufferLength: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwDuplicateObject(
+        SourceProcessHandle: HANDLE,
+        SourceHandle: HANDLE,
+        TargetProcessHandle: HANDLE,
+        TargetHandle: PHANDLE,
+        DesiredAccess: ACCESS_MASK,
+        HandleAttributes: ULONG,
+        Options: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwOpenDirectoryObject(
+        DirectoryHandle: PHANDLE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectAttributes: POBJECT_ATTRIBUTES,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwAllocateVirtualMemory(
+        ProcessHandle: HANDLE,
+        BaseAddress: *mut PVOID,
+        ZeroBits: ULONG_PTR,
+        RegionSize: PSIZE_T,
+        AllocationType: ULONG,
+        Protect: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwAllocateVirtualMemoryEx(
+        ProcessHandle: HANDLE,
+        BaseAddress: *mut PVOID,
+        RegionSize: PSIZE_T,
+        AllocationType: ULONG,
+        PageProtection: ULONG,
+        ExtendedParameters: PMEM_EXTENDED_PARAMETER,
+        ExtendedParameterCount: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwFreeVirtualMemory(
+        ProcessHandle: HANDLE,
+        BaseAddress: *mut PVOID,
+        RegionSize: PSIZE_T,
+        FreeType: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwQueryVirtualMemory(
+        ProcessHandle: HANDLE,
+        BaseAddress: PVOID,
+        MemoryInformationClass: MEMORY_INFORMATION_CLASS,
+        MemoryInformation: PVOID,
+        MemoryInformationLength: SIZE_T,
+        ReturnLength: PSIZE_T,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwSetInformationVirtualMemory(
+        ProcessHandle: HANDLE,
+        VmInformationClass: VIRTUAL_MEMORY_INFORMATION_CLASS,
+        NumberOfEntries: ULONG_PTR,
+        VirtualAddresses: PMEMORY_RANGE_ENTRY,
+        VmInformation: PVOID,
+        VmInformationLength: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwWaitForSingleObject(
+        Handle: HANDLE,
+        Alertable: BOOLEAN,
+        Timeout: PLARGE_INTEGER,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwSetEvent(EventHandle: HANDLE, PreviousState: PLONG) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwFlushVirtualMemory(
+        ProcessHandle: HANDLE,
+        BaseAddress: *mut PVOID,
+        RegionSize: PSIZE_T,
+        IoStatus: PIO_STATUS_BLOCK,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwOpenProcessTokenEx(
+        ProcessHandle: HANDLE,
+        DesiredAccess: ACCESS_MASK,
+        HandleAttributes: ULONG,
+        TokenHandle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwOpenThreadTokenEx(
+        ThreadHandle: HANDLE,
+        DesiredAccess: ACCESS_MASK,
+        OpenAsSelf: BOOLEAN,
+        HandleAttributes: ULONG,
+        TokenHandle: PHANDLE,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwQueryInformationToken(
+        TokenHandle: HANDLE,
+        TokenInformationClass: TOKEN_INFORMATION_CLASS,
+        TokenInformation: PVOID,
+        TokenInformationLength: ULONG,
+        ReturnLength: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwSetInformationToken(
+        TokenHandle: HANDLE,
+        TokenInformationClass: TOKEN_INFORMATION_CLASS,
+        TokenInformation: PVOID,
+        TokenInformationLength: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwSetSecurityObject(
+        Handle: HANDLE,
+        SecurityInformation: SECURITY_INFORMATION,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwQuerySecurityObject(
+        Handle: HANDLE,
+        SecurityInformation: SECURITY_INFORMATION,
+        SecurityDescriptor: PSECURITY_DESCRIPTOR,
+        Length: ULONG,
+        LengthNeeded: PULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwLockFile(
+        FileHandle: HANDLE,
+        Event: HANDLE,
+        ApcRoutine: PIO_APC_ROUTINE,
+        ApcContext: PVOID,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        ByteOffset: PLARGE_INTEGER,
+        Length: PLARGE_INTEGER,
+        Key: ULONG,
+        FailImmediately: BOOLEAN,
+        ExclusiveLock: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwUnlockFile(
+        FileHandle: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        ByteOffset: PLARGE_INTEGER,
+        Length: PLARGE_INTEGER,
+        Key: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwQueryQuotaInformationFile(
+        FileHandle: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        Buffer: PVOID,
+        Length: ULONG,
+        ReturnSingleEntry: BOOLEAN,
+        SidList: PVOID,
+        SidListLength: ULONG,
+        StartSid: PSID,
+        RestartScan: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwSetQuotaInformationFile(
+        FileHandle: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        Buffer: PVOID,
+        Length: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwFlushBuffersFile(FileHandle: HANDLE, IoStatusBlock: PIO_STATUS_BLOCK) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwFlushBuffersFileEx(
+        FileHandle: HANDLE,
+        FLags: ULONG,
+        Parameters: PVOID,
+        ParametersSize: ULONG,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwQueryEaFile(
+        FileHandle: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        Buffer: PVOID,
+        Length: ULONG,
+        ReturnSingleEntry: BOOLEAN,
+        EaList: PVOID,
+        EaListLength: ULONG,
+        EaIndex: PULONG,
+        RestartScan: BOOLEAN,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwSetEaFile(
+        FileHandle: HANDLE,
+        IoStatusBlock: PIO_STATUS_BLOCK,
+        Buffer: PVOID,
+        Length: ULONG,
+    ) -> NTSTATUS;
+}
+unsafe extern "C" {
+    pub fn ZwDuplicateToken(
+        ExistingTokenHandle: HANDLE,
+        DesiredAccess: ACCESS_MASK,
+        ObjectAttributes: POBJECT_ATTRIBUTES,
+        EffectiveOnly: BOOLEAN,
+        TokenType: TOKEN_TYPE,
+        NewTokenHandle: PHANDLE,
+    ) -> NTSTATUS;
+}
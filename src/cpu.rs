@@ -0,0 +1,162 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::ptr;
+use crate::*;
+use crate::irql::{self, AtDispatch, Irql, Passive, DISPATCH_LEVEL, PASSIVE_LEVEL};
+
+
+/// Active logical processors across all processor groups.
+pub fn active_count() -> u32 {
+    unsafe { KeQueryActiveProcessorCountEx(ALL_PROCESSOR_GROUPS as _) }
+}
+
+/// Processors the system can ever have, including hot-add capacity.
+pub fn max_count() -> u32 {
+    unsafe { KeQueryMaximumProcessorCountEx(ALL_PROCESSOR_GROUPS as _) }
+}
+
+/// System-wide index of the processor this runs on. Only stable at DISPATCH_LEVEL or above.
+pub fn current_index() -> u32 {
+    unsafe { KeGetCurrentProcessorNumberEx(ptr::null_mut()) }
+}
+
+/// Group and group-relative number of processor `index`.
+pub fn processor_number(index: u32) -> Result<PROCESSOR_NUMBER, NtStatus> {
+    let mut number: PROCESSOR_NUMBER = unsafe { core::mem::zeroed() };
+    NtStatus::from_raw(unsafe { KeGetProcessorNumberFromIndex(index, &mut number) }).into_result()?;
+    Ok(number)
+}
+
+
+/// Runs `f` once on every active processor, in index order, by pinning the current thread to
+/// each one with `KeSetSystemGroupAffinityThread`. `f` runs at PASSIVE_LEVEL and may block, but
+/// stays on that processor. Results are indexed by processor.
+pub fn for_each_cpu<T, F>(mut f: F, _irql: &Passive) -> Result<Vec<T>, NtStatus>
+where
+    F: FnMut(u32) -> T,
+{
+    irql::debug_assert_at_most(PASSIVE_LEVEL);
+    let count = active_count();
+    let mut results = Vec::with_capacity(count as usize);
+    let mut previous: GROUP_AFFINITY = unsafe { core::mem::zeroed() };
+
+    for index in 0..count {
+        let number = match processor_number(index) {
+            Ok(number) => number,
+            Err(status) => {
+                if index != 0 {
+                    unsafe { KeRevertToUserGroupAffinityThread(&mut previous) };
+                }
+                return Err(status);
+            }
+        };
+        let mut affinity = GROUP_AFFINITY { Mask: 1 << number.Number, Group: number.Group, Reserved: [0; 3] };
+        // only the first call's previous affinity is the one to restore
+        let previous_ptr = if index == 0 { &mut previous as *mut _ } else { ptr::null_mut() };
+        unsafe { KeSetSystemGroupAffinityThread(&mut affinity, previous_ptr) };
+        results.push(f(index));
+    }
+
+    if count != 0 {
+        unsafe { KeRevertToUserGroupAffinityThread(&mut previous) };
+    }
+    Ok(results)
+}
+
+
+struct Broadcast<'a, T, F> {
+    f: &'a F,
+    slots: &'a [UnsafeCell<Option<T>>],
+}
+
+unsafe extern "C" fn broadcast<T, F: Fn(u32) -> T>(context: ULONG_PTR) -> ULONG_PTR {
+    let broadcast = unsafe { &*(context as *const Broadcast<T, F>) };
+    let index = current_index();
+    // each processor writes only its own slot
+    if let Some(slot) = broadcast.slots.get(index as usize) {
+        unsafe { *slot.get() = Some((broadcast.f)(index)) };
+    }
+    0
+}
+
+/// Runs `f` on every active processor at the same time with `KeIpiGenericCall`, at IPI_LEVEL
+/// with interrupts disabled; use it for reading MSRs or control registers. Callable at
+/// IRQL <= DISPATCH_LEVEL.
+///
+/// The result has a slot for each of `max_count()` processors, indexed by processor; slots of
+/// processors that are not active are `None`.
+///
+/// # Safety
+///
+/// `f` runs at IPI_LEVEL on all processors at once: it must not allocate or free, touch paged
+/// memory, take locks or wait, call anything that requires a lower IRQL, or panic. Dropping its
+/// `T` happens later, at the caller's IRQL.
+pub unsafe fn for_each_cpu_ipi<T, F>(f: F, _irql: &impl Irql) -> Vec<Option<T>>
+where
+    F: Fn(u32) -> T + Sync,
+    T: Send,
+{
+    irql::debug_assert_at_most(DISPATCH_LEVEL);
+    // sized for hot-added processors too, so a late arrival still has a slot
+    let slots: Vec<UnsafeCell<Option<T>>> = (0..max_count()).map(|_| UnsafeCell::new(None)).collect();
+    let context = Broadcast { f: &f, slots: &slots };
+    unsafe { KeIpiGenericCall(Some(broadcast::<T, F>), &context as *const _ as ULONG_PTR) };
+    slots.into_iter().map(UnsafeCell::into_inner).collect()
+}
+
+
+/// One `T` per possible processor, indexed by `KeGetCurrentProcessorNumberEx`.
+///
+/// Values are shared, so mutation goes through atomics or other interior mutability; at
+/// DISPATCH_LEVEL the current processor's slot is not touched by anything else on it.
+pub struct PerCpu<T> {
+    slots: Box<[T]>,
+}
+
+impl<T> PerCpu<T> {
+    /// Builds a slot for each of `max_count()` processors.
+    pub fn new(mut init: impl FnMut(u32) -> T) -> Self {
+        PerCpu { slots: (0..max_count()).map(&mut init).collect() }
+    }
+
+    /// The slot of the processor this runs on. Being at DISPATCH_LEVEL keeps the thread from
+    /// migrating while the slot is borrowed.
+    pub fn current<'a>(&'a self, _pinned: &'a impl AtDispatch) -> &'a T {
+        &self.slots[current_index() as usize]
+    }
+
+    pub fn get(&self, index: u32) -> Option<&T> {
+        self.slots.get(index as usize)
+    }
+
+    pub fn get_mut(&mut self, index: u32) -> Option<&mut T> {
+        self.slots.get_mut(index as usize)
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.slots.iter()
+    }
+}
+
+impl<T: Default> Default for PerCpu<T> {
+    fn default() -> Self {
+        PerCpu::new(|_| T::default())
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PerCpu<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.slots.iter()
+    }
+}
@@ -0,0 +1,234 @@
+use alloc::boxed::Box;
+use core::cell::UnsafeCell;
+use core::hint;
+use core::mem::ManuallyDrop;
+use core::ptr;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use crate::*;
+use crate::irp::{Completion, Irp, PendingIrp};
+use crate::irql::{self, Irql, DISPATCH_LEVEL};
+
+
+/// A pending-IRP queue on top of `IoCsq*`, which owns the cancel routine and its races with
+/// removal. Each queued IRP carries a key `K`, e.g. what a pending request is waiting for.
+///
+/// The typical use is inverted call: user mode keeps a few `IOCTL`s pending, the dispatch
+/// routine [`insert`](Self::insert)s them, and an event later [`remove_next`](Self::remove_next)s
+/// one and completes it with the data. A cancelled IRP is completed with `STATUS_CANCELLED`
+/// without the driver seeing it again. Dropping the queue cancels whatever is still queued.
+pub struct CancelSafeQueue<K: Send + 'static = ()> {
+    inner: Box<Inner<K>>,
+}
+
+// `csq` must stay first: the callbacks get a `PIO_CSQ` and cast it back to the whole `Inner`
+#[repr(C)]
+struct Inner<K> {
+    csq: UnsafeCell<IO_CSQ>,
+    lock: UnsafeCell<KSPIN_LOCK>,
+    head: UnsafeCell<LIST_ENTRY>,
+    // inserted IRPs not yet handed back or completed as cancelled
+    outstanding: AtomicUsize,
+    _key: core::marker::PhantomData<K>,
+}
+
+unsafe impl<K: Send> Send for CancelSafeQueue<K> {}
+unsafe impl<K: Send> Sync for CancelSafeQueue<K> {}
+
+type Predicate<'a, K> = &'a mut dyn FnMut(&Irp, &K) -> bool;
+
+impl<K: Send + 'static> CancelSafeQueue<K> {
+    pub fn new() -> Self {
+        let inner = Box::new(Inner {
+            csq: UnsafeCell::new(unsafe { core::mem::zeroed() }),
+            lock: UnsafeCell::new(0),
+            head: UnsafeCell::new(LIST_ENTRY { Flink: ptr::null_mut(), Blink: ptr::null_mut() }),
+            outstanding: AtomicUsize::new(0),
+            _key: core::marker::PhantomData,
+        });
+        unsafe {
+            let head = inner.head.get();
+            (*head).Flink = head;
+            (*head).Blink = head;
+            // only fails for a null callback
+            IoCsqInitializeEx(inner.csq.get(), Some(insert_irp::<K>), Some(remove_irp), Some(peek_next_irp::<K>),
+                              Some(acquire_lock::<K>), Some(release_lock::<K>), Some(complete_canceled_irp::<K>));
+        }
+        CancelSafeQueue { inner }
+    }
+
+    fn csq(&self) -> PIO_CSQ {
+        self.inner.csq.get()
+    }
+
+    /// Queues `irp` as pending and returns the completion for the dispatch routine. If it was
+    /// already cancelled it is completed right away. IRQL <= DISPATCH_LEVEL.
+    pub fn insert<'irp>(&self, irp: Irp<'irp>, key: K) -> Completion<'irp> {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
+        let raw = irp.into_raw();
+        unsafe {
+            set_key(raw, Box::into_raw(Box::new(key)));
+            self.inner.outstanding.fetch_add(1, Ordering::AcqRel);
+            // marks the IRP pending on success, and completes it through the callback if it
+            // was cancelled before the cancel routine got set
+            let status = IoCsqInsertIrpEx(self.csq(), raw, ptr::null_mut(), ptr::null_mut());
+            if let Err(status) = NtStatus::check(status) {
+                self.inner.outstanding.fetch_sub(1, Ordering::AcqRel);
+                drop(take_key::<K>(raw));
+                return Irp::from_raw(raw).complete(status);
+            }
+        }
+        Completion::pending()
+    }
+
+    /// Removes the oldest IRP. It was marked pending when queued, so it comes back as a
+    /// [`PendingIrp`]. IRQL <= DISPATCH_LEVEL.
+    pub fn remove_next(&self, _irql: &impl Irql) -> Option<(PendingIrp, K)> {
+        self.remove(ptr::null_mut())
+    }
+
+    /// Removes the oldest IRP for which `matches` returns true; the peek context of `IoCsq`.
+    /// `matches` runs with the queue's spin lock held, at DISPATCH_LEVEL.
+    pub fn remove_next_where(&self, mut matches: impl FnMut(&Irp, &K) -> bool, _irql: &impl Irql) -> Option<(PendingIrp, K)> {
+        let mut matches: Predicate<K> = &mut matches;
+        self.remove(&mut matches as *mut Predicate<K> as PVOID)
+    }
+
+    fn remove(&self, peek_context: PVOID) -> Option<(PendingIrp, K)> {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
+        let raw = unsafe { IoCsqRemoveNextIrp(self.csq(), peek_context) };
+        if raw.is_null() {
+            return None;
+        }
+        self.inner.outstanding.fetch_sub(1, Ordering::AcqRel);
+        unsafe { Some((PendingIrp::from_raw(raw), *take_key::<K>(raw))) }
+    }
+
+    /// Completes every queued IRP matching `matches` with `STATUS_CANCELLED`, e.g. those of a
+    /// file object in `IRP_MJ_CLEANUP`. Returns how many were cancelled.
+    pub fn cancel_where(&self, mut matches: impl FnMut(&Irp, &K) -> bool, _irql: &impl Irql) -> usize {
+        self.cancel(&mut matches)
+    }
+
+    /// Completes every queued IRP with `STATUS_CANCELLED`.
+    pub fn cancel_all(&self, _irql: &impl Irql) -> usize {
+        self.cancel(&mut |_, _| true)
+    }
+
+    fn cancel(&self, mut matches: Predicate<K>) -> usize {
+        let mut count = 0;
+        while let Some((irp, _)) = self.remove(&mut matches as *mut Predicate<K> as PVOID) {
+            irp.complete(NtStatus::CANCELLED);
+            count += 1;
+        }
+        count
+    }
+}
+
+impl<K: Send + 'static> Default for CancelSafeQueue<K> {
+    fn default() -> Self {
+        CancelSafeQueue::new()
+    }
+}
+
+impl<K: Send + 'static> Drop for CancelSafeQueue<K> {
+    fn drop(&mut self) {
+        self.cancel(&mut |_, _| true);
+        // a cancel routine on another processor may still be between dequeuing its IRP and
+        // calling back into `complete_canceled_irp` with a pointer to `inner`
+        while self.inner.outstanding.load(Ordering::Acquire) != 0 {
+            hint::spin_loop();
+        }
+    }
+}
+
+
+// the key lives in `DriverContext[0]`; `IoCsq` itself uses `DriverContext[3]`
+unsafe fn driver_context(irp: PIRP) -> *mut [PVOID; 4] {
+    unsafe { &raw mut (*irp).Tail.Overlay.__bindgen_anon_1.__bindgen_anon_1.DriverContext }
+}
+
+unsafe fn set_key<K>(irp: PIRP, key: *mut K) {
+    unsafe { (*driver_context(irp))[0] = key as PVOID };
+}
+
+unsafe fn take_key<K>(irp: PIRP) -> Box<K> {
+    unsafe {
+        let key = (*driver_context(irp))[0] as *mut K;
+        (*driver_context(irp))[0] = ptr::null_mut();
+        Box::from_raw(key)
+    }
+}
+
+unsafe fn list_entry(irp: PIRP) -> *mut LIST_ENTRY {
+    unsafe { &raw mut (*irp).Tail.Overlay.__bindgen_anon_2.ListEntry }
+}
+
+unsafe fn irp_of(entry: *mut LIST_ENTRY) -> PIRP {
+    unsafe { (entry as *mut u8).sub(core::mem::offset_of!(IRP, Tail.Overlay.__bindgen_anon_2.ListEntry)) as PIRP }
+}
+
+unsafe fn inner<'a, K>(csq: PIO_CSQ) -> &'a Inner<K> {
+    unsafe { &*(csq as *const Inner<K>) }
+}
+
+
+unsafe extern "C" fn insert_irp<K>(csq: *mut IO_CSQ, irp: PIRP, _context: PVOID) -> NTSTATUS {
+    unsafe {
+        let head = inner::<K>(csq).head.get();
+        let entry = list_entry(irp);
+        (*entry).Flink = head;
+        (*entry).Blink = (*head).Blink;
+        (*(*head).Blink).Flink = entry;
+        (*head).Blink = entry;
+    }
+    STATUS_SUCCESS
+}
+
+unsafe extern "C" fn remove_irp(_csq: PIO_CSQ, irp: PIRP) {
+    unsafe {
+        let entry = list_entry(irp);
+        (*(*entry).Blink).Flink = (*entry).Flink;
+        (*(*entry).Flink).Blink = (*entry).Blink;
+        (*entry).Flink = entry;
+        (*entry).Blink = entry;
+    }
+}
+
+unsafe extern "C" fn peek_next_irp<K>(csq: PIO_CSQ, irp: PIRP, peek_context: PVOID) -> PIRP {
+    unsafe {
+        let head = inner::<K>(csq).head.get();
+        let mut entry = if irp.is_null() { (*head).Flink } else { (*list_entry(irp)).Flink };
+        while entry != head {
+            let next = irp_of(entry);
+            if peek_context.is_null() {
+                return next;
+            }
+            let matches = &mut *(peek_context as *mut Predicate<K>);
+            let key = &*((*driver_context(next))[0] as *const K);
+            // borrowed for the predicate only; the queue still owns it
+            let borrowed = ManuallyDrop::new(Irp::from_raw(next));
+            if matches(&borrowed, key) {
+                return next;
+            }
+            entry = (*entry).Flink;
+        }
+        ptr::null_mut()
+    }
+}
+
+unsafe extern "C" fn acquire_lock<K>(csq: PIO_CSQ, irql: PKIRQL) {
+    unsafe { *irql = KeAcquireSpinLockRaiseToDpc(inner::<K>(csq).lock.get()) };
+}
+
+unsafe extern "C" fn release_lock<K>(csq: PIO_CSQ, irql: KIRQL) {
+    unsafe { KeReleaseSpinLock(inner::<K>(csq).lock.get(), irql) };
+}
+
+unsafe extern "C" fn complete_canceled_irp<K>(csq: PIO_CSQ, irp: PIRP) {
+    unsafe {
+        drop(take_key::<K>(irp));
+        PendingIrp::from_raw(irp).complete_with(NtStatus::CANCELLED, 0);
+        // last touch of `inner`; `Drop` may free it once this reaches zero
+        inner::<K>(csq).outstanding.fetch_sub(1, Ordering::AcqRel);
+    }
+}
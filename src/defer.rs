@@ -0,0 +1,177 @@
+use alloc::alloc::{alloc, dealloc, Layout};
+use alloc::boxed::Box;
+use core::ptr;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use core::time::Duration;
+use crate::*;
+use crate::irql::{self, Dispatch, Passive, DISPATCH_LEVEL, PASSIVE_LEVEL};
+
+
+/// Deferred closures that have been queued but not yet finished running, and requests sent
+/// through [`io_target`](crate::io_target) whose completion routine has not returned.
+static PENDING: AtomicUsize = AtomicUsize::new(0);
+
+pub(crate) struct Pending;
+
+impl Pending {
+    pub(crate) fn new() -> Self {
+        PENDING.fetch_add(1, Ordering::AcqRel);
+        Pending
+    }
+}
+
+impl Drop for Pending {
+    fn drop(&mut self) {
+        PENDING.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Number of work items, DPCs and lower-driver requests in flight.
+pub fn pending() -> usize {
+    PENDING.load(Ordering::Acquire)
+}
+
+/// Waits until every deferred closure has run and every lower-driver request has completed,
+/// then `KeFlushQueuedDpcs` so no DPC routine is still on its way out of the driver image. Call
+/// it from the unload routine, after whatever queues new work has been stopped.
+pub fn flush(irql: &Passive) {
+    irql::debug_assert_at_most(PASSIVE_LEVEL);
+    while PENDING.load(Ordering::Acquire) != 0 {
+        sync::sleep(Duration::from_millis(1), irql);
+    }
+    unsafe { KeFlushQueuedDpcs() };
+}
+
+
+/// The object a work item is allocated for. The I/O manager references it while the item is
+/// queued, so the driver cannot unload under a running closure.
+#[derive(Copy, Clone)]
+pub enum IoObject {
+    Device(PDEVICE_OBJECT),
+    Driver(PDRIVER_OBJECT),
+}
+
+impl IoObject {
+    fn as_raw(self) -> PVOID {
+        match self {
+            IoObject::Device(device) => device as PVOID,
+            IoObject::Driver(driver) => driver as PVOID,
+        }
+    }
+}
+
+impl From<PDEVICE_OBJECT> for IoObject {
+    fn from(device: PDEVICE_OBJECT) -> Self {
+        IoObject::Device(device)
+    }
+}
+
+impl From<PDRIVER_OBJECT> for IoObject {
+    fn from(driver: PDRIVER_OBJECT) -> Self {
+        IoObject::Driver(driver)
+    }
+}
+
+fn work_item_layout() -> Layout {
+    Layout::from_size_align(unsafe { IoSizeofWorkItem() } as usize, 16).unwrap()
+}
+
+struct Work<F> {
+    f: F,
+    from: IoObject,
+    _pending: Pending,
+}
+
+unsafe extern "C" fn work_routine<F: FnOnce(&Passive)>(_object: PVOID, context: PVOID, item: PIO_WORKITEM) {
+    let work = unsafe { Box::from_raw(context as *mut Work<F>) };
+    let from = work.from;
+    let irql = unsafe { Passive::assume() };
+    (work.f)(&irql);
+    // the I/O manager holds its reference on the device/driver until this routine returns
+    unsafe {
+        match from {
+            IoObject::Device(_) => IoFreeWorkItem(item),
+            IoObject::Driver(_) => {
+                IoUninitializeWorkItem(item);
+                dealloc(item as *mut u8, work_item_layout());
+            }
+        }
+    }
+}
+
+/// Runs `f` at PASSIVE_LEVEL on a system worker thread (`IoQueueWorkItemEx`, delayed queue).
+/// Callable at IRQL <= DISPATCH_LEVEL.
+pub fn to_passive<F>(io_object: impl Into<IoObject>, f: F) -> Result<(), NtStatus>
+where
+    F: FnOnce(&Passive) + Send + 'static,
+{
+    irql::debug_assert_at_most(DISPATCH_LEVEL);
+    let from = io_object.into();
+    let item = unsafe {
+        match from {
+            IoObject::Device(device) => IoAllocateWorkItem(device),
+            IoObject::Driver(driver) => {
+                let item = alloc(work_item_layout()) as PIO_WORKITEM;
+                if !item.is_null() {
+                    IoInitializeWorkItem(driver as _, item);
+                }
+                item
+            }
+        }
+    };
+    if item.is_null() {
+        return Err(NtStatus::INSUFFICIENT_RESOURCES);
+    }
+
+    let work = Box::into_raw(Box::new(Work { f, from, _pending: Pending::new() }));
+    unsafe { IoQueueWorkItemEx(item, Some(work_routine::<F>), _WORK_QUEUE_TYPE_DelayedWorkQueue, work as _) };
+    Ok(())
+}
+
+
+struct DpcWork<F> {
+    dpc: KDPC,
+    f: F,
+    from: IoObject,
+    _pending: Pending,
+}
+
+unsafe extern "C" fn dpc_routine<F: FnOnce(&Dispatch)>(_dpc: *mut KDPC, context: PVOID, _arg1: PVOID, _arg2: PVOID) {
+    // the DPC is dequeued before its routine runs, so the box can go with it
+    let work = unsafe { Box::from_raw(context as *mut DpcWork<F>) };
+    let DpcWork { f, from, _pending, .. } = *work;
+    let irql = unsafe { Dispatch::assume() };
+    f(&irql);
+    unsafe { ObfDereferenceObject(from.as_raw()) };
+}
+
+/// Runs `f` in a DPC at DISPATCH_LEVEL, on processor index `target_cpu` or on the current one.
+/// The device or driver stays referenced until `f` has run, and [`flush`] waits for it like for
+/// a work item. Callable at IRQL <= DISPATCH_LEVEL.
+pub fn to_dpc<F>(io_object: impl Into<IoObject>, f: F, target_cpu: Option<u32>) -> Result<(), NtStatus>
+where
+    F: FnOnce(&Dispatch) + Send + 'static,
+{
+    irql::debug_assert_at_most(DISPATCH_LEVEL);
+    let from = io_object.into();
+    let mut target: PROCESSOR_NUMBER = unsafe { core::mem::zeroed() };
+    if let Some(index) = target_cpu {
+        NtStatus::from_raw(unsafe { KeGetProcessorNumberFromIndex(index, &mut target) }).into_result()?;
+    }
+
+    let work = Box::into_raw(Box::new(DpcWork { dpc: unsafe { core::mem::zeroed() }, f, from, _pending: Pending::new() }));
+    unsafe {
+        let dpc = &raw mut (*work).dpc;
+        KeInitializeDpc(dpc, Some(dpc_routine::<F>), work as _);
+        if target_cpu.is_some() {
+            let status = KeSetTargetProcessorDpcEx(dpc, &mut target);
+            if !NT_SUCCESS(status) {
+                drop(Box::from_raw(work));
+                return Err(NtStatus::from_raw(status));
+            }
+        }
+        ObfReferenceObject(from.as_raw());
+        KeInsertQueueDpc(dpc, ptr::null_mut(), ptr::null_mut());
+    }
+    Ok(())
+}
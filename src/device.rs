@@ -0,0 +1,176 @@
+use core::marker::PhantomData;
+use core::ptr;
+use crate::*;
+use crate::driver::DriverObject;
+use crate::irql::{self, Passive, PASSIVE_LEVEL};
+use crate::ustr::{OwnedUnicodeString, UnicodeCow, UnicodeStr};
+
+
+/// Options for `IoCreateDevice`, then [`DeviceBuilder::build`].
+pub struct DeviceBuilder<'a> {
+    name: Option<UnicodeCow<'a>>,
+    link: Option<UnicodeCow<'a>>,
+    sddl: Option<&'a str>,
+    device_type: u32,
+    characteristics: u32,
+    exclusive: bool,
+    flags: u32,
+}
+
+impl<'a> DeviceBuilder<'a> {
+    /// A device with the full object name `name`, e.g. `\Device\MyDevice`; no prefix is added.
+    /// Defaults to `FILE_DEVICE_UNKNOWN`, `FILE_DEVICE_SECURE_OPEN` and buffered I/O.
+    pub fn new(name: impl Into<UnicodeCow<'a>>) -> Self {
+        DeviceBuilder {
+            name: Some(name.into()),
+            link: None,
+            sddl: None,
+            device_type: FILE_DEVICE_UNKNOWN,
+            characteristics: FILE_DEVICE_SECURE_OPEN,
+            exclusive: false,
+            flags: DO_BUFFERED_IO,
+        }
+    }
+
+    /// An unnamed device, e.g. a filter attached to another stack.
+    pub fn unnamed() -> Self {
+        DeviceBuilder { name: None, ..DeviceBuilder::new(UnicodeStr::default()) }
+    }
+
+    pub fn device_type(mut self, device_type: u32) -> Self {
+        self.device_type = device_type;
+        self
+    }
+
+    /// `FILE_DEVICE_*` characteristics, replacing the default `FILE_DEVICE_SECURE_OPEN`.
+    pub fn characteristics(mut self, characteristics: u32) -> Self {
+        self.characteristics = characteristics;
+        self
+    }
+
+    /// Only one handle may be open at a time.
+    pub fn exclusive(mut self, exclusive: bool) -> Self {
+        self.exclusive = exclusive;
+        self
+    }
+
+    /// `DO_*` flags set on the device object, replacing the default `DO_BUFFERED_IO`.
+    pub fn flags(mut self, flags: u32) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// DACL applied before the device can be opened, in the SDDL subset `IoCreateDeviceSecure`
+    /// accepts, e.g. `D:P(A;;GA;;;SY)(A;;GA;;;BA)` for SYSTEM and administrators only.
+    pub fn sddl(mut self, sddl: &'a str) -> Self {
+        self.sddl = Some(sddl);
+        self
+    }
+
+    /// `IoCreateSymbolicLink` to the device, e.g. `\??\MyDevice`, deleted with the device.
+    pub fn symbolic_link(mut self, link: impl Into<UnicodeCow<'a>>) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+
+    /// Creates the device with `extension` as its device extension.
+    pub fn build<T: Send + Sync>(self, driver: &DriverObject, extension: T, _irql: &Passive) -> Result<Device<T>, NtStatus> {
+        irql::debug_assert_at_most(PASSIVE_LEVEL);
+        if align_of::<T>() > MEMORY_ALLOCATION_ALIGNMENT as usize || (self.link.is_some() && self.name.is_none()) {
+            return Err(NtStatus::INVALID_PARAMETER);
+        }
+        let security = self.sddl.map(sddl::parse).transpose()?;
+
+        let mut name = match &self.name {
+            Some(name) => Some(name.as_ustr().as_unicode_string()?),
+            None => None,
+        };
+        let name_ptr = name.as_mut().map_or(ptr::null_mut(), |n| n as *mut UNICODE_STRING);
+        let mut raw: PDEVICE_OBJECT = ptr::null_mut();
+        NtStatus::from_raw(unsafe {
+            IoCreateDevice(driver.as_raw(), size_of::<T>() as _, name_ptr, self.device_type, self.characteristics,
+                           self.exclusive as _, &mut raw)
+        }).into_result()?;
+
+        unsafe { ptr::write(extension_ptr(raw), extension) };
+        // from here on dropping `device` deletes the object
+        let mut device = Device { raw, link: None, _extension: PhantomData };
+
+        if let Some(mut security) = security {
+            NtStatus::from_raw(unsafe {
+                ObSetSecurityObjectByPointer(raw as _, DACL_SECURITY_INFORMATION, security.as_mut_ptr() as _)
+            }).into_result()?;
+        }
+
+        if let (Some(link), Some(name)) = (&self.link, name.as_mut()) {
+            let link = link.as_ustr().to_owned();
+            let mut link_us = link.as_unicode_string()?;
+            NtStatus::from_raw(unsafe { IoCreateSymbolicLink(&mut link_us, name) }).into_result()?;
+            device.link = Some(link);
+        }
+
+        // the I/O manager refuses opens until DO_DEVICE_INITIALIZING is cleared
+        unsafe { (*raw).Flags = ((*raw).Flags | self.flags) & !DO_DEVICE_INITIALIZING };
+        Ok(device)
+    }
+}
+
+
+// a zero-sized extension gets no allocation, and DeviceExtension is null
+unsafe fn extension_ptr<T>(device: PDEVICE_OBJECT) -> *mut T {
+    if size_of::<T>() == 0 {
+        ptr::NonNull::dangling().as_ptr()
+    } else {
+        unsafe { (*device).DeviceExtension as *mut T }
+    }
+}
+
+
+/// A device object owning a `T` in its device extension. Dropping it deletes the symbolic link,
+/// drops the extension and calls `IoDeleteDevice`, at PASSIVE_LEVEL.
+pub struct Device<T> {
+    raw: PDEVICE_OBJECT,
+    link: Option<OwnedUnicodeString>,
+    _extension: PhantomData<T>,
+}
+
+unsafe impl<T: Send + Sync> Send for Device<T> {}
+unsafe impl<T: Send + Sync> Sync for Device<T> {}
+
+impl<T> Device<T> {
+    pub fn as_raw(&self) -> PDEVICE_OBJECT {
+        self.raw
+    }
+
+    pub fn extension(&self) -> &T {
+        unsafe { &*extension_ptr(self.raw) }
+    }
+
+    /// The extension of a device created by a `Device<T>`, e.g. in a dispatch routine.
+    ///
+    /// # Safety
+    ///
+    /// `device` must have been built with the same `T` and not be deleted yet.
+    pub unsafe fn extension_of<'a>(device: PDEVICE_OBJECT) -> &'a T {
+        unsafe { &*extension_ptr(device) }
+    }
+
+    pub fn symbolic_link(&self) -> Option<UnicodeStr<'_>> {
+        self.link.as_ref().map(|l| l.as_ustr())
+    }
+}
+
+impl<T> Drop for Device<T> {
+    fn drop(&mut self) {
+        irql::debug_assert_at_most(PASSIVE_LEVEL);
+        unsafe {
+            if let Some(link) = &self.link
+                && let Ok(mut link) = link.as_unicode_string()
+            {
+                IoDeleteSymbolicLink(&mut link);
+            }
+            ptr::drop_in_place(extension_ptr::<T>(self.raw));
+            IoDeleteDevice(self.raw);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use core::sync::atomic::{AtomicPtr, Ordering};
+use crate::*;
+use crate::device::Device;
+use crate::driver::DriverObject;
+use crate::irp::{Completion, Irp};
+
+
+/// A dispatch routine: the device extension of the target device and the IRP to complete.
+pub type Handler<T> = for<'irp> fn(&T, Irp<'irp>) -> Completion<'irp>;
+
+const MAJOR_COUNT: usize = IRP_MJ_MAXIMUM_FUNCTION + 1;
+
+// the installed table, type-erased; the trampoline is instantiated for its `T`
+static TABLE: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+
+/// Routes each `IRP_MJ_*` to a handler. Build it in a `static` and [`install`](Self::install) it
+/// from `DriverEntry`; major functions without a handler keep the I/O manager's default, which
+/// fails the request with `STATUS_INVALID_DEVICE_REQUEST`.
+///
+/// Every device of the driver must have been created as a `Device<T>` with the same `T`; see
+/// [`install`](Self::install).
+pub struct DispatchTable<T: 'static> {
+    handlers: [Option<Handler<T>>; MAJOR_COUNT],
+}
+
+impl<T: Sync + 'static> DispatchTable<T> {
+    pub const fn new() -> Self {
+        DispatchTable { handlers: [None; MAJOR_COUNT] }
+    }
+
+    /// Handler for any major function code.
+    pub const fn on(mut self, major: u32, handler: Handler<T>) -> Self {
+        self.handlers[major as usize] = Some(handler);
+        self
+    }
+
+    pub const fn create(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_CREATE, handler)
+    }
+
+    pub const fn close(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_CLOSE, handler)
+    }
+
+    pub const fn cleanup(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_CLEANUP, handler)
+    }
+
+    pub const fn read(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_READ, handler)
+    }
+
+    pub const fn write(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_WRITE, handler)
+    }
+
+    pub const fn device_control(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_DEVICE_CONTROL, handler)
+    }
+
+    pub const fn internal_device_control(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_INTERNAL_DEVICE_CONTROL, handler)
+    }
+
+    pub const fn flush_buffers(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_FLUSH_BUFFERS, handler)
+    }
+
+    pub const fn query_information(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_QUERY_INFORMATION, handler)
+    }
+
+    pub const fn set_information(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_SET_INFORMATION, handler)
+    }
+
+    pub const fn shutdown(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_SHUTDOWN, handler)
+    }
+
+    pub const fn power(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_POWER, handler)
+    }
+
+    pub const fn system_control(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_SYSTEM_CONTROL, handler)
+    }
+
+    pub const fn pnp(self, handler: Handler<T>) -> Self {
+        self.on(IRP_MJ_PNP, handler)
+    }
+
+    /// Points `MajorFunction[]` of the driver at this table.
+    ///
+    /// # Safety
+    ///
+    /// Every device object of the driver that can receive an IRP must have been created as a
+    /// `Device<T>`, since the handlers get its extension cast to `T`. It must be the only table
+    /// the driver installs: the dispatch routine reads the table back as a `DispatchTable<T>`.
+    pub unsafe fn install(&'static self, driver: &mut DriverObject) {
+        TABLE.store(self as *const Self as *mut (), Ordering::Release);
+        let raw = driver.as_raw();
+        for (major, handler) in self.handlers.iter().enumerate() {
+            if handler.is_some() {
+                unsafe { (*raw).MajorFunction[major] = Some(dispatch::<T>) };
+            }
+        }
+    }
+}
+
+impl<T: Sync + 'static> Default for DispatchTable<T> {
+    fn default() -> Self {
+        DispatchTable::new()
+    }
+}
+
+
+unsafe extern "C" fn dispatch<T: Sync + 'static>(device: PDEVICE_OBJECT, irp: PIRP) -> NTSTATUS {
+    let irp = unsafe { Irp::from_raw(irp) };
+    let table = TABLE.load(Ordering::Acquire) as *const DispatchTable<T>;
+    let handler = if table.is_null() { None } else { unsafe { (*table).handlers[irp.major() as usize] } };
+    match handler {
+        Some(handler) => handler(unsafe { Device::<T>::extension_of(device) }, irp).status(),
+        None => irp.complete(NtStatus::INVALID_DEVICE_REQUEST).status(),
+    }
+}
@@ -0,0 +1,187 @@
+use core::cell::UnsafeCell;
+use core::ffi::c_void;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+use crate::*;
+use crate::irql::{self, PASSIVE_LEVEL};
+use crate::ustr::{UnicodeCow, UnicodeStr};
+
+
+/// The `DRIVER_OBJECT` passed to `DriverEntry`.
+pub struct DriverObject {
+    raw: PDRIVER_OBJECT,
+}
+
+impl DriverObject {
+    /// # Safety
+    ///
+    /// `raw` must be the driver object of the running driver.
+    pub unsafe fn from_raw(raw: PDRIVER_OBJECT) -> Self {
+        DriverObject { raw }
+    }
+
+    pub fn as_raw(&self) -> PDRIVER_OBJECT {
+        self.raw
+    }
+
+    /// `\Driver\<name>`.
+    pub fn name(&self) -> UnicodeStr<'_> {
+        unsafe { UnicodeStr::from_unicode_string(&(*self.raw).DriverName) }
+    }
+}
+
+
+/// A driver's state, built in `DriverEntry` and torn down in the unload routine.
+/// Declared with [`driver_entry!`](crate::driver_entry).
+pub trait Driver: Sized + Send + Sync + 'static {
+    /// Runs at PASSIVE_LEVEL from `DriverEntry`. An error fails the load with that status and
+    /// [`Driver::unload`] is never called.
+    fn init(driver: &mut DriverObject, registry_path: UnicodeStr<'_>) -> Result<Self, NtStatus>;
+
+    /// Runs at PASSIVE_LEVEL from the unload routine, before the state is dropped. [`DriverCell::get`]
+    /// already returns `None` here, but references taken from it earlier may still be in use, so
+    /// this is the place to stop or flush whatever holds them.
+    fn unload(&self) {}
+}
+
+
+/// The global holding a [`Driver`]'s state between load and unload.
+pub struct DriverCell<D> {
+    driver: AtomicPtr<DRIVER_OBJECT>,
+    // cleared before `Driver::unload` runs, while `driver` stays set until the unload routine ends
+    loaded: AtomicBool,
+    state: UnsafeCell<Option<D>>,
+}
+
+unsafe impl<D: Send + Sync> Sync for DriverCell<D> {}
+
+impl<D: Driver> DriverCell<D> {
+    pub const fn new() -> Self {
+        DriverCell { driver: AtomicPtr::new(ptr::null_mut()), loaded: AtomicBool::new(false), state: UnsafeCell::new(None) }
+    }
+
+    /// The state, once `init` has returned and until unload starts.
+    ///
+    /// The state is dropped as soon as [`Driver::unload`] returns, so a reference must not outlive
+    /// it: work items, DPCs, threads and callbacks that call `get` have to be stopped or flushed
+    /// from `unload`.
+    pub fn get(&self) -> Option<&D> {
+        if !self.loaded.load(Ordering::Acquire) {
+            return None;
+        }
+        unsafe { (*self.state.get()).as_ref() }
+    }
+
+    /// The driver object, e.g. for `defer::to_passive`. Null outside of load and unload.
+    pub fn driver_object(&self) -> PDRIVER_OBJECT {
+        self.driver.load(Ordering::Acquire)
+    }
+
+    #[doc(hidden)]
+    pub unsafe fn __entry(&self, driver: PDRIVER_OBJECT, registry_path: PUNICODE_STRING, unload: PDRIVER_UNLOAD) -> NTSTATUS {
+        irql::debug_assert_at_most(PASSIVE_LEVEL);
+        // drivers created with IoCreateDriver get no registry path
+        let path = if registry_path.is_null() { UnicodeStr::default() } else { unsafe { UnicodeStr::from_unicode_string(&*registry_path) } };
+        let mut object = unsafe { DriverObject::from_raw(driver) };
+        match D::init(&mut object, path) {
+            Ok(state) => {
+                unsafe {
+                    *self.state.get() = Some(state);
+                    (*driver).DriverUnload = unload;
+                }
+                self.driver.store(driver, Ordering::Release);
+                self.loaded.store(true, Ordering::Release);
+                STATUS_SUCCESS
+            }
+            Err(status) => status.raw(),
+        }
+    }
+
+    #[doc(hidden)]
+    pub unsafe fn __unload(&self) {
+        irql::debug_assert_at_most(PASSIVE_LEVEL);
+        self.loaded.store(false, Ordering::Release);
+        if let Some(state) = unsafe { (*self.state.get()).as_ref() } {
+            state.unload();
+        }
+        self.driver.store(ptr::null_mut(), Ordering::Release);
+        unsafe { *self.state.get() = None };
+    }
+}
+
+impl<D: Driver> Default for DriverCell<D> {
+    fn default() -> Self {
+        DriverCell::new()
+    }
+}
+
+
+/// `IoCreateDriver`: creates `\Driver\<name>` and runs `init` as its `DriverEntry`. For drivers
+/// that are not loaded by the service control manager and so have no driver object of their own.
+pub fn create<'a>(name: impl Into<UnicodeCow<'a>>, init: extern "system" fn(*mut c_void, *mut UNICODE_STRING) -> NTSTATUS) -> Result<(), NtStatus> {
+    irql::debug_assert_at_most(PASSIVE_LEVEL);
+    let name = name.into();
+    let mut name = name.as_ustr().as_unicode_string()?;
+    NtStatus::from_raw(unsafe { IoCreateDriver(&mut name, init) }).into_result()
+}
+
+
+/// Generates `DriverEntry` and the unload routine for a [`Driver`](crate::driver::Driver) and
+/// stores its state in a `static` [`DriverCell`](crate::driver::DriverCell).
+///
+/// ```ignore
+/// klib_rs::driver_entry!(static DRIVER: MyDriver);
+/// // not loaded through the SCM: DriverEntry calls IoCreateDriver instead
+/// klib_rs::driver_entry!(static DRIVER: MyDriver, create = "\\Driver\\MyDriver");
+/// ```
+#[macro_export]
+macro_rules! driver_entry {
+    ($vis:vis static $name:ident: $ty:ty) => {
+        $vis static $name: $crate::driver::DriverCell<$ty> = $crate::driver::DriverCell::new();
+
+        const _: () = {
+            unsafe extern "C" fn __klib_unload(_driver: $crate::PDRIVER_OBJECT) {
+                unsafe { $name.__unload() }
+            }
+
+            #[unsafe(no_mangle)]
+            pub unsafe extern "system" fn DriverEntry(driver: $crate::PDRIVER_OBJECT, registry_path: $crate::PUNICODE_STRING) -> $crate::NTSTATUS {
+                unsafe { $name.__entry(driver, registry_path, Some(__klib_unload)) }
+            }
+        };
+    };
+    ($vis:vis static $name:ident: $ty:ty, create = $driver_name:expr) => {
+        $vis static $name: $crate::driver::DriverCell<$ty> = $crate::driver::DriverCell::new();
+
+        const _: () = {
+            unsafe extern "C" fn __klib_unload(_driver: $crate::PDRIVER_OBJECT) {
+                unsafe { $name.__unload() }
+            }
+
+            extern "system" fn __klib_init(driver: *mut ::core::ffi::c_void, registry_path: *mut $crate::UNICODE_STRING) -> $crate::NTSTATUS {
+                unsafe { $name.__entry(driver as _, registry_path, Some(__klib_unload)) }
+            }
+
+            #[unsafe(no_mangle)]
+            pub unsafe extern "system" fn DriverEntry(_driver: $crate::PDRIVER_OBJECT, _registry_path: $crate::PUNICODE_STRING) -> $crate::NTSTATUS {
+                match $crate::driver::create($driver_name, __klib_init) {
+                    Ok(()) => $crate::STATUS_SUCCESS,
+                    Err(status) => status.raw(),
+                }
+            }
+        };
+    };
+}
+
+/// A `#[panic_handler]` that logs the panic through `klog` and bugchecks with
+/// `MANUALLY_INITIATED_CRASH1` (0xDEADDEAD). Invoke once in the driver crate.
+#[macro_export]
+macro_rules! panic_handler {
+    () => {
+        #[panic_handler]
+        fn __klib_panic(info: &::core::panic::PanicInfo) -> ! {
+            $crate::error!("{}", info);
+            unsafe { $crate::KeBugCheckEx(0xDEAD_DEAD, 0, 0, 0, 0) }
+        }
+    };
+}
@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use crate::*;
+use crate::irql::Passive;
+use crate::memory::module::{get_proc_addr, get_system_module_base};
+
+// cached value for an export that could not be found
+const MISSING: *mut c_void = ptr::without_provenance_mut(1);
+
+
+/// A kernel export looked up on first use instead of at load time, so the driver still loads on
+/// builds that lack it. `F` is the `unsafe extern "system" fn(...)` type of the export.
+///
+/// Usually declared through [`dyn_import!`](crate::dyn_import).
+pub struct DynFn<F> {
+    module: Option<&'static str>,
+    name: &'static str,
+    addr: AtomicPtr<c_void>,
+    _f: PhantomData<F>,
+}
+
+unsafe impl<F> Sync for DynFn<F> {}
+
+impl<F: Copy> DynFn<F> {
+    /// An export of ntoskrnl or hal, resolved with `MmGetSystemRoutineAddress`.
+    ///
+    /// # Safety
+    ///
+    /// `F` must be the `unsafe extern "system" fn` type of the export, since [`get`](Self::get)
+    /// turns the export's address into an `F`. [`dyn_import!`](crate::dyn_import) builds it from
+    /// the declared signature.
+    pub const unsafe fn new(name: &'static str) -> Self {
+        DynFn { module: None, name, addr: AtomicPtr::new(ptr::null_mut()), _f: PhantomData }
+    }
+
+    /// An export of another loaded kernel module (`"cng.sys"`), resolved with [`get_proc_addr`].
+    ///
+    /// # Safety
+    ///
+    /// As for [`new`](Self::new).
+    pub const unsafe fn in_module(module: &'static str, name: &'static str) -> Self {
+        DynFn { module: Some(module), name, addr: AtomicPtr::new(ptr::null_mut()), _f: PhantomData }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Address of the export, looked up once and cached. `STATUS_NOT_SUPPORTED` when it does not
+    /// exist on this build.
+    ///
+    /// The lookup only runs at PASSIVE_LEVEL. Above it, an export that has not been resolved yet
+    /// also yields `STATUS_NOT_SUPPORTED`, without caching that, so resolve imports needed at raised
+    /// IRQL from `DriverEntry`.
+    pub fn resolve(&self) -> Result<PVOID, NtStatus> {
+        let mut addr = self.addr.load(Ordering::Acquire);
+        if addr.is_null() {
+            let irql = Passive::get().ok_or(NtStatus::NOT_SUPPORTED)?;
+            addr = lookup(self.module, self.name, &irql).unwrap_or(MISSING);
+            self.addr.store(addr, Ordering::Release);
+        }
+
+        if addr == MISSING {
+            Err(NtStatus::NOT_SUPPORTED)
+        } else {
+            Ok(addr)
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.resolve().is_ok()
+    }
+
+    /// The typed function pointer, or `STATUS_NOT_SUPPORTED` when the export is missing.
+    pub fn get(&self) -> Result<F, NtStatus> {
+        const { assert!(size_of::<F>() == size_of::<PVOID>()) };
+        let addr = self.resolve()?;
+        Ok(unsafe { core::mem::transmute_copy::<PVOID, F>(&addr) })
+    }
+}
+
+
+fn lookup(module: Option<&str>, name: &str, irql: &Passive) -> Option<PVOID> {
+    match module {
+        Some(module) => get_proc_addr(get_system_module_base(module, irql).ok()?, name, irql).ok(),
+        None => {
+            let mut wide: Vec<u16> = name.encode_utf16().collect();
+            let len = u16::try_from(wide.len() * 2).ok()?;
+            let mut name = UNICODE_STRING { Length: len, MaximumLength: len, Buffer: wide.as_mut_ptr() };
+
+            let addr = unsafe { MmGetSystemRoutineAddress(&mut name) };
+            if addr.is_null() { None } else { Some(addr) }
+        }
+    }
+}
+
+
+/// Declares kernel exports that are resolved at runtime through [`DynFn`].
+///
+/// Each entry becomes a `static` of the same name; `Name.get()?` yields the typed function
+/// pointer, or `STATUS_NOT_SUPPORTED` when the running kernel does not export it.
+/// `#[module = "x.sys"]` looks the export up in another loaded module instead of ntoskrnl.
+///
+/// ```ignore
+/// dyn_import! {
+///     pub fn ExAllocatePool2(Flags: POOL_FLAGS, NumberOfBytes: SIZE_T, Tag: ULONG) -> PVOID;
+///     #[module = "cng.sys"]
+///     pub fn BCryptGenRandom(Algorithm: PVOID, Buffer: *mut u8, Size: ULONG, Flags: ULONG) -> NTSTATUS;
+/// }
+///
+/// let p = unsafe { ExAllocatePool2.get()?(0x40, 0x1000, u32::from_le_bytes(*b"klib")) };
+/// ```
+#[macro_export]
+macro_rules! dyn_import {
+    () => {};
+    (#[module = $module:literal] $(#[$attr:meta])* $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?; $($rest:tt)*) => {
+        $(#[$attr])*
+        $vis static $name: $crate::dynimport::DynFn<unsafe extern "system" fn($($ty),*) $(-> $ret)?> =
+            unsafe { $crate::dynimport::DynFn::in_module($module, stringify!($name)) };
+        $crate::dyn_import!($($rest)*);
+    };
+    ($(#[$attr:meta])* $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) $(-> $ret:ty)?; $($rest:tt)*) => {
+        $(#[$attr])*
+        $vis static $name: $crate::dynimport::DynFn<unsafe extern "system" fn($($ty),*) $(-> $ret)?> =
+            unsafe { $crate::dynimport::DynFn::new(stringify!($name)) };
+        $crate::dyn_import!($($rest)*);
+    };
+}
+
+
+// Exports that only exist on newer builds; use these instead of the `win10-*` gated bindings to
+// support older systems from a single binary.
+dyn_import! {
+    pub fn ExAllocatePool2(Flags: POOL_FLAGS, NumberOfBytes: SIZE_T, Tag: ULONG) -> PVOID;
+    pub fn ExAllocatePool3(Flags: POOL_FLAGS, NumberOfBytes: SIZE_T, Tag: ULONG, ExtendedParameters: PVOID, ExtendedParametersCount: ULONG) -> PVOID;
+    pub fn ExFreePool2(P: PVOID, Tag: ULONG, ExtendedParameters: PVOID, ExtendedParametersCount: ULONG);
+    pub fn KeQueryInterruptTimePrecise(QpcTimeStamp: *mut ULONG64) -> ULONG64;
+}
@@ -0,0 +1,219 @@
+use crate::NtStatus;
+
+// TraceLogging input types (TlgIn_t)
+pub const TLG_IN_NULL: u8 = 0;
+pub const TLG_IN_UNICODESTRING: u8 = 1;
+pub const TLG_IN_ANSISTRING: u8 = 2;
+pub const TLG_IN_INT8: u8 = 3;
+pub const TLG_IN_UINT8: u8 = 4;
+pub const TLG_IN_INT16: u8 = 5;
+pub const TLG_IN_UINT16: u8 = 6;
+pub const TLG_IN_INT32: u8 = 7;
+pub const TLG_IN_UINT32: u8 = 8;
+pub const TLG_IN_INT64: u8 = 9;
+pub const TLG_IN_UINT64: u8 = 10;
+pub const TLG_IN_FLOAT: u8 = 11;
+pub const TLG_IN_DOUBLE: u8 = 12;
+pub const TLG_IN_BOOL32: u8 = 13;
+pub const TLG_IN_BINARY: u8 = 14;
+pub const TLG_IN_GUID: u8 = 15;
+pub const TLG_IN_FILETIME: u8 = 17;
+pub const TLG_IN_SYSTEMTIME: u8 = 18;
+pub const TLG_IN_SID: u8 = 19;
+pub const TLG_IN_HEXINT32: u8 = 20;
+pub const TLG_IN_HEXINT64: u8 = 21;
+pub const TLG_IN_COUNTEDSTRING: u8 = 22;
+pub const TLG_IN_COUNTEDANSISTRING: u8 = 23;
+pub const TLG_IN_STRUCT: u8 = 24;
+pub const TLG_IN_COUNTEDBINARY: u8 = 25;
+
+pub const TLG_IN_CHAIN_FLAG: u8 = 0x80;
+
+// TraceLogging output types (TlgOut_t)
+pub const TLG_OUT_DEFAULT: u8 = 0;
+pub const TLG_OUT_NOPRINT: u8 = 1;
+pub const TLG_OUT_STRING: u8 = 2;
+pub const TLG_OUT_BOOLEAN: u8 = 3;
+pub const TLG_OUT_HEX: u8 = 4;
+pub const TLG_OUT_PID: u8 = 5;
+pub const TLG_OUT_TID: u8 = 6;
+pub const TLG_OUT_PORT: u8 = 7;
+pub const TLG_OUT_IPV4: u8 = 8;
+pub const TLG_OUT_IPV6: u8 = 9;
+pub const TLG_OUT_WIN32ERROR: u8 = 13;
+pub const TLG_OUT_NTSTATUS: u8 = 14;
+pub const TLG_OUT_HRESULT: u8 = 15;
+pub const TLG_OUT_FILETIME: u8 = 16;
+pub const TLG_OUT_SIGNED: u8 = 17;
+pub const TLG_OUT_UNSIGNED: u8 = 18;
+pub const TLG_OUT_UTF8: u8 = 35;
+pub const TLG_OUT_CODEPOINTER: u8 = 37;
+
+/// Channel that marks an event as TraceLogging (self-describing) to ETW consumers.
+pub const TLG_CHANNEL: u8 = 11;
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FieldMeta<'a> {
+    pub name: &'a str,
+    pub in_type: u8,
+    pub out_type: u8,
+}
+
+impl<'a> FieldMeta<'a> {
+    pub const fn new(name: &'a str, in_type: u8, out_type: u8) -> Self {
+        FieldMeta { name, in_type, out_type }
+    }
+}
+
+
+struct Cursor<'b> {
+    out: &'b mut [u8],
+    pos: usize,
+}
+
+impl Cursor<'_> {
+    fn put(&mut self, bytes: &[u8]) -> Result<(), NtStatus> {
+        let end = self.pos.checked_add(bytes.len()).ok_or(NtStatus::BUFFER_TOO_SMALL)?;
+        if end > self.out.len() {
+            return Err(NtStatus::BUFFER_TOO_SMALL);
+        }
+        self.out[self.pos..end].copy_from_slice(bytes);
+        self.pos = end;
+        Ok(())
+    }
+
+    fn put_name(&mut self, name: &str) -> Result<(), NtStatus> {
+        // names are NUL terminated, so an embedded NUL would corrupt the blob
+        if name.as_bytes().contains(&0) {
+            return Err(NtStatus::INVALID_PARAMETER);
+        }
+        self.put(name.as_bytes())?;
+        self.put(&[0])
+    }
+
+    fn finish(self) -> Result<usize, NtStatus> {
+        let size = u16::try_from(self.pos).map_err(|_| NtStatus::BUFFER_TOO_SMALL)?;
+        self.out[..2].copy_from_slice(&size.to_le_bytes());
+        Ok(self.pos)
+    }
+}
+
+
+/// Serializes TraceLogging provider metadata (`UINT16 size, name\0`) into `out`, returning its length.
+pub fn encode_provider_metadata(name: &str, out: &mut [u8]) -> Result<usize, NtStatus> {
+    let mut c = Cursor { out, pos: 0 };
+    c.put(&[0, 0])?;
+    c.put_name(name)?;
+    c.finish()
+}
+
+/// Serializes TraceLogging event metadata (`UINT16 size, UINT8 tags, name\0, fields...`) into `out`.
+///
+/// Each field is `name\0` followed by its input type and, when `out_type` is not
+/// [`TLG_OUT_DEFAULT`], the chain flag and the output type byte.
+pub fn encode_event_metadata(name: &str, fields: &[FieldMeta], out: &mut [u8]) -> Result<usize, NtStatus> {
+    let mut c = Cursor { out, pos: 0 };
+    c.put(&[0, 0])?;
+    c.put(&[0])?;
+    c.put_name(name)?;
+
+    for field in fields {
+        if field.in_type & TLG_IN_CHAIN_FLAG != 0 {
+            return Err(NtStatus::INVALID_PARAMETER);
+        }
+        c.put_name(field.name)?;
+        if field.out_type == TLG_OUT_DEFAULT {
+            c.put(&[field.in_type])?;
+        } else {
+            c.put(&[field.in_type | TLG_IN_CHAIN_FLAG, field.out_type])?;
+        }
+    }
+
+    c.finish()
+}
+
+/// Exact number of bytes [`encode_event_metadata`] needs.
+pub fn event_metadata_len(name: &str, fields: &[FieldMeta]) -> usize {
+    let mut len = 2 + 1 + name.len() + 1;
+    for field in fields {
+        len += field.name.len() + 1 + if field.out_type == TLG_OUT_DEFAULT { 1 } else { 2 };
+    }
+    len
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provider_metadata() {
+        let mut out = [0xAAu8; 16];
+        assert_eq!(encode_provider_metadata("Prov", &mut out), Ok(7));
+        assert_eq!(&out[..8], &[7, 0, b'P', b'r', b'o', b'v', 0, 0xAA]);
+        assert_eq!(encode_provider_metadata("", &mut out), Ok(3));
+        assert_eq!(&out[..3], &[3, 0, 0]);
+    }
+
+    #[test]
+    fn event_metadata() {
+        let fields = [
+            FieldMeta::new("pid", TLG_IN_UINT32, TLG_OUT_PID),
+            FieldMeta::new("n", TLG_IN_INT64, TLG_OUT_DEFAULT),
+            FieldMeta::new("s", TLG_IN_COUNTEDSTRING, TLG_OUT_DEFAULT),
+        ];
+        let mut out = [0u8; 64];
+        let len = encode_event_metadata("Ev", &fields, &mut out).unwrap();
+        let expected = [
+            18, 0,                        // size, including itself
+            0,                            // tags
+            b'E', b'v', 0,
+            b'p', b'i', b'd', 0, TLG_IN_UINT32 | TLG_IN_CHAIN_FLAG, TLG_OUT_PID,
+            b'n', 0, TLG_IN_INT64,
+            b's', 0, TLG_IN_COUNTEDSTRING,
+        ];
+        assert_eq!(&out[..len], &expected[..]);
+        assert_eq!(len, event_metadata_len("Ev", &fields));
+    }
+
+    #[test]
+    fn size_header() {
+        let fields = [FieldMeta::new("value", TLG_IN_UINT8, TLG_OUT_HEX)];
+        let mut out = [0u8; 32];
+        let len = encode_event_metadata("E", &fields, &mut out).unwrap();
+        assert_eq!(u16::from_le_bytes([out[0], out[1]]) as usize, len);
+        assert_eq!(len, event_metadata_len("E", &fields));
+        assert_eq!(len, 2 + 1 + 2 + 6 + 2);
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        let mut out = [0u8; 32];
+        assert_eq!(encode_provider_metadata("a\0b", &mut out), Err(NtStatus::INVALID_PARAMETER));
+        assert_eq!(encode_event_metadata("E", &[FieldMeta::new("f\0", TLG_IN_INT8, TLG_OUT_DEFAULT)], &mut out),
+                   Err(NtStatus::INVALID_PARAMETER));
+        assert_eq!(encode_event_metadata("E", &[FieldMeta::new("f", TLG_IN_INT8 | TLG_IN_CHAIN_FLAG, TLG_OUT_DEFAULT)], &mut out),
+                   Err(NtStatus::INVALID_PARAMETER));
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        let fields = [FieldMeta::new("field", TLG_IN_UINT32, TLG_OUT_HEX)];
+        let len = event_metadata_len("Event", &fields);
+        let mut out = [0u8; 64];
+        for short in 0..len {
+            assert_eq!(encode_event_metadata("Event", &fields, &mut out[..short]), Err(NtStatus::BUFFER_TOO_SMALL));
+        }
+        assert_eq!(encode_event_metadata("Event", &fields, &mut out[..len]), Ok(len));
+        assert_eq!(encode_provider_metadata("P", &mut out[..3]), Err(NtStatus::BUFFER_TOO_SMALL));
+    }
+
+    #[test]
+    fn size_over_u16() {
+        let name = "n".repeat(0x1_0000);
+        let mut out = alloc::vec![0u8; 0x1_0010];
+        assert_eq!(encode_provider_metadata(&name, &mut out), Err(NtStatus::BUFFER_TOO_SMALL));
+        assert_eq!(encode_provider_metadata(&name[..0xFFFC], &mut out), Ok(0xFFFF));
+    }
+}
@@ -0,0 +1,348 @@
+pub mod meta;
+
+#[cfg(feature = "kernel")]
+use alloc::vec;
+#[cfg(feature = "kernel")]
+use alloc::vec::Vec;
+#[cfg(feature = "kernel")]
+use core::{mem, ptr};
+#[cfg(feature = "kernel")]
+use crate::*;
+#[cfg(feature = "kernel")]
+use meta::*;
+
+pub const LEVEL_CRITICAL: u8 = 1;
+pub const LEVEL_ERROR: u8 = 2;
+pub const LEVEL_WARNING: u8 = 3;
+pub const LEVEL_INFO: u8 = 4;
+pub const LEVEL_VERBOSE: u8 = 5;
+
+pub const OPCODE_INFO: u8 = 0;
+pub const OPCODE_START: u8 = 1;
+pub const OPCODE_STOP: u8 = 2;
+
+#[cfg(feature = "kernel")]
+const MAX_FIELDS: usize = 16;
+#[cfg(feature = "kernel")]
+const MAX_EVENT_METADATA: usize = 512;
+
+
+/// Payload of one field as it is handed to `EtwWrite`.
+#[cfg(feature = "kernel")]
+#[derive(Copy, Clone)]
+pub enum FieldData<'a> {
+    /// Raw bytes written as-is.
+    Bytes(&'a [u8]),
+    /// Bytes preceded by their `UINT16` length, for counted strings and binary.
+    Counted(&'a [u8]),
+    /// Up to eight little-endian bytes of a scalar, copied when the event is written.
+    Inline(u64, u8),
+}
+
+#[cfg(feature = "kernel")]
+pub trait EtwField {
+    const IN_TYPE: u8;
+    const OUT_TYPE: u8 = TLG_OUT_DEFAULT;
+    fn data(&self) -> FieldData<'_>;
+}
+
+#[cfg(feature = "kernel")]
+macro_rules! scalar_field {
+    ($($ty:ty => $in_type:expr, $out_type:expr, |$v:ident| $bits:expr;)*) => {$(
+        impl EtwField for $ty {
+            const IN_TYPE: u8 = $in_type;
+            const OUT_TYPE: u8 = $out_type;
+            fn data(&self) -> FieldData<'_> {
+                let $v = *self;
+                FieldData::Inline($bits, size_of::<$ty>() as u8)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "kernel")]
+scalar_field! {
+    i8 => TLG_IN_INT8, TLG_OUT_DEFAULT, |v| v as u8 as u64;
+    u8 => TLG_IN_UINT8, TLG_OUT_DEFAULT, |v| v as u64;
+    i16 => TLG_IN_INT16, TLG_OUT_DEFAULT, |v| v as u16 as u64;
+    u16 => TLG_IN_UINT16, TLG_OUT_DEFAULT, |v| v as u64;
+    i32 => TLG_IN_INT32, TLG_OUT_DEFAULT, |v| v as u32 as u64;
+    u32 => TLG_IN_UINT32, TLG_OUT_DEFAULT, |v| v as u64;
+    i64 => TLG_IN_INT64, TLG_OUT_DEFAULT, |v| v as u64;
+    u64 => TLG_IN_UINT64, TLG_OUT_DEFAULT, |v| v;
+    usize => TLG_IN_UINT64, TLG_OUT_DEFAULT, |v| v as u64;
+    f32 => TLG_IN_FLOAT, TLG_OUT_DEFAULT, |v| v.to_bits() as u64;
+    f64 => TLG_IN_DOUBLE, TLG_OUT_DEFAULT, |v| v.to_bits();
+}
+
+#[cfg(feature = "kernel")]
+impl EtwField for bool {
+    const IN_TYPE: u8 = TLG_IN_BOOL32;
+    fn data(&self) -> FieldData<'_> {
+        FieldData::Inline(*self as u64, 4)
+    }
+}
+
+#[cfg(feature = "kernel")]
+impl EtwField for NtStatus {
+    const IN_TYPE: u8 = TLG_IN_INT32;
+    const OUT_TYPE: u8 = TLG_OUT_NTSTATUS;
+    fn data(&self) -> FieldData<'_> {
+        FieldData::Inline(self.raw() as u32 as u64, 4)
+    }
+}
+
+#[cfg(feature = "kernel")]
+impl<T> EtwField for *const T {
+    const IN_TYPE: u8 = TLG_IN_HEXINT64;
+    fn data(&self) -> FieldData<'_> {
+        FieldData::Inline(*self as u64, 8)
+    }
+}
+
+#[cfg(feature = "kernel")]
+impl<T> EtwField for *mut T {
+    const IN_TYPE: u8 = TLG_IN_HEXINT64;
+    fn data(&self) -> FieldData<'_> {
+        FieldData::Inline(*self as u64, 8)
+    }
+}
+
+#[cfg(feature = "kernel")]
+impl EtwField for str {
+    const IN_TYPE: u8 = TLG_IN_COUNTEDANSISTRING;
+    const OUT_TYPE: u8 = TLG_OUT_UTF8;
+    fn data(&self) -> FieldData<'_> {
+        FieldData::Counted(self.as_bytes())
+    }
+}
+
+#[cfg(feature = "kernel")]
+impl EtwField for [u8] {
+    const IN_TYPE: u8 = TLG_IN_COUNTEDBINARY;
+    fn data(&self) -> FieldData<'_> {
+        FieldData::Counted(self)
+    }
+}
+
+#[cfg(feature = "kernel")]
+impl EtwField for GUID {
+    const IN_TYPE: u8 = TLG_IN_GUID;
+    fn data(&self) -> FieldData<'_> {
+        FieldData::Bytes(unsafe { slice::from_raw_parts(self as *const GUID as *const u8, size_of::<GUID>()) })
+    }
+}
+
+#[cfg(feature = "kernel")]
+impl EtwField for UNICODE_STRING {
+    const IN_TYPE: u8 = TLG_IN_COUNTEDSTRING;
+    fn data(&self) -> FieldData<'_> {
+        if self.Buffer.is_null() {
+            return FieldData::Counted(&[]);
+        }
+        FieldData::Counted(unsafe { slice::from_raw_parts(self.Buffer as *const u8, self.Length as usize) })
+    }
+}
+
+#[cfg(feature = "kernel")]
+impl<T: EtwField + ?Sized> EtwField for &T {
+    const IN_TYPE: u8 = T::IN_TYPE;
+    const OUT_TYPE: u8 = T::OUT_TYPE;
+    fn data(&self) -> FieldData<'_> {
+        (**self).data()
+    }
+}
+
+
+/// Registered ETW provider, unregistered on drop.
+#[cfg(feature = "kernel")]
+pub struct Provider {
+    handle: REGHANDLE,
+    traits: Vec<u8>,
+}
+
+#[cfg(feature = "kernel")]
+unsafe impl Send for Provider {}
+#[cfg(feature = "kernel")]
+unsafe impl Sync for Provider {}
+
+#[cfg(feature = "kernel")]
+impl Provider {
+    /// Must be called at PASSIVE_LEVEL.
+    pub fn register(guid: &GUID, name: &str) -> Result<Self, NtStatus> {
+        let mut traits = vec![0u8; 2 + name.len() + 1];
+        let len = encode_provider_metadata(name, &mut traits)?;
+        traits.truncate(len);
+
+        let mut handle: REGHANDLE = 0;
+        NtStatus::check(unsafe { EtwRegister(guid, None, ptr::null_mut(), &mut handle) })?;
+
+        // both are unsupported before Windows 10, where the event is still written but not decoded
+        let mut use_descriptor_type: BOOLEAN = TRUE as _;
+        unsafe {
+            EtwSetInformation(handle, _EVENT_INFO_CLASS_EventProviderSetTraits, traits.as_mut_ptr() as _, traits.len() as _);
+            EtwSetInformation(handle, _EVENT_INFO_CLASS_EventProviderUseDescriptorType, &mut use_descriptor_type as *mut BOOLEAN as _, size_of::<BOOLEAN>() as _);
+        }
+
+        Ok(Provider { handle, traits })
+    }
+
+    pub fn handle(&self) -> REGHANDLE {
+        self.handle
+    }
+
+    pub fn enabled(&self, level: u8, keyword: u64) -> bool {
+        unsafe { EtwProviderEnabled(self.handle, level, keyword) != 0 }
+    }
+}
+
+#[cfg(feature = "kernel")]
+impl Drop for Provider {
+    fn drop(&mut self) {
+        unsafe {
+            EtwUnregister(self.handle);
+        }
+    }
+}
+
+
+/// Self-describing event under construction, see [`etw_write!`](crate::etw_write).
+#[cfg(feature = "kernel")]
+pub struct Event<'a> {
+    name: &'a str,
+    level: u8,
+    opcode: u8,
+    keyword: u64,
+    fields: [FieldMeta<'a>; MAX_FIELDS],
+    data: [FieldData<'a>; MAX_FIELDS],
+    count: usize,
+    overflow: bool,
+}
+
+#[cfg(feature = "kernel")]
+impl<'a> Event<'a> {
+    pub fn new(name: &'a str) -> Self {
+        Event {
+            name,
+            level: LEVEL_VERBOSE,
+            opcode: OPCODE_INFO,
+            keyword: 0,
+            fields: [FieldMeta::new("", TLG_IN_NULL, TLG_OUT_DEFAULT); MAX_FIELDS],
+            data: [FieldData::Inline(0, 0); MAX_FIELDS],
+            count: 0,
+            overflow: false,
+        }
+    }
+
+    pub fn level(mut self, level: u8) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn opcode(mut self, opcode: u8) -> Self {
+        self.opcode = opcode;
+        self
+    }
+
+    pub fn keyword(mut self, keyword: u64) -> Self {
+        self.keyword = keyword;
+        self
+    }
+
+    pub fn field<T: EtwField + ?Sized>(mut self, name: &'a str, value: &'a T) -> Self {
+        if self.count == MAX_FIELDS {
+            self.overflow = true;
+            return self;
+        }
+        self.fields[self.count] = FieldMeta::new(name, T::IN_TYPE, T::OUT_TYPE);
+        self.data[self.count] = value.data();
+        self.count += 1;
+        self
+    }
+
+    /// Writes the event through `EtwWrite`. Callable at IRQL <= HIGH_LEVEL with non-paged field data.
+    pub fn write(self, provider: &Provider) -> Result<(), NtStatus> {
+        if self.overflow {
+            return Err(NtStatus::BUFFER_TOO_SMALL);
+        }
+
+        let mut metadata = [0u8; MAX_EVENT_METADATA];
+        let metadata_len = encode_event_metadata(self.name, &self.fields[..self.count], &mut metadata)?;
+
+        let mut inline = [0u64; MAX_FIELDS * 2];
+        let mut descriptors: [EVENT_DATA_DESCRIPTOR; 2 + MAX_FIELDS * 2] = unsafe { mem::zeroed() };
+
+        descriptors[0].Ptr = provider.traits.as_ptr() as u64;
+        descriptors[0].Size = provider.traits.len() as _;
+        descriptors[0].__bindgen_anon_1.Reserved = EVENT_DATA_DESCRIPTOR_TYPE_PROVIDER_METADATA as _;
+        descriptors[1].Ptr = metadata.as_ptr() as u64;
+        descriptors[1].Size = metadata_len as _;
+        descriptors[1].__bindgen_anon_1.Reserved = EVENT_DATA_DESCRIPTOR_TYPE_EVENT_METADATA as _;
+
+        let mut n = 2;
+        for (i, data) in self.data[..self.count].iter().enumerate() {
+            match *data {
+                FieldData::Bytes(bytes) => {
+                    descriptors[n].Ptr = bytes.as_ptr() as u64;
+                    descriptors[n].Size = bytes.len() as _;
+                    n += 1;
+                }
+                FieldData::Counted(bytes) => {
+                    let len = core::cmp::min(bytes.len(), u16::MAX as usize);
+                    inline[i * 2] = len as u64;
+                    descriptors[n].Ptr = &inline[i * 2] as *const u64 as u64;
+                    descriptors[n].Size = 2;
+                    descriptors[n + 1].Ptr = bytes.as_ptr() as u64;
+                    descriptors[n + 1].Size = len as _;
+                    n += 2;
+                }
+                FieldData::Inline(bits, size) => {
+                    inline[i * 2] = bits;
+                    descriptors[n].Ptr = &inline[i * 2] as *const u64 as u64;
+                    descriptors[n].Size = size as _;
+                    n += 1;
+                }
+            }
+        }
+
+        let descriptor = EVENT_DESCRIPTOR {
+            Id: 0,
+            Version: 0,
+            Channel: TLG_CHANNEL,
+            Level: self.level,
+            Opcode: self.opcode,
+            Task: 0,
+            Keyword: self.keyword,
+        };
+
+        NtStatus::check(unsafe { EtwWrite(provider.handle, &descriptor, ptr::null(), n as _, descriptors.as_mut_ptr()) })
+    }
+}
+
+
+/// Writes a TraceLogging event when the provider is enabled for its level and keyword.
+///
+/// ```ignore
+/// etw_write!(PROVIDER, "ProcessCreated", level: etw::LEVEL_INFO, keyword: 0x1,
+///     "pid" => pid, "image" => image_name, "status" => status)?;
+/// ```
+#[macro_export]
+macro_rules! etw_write {
+    ($provider:expr, $name:expr, level: $level:expr, keyword: $keyword:expr $(, $field:literal => $value:expr)* $(,)?) => {{
+        let provider: &$crate::etw::Provider = &$provider;
+        let level: u8 = $level;
+        let keyword: u64 = $keyword;
+        if provider.enabled(level, keyword) {
+            $crate::etw::Event::new($name)
+                .level(level)
+                .keyword(keyword)
+                $(.field($field, &$value))*
+                .write(provider)
+        } else {
+            Ok(())
+        }
+    }};
+    ($provider:expr, $name:expr $(, $field:literal => $value:expr)* $(,)?) => {
+        $crate::etw_write!($provider, $name, level: $crate::etw::LEVEL_VERBOSE, keyword: 0 $(, $field => $value)*)
+    };
+}
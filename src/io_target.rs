@@ -0,0 +1,341 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use crate::*;
+use crate::defer::Pending;
+use crate::ioctl::{self, Ioctl};
+use crate::irql::{self, BelowDispatch, Passive, DISPATCH_LEVEL, PASSIVE_LEVEL};
+use crate::sync::{KEvent, Timeout};
+use crate::ustr::UnicodeCow;
+
+
+/// Another driver's device to send requests to, e.g. `\Device\KeyboardClass0`.
+pub struct IoTarget {
+    device: PDEVICE_OBJECT,
+    // referenced by `open`; null for a borrowed device
+    file: PFILE_OBJECT,
+}
+
+unsafe impl Send for IoTarget {}
+unsafe impl Sync for IoTarget {}
+
+impl IoTarget {
+    /// `IoGetDeviceObjectPointer`: opens the named device and targets the top of its stack.
+    pub fn open<'a>(name: impl Into<UnicodeCow<'a>>, access: ACCESS_MASK, _irql: &Passive) -> Result<Self, NtStatus> {
+        irql::debug_assert_at_most(PASSIVE_LEVEL);
+        let name = name.into();
+        let mut name = name.as_ustr().as_unicode_string()?;
+        let mut file: PFILE_OBJECT = ptr::null_mut();
+        let mut device: PDEVICE_OBJECT = ptr::null_mut();
+        NtStatus::check(unsafe { IoGetDeviceObjectPointer(&mut name, access, &mut file, &mut device) })?;
+        Ok(IoTarget { device, file })
+    }
+
+    /// Targets `device` without taking a reference, e.g. the lower device a filter is attached to.
+    ///
+    /// # Safety
+    ///
+    /// `device` must stay valid for the lifetime of the target. Requests in flight hold their own
+    /// reference on it.
+    pub unsafe fn from_device(device: PDEVICE_OBJECT) -> Self {
+        IoTarget { device, file: ptr::null_mut() }
+    }
+
+    pub fn device(&self) -> PDEVICE_OBJECT {
+        self.device
+    }
+
+    pub fn file_object(&self) -> PFILE_OBJECT {
+        self.file
+    }
+
+    /// Sends `IRP_MJ_DEVICE_CONTROL` and waits for it. Returns the number of bytes written to
+    /// `output`. If `timeout` passes first the IRP is cancelled, and the call returns
+    /// `STATUS_IO_TIMEOUT` once the lower driver has given it back.
+    pub fn send_ioctl(&self, code: u32, input: &[u8], output: &mut [u8], timeout: impl Into<Timeout>,
+                      irql: &Passive) -> Result<usize, NtStatus> {
+        self.send_sync(IRP_MJ_DEVICE_CONTROL, code, input, output, timeout.into(), irql)
+    }
+
+    /// [`send_ioctl`](Self::send_ioctl) with `IRP_MJ_INTERNAL_DEVICE_CONTROL`.
+    pub fn send_internal_ioctl(&self, code: u32, input: &[u8], output: &mut [u8], timeout: impl Into<Timeout>,
+                               irql: &impl BelowDispatch) -> Result<usize, NtStatus> {
+        self.send_sync(IRP_MJ_INTERNAL_DEVICE_CONTROL, code, input, output, timeout.into(), irql)
+    }
+
+    /// [`send_ioctl`](Self::send_ioctl) with the types of an [`ioctl!`](crate::ioctl!) definition.
+    /// Fails with `STATUS_INFO_LENGTH_MISMATCH` if less than a whole `I::Output` came back.
+    pub fn call<I: Ioctl>(&self, input: &I::Input, timeout: impl Into<Timeout>, irql: &Passive) -> Result<I::Output, NtStatus> {
+        let mut output: I::Output = unsafe { core::mem::zeroed() };
+        let n = self.send_ioctl(I::CODE, ioctl::bytes_of(input), ioctl::bytes_of_mut(&mut output), timeout, irql)?;
+        if n < size_of::<I::Output>() {
+            return Err(NtStatus::INFO_LENGTH_MISMATCH);
+        }
+        Ok(output)
+    }
+
+    /// Sends `IRP_MJ_DEVICE_CONTROL` without waiting. `on_complete` gets up to `output_len`
+    /// bytes of output, at IRQL <= DISPATCH_LEVEL, from the completion routine. With a timeout
+    /// the IRP is cancelled when it passes and the result is `STATUS_IO_TIMEOUT`.
+    ///
+    /// The request keeps the target's device and file object referenced until it completes, so
+    /// the `IoTarget` may be dropped in the meantime.
+    pub fn send_ioctl_async<F>(&self, code: u32, input: &[u8], output_len: usize, timeout: impl Into<Timeout>,
+                               on_complete: F, _irql: &Passive) -> Result<(), NtStatus>
+    where
+        F: FnOnce(Result<Vec<u8>, NtStatus>) + Send + 'static,
+    {
+        irql::debug_assert_at_most(PASSIVE_LEVEL);
+        self.send(IRP_MJ_DEVICE_CONTROL, code, input, output_len, timeout.into(), on_complete)
+    }
+
+    /// [`send_ioctl_async`](Self::send_ioctl_async) with `IRP_MJ_INTERNAL_DEVICE_CONTROL`, which
+    /// can be sent at IRQL <= DISPATCH_LEVEL if the lower driver accepts it there.
+    pub fn send_internal_ioctl_async<F>(&self, code: u32, input: &[u8], output_len: usize, timeout: impl Into<Timeout>,
+                                        on_complete: F) -> Result<(), NtStatus>
+    where
+        F: FnOnce(Result<Vec<u8>, NtStatus>) + Send + 'static,
+    {
+        self.send(IRP_MJ_INTERNAL_DEVICE_CONTROL, code, input, output_len, timeout.into(), on_complete)
+    }
+
+    fn send_sync(&self, major: u32, code: u32, input: &[u8], output: &mut [u8], timeout: Timeout,
+                 irql: &impl BelowDispatch) -> Result<usize, NtStatus> {
+        let shared = Arc::new(SyncResult { done: KEvent::notification(false), result: UnsafeCell::new(None) });
+        let sender = shared.clone();
+        self.send(major, code, input, output.len(), timeout, move |result| {
+            // read only after `done` is set
+            unsafe { *sender.result.get() = Some(result) };
+            sender.done.set();
+        })?;
+        // the timeout is enforced by cancelling the IRP, so this wait ends with the completion
+        shared.done.wait(Timeout::Infinite, irql)?;
+        let data = unsafe { (*shared.result.get()).take() }.ok_or(NtStatus::INTERNAL_ERROR)??;
+        let n = data.len().min(output.len());
+        output[..n].copy_from_slice(&data[..n]);
+        Ok(n)
+    }
+
+    fn send<F>(&self, major: u32, code: u32, input: &[u8], output_len: usize, timeout: Timeout, on_complete: F) -> Result<(), NtStatus>
+    where
+        F: FnOnce(Result<Vec<u8>, NtStatus>) + Send + 'static,
+    {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
+        let (Ok(input_length), Ok(output_length)) = (u32::try_from(input.len()), u32::try_from(output_len)) else {
+            return Err(NtStatus::INVALID_PARAMETER);
+        };
+        let method = ioctl::method_of(code);
+        // METHOD_BUFFERED shares one buffer for both directions, like the I/O manager does
+        let system_len = if method == METHOD_BUFFERED { input.len().max(output_len) } else { input.len() };
+        let system = PoolBuffer::new(system_len)?;
+        let output = if method == METHOD_BUFFERED { PoolBuffer::new(0)? } else { PoolBuffer::new(output_len)? };
+        if !input.is_empty() {
+            unsafe { ptr::copy_nonoverlapping(input.as_ptr(), system.ptr, input.len()) };
+        }
+
+        let mut mdl = ptr::null_mut();
+        if matches!(method, METHOD_IN_DIRECT | METHOD_OUT_DIRECT) && output_len != 0 {
+            mdl = unsafe { IoAllocateMdl(output.ptr as _, output_len as _, FALSE as _, FALSE as _, ptr::null_mut()) };
+            if mdl.is_null() {
+                return Err(NtStatus::INSUFFICIENT_RESOURCES);
+            }
+            unsafe { MmBuildMdlForNonPagedPool(mdl) };
+        }
+
+        let irp = unsafe { IoAllocateIrp((*self.device).StackSize, FALSE as _) };
+        if irp.is_null() {
+            if !mdl.is_null() {
+                unsafe { IoFreeMdl(mdl) };
+            }
+            return Err(NtStatus::INSUFFICIENT_RESOURCES);
+        }
+
+        let due = timeout.to_due_time();
+        let request = Box::into_raw(Box::new(Request {
+            timer: unsafe { core::mem::zeroed() },
+            dpc: unsafe { core::mem::zeroed() },
+            irp,
+            mdl,
+            device: self.device,
+            file: self.file,
+            system,
+            output,
+            output_len,
+            method,
+            // the completion routine's, and the timer's while it is set
+            refs: AtomicU32::new(1 + due.is_some() as u32),
+            timed_out: AtomicBool::new(false),
+            on_complete: Some(on_complete),
+            _pending: Pending::new(),
+        }));
+
+        unsafe {
+            let r = &mut *request;
+            // dropped with the request, so the IoTarget can go away while it is in flight
+            ObfReferenceObject(self.device as PVOID);
+            if !self.file.is_null() {
+                ObfReferenceObject(self.file as PVOID);
+            }
+            match method {
+                METHOD_BUFFERED => (*irp).AssociatedIrp.SystemBuffer = r.system.ptr as _,
+                METHOD_NEITHER => (*irp).UserBuffer = r.output.ptr as _,
+                _ => {
+                    (*irp).AssociatedIrp.SystemBuffer = r.system.ptr as _;
+                    (*irp).MdlAddress = mdl;
+                }
+            }
+
+            // IoGetNextIrpStackLocation
+            let stack = &mut *(*irp).Tail.Overlay.__bindgen_anon_2.__bindgen_anon_1.CurrentStackLocation.sub(1);
+            stack.MajorFunction = major as u8;
+            stack.FileObject = self.file;
+            stack.Parameters.DeviceIoControl.IoControlCode = code;
+            stack.Parameters.DeviceIoControl.InputBufferLength = input_length;
+            stack.Parameters.DeviceIoControl.OutputBufferLength = output_length;
+            if method == METHOD_NEITHER {
+                stack.Parameters.DeviceIoControl.Type3InputBuffer = r.system.ptr as _;
+            }
+            // IoSetCompletionRoutine
+            stack.CompletionRoutine = Some(completion::<F>);
+            stack.Context = request as PVOID;
+            stack.Control = (SL_INVOKE_ON_SUCCESS | SL_INVOKE_ON_ERROR | SL_INVOKE_ON_CANCEL) as u8;
+
+            if let Some(due) = due {
+                KeInitializeTimer(&mut r.timer);
+                KeInitializeDpc(&mut r.dpc, Some(timeout_dpc::<F>), request as PVOID);
+                KeSetTimer(&mut r.timer, due, &mut r.dpc);
+            }
+
+            // the completion routine runs whatever this returns
+            IofCallDriver(self.device, irp);
+        }
+        Ok(())
+    }
+}
+
+impl Drop for IoTarget {
+    fn drop(&mut self) {
+        if !self.file.is_null() {
+            unsafe { ObfDereferenceObject(self.file as PVOID) };
+        }
+    }
+}
+
+
+struct SyncResult {
+    done: KEvent,
+    result: UnsafeCell<Option<Result<Vec<u8>, NtStatus>>>,
+}
+
+unsafe impl Sync for SyncResult {}
+
+
+// non-paged, since the MDL is built with `MmBuildMdlForNonPagedPool`
+struct PoolBuffer {
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl PoolBuffer {
+    fn new(len: usize) -> Result<Self, NtStatus> {
+        if len == 0 {
+            return Ok(PoolBuffer { ptr: ptr::null_mut(), len });
+        }
+        let ptr = memory::alloc_pool(_POOL_TYPE_NonPagedPoolNx, len)? as *mut u8;
+        Ok(PoolBuffer { ptr, len })
+    }
+
+    fn to_vec(&self, len: usize) -> Vec<u8> {
+        if self.ptr.is_null() {
+            return Vec::new();
+        }
+        unsafe { core::slice::from_raw_parts(self.ptr, len.min(self.len)) }.to_vec()
+    }
+}
+
+impl Drop for PoolBuffer {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe { ExFreePool(self.ptr as PVOID) };
+        }
+    }
+}
+
+
+struct Request<F> {
+    timer: KTIMER,
+    dpc: KDPC,
+    irp: PIRP,
+    mdl: PMDL,
+    // referenced for as long as the IRP carries them
+    device: PDEVICE_OBJECT,
+    file: PFILE_OBJECT,
+    system: PoolBuffer,
+    output: PoolBuffer,
+    output_len: usize,
+    method: u32,
+    // the IRP and this state are freed when the last of the completion routine and the timer is done
+    refs: AtomicU32,
+    timed_out: AtomicBool,
+    on_complete: Option<F>,
+    _pending: Pending,
+}
+
+unsafe fn release<F>(request: *mut Request<F>) {
+    unsafe {
+        if (*request).refs.fetch_sub(1, Ordering::AcqRel) != 1 {
+            return;
+        }
+        let request = Box::from_raw(request);
+        if !request.mdl.is_null() {
+            IoFreeMdl(request.mdl);
+        }
+        IoFreeIrp(request.irp);
+        if !request.file.is_null() {
+            ObfDereferenceObject(request.file as PVOID);
+        }
+        ObfDereferenceObject(request.device as PVOID);
+    }
+}
+
+unsafe extern "C" fn completion<F>(_device: PDEVICE_OBJECT, irp: PIRP, context: PVOID) -> NTSTATUS
+where
+    F: FnOnce(Result<Vec<u8>, NtStatus>) + Send + 'static,
+{
+    let request = context as *mut Request<F>;
+    unsafe {
+        let r = &mut *request;
+        let status = NtStatus::from_raw((*irp).IoStatus.__bindgen_anon_1.Status);
+        let information = (*irp).IoStatus.Information as usize;
+        let result = if status == NtStatus::CANCELLED && r.timed_out.load(Ordering::Acquire) {
+            Err(NtStatus::IO_TIMEOUT)
+        } else {
+            status.into_result().map(|()| {
+                let len = information.min(r.output_len);
+                if r.method == METHOD_BUFFERED { r.system.to_vec(len) } else { r.output.to_vec(len) }
+            })
+        };
+        if let Some(on_complete) = r.on_complete.take() {
+            on_complete(result);
+        }
+        // a timer that never fired drops its reference here; one that did holds it until its DPC returns
+        if r.refs.load(Ordering::Acquire) == 2 && KeCancelTimer(&mut r.timer) != 0 {
+            release(request);
+        }
+        release(request);
+    }
+    // the IRP was allocated here and is freed here, not by the I/O manager
+    NtStatus::MORE_PROCESSING_REQUIRED.raw()
+}
+
+unsafe extern "C" fn timeout_dpc<F>(_dpc: PKDPC, context: PVOID, _arg1: PVOID, _arg2: PVOID) {
+    let request = context as *mut Request<F>;
+    unsafe {
+        (*request).timed_out.store(true, Ordering::Release);
+        IoCancelIrp((*request).irp);
+        release(request);
+    }
+}
@@ -0,0 +1,165 @@
+use core::slice;
+
+
+// the values of the wdm bindings, which only exist for Windows targets
+pub const FILE_DEVICE_UNKNOWN: u32 = 0x22;
+
+pub const METHOD_BUFFERED: u32 = 0;
+pub const METHOD_IN_DIRECT: u32 = 1;
+pub const METHOD_OUT_DIRECT: u32 = 2;
+pub const METHOD_NEITHER: u32 = 3;
+
+pub const FILE_ANY_ACCESS: u32 = 0;
+pub const FILE_READ_ACCESS: u32 = 1;
+pub const FILE_WRITE_ACCESS: u32 = 2;
+
+
+/// `CTL_CODE`.
+pub const fn ctl_code(device_type: u32, function: u32, method: u32, access: u32) -> u32 {
+    (device_type << 16) | (access << 14) | (function << 2) | method
+}
+
+pub const fn device_type_of(code: u32) -> u32 {
+    code >> 16
+}
+
+pub const fn function_of(code: u32) -> u32 {
+    (code >> 2) & 0xFFF
+}
+
+/// `METHOD_BUFFERED`, `METHOD_IN_DIRECT`, `METHOD_OUT_DIRECT` or `METHOD_NEITHER`.
+pub const fn method_of(code: u32) -> u32 {
+    code & 3
+}
+
+pub const fn access_of(code: u32) -> u32 {
+    (code >> 14) & 3
+}
+
+
+/// Plain data that can be copied from and to a request buffer: `Copy`, no padding, no pointers
+/// that are dereferenced, and every bit pattern is a valid value.
+///
+/// # Safety
+///
+/// The type must be `#[repr(C)]` (or a primitive/array), valid for any bit pattern, and have no
+/// padding bytes, trailing ones included. [`bytes_of`] exposes every byte, and an IOCTL output is
+/// copied to the caller as is, so padding would leak uninitialized kernel memory; spell it out as
+/// reserved fields instead.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! pod {
+    ($($t:ty),*) => { $(unsafe impl Pod for $t {})* };
+}
+
+pod!((), u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+pub fn bytes_of<T: Pod>(value: &T) -> &[u8] {
+    unsafe { slice::from_raw_parts(value as *const T as *const u8, size_of::<T>()) }
+}
+
+pub fn bytes_of_mut<T: Pod>(value: &mut T) -> &mut [u8] {
+    unsafe { slice::from_raw_parts_mut(value as *mut T as *mut u8, size_of::<T>()) }
+}
+
+
+/// A control code together with its input and output types, declared with
+/// [`ioctl!`](crate::ioctl!). `()` means the request has no input or no output.
+pub trait Ioctl {
+    const CODE: u32;
+    type Input: Pod;
+    type Output: Pod;
+}
+
+
+/// Declares control codes as marker types implementing [`Ioctl`]. Needs nothing from the
+/// bindings, so a user-mode crate can share the definitions with the driver.
+///
+/// ```ignore
+/// use klib_rs::ioctl::{FILE_ANY_ACCESS, FILE_DEVICE_UNKNOWN, FILE_READ_ACCESS, METHOD_BUFFERED, METHOD_NEITHER};
+///
+/// klib_rs::ioctl! {
+///     pub GetVersion(FILE_DEVICE_UNKNOWN, 0x800, METHOD_BUFFERED, FILE_ANY_ACCESS): () => u32;
+///     pub ReadMemory(FILE_DEVICE_UNKNOWN, 0x801, METHOD_NEITHER, FILE_READ_ACCESS): ReadRequest => [u8; 64];
+/// }
+/// ```
+#[macro_export]
+macro_rules! ioctl {
+    ($($(#[$attr:meta])* $vis:vis $name:ident($device_type:expr, $function:expr, $method:expr, $access:expr): $input:ty => $output:ty;)*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Debug, Copy, Clone)]
+            $vis struct $name;
+
+            impl $crate::ioctl::Ioctl for $name {
+                const CODE: u32 = $crate::ioctl::ctl_code($device_type, $function, $method, $access);
+                type Input = $input;
+                type Output = $output;
+            }
+        )*
+    };
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILE_DEVICE_DISK: u32 = 7;
+    const FILE_DEVICE_FILE_SYSTEM: u32 = 9;
+    const IOCTL_STORAGE_BASE: u32 = 0x2D;
+
+    #[repr(C)]
+    #[derive(Copy, Clone)]
+    struct Request {
+        address: u64,
+        length: u32,
+        reserved: u32,
+    }
+
+    unsafe impl Pod for Request {}
+
+    crate::ioctl! {
+        GetVersion(FILE_DEVICE_UNKNOWN, 0x800, METHOD_BUFFERED, FILE_ANY_ACCESS): () => u32;
+        /// Doc comments and attributes are kept.
+        ReadMemory(FILE_DEVICE_UNKNOWN, 0x801, METHOD_NEITHER, FILE_READ_ACCESS): Request => [u8; 64];
+        SetLayout(FILE_DEVICE_DISK, 0x004, METHOD_BUFFERED, FILE_READ_ACCESS | FILE_WRITE_ACCESS): [u8; 16] => ();
+    }
+
+    #[test]
+    fn ctl_codes() {
+        // IOCTL_DISK_GET_DRIVE_GEOMETRY
+        assert_eq!(ctl_code(FILE_DEVICE_DISK, 0, METHOD_BUFFERED, FILE_ANY_ACCESS), 0x0007_0000);
+        // IOCTL_STORAGE_QUERY_PROPERTY
+        assert_eq!(ctl_code(IOCTL_STORAGE_BASE, 0x500, METHOD_BUFFERED, FILE_ANY_ACCESS), 0x002D_1400);
+        // FSCTL_GET_RETRIEVAL_POINTERS
+        assert_eq!(ctl_code(FILE_DEVICE_FILE_SYSTEM, 28, METHOD_NEITHER, FILE_ANY_ACCESS), 0x0009_0073);
+        // IOCTL_DISK_SET_DRIVE_LAYOUT
+        assert_eq!(ctl_code(FILE_DEVICE_DISK, 0x004, METHOD_BUFFERED, FILE_READ_ACCESS | FILE_WRITE_ACCESS), 0x0007_C010);
+    }
+
+    #[test]
+    fn fields() {
+        let code = 0x0009_0073;
+        assert_eq!((device_type_of(code), function_of(code), method_of(code), access_of(code)), (9, 28, METHOD_NEITHER, FILE_ANY_ACCESS));
+        let code = 0x0007_C010;
+        assert_eq!((device_type_of(code), function_of(code), method_of(code), access_of(code)),
+                   (FILE_DEVICE_DISK, 4, METHOD_BUFFERED, FILE_READ_ACCESS | FILE_WRITE_ACCESS));
+        for method in [METHOD_BUFFERED, METHOD_IN_DIRECT, METHOD_OUT_DIRECT, METHOD_NEITHER] {
+            let code = ctl_code(FILE_DEVICE_UNKNOWN, 0xFFF, method, FILE_WRITE_ACCESS);
+            assert_eq!((device_type_of(code), function_of(code), method_of(code), access_of(code)),
+                       (FILE_DEVICE_UNKNOWN, 0xFFF, method, FILE_WRITE_ACCESS));
+        }
+    }
+
+    #[test]
+    fn declared_codes() {
+        assert_eq!(GetVersion::CODE, 0x0022_2000);
+        assert_eq!(ReadMemory::CODE, 0x0022_6007);
+        assert_eq!(SetLayout::CODE, 0x0007_C010);
+        assert_eq!(method_of(ReadMemory::CODE), METHOD_NEITHER);
+        assert_eq!(size_of::<<ReadMemory as Ioctl>::Input>(), 16);
+        assert_eq!(size_of::<<GetVersion as Ioctl>::Output>(), 4);
+    }
+}
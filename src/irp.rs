@@ -0,0 +1,431 @@
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ops::Deref;
+use core::ptr;
+use core::slice;
+use crate::*;
+use crate::ioctl::{self, Ioctl};
+use crate::irql::{self, APC_LEVEL, DISPATCH_LEVEL};
+use crate::memory::probe;
+
+
+/// Proof that a dispatch routine has completed or pended its IRP; the only way to get one is
+/// through [`Irp::complete`] and friends, so a handler cannot return without doing either.
+/// It carries the lifetime of the `Irp` it came from, so completing some other IRP does not
+/// produce one the handler could return for its own.
+#[must_use]
+pub struct Completion<'irp>(NTSTATUS, Brand<'irp>);
+
+impl Completion<'_> {
+    pub fn status(&self) -> NTSTATUS {
+        self.0
+    }
+
+    // for code that pends an IRP some other way, e.g. `IoCsqInsertIrpEx` marking it itself
+    pub(crate) fn pending() -> Self {
+        Completion(NtStatus::PENDING.raw(), PhantomData)
+    }
+}
+
+// invariant, so neither an `Irp` nor a `Completion` can be passed off as one of another lifetime
+type Brand<'irp> = PhantomData<fn(&'irp ()) -> &'irp ()>;
+
+
+/// The request-specific part of the current stack location.
+#[derive(Debug, Copy, Clone)]
+pub enum Parameters {
+    Create { options: u32, share_access: u16, file_attributes: u16 },
+    Read { length: u32, offset: i64 },
+    Write { length: u32, offset: i64 },
+    DeviceControl { code: u32, input_length: u32, output_length: u32, type3_input: PVOID },
+    InternalDeviceControl { code: u32, input_length: u32, output_length: u32, type3_input: PVOID },
+    Other,
+}
+
+
+/// An IRP owned by the driver. It must be completed exactly once: [`Irp::complete`] consumes it,
+/// and [`Irp::into_raw`] hands ownership to something that completes it later (a queue).
+/// Dropping one instead completes it with `STATUS_UNSUCCESSFUL`, since a lost IRP hangs the thread
+/// that sent it.
+///
+/// The lifetime brands the IRP a dispatch routine was called with, and its [`Completion`].
+#[must_use = "an IRP must be completed"]
+pub struct Irp<'irp> {
+    raw: PIRP,
+    brand: Brand<'irp>,
+}
+
+unsafe impl Send for Irp<'_> {}
+
+impl<'irp> Irp<'irp> {
+    /// # Safety
+    ///
+    /// `raw` must be an IRP the caller owns, e.g. the one passed to a dispatch routine, and
+    /// nothing else may complete it.
+    pub unsafe fn from_raw(raw: PIRP) -> Self {
+        Irp { raw, brand: PhantomData }
+    }
+
+    pub fn as_raw(&self) -> PIRP {
+        self.raw
+    }
+
+    /// Gives up ownership without completing the IRP.
+    pub fn into_raw(self) -> PIRP {
+        ManuallyDrop::new(self).raw
+    }
+
+    /// `IoGetCurrentIrpStackLocation`.
+    pub fn stack(&self) -> &IO_STACK_LOCATION {
+        unsafe { &*(*self.raw).Tail.Overlay.__bindgen_anon_2.__bindgen_anon_1.CurrentStackLocation }
+    }
+
+    fn stack_mut(&mut self) -> &mut IO_STACK_LOCATION {
+        unsafe { &mut *(*self.raw).Tail.Overlay.__bindgen_anon_2.__bindgen_anon_1.CurrentStackLocation }
+    }
+
+    pub fn major(&self) -> u8 {
+        self.stack().MajorFunction
+    }
+
+    pub fn minor(&self) -> u8 {
+        self.stack().MinorFunction
+    }
+
+    pub fn device_object(&self) -> PDEVICE_OBJECT {
+        self.stack().DeviceObject
+    }
+
+    pub fn file_object(&self) -> PFILE_OBJECT {
+        self.stack().FileObject
+    }
+
+    pub fn requestor_mode(&self) -> KPROCESSOR_MODE {
+        unsafe { (*self.raw).RequestorMode }
+    }
+
+    /// Whether the request came from user mode, so buffers and parameters are untrusted.
+    pub fn from_user_mode(&self) -> bool {
+        self.requestor_mode() == _MODE_UserMode as KPROCESSOR_MODE
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        unsafe { (*self.raw).Cancel != 0 }
+    }
+
+    pub fn parameters(&self) -> Parameters {
+        let stack = self.stack();
+        unsafe {
+            match stack.MajorFunction as u32 {
+                IRP_MJ_CREATE => {
+                    let p = stack.Parameters.Create;
+                    Parameters::Create { options: p.Options, share_access: p.ShareAccess, file_attributes: p.FileAttributes }
+                }
+                IRP_MJ_READ => {
+                    let p = stack.Parameters.Read;
+                    Parameters::Read { length: p.Length, offset: p.ByteOffset.QuadPart }
+                }
+                IRP_MJ_WRITE => {
+                    let p = stack.Parameters.Write;
+                    Parameters::Write { length: p.Length, offset: p.ByteOffset.QuadPart }
+                }
+                IRP_MJ_DEVICE_CONTROL => {
+                    let p = stack.Parameters.DeviceIoControl;
+                    Parameters::DeviceControl { code: p.IoControlCode, input_length: p.InputBufferLength,
+                                                output_length: p.OutputBufferLength, type3_input: p.Type3InputBuffer }
+                }
+                IRP_MJ_INTERNAL_DEVICE_CONTROL => {
+                    let p = stack.Parameters.DeviceIoControl;
+                    Parameters::InternalDeviceControl { code: p.IoControlCode, input_length: p.InputBufferLength,
+                                                        output_length: p.OutputBufferLength, type3_input: p.Type3InputBuffer }
+                }
+                _ => Parameters::Other,
+            }
+        }
+    }
+
+    /// `AssociatedIrp.SystemBuffer`: the kernel copy used by buffered I/O and `METHOD_BUFFERED`.
+    pub fn system_buffer(&self) -> PVOID {
+        unsafe { (*self.raw).AssociatedIrp.SystemBuffer }
+    }
+
+    // what the I/O manager allocated for the system buffer, as far as the stack location tells
+    fn system_buffer_len(&self) -> usize {
+        match self.parameters() {
+            Parameters::Read { length, .. } | Parameters::Write { length, .. } => length as usize,
+            Parameters::DeviceControl { code, input_length, output_length, .. }
+            | Parameters::InternalDeviceControl { code, input_length, output_length, .. } => match ioctl::method_of(code) {
+                METHOD_BUFFERED => input_length.max(output_length) as usize,
+                METHOD_NEITHER => 0,
+                // the direct methods buffer only the input
+                _ => input_length as usize,
+            },
+            _ => 0,
+        }
+    }
+
+    /// Up to `len` bytes of the system buffer, cut to the size the request gave it: the `Length`
+    /// of a buffered read or write, the input length of an IOCTL, or for `METHOD_BUFFERED` the larger
+    /// of the input and output lengths, since it holds the input on entry and the output on
+    /// completion. `None` for other requests or an empty buffer.
+    pub fn buffered(&mut self, len: usize) -> Option<&mut [u8]> {
+        let buffer = self.system_buffer();
+        let len = len.min(self.system_buffer_len());
+        if buffer.is_null() || len == 0 {
+            return None;
+        }
+        Some(unsafe { slice::from_raw_parts_mut(buffer as *mut u8, len) })
+    }
+
+    /// `Irp->MdlAddress`, set for direct I/O and `METHOD_IN/OUT_DIRECT`.
+    pub fn mdl(&self) -> PMDL {
+        unsafe { (*self.raw).MdlAddress }
+    }
+
+    /// The pages described by `MdlAddress`, mapped into system space
+    /// (`MmGetSystemAddressForMdlSafe`, non-executable). IRQL <= DISPATCH_LEVEL.
+    pub fn mdl_buffer(&mut self) -> Result<&mut [u8], NtStatus> {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
+        let mdl = self.mdl();
+        if mdl.is_null() {
+            return Err(NtStatus::INVALID_PARAMETER);
+        }
+        unsafe {
+            let mapped = ((*mdl).MdlFlags as u32) & (MDL_MAPPED_TO_SYSTEM_VA | MDL_SOURCE_IS_NONPAGED_POOL) != 0;
+            let va = if mapped {
+                (*mdl).MappedSystemVa
+            } else {
+                MmMapLockedPagesSpecifyCache(mdl, _MODE_KernelMode as _, _MEMORY_CACHING_TYPE_MmCached, ptr::null_mut(),
+                                             FALSE, _MM_PAGE_PRIORITY_NormalPagePriority as u32 | MdlMappingNoExecute)
+            };
+            if va.is_null() {
+                return Err(NtStatus::INSUFFICIENT_RESOURCES);
+            }
+            Ok(slice::from_raw_parts_mut(va as *mut u8, (*mdl).ByteCount as usize))
+        }
+    }
+
+    /// `Irp->UserBuffer`: the caller's own address for neither I/O and the output of
+    /// `METHOD_NEITHER`. Only valid in the caller's process context, and must be probed.
+    pub fn user_buffer(&self) -> PVOID {
+        unsafe { (*self.raw).UserBuffer }
+    }
+
+    pub fn information(&self) -> usize {
+        unsafe { (*self.raw).IoStatus.Information as usize }
+    }
+
+    /// `IoStatus.Information`, e.g. the number of bytes returned.
+    pub fn set_information(&mut self, information: usize) {
+        unsafe { (*self.raw).IoStatus.Information = information as _ };
+    }
+
+    /// Sets `IoStatus.Status` and calls `IofCompleteRequest`. IRQL <= DISPATCH_LEVEL.
+    pub fn complete(self, status: NtStatus) -> Completion<'irp> {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
+        let raw = self.into_raw();
+        unsafe {
+            (*raw).IoStatus.__bindgen_anon_1.Status = status.raw();
+            IofCompleteRequest(raw, IO_NO_INCREMENT as _);
+        }
+        Completion(status.raw(), PhantomData)
+    }
+
+    /// [`Irp::complete`] with `IoStatus.Information` set to `information`.
+    pub fn complete_with(mut self, status: NtStatus, information: usize) -> Completion<'irp> {
+        self.set_information(information);
+        self.complete(status)
+    }
+
+    /// `IoMarkIrpPending`: the dispatch routine returns the `STATUS_PENDING` completion and the
+    /// [`PendingIrp`] is completed later.
+    pub fn mark_pending(mut self) -> (PendingIrp, Completion<'irp>) {
+        self.stack_mut().Control |= SL_PENDING_RETURNED as u8;
+        (unsafe { PendingIrp::from_raw(self.into_raw()) }, Completion::pending())
+    }
+}
+
+
+/// An IRP marked pending by [`Irp::mark_pending`]. The dispatch routine already has its
+/// `STATUS_PENDING` completion, so completing this one yields none that could be returned instead.
+/// Dropping it completes the IRP with `STATUS_UNSUCCESSFUL`, like an `Irp`.
+#[must_use = "a pending IRP must be completed"]
+pub struct PendingIrp {
+    irp: Irp<'static>,
+}
+
+impl PendingIrp {
+    // for IRPs that were marked pending some other way, e.g. by `IoCsqInsertIrpEx`
+    pub(crate) unsafe fn from_raw(raw: PIRP) -> Self {
+        PendingIrp { irp: unsafe { Irp::from_raw(raw) } }
+    }
+
+    /// Gives up ownership without completing the IRP.
+    pub fn into_raw(self) -> PIRP {
+        self.irp.into_raw()
+    }
+
+    // forwarded rather than a `DerefMut`, through which the `Irp` could be swapped out and
+    // completed for a `Completion`
+    pub fn buffered(&mut self, len: usize) -> Option<&mut [u8]> {
+        self.irp.buffered(len)
+    }
+
+    pub fn mdl_buffer(&mut self) -> Result<&mut [u8], NtStatus> {
+        self.irp.mdl_buffer()
+    }
+
+    pub fn set_information(&mut self, information: usize) {
+        self.irp.set_information(information);
+    }
+
+    pub fn write_ioctl_output<I: Ioctl>(&mut self, output: &I::Output) -> Result<usize, NtStatus> {
+        self.irp.write_ioctl_output::<I>(output)
+    }
+
+    /// [`Irp::complete`]. IRQL <= DISPATCH_LEVEL.
+    pub fn complete(self, status: NtStatus) {
+        let _ = self.irp.complete(status);
+    }
+
+    /// [`Irp::complete_with`].
+    pub fn complete_with(self, status: NtStatus, information: usize) {
+        let _ = self.irp.complete_with(status, information);
+    }
+}
+
+impl Deref for PendingIrp {
+    type Target = Irp<'static>;
+    fn deref(&self) -> &Irp<'static> {
+        &self.irp
+    }
+}
+
+
+
+// METHOD_NEITHER buffers are raw caller addresses: user ones are range- and alignment-checked
+// and copied through the fault-tolerant probe helpers
+fn check_neither<T>(irp: &Irp, addr: *const u8) -> Result<bool, NtStatus> {
+    if addr.is_null() {
+        return Err(NtStatus::INVALID_USER_BUFFER);
+    }
+    if !irp.from_user_mode() {
+        return Ok(false);
+    }
+    if !(addr as usize).is_multiple_of(align_of::<T>()) {
+        return Err(NtStatus::DATATYPE_MISALIGNMENT_ERROR);
+    }
+    if !probe::is_user_range(addr, size_of::<T>()) {
+        return Err(NtStatus::ACCESS_VIOLATION);
+    }
+    Ok(true)
+}
+
+impl<'irp> Irp<'irp> {
+    /// The control code of an `IRP_MJ_(INTERNAL_)DEVICE_CONTROL` request.
+    pub fn ioctl_code(&self) -> Option<u32> {
+        match self.parameters() {
+            Parameters::DeviceControl { code, .. } | Parameters::InternalDeviceControl { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+
+    fn ioctl_lengths<I: Ioctl>(&self) -> Result<(usize, usize, PVOID), NtStatus> {
+        match self.parameters() {
+            Parameters::DeviceControl { code, input_length, output_length, type3_input }
+            | Parameters::InternalDeviceControl { code, input_length, output_length, type3_input } if code == I::CODE => {
+                Ok((input_length as usize, output_length as usize, type3_input))
+            }
+            _ => Err(NtStatus::INVALID_DEVICE_REQUEST),
+        }
+    }
+
+    /// Captures the input of `I` after checking the control code and buffer size. For
+    /// `METHOD_NEITHER` from user mode the buffer is checked and copied without trusting it.
+    /// IRQL <= APC_LEVEL for `METHOD_NEITHER`, DISPATCH_LEVEL otherwise.
+    pub fn ioctl_input<I: Ioctl>(&self) -> Result<I::Input, NtStatus> {
+        let (input_length, _, type3_input) = self.ioctl_lengths::<I>()?;
+        if size_of::<I::Input>() == 0 {
+            return Ok(unsafe { core::mem::zeroed() });
+        }
+        if input_length < size_of::<I::Input>() {
+            return Err(NtStatus::INVALID_PARAMETER);
+        }
+
+        if ioctl::method_of(I::CODE) == METHOD_NEITHER {
+            irql::debug_assert_at_most(APC_LEVEL);
+            let src = type3_input as *const I::Input;
+            return if check_neither::<I::Input>(self, src as _)? {
+                unsafe { probe::try_read(src) }
+            } else {
+                Ok(unsafe { ptr::read_unaligned(src) })
+            };
+        }
+
+        let buffer = self.system_buffer();
+        if buffer.is_null() {
+            return Err(NtStatus::INVALID_USER_BUFFER);
+        }
+        Ok(unsafe { ptr::read_unaligned(buffer as *const I::Input) })
+    }
+
+    /// Copies `output` to where `I`'s method puts it and returns the byte count for `Information`.
+    /// `METHOD_IN_DIRECT` codes have no output buffer, so a non-empty output fails with
+    /// `STATUS_INVALID_DEVICE_REQUEST`.
+    pub fn write_ioctl_output<I: Ioctl>(&mut self, output: &I::Output) -> Result<usize, NtStatus> {
+        let (_, output_length, _) = self.ioctl_lengths::<I>()?;
+        let bytes = ioctl::bytes_of(output);
+        if bytes.is_empty() {
+            return Ok(0);
+        }
+        if output_length < bytes.len() {
+            return Err(NtStatus::BUFFER_TOO_SMALL);
+        }
+
+        match ioctl::method_of(I::CODE) {
+            METHOD_BUFFERED => {
+                let buffer = self.buffered(bytes.len()).ok_or(NtStatus::INVALID_USER_BUFFER)?;
+                buffer.copy_from_slice(bytes);
+            }
+            METHOD_NEITHER => {
+                irql::debug_assert_at_most(APC_LEVEL);
+                let dst = self.user_buffer() as *mut u8;
+                if check_neither::<I::Output>(self, dst)? {
+                    unsafe { probe::try_copy_to_user(dst, bytes.as_ptr(), bytes.len())? };
+                } else {
+                    unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), dst, bytes.len()) };
+                }
+            }
+            METHOD_OUT_DIRECT => {
+                let buffer = self.mdl_buffer()?;
+                buffer.get_mut(..bytes.len()).ok_or(NtStatus::BUFFER_TOO_SMALL)?.copy_from_slice(bytes);
+            }
+            // the MDL of METHOD_IN_DIRECT describes a buffer the caller only granted read access to
+            _ => return Err(NtStatus::INVALID_DEVICE_REQUEST),
+        }
+        Ok(bytes.len())
+    }
+
+    /// Captures the input, runs `f` and completes the IRP with its output or error. The size
+    /// of the output buffer is checked before `f` runs, so a short buffer does not waste the work.
+    pub fn handle_ioctl<I: Ioctl>(mut self, f: impl FnOnce(I::Input) -> Result<I::Output, NtStatus>) -> Completion<'irp> {
+        let result = self.ioctl_lengths::<I>().and_then(|(_, output_length, _)| {
+            if output_length < size_of::<I::Output>() {
+                return Err(NtStatus::BUFFER_TOO_SMALL);
+            }
+            let output = f(self.ioctl_input::<I>()?)?;
+            self.write_ioctl_output::<I>(&output)
+        });
+        match result {
+            Ok(written) => self.complete_with(NtStatus::SUCCESS, written),
+            Err(status) => self.complete_with(status, 0),
+        }
+    }
+}
+
+impl Drop for Irp<'_> {
+    fn drop(&mut self) {
+        let irp = Irp { raw: self.raw, brand: self.brand };
+        let _ = irp.complete(NtStatus::UNSUCCESSFUL);
+    }
+}
@@ -0,0 +1,191 @@
+use core::marker::PhantomData;
+use crate::*;
+
+pub const PASSIVE_LEVEL: KIRQL = 0;
+pub const APC_LEVEL: KIRQL = 1;
+pub const DISPATCH_LEVEL: KIRQL = 2;
+pub const HIGH_LEVEL: KIRQL = 15;
+
+
+/// `KeGetCurrentIrql`; on x64 this is the inline CR8 read the WDK headers use.
+#[inline(always)]
+pub fn current() -> KIRQL {
+    #[cfg(target_arch = "x86_64")]
+    unsafe {
+        let irql: u64;
+        core::arch::asm!("mov {}, cr8", out(reg) irql, options(nomem, nostack, preserves_flags));
+        irql as KIRQL
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    unsafe { KeGetCurrentIrql() }
+}
+
+#[inline(always)]
+unsafe fn write_irql(irql: KIRQL) {
+    #[cfg(target_arch = "x86_64")]
+    unsafe { core::arch::asm!("mov cr8, {}", in(reg) irql as u64, options(nomem, nostack, preserves_flags)) };
+    #[cfg(not(target_arch = "x86_64"))]
+    unsafe { KeLowerIrql(irql) };
+}
+
+/// Debug builds panic when the current IRQL is above `max`; release builds compile this out.
+#[inline(always)]
+#[track_caller]
+pub fn debug_assert_at_most(max: KIRQL) {
+    if cfg!(debug_assertions) {
+        let irql = current();
+        assert!(irql <= max, "running at IRQL {} where at most {} is allowed", irql, max);
+    }
+}
+
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// Proof that the current thread runs at or below `MAX`.
+///
+/// Tokens are `!Send`, so a proof cannot leave the thread it was taken on. Functions that only work
+/// at low IRQL borrow one: `&Passive` where waiting on objects or touching paged memory with
+/// blocking I/O is needed, `&impl BelowDispatch` for paged memory access, `&impl Irql` for code
+/// that runs up to DISPATCH_LEVEL.
+///
+/// Tokens are neither `Copy` nor `Clone`. Anything that raises the IRQL ([`raise_irql`], the spin
+/// locks) mutably borrows the token until it lowers again, so no reference to it can vouch for the
+/// old IRQL meanwhile. `get` checks the IRQL each time it is called; hold on to one token and pass
+/// references rather than taking another one inside a raised section.
+pub trait Irql: sealed::Sealed {
+    const MAX: KIRQL;
+}
+
+/// IRQL <= APC_LEVEL: paged memory may be touched.
+pub trait BelowDispatch: Irql {}
+
+
+#[derive(Debug)]
+pub struct Passive(PhantomData<*mut ()>);
+
+#[derive(Debug)]
+pub struct Apc(PhantomData<*mut ()>);
+
+#[derive(Debug)]
+pub struct Dispatch(PhantomData<*mut ()>);
+
+macro_rules! token {
+    ($name:ident, $max:expr) => {
+        impl sealed::Sealed for $name {}
+
+        impl Irql for $name {
+            const MAX: KIRQL = $max;
+        }
+
+        impl $name {
+            /// Checks the current IRQL and returns the token if it is low enough.
+            pub fn get() -> Option<Self> {
+                if current() <= $max { Some($name(PhantomData)) } else { None }
+            }
+
+            /// Takes the token without checking in release builds.
+            ///
+            /// # Safety
+            ///
+            /// The caller must know the current IRQL is at or below the token's level, e.g. because
+            /// the function is a dispatch routine or a callback documented to run there.
+            #[track_caller]
+            pub unsafe fn assume() -> Self {
+                debug_assert_at_most($max);
+                $name(PhantomData)
+            }
+        }
+    };
+}
+
+token!(Passive, PASSIVE_LEVEL);
+token!(Apc, APC_LEVEL);
+token!(Dispatch, DISPATCH_LEVEL);
+
+impl BelowDispatch for Passive {}
+impl BelowDispatch for Apc {}
+
+impl Passive {
+    /// A passive token also proves IRQL <= APC_LEVEL, for as long as it is borrowed.
+    pub fn apc(&self) -> &Apc {
+        &Apc(PhantomData)
+    }
+}
+
+impl Apc {
+    pub fn dispatch(&self) -> &Dispatch {
+        &Dispatch(PhantomData)
+    }
+}
+
+
+/// Restores the previous IRQL with `KeLowerIrql` when dropped.
+///
+/// The guard mutably borrows the token the raise was made with, so that token cannot vouch for the
+/// old, lower IRQL while the guard lives.
+#[must_use = "the IRQL is lowered again as soon as the guard is dropped"]
+pub struct IrqlGuard<'a> {
+    old: KIRQL,
+    _token: PhantomData<&'a mut ()>,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl IrqlGuard<'_> {
+    pub fn old_irql(&self) -> KIRQL {
+        self.old
+    }
+}
+
+impl Drop for IrqlGuard<'_> {
+    fn drop(&mut self) {
+        unsafe { write_irql(self.old) };
+    }
+}
+
+/// `KeRaiseIrql` to `new`, lowering back when the guard drops. Raising to a lower IRQL than the
+/// current one is a bugcheck in the kernel and a panic here in debug builds.
+///
+/// ```ignore
+/// let mut passive = irql::Passive::get().unwrap();
+/// let guard = irql::raise_irql(irql::DISPATCH_LEVEL, &mut passive);
+/// // `passive` cannot be used here
+/// drop(guard);
+/// ```
+#[track_caller]
+pub fn raise_irql<T: Irql>(new: KIRQL, _irql: &mut T) -> IrqlGuard<'_> {
+    let old = current();
+    debug_assert!(new >= old, "raise_irql({}) called at IRQL {}", new, old);
+    unsafe { write_irql(new) };
+    IrqlGuard { old, _token: PhantomData, _not_send: PhantomData }
+}
+
+/// Raises to DISPATCH_LEVEL and returns the matching token with the guard.
+#[track_caller]
+pub fn raise_to_dispatch<T: Irql>(irql: &mut T) -> (DispatchGuard<'_>, Dispatch) {
+    (DispatchGuard { guard: raise_irql(DISPATCH_LEVEL, irql) }, Dispatch(PhantomData))
+}
+
+
+/// Held while the current processor runs at DISPATCH_LEVEL, where the thread can neither be
+/// preempted nor migrate to another processor. A [`Dispatch`] token is not enough for that: it only
+/// proves the IRQL is at most DISPATCH_LEVEL.
+///
+/// Implemented by the guard of [`raise_to_dispatch`] and by the spin lock guards.
+pub trait AtDispatch: sealed::Sealed {}
+
+/// The guard of [`raise_to_dispatch`]; lowers back to the previous IRQL when dropped.
+#[must_use = "the IRQL is lowered again as soon as the guard is dropped"]
+pub struct DispatchGuard<'a> {
+    guard: IrqlGuard<'a>,
+}
+
+impl DispatchGuard<'_> {
+    pub fn old_irql(&self) -> KIRQL {
+        self.guard.old_irql()
+    }
+}
+
+impl sealed::Sealed for DispatchGuard<'_> {}
+impl AtDispatch for DispatchGuard<'_> {}
@@ -2,10 +2,13 @@ use core::ptr;
 use core::alloc::{GlobalAlloc, Layout};
 use core::ffi::c_void;
 use crate::*;
+use crate::irql::{self, DISPATCH_LEVEL};
 
+/// Allocates from non-paged pool, so it may be used at IRQL <= DISPATCH_LEVEL.
 pub struct KernelAllocator;
 unsafe impl GlobalAlloc for KernelAllocator {
     unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
         unsafe {
             let size = core::cmp::max(layout.size(), 1);
             let align = core::cmp::max(layout.align(), size_of::<usize>());
@@ -34,6 +37,7 @@ unsafe impl GlobalAlloc for KernelAllocator {
     }
 
     unsafe fn dealloc(&self, ptr: *mut u8, _layout: Layout) {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
         unsafe {
             if ptr.is_null() {
                 return;
@@ -8,6 +8,8 @@ use iced_x86::{Decoder, DecoderOptions};
 use crate::*;
 #[cfg(feature = "kernel")]
 use crate::memory::rw::write_to_read_only_memory;
+#[cfg(feature = "kernel")]
+use crate::irql::{self, Apc, BelowDispatch, APC_LEVEL};
 
 const TRAMPOLINE_JMP_SIZE: usize = 12;
 const MAX_READ_BYTES: usize = 64;
@@ -42,7 +44,8 @@ impl Hook {
         self.stub2real as u64
     }
 
-    pub fn set_hook(addr: *mut u8, hook: u64, unset_drop: bool) -> Result<Self, NTSTATUS> {
+    pub fn set_hook(addr: *mut u8, hook: u64, unset_drop: bool, irql: &impl BelowDispatch) -> Result<Self, NtStatus> {
+        irql::debug_assert_at_most(APC_LEVEL);
         unsafe {
             let mut patch_len = 0usize;
             let mut stolen = Vec::new();
@@ -63,7 +66,7 @@ impl Hook {
             let stub = alloc::alloc::alloc(Layout::from_size_align(tramp_size, 16).unwrap());
 
             if stub.is_null() {
-                return Err(STATUS_NO_MEMORY);
+                return Err(NtStatus::NO_MEMORY);
             }
 
             let tramp_slice = slice::from_raw_parts_mut(stub, tramp_size);
@@ -74,8 +77,9 @@ impl Hook {
             let mut patch = vec![0x90u8; patch_len];
             build_abs_jump(&mut patch[..JMP_ABS_SIZE], hook);
 
-            if !write_to_read_only_memory(addr as _, patch.as_ptr(), patch_len) {
-                return Err(STATUS_NO_MEMORY);
+            if let Err(e) = write_to_read_only_memory(addr as _, patch.as_ptr(), patch_len, irql) {
+                alloc::alloc::dealloc(stub, Layout::from_size_align(tramp_size, 16).unwrap());
+                return Err(e);
             }
 
             Ok(Hook {
@@ -87,15 +91,14 @@ impl Hook {
         }
     }
 
-    pub fn free_hook(&self) -> Result<(), NTSTATUS> {
+    pub fn free_hook(&self, irql: &impl BelowDispatch) -> Result<(), NtStatus> {
+        irql::debug_assert_at_most(APC_LEVEL);
         unsafe {
             if self.hooked == 0 {
-                return Err(STATUS_INVALID_PARAMETER);
+                return Err(NtStatus::INVALID_PARAMETER);
             }
 
-            if !write_to_read_only_memory(self.hooked as PVOID, self.original_bytes.as_ptr(), self.original_bytes.len()) {
-                return Err(STATUS_UNSUCCESSFUL);
-            }
+            write_to_read_only_memory(self.hooked as PVOID, self.original_bytes.as_ptr(), self.original_bytes.len(), irql)?;
 
             alloc::alloc::dealloc(self.stub2real, Layout::from_size_align(self.original_bytes.len() + JMP_ABS_SIZE, 16).unwrap());
             Ok(())
@@ -106,8 +109,9 @@ impl Hook {
 
 impl Drop for Hook {
     fn drop(&mut self) {
-        if self.unset_drop {
-            let _ = self.free_hook().ok();
+        // above APC_LEVEL the original bytes cannot be written back and the hook stays in place
+        if self.unset_drop && let Some(irql) = Apc::get() {
+            let _ = self.free_hook(&irql).ok();
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,197 @@
+use core::fmt::{self, Write};
+use core::sync::atomic::{AtomicU32, AtomicU8, Ordering};
+use crate::*;
+
+const LOG_BUFFER_SIZE: usize = 512;
+const TRUNCATED_MARKER: &[u8] = b"...\n";
+
+pub const DPFLTR_IHVDRIVER_ID: u32 = _DPFLTR_TYPE_DPFLTR_IHVDRIVER_ID as u32;
+
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        }
+    }
+
+    /// The `DPFLTR_*_LEVEL` passed to `DbgPrintEx`; debug and trace both map to `DPFLTR_TRACE_LEVEL`.
+    pub const fn dpfltr_level(self) -> u32 {
+        match self {
+            Level::Error => DPFLTR_ERROR_LEVEL,
+            Level::Warn => DPFLTR_WARNING_LEVEL,
+            Level::Info => DPFLTR_INFO_LEVEL,
+            Level::Debug | Level::Trace => DPFLTR_TRACE_LEVEL,
+        }
+    }
+}
+
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LevelFilter {
+    Off = 0,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LevelFilter {
+    const fn from_u8(v: u8) -> LevelFilter {
+        match v {
+            0 => LevelFilter::Off,
+            1 => LevelFilter::Error,
+            2 => LevelFilter::Warn,
+            3 => LevelFilter::Info,
+            4 => LevelFilter::Debug,
+            _ => LevelFilter::Trace,
+        }
+    }
+}
+
+
+/// Upper bound fixed at compile time by the `log-max-level-*` features; calls above it are compiled out.
+pub const STATIC_MAX_LEVEL: LevelFilter = if cfg!(feature = "log-max-level-off") {
+    LevelFilter::Off
+} else if cfg!(feature = "log-max-level-error") {
+    LevelFilter::Error
+} else if cfg!(feature = "log-max-level-warn") {
+    LevelFilter::Warn
+} else if cfg!(feature = "log-max-level-info") {
+    LevelFilter::Info
+} else if cfg!(feature = "log-max-level-debug") {
+    LevelFilter::Debug
+} else {
+    LevelFilter::Trace
+};
+
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(LevelFilter::Trace as u8);
+static COMPONENT_ID: AtomicU32 = AtomicU32::new(DPFLTR_IHVDRIVER_ID);
+
+
+/// Sets the `DbgPrintEx` component id and the runtime level filter.
+pub fn init(component_id: u32, level: LevelFilter) {
+    COMPONENT_ID.store(component_id, Ordering::Relaxed);
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn set_max_level(level: LevelFilter) {
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+#[inline(always)]
+pub fn max_level() -> LevelFilter {
+    LevelFilter::from_u8(MAX_LEVEL.load(Ordering::Relaxed))
+}
+
+#[inline(always)]
+pub fn enabled(level: Level) -> bool {
+    (level as u8) <= (STATIC_MAX_LEVEL as u8) && (level as u8) <= (max_level() as u8)
+}
+
+
+/// Fixed-size, NUL terminated line buffer; output past the end is dropped and marked with `...`.
+struct LineBuffer {
+    buf: [u8; LOG_BUFFER_SIZE],
+    len: usize,
+    truncated: bool,
+}
+
+impl LineBuffer {
+    const fn new() -> Self {
+        LineBuffer { buf: [0; LOG_BUFFER_SIZE], len: 0, truncated: false }
+    }
+
+    fn finish(&mut self) -> *const u8 {
+        if self.truncated {
+            let at = LOG_BUFFER_SIZE - 1 - TRUNCATED_MARKER.len();
+            self.buf[at..at + TRUNCATED_MARKER.len()].copy_from_slice(TRUNCATED_MARKER);
+            self.len = at + TRUNCATED_MARKER.len();
+        } else if self.len == 0 || self.buf[self.len - 1] != b'\n' {
+            let _ = self.write_str("\n");
+        }
+        self.buf[self.len] = 0;
+        self.buf.as_ptr()
+    }
+}
+
+impl Write for LineBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // one byte is always kept for the terminating NUL
+        let room = LOG_BUFFER_SIZE - 1 - self.len;
+        let bytes = s.as_bytes();
+        let n = core::cmp::min(room, bytes.len());
+        self.buf[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+        self.len += n;
+        if n < bytes.len() {
+            self.truncated = true;
+        }
+        Ok(())
+    }
+}
+
+
+/// Formats into a stack buffer and hands it to `DbgPrintEx`. Never allocates, callable at any IRQL <= DIRQL.
+#[doc(hidden)]
+pub fn __private_log(level: Level, args: fmt::Arguments) {
+    let mut line = LineBuffer::new();
+    let _ = write!(line, "[{}] ", level.as_str());
+    let _ = line.write_fmt(args);
+    let text = line.finish();
+
+    unsafe {
+        DbgPrintEx(COMPONENT_ID.load(Ordering::Relaxed), level.dpfltr_level(), c"%s".as_ptr(), text);
+    }
+}
+
+
+#[macro_export]
+macro_rules! log {
+    ($lvl:expr, $($arg:tt)+) => {{
+        let lvl: $crate::klog::Level = $lvl;
+        if $crate::klog::enabled(lvl) {
+            $crate::klog::__private_log(lvl, format_args!($($arg)+));
+        }
+    }};
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::klog::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::klog::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::klog::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::klog::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::klog::Level::Trace, $($arg)+) };
+}
@@ -0,0 +1,253 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::cell::UnsafeCell;
+use core::ptr;
+use crate::*;
+use crate::irql::{self, BelowDispatch, Passive, PASSIVE_LEVEL};
+use crate::sync::{wait, KEvent, Timeout, WaitResult, Waitable};
+
+
+/// Cooperative cancellation for a spawned thread. It is a notification event, so the thread can
+/// also pass it to `sync::wait_any` next to its own work objects.
+#[derive(Clone)]
+pub struct StopToken {
+    event: Arc<KEvent>,
+}
+
+impl StopToken {
+    fn new() -> Self {
+        StopToken { event: Arc::new(KEvent::notification(false)) }
+    }
+
+    pub fn is_stop_requested(&self) -> bool {
+        self.event.is_set()
+    }
+
+    /// IRQL <= DISPATCH_LEVEL.
+    pub fn request_stop(&self) {
+        self.event.set();
+    }
+
+    /// Waits until a stop is requested; `Timeout` means it was not.
+    pub fn wait(&self, timeout: impl Into<Timeout>, irql: &impl BelowDispatch) -> Result<WaitResult, NtStatus> {
+        self.event.wait(timeout, irql)
+    }
+}
+
+unsafe impl Waitable for StopToken {
+    fn dispatcher_object(&self) -> PVOID {
+        self.event.dispatcher_object()
+    }
+}
+
+
+struct Packet<T> {
+    result: UnsafeCell<Option<T>>,
+}
+
+unsafe impl<T: Send> Sync for Packet<T> {}
+
+struct Start<F, T> {
+    f: F,
+    priority: Option<KPRIORITY>,
+    affinity: Option<GROUP_AFFINITY>,
+    stop: StopToken,
+    packet: Arc<Packet<T>>,
+}
+
+unsafe extern "C" fn start<F, T>(context: PVOID)
+where
+    F: FnOnce(StopToken) -> T,
+{
+    let start = unsafe { Box::from_raw(context as *mut Start<F, T>) };
+    let Start { f, priority, affinity, stop, packet } = *start;
+    unsafe {
+        if let Some(priority) = priority {
+            KeSetPriorityThread(KeGetCurrentThread(), priority);
+        }
+        if let Some(mut affinity) = affinity {
+            KeSetSystemGroupAffinityThread(&mut affinity, ptr::null_mut());
+        }
+    }
+    let value = f(stop);
+    // read by the joiner after the thread object is signaled
+    unsafe { *packet.result.get() = Some(value) };
+}
+
+
+/// Options for a system thread, then [`Builder::spawn`].
+#[derive(Default)]
+pub struct Builder {
+    priority: Option<KPRIORITY>,
+    affinity: Option<GROUP_AFFINITY>,
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Builder::default()
+    }
+
+    /// `KeSetPriorityThread` before the closure runs, 1..=31 (16 and up is real-time).
+    pub fn priority(mut self, priority: KPRIORITY) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Restricts the thread to the processors in `mask` of processor group 0.
+    pub fn affinity(self, mask: KAFFINITY) -> Self {
+        self.group_affinity(0, mask)
+    }
+
+    pub fn group_affinity(mut self, group: u16, mask: KAFFINITY) -> Self {
+        self.affinity = Some(GROUP_AFFINITY { Mask: mask, Group: group, Reserved: [0; 3] });
+        self
+    }
+
+    pub fn spawn<F, T>(self, f: F, irql: &Passive) -> Result<JoinHandle<T>, NtStatus>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        self.spawn_stoppable(|_| f(), irql)
+    }
+
+    /// Like [`Builder::spawn`], handing the closure the [`StopToken`] that
+    /// [`JoinHandle::request_stop`] signals.
+    pub fn spawn_stoppable<F, T>(self, f: F, _irql: &Passive) -> Result<JoinHandle<T>, NtStatus>
+    where
+        F: FnOnce(StopToken) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        irql::debug_assert_at_most(PASSIVE_LEVEL);
+        if let Some(priority) = self.priority && !(1..=HIGH_PRIORITY as KPRIORITY).contains(&priority) {
+            return Err(NtStatus::INVALID_PARAMETER);
+        }
+        if self.affinity.is_some_and(|a| a.Mask == 0) {
+            return Err(NtStatus::INVALID_PARAMETER);
+        }
+
+        let stop = StopToken::new();
+        let packet = Arc::new(Packet { result: UnsafeCell::new(None) });
+        let context = Box::into_raw(Box::new(Start {
+            f,
+            priority: self.priority,
+            affinity: self.affinity,
+            stop: stop.clone(),
+            packet: packet.clone(),
+        }));
+
+        let mut attributes: OBJECT_ATTRIBUTES = unsafe { core::mem::zeroed() };
+        init_object_attributes(&mut attributes, ptr::null_mut(), OBJ_KERNEL_HANDLE);
+        let mut handle: HANDLE = ptr::null_mut();
+        let status = unsafe {
+            PsCreateSystemThread(&mut handle, THREAD_ALL_ACCESS, &mut attributes, ptr::null_mut(), ptr::null_mut(),
+                                 Some(start::<F, T>), context as _)
+        };
+        if !NT_SUCCESS(status) {
+            drop(unsafe { Box::from_raw(context) });
+            return Err(NtStatus::from_raw(status));
+        }
+
+        // the thread owns `context` from here on
+        let mut thread: PVOID = ptr::null_mut();
+        let status = unsafe {
+            let status = ObReferenceObjectByHandle(handle, SYNCHRONIZE, *PsThreadType, _MODE_KernelMode as _, &mut thread, ptr::null_mut());
+            ZwClose(handle);
+            status
+        };
+        if !NT_SUCCESS(status) {
+            // cannot happen for a kernel handle we just created, but never lose track of the thread
+            stop.request_stop();
+            return Err(NtStatus::from_raw(status));
+        }
+
+        Ok(JoinHandle { thread: thread as PETHREAD, stop, packet })
+    }
+}
+
+
+/// Starts `f` on a new system thread. Must be called at PASSIVE_LEVEL.
+///
+/// The driver must not unload while the thread runs: join it (or request a stop and join) from
+/// the unload routine.
+pub fn spawn<F, T>(f: F, irql: &Passive) -> Result<JoinHandle<T>, NtStatus>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    Builder::new().spawn(f, irql)
+}
+
+/// [`spawn`] for a closure that watches a [`StopToken`].
+pub fn spawn_stoppable<F, T>(f: F, irql: &Passive) -> Result<JoinHandle<T>, NtStatus>
+where
+    F: FnOnce(StopToken) -> T + Send + 'static,
+    T: Send + 'static,
+{
+    Builder::new().spawn_stoppable(f, irql)
+}
+
+
+/// Owns a reference to the thread object. Dropping it detaches the thread.
+pub struct JoinHandle<T> {
+    thread: PETHREAD,
+    stop: StopToken,
+    packet: Arc<Packet<T>>,
+}
+
+unsafe impl<T: Send> Send for JoinHandle<T> {}
+unsafe impl<T: Send> Sync for JoinHandle<T> {}
+
+impl<T> JoinHandle<T> {
+    pub fn thread(&self) -> PETHREAD {
+        self.thread
+    }
+
+    #[cfg(feature = "ntddk")]
+    pub fn thread_id(&self) -> HANDLE {
+        unsafe { crate::ntddk::PsGetThreadId(self.thread) }
+    }
+
+    pub fn stop_token(&self) -> StopToken {
+        self.stop.clone()
+    }
+
+    /// Signals the thread's [`StopToken`]; it is up to the closure to notice. IRQL <= DISPATCH_LEVEL.
+    pub fn request_stop(&self) {
+        self.stop.request_stop();
+    }
+
+    /// Polls the thread object; usable at DISPATCH_LEVEL.
+    pub fn is_finished(&self) -> bool {
+        wait::wait_single(self.thread as _, Timeout::ZERO, false).is_ok_and(WaitResult::is_signaled)
+    }
+
+    /// Waits for the thread to exit without taking its result.
+    pub fn wait(&self, timeout: impl Into<Timeout>, _irql: &impl BelowDispatch) -> Result<WaitResult, NtStatus> {
+        wait::wait_single(self.thread as _, timeout.into(), false)
+    }
+
+    /// Waits for the thread to exit and returns what the closure returned.
+    pub fn join(self, irql: &impl BelowDispatch) -> T {
+        let _ = self.wait(Timeout::Infinite, irql);
+        unsafe { (*self.packet.result.get()).take() }.expect("system thread exited without a result")
+    }
+
+    /// [`JoinHandle::request_stop`], then [`JoinHandle::join`].
+    pub fn stop_and_join(self, irql: &impl BelowDispatch) -> T {
+        self.request_stop();
+        self.join(irql)
+    }
+}
+
+impl<T> Drop for JoinHandle<T> {
+    fn drop(&mut self) {
+        unsafe { ObfDereferenceObject(self.thread as _) };
+    }
+}
+
+unsafe impl<T> Waitable for JoinHandle<T> {
+    fn dispatcher_object(&self) -> PVOID {
+        self.thread as _
+    }
+}
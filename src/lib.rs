@@ -1,19 +1,59 @@
 #![no_std]
 #![allow(non_camel_case_types, non_snake_case, non_upper_case_globals, dead_code, improper_ctypes)]
 extern crate alloc;
-#[cfg(feature = "kernel")]
+#[cfg(windows)]
 use alloc::vec::Vec;
 #[cfg(feature = "kernel")]
 use core::ffi::c_void;
+#[cfg(windows)]
 use core::{ptr, slice};
+#[cfg(windows)]
 pub use crate::wdm::*;
+pub use crate::status::NtStatus;
+// the bindings describe the Windows ABI (`c_long` is 32 bits); the pure modules build without them
+#[cfg(windows)]
 pub mod wdm;
-#[cfg(feature = "kernel")]
+pub mod status;
+pub mod etw;
+pub mod ustr;
+pub mod sddl;
+pub mod ioctl;
+pub mod registry;
 pub mod memory;
 #[cfg(feature = "kernel")]
 pub mod khook;
 #[cfg(feature = "kernel")]
 pub mod kalloc;
+#[cfg(feature = "kernel")]
+pub mod klog;
+#[cfg(feature = "kernel")]
+pub mod dynimport;
+#[cfg(feature = "kernel")]
+pub mod irql;
+#[cfg(feature = "kernel")]
+pub mod sync;
+#[cfg(feature = "kernel")]
+pub mod kthread;
+#[cfg(feature = "kernel")]
+pub mod defer;
+#[cfg(feature = "kernel")]
+pub mod cpu;
+#[cfg(feature = "kernel")]
+pub mod driver;
+#[cfg(feature = "kernel")]
+pub mod device;
+#[cfg(feature = "kernel")]
+pub mod irp;
+#[cfg(feature = "kernel")]
+pub mod dispatch;
+#[cfg(feature = "kernel")]
+pub mod csq;
+#[cfg(feature = "kernel")]
+pub mod io_target;
+#[cfg(feature = "kernel")]
+pub mod ob_callback;
+#[cfg(all(feature = "kernel", feature = "ntddk"))]
+pub mod notify;
 #[cfg(feature = "ntddk")]
 pub mod ntddk;
 #[cfg(feature = "ntifs")]
@@ -43,19 +83,30 @@ pub fn segment_limit(selector: u16) -> Option<u32> {
     }
 }
 
-pub type NTSTATUS = i32;
-
-// NTSTATUS codes (small)
-pub const STATUS_SUCCESS: NTSTATUS                 = 0x00000000;
-pub const STATUS_UNSUCCESSFUL: NTSTATUS            = 0xC0000001u32 as i32;
-pub const STATUS_INVALID_PARAMETER: NTSTATUS       = 0xC000000Du32 as i32;
-pub const STATUS_INFO_LENGTH_MISMATCH: NTSTATUS    = 0xC0000004u32 as i32;
-pub const STATUS_NO_MEMORY: NTSTATUS               = 0xC0000017u32 as i32;
-pub const STATUS_INSUFFICIENT_RESOURCES: NTSTATUS  = 0xC000009Au32 as i32;
-pub const STATUS_OBJECT_NAME_NOT_FOUND: NTSTATUS   = 0xC0000034u32 as i32;
-pub const STATUS_BUFFER_TOO_SMALL: NTSTATUS = 0xC0000023u32 as i32;
-
-
+// NTSTATUS codes (small), raw values for FFI; see `NtStatus` for the typed equivalents
+#[cfg(windows)]
+pub const STATUS_SUCCESS: NTSTATUS                 = NtStatus::SUCCESS.raw();
+#[cfg(windows)]
+pub const STATUS_UNSUCCESSFUL: NTSTATUS            = NtStatus::UNSUCCESSFUL.raw();
+#[cfg(windows)]
+pub const STATUS_INVALID_PARAMETER: NTSTATUS       = NtStatus::INVALID_PARAMETER.raw();
+#[cfg(windows)]
+pub const STATUS_INFO_LENGTH_MISMATCH: NTSTATUS    = NtStatus::INFO_LENGTH_MISMATCH.raw();
+#[cfg(windows)]
+pub const STATUS_NO_MEMORY: NTSTATUS               = NtStatus::NO_MEMORY.raw();
+#[cfg(windows)]
+pub const STATUS_INSUFFICIENT_RESOURCES: NTSTATUS  = NtStatus::INSUFFICIENT_RESOURCES.raw();
+#[cfg(windows)]
+pub const STATUS_OBJECT_NAME_NOT_FOUND: NTSTATUS   = NtStatus::OBJECT_NAME_NOT_FOUND.raw();
+#[cfg(windows)]
+pub const STATUS_BUFFER_TOO_SMALL: NTSTATUS        = NtStatus::BUFFER_TOO_SMALL.raw();
+#[cfg(windows)]
+pub const STATUS_ACCESS_VIOLATION: NTSTATUS        = NtStatus::ACCESS_VIOLATION.raw();
+#[cfg(windows)]
+pub const STATUS_PARTIAL_COPY: NTSTATUS            = NtStatus::PARTIAL_COPY.raw();
+
+
+#[cfg(windows)]
 #[inline(always)]
 pub const fn NT_SUCCESS(status: NTSTATUS) -> bool {
     status >= 0
@@ -65,6 +116,7 @@ pub const fn NT_SUCCESS(status: NTSTATUS) -> bool {
 pub const IRP_MJ_MAXIMUM_FUNCTION: usize = 0x1b;
 
 
+#[cfg(windows)]
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct RTL_PROCESS_MODULE_INFORMATION {
@@ -80,6 +132,7 @@ pub struct RTL_PROCESS_MODULE_INFORMATION {
     pub FullPathName: [UCHAR; 256],
 }
 
+#[cfg(windows)]
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct RTL_PROCESS_MODULES {
@@ -92,8 +145,27 @@ pub const SystemModuleInformation: u32 = 11;
 pub const SystemProcessInformation: u32 = 5;
 pub const IoReadAccess: i32 = 1;
 pub const MmNonCached: i32 = 0;
+pub const MM_COPY_MEMORY_PHYSICAL: u32 = 1;
+pub const MM_COPY_MEMORY_VIRTUAL: u32 = 2;
+
+
+#[cfg(windows)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub union MM_COPY_ADDRESS_u {
+    pub VirtualAddress: PVOID,
+    pub PhysicalAddress: PHYSICAL_ADDRESS,
+}
+
+#[cfg(windows)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct MM_COPY_ADDRESS {
+    pub u: MM_COPY_ADDRESS_u,
+}
 
 
+#[cfg(windows)]
 #[repr(C)]
 pub struct SYSTEM_PROCESS_INFORMATION {
     pub NextEntryOffset: ULONG,
@@ -110,6 +182,7 @@ pub struct SYSTEM_PROCESS_INFORMATION {
 
 
 
+#[cfg(windows)]
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union MouseButtons {
@@ -117,6 +190,7 @@ pub union MouseButtons {
     pub ButtonFields: ButtonFields,
 }
 
+#[cfg(windows)]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ButtonFields {
@@ -124,6 +198,7 @@ pub struct ButtonFields {
     pub ButtonData: USHORT,
 }
 
+#[cfg(windows)]
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct MOUSE_INPUT_DATA {
@@ -137,12 +212,15 @@ pub struct MOUSE_INPUT_DATA {
 }
 
 
+#[cfg(windows)]
 pub type PMOUSE_INPUT_DATA = *mut MOUSE_INPUT_DATA;
 
+#[cfg(windows)]
 pub type MouseClassServiceCallbackFn = Option<unsafe extern "system" fn(DeviceObject: PDEVICE_OBJECT, InputDataStart: PMOUSE_INPUT_DATA, InputDataEnd: PMOUSE_INPUT_DATA, InputDataConsumed: PULONG)>;
 
 
 
+#[cfg(windows)]
 #[derive(Copy, Clone)]
 pub struct MOUSE_OBJECT {
     pub mouse_device: PDEVICE_OBJECT,
@@ -155,15 +233,15 @@ pub struct MOUSE_OBJECT {
 #[cfg(feature = "kernel")]
 unsafe extern "C" {
     pub static IoDriverObjectType: *mut *mut u8;
-    pub fn PsLookupProcessByProcessId(ProcessId: HANDLE, Process: *mut PEPROCESS) -> wdm::NTSTATUS;
+    pub static MmHighestUserAddress: PVOID;
+    pub fn MmCopyMemory(TargetAddress: PVOID, SourceAddress: MM_COPY_ADDRESS, NumberOfBytes: SIZE_T, Flags: ULONG, NumberOfBytesTransferred: *mut SIZE_T) -> NTSTATUS;
+    pub fn PsLookupProcessByProcessId(ProcessId: HANDLE, Process: *mut PEPROCESS) -> NTSTATUS;
     pub fn ZwQuerySystemInformation(SystemInformationClass: u32, SystemInformation: PVOID, SystemInformationLength: ULONG, ReturnLength: *mut ULONG) -> NTSTATUS;
     pub fn RtlSecureZeroMemory(Destination: PVOID, Length: SIZE_T) -> PVOID;
     pub fn RtlFindExportedRoutineByName(image_base: PVOID, routine_name: *const u8) -> PVOID;
-    pub fn PsGetProcessPeb(pep: PEPROCESS) -> u64;
-    pub fn MmCopyVirtualMemory(FromProcess: PEPROCESS, FromAddress: PVOID, ToProcess: PEPROCESS, ToAddress: PVOID, BufferSize: SIZE_T,
-                               PreviousMode: KPROCESSOR_MODE, NumberOfBytesCopied: *mut SIZE_T) -> NTSTATUS;
-
     pub fn PsGetCurrentProcess() -> PEPROCESS;
+    pub fn KeGetCurrentThread() -> PKTHREAD;
+    pub fn ObSetSecurityObjectByPointer(Object: PVOID, SecurityInformation: SECURITY_INFORMATION, SecurityDescriptor: PSECURITY_DESCRIPTOR) -> NTSTATUS;
     pub fn PsGetProcessSectionBaseAddress(Process: PEPROCESS) -> PVOID;
     pub fn ObReferenceObjectByName(ObjectName: *mut UNICODE_STRING, Attributes: ULONG, PassedAccessState: *mut ACCESS_STATE, DesiredAccess: ACCESS_MASK, ObjectType: *mut u8,
     AccessMode: u8, ParseContext: *mut c_void, Object: *mut *mut c_void) -> NTSTATUS;
@@ -171,9 +249,18 @@ unsafe extern "C" {
     pub fn IoCreateDriver(DriverName: *mut UNICODE_STRING, InitializationFunction: extern "system" fn(*mut c_void, *mut UNICODE_STRING) -> NTSTATUS) -> NTSTATUS;
 }
 
+// undocumented, resolved at runtime so a missing export fails the call instead of the driver load
+#[cfg(feature = "kernel")]
+dyn_import! {
+    pub fn PsGetProcessPeb(pep: PEPROCESS) -> u64;
+    pub fn MmCopyVirtualMemory(FromProcess: PEPROCESS, FromAddress: PVOID, ToProcess: PEPROCESS, ToAddress: PVOID, BufferSize: SIZE_T,
+                               PreviousMode: KPROCESSOR_MODE, NumberOfBytesCopied: *mut SIZE_T) -> NTSTATUS;
+}
+
 
 
 
+#[cfg(windows)]
 pub fn modules_from_ptr<'a>(ptr: *const RTL_PROCESS_MODULES) -> &'a [RTL_PROCESS_MODULE_INFORMATION] {
     unsafe {
         if ptr.is_null() {
@@ -187,6 +274,7 @@ pub fn modules_from_ptr<'a>(ptr: *const RTL_PROCESS_MODULES) -> &'a [RTL_PROCESS
 }
 
 
+#[cfg(windows)]
 pub fn filename_from_info(info: &RTL_PROCESS_MODULE_INFORMATION) -> &[u8] {
     let off = info.OffsetToFileName as usize;
     if off >= info.FullPathName.len() {
@@ -205,11 +293,13 @@ pub fn filename_from_info(info: &RTL_PROCESS_MODULE_INFORMATION) -> &[u8] {
     }
 }
 
+#[cfg(windows)]
 pub fn module_base_and_name(info: &RTL_PROCESS_MODULE_INFORMATION) -> (PVOID, &[u8]) {
     (info.ImageBase, filename_from_info(info))
 }
 
 
+#[cfg(windows)]
 pub fn init_object_attributes(obj: &mut OBJECT_ATTRIBUTES, name: *mut UNICODE_STRING, attributes: u32) {
     obj.Length = size_of::<OBJECT_ATTRIBUTES>() as u32;
     obj.RootDirectory = ptr::null_mut();
@@ -217,4 +307,27 @@ pub fn init_object_attributes(obj: &mut OBJECT_ATTRIBUTES, name: *mut UNICODE_ST
     obj.Attributes = attributes;
     obj.SecurityDescriptor = ptr::null_mut();
     obj.SecurityQualityOfService = ptr::null_mut();
+}
+
+
+// kept out of the generated bindings so `cargo xtask bindings generate` reproduces them
+#[cfg(windows)]
+impl UNICODE_STRING {
+    /// A NUL terminated copy of `s` whose buffer is leaked; `ustr::OwnedUnicodeString` frees its own.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Self {
+        let mut wide: Vec<u16> = s.encode_utf16().chain(Some(0)).collect();
+
+        let length = ((wide.len() - 1) * 2) as u16;
+        let max_length = (wide.len() * 2) as u16;
+
+        let buffer = wide.as_mut_ptr();
+        core::mem::forget(wide);
+
+        Self {
+            Length: length,
+            MaximumLength: max_length,
+            Buffer: buffer,
+        }
+    }
 }
\ No newline at end of file
@@ -1,6 +1,16 @@
+use alloc::vec::Vec;
+#[cfg(feature = "kernel")]
 use crate::wdm::*;
+#[cfg(feature = "kernel")]
+use crate::NtStatus;
+#[cfg(feature = "kernel")]
+use crate::irql::{self, BelowDispatch, APC_LEVEL, DISPATCH_LEVEL};
 
+#[cfg(feature = "kernel")]
 pub mod module;
+#[cfg(feature = "kernel")]
+pub mod probe;
+#[cfg(feature = "kernel")]
 pub mod rw;
 
 
@@ -8,65 +18,198 @@ pub const PAGE_SIZE: usize = 0x1000;
 pub const MAX_RW_SIZE: usize = 0x10000;
 
 
-pub fn alloc_pool(pool_type: i32, size: usize) -> PVOID {
-    unsafe {
-        ExAllocatePool(pool_type, size as _)
+/// Must be called at IRQL <= DISPATCH_LEVEL, or <= APC_LEVEL for paged pool.
+#[cfg(feature = "kernel")]
+pub fn alloc_pool(pool_type: i32, size: usize) -> Result<PVOID, NtStatus> {
+    irql::debug_assert_at_most(if pool_type & 1 != 0 { APC_LEVEL } else { DISPATCH_LEVEL });
+    let p = unsafe { ExAllocatePool(pool_type, size as _) };
+    if p.is_null() {
+        Err(NtStatus::INSUFFICIENT_RESOURCES)
+    } else {
+        Ok(p)
     }
 }
 
-pub fn alloc_pool_t<T>(pool_type: i32) -> PVOID {
+#[cfg(feature = "kernel")]
+pub fn alloc_pool_t<T>(pool_type: i32) -> Result<PVOID, NtStatus> {
     alloc_pool(pool_type, size_of::<T>())
 }
 
-pub fn alloc_contiguous_memory(size: usize) -> PVOID {
-    unsafe {
+/// Must be called at IRQL <= DISPATCH_LEVEL.
+#[cfg(feature = "kernel")]
+pub fn alloc_contiguous_memory(size: usize) -> Result<PVOID, NtStatus> {
+    irql::debug_assert_at_most(DISPATCH_LEVEL);
+    let p = unsafe {
         let lowest = PHYSICAL_ADDRESS { QuadPart: 0 };
         let highest = PHYSICAL_ADDRESS { QuadPart: i64::MAX };
         let boundary = PHYSICAL_ADDRESS { QuadPart: 0 };
         MmAllocateContiguousMemorySpecifyCacheNode(size as _, lowest, highest, boundary, _MEMORY_CACHING_TYPE_MmNonCached, MM_ANY_NODE_OK)
+    };
+    if p.is_null() {
+        Err(NtStatus::INSUFFICIENT_RESOURCES)
+    } else {
+        Ok(p)
     }
 }
 
 
-pub fn alloc_contiguous_memory_t<T>() -> PVOID {
+#[cfg(feature = "kernel")]
+pub fn alloc_contiguous_memory_t<T>() -> Result<PVOID, NtStatus> {
     alloc_contiguous_memory(size_of::<T>())
 }
 
 
 
-pub fn pattern_search(base: u64, img_size: usize, pattern: &[Option<u8>]) -> Option<u64> {
-    unsafe {
-        let plen = pattern.len();
-        if plen == 0 || img_size < plen {
-            return None;
-        }
+/// IRQL <= APC_LEVEL, as the image may be paged.
+#[cfg(feature = "kernel")]
+pub fn pattern_search(base: u64, img_size: usize, pattern: &[Option<u8>], _irql: &impl BelowDispatch) -> Option<u64> {
+    irql::debug_assert_at_most(APC_LEVEL);
+    pattern_search_with(base, img_size, pattern, |page, buf| unsafe { probe::try_read_bytes(page as *const u8, buf) }.is_ok())
+}
+
+/// [`pattern_search`] over memory read by `read(addr, buf)`, which fills `buf` from `addr` and
+/// returns false if it cannot. `buf` never crosses a page boundary; pages that cannot be read are
+/// skipped, and no match spans them.
+pub fn pattern_search_with(base: u64, img_size: usize, pattern: &[Option<u8>], mut read: impl FnMut(u64, &mut [u8]) -> bool) -> Option<u64> {
+    let plen = pattern.len();
+    if plen == 0 || img_size < plen {
+        return None;
+    }
 
-        let last = img_size - plen;
-
-        for i in 0..=last {
-            let start = (base + i as u64) as *const u8;
-            let mut matched = true;
-
-            for (j, pat) in pattern.iter().enumerate() {
-                let mem_byte = *start.add(j);
-                match pat {
-                    Some(b) => {
-                        if mem_byte != *b {
-                            matched = false;
-                            break;
-                        }
-                    }
-                    None => {},
+    let end = base.checked_add(img_size as u64)?;
+    let page_mask = !(PAGE_SIZE as u64 - 1);
+    // the bytes at `start..`: the last `plen - 1` bytes read so far, then the current page
+    let mut chunk: Vec<u8> = Vec::with_capacity(PAGE_SIZE + plen - 1);
+    let mut start = base;
+    let mut page = base;
+
+    while page < end {
+        let next = core::cmp::min((page & page_mask).saturating_add(PAGE_SIZE as u64), end);
+        let carried = chunk.len();
+        chunk.resize(carried + (next - page) as usize, 0);
+
+        // pages that are not resident (discarded INIT sections, guard pages) are skipped, and no
+        // match spans them
+        if !read(page, &mut chunk[carried..]) {
+            chunk.clear();
+            start = next;
+        } else {
+            for (i, candidate) in chunk.windows(plen).enumerate() {
+                let matched = pattern.iter().zip(candidate).all(|(pat, byte)| match pat {
+                    Some(b) => b == byte,
+                    None => true,
+                });
+
+                if matched {
+                    return Some(start + i as u64);
                 }
             }
+            let keep = core::cmp::min(chunk.len(), plen - 1);
+            start += (chunk.len() - keep) as u64;
+            chunk.drain(..chunk.len() - keep);
+        }
+        page = next;
+    }
 
-            if matched {
-                return Some(base + i as u64);
-            }
+    None
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    const BASE: u64 = 0x7FF6_0000_0000;
+
+    // `pages` pages of memory at `BASE` filled with a pattern-free byte, with `unreadable` pages
+    // failing the read
+    struct Image {
+        bytes: Vec<u8>,
+        unreadable: Vec<u64>,
+    }
+
+    impl Image {
+        fn new(pages: usize) -> Self {
+            Image { bytes: vec![0xCC; pages * PAGE_SIZE], unreadable: Vec::new() }
         }
 
-        None
+        fn put(&mut self, addr: u64, bytes: &[u8]) {
+            let at = (addr - BASE) as usize;
+            self.bytes[at..at + bytes.len()].copy_from_slice(bytes);
+        }
+
+        fn search(&self, base: u64, len: usize, pattern: &[Option<u8>]) -> Option<u64> {
+            pattern_search_with(base, len, pattern, |addr, buf| {
+                let page = addr / PAGE_SIZE as u64;
+                assert_eq!((addr + buf.len() as u64 - 1) / PAGE_SIZE as u64, page, "read across a page");
+                if self.unreadable.contains(&page) {
+                    return false;
+                }
+                let at = (addr - BASE) as usize;
+                buf.copy_from_slice(&self.bytes[at..at + buf.len()]);
+                true
+            })
+        }
+    }
+
+    fn exact(bytes: &[u8]) -> Vec<Option<u8>> {
+        bytes.iter().copied().map(Some).collect()
     }
-}
 
+    #[test]
+    fn finds_a_pattern_spanning_pages() {
+        let mut image = Image::new(3);
+        let at = BASE + PAGE_SIZE as u64 - 2;
+        image.put(at, &[0x48, 0x8B, 0x05, 0x11]);
+        let size = image.bytes.len();
+        assert_eq!(image.search(BASE, size, &exact(&[0x48, 0x8B, 0x05, 0x11])), Some(at));
+        assert_eq!(image.search(BASE, size, &[Some(0x48), None, None, Some(0x11)]), Some(at));
+        // the match has to end inside the range
+        assert_eq!(image.search(BASE, PAGE_SIZE + 1, &exact(&[0x48, 0x8B, 0x05, 0x11])), None);
+        // an unaligned base only reads from there on
+        assert_eq!(image.search(at + 1, size - PAGE_SIZE, &exact(&[0x48, 0x8B])), None);
+        assert_eq!(image.search(BASE + 0x10, size - 0x10, &exact(&[0x8B, 0x05])), Some(at + 1));
+    }
+
+    #[test]
+    fn skips_unreadable_pages() {
+        let mut image = Image::new(4);
+        let pattern = exact(&[0x0F, 0x05, 0xC3]);
+        image.put(BASE + 2 * PAGE_SIZE as u64 - 1, &[0x0F, 0x05, 0xC3]);
+        image.put(BASE + 3 * PAGE_SIZE as u64 + 8, &[0x0F, 0x05, 0xC3]);
+        let size = image.bytes.len();
+        assert_eq!(image.search(BASE, size, &pattern), Some(BASE + 2 * PAGE_SIZE as u64 - 1));
+
+        // no match spans an unreadable page, but the search goes on behind it
+        image.unreadable.push(BASE / PAGE_SIZE as u64 + 1);
+        assert_eq!(image.search(BASE, size, &pattern), Some(BASE + 3 * PAGE_SIZE as u64 + 8));
+        image.unreadable.push(BASE / PAGE_SIZE as u64 + 3);
+        assert_eq!(image.search(BASE, size, &pattern), None);
+    }
 
+    #[test]
+    fn pattern_longer_than_a_page() {
+        let mut image = Image::new(4);
+        let bytes: Vec<u8> = (0..PAGE_SIZE + 0x20).map(|i| (i % 251) as u8).collect();
+        let at = BASE + 0x800;
+        image.put(at, &bytes);
+        let mut pattern = exact(&bytes);
+        pattern[PAGE_SIZE] = None;
+        let size = image.bytes.len();
+        assert_eq!(image.search(BASE, size, &pattern), Some(at));
+        assert_eq!(image.search(BASE, 0x800 + bytes.len() - 1, &pattern), None);
+
+        image.unreadable.push(BASE / PAGE_SIZE as u64 + 1);
+        assert_eq!(image.search(BASE, size, &pattern), None);
+    }
+
+    #[test]
+    fn empty_and_short_ranges() {
+        let image = Image::new(1);
+        assert_eq!(image.search(BASE, PAGE_SIZE, &[]), None);
+        assert_eq!(image.search(BASE, 1, &exact(&[0xCC, 0xCC])), None);
+        assert_eq!(image.search(BASE, 2, &exact(&[0xCC, 0xCC])), Some(BASE));
+        assert_eq!(image.search(u64::MAX - 1, 4, &exact(&[0xCC])), None);
+    }
+}
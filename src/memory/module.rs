@@ -1,17 +1,22 @@
-use alloc::string::ToString;
+use alloc::vec::Vec;
 use core::ffi::CStr;
 use core::ptr;
-use crate::{PsGetProcessSectionBaseAddress, PsLookupProcessByProcessId, RtlFindExportedRoutineByName, SystemModuleInformation, SystemProcessInformation, ZwQuerySystemInformation, NT_SUCCESS, RTL_PROCESS_MODULES, RTL_PROCESS_MODULE_INFORMATION, STATUS_INFO_LENGTH_MISMATCH, STATUS_INSUFFICIENT_RESOURCES, STATUS_INVALID_PARAMETER, STATUS_NO_MEMORY, STATUS_OBJECT_NAME_NOT_FOUND, STATUS_SUCCESS, STATUS_UNSUCCESSFUL, SYSTEM_PROCESS_INFORMATION};
+use crate::{PsGetProcessSectionBaseAddress, PsLookupProcessByProcessId, RtlFindExportedRoutineByName, SystemModuleInformation, SystemProcessInformation, ZwQuerySystemInformation, NtStatus, RTL_PROCESS_MODULES, RTL_PROCESS_MODULE_INFORMATION, STATUS_INFO_LENGTH_MISMATCH, STATUS_SUCCESS, SYSTEM_PROCESS_INFORMATION};
+use crate::ustr::{OwnedUnicodeString, UnicodeCow, UnicodeStr};
 use crate::wdm::*;
+use crate::irql::{self, Passive, PASSIVE_LEVEL};
 
 
-pub fn get_system_module_strc(module_name: &str) -> Result<RTL_PROCESS_MODULE_INFORMATION, NTSTATUS> {
+/// Finds a loaded kernel module by full path or file name, ignoring case.
+pub fn get_system_module_strc<'a>(module_name: impl Into<UnicodeCow<'a>>, _irql: &Passive) -> Result<RTL_PROCESS_MODULE_INFORMATION, NtStatus> {
+    irql::debug_assert_at_most(PASSIVE_LEVEL);
+    let module_name = module_name.into();
+    let module_name = module_name.as_ustr();
     unsafe {
         if module_name.is_empty() {
-            return Err(STATUS_INVALID_PARAMETER);
+            return Err(NtStatus::INVALID_PARAMETER);
         }
 
-        let module_name = module_name.to_lowercase();
         let mut required: u32 = 0;
         let mut buf: PVOID = ptr::null_mut();
         let mut buf_size: usize = 0;
@@ -19,24 +24,24 @@ pub fn get_system_module_strc(module_name: &str) -> Result<RTL_PROCESS_MODULE_IN
         loop {
             let q_len = match buf_size.try_into() {
                 Ok(v) => v,
-                Err(_) => return Err(STATUS_NO_MEMORY),
+                Err(_) => return Err(NtStatus::NO_MEMORY),
             };
 
             let status = ZwQuerySystemInformation(SystemModuleInformation, buf, q_len, &mut required);
 
             if buf.is_null() {
                 if required == 0 {
-                    return Err(status);
+                    return Err(status.into());
                 }
 
                 if (required as usize) > 0x7FFFFFFFusize {
-                    return Err(STATUS_NO_MEMORY);
+                    return Err(NtStatus::NO_MEMORY);
                 }
 
                 buf_size = required as usize;
                 buf = ExAllocatePool(_POOL_TYPE_NonPagedPool, buf_size as _);
                 if buf.is_null() {
-                    return Err(STATUS_NO_MEMORY);
+                    return Err(NtStatus::NO_MEMORY);
                 }
                 continue;
             } else {
@@ -47,7 +52,7 @@ pub fn get_system_module_strc(module_name: &str) -> Result<RTL_PROCESS_MODULE_IN
                     continue;
                 } else if status != STATUS_SUCCESS {
                     ExFreePool(buf);
-                    return Err(status);
+                    return Err(status.into());
                 } else {
                     break;
                 }
@@ -55,12 +60,12 @@ pub fn get_system_module_strc(module_name: &str) -> Result<RTL_PROCESS_MODULE_IN
         }
 
         if buf.is_null() {
-            return Err(STATUS_OBJECT_NAME_NOT_FOUND);
+            return Err(NtStatus::OBJECT_NAME_NOT_FOUND);
         }
 
         if buf_size < size_of::<u32>() {
             ExFreePool(buf);
-            return Err(STATUS_OBJECT_NAME_NOT_FOUND);
+            return Err(NtStatus::OBJECT_NAME_NOT_FOUND);
         }
 
         let modules_ptr = buf as *const RTL_PROCESS_MODULES;
@@ -71,7 +76,7 @@ pub fn get_system_module_strc(module_name: &str) -> Result<RTL_PROCESS_MODULE_IN
         let needed = header_size.saturating_add(num.saturating_mul(entry_size));
         if needed > buf_size {
             ExFreePool(buf);
-            return Err(STATUS_OBJECT_NAME_NOT_FOUND);
+            return Err(NtStatus::OBJECT_NAME_NOT_FOUND);
         }
 
         let first_entry_ptr = (&(*modules_ptr).Modules) as *const RTL_PROCESS_MODULE_INFORMATION;
@@ -79,27 +84,18 @@ pub fn get_system_module_strc(module_name: &str) -> Result<RTL_PROCESS_MODULE_IN
 
         let mut out = core::mem::zeroed();
         for info in entries {
-            let full = &info.FullPathName;
-            let path = CStr::from_ptr(info.FullPathName.as_ptr() as _).to_string_lossy().to_lowercase();
-            if path == module_name {
+            let path = OwnedUnicodeString::from_ansi(CStr::from_ptr(info.FullPathName.as_ptr() as _).to_bytes());
+            let path = path.as_ustr();
+            if path.eq_ignore_case(module_name) || path.file_name().eq_ignore_case(module_name) {
                 out = *info;
-            } else {
-                let off = info.OffsetToFileName as usize;
-                if off < full.len() {
-                    let name_slice = &full[off..];
-                    let c_name = CStr::from_ptr(name_slice.as_ptr() as _).to_string_lossy().to_lowercase();
-                    if c_name == module_name {
-                        out = *info;
-                        break;
-                    }
-                }
+                break;
             }
         }
 
         ExFreePool(buf);
 
         if out.ImageBase.is_null() {
-            Err(STATUS_OBJECT_NAME_NOT_FOUND)
+            Err(NtStatus::OBJECT_NAME_NOT_FOUND)
         } else {
             Ok(out)
         }
@@ -107,28 +103,38 @@ pub fn get_system_module_strc(module_name: &str) -> Result<RTL_PROCESS_MODULE_IN
 }
 
 
-pub fn get_system_module_base(module_name: &str) -> Result<PVOID, NTSTATUS> {
-    Ok(get_system_module_strc(module_name)?.ImageBase)
+pub fn get_system_module_base<'a>(module_name: impl Into<UnicodeCow<'a>>, irql: &Passive) -> Result<PVOID, NtStatus> {
+    Ok(get_system_module_strc(module_name, irql)?.ImageBase)
 }
 
 
-pub fn get_proc_addr(module: PVOID, proc_name: &str) -> Result<PVOID, NTSTATUS> {
+pub fn get_proc_addr<'a>(module: PVOID, proc_name: impl Into<UnicodeCow<'a>>, _irql: &Passive) -> Result<PVOID, NtStatus> {
+    irql::debug_assert_at_most(PASSIVE_LEVEL);
+    let proc_name = proc_name.into();
+    let proc_name = proc_name.as_ustr();
 
     if module.is_null() {
-        return Err(STATUS_INVALID_PARAMETER);
+        return Err(NtStatus::INVALID_PARAMETER);
     }
     if proc_name.is_empty() {
-        return Err(STATUS_INVALID_PARAMETER);
+        return Err(NtStatus::INVALID_PARAMETER);
     }
 
-    let mut proc_name = proc_name.to_string();
-    proc_name.push('\0');
+    // export names are ASCII
+    let mut ansi = Vec::with_capacity(proc_name.len() + 1);
+    for &unit in proc_name.as_slice() {
+        if unit == 0 || unit >= 0x80 {
+            return Err(NtStatus::INVALID_PARAMETER);
+        }
+        ansi.push(unit as u8);
+    }
+    ansi.push(0);
 
 
     unsafe {
-        let addr = RtlFindExportedRoutineByName(module, proc_name.as_ptr());
+        let addr = RtlFindExportedRoutineByName(module, ansi.as_ptr());
         if addr.is_null() {
-            Err(STATUS_OBJECT_NAME_NOT_FOUND)
+            Err(NtStatus::OBJECT_NAME_NOT_FOUND)
         } else {
             Ok(addr as PVOID)
         }
@@ -139,20 +145,17 @@ pub fn get_proc_addr(module: PVOID, proc_name: &str) -> Result<PVOID, NTSTATUS>
 
 
 
-pub fn get_process_base_address(pid: u64) -> Result<PVOID, NTSTATUS> {
+pub fn get_process_base_address(pid: u64, _irql: &Passive) -> Result<PVOID, NtStatus> {
+    irql::debug_assert_at_most(PASSIVE_LEVEL);
     unsafe {
         let mut process: PEPROCESS = ptr::null_mut();
-        let status = PsLookupProcessByProcessId(pid as PVOID, &mut process);
-
-        if !NT_SUCCESS(status) {
-            return Err(status);
-        }
+        NtStatus::check(PsLookupProcessByProcessId(pid as PVOID, &mut process))?;
 
         let base = PsGetProcessSectionBaseAddress(process);
         ObfDereferenceObject(process as _);
 
         if base.is_null() {
-            return Err(STATUS_UNSUCCESSFUL);
+            return Err(NtStatus::UNSUCCESSFUL);
         }
 
         Ok(base)
@@ -160,48 +163,37 @@ pub fn get_process_base_address(pid: u64) -> Result<PVOID, NTSTATUS> {
 }
 
 
-pub unsafe fn get_process_id(process_name: *const u8) -> Result<u64, NTSTATUS> {
+/// Pid of the first process whose image name matches `process_name`, ignoring case.
+pub fn get_process_id<'a>(process_name: impl Into<UnicodeCow<'a>>, _irql: &Passive) -> Result<u64, NtStatus> {
+    irql::debug_assert_at_most(PASSIVE_LEVEL);
+    let process_name = process_name.into();
+    let process_name = process_name.as_ustr();
     unsafe {
         let mut needed = 0;
         let status = ZwQuerySystemInformation(SystemProcessInformation, ptr::null_mut(), 0, &mut needed as *mut ULONG);
 
         if needed == 0 {
-            return Err(status);
+            return Err(status.into());
         }
 
         let buffer = ExAllocatePool(_POOL_TYPE_NonPagedPool, needed as _);
         if buffer.is_null() {
-            return Err(STATUS_INSUFFICIENT_RESOURCES);
-        }
-
-        let mut ansi: ANSI_STRING = ANSI_STRING { Length: 0, MaximumLength: 0, Buffer: ptr::null_mut() };
-        RtlInitAnsiString(&mut ansi as *mut ANSI_STRING, process_name as _);
-
-        let mut unicode: UNICODE_STRING = UNICODE_STRING { Length: 0, MaximumLength: 0, Buffer: ptr::null_mut() };
-        let status_conv = RtlAnsiStringToUnicodeString(&mut unicode as *mut UNICODE_STRING, &mut ansi as *mut ANSI_STRING, TRUE as _);
-        if status_conv < 0 {
-            ExFreePool(buffer);
-            return Err(STATUS_UNSUCCESSFUL);
+            return Err(NtStatus::INSUFFICIENT_RESOURCES);
         }
 
         let status_sys = ZwQuerySystemInformation(SystemProcessInformation, buffer, needed, ptr::null_mut());
         if status_sys < 0 {
-            RtlFreeUnicodeString(&mut unicode as *mut UNICODE_STRING);
             ExFreePool(buffer);
-            return Err(status_sys);
+            return Err(status_sys.into());
         }
 
+        let mut found = Err(NtStatus::UNSUCCESSFUL);
         let mut cur = buffer as *mut SYSTEM_PROCESS_INFORMATION;
         loop {
-            let img = &(*cur).ImageName;
-            if !img.Buffer.is_null() && img.Length > 0 {
-                let cmp = RtlCompareUnicodeString(&unicode as *const UNICODE_STRING, img as *const _ as _, TRUE as _);
-                if cmp == 0 {
-                    let pid = (*cur).UniqueProcessId;
-                    RtlFreeUnicodeString(&mut unicode as *mut UNICODE_STRING);
-                    ExFreePool(buffer);
-                    return Ok(pid as _);
-                }
+            let image = UnicodeStr::from_unicode_string(&(*cur).ImageName);
+            if !image.is_empty() && image.eq_ignore_case(process_name) {
+                found = Ok((*cur).UniqueProcessId as u64);
+                break;
             }
 
             if (*cur).NextEntryOffset == 0 {
@@ -210,8 +202,7 @@ pub unsafe fn get_process_id(process_name: *const u8) -> Result<u64, NTSTATUS> {
             cur = (cur as *mut u8).add((*cur).NextEntryOffset as usize) as *mut SYSTEM_PROCESS_INFORMATION;
         }
 
-        RtlFreeUnicodeString(&mut unicode as *mut UNICODE_STRING);
         ExFreePool(buffer);
-        Err(STATUS_UNSUCCESSFUL)
+        found
     }
-}
\ No newline at end of file
+}
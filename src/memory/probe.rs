@@ -0,0 +1,130 @@
+use core::mem::MaybeUninit;
+use crate::*;
+use crate::memory::PAGE_SIZE;
+use crate::irql::{self, BelowDispatch, APC_LEVEL};
+
+/// Returns `true` when `addr` lies in the user half of the address space.
+pub fn is_user_address(addr: *const u8) -> bool {
+    unsafe { (addr as usize) <= (MmHighestUserAddress as usize) }
+}
+
+/// Copies `len` bytes from `src` to `dst` without bugchecking when `src` is invalid.
+///
+/// Kernel addresses go through `MmCopyMemory`, user addresses through `MmCopyVirtualMemory`
+/// on the current process; both catch the fault internally, so an unmapped or paged-out
+/// source yields `Err(NtStatus::ACCESS_VIOLATION)` instead of a crash.
+/// Must be called at IRQL <= APC_LEVEL. Above PASSIVE_LEVEL, user addresses need
+/// `MmCopyVirtualMemory` to have been resolved already, e.g. from `DriverEntry`, or fail with
+/// `STATUS_NOT_SUPPORTED`.
+///
+/// # Safety
+/// `dst` must be a valid, resident kernel buffer of at least `len` bytes.
+pub unsafe fn try_copy(dst: *mut u8, src: *const u8, len: usize) -> Result<(), NtStatus> {
+    irql::debug_assert_at_most(APC_LEVEL);
+    unsafe {
+        if len == 0 {
+            return Ok(());
+        }
+        if dst.is_null() || src.is_null() {
+            return Err(NtStatus::INVALID_PARAMETER);
+        }
+        if (src as usize).checked_add(len).is_none() {
+            return Err(NtStatus::ACCESS_VIOLATION);
+        }
+
+        let mut copied: SIZE_T = 0;
+        let status = if is_user_address(src) {
+            let process = PsGetCurrentProcess();
+            MmCopyVirtualMemory.get()?(process, src as _, process, dst as _, len as _, _MODE_KernelMode as _, &mut copied)
+        } else {
+            let address = MM_COPY_ADDRESS { u: MM_COPY_ADDRESS_u { VirtualAddress: src as PVOID } };
+            MmCopyMemory(dst as _, address, len as _, MM_COPY_MEMORY_VIRTUAL, &mut copied)
+        };
+
+        if !NT_SUCCESS(status) || copied as usize != len {
+            return Err(NtStatus::ACCESS_VIOLATION);
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads a `T` from `ptr`, returning `Err(NtStatus::ACCESS_VIOLATION)` if the memory is not readable.
+///
+/// # Safety
+/// Any bit pattern read from `ptr` must be a valid `T`.
+pub unsafe fn try_read<T: Copy>(ptr: *const T) -> Result<T, NtStatus> {
+    unsafe {
+        let mut out = MaybeUninit::<T>::uninit();
+        try_copy(out.as_mut_ptr() as *mut u8, ptr as *const u8, size_of::<T>())?;
+        Ok(out.assume_init())
+    }
+}
+
+/// Fills `out` from `src`, see [`try_copy`].
+///
+/// # Safety
+/// `out` must be resident, i.e. not a pageable buffer touched at raised IRQL.
+pub unsafe fn try_read_bytes(src: *const u8, out: &mut [u8]) -> Result<(), NtStatus> {
+    unsafe { try_copy(out.as_mut_ptr(), src, out.len()) }
+}
+
+/// Returns `true` if every page touched by `len` bytes at `addr` can currently be read.
+pub fn is_readable(addr: *const u8, len: usize, _irql: &impl BelowDispatch) -> bool {
+    if len == 0 {
+        return true;
+    }
+    let start = addr as usize;
+    let end = match start.checked_add(len - 1) {
+        Some(v) => v,
+        None => return false,
+    };
+
+    let mut byte = 0u8;
+    let mut page = start & !(PAGE_SIZE - 1);
+    loop {
+        let probe = core::cmp::max(page, start) as *const u8;
+        if unsafe { try_copy(&mut byte, probe, 1) }.is_err() {
+            return false;
+        }
+        match page.checked_add(PAGE_SIZE) {
+            Some(next) if next <= end => page = next,
+            _ => return true,
+        }
+    }
+}
+
+/// Returns `true` when all `len` bytes at `addr` lie in the user half of the address space.
+pub fn is_user_range(addr: *const u8, len: usize) -> bool {
+    match (addr as usize).checked_add(len) {
+        Some(end) => len == 0 || (end - 1) <= unsafe { MmHighestUserAddress as usize },
+        None => false,
+    }
+}
+
+/// Copies `len` bytes from the kernel buffer `src` to the user address `dst` in the current
+/// process through `MmCopyVirtualMemory`, so an invalid destination yields
+/// `Err(NtStatus::ACCESS_VIOLATION)`. Must be called at IRQL <= APC_LEVEL; see [`try_copy`] for
+/// resolving `MmCopyVirtualMemory` ahead of raised IRQL.
+///
+/// # Safety
+/// `src` must be a valid, resident kernel buffer of at least `len` bytes.
+pub unsafe fn try_copy_to_user(dst: *mut u8, src: *const u8, len: usize) -> Result<(), NtStatus> {
+    irql::debug_assert_at_most(APC_LEVEL);
+    if len == 0 {
+        return Ok(());
+    }
+    if dst.is_null() || !is_user_range(dst, len) {
+        return Err(NtStatus::ACCESS_VIOLATION);
+    }
+
+    let mut copied: SIZE_T = 0;
+    let status = unsafe {
+        let process = PsGetCurrentProcess();
+        MmCopyVirtualMemory.get()?(process, src as _, process, dst as _, len as _, _MODE_KernelMode as _, &mut copied)
+    };
+    if !NT_SUCCESS(status) || copied as usize != len {
+        return Err(NtStatus::ACCESS_VIOLATION);
+    }
+    Ok(())
+}
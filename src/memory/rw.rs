@@ -1,15 +1,18 @@
 use core::ptr;
 use crate::*;
+use crate::irql::{self, BelowDispatch, Passive, APC_LEVEL, PASSIVE_LEVEL};
 
-pub fn write_to_read_only_memory(dst: PVOID, src: *const u8, size: usize) -> bool {
+/// IRQL <= APC_LEVEL for `MmProbeAndLockPages`.
+pub fn write_to_read_only_memory(dst: PVOID, src: *const u8, size: usize, _irql: &impl BelowDispatch) -> Result<(), NtStatus> {
+    irql::debug_assert_at_most(APC_LEVEL);
     unsafe {
         if dst.is_null() || src.is_null() || size == 0 {
-            return false;
+            return Err(NtStatus::INVALID_PARAMETER);
         }
 
         let mdl = IoAllocateMdl(dst, size as _, 0, 0, ptr::null_mut());
         if mdl.is_null() {
-            return false;
+            return Err(NtStatus::INSUFFICIENT_RESOURCES);
         }
 
         MmProbeAndLockPages(mdl, _MODE_KernelMode as _, _LOCK_OPERATION_IoWriteAccess);
@@ -18,7 +21,7 @@ pub fn write_to_read_only_memory(dst: PVOID, src: *const u8, size: usize) -> boo
         if mapping.is_null() {
             MmUnlockPages(mdl);
             IoFreeMdl(mdl);
-            return false;
+            return Err(NtStatus::INSUFFICIENT_RESOURCES);
         }
 
         MmProtectMdlSystemAddress(mdl, PAGE_READWRITE);
@@ -28,35 +31,33 @@ pub fn write_to_read_only_memory(dst: PVOID, src: *const u8, size: usize) -> boo
         MmUnlockPages(mdl);
         IoFreeMdl(mdl);
 
-        true
+        Ok(())
     }
 }
 
 #[cfg(feature = "kernel")]
-pub fn read_memory_from_pid(pid: u64, addr: u64, out: &mut [u8]) -> Result<(), wdm::NTSTATUS> {
+pub fn read_memory_from_pid(pid: u64, addr: u64, out: &mut [u8], _irql: &Passive) -> Result<(), NtStatus> {
+    irql::debug_assert_at_most(PASSIVE_LEVEL);
+    // resolved before the lookup so a missing export cannot leak the process reference
+    let copy_virtual_memory = MmCopyVirtualMemory.get()?;
     unsafe {
         let mut src_process: PEPROCESS = ptr::null_mut();
-        let status = PsLookupProcessByProcessId(pid as PVOID, &mut src_process);
-        if !NT_SUCCESS(status) {
-            return Err(status);
-        }
+        NtStatus::check(PsLookupProcessByProcessId(pid as PVOID, &mut src_process))?;
 
         let mut bytes_copied = 0;
 
-        let status = MmCopyVirtualMemory(src_process, addr as _, PsGetCurrentProcess(), out.as_mut_ptr() as _, out.len() as _, _MODE_KernelMode as _, &mut bytes_copied);
+        let status = copy_virtual_memory(src_process, addr as _, PsGetCurrentProcess(), out.as_mut_ptr() as _, out.len() as _, _MODE_KernelMode as _, &mut bytes_copied);
 
         ObfDereferenceObject(src_process as _);
 
-        if !NT_SUCCESS(status) {
-            return Err(status);
-        }
+        NtStatus::check(status)?;
 
         if bytes_copied == 0 {
-            return Err(STATUS_UNSUCCESSFUL);
+            return Err(NtStatus::UNSUCCESSFUL);
         }
 
         if bytes_copied != out.len() as _ {
-            return Err(STATUS_BUFFER_TOO_SMALL);
+            return Err(NtStatus::BUFFER_TOO_SMALL);
         }
 
         Ok(())
@@ -64,30 +65,27 @@ pub fn read_memory_from_pid(pid: u64, addr: u64, out: &mut [u8]) -> Result<(), w
 }
 
 #[cfg(feature = "kernel")]
-pub fn write_memory_from_pid(pid: u64, addr: u64, buffer: &[u8]) -> Result<(), wdm::NTSTATUS> {
+pub fn write_memory_from_pid(pid: u64, addr: u64, buffer: &[u8], _irql: &Passive) -> Result<(), NtStatus> {
+    irql::debug_assert_at_most(PASSIVE_LEVEL);
+    let copy_virtual_memory = MmCopyVirtualMemory.get()?;
     unsafe {
         let mut target_process: PEPROCESS = ptr::null_mut();
-        let status = PsLookupProcessByProcessId(pid as PVOID, &mut target_process);
-        if !NT_SUCCESS(status) {
-            return Err(status);
-        }
+        NtStatus::check(PsLookupProcessByProcessId(pid as PVOID, &mut target_process))?;
 
         let mut bytes_copied = 0;
 
-        let status = MmCopyVirtualMemory(PsGetCurrentProcess(), buffer.as_ptr() as _, target_process, addr as _, buffer.len() as _, _MODE_KernelMode as _, &mut bytes_copied);
+        let status = copy_virtual_memory(PsGetCurrentProcess(), buffer.as_ptr() as _, target_process, addr as _, buffer.len() as _, _MODE_KernelMode as _, &mut bytes_copied);
 
         ObfDereferenceObject(target_process as _);
 
-        if !NT_SUCCESS(status) {
-            return Err(status);
-        }
+        NtStatus::check(status)?;
 
         if bytes_copied == 0 {
-            return Err(STATUS_UNSUCCESSFUL);
+            return Err(NtStatus::UNSUCCESSFUL);
         }
 
         if bytes_copied != buffer.len() as _ {
-            return Err(STATUS_BUFFER_TOO_SMALL);
+            return Err(NtStatus::BUFFER_TOO_SMALL);
         }
         Ok(())
     }
@@ -0,0 +1,287 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use crate::*;
+use crate::irql::{Apc, BelowDispatch, Passive};
+use crate::ntddk::{PsRemoveCreateThreadNotifyRoutine, PsRemoveLoadImageNotifyRoutine, PsSetCreateProcessNotifyRoutineEx,
+                   PsSetCreateThreadNotifyRoutine, PsSetLoadImageNotifyRoutine, IMAGE_INFO, IMAGE_INFO_EX, PIMAGE_INFO,
+                   PPS_CREATE_NOTIFY_INFO, PS_CREATE_NOTIFY_INFO};
+use crate::sync::{GuardedMutex, PushLock};
+use crate::ustr::UnicodeStr;
+
+
+// The kernel takes one context-free routine per registration and rejects duplicates, so each kind
+// registers a single trampoline while it has subscribers and fans out to their closures.
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
+struct Subscribers<H: ?Sized> {
+    // held across registering and removing the trampoline, which waits for running callbacks and
+    // so cannot happen under `list`
+    registration: GuardedMutex<()>,
+    list: PushLock<Vec<(usize, Box<H>)>>,
+}
+
+impl<H: ?Sized + Send + Sync> Subscribers<H> {
+    const fn new() -> Self {
+        Subscribers { registration: GuardedMutex::new(()), list: PushLock::new(Vec::new()) }
+    }
+
+    fn add(&self, handler: Box<H>, register: impl FnOnce() -> NTSTATUS, irql: &Passive) -> Result<usize, NtStatus> {
+        let _registration = self.registration.lock(irql);
+        if self.list.read(irql).is_empty() {
+            NtStatus::check(register())?;
+        }
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        self.list.write(irql).push((id, handler));
+        Ok(id)
+    }
+
+    fn remove(&self, id: usize, unregister: impl FnOnce() -> NTSTATUS, irql: &Passive) {
+        let _registration = self.registration.lock(irql);
+        let (handler, empty) = {
+            let mut list = self.list.write(irql);
+            let handler = list.iter().position(|(i, _)| *i == id).map(|index| list.remove(index));
+            (handler, list.is_empty())
+        };
+        if empty {
+            unregister();
+        }
+        drop(handler);
+    }
+
+    fn for_each(&self, irql: &impl BelowDispatch, mut f: impl FnMut(&H)) {
+        for (_, handler) in self.list.read(irql).iter() {
+            f(handler);
+        }
+    }
+}
+
+
+/// A process being created, with the option to fail the creation.
+pub struct ProcessCreate<'a> {
+    process: PEPROCESS,
+    process_id: u64,
+    info: &'a mut PS_CREATE_NOTIFY_INFO,
+}
+
+impl ProcessCreate<'_> {
+    pub fn process(&self) -> PEPROCESS {
+        self.process
+    }
+
+    pub fn process_id(&self) -> u64 {
+        self.process_id
+    }
+
+    /// The process the new one inherits from, which is not always the creator.
+    pub fn parent_id(&self) -> u64 {
+        self.info.ParentProcessId as u64
+    }
+
+    pub fn creator_process_id(&self) -> u64 {
+        self.info.CreatingThreadId.UniqueProcess as u64
+    }
+
+    pub fn creator_thread_id(&self) -> u64 {
+        self.info.CreatingThreadId.UniqueThread as u64
+    }
+
+    pub fn image_name(&self) -> Option<UnicodeStr<'_>> {
+        unsafe { self.info.ImageFileName.as_ref().map(|s| UnicodeStr::from_unicode_string(s)) }
+    }
+
+    pub fn command_line(&self) -> Option<UnicodeStr<'_>> {
+        unsafe { self.info.CommandLine.as_ref().map(|s| UnicodeStr::from_unicode_string(s)) }
+    }
+
+    pub fn file_object(&self) -> PFILE_OBJECT {
+        self.info.FileObject
+    }
+
+    /// `STATUS_SUCCESS` unless this or an earlier subscriber denied the creation.
+    pub fn creation_status(&self) -> NtStatus {
+        NtStatus::from_raw(self.info.CreationStatus)
+    }
+
+    /// Fails the creation with `status`, e.g. `STATUS_ACCESS_DENIED`.
+    pub fn deny(&mut self, status: NtStatus) {
+        self.info.CreationStatus = status.raw();
+    }
+}
+
+pub enum ProcessEvent<'a> {
+    Create(ProcessCreate<'a>),
+    Exit { process: PEPROCESS, process_id: u64 },
+}
+
+type ProcessHandler = dyn Fn(ProcessEvent<'_>, &Passive) + Send + Sync;
+
+static PROCESSES: Subscribers<ProcessHandler> = Subscribers::new();
+
+unsafe extern "C" fn process_notify(process: PEPROCESS, process_id: HANDLE, info: PPS_CREATE_NOTIFY_INFO) {
+    let irql = unsafe { Passive::assume() };
+    PROCESSES.for_each(&irql, |handler| {
+        let event = match unsafe { info.as_mut() } {
+            Some(info) => ProcessEvent::Create(ProcessCreate { process, process_id: process_id as u64, info }),
+            None => ProcessEvent::Exit { process, process_id: process_id as u64 },
+        };
+        handler(event, &irql);
+    });
+}
+
+/// A `PsSetCreateProcessNotifyRoutineEx` subscription, removed on drop. The closure runs at
+/// PASSIVE_LEVEL in the context of the creating thread, or of the last thread of an exiting
+/// process. The driver must be linked with `/INTEGRITYCHECK`, or registration fails with
+/// `STATUS_ACCESS_DENIED`.
+///
+/// Subscriptions are dropped at PASSIVE_LEVEL, which returns once no callback uses the closure.
+/// Closures run under a shared lock, so they must not create or drop subscriptions themselves.
+pub struct ProcessNotify {
+    id: usize,
+}
+
+impl ProcessNotify {
+    pub fn new(f: impl Fn(ProcessEvent<'_>, &Passive) + Send + Sync + 'static, irql: &Passive) -> Result<Self, NtStatus> {
+        let id = PROCESSES.add(Box::new(f), || unsafe { PsSetCreateProcessNotifyRoutineEx(Some(process_notify), FALSE as _) }, irql)?;
+        Ok(ProcessNotify { id })
+    }
+}
+
+impl Drop for ProcessNotify {
+    fn drop(&mut self) {
+        let irql = unsafe { Passive::assume() };
+        PROCESSES.remove(self.id, || unsafe { PsSetCreateProcessNotifyRoutineEx(Some(process_notify), TRUE as _) }, &irql);
+    }
+}
+
+
+#[derive(Debug, Copy, Clone)]
+pub struct ThreadEvent {
+    pub process_id: u64,
+    pub thread_id: u64,
+    /// `false` when the thread exits.
+    pub created: bool,
+}
+
+type ThreadHandler = dyn Fn(ThreadEvent, &Apc) + Send + Sync;
+
+static THREADS: Subscribers<ThreadHandler> = Subscribers::new();
+
+unsafe extern "C" fn thread_notify(process_id: HANDLE, thread_id: HANDLE, create: BOOLEAN) {
+    let irql = unsafe { Apc::assume() };
+    let event = ThreadEvent { process_id: process_id as u64, thread_id: thread_id as u64, created: create != 0 };
+    THREADS.for_each(&irql, |handler| handler(event, &irql));
+}
+
+/// A `PsSetCreateThreadNotifyRoutine` subscription, removed on drop. The closure runs at
+/// IRQL <= APC_LEVEL, in the creating thread for a new thread and in the thread itself on exit.
+/// The same drop and reentrancy rules as [`ProcessNotify`] apply.
+pub struct ThreadNotify {
+    id: usize,
+}
+
+impl ThreadNotify {
+    pub fn new(f: impl Fn(ThreadEvent, &Apc) + Send + Sync + 'static, irql: &Passive) -> Result<Self, NtStatus> {
+        let id = THREADS.add(Box::new(f), || unsafe { PsSetCreateThreadNotifyRoutine(Some(thread_notify)) }, irql)?;
+        Ok(ThreadNotify { id })
+    }
+}
+
+impl Drop for ThreadNotify {
+    fn drop(&mut self) {
+        let irql = unsafe { Passive::assume() };
+        THREADS.remove(self.id, || unsafe { PsRemoveCreateThreadNotifyRoutine(Some(thread_notify)) }, &irql);
+    }
+}
+
+
+/// An image mapped into a process, or a driver loaded into system space.
+pub struct ImageLoad<'a> {
+    name: Option<UnicodeStr<'a>>,
+    process_id: u64,
+    info: &'a IMAGE_INFO,
+}
+
+impl ImageLoad<'_> {
+    /// Full path of the image; may be missing, e.g. for some early boot images.
+    pub fn name(&self) -> Option<UnicodeStr<'_>> {
+        self.name
+    }
+
+    /// The process the image is mapped into; 0 for drivers.
+    pub fn process_id(&self) -> u64 {
+        self.process_id
+    }
+
+    pub fn base(&self) -> PVOID {
+        self.info.ImageBase
+    }
+
+    pub fn size(&self) -> usize {
+        self.info.ImageSize as usize
+    }
+
+    /// A kernel-mode image: a driver or a DLL loaded by one.
+    pub fn is_system_mode(&self) -> bool {
+        unsafe { self.info.__bindgen_anon_1.__bindgen_anon_1.SystemModeImage() != 0 }
+    }
+
+    /// `SE_SIGNING_LEVEL_*` that code integrity verified the image at.
+    pub fn signature_level(&self) -> u8 {
+        unsafe { self.info.__bindgen_anon_1.__bindgen_anon_1.ImageSignatureLevel() as u8 }
+    }
+
+    /// `SE_IMAGE_SIGNATURE_TYPE`: embedded, catalog-signed or none.
+    pub fn signature_type(&self) -> u8 {
+        unsafe { self.info.__bindgen_anon_1.__bindgen_anon_1.ImageSignatureType() as u8 }
+    }
+
+    pub fn is_partial_map(&self) -> bool {
+        unsafe { self.info.__bindgen_anon_1.__bindgen_anon_1.ImagePartialMap() != 0 }
+    }
+
+    /// The image's file object, when the kernel passed the extended information.
+    pub fn file_object(&self) -> Option<PFILE_OBJECT> {
+        unsafe {
+            if self.info.__bindgen_anon_1.__bindgen_anon_1.ExtendedInfoPresent() == 0 {
+                return None;
+            }
+            let ex = (self.info as *const IMAGE_INFO as *const u8).sub(core::mem::offset_of!(IMAGE_INFO_EX, ImageInfo));
+            Some((*(ex as *const IMAGE_INFO_EX)).FileObject)
+        }
+    }
+}
+
+type ImageHandler = dyn Fn(&ImageLoad<'_>, &Passive) + Send + Sync;
+
+static IMAGES: Subscribers<ImageHandler> = Subscribers::new();
+
+unsafe extern "C" fn image_notify(name: PUNICODE_STRING, process_id: HANDLE, info: PIMAGE_INFO) {
+    let irql = unsafe { Passive::assume() };
+    let Some(info) = (unsafe { info.as_ref() }) else { return };
+    let name = unsafe { name.as_ref().map(|s| UnicodeStr::from_unicode_string(s)) };
+    let event = ImageLoad { name, process_id: process_id as u64, info };
+    IMAGES.for_each(&irql, |handler| handler(&event, &irql));
+}
+
+/// A `PsSetLoadImageNotifyRoutine` subscription, removed on drop. The closure runs at
+/// PASSIVE_LEVEL after the image is mapped and before its code runs. The same drop and
+/// reentrancy rules as [`ProcessNotify`] apply.
+pub struct ImageLoadNotify {
+    id: usize,
+}
+
+impl ImageLoadNotify {
+    pub fn new(f: impl Fn(&ImageLoad<'_>, &Passive) + Send + Sync + 'static, irql: &Passive) -> Result<Self, NtStatus> {
+        let id = IMAGES.add(Box::new(f), || unsafe { PsSetLoadImageNotifyRoutine(Some(image_notify)) }, irql)?;
+        Ok(ImageLoadNotify { id })
+    }
+}
+
+impl Drop for ImageLoadNotify {
+    fn drop(&mut self) {
+        let irql = unsafe { Passive::assume() };
+        IMAGES.remove(self.id, || unsafe { PsRemoveLoadImageNotifyRoutine(Some(image_notify)) }, &irql);
+    }
+}
@@ -0,0 +1,300 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::ptr;
+use crate::*;
+use crate::irql::{self, Apc, Passive, PASSIVE_LEVEL};
+use crate::ustr::UnicodeCow;
+
+
+/// The object types `ObRegisterCallbacks` accepts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ObjectKind {
+    Process,
+    Thread,
+    Desktop,
+}
+
+const KINDS: [ObjectKind; 3] = [ObjectKind::Process, ObjectKind::Thread, ObjectKind::Desktop];
+
+impl ObjectKind {
+    fn object_type(self) -> *mut POBJECT_TYPE {
+        unsafe {
+            match self {
+                ObjectKind::Process => PsProcessType,
+                ObjectKind::Thread => PsThreadType,
+                ObjectKind::Desktop => ExDesktopObjectType,
+            }
+        }
+    }
+
+    fn of(object_type: POBJECT_TYPE) -> Option<Self> {
+        KINDS.into_iter().find(|kind| unsafe { *kind.object_type() } == object_type)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operation {
+    /// `OB_OPERATION_HANDLE_CREATE`: a handle is opened.
+    Create,
+    /// `OB_OPERATION_HANDLE_DUPLICATE`: a handle is duplicated.
+    Duplicate,
+}
+
+impl Operation {
+    fn from_raw(operation: OB_OPERATION) -> Self {
+        if operation == OB_OPERATION_HANDLE_DUPLICATE { Operation::Duplicate } else { Operation::Create }
+    }
+}
+
+
+/// A handle about to be created or duplicated. The requested access can only be reduced.
+pub struct PreOperation<'a> {
+    kind: ObjectKind,
+    info: &'a mut OB_PRE_OPERATION_INFORMATION,
+}
+
+impl PreOperation<'_> {
+    pub fn kind(&self) -> ObjectKind {
+        self.kind
+    }
+
+    pub fn operation(&self) -> Operation {
+        Operation::from_raw(self.info.Operation)
+    }
+
+    /// The process, thread or desktop the handle is for.
+    pub fn object(&self) -> PVOID {
+        self.info.Object
+    }
+
+    /// A kernel handle, which callbacks usually leave alone.
+    pub fn is_kernel_handle(&self) -> bool {
+        unsafe { self.info.__bindgen_anon_1.__bindgen_anon_1.KernelHandle() != 0 }
+    }
+
+    /// The access the handle will get, after earlier callbacks.
+    pub fn desired_access(&self) -> ACCESS_MASK {
+        unsafe {
+            match self.operation() {
+                Operation::Create => (*self.info.Parameters).CreateHandleInformation.DesiredAccess,
+                Operation::Duplicate => (*self.info.Parameters).DuplicateHandleInformation.DesiredAccess,
+            }
+        }
+    }
+
+    /// The access the caller asked for.
+    pub fn original_desired_access(&self) -> ACCESS_MASK {
+        unsafe {
+            match self.operation() {
+                Operation::Create => (*self.info.Parameters).CreateHandleInformation.OriginalDesiredAccess,
+                Operation::Duplicate => (*self.info.Parameters).DuplicateHandleInformation.OriginalDesiredAccess,
+            }
+        }
+    }
+
+    /// Limits the handle to `access`; rights outside the current desired access are not added.
+    pub fn set_desired_access(&mut self, access: ACCESS_MASK) {
+        let access = access & self.desired_access();
+        unsafe {
+            match self.operation() {
+                Operation::Create => (*self.info.Parameters).CreateHandleInformation.DesiredAccess = access,
+                Operation::Duplicate => (*self.info.Parameters).DuplicateHandleInformation.DesiredAccess = access,
+            }
+        }
+    }
+
+    /// Strips the rights in `access`, e.g. `PROCESS_VM_WRITE | PROCESS_TERMINATE`.
+    pub fn remove_access(&mut self, access: ACCESS_MASK) {
+        self.set_desired_access(self.desired_access() & !access);
+    }
+
+    /// The process that owns the source handle of a duplication.
+    pub fn source_process(&self) -> Option<PEPROCESS> {
+        match self.operation() {
+            Operation::Duplicate => Some(unsafe { (*self.info.Parameters).DuplicateHandleInformation.SourceProcess as PEPROCESS }),
+            Operation::Create => None,
+        }
+    }
+
+    /// The process that receives the duplicated handle.
+    pub fn target_process(&self) -> Option<PEPROCESS> {
+        match self.operation() {
+            Operation::Duplicate => Some(unsafe { (*self.info.Parameters).DuplicateHandleInformation.TargetProcess as PEPROCESS }),
+            Operation::Create => None,
+        }
+    }
+}
+
+
+/// A handle that was created or duplicated.
+pub struct PostOperation<'a> {
+    kind: ObjectKind,
+    info: &'a OB_POST_OPERATION_INFORMATION,
+}
+
+impl PostOperation<'_> {
+    pub fn kind(&self) -> ObjectKind {
+        self.kind
+    }
+
+    pub fn operation(&self) -> Operation {
+        Operation::from_raw(self.info.Operation)
+    }
+
+    pub fn object(&self) -> PVOID {
+        self.info.Object
+    }
+
+    pub fn is_kernel_handle(&self) -> bool {
+        unsafe { self.info.__bindgen_anon_1.__bindgen_anon_1.KernelHandle() != 0 }
+    }
+
+    pub fn status(&self) -> NtStatus {
+        NtStatus::from_raw(self.info.ReturnStatus)
+    }
+
+    /// The access the handle got; only meaningful when [`status`](Self::status) is a success.
+    pub fn granted_access(&self) -> ACCESS_MASK {
+        unsafe {
+            match self.operation() {
+                Operation::Create => (*self.info.Parameters).CreateHandleInformation.GrantedAccess,
+                Operation::Duplicate => (*self.info.Parameters).DuplicateHandleInformation.GrantedAccess,
+            }
+        }
+    }
+}
+
+
+type PreHandler = dyn Fn(&mut PreOperation<'_>, &Apc) + Send + Sync;
+type PostHandler = dyn Fn(&PostOperation<'_>, &Apc) + Send + Sync;
+
+// each closure with the operations it asked for; the registration covers both
+#[derive(Default)]
+struct Handlers {
+    pre: Option<(OB_OPERATION, Box<PreHandler>)>,
+    post: Option<(OB_OPERATION, Box<PostHandler>)>,
+}
+
+impl Handlers {
+    fn operations(&self) -> OB_OPERATION {
+        self.pre.as_ref().map_or(0, |(ops, _)| *ops) | self.post.as_ref().map_or(0, |(ops, _)| *ops)
+    }
+}
+
+// the registration context, indexed like `KINDS`
+struct Callbacks {
+    handlers: [Handlers; 3],
+}
+
+
+/// Registers handle create/duplicate callbacks with `ObRegisterCallbacks`. Each object kind takes
+/// one pre and one post closure; they run at IRQL <= APC_LEVEL in the thread opening the handle.
+///
+/// The driver must be linked with `/INTEGRITYCHECK`, or registration fails with
+/// `STATUS_ACCESS_DENIED`.
+pub struct ObCallbackBuilder<'a> {
+    altitude: UnicodeCow<'a>,
+    callbacks: Callbacks,
+}
+
+impl<'a> ObCallbackBuilder<'a> {
+    /// `altitude` orders the callbacks against other drivers', e.g. `"321000"`; two registrations
+    /// at the same altitude fail with `STATUS_FLT_INSTANCE_ALTITUDE_COLLISION`.
+    pub fn new(altitude: impl Into<UnicodeCow<'a>>) -> Self {
+        ObCallbackBuilder { altitude: altitude.into(), callbacks: Callbacks { handlers: Default::default() } }
+    }
+
+    fn handlers(&mut self, kind: ObjectKind) -> &mut Handlers {
+        &mut self.callbacks.handlers[kind as usize]
+    }
+
+    /// Runs `f` before `operations` (`OB_OPERATION_HANDLE_CREATE` and/or `_DUPLICATE`) on handles
+    /// to `kind`, replacing an earlier pre callback for it.
+    pub fn pre(mut self, kind: ObjectKind, operations: OB_OPERATION,
+               f: impl Fn(&mut PreOperation<'_>, &Apc) + Send + Sync + 'static) -> Self {
+        self.handlers(kind).pre = Some((operations, Box::new(f)));
+        self
+    }
+
+    /// Runs `f` after `operations` on handles to `kind`, replacing an earlier post callback for it.
+    pub fn post(mut self, kind: ObjectKind, operations: OB_OPERATION,
+                f: impl Fn(&PostOperation<'_>, &Apc) + Send + Sync + 'static) -> Self {
+        self.handlers(kind).post = Some((operations, Box::new(f)));
+        self
+    }
+
+    pub fn build(self, _irql: &Passive) -> Result<ObCallbacks, NtStatus> {
+        irql::debug_assert_at_most(PASSIVE_LEVEL);
+        let callbacks = Box::new(self.callbacks);
+        let operations: Vec<OB_OPERATION_REGISTRATION> = KINDS.iter()
+            .zip(&callbacks.handlers)
+            .filter(|(_, handlers)| handlers.operations() != 0)
+            .map(|(kind, handlers)| OB_OPERATION_REGISTRATION {
+                ObjectType: kind.object_type(),
+                Operations: handlers.operations(),
+                PreOperation: handlers.pre.as_ref().map(|_| pre_operation as _),
+                PostOperation: handlers.post.as_ref().map(|_| post_operation as _),
+            })
+            .collect();
+        if operations.is_empty() {
+            return Err(NtStatus::INVALID_PARAMETER);
+        }
+
+        let mut registration = OB_CALLBACK_REGISTRATION {
+            Version: OB_FLT_REGISTRATION_VERSION as _,
+            OperationRegistrationCount: operations.len() as _,
+            Altitude: self.altitude.as_ustr().as_unicode_string()?,
+            RegistrationContext: &*callbacks as *const Callbacks as PVOID,
+            OperationRegistration: operations.as_ptr() as *mut _,
+        };
+        let mut handle = ptr::null_mut();
+        // the registration, altitude included, is copied by the object manager
+        NtStatus::check(unsafe { ObRegisterCallbacks(&mut registration, &mut handle) })?;
+        Ok(ObCallbacks { handle, _callbacks: callbacks })
+    }
+}
+
+
+/// Registered object callbacks; dropping it calls `ObUnRegisterCallbacks`, at PASSIVE_LEVEL,
+/// and frees the closures.
+pub struct ObCallbacks {
+    handle: PVOID,
+    // referenced by the callbacks until unregistered
+    _callbacks: Box<Callbacks>,
+}
+
+unsafe impl Send for ObCallbacks {}
+unsafe impl Sync for ObCallbacks {}
+
+impl Drop for ObCallbacks {
+    fn drop(&mut self) {
+        irql::debug_assert_at_most(PASSIVE_LEVEL);
+        unsafe { ObUnRegisterCallbacks(self.handle) };
+    }
+}
+
+
+unsafe extern "C" fn pre_operation(context: PVOID, info: POB_PRE_OPERATION_INFORMATION) -> OB_PREOP_CALLBACK_STATUS {
+    let callbacks = unsafe { &*(context as *const Callbacks) };
+    let info = unsafe { &mut *info };
+    if let Some(kind) = ObjectKind::of(info.ObjectType)
+        && let Some((operations, pre)) = &callbacks.handlers[kind as usize].pre
+        && operations & info.Operation != 0
+    {
+        let irql = unsafe { Apc::assume() };
+        pre(&mut PreOperation { kind, info }, &irql);
+    }
+    _OB_PREOP_CALLBACK_STATUS_OB_PREOP_SUCCESS
+}
+
+unsafe extern "C" fn post_operation(context: PVOID, info: POB_POST_OPERATION_INFORMATION) {
+    let callbacks = unsafe { &*(context as *const Callbacks) };
+    let info = unsafe { &*info };
+    if let Some(kind) = ObjectKind::of(info.ObjectType)
+        && let Some((operations, post)) = &callbacks.handlers[kind as usize].post
+        && operations & info.Operation != 0
+    {
+        let irql = unsafe { Apc::assume() };
+        post(&PostOperation { kind, info }, &irql);
+    }
+}
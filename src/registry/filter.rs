@@ -0,0 +1,406 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ptr;
+use core::sync::atomic::{AtomicI64, Ordering};
+use crate::*;
+use crate::driver::DriverObject;
+use crate::irql::{self, Passive, PASSIVE_LEVEL};
+use crate::memory::probe;
+use crate::ustr::{UnicodeCow, UnicodeStr};
+
+
+/// What a callback does with the operation it was shown.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Let the operation run, or keep its result in a post notification.
+    Pass,
+    /// Fail the operation with an error status. In a post notification the status replaces the
+    /// result the caller sees.
+    Block(NtStatus),
+    /// Report success without the registry doing the operation, for a callback that performed it
+    /// itself, output included. Same as [`Pass`](Self::Pass) in a post notification.
+    Bypass,
+}
+
+
+/// A registry notification decoded from its `REG_NOTIFY_CLASS`. `Pre*` variants run before the
+/// configuration manager acts and can be blocked or have their parameters changed; `Post*` run
+/// after it, whether the operation succeeded or not.
+pub enum Operation<'a> {
+    PreDeleteKey(KeyOperation<'a>),
+    PreSetValue(SetValue<'a>),
+    PreDeleteValue(ValueOperation<'a>),
+    PreSetInformationKey(KeyOperation<'a>),
+    PreRenameKey(RenameKey<'a>),
+    PreQueryValue(ValueOperation<'a>),
+    /// `RegNtPreCreateKeyEx`.
+    PreCreateKey(CreateKey<'a>),
+    /// `RegNtPreOpenKeyEx`.
+    PreOpenKey(CreateKey<'a>),
+    PostDeleteKey(PostOperation<'a>),
+    PostSetValue(PostOperation<'a>),
+    PostDeleteValue(PostOperation<'a>),
+    PostSetInformationKey(PostOperation<'a>),
+    PostRenameKey(PostOperation<'a>),
+    PostQueryValue(PostOperation<'a>),
+    PostCreateKey(PostOperation<'a>),
+    PostOpenKey(PostOperation<'a>),
+    /// Any other class, with the raw `Argument2` of the callback.
+    Other { class: _REG_NOTIFY_CLASS, argument: PVOID },
+}
+
+impl<'a> Operation<'a> {
+    unsafe fn decode(class: _REG_NOTIFY_CLASS, argument: PVOID, cookie: &'a AtomicI64) -> Self {
+        unsafe {
+            let key = |object| KeyOperation { object, cookie };
+            let post = || PostOperation { info: &mut *(argument as *mut REG_POST_OPERATION_INFORMATION), cookie };
+            match class {
+                _REG_NOTIFY_CLASS_RegNtPreDeleteKey =>
+                    Operation::PreDeleteKey(key((*(argument as *mut REG_DELETE_KEY_INFORMATION)).Object)),
+                _REG_NOTIFY_CLASS_RegNtPreSetValueKey =>
+                    Operation::PreSetValue(SetValue { info: &mut *(argument as *mut REG_SET_VALUE_KEY_INFORMATION), cookie }),
+                _REG_NOTIFY_CLASS_RegNtPreDeleteValueKey => {
+                    let info = &*(argument as *const REG_DELETE_VALUE_KEY_INFORMATION);
+                    Operation::PreDeleteValue(ValueOperation { object: info.Object, value_name: info.ValueName, cookie })
+                }
+                _REG_NOTIFY_CLASS_RegNtPreSetInformationKey =>
+                    Operation::PreSetInformationKey(key((*(argument as *mut REG_SET_INFORMATION_KEY_INFORMATION)).Object)),
+                _REG_NOTIFY_CLASS_RegNtPreRenameKey =>
+                    Operation::PreRenameKey(RenameKey { info: &*(argument as *const REG_RENAME_KEY_INFORMATION), cookie }),
+                _REG_NOTIFY_CLASS_RegNtPreQueryValueKey => {
+                    let info = &*(argument as *const REG_QUERY_VALUE_KEY_INFORMATION);
+                    Operation::PreQueryValue(ValueOperation { object: info.Object, value_name: info.ValueName, cookie })
+                }
+                _REG_NOTIFY_CLASS_RegNtPreCreateKeyEx =>
+                    Operation::PreCreateKey(CreateKey { info: &mut *(argument as *mut REG_CREATE_KEY_INFORMATION), cookie }),
+                _REG_NOTIFY_CLASS_RegNtPreOpenKeyEx =>
+                    Operation::PreOpenKey(CreateKey { info: &mut *(argument as *mut REG_CREATE_KEY_INFORMATION), cookie }),
+                _REG_NOTIFY_CLASS_RegNtPostDeleteKey => Operation::PostDeleteKey(post()),
+                _REG_NOTIFY_CLASS_RegNtPostSetValueKey => Operation::PostSetValue(post()),
+                _REG_NOTIFY_CLASS_RegNtPostDeleteValueKey => Operation::PostDeleteValue(post()),
+                _REG_NOTIFY_CLASS_RegNtPostSetInformationKey => Operation::PostSetInformationKey(post()),
+                _REG_NOTIFY_CLASS_RegNtPostRenameKey => Operation::PostRenameKey(post()),
+                _REG_NOTIFY_CLASS_RegNtPostQueryValueKey => Operation::PostQueryValue(post()),
+                _REG_NOTIFY_CLASS_RegNtPostCreateKeyEx => Operation::PostCreateKey(post()),
+                _REG_NOTIFY_CLASS_RegNtPostOpenKeyEx => Operation::PostOpenKey(post()),
+                _ => Operation::Other { class, argument },
+            }
+        }
+    }
+
+    pub fn is_post(&self) -> bool {
+        self.post().is_some()
+    }
+
+    fn post(&self) -> Option<&PostOperation<'a>> {
+        match self {
+            Operation::PostDeleteKey(post) | Operation::PostSetValue(post) | Operation::PostDeleteValue(post)
+            | Operation::PostSetInformationKey(post) | Operation::PostRenameKey(post)
+            | Operation::PostQueryValue(post) | Operation::PostCreateKey(post)
+            | Operation::PostOpenKey(post) => Some(post),
+            _ => None,
+        }
+    }
+
+    fn post_mut(&mut self) -> Option<&mut PostOperation<'a>> {
+        match self {
+            Operation::PostDeleteKey(post) | Operation::PostSetValue(post) | Operation::PostDeleteValue(post)
+            | Operation::PostSetInformationKey(post) | Operation::PostRenameKey(post)
+            | Operation::PostQueryValue(post) | Operation::PostCreateKey(post)
+            | Operation::PostOpenKey(post) => Some(post),
+            _ => None,
+        }
+    }
+
+    // the callback's return value
+    fn finish(&mut self, action: Action) -> NTSTATUS {
+        match (self.post_mut(), action) {
+            (_, Action::Pass) => STATUS_SUCCESS,
+            // a post callback changes the result through `ReturnStatus` and says so with a bypass
+            (Some(post), Action::Block(status)) => {
+                post.info.ReturnStatus = status.raw();
+                NtStatus::CALLBACK_BYPASS.raw()
+            }
+            (Some(_), Action::Bypass) => STATUS_SUCCESS,
+            (None, Action::Block(status)) => status.raw(),
+            (None, Action::Bypass) => NtStatus::CALLBACK_BYPASS.raw(),
+        }
+    }
+}
+
+
+/// The full path of a key object, from `CmCallbackGetKeyObjectIDEx`, e.g.
+/// `\REGISTRY\MACHINE\SOFTWARE\Foo`. Released on drop.
+pub struct KeyName {
+    raw: PCUNICODE_STRING,
+}
+
+impl KeyName {
+    pub fn as_ustr(&self) -> UnicodeStr<'_> {
+        unsafe { UnicodeStr::from_unicode_string(&*self.raw) }
+    }
+}
+
+impl fmt::Display for KeyName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_ustr().fmt(f)
+    }
+}
+
+impl Drop for KeyName {
+    fn drop(&mut self) {
+        unsafe { CmCallbackReleaseKeyObjectIDEx(self.raw) };
+    }
+}
+
+fn key_name(cookie: &AtomicI64, object: PVOID) -> Result<KeyName, NtStatus> {
+    if object.is_null() {
+        return Err(NtStatus::INVALID_PARAMETER);
+    }
+    let mut cookie = LARGE_INTEGER { QuadPart: cookie.load(Ordering::Acquire) };
+    let mut raw: PCUNICODE_STRING = ptr::null();
+    NtStatus::check(unsafe { CmCallbackGetKeyObjectIDEx(&mut cookie, object, ptr::null_mut(), &mut raw, 0) })?;
+    Ok(KeyName { raw })
+}
+
+unsafe fn value_name<'a>(name: PUNICODE_STRING) -> UnicodeStr<'a> {
+    unsafe { name.as_ref().map(|s| UnicodeStr::from_unicode_string(s)).unwrap_or_default() }
+}
+
+
+/// An operation on an open key: deleting it or setting its information.
+pub struct KeyOperation<'a> {
+    object: PVOID,
+    cookie: &'a AtomicI64,
+}
+
+impl KeyOperation<'_> {
+    pub fn object(&self) -> PVOID {
+        self.object
+    }
+
+    pub fn key_name(&self) -> Result<KeyName, NtStatus> {
+        key_name(self.cookie, self.object)
+    }
+}
+
+
+/// Deleting or querying a value of an open key.
+pub struct ValueOperation<'a> {
+    object: PVOID,
+    value_name: PUNICODE_STRING,
+    cookie: &'a AtomicI64,
+}
+
+impl ValueOperation<'_> {
+    pub fn object(&self) -> PVOID {
+        self.object
+    }
+
+    pub fn key_name(&self) -> Result<KeyName, NtStatus> {
+        key_name(self.cookie, self.object)
+    }
+
+    /// Empty for the key's default value.
+    pub fn value_name(&self) -> UnicodeStr<'_> {
+        unsafe { value_name(self.value_name) }
+    }
+}
+
+
+/// A value being written.
+pub struct SetValue<'a> {
+    info: &'a mut REG_SET_VALUE_KEY_INFORMATION,
+    cookie: &'a AtomicI64,
+}
+
+impl SetValue<'_> {
+    pub fn object(&self) -> PVOID {
+        self.info.Object
+    }
+
+    pub fn key_name(&self) -> Result<KeyName, NtStatus> {
+        key_name(self.cookie, self.info.Object)
+    }
+
+    /// Empty for the key's default value.
+    pub fn value_name(&self) -> UnicodeStr<'_> {
+        unsafe { value_name(self.info.ValueName) }
+    }
+
+    /// `REG_SZ`, `REG_DWORD`, ...
+    pub fn value_type(&self) -> u32 {
+        self.info.Type
+    }
+
+    pub fn data_size(&self) -> usize {
+        self.info.DataSize as usize
+    }
+
+    /// A copy of the data. For a user-mode caller it is still the caller's buffer, which can
+    /// change or vanish under the callback, so it is read through [`probe::try_read_bytes`].
+    pub fn data(&self) -> Result<Vec<u8>, NtStatus> {
+        let mut data = vec![0u8; self.data_size()];
+        unsafe { probe::try_read_bytes(self.info.Data as *const u8, &mut data)? };
+        Ok(data)
+    }
+
+    pub fn set_value_type(&mut self, value_type: u32) {
+        self.info.Type = value_type;
+    }
+
+    /// Replaces the data that will be written.
+    ///
+    /// # Safety
+    ///
+    /// `data` must stay valid until the post notification of this operation; the registry
+    /// reads it after the callback returns.
+    pub unsafe fn set_data(&mut self, data: &[u8]) -> Result<(), NtStatus> {
+        self.info.DataSize = u32::try_from(data.len()).map_err(|_| NtStatus::INVALID_PARAMETER)?;
+        self.info.Data = data.as_ptr() as PVOID;
+        Ok(())
+    }
+}
+
+
+pub struct RenameKey<'a> {
+    info: &'a REG_RENAME_KEY_INFORMATION,
+    cookie: &'a AtomicI64,
+}
+
+impl RenameKey<'_> {
+    pub fn object(&self) -> PVOID {
+        self.info.Object
+    }
+
+    pub fn key_name(&self) -> Result<KeyName, NtStatus> {
+        key_name(self.cookie, self.info.Object)
+    }
+
+    /// The new last component of the key's path.
+    pub fn new_name(&self) -> UnicodeStr<'_> {
+        unsafe { value_name(self.info.NewName) }
+    }
+}
+
+
+/// A key being created or opened. The key object does not exist yet; the path is
+/// [`complete_name`](Self::complete_name), relative to [`root_name`](Self::root_name) unless it
+/// starts with `\`.
+pub struct CreateKey<'a> {
+    info: &'a mut REG_CREATE_KEY_INFORMATION,
+    cookie: &'a AtomicI64,
+}
+
+impl CreateKey<'_> {
+    pub fn complete_name(&self) -> UnicodeStr<'_> {
+        unsafe { value_name(self.info.CompleteName) }
+    }
+
+    pub fn root_object(&self) -> PVOID {
+        self.info.RootObject
+    }
+
+    pub fn root_name(&self) -> Result<KeyName, NtStatus> {
+        key_name(self.cookie, self.info.RootObject)
+    }
+
+    /// `REG_OPTION_*`.
+    pub fn create_options(&self) -> u32 {
+        self.info.CreateOptions
+    }
+
+    pub fn desired_access(&self) -> ACCESS_MASK {
+        self.info.DesiredAccess
+    }
+
+    /// Strips the rights in `access` from the handle, e.g. `KEY_SET_VALUE`.
+    pub fn remove_access(&mut self, access: ACCESS_MASK) {
+        self.info.DesiredAccess &= !access;
+    }
+}
+
+
+/// A completed operation. Blocking it replaces the status its caller sees.
+pub struct PostOperation<'a> {
+    info: &'a mut REG_POST_OPERATION_INFORMATION,
+    cookie: &'a AtomicI64,
+}
+
+impl PostOperation<'_> {
+    pub fn status(&self) -> NtStatus {
+        NtStatus::from_raw(self.info.Status)
+    }
+
+    /// The key the operation was on, or the new key of a create or open; only valid when
+    /// [`status`](Self::status) is a success.
+    pub fn object(&self) -> PVOID {
+        self.info.Object
+    }
+
+    pub fn key_name(&self) -> Result<KeyName, NtStatus> {
+        self.status().into_result()?;
+        key_name(self.cookie, self.info.Object)
+    }
+
+    /// The `REG_*_INFORMATION` of the matching pre notification.
+    pub fn pre_information(&self) -> PVOID {
+        self.info.PreInformation
+    }
+}
+
+
+type Handler = dyn Fn(&mut Operation<'_>, &Passive) -> Action + Send + Sync;
+
+struct Context {
+    // written by `CmRegisterCallbackEx`; a callback racing the registration sees 0 and
+    // `key_name` fails
+    cookie: AtomicI64,
+    handler: Box<Handler>,
+}
+
+/// A registry filter registered with `CmRegisterCallbackEx`, unregistered on drop. The closure
+/// runs at PASSIVE_LEVEL in the thread doing the registry operation, for every operation
+/// system-wide, and decides with an [`Action`].
+pub struct Filter {
+    context: Box<Context>,
+}
+
+impl Filter {
+    /// `altitude` orders the filter against other drivers', e.g. `"321000"`.
+    pub fn new<'a>(altitude: impl Into<UnicodeCow<'a>>, driver: &DriverObject,
+                   f: impl Fn(&mut Operation<'_>, &Passive) -> Action + Send + Sync + 'static,
+                   _irql: &Passive) -> Result<Self, NtStatus> {
+        irql::debug_assert_at_most(PASSIVE_LEVEL);
+        let context = Box::new(Context { cookie: AtomicI64::new(0), handler: Box::new(f) });
+        let altitude = altitude.into();
+        let altitude = altitude.as_ustr().as_unicode_string()?;
+        // the altitude is copied by the configuration manager
+        NtStatus::check(unsafe {
+            CmRegisterCallbackEx(Some(registry_callback), &altitude, driver.as_raw() as PVOID,
+                                 &*context as *const Context as PVOID, context.cookie.as_ptr() as PLARGE_INTEGER,
+                                 ptr::null_mut())
+        })?;
+        Ok(Filter { context })
+    }
+}
+
+impl Drop for Filter {
+    fn drop(&mut self) {
+        irql::debug_assert_at_most(PASSIVE_LEVEL);
+        // waits for callbacks in progress, after which the context can go
+        let cookie = LARGE_INTEGER { QuadPart: self.context.cookie.load(Ordering::Acquire) };
+        unsafe { CmUnRegisterCallback(cookie) };
+    }
+}
+
+
+unsafe extern "C" fn registry_callback(context: PVOID, argument1: PVOID, argument2: PVOID) -> NTSTATUS {
+    let context = unsafe { &*(context as *const Context) };
+    let mut operation = unsafe { Operation::decode(argument1 as usize as _REG_NOTIFY_CLASS, argument2, &context.cookie) };
+    let irql = unsafe { Passive::assume() };
+    let action = (context.handler)(&mut operation, &irql);
+    operation.finish(action)
+}
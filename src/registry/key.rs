@@ -0,0 +1,230 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ptr;
+use crate::*;
+use crate::irql::{self, Passive, PASSIVE_LEVEL};
+use crate::registry::value::{self, RegValue};
+use crate::ustr::{OwnedUnicodeString, UnicodeCow};
+
+
+/// An open registry key, closed on drop. Everything runs at PASSIVE_LEVEL.
+///
+/// Paths are kernel paths, e.g. the `RegistryPath` passed to `DriverEntry` or
+/// `\Registry\Machine\Software\Vendor`. An empty value name is the key's default value.
+pub struct RegKey {
+    handle: HANDLE,
+}
+
+// a kernel handle, valid in any process
+unsafe impl Send for RegKey {}
+unsafe impl Sync for RegKey {}
+
+impl RegKey {
+    pub fn open<'a>(path: impl Into<UnicodeCow<'a>>, access: ACCESS_MASK, _irql: &Passive) -> Result<Self, NtStatus> {
+        open_at(ptr::null_mut(), path.into(), access, false)
+    }
+
+    /// Opens `path`, creating it as a non-volatile key if it does not exist. Parent keys are
+    /// not created.
+    pub fn create<'a>(path: impl Into<UnicodeCow<'a>>, access: ACCESS_MASK, _irql: &Passive) -> Result<Self, NtStatus> {
+        open_at(ptr::null_mut(), path.into(), access, true)
+    }
+
+    pub fn open_subkey<'a>(&self, name: impl Into<UnicodeCow<'a>>, access: ACCESS_MASK, _irql: &Passive) -> Result<Self, NtStatus> {
+        open_at(self.handle, name.into(), access, false)
+    }
+
+    pub fn create_subkey<'a>(&self, name: impl Into<UnicodeCow<'a>>, access: ACCESS_MASK, _irql: &Passive) -> Result<Self, NtStatus> {
+        open_at(self.handle, name.into(), access, true)
+    }
+
+    pub fn as_raw(&self) -> HANDLE {
+        self.handle
+    }
+
+    /// Names of the direct subkeys. Needs `KEY_ENUMERATE_SUB_KEYS`.
+    pub fn subkeys(&self, _irql: &Passive) -> Result<Vec<OwnedUnicodeString>, NtStatus> {
+        let mut names = Vec::new();
+        for index in 0.. {
+            let buffer = query(|buffer, needed| unsafe {
+                ZwEnumerateKey(self.handle, index, _KEY_INFORMATION_CLASS_KeyBasicInformation,
+                               buffer.as_mut_ptr() as _, buffer.len() as _, needed)
+            });
+            match buffer {
+                Ok(buffer) => names.push(value::parse_key_basic_information(&buffer)?),
+                Err(NtStatus::NO_MORE_ENTRIES) => break,
+                Err(status) => return Err(status),
+            }
+        }
+        Ok(names)
+    }
+
+    /// Names and types of the values. Needs `KEY_QUERY_VALUE`.
+    pub fn values(&self, _irql: &Passive) -> Result<Vec<(OwnedUnicodeString, u32)>, NtStatus> {
+        let mut values = Vec::new();
+        for index in 0.. {
+            let buffer = query(|buffer, needed| unsafe {
+                ZwEnumerateValueKey(self.handle, index, _KEY_VALUE_INFORMATION_CLASS_KeyValueBasicInformation,
+                                    buffer.as_mut_ptr() as _, buffer.len() as _, needed)
+            });
+            match buffer {
+                Ok(buffer) => values.push(value::parse_value_basic_information(&buffer)?),
+                Err(NtStatus::NO_MORE_ENTRIES) => break,
+                Err(status) => return Err(status),
+            }
+        }
+        Ok(values)
+    }
+
+    /// Reads a value of any type. A missing value is `STATUS_OBJECT_NAME_NOT_FOUND`.
+    pub fn get<'a>(&self, name: impl Into<UnicodeCow<'a>>, _irql: &Passive) -> Result<RegValue, NtStatus> {
+        let name = name.into();
+        let mut name = name.as_ustr().as_unicode_string()?;
+        let buffer = query(|buffer, needed| unsafe {
+            ZwQueryValueKey(self.handle, &mut name, _KEY_VALUE_INFORMATION_CLASS_KeyValuePartialInformation,
+                            buffer.as_mut_ptr() as _, buffer.len() as _, needed)
+        })?;
+        RegValue::from_partial_information(&buffer)
+    }
+
+    /// A `REG_DWORD`; a value of another type is `STATUS_OBJECT_TYPE_MISMATCH`, as for the
+    /// other typed getters.
+    pub fn get_dword<'a>(&self, name: impl Into<UnicodeCow<'a>>, irql: &Passive) -> Result<u32, NtStatus> {
+        match self.get(name, irql)? {
+            RegValue::Dword(v) => Ok(v),
+            _ => Err(NtStatus::OBJECT_TYPE_MISMATCH),
+        }
+    }
+
+    pub fn get_qword<'a>(&self, name: impl Into<UnicodeCow<'a>>, irql: &Passive) -> Result<u64, NtStatus> {
+        match self.get(name, irql)? {
+            RegValue::Qword(v) => Ok(v),
+            _ => Err(NtStatus::OBJECT_TYPE_MISMATCH),
+        }
+    }
+
+    pub fn get_string<'a>(&self, name: impl Into<UnicodeCow<'a>>, irql: &Passive) -> Result<OwnedUnicodeString, NtStatus> {
+        match self.get(name, irql)? {
+            RegValue::Sz(s) => Ok(s),
+            _ => Err(NtStatus::OBJECT_TYPE_MISMATCH),
+        }
+    }
+
+    /// A `REG_EXPAND_SZ`, as stored; environment variables are not expanded.
+    pub fn get_expand_string<'a>(&self, name: impl Into<UnicodeCow<'a>>, irql: &Passive) -> Result<OwnedUnicodeString, NtStatus> {
+        match self.get(name, irql)? {
+            RegValue::ExpandSz(s) => Ok(s),
+            _ => Err(NtStatus::OBJECT_TYPE_MISMATCH),
+        }
+    }
+
+    pub fn get_multi_string<'a>(&self, name: impl Into<UnicodeCow<'a>>, irql: &Passive) -> Result<Vec<OwnedUnicodeString>, NtStatus> {
+        match self.get(name, irql)? {
+            RegValue::MultiSz(strings) => Ok(strings),
+            _ => Err(NtStatus::OBJECT_TYPE_MISMATCH),
+        }
+    }
+
+    pub fn get_binary<'a>(&self, name: impl Into<UnicodeCow<'a>>, irql: &Passive) -> Result<Vec<u8>, NtStatus> {
+        match self.get(name, irql)? {
+            RegValue::Binary(data) => Ok(data),
+            _ => Err(NtStatus::OBJECT_TYPE_MISMATCH),
+        }
+    }
+
+    /// Writes a value, replacing one of any type. Needs `KEY_SET_VALUE`.
+    pub fn set<'a>(&self, name: impl Into<UnicodeCow<'a>>, value: &RegValue, _irql: &Passive) -> Result<(), NtStatus> {
+        self.set_raw(name.into(), value.value_type(), &value.to_bytes())
+    }
+
+    pub fn set_dword<'a>(&self, name: impl Into<UnicodeCow<'a>>, value: u32, _irql: &Passive) -> Result<(), NtStatus> {
+        self.set_raw(name.into(), REG_DWORD, &value.to_le_bytes())
+    }
+
+    pub fn set_qword<'a>(&self, name: impl Into<UnicodeCow<'a>>, value: u64, _irql: &Passive) -> Result<(), NtStatus> {
+        self.set_raw(name.into(), REG_QWORD, &value.to_le_bytes())
+    }
+
+    pub fn set_string<'a, 'b>(&self, name: impl Into<UnicodeCow<'a>>, value: impl Into<UnicodeCow<'b>>, _irql: &Passive) -> Result<(), NtStatus> {
+        self.set_raw(name.into(), REG_SZ, &value::encode_sz(value.into().as_ustr()))
+    }
+
+    pub fn set_expand_string<'a, 'b>(&self, name: impl Into<UnicodeCow<'a>>, value: impl Into<UnicodeCow<'b>>, _irql: &Passive) -> Result<(), NtStatus> {
+        self.set_raw(name.into(), REG_EXPAND_SZ, &value::encode_sz(value.into().as_ustr()))
+    }
+
+    pub fn set_multi_string<'a, 'b, S: Into<UnicodeCow<'b>>>(&self, name: impl Into<UnicodeCow<'a>>, values: impl IntoIterator<Item = S>,
+                                                            _irql: &Passive) -> Result<(), NtStatus> {
+        let values: Vec<UnicodeCow> = values.into_iter().map(Into::into).collect();
+        self.set_raw(name.into(), REG_MULTI_SZ, &value::encode_multi_sz(values.iter().map(|s| s.as_ustr())))
+    }
+
+    pub fn set_binary<'a>(&self, name: impl Into<UnicodeCow<'a>>, data: &[u8], _irql: &Passive) -> Result<(), NtStatus> {
+        self.set_raw(name.into(), REG_BINARY, data)
+    }
+
+    fn set_raw(&self, name: UnicodeCow, value_type: u32, data: &[u8]) -> Result<(), NtStatus> {
+        irql::debug_assert_at_most(PASSIVE_LEVEL);
+        let mut name = name.as_ustr().as_unicode_string()?;
+        let size = u32::try_from(data.len()).map_err(|_| NtStatus::INVALID_PARAMETER)?;
+        NtStatus::check(unsafe { ZwSetValueKey(self.handle, &mut name, 0, value_type, data.as_ptr() as PVOID, size) })
+    }
+
+    pub fn delete_value<'a>(&self, name: impl Into<UnicodeCow<'a>>, _irql: &Passive) -> Result<(), NtStatus> {
+        let name = name.into();
+        let mut name = name.as_ustr().as_unicode_string()?;
+        NtStatus::check(unsafe { ZwDeleteValueKey(self.handle, &mut name) })
+    }
+
+    /// Deletes the key, which must have been opened with `DELETE` and have no subkeys.
+    pub fn delete(self, _irql: &Passive) -> Result<(), NtStatus> {
+        NtStatus::check(unsafe { ZwDeleteKey(self.handle) })
+    }
+}
+
+impl Drop for RegKey {
+    fn drop(&mut self) {
+        irql::debug_assert_at_most(PASSIVE_LEVEL);
+        unsafe { ZwClose(self.handle) };
+    }
+}
+
+
+fn open_at(root: HANDLE, path: UnicodeCow, access: ACCESS_MASK, create: bool) -> Result<RegKey, NtStatus> {
+    irql::debug_assert_at_most(PASSIVE_LEVEL);
+    let mut name = path.as_ustr().as_unicode_string()?;
+    let mut attributes: OBJECT_ATTRIBUTES = unsafe { core::mem::zeroed() };
+    init_object_attributes(&mut attributes, &mut name, OBJ_KERNEL_HANDLE | OBJ_CASE_INSENSITIVE);
+    attributes.RootDirectory = root;
+    let mut handle: HANDLE = ptr::null_mut();
+    NtStatus::check(unsafe {
+        if create {
+            ZwCreateKey(&mut handle, access, &mut attributes, 0, ptr::null_mut(), REG_OPTION_NON_VOLATILE, ptr::null_mut())
+        } else {
+            ZwOpenKey(&mut handle, access, &mut attributes)
+        }
+    })?;
+    Ok(RegKey { handle })
+}
+
+// Runs a `Zw*Key` query, growing the buffer to the reported size on `STATUS_BUFFER_OVERFLOW` or
+// `STATUS_BUFFER_TOO_SMALL`; the data can grow in between, hence the loop. Any status other than
+// success is returned as the error, `STATUS_NO_MORE_ENTRIES` included.
+fn query(mut f: impl FnMut(&mut [u8], &mut u32) -> NTSTATUS) -> Result<Vec<u8>, NtStatus> {
+    irql::debug_assert_at_most(PASSIVE_LEVEL);
+    let mut buffer = vec![0u8; 128];
+    loop {
+        let mut needed = 0u32;
+        match NtStatus::from_raw(f(&mut buffer, &mut needed)) {
+            NtStatus::BUFFER_OVERFLOW | NtStatus::BUFFER_TOO_SMALL => {
+                let len = core::cmp::max(needed as usize, buffer.len() * 2);
+                buffer.resize(len, 0);
+            }
+            NtStatus::SUCCESS => {
+                buffer.truncate(needed as usize);
+                return Ok(buffer);
+            }
+            status => return Err(status),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+#[cfg(feature = "kernel")]
+pub mod filter;
+#[cfg(feature = "kernel")]
+pub mod key;
+pub mod value;
+
+#[cfg(feature = "kernel")]
+pub use filter::{Action, CreateKey, Filter, KeyName, KeyOperation, Operation, PostOperation, RenameKey, SetValue, ValueOperation};
+#[cfg(feature = "kernel")]
+pub use key::RegKey;
+pub use value::RegValue;
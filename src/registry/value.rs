@@ -0,0 +1,295 @@
+use alloc::vec::Vec;
+use crate::status::NtStatus;
+use crate::ustr::{OwnedUnicodeString, UnicodeStr};
+
+
+// Decoding of the `Zw*Key` information classes and of value data. Only byte slices go in, so
+// none of it needs the kernel or the bindings; the types and offsets below are the wdm.h ones.
+
+const REG_SZ: u32 = 1;
+const REG_EXPAND_SZ: u32 = 2;
+const REG_BINARY: u32 = 3;
+const REG_DWORD: u32 = 4;
+const REG_MULTI_SZ: u32 = 7;
+const REG_QWORD: u32 = 11;
+
+// KEY_VALUE_PARTIAL_INFORMATION: TitleIndex, Type, DataLength, Data[]
+const PARTIAL_TYPE: usize = 4;
+const PARTIAL_DATA_LENGTH: usize = 8;
+const PARTIAL_DATA: usize = 12;
+// KEY_BASIC_INFORMATION: LastWriteTime, TitleIndex, NameLength, Name[]
+const KEY_BASIC_NAME_LENGTH: usize = 12;
+const KEY_BASIC_NAME: usize = 16;
+// KEY_VALUE_BASIC_INFORMATION: TitleIndex, Type, NameLength, Name[]
+const VALUE_BASIC_TYPE: usize = 4;
+const VALUE_BASIC_NAME_LENGTH: usize = 8;
+const VALUE_BASIC_NAME: usize = 12;
+
+#[cfg(windows)]
+const _: () = {
+    use core::mem::offset_of;
+    use crate::wdm;
+    assert!(REG_SZ == wdm::REG_SZ && REG_EXPAND_SZ == wdm::REG_EXPAND_SZ && REG_BINARY == wdm::REG_BINARY);
+    assert!(REG_DWORD == wdm::REG_DWORD && REG_MULTI_SZ == wdm::REG_MULTI_SZ && REG_QWORD == wdm::REG_QWORD);
+    assert!(offset_of!(wdm::KEY_VALUE_PARTIAL_INFORMATION, Type) == PARTIAL_TYPE);
+    assert!(offset_of!(wdm::KEY_VALUE_PARTIAL_INFORMATION, DataLength) == PARTIAL_DATA_LENGTH);
+    assert!(offset_of!(wdm::KEY_VALUE_PARTIAL_INFORMATION, Data) == PARTIAL_DATA);
+    assert!(offset_of!(wdm::KEY_BASIC_INFORMATION, NameLength) == KEY_BASIC_NAME_LENGTH);
+    assert!(offset_of!(wdm::KEY_BASIC_INFORMATION, Name) == KEY_BASIC_NAME);
+    assert!(offset_of!(wdm::KEY_VALUE_BASIC_INFORMATION, Type) == VALUE_BASIC_TYPE);
+    assert!(offset_of!(wdm::KEY_VALUE_BASIC_INFORMATION, NameLength) == VALUE_BASIC_NAME_LENGTH);
+    assert!(offset_of!(wdm::KEY_VALUE_BASIC_INFORMATION, Name) == VALUE_BASIC_NAME);
+};
+
+/// A registry value, decoded by type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegValue {
+    Dword(u32),
+    Qword(u64),
+    Sz(OwnedUnicodeString),
+    /// Unexpanded, `%SystemRoot%` and all.
+    ExpandSz(OwnedUnicodeString),
+    MultiSz(Vec<OwnedUnicodeString>),
+    Binary(Vec<u8>),
+    /// Any other type, e.g. `REG_NONE` or `REG_RESOURCE_LIST`, with its raw data.
+    Other { value_type: u32, data: Vec<u8> },
+}
+
+impl RegValue {
+    /// Decodes `data` of `value_type`. Strings end at their first NUL or, missing one, at the end
+    /// of the data. A `REG_DWORD` or `REG_QWORD` of the wrong size is
+    /// `STATUS_INFO_LENGTH_MISMATCH`.
+    pub fn parse(value_type: u32, data: &[u8]) -> Result<Self, NtStatus> {
+        Ok(match value_type {
+            REG_DWORD => RegValue::Dword(u32::from_le_bytes(data.try_into().map_err(|_| NtStatus::INFO_LENGTH_MISMATCH)?)),
+            REG_QWORD => RegValue::Qword(u64::from_le_bytes(data.try_into().map_err(|_| NtStatus::INFO_LENGTH_MISMATCH)?)),
+            REG_SZ => RegValue::Sz(parse_sz(data)),
+            REG_EXPAND_SZ => RegValue::ExpandSz(parse_sz(data)),
+            REG_MULTI_SZ => RegValue::MultiSz(parse_multi_sz(data)),
+            REG_BINARY => RegValue::Binary(data.to_vec()),
+            _ => RegValue::Other { value_type, data: data.to_vec() },
+        })
+    }
+
+    /// Decodes a `KEY_VALUE_PARTIAL_INFORMATION` from `ZwQueryValueKey`.
+    pub fn from_partial_information(buffer: &[u8]) -> Result<Self, NtStatus> {
+        let (value_type, data) = parse_partial_information(buffer)?;
+        RegValue::parse(value_type, data)
+    }
+
+    pub fn value_type(&self) -> u32 {
+        match self {
+            RegValue::Dword(_) => REG_DWORD,
+            RegValue::Qword(_) => REG_QWORD,
+            RegValue::Sz(_) => REG_SZ,
+            RegValue::ExpandSz(_) => REG_EXPAND_SZ,
+            RegValue::MultiSz(_) => REG_MULTI_SZ,
+            RegValue::Binary(_) => REG_BINARY,
+            RegValue::Other { value_type, .. } => *value_type,
+        }
+    }
+
+    /// The data as `ZwSetValueKey` takes it, strings NUL terminated.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            RegValue::Dword(v) => v.to_le_bytes().to_vec(),
+            RegValue::Qword(v) => v.to_le_bytes().to_vec(),
+            RegValue::Sz(s) | RegValue::ExpandSz(s) => encode_sz(s.as_ustr()),
+            RegValue::MultiSz(strings) => encode_multi_sz(strings.iter().map(|s| s.as_ustr())),
+            RegValue::Binary(data) | RegValue::Other { data, .. } => data.clone(),
+        }
+    }
+}
+
+
+fn units(bytes: &[u8]) -> impl Iterator<Item = u16> + '_ {
+    bytes.chunks_exact(2).map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+}
+
+fn read_u32(buffer: &[u8], offset: usize) -> Result<u32, NtStatus> {
+    buffer.get(offset..offset + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or(NtStatus::INFO_LENGTH_MISMATCH)
+}
+
+// `length` bytes at `offset`, all of which must be in `buffer`
+fn field(buffer: &[u8], offset: usize, length: u32) -> Result<&[u8], NtStatus> {
+    offset.checked_add(length as usize)
+        .and_then(|end| buffer.get(offset..end))
+        .ok_or(NtStatus::INFO_LENGTH_MISMATCH)
+}
+
+fn parse_sz(data: &[u8]) -> OwnedUnicodeString {
+    let units: Vec<u16> = units(data).take_while(|&u| u != 0).collect();
+    OwnedUnicodeString::from_slice(&units)
+}
+
+// the list ends at an empty string, i.e. the second NUL of the terminating pair
+fn parse_multi_sz(data: &[u8]) -> Vec<OwnedUnicodeString> {
+    let units: Vec<u16> = units(data).collect();
+    units.split(|&u| u == 0)
+        .take_while(|s| !s.is_empty())
+        .map(OwnedUnicodeString::from_slice)
+        .collect()
+}
+
+/// `REG_SZ` data: the UTF-16 units and a NUL.
+pub fn encode_sz(s: UnicodeStr<'_>) -> Vec<u8> {
+    let mut data = Vec::with_capacity((s.len() + 1) * 2);
+    data.extend(s.as_slice().iter().flat_map(|u| u.to_le_bytes()));
+    data.extend_from_slice(&[0, 0]);
+    data
+}
+
+/// `REG_MULTI_SZ` data: each string NUL terminated, then one more NUL.
+pub fn encode_multi_sz<'a>(strings: impl IntoIterator<Item = UnicodeStr<'a>>) -> Vec<u8> {
+    let mut data = Vec::new();
+    for s in strings {
+        data.extend(encode_sz(s));
+    }
+    data.extend_from_slice(&[0, 0]);
+    data
+}
+
+/// The type and data of a `KEY_VALUE_PARTIAL_INFORMATION`. `STATUS_INFO_LENGTH_MISMATCH` if the
+/// header or the data it announces runs past `buffer`.
+pub fn parse_partial_information(buffer: &[u8]) -> Result<(u32, &[u8]), NtStatus> {
+    let value_type = read_u32(buffer, PARTIAL_TYPE)?;
+    let length = read_u32(buffer, PARTIAL_DATA_LENGTH)?;
+    Ok((value_type, field(buffer, PARTIAL_DATA, length)?))
+}
+
+/// The subkey name of a `KEY_BASIC_INFORMATION` from `ZwEnumerateKey`.
+pub fn parse_key_basic_information(buffer: &[u8]) -> Result<OwnedUnicodeString, NtStatus> {
+    let length = read_u32(buffer, KEY_BASIC_NAME_LENGTH)?;
+    let name = field(buffer, KEY_BASIC_NAME, length)?;
+    Ok(OwnedUnicodeString::from_slice(&units(name).collect::<Vec<_>>()))
+}
+
+/// The name and type of a `KEY_VALUE_BASIC_INFORMATION` from `ZwEnumerateValueKey`.
+pub fn parse_value_basic_information(buffer: &[u8]) -> Result<(OwnedUnicodeString, u32), NtStatus> {
+    let value_type = read_u32(buffer, VALUE_BASIC_TYPE)?;
+    let length = read_u32(buffer, VALUE_BASIC_NAME_LENGTH)?;
+    let name = field(buffer, VALUE_BASIC_NAME, length)?;
+    Ok((OwnedUnicodeString::from_slice(&units(name).collect::<Vec<_>>()), value_type))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16(s: &str) -> Vec<u8> {
+        s.encode_utf16().flat_map(u16::to_le_bytes).collect()
+    }
+
+    fn partial(value_type: u32, data_length: u32, data: &[u8]) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        buffer.extend(0u32.to_le_bytes());
+        buffer.extend(value_type.to_le_bytes());
+        buffer.extend(data_length.to_le_bytes());
+        buffer.extend_from_slice(data);
+        buffer
+    }
+
+    #[test]
+    fn partial_information() {
+        let buffer = partial(REG_DWORD, 4, &7u32.to_le_bytes());
+        assert_eq!(parse_partial_information(&buffer), Ok((REG_DWORD, &7u32.to_le_bytes()[..])));
+        assert_eq!(RegValue::from_partial_information(&buffer), Ok(RegValue::Dword(7)));
+        // trailing bytes past DataLength are ignored
+        let buffer = partial(REG_BINARY, 2, &[1, 2, 3, 4]);
+        assert_eq!(parse_partial_information(&buffer), Ok((REG_BINARY, &[1u8, 2][..])));
+    }
+
+    #[test]
+    fn partial_information_truncated_header() {
+        let buffer = partial(REG_DWORD, 0, &[]);
+        for len in 0..PARTIAL_DATA {
+            assert_eq!(parse_partial_information(&buffer[..len]), Err(NtStatus::INFO_LENGTH_MISMATCH));
+        }
+        assert_eq!(parse_partial_information(&buffer), Ok((REG_DWORD, &[][..])));
+    }
+
+    #[test]
+    fn partial_information_data_past_end() {
+        let buffer = partial(REG_BINARY, 5, &[1, 2, 3, 4]);
+        assert_eq!(parse_partial_information(&buffer), Err(NtStatus::INFO_LENGTH_MISMATCH));
+        let buffer = partial(REG_BINARY, u32::MAX, &[1, 2, 3, 4]);
+        assert_eq!(parse_partial_information(&buffer), Err(NtStatus::INFO_LENGTH_MISMATCH));
+    }
+
+    #[test]
+    fn dword_and_qword_sizes() {
+        assert_eq!(RegValue::parse(REG_DWORD, &[1, 0, 0, 0]), Ok(RegValue::Dword(1)));
+        assert_eq!(RegValue::parse(REG_QWORD, &[1, 0, 0, 0, 0, 0, 0, 0]), Ok(RegValue::Qword(1)));
+        for data in [&[][..], &[1, 0, 0], &[1, 0, 0, 0, 0], &[1, 0, 0, 0, 0, 0, 0, 0]] {
+            assert_eq!(RegValue::parse(REG_DWORD, data), Err(NtStatus::INFO_LENGTH_MISMATCH));
+        }
+        for data in [&[][..], &[1, 0, 0, 0], &[1, 0, 0, 0, 0, 0, 0], &[1, 0, 0, 0, 0, 0, 0, 0, 0]] {
+            assert_eq!(RegValue::parse(REG_QWORD, data), Err(NtStatus::INFO_LENGTH_MISMATCH));
+        }
+    }
+
+    #[test]
+    fn sz() {
+        let mut data = utf16("abc");
+        assert_eq!(RegValue::parse(REG_SZ, &data), Ok(RegValue::Sz("abc".into())));
+        data.extend_from_slice(&[0, 0]);
+        data.extend(utf16("junk"));
+        assert_eq!(RegValue::parse(REG_EXPAND_SZ, &data), Ok(RegValue::ExpandSz("abc".into())));
+        // an odd trailing byte is not a unit
+        assert_eq!(RegValue::parse(REG_SZ, &[b'a', 0, b'b']), Ok(RegValue::Sz("a".into())));
+    }
+
+    #[test]
+    fn multi_sz_terminators() {
+        let strings = |v: &[&str]| RegValue::MultiSz(v.iter().map(|&s| s.into()).collect());
+        let mut data = utf16("a\0bc\0\0");
+        assert_eq!(RegValue::parse(REG_MULTI_SZ, &data), Ok(strings(&["a", "bc"])));
+        // missing the final NUL, or both
+        data.truncate(data.len() - 2);
+        assert_eq!(RegValue::parse(REG_MULTI_SZ, &data), Ok(strings(&["a", "bc"])));
+        data.truncate(data.len() - 2);
+        assert_eq!(RegValue::parse(REG_MULTI_SZ, &data), Ok(strings(&["a", "bc"])));
+        // the list ends at the first empty string
+        assert_eq!(RegValue::parse(REG_MULTI_SZ, &utf16("a\0\0b\0\0")), Ok(strings(&["a"])));
+        assert_eq!(RegValue::parse(REG_MULTI_SZ, &utf16("\0\0")), Ok(strings(&[])));
+        assert_eq!(RegValue::parse(REG_MULTI_SZ, &[]), Ok(strings(&[])));
+    }
+
+    #[test]
+    fn round_trips() {
+        let values = [
+            RegValue::Dword(0xDEAD_BEEF),
+            RegValue::Qword(u64::MAX - 1),
+            RegValue::Sz("S\u{e9}rvice".into()),
+            RegValue::ExpandSz("%SystemRoot%\\x".into()),
+            RegValue::MultiSz(alloc::vec!["one".into(), "two".into()]),
+            RegValue::Binary(alloc::vec![0, 1, 2]),
+            RegValue::Other { value_type: 0, data: alloc::vec![9] },
+        ];
+        for value in values {
+            assert_eq!(RegValue::parse(value.value_type(), &value.to_bytes()), Ok(value));
+        }
+        assert_eq!(RegValue::Sz("ab".into()).to_bytes(), utf16("ab\0"));
+        assert_eq!(RegValue::MultiSz(alloc::vec!["a".into()]).to_bytes(), utf16("a\0\0"));
+        assert_eq!(RegValue::MultiSz(Vec::new()).to_bytes(), utf16("\0"));
+    }
+
+    #[test]
+    fn basic_information() {
+        let mut buffer = alloc::vec![0u8; KEY_BASIC_NAME_LENGTH];
+        buffer.extend(6u32.to_le_bytes());
+        buffer.extend(utf16("key"));
+        assert_eq!(parse_key_basic_information(&buffer), Ok("key".into()));
+        assert_eq!(parse_key_basic_information(&buffer[..buffer.len() - 1]), Err(NtStatus::INFO_LENGTH_MISMATCH));
+
+        let mut buffer = 0u32.to_le_bytes().to_vec();
+        buffer.extend(REG_SZ.to_le_bytes());
+        buffer.extend(4u32.to_le_bytes());
+        buffer.extend(utf16("vx"));
+        assert_eq!(parse_value_basic_information(&buffer), Ok(("vx".into(), REG_SZ)));
+        assert_eq!(parse_value_basic_information(&buffer[..VALUE_BASIC_NAME]), Err(NtStatus::INFO_LENGTH_MISMATCH));
+    }
+}
@@ -0,0 +1,266 @@
+use alloc::vec::Vec;
+use crate::status::NtStatus;
+
+
+// the DACL-only subset of SDDL that IoCreateDeviceSecure accepts, e.g. "D:P(A;;GA;;;SY)(A;;GRGW;;;BA)"
+
+const SE_DACL_PRESENT: u16 = 0x0004;
+const SE_DACL_PROTECTED: u16 = 0x1000;
+const SE_SELF_RELATIVE: u16 = 0x8000;
+const SD_HEADER_LEN: usize = 20;
+const ACL_HEADER_LEN: usize = 8;
+const ACL_REVISION: u8 = 2;
+
+const ACCESS_ALLOWED_ACE_TYPE: u8 = 0;
+const ACCESS_DENIED_ACE_TYPE: u8 = 1;
+
+const SID_ALIASES: &[(&str, &str)] = &[
+    ("WD", "S-1-1-0"),
+    ("CO", "S-1-3-0"),
+    ("IU", "S-1-5-4"),
+    ("AU", "S-1-5-11"),
+    ("RC", "S-1-5-12"),
+    ("SY", "S-1-5-18"),
+    ("LS", "S-1-5-19"),
+    ("NS", "S-1-5-20"),
+    ("BA", "S-1-5-32-544"),
+    ("BU", "S-1-5-32-545"),
+    ("BG", "S-1-5-32-546"),
+    ("AC", "S-1-15-2-1"),
+];
+
+const RIGHTS: &[(&str, u32)] = &[
+    ("GA", 0x1000_0000),
+    ("GX", 0x2000_0000),
+    ("GW", 0x4000_0000),
+    ("GR", 0x8000_0000),
+    ("SD", 0x0001_0000),
+    ("RC", 0x0002_0000),
+    ("WD", 0x0004_0000),
+    ("WO", 0x0008_0000),
+    ("FA", 0x001F_01FF),
+    ("FR", 0x0012_0089),
+    ("FW", 0x0012_0116),
+    ("FX", 0x0012_00A0),
+];
+
+const ACE_FLAGS: &[(&str, u8)] = &[("OI", 0x01), ("CI", 0x02), ("NP", 0x04), ("IO", 0x08)];
+
+
+/// Binary SID for `S-1-...` or a two-letter alias (`SY`, `BA`, `WD`, ...).
+pub fn parse_sid(s: &str) -> Result<Vec<u8>, NtStatus> {
+    let s = SID_ALIASES.iter().find(|(alias, _)| *alias == s).map_or(s, |(_, sid)| sid);
+    let mut parts = s.strip_prefix("S-1-").ok_or(NtStatus::INVALID_PARAMETER)?.split('-');
+
+    let authority: u64 = parts.next().and_then(|a| a.parse().ok()).ok_or(NtStatus::INVALID_PARAMETER)?;
+    if authority >= 1 << 48 {
+        return Err(NtStatus::INVALID_PARAMETER);
+    }
+    let sub: Vec<u32> = parts.map(|p| p.parse().map_err(|_| NtStatus::INVALID_PARAMETER)).collect::<Result<_, _>>()?;
+    if sub.len() > 15 {
+        return Err(NtStatus::INVALID_PARAMETER);
+    }
+
+    let mut sid = Vec::with_capacity(8 + 4 * sub.len());
+    sid.push(1);
+    sid.push(sub.len() as u8);
+    sid.extend_from_slice(&authority.to_be_bytes()[2..]);
+    for s in sub {
+        sid.extend_from_slice(&s.to_le_bytes());
+    }
+    Ok(sid)
+}
+
+fn parse_rights(s: &str) -> Result<u32, NtStatus> {
+    if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        return u32::from_str_radix(hex, 16).map_err(|_| NtStatus::INVALID_PARAMETER);
+    }
+    if s.is_empty() || !s.len().is_multiple_of(2) || !s.is_ascii() {
+        return Err(NtStatus::INVALID_PARAMETER);
+    }
+    let mut mask = 0;
+    for i in (0..s.len()).step_by(2) {
+        let code = &s[i..i + 2];
+        mask |= RIGHTS.iter().find(|(c, _)| *c == code).ok_or(NtStatus::INVALID_PARAMETER)?.1;
+    }
+    Ok(mask)
+}
+
+fn parse_ace_flags(s: &str) -> Result<u8, NtStatus> {
+    if !s.len().is_multiple_of(2) || !s.is_ascii() {
+        return Err(NtStatus::INVALID_PARAMETER);
+    }
+    let mut flags = 0;
+    for i in (0..s.len()).step_by(2) {
+        let code = &s[i..i + 2];
+        flags |= ACE_FLAGS.iter().find(|(c, _)| *c == code).ok_or(NtStatus::INVALID_PARAMETER)?.1;
+    }
+    Ok(flags)
+}
+
+/// `(type;flags;rights;;;sid)` without the parentheses.
+fn parse_ace(s: &str, acl: &mut Vec<u8>) -> Result<(), NtStatus> {
+    let fields: Vec<&str> = s.split(';').collect();
+    let [ace_type, flags, rights, object, inherit, sid] = fields[..] else {
+        return Err(NtStatus::INVALID_PARAMETER);
+    };
+    let ace_type = match ace_type {
+        "A" => ACCESS_ALLOWED_ACE_TYPE,
+        "D" => ACCESS_DENIED_ACE_TYPE,
+        _ => return Err(NtStatus::NOT_SUPPORTED),
+    };
+    if !object.is_empty() || !inherit.is_empty() {
+        return Err(NtStatus::NOT_SUPPORTED);
+    }
+    let flags = parse_ace_flags(flags)?;
+    let mask = parse_rights(rights)?;
+    let sid = parse_sid(sid)?;
+
+    let size = 8 + sid.len();
+    acl.push(ace_type);
+    acl.push(flags);
+    acl.extend_from_slice(&(size as u16).to_le_bytes());
+    acl.extend_from_slice(&mask.to_le_bytes());
+    acl.extend_from_slice(&sid);
+    Ok(())
+}
+
+/// Builds a self-relative security descriptor holding only a DACL from an SDDL string such as
+/// `D:P(A;;GA;;;SY)(A;;GA;;;BA)`. Owner, group and SACL sections and object ACEs are rejected
+/// with `STATUS_NOT_SUPPORTED`, as `IoCreateDeviceSecure` does.
+pub fn parse(sddl: &str) -> Result<Vec<u8>, NtStatus> {
+    let sddl: alloc::string::String = sddl.chars().filter(|c| !c.is_whitespace()).collect();
+    let body = sddl.strip_prefix("D:").ok_or(NtStatus::NOT_SUPPORTED)?;
+    let (protected, mut rest) = match body.strip_prefix('P') {
+        Some(rest) => (true, rest),
+        None => (false, body),
+    };
+
+    let mut aces = Vec::new();
+    let mut count: u16 = 0;
+    while !rest.is_empty() {
+        let inner = rest.strip_prefix('(').ok_or(NtStatus::INVALID_PARAMETER)?;
+        let end = inner.find(')').ok_or(NtStatus::INVALID_PARAMETER)?;
+        parse_ace(&inner[..end], &mut aces)?;
+        count = count.checked_add(1).ok_or(NtStatus::INVALID_PARAMETER)?;
+        rest = &inner[end + 1..];
+    }
+
+    let acl_len = ACL_HEADER_LEN + aces.len();
+    if acl_len > u16::MAX as usize {
+        return Err(NtStatus::INVALID_PARAMETER);
+    }
+
+    let mut control = SE_DACL_PRESENT | SE_SELF_RELATIVE;
+    if protected {
+        control |= SE_DACL_PROTECTED;
+    }
+    let mut sd = Vec::with_capacity(SD_HEADER_LEN + acl_len);
+    sd.push(1);
+    sd.push(0);
+    sd.extend_from_slice(&control.to_le_bytes());
+    sd.extend_from_slice(&0u32.to_le_bytes()); // owner
+    sd.extend_from_slice(&0u32.to_le_bytes()); // group
+    sd.extend_from_slice(&0u32.to_le_bytes()); // sacl
+    sd.extend_from_slice(&(SD_HEADER_LEN as u32).to_le_bytes());
+
+    sd.push(ACL_REVISION);
+    sd.push(0);
+    sd.extend_from_slice(&(acl_len as u16).to_le_bytes());
+    sd.extend_from_slice(&count.to_le_bytes());
+    sd.extend_from_slice(&0u16.to_le_bytes());
+    sd.extend_from_slice(&aces);
+    Ok(sd)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // self-relative layout as ConvertStringSecurityDescriptorToSecurityDescriptorW builds it: header, then the DACL at offset 20
+
+    #[test]
+    fn sids() {
+        assert_eq!(parse_sid("SY").unwrap(), [1, 1, 0, 0, 0, 0, 0, 5, 18, 0, 0, 0]);
+        assert_eq!(parse_sid("WD").unwrap(), [1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0]);
+        assert_eq!(parse_sid("BA").unwrap(), [1, 2, 0, 0, 0, 0, 0, 5, 32, 0, 0, 0, 0x20, 0x02, 0, 0]);
+        assert_eq!(parse_sid("S-1-5-21-1-2-3-500").unwrap(), [
+            1, 5, 0, 0, 0, 0, 0, 5,
+            21, 0, 0, 0,
+            1, 0, 0, 0,
+            2, 0, 0, 0,
+            3, 0, 0, 0,
+            0xF4, 0x01, 0, 0,
+        ]);
+        // identifier authorities are 48-bit big-endian
+        assert_eq!(parse_sid("S-1-281474976710655").unwrap(), [1, 0, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn bad_sids() {
+        assert_eq!(parse_sid("S-1-281474976710656"), Err(NtStatus::INVALID_PARAMETER));
+        assert_eq!(parse_sid("S-2-5-18"), Err(NtStatus::INVALID_PARAMETER));
+        assert_eq!(parse_sid("S-1-5-x"), Err(NtStatus::INVALID_PARAMETER));
+        assert_eq!(parse_sid("XX"), Err(NtStatus::INVALID_PARAMETER));
+        assert_eq!(parse_sid("S-1-5-1-2-3-4-5-6-7-8-9-10-11-12-13-14-15-16"), Err(NtStatus::INVALID_PARAMETER));
+    }
+
+    #[test]
+    fn protected_dacl() {
+        let expected = [
+            1, 0, 0x04, 0x90,             // revision, sbz1, control: self-relative, protected, DACL present
+            0, 0, 0, 0,                   // owner
+            0, 0, 0, 0,                   // group
+            0, 0, 0, 0,                   // sacl
+            20, 0, 0, 0,                  // dacl
+            2, 0, 52, 0, 2, 0, 0, 0,      // ACL header: revision, size, ACE count
+            0, 0, 20, 0, 0, 0, 0, 0x10,   // allow, GENERIC_ALL
+            1, 1, 0, 0, 0, 0, 0, 5, 18, 0, 0, 0,
+            0, 0, 24, 0, 0, 0, 0, 0x10,
+            1, 2, 0, 0, 0, 0, 0, 5, 32, 0, 0, 0, 0x20, 0x02, 0, 0,
+        ];
+        assert_eq!(parse("D:P(A;;GA;;;SY)(A;;GA;;;BA)").unwrap(), expected);
+        assert_eq!(parse(" D:P (A;;GA;;;SY)\n(A;;GA;;;BA) ").unwrap(), expected);
+    }
+
+    #[test]
+    fn flags_and_masks() {
+        let expected = [
+            1, 0, 0x04, 0x80,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            20, 0, 0, 0,
+            2, 0, 48, 0, 2, 0, 0, 0,
+            1, 0x03, 20, 0, 0xFF, 0x01, 0x1F, 0,           // deny, OI|CI, FILE_ALL_ACCESS
+            1, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0,
+            0, 0, 20, 0, 0x89, 0, 0x12, 0xC0,              // GR|GW|FR
+            1, 1, 0, 0, 0, 0, 0, 5, 11, 0, 0, 0,
+        ];
+        assert_eq!(parse("D:(D;OICI;0x1F01FF;;;WD)(A;;GRGWFR;;;AU)").unwrap(), expected);
+    }
+
+    #[test]
+    fn empty_dacl() {
+        assert_eq!(parse("D:P").unwrap(), [
+            1, 0, 0x04, 0x90,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            0, 0, 0, 0,
+            20, 0, 0, 0,
+            2, 0, 8, 0, 0, 0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn unsupported() {
+        assert_eq!(parse("O:SYD:(A;;GA;;;SY)"), Err(NtStatus::NOT_SUPPORTED));
+        assert_eq!(parse("D:(OA;;GA;;;SY)"), Err(NtStatus::NOT_SUPPORTED));
+        assert_eq!(parse("D:(A;;GA;obj;;SY)"), Err(NtStatus::NOT_SUPPORTED));
+        assert_eq!(parse("D:(A;;GA;;;SY"), Err(NtStatus::INVALID_PARAMETER));
+        assert_eq!(parse("D:(A;;XY;;;SY)"), Err(NtStatus::INVALID_PARAMETER));
+        assert_eq!(parse("D:(A;ZZ;GA;;;SY)"), Err(NtStatus::INVALID_PARAMETER));
+        assert_eq!(parse("D:(A;;GA;;SY)"), Err(NtStatus::INVALID_PARAMETER));
+    }
+}
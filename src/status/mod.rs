@@ -0,0 +1,496 @@
+use core::fmt;
+#[cfg(windows)]
+use crate::NTSTATUS;
+use table::NTSTATUS_TABLE;
+
+mod table;
+
+// the bindings' `NTSTATUS`, a 32-bit `LONG`
+#[cfg(not(windows))]
+#[allow(clippy::upper_case_acronyms)]
+type NTSTATUS = i32;
+
+
+pub const ERROR_SEVERITY_SUCCESS: u32       = 0x00000000;
+pub const ERROR_SEVERITY_INFORMATIONAL: u32 = 0x40000000;
+pub const ERROR_SEVERITY_WARNING: u32       = 0x80000000;
+pub const ERROR_SEVERITY_ERROR: u32         = 0xC0000000;
+pub const FACILITY_NT_BIT: u32              = 0x10000000;
+
+pub const FACILITY_DEBUGGER: u16          = 0x1;
+pub const FACILITY_RPC_RUNTIME: u16       = 0x2;
+pub const FACILITY_RPC_STUBS: u16         = 0x3;
+pub const FACILITY_IO_ERROR_CODE: u16     = 0x4;
+pub const FACILITY_NTWIN32: u16           = 0x7;
+pub const FACILITY_NTCERT: u16            = 0x8;
+pub const FACILITY_NTSSPI: u16            = 0x9;
+pub const FACILITY_TERMINAL_SERVER: u16   = 0xA;
+pub const FACILITY_MUI_ERROR_CODE: u16    = 0xB;
+pub const FACILITY_USB_ERROR_CODE: u16    = 0x10;
+pub const FACILITY_HID_ERROR_CODE: u16    = 0x11;
+pub const FACILITY_FIREWIRE_ERROR_CODE: u16 = 0x12;
+pub const FACILITY_CLUSTER_ERROR_CODE: u16 = 0x13;
+pub const FACILITY_ACPI_ERROR_CODE: u16   = 0x14;
+pub const FACILITY_SXS_ERROR_CODE: u16    = 0x15;
+pub const FACILITY_TRANSACTION: u16       = 0x19;
+pub const FACILITY_COMMONLOG: u16         = 0x1A;
+pub const FACILITY_VIDEO: u16             = 0x1B;
+pub const FACILITY_FILTER_MANAGER: u16    = 0x1C;
+pub const FACILITY_MONITOR: u16           = 0x1D;
+pub const FACILITY_GRAPHICS_KERNEL: u16   = 0x1E;
+pub const FACILITY_DRIVER_FRAMEWORK: u16  = 0x20;
+pub const FACILITY_FVE_ERROR_CODE: u16    = 0x21;
+pub const FACILITY_FWP_ERROR_CODE: u16    = 0x22;
+pub const FACILITY_NDIS_ERROR_CODE: u16   = 0x23;
+pub const FACILITY_QUIC_ERROR_CODE: u16   = 0x24;
+pub const FACILITY_TPM: u16               = 0x29;
+pub const FACILITY_RTPM: u16              = 0x2A;
+pub const FACILITY_HYPERVISOR: u16        = 0x35;
+pub const FACILITY_IPSEC: u16             = 0x36;
+pub const FACILITY_VIRTUALIZATION: u16    = 0x37;
+pub const FACILITY_VOLMGR: u16            = 0x38;
+pub const FACILITY_BCD_ERROR_CODE: u16    = 0x39;
+pub const FACILITY_VIRTDISK: u16          = 0x3A;
+pub const FACILITY_WIN32K_NTUSER: u16     = 0x3E;
+pub const FACILITY_WIN32K_NTGDI: u16      = 0x3F;
+pub const FACILITY_RESUME_KEY_FILTER: u16 = 0x40;
+pub const FACILITY_RDBSS: u16             = 0x41;
+pub const FACILITY_BTH_ATT: u16           = 0x42;
+pub const FACILITY_SECUREBOOT: u16        = 0x43;
+pub const FACILITY_AUDIO_KERNEL: u16      = 0x44;
+pub const FACILITY_VSM: u16               = 0x45;
+pub const FACILITY_NT_IORING: u16         = 0x46;
+pub const FACILITY_VOLSNAP: u16           = 0x50;
+pub const FACILITY_SDBUS: u16             = 0x51;
+pub const FACILITY_SHARED_VHDX: u16       = 0x5C;
+pub const FACILITY_SMB: u16               = 0x5D;
+pub const FACILITY_INTERIX: u16           = 0x99;
+pub const FACILITY_SPACES: u16            = 0xE7;
+pub const FACILITY_SECURITY_CORE: u16     = 0xE8;
+pub const FACILITY_SYSTEM_INTEGRITY: u16  = 0xE9;
+pub const FACILITY_LICENSING: u16         = 0xEA;
+pub const FACILITY_PLATFORM_MANIFEST: u16 = 0xEB;
+pub const FACILITY_APP_EXEC: u16          = 0xEC;
+
+
+pub const fn facility_name(facility: u16) -> Option<&'static str> {
+    Some(match facility {
+        0 => "FACILITY_NONE",
+        FACILITY_DEBUGGER => "FACILITY_DEBUGGER",
+        FACILITY_RPC_RUNTIME => "FACILITY_RPC_RUNTIME",
+        FACILITY_RPC_STUBS => "FACILITY_RPC_STUBS",
+        FACILITY_IO_ERROR_CODE => "FACILITY_IO_ERROR_CODE",
+        FACILITY_NTWIN32 => "FACILITY_NTWIN32",
+        FACILITY_NTCERT => "FACILITY_NTCERT",
+        FACILITY_NTSSPI => "FACILITY_NTSSPI",
+        FACILITY_TERMINAL_SERVER => "FACILITY_TERMINAL_SERVER",
+        FACILITY_MUI_ERROR_CODE => "FACILITY_MUI_ERROR_CODE",
+        FACILITY_USB_ERROR_CODE => "FACILITY_USB_ERROR_CODE",
+        FACILITY_HID_ERROR_CODE => "FACILITY_HID_ERROR_CODE",
+        FACILITY_FIREWIRE_ERROR_CODE => "FACILITY_FIREWIRE_ERROR_CODE",
+        FACILITY_CLUSTER_ERROR_CODE => "FACILITY_CLUSTER_ERROR_CODE",
+        FACILITY_ACPI_ERROR_CODE => "FACILITY_ACPI_ERROR_CODE",
+        FACILITY_SXS_ERROR_CODE => "FACILITY_SXS_ERROR_CODE",
+        FACILITY_TRANSACTION => "FACILITY_TRANSACTION",
+        FACILITY_COMMONLOG => "FACILITY_COMMONLOG",
+        FACILITY_VIDEO => "FACILITY_VIDEO",
+        FACILITY_FILTER_MANAGER => "FACILITY_FILTER_MANAGER",
+        FACILITY_MONITOR => "FACILITY_MONITOR",
+        FACILITY_GRAPHICS_KERNEL => "FACILITY_GRAPHICS_KERNEL",
+        FACILITY_DRIVER_FRAMEWORK => "FACILITY_DRIVER_FRAMEWORK",
+        FACILITY_FVE_ERROR_CODE => "FACILITY_FVE_ERROR_CODE",
+        FACILITY_FWP_ERROR_CODE => "FACILITY_FWP_ERROR_CODE",
+        FACILITY_NDIS_ERROR_CODE => "FACILITY_NDIS_ERROR_CODE",
+        FACILITY_QUIC_ERROR_CODE => "FACILITY_QUIC_ERROR_CODE",
+        FACILITY_TPM => "FACILITY_TPM",
+        FACILITY_RTPM => "FACILITY_RTPM",
+        FACILITY_HYPERVISOR => "FACILITY_HYPERVISOR",
+        FACILITY_IPSEC => "FACILITY_IPSEC",
+        FACILITY_VIRTUALIZATION => "FACILITY_VIRTUALIZATION",
+        FACILITY_VOLMGR => "FACILITY_VOLMGR",
+        FACILITY_BCD_ERROR_CODE => "FACILITY_BCD_ERROR_CODE",
+        FACILITY_VIRTDISK => "FACILITY_VIRTDISK",
+        FACILITY_WIN32K_NTUSER => "FACILITY_WIN32K_NTUSER",
+        FACILITY_WIN32K_NTGDI => "FACILITY_WIN32K_NTGDI",
+        FACILITY_RESUME_KEY_FILTER => "FACILITY_RESUME_KEY_FILTER",
+        FACILITY_RDBSS => "FACILITY_RDBSS",
+        FACILITY_BTH_ATT => "FACILITY_BTH_ATT",
+        FACILITY_SECUREBOOT => "FACILITY_SECUREBOOT",
+        FACILITY_AUDIO_KERNEL => "FACILITY_AUDIO_KERNEL",
+        FACILITY_VSM => "FACILITY_VSM",
+        FACILITY_NT_IORING => "FACILITY_NT_IORING",
+        FACILITY_VOLSNAP => "FACILITY_VOLSNAP",
+        FACILITY_SDBUS => "FACILITY_SDBUS",
+        FACILITY_SHARED_VHDX => "FACILITY_SHARED_VHDX",
+        FACILITY_SMB => "FACILITY_SMB",
+        FACILITY_INTERIX => "FACILITY_INTERIX",
+        FACILITY_SPACES => "FACILITY_SPACES",
+        FACILITY_SECURITY_CORE => "FACILITY_SECURITY_CORE",
+        FACILITY_SYSTEM_INTEGRITY => "FACILITY_SYSTEM_INTEGRITY",
+        FACILITY_LICENSING => "FACILITY_LICENSING",
+        FACILITY_PLATFORM_MANIFEST => "FACILITY_PLATFORM_MANIFEST",
+        FACILITY_APP_EXEC => "FACILITY_APP_EXEC",
+        _ => return None,
+    })
+}
+
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Severity {
+    Success,
+    Informational,
+    Warning,
+    Error,
+}
+
+
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NtStatus(NTSTATUS);
+
+impl NtStatus {
+    pub const SUCCESS: NtStatus                = NtStatus(0x00000000);
+    pub const ABANDONED: NtStatus              = NtStatus(0x00000080);
+    pub const USER_APC: NtStatus               = NtStatus(0x000000C0);
+    pub const ALERTED: NtStatus                = NtStatus(0x00000101);
+    pub const TIMEOUT: NtStatus                = NtStatus(0x00000102);
+    pub const PENDING: NtStatus                = NtStatus(0x00000103);
+    pub const BUFFER_OVERFLOW: NtStatus        = NtStatus(0x80000005u32 as i32);
+    pub const PARTIAL_COPY: NtStatus           = NtStatus(0x8000000Du32 as i32);
+    pub const NO_MORE_ENTRIES: NtStatus        = NtStatus(0x8000001Au32 as i32);
+    pub const UNSUCCESSFUL: NtStatus           = NtStatus(0xC0000001u32 as i32);
+    pub const NOT_IMPLEMENTED: NtStatus        = NtStatus(0xC0000002u32 as i32);
+    pub const INFO_LENGTH_MISMATCH: NtStatus   = NtStatus(0xC0000004u32 as i32);
+    pub const ACCESS_VIOLATION: NtStatus       = NtStatus(0xC0000005u32 as i32);
+    pub const INVALID_HANDLE: NtStatus         = NtStatus(0xC0000008u32 as i32);
+    pub const INVALID_PARAMETER: NtStatus      = NtStatus(0xC000000Du32 as i32);
+    pub const INVALID_DEVICE_REQUEST: NtStatus = NtStatus(0xC0000010u32 as i32);
+    pub const MORE_PROCESSING_REQUIRED: NtStatus = NtStatus(0xC0000016u32 as i32);
+    pub const NO_MEMORY: NtStatus              = NtStatus(0xC0000017u32 as i32);
+    pub const ACCESS_DENIED: NtStatus          = NtStatus(0xC0000022u32 as i32);
+    pub const BUFFER_TOO_SMALL: NtStatus       = NtStatus(0xC0000023u32 as i32);
+    pub const OBJECT_TYPE_MISMATCH: NtStatus   = NtStatus(0xC0000024u32 as i32);
+    pub const OBJECT_NAME_INVALID: NtStatus    = NtStatus(0xC0000033u32 as i32);
+    pub const OBJECT_NAME_NOT_FOUND: NtStatus  = NtStatus(0xC0000034u32 as i32);
+    pub const OBJECT_NAME_COLLISION: NtStatus  = NtStatus(0xC0000035u32 as i32);
+    pub const SEMAPHORE_LIMIT_EXCEEDED: NtStatus = NtStatus(0xC0000047u32 as i32);
+    pub const INSUFFICIENT_RESOURCES: NtStatus = NtStatus(0xC000009Au32 as i32);
+    pub const IO_TIMEOUT: NtStatus             = NtStatus(0xC00000B5u32 as i32);
+    pub const NOT_SUPPORTED: NtStatus          = NtStatus(0xC00000BBu32 as i32);
+    pub const INTERNAL_ERROR: NtStatus         = NtStatus(0xC00000E5u32 as i32);
+    pub const INVALID_USER_BUFFER: NtStatus    = NtStatus(0xC00000E8u32 as i32);
+    pub const NAME_TOO_LONG: NtStatus          = NtStatus(0xC0000106u32 as i32);
+    pub const CANCELLED: NtStatus              = NtStatus(0xC0000120u32 as i32);
+    pub const DATATYPE_MISALIGNMENT_ERROR: NtStatus = NtStatus(0xC00002C5u32 as i32);
+    pub const NOT_FOUND: NtStatus              = NtStatus(0xC0000225u32 as i32);
+    pub const CALLBACK_BYPASS: NtStatus        = NtStatus(0xC0000503u32 as i32);
+
+    #[inline(always)]
+    pub const fn from_raw(raw: NTSTATUS) -> Self {
+        NtStatus(raw)
+    }
+
+    #[inline(always)]
+    pub const fn raw(self) -> NTSTATUS {
+        self.0
+    }
+
+    /// `Ok(())` when `NT_SUCCESS` holds, i.e. for success and informational codes. Warnings such as
+    /// `STATUS_BUFFER_OVERFLOW` or `STATUS_NO_MORE_ENTRIES` are `Err(self)` like errors; callers that
+    /// want to treat one as success must match it before using `?`.
+    #[inline(always)]
+    pub const fn into_result(self) -> Result<(), NtStatus> {
+        if self.is_success() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Shorthand for `NtStatus::from_raw(status).into_result()`, meant to be used with `?`.
+    #[inline(always)]
+    pub const fn check(status: NTSTATUS) -> Result<(), NtStatus> {
+        NtStatus(status).into_result()
+    }
+
+    #[inline(always)]
+    pub const fn is_success(self) -> bool {
+        self.0 >= 0
+    }
+
+    #[inline(always)]
+    pub const fn is_error(self) -> bool {
+        matches!(self.severity(), Severity::Error)
+    }
+
+    #[inline(always)]
+    pub const fn is_warning(self) -> bool {
+        matches!(self.severity(), Severity::Warning)
+    }
+
+    #[inline(always)]
+    pub const fn is_information(self) -> bool {
+        matches!(self.severity(), Severity::Informational)
+    }
+
+    pub const fn severity(self) -> Severity {
+        match (self.0 as u32) >> 30 {
+            0 => Severity::Success,
+            1 => Severity::Informational,
+            2 => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    /// Customer-defined codes have bit 29 set.
+    #[inline(always)]
+    pub const fn is_customer(self) -> bool {
+        (self.0 as u32) & 0x2000_0000 != 0
+    }
+
+    #[inline(always)]
+    pub const fn facility(self) -> u16 {
+        (((self.0 as u32) >> 16) & 0x0FFF) as u16
+    }
+
+    #[inline(always)]
+    pub const fn code(self) -> u16 {
+        (self.0 as u32 & 0xFFFF) as u16
+    }
+
+    /// Symbolic name from ntstatus.h, e.g. `STATUS_ACCESS_VIOLATION`.
+    pub const fn name(self) -> Option<&'static str> {
+        match self.entry() {
+            Some((_, name, _)) => Some(name),
+            None => None,
+        }
+    }
+
+    // the canonical table entry of this value
+    const fn entry(self) -> Option<(u32, &'static str, &'static str)> {
+        let raw = self.0 as u32;
+        let mut lo = 0usize;
+        let mut hi = NTSTATUS_TABLE.len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if NTSTATUS_TABLE[mid].0 < raw {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        if lo < NTSTATUS_TABLE.len() && NTSTATUS_TABLE[lo].0 == raw {
+            Some(NTSTATUS_TABLE[lo])
+        } else {
+            None
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<NtStatus> {
+        NTSTATUS_TABLE.iter().find(|(_, n, _)| *n == name).map(|(v, _, _)| NtStatus(*v as i32))
+    }
+
+    /// The MessageText of ntstatus.h, e.g. "{Buffer Overflow}\nThe data was too large to fit into
+    /// the specified buffer." for `STATUS_BUFFER_OVERFLOW`. Lines are separated by `\n` and format
+    /// inserts such as `%hs` are left as they are. Codes tools/ntstatus.txt has no MessageText
+    /// for get one spelled out from the name, e.g. "Device not ready." for
+    /// `STATUS_DEVICE_NOT_READY`. `None` for unknown codes.
+    pub const fn message(self) -> Option<&'static str> {
+        match self.entry() {
+            Some((_, _, message)) => Some(message),
+            None => None,
+        }
+    }
+
+    pub const fn facility_name(self) -> Option<&'static str> {
+        facility_name(self.facility())
+    }
+
+    /// `NTSTATUS_FROM_WIN32`: wraps a Win32 error code into `FACILITY_NTWIN32` with warning
+    /// severity, e.g. `ERROR_ACCESS_DENIED` (5) becomes 0x80070005.
+    pub const fn from_win32(error: u32) -> Self {
+        if error as i32 <= 0 {
+            NtStatus(error as i32)
+        } else {
+            NtStatus(((error & 0xFFFF) | ((FACILITY_NTWIN32 as u32) << 16) | ERROR_SEVERITY_WARNING) as i32)
+        }
+    }
+
+    /// The Win32 error code carried by a `FACILITY_NTWIN32` status.
+    pub const fn win32_error(self) -> Option<u32> {
+        if self.facility() == FACILITY_NTWIN32 && !self.is_customer() {
+            Some(self.code() as u32)
+        } else {
+            None
+        }
+    }
+
+    /// `HRESULT_FROM_NT`: sets `FACILITY_NT_BIT` so the status can travel as an HRESULT.
+    pub const fn to_hresult(self) -> i32 {
+        ((self.0 as u32) | FACILITY_NT_BIT) as i32
+    }
+
+    /// Recovers the status from an HRESULT produced by `HRESULT_FROM_NT`.
+    pub const fn from_hresult(hr: i32) -> Option<NtStatus> {
+        if (hr as u32) & FACILITY_NT_BIT != 0 {
+            Some(NtStatus(((hr as u32) & !FACILITY_NT_BIT) as i32))
+        } else {
+            None
+        }
+    }
+}
+
+impl From<NTSTATUS> for NtStatus {
+    #[inline(always)]
+    fn from(raw: NTSTATUS) -> Self {
+        NtStatus(raw)
+    }
+}
+
+impl From<NtStatus> for NTSTATUS {
+    #[inline(always)]
+    fn from(status: NtStatus) -> Self {
+        status.0
+    }
+}
+
+impl<T> From<Result<T, NtStatus>> for NtStatus {
+    fn from(result: Result<T, NtStatus>) -> Self {
+        match result {
+            Ok(_) => NtStatus::SUCCESS,
+            Err(e) => e,
+        }
+    }
+}
+
+impl fmt::Debug for NtStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "NtStatus({})", name),
+            None => write!(f, "NtStatus({:#010X})", self.0 as u32),
+        }
+    }
+}
+
+impl fmt::Display for NtStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => {
+                write!(f, "{} ({:#010X})", name, self.0 as u32)?;
+                if let Some(message) = self.message() {
+                    f.write_str(":")?;
+                    for line in message.lines() {
+                        write!(f, " {}", line)?;
+                    }
+                }
+                Ok(())
+            }
+            None => match self.facility_name() {
+                Some(facility) => write!(f, "{:#010X} ({:?}, {}, code {:#06X})", self.0 as u32, self.severity(), facility, self.code()),
+                None => write!(f, "{:#010X}", self.0 as u32),
+            },
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn table_is_sorted() {
+        assert!(NTSTATUS_TABLE.windows(2).all(|w| w[0].0 <= w[1].0));
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(NtStatus::SUCCESS.name(), Some("STATUS_SUCCESS"));
+        assert_eq!(NtStatus::ACCESS_VIOLATION.name(), Some("STATUS_ACCESS_VIOLATION"));
+        assert_eq!(NtStatus::BUFFER_OVERFLOW.name(), Some("STATUS_BUFFER_OVERFLOW"));
+        // aliases never win over the common name
+        assert_eq!(NtStatus::ABANDONED.name(), Some("STATUS_ABANDONED"));
+        assert_eq!(NtStatus::from_raw(0xC0FF_EEEEu32 as i32).name(), None);
+
+        assert_eq!(NtStatus::from_name("STATUS_ACCESS_DENIED"), Some(NtStatus::ACCESS_DENIED));
+        assert_eq!(NtStatus::from_name("STATUS_WAIT_0"), Some(NtStatus::SUCCESS));
+        assert_eq!(NtStatus::from_name("STATUS_NO_SUCH_THING"), None);
+        for &(value, name, _) in NTSTATUS_TABLE.iter().step_by(97) {
+            assert_eq!(NtStatus::from_name(name), Some(NtStatus::from_raw(value as i32)));
+        }
+    }
+
+    #[test]
+    fn messages() {
+        assert_eq!(NtStatus::INVALID_PARAMETER.message(), Some("An invalid parameter was passed to a service or function."));
+        assert_eq!(NtStatus::BUFFER_OVERFLOW.message(),
+                   Some("{Buffer Overflow}\nThe data was too large to fit into the specified buffer."));
+        assert_eq!(NtStatus::from_raw(0xC0FF_EEEEu32 as i32).message(), None);
+        assert_eq!(format!("{}", NtStatus::NOT_FOUND), "STATUS_NOT_FOUND (0xC0000225): The object was not found.");
+        assert_eq!(format!("{}", NtStatus::UNSUCCESSFUL),
+                   "STATUS_UNSUCCESSFUL (0xC0000001): {Operation Failed} The requested operation was unsuccessful.");
+        assert_eq!(format!("{:?}", NtStatus::PENDING), "NtStatus(STATUS_PENDING)");
+    }
+
+    #[test]
+    fn every_code_has_a_message() {
+        for &(value, name, message) in NTSTATUS_TABLE.iter() {
+            assert!(!message.is_empty(), "{name}");
+            assert_eq!(NtStatus::from_raw(value as i32).message().map(str::is_empty), Some(false), "{name}");
+        }
+        assert_eq!(NtStatus::from_name("STATUS_DEVICE_NOT_READY").and_then(NtStatus::message), Some("Device not ready."));
+        assert_eq!(NtStatus::from_name("STATUS_FWP_CALLOUT_NOT_FOUND").and_then(NtStatus::message),
+                   Some("FWP callout not found."));
+    }
+
+    #[test]
+    fn severity_and_facility() {
+        assert_eq!(NtStatus::SUCCESS.severity(), Severity::Success);
+        assert_eq!(NtStatus::from_raw(0x4000_0000).severity(), Severity::Informational);
+        assert_eq!(NtStatus::BUFFER_OVERFLOW.severity(), Severity::Warning);
+        assert_eq!(NtStatus::ACCESS_DENIED.severity(), Severity::Error);
+        assert!(NtStatus::PENDING.is_success() && !NtStatus::PENDING.is_error());
+        assert!(NtStatus::BUFFER_OVERFLOW.is_warning() && !NtStatus::BUFFER_OVERFLOW.is_success());
+        assert_eq!(NtStatus::BUFFER_OVERFLOW.into_result(), Err(NtStatus::BUFFER_OVERFLOW));
+        assert_eq!(NtStatus::check(0x103), Ok(()));
+
+        // STATUS_FWP_CALLOUT_NOT_FOUND
+        let status = NtStatus::from_raw(0xC022_0001u32 as i32);
+        assert_eq!((status.facility(), status.code()), (FACILITY_FWP_ERROR_CODE, 1));
+        assert_eq!(status.facility_name(), Some("FACILITY_FWP_ERROR_CODE"));
+        assert!(!status.is_customer());
+        assert!(NtStatus::from_raw(0xE000_0001u32 as i32).is_customer());
+        assert_eq!(NtStatus::from_raw(0xC123_0000u32 as i32).facility_name(), None);
+        assert_eq!(format!("{}", NtStatus::from_raw(0xC022_FFFFu32 as i32)),
+                   "0xC022FFFF (Error, FACILITY_FWP_ERROR_CODE, code 0xFFFF)");
+    }
+
+    #[test]
+    fn win32_round_trip() {
+        // ERROR_ACCESS_DENIED
+        let status = NtStatus::from_win32(5);
+        assert_eq!(status.raw() as u32, 0x8007_0005);
+        assert_eq!(status.severity(), Severity::Warning);
+        assert_eq!(status.facility_name(), Some("FACILITY_NTWIN32"));
+        assert_eq!(status.win32_error(), Some(5));
+        for error in [1u32, 87, 1223, 0xFFFF] {
+            assert_eq!(NtStatus::from_win32(error).win32_error(), Some(error));
+        }
+        // ERROR_SUCCESS and values that already are statuses pass through
+        assert_eq!(NtStatus::from_win32(0), NtStatus::SUCCESS);
+        assert_eq!(NtStatus::from_win32(0xC000_0005), NtStatus::ACCESS_VIOLATION);
+        assert_eq!(NtStatus::ACCESS_VIOLATION.win32_error(), None);
+    }
+
+    #[test]
+    fn hresult_round_trip() {
+        assert_eq!(NtStatus::ACCESS_VIOLATION.to_hresult() as u32, 0xD000_0005);
+        assert_eq!(NtStatus::BUFFER_OVERFLOW.to_hresult() as u32, 0x9000_0005);
+        for status in [NtStatus::SUCCESS, NtStatus::PENDING, NtStatus::BUFFER_OVERFLOW, NtStatus::CALLBACK_BYPASS, NtStatus::from_win32(5)] {
+            assert_eq!(NtStatus::from_hresult(status.to_hresult()), Some(status));
+        }
+        // E_ACCESSDENIED is HRESULT_FROM_WIN32, not HRESULT_FROM_NT
+        assert_eq!(NtStatus::from_hresult(0x8007_0005u32 as i32), None);
+    }
+}
@@ -0,0 +1,2839 @@
+/* automatically generated by tools/gen_ntstatus.py, do not edit */
+
+/// `(value, name, message)` sorted by value; the first entry of a value is its canonical name.
+/// `message` is the ntstatus.h MessageText, or spelled out from the name where the snapshot has none.
+pub(crate) static NTSTATUS_TABLE: [(u32, &str, &str); 2833] = [
+    (0x00000000, "STATUS_SUCCESS", "Success."),
+    (0x00000000, "STATUS_WAIT_0", "Wait 0."),
+    (0x00000001, "STATUS_WAIT_1", "Wait 1."),
+    (0x00000002, "STATUS_WAIT_2", "Wait 2."),
+    (0x00000003, "STATUS_WAIT_3", "Wait 3."),
+    (0x0000003F, "STATUS_WAIT_63", "Wait 63."),
+    (0x00000080, "STATUS_ABANDONED", "Abandoned."),
+    (0x00000080, "STATUS_ABANDONED_WAIT_0", "Abandoned wait 0."),
+    (0x000000BF, "STATUS_ABANDONED_WAIT_63", "Abandoned wait 63."),
+    (0x000000C0, "STATUS_USER_APC", "A user-mode APC was delivered before the given Interval expired."),
+    (0x000000FF, "STATUS_ALREADY_COMPLETE", "Already complete."),
+    (0x00000100, "STATUS_KERNEL_APC", "Kernel apc."),
+    (0x00000101, "STATUS_ALERTED", "The delay completed because the thread was alerted."),
+    (0x00000102, "STATUS_TIMEOUT", "Timeout."),
+    (0x00000103, "STATUS_PENDING", "The operation that was requested is pending completion."),
+    (0x00000104, "STATUS_REPARSE", "Reparse."),
+    (0x00000105, "STATUS_MORE_ENTRIES", "More entries."),
+    (0x00000106, "STATUS_NOT_ALL_ASSIGNED", "Not all assigned."),
+    (0x00000107, "STATUS_SOME_NOT_MAPPED", "Some not mapped."),
+    (0x00000108, "STATUS_OPLOCK_BREAK_IN_PROGRESS", "Oplock break in progress."),
+    (0x00000109, "STATUS_VOLUME_MOUNTED", "Volume mounted."),
+    (0x0000010A, "STATUS_RXACT_COMMITTED", "RXACT committed."),
+    (0x0000010B, "STATUS_NOTIFY_CLEANUP", "Notify cleanup."),
+    (0x0000010C, "STATUS_NOTIFY_ENUM_DIR", "Notify enum dir."),
+    (0x0000010D, "STATUS_NO_QUOTAS_FOR_ACCOUNT", "No quotas for account."),
+    (0x0000010E, "STATUS_PRIMARY_TRANSPORT_CONNECT_FAILED", "Primary transport connect failed."),
+    (0x00000110, "STATUS_PAGE_FAULT_TRANSITION", "Page fault transition."),
+    (0x00000111, "STATUS_PAGE_FAULT_DEMAND_ZERO", "Page fault demand zero."),
+    (0x00000112, "STATUS_PAGE_FAULT_COPY_ON_WRITE", "Page fault copy on write."),
+    (0x00000113, "STATUS_PAGE_FAULT_GUARD_PAGE", "Page fault guard page."),
+    (0x00000114, "STATUS_PAGE_FAULT_PAGING_FILE", "Page fault paging file."),
+    (0x00000115, "STATUS_CACHE_PAGE_LOCKED", "Cache page locked."),
+    (0x00000116, "STATUS_CRASH_DUMP", "Crash dump."),
+    (0x00000117, "STATUS_BUFFER_ALL_ZEROS", "Buffer all zeros."),
+    (0x00000118, "STATUS_REPARSE_OBJECT", "Reparse object."),
+    (0x00000119, "STATUS_RESOURCE_REQUIREMENTS_CHANGED", "Resource requirements changed."),
+    (0x00000120, "STATUS_TRANSLATION_COMPLETE", "Translation complete."),
+    (0x00000121, "STATUS_DS_MEMBERSHIP_EVALUATED_LOCALLY", "DS membership evaluated locally."),
+    (0x00000122, "STATUS_NOTHING_TO_TERMINATE", "Nothing to terminate."),
+    (0x00000123, "STATUS_PROCESS_NOT_IN_JOB", "Process not in job."),
+    (0x00000124, "STATUS_PROCESS_IN_JOB", "Process in job."),
+    (0x00000125, "STATUS_VOLSNAP_HIBERNATE_READY", "Volsnap hibernate ready."),
+    (0x00000126, "STATUS_FSFILTER_OP_COMPLETED_SUCCESSFULLY", "Fsfilter op completed successfully."),
+    (0x00000127, "STATUS_INTERRUPT_VECTOR_ALREADY_CONNECTED", "Interrupt vector already connected."),
+    (0x00000128, "STATUS_INTERRUPT_STILL_CONNECTED", "Interrupt still connected."),
+    (0x00000129, "STATUS_PROCESS_CLONED", "Process cloned."),
+    (0x0000012A, "STATUS_FILE_LOCKED_WITH_ONLY_READERS", "File locked with only readers."),
+    (0x0000012B, "STATUS_FILE_LOCKED_WITH_WRITERS", "File locked with writers."),
+    (0x0000012C, "STATUS_VALID_IMAGE_HASH", "Valid image hash."),
+    (0x0000012D, "STATUS_VALID_CATALOG_HASH", "Valid catalog hash."),
+    (0x0000012E, "STATUS_VALID_STRONG_CODE_HASH", "Valid strong code hash."),
+    (0x0000012F, "STATUS_GHOSTED", "Ghosted."),
+    (0x00000130, "STATUS_DATA_OVERWRITTEN", "Data overwritten."),
+    (0x00000202, "STATUS_RESOURCEMANAGER_READ_ONLY", "Resourcemanager read only."),
+    (0x00000210, "STATUS_RING_PREVIOUSLY_EMPTY", "Ring previously empty."),
+    (0x00000211, "STATUS_RING_PREVIOUSLY_FULL", "Ring previously full."),
+    (0x00000212, "STATUS_RING_PREVIOUSLY_ABOVE_QUOTA", "Ring previously above quota."),
+    (0x00000213, "STATUS_RING_NEWLY_EMPTY", "Ring newly empty."),
+    (0x00000214, "STATUS_RING_SIGNAL_OPPOSITE_ENDPOINT", "Ring signal opposite endpoint."),
+    (0x00000215, "STATUS_OPLOCK_SWITCHED_TO_NEW_HANDLE", "Oplock switched to new handle."),
+    (0x00000216, "STATUS_OPLOCK_HANDLE_CLOSED", "Oplock handle closed."),
+    (0x00000367, "STATUS_WAIT_FOR_OPLOCK", "Wait for oplock."),
+    (0x00000368, "STATUS_REPARSE_GLOBAL", "Reparse global."),
+    (0x00000369, "STATUS_PAGE_FAULT_RETRY", "Page fault retry."),
+    (0x00010001, "DBG_EXCEPTION_HANDLED", "Exception handled."),
+    (0x00010002, "DBG_CONTINUE", "Continue."),
+    (0x001C0001, "STATUS_FLT_IO_COMPLETE", "FLT IO complete."),
+    (0x00293000, "STATUS_RTPM_CONTEXT_CONTINUE", "RTPM context continue."),
+    (0x00293001, "STATUS_RTPM_CONTEXT_COMPLETE", "RTPM context complete."),
+    (0x00350059, "STATUS_HV_PENDING_PAGE_REQUESTS", "HV pending page requests."),
+    (0x00E70000, "STATUS_SPACES_REPAIRED", "Spaces repaired."),
+    (0x00E70001, "STATUS_SPACES_PAUSE", "Spaces pause."),
+    (0x00E70002, "STATUS_SPACES_COMPLETE", "Spaces complete."),
+    (0x00E70003, "STATUS_SPACES_REDIRECT", "Spaces redirect."),
+    (0x40000000, "STATUS_OBJECT_NAME_EXISTS", "Object name exists."),
+    (0x40000001, "STATUS_THREAD_WAS_SUSPENDED", "Thread was suspended."),
+    (0x40000002, "STATUS_WORKING_SET_LIMIT_RANGE", "Working set limit range."),
+    (0x40000003, "STATUS_IMAGE_NOT_AT_BASE", "Image not at base."),
+    (0x40000004, "STATUS_RXACT_STATE_CREATED", "RXACT state created."),
+    (0x40000005, "STATUS_SEGMENT_NOTIFICATION", "Segment notification."),
+    (0x40000006, "STATUS_LOCAL_USER_SESSION_KEY", "Local user session key."),
+    (0x40000007, "STATUS_BAD_CURRENT_DIRECTORY", "Bad current directory."),
+    (0x40000008, "STATUS_SERIAL_MORE_WRITES", "Serial more writes."),
+    (0x40000009, "STATUS_REGISTRY_RECOVERED", "Registry recovered."),
+    (0x4000000A, "STATUS_FT_READ_RECOVERY_FROM_BACKUP", "FT read recovery from backup."),
+    (0x4000000B, "STATUS_FT_WRITE_RECOVERY", "FT write recovery."),
+    (0x4000000C, "STATUS_SERIAL_COUNTER_TIMEOUT", "Serial counter timeout."),
+    (0x4000000D, "STATUS_NULL_LM_PASSWORD", "Null LM password."),
+    (0x4000000E, "STATUS_IMAGE_MACHINE_TYPE_MISMATCH", "Image machine type mismatch."),
+    (0x4000000F, "STATUS_RECEIVE_PARTIAL", "Receive partial."),
+    (0x40000010, "STATUS_RECEIVE_EXPEDITED", "Receive expedited."),
+    (0x40000011, "STATUS_RECEIVE_PARTIAL_EXPEDITED", "Receive partial expedited."),
+    (0x40000012, "STATUS_EVENT_DONE", "Event done."),
+    (0x40000013, "STATUS_EVENT_PENDING", "Event pending."),
+    (0x40000014, "STATUS_CHECKING_FILE_SYSTEM", "Checking file system."),
+    (0x40000015, "STATUS_FATAL_APP_EXIT", "Fatal app exit."),
+    (0x40000016, "STATUS_PREDEFINED_HANDLE", "Predefined handle."),
+    (0x40000017, "STATUS_WAS_UNLOCKED", "Was unlocked."),
+    (0x40000018, "STATUS_SERVICE_NOTIFICATION", "Service notification."),
+    (0x40000019, "STATUS_WAS_LOCKED", "Was locked."),
+    (0x4000001A, "STATUS_LOG_HARD_ERROR", "Log hard error."),
+    (0x4000001B, "STATUS_ALREADY_WIN32", "Already WIN32."),
+    (0x4000001C, "STATUS_WX86_UNSIMULATE", "WX86 unsimulate."),
+    (0x4000001D, "STATUS_WX86_CONTINUE", "WX86 continue."),
+    (0x4000001E, "STATUS_WX86_SINGLE_STEP", "WX86 single step."),
+    (0x4000001F, "STATUS_WX86_BREAKPOINT", "WX86 breakpoint."),
+    (0x40000020, "STATUS_WX86_EXCEPTION_CONTINUE", "WX86 exception continue."),
+    (0x40000021, "STATUS_WX86_EXCEPTION_LASTCHANCE", "WX86 exception lastchance."),
+    (0x40000022, "STATUS_WX86_EXCEPTION_CHAIN", "WX86 exception chain."),
+    (0x40000023, "STATUS_IMAGE_MACHINE_TYPE_MISMATCH_EXE", "Image machine type mismatch exe."),
+    (0x40000024, "STATUS_NO_YIELD_PERFORMED", "No yield performed."),
+    (0x40000025, "STATUS_TIMER_RESUME_IGNORED", "Timer resume ignored."),
+    (0x40000026, "STATUS_ARBITRATION_UNHANDLED", "Arbitration unhandled."),
+    (0x40000027, "STATUS_CARDBUS_NOT_SUPPORTED", "Cardbus not supported."),
+    (0x40000028, "STATUS_WX86_CREATEWX86TIB", "WX86 CREATEWX86TIB."),
+    (0x40000029, "STATUS_MP_PROCESSOR_MISMATCH", "MP processor mismatch."),
+    (0x4000002A, "STATUS_HIBERNATED", "Hibernated."),
+    (0x4000002B, "STATUS_RESUME_HIBERNATION", "Resume hibernation."),
+    (0x4000002C, "STATUS_FIRMWARE_UPDATED", "Firmware updated."),
+    (0x4000002D, "STATUS_DRIVERS_LEAKING_LOCKED_PAGES", "Drivers leaking locked pages."),
+    (0x4000002E, "STATUS_MESSAGE_RETRIEVED", "Message retrieved."),
+    (0x4000002F, "STATUS_SYSTEM_POWERSTATE_TRANSITION", "System powerstate transition."),
+    (0x40000030, "STATUS_ALPC_CHECK_COMPLETION_LIST", "ALPC check completion list."),
+    (0x40000031, "STATUS_SYSTEM_POWERSTATE_COMPLEX_TRANSITION", "System powerstate complex transition."),
+    (0x40000032, "STATUS_ACCESS_AUDIT_BY_POLICY", "Access audit by policy."),
+    (0x40000033, "STATUS_ABANDON_HIBERFILE", "Abandon hiberfile."),
+    (0x40000034, "STATUS_BIZRULES_NOT_ENABLED", "Bizrules not enabled."),
+    (0x40000035, "STATUS_FT_READ_FROM_COPY", "FT read from copy."),
+    (0x40000036, "STATUS_IMAGE_AT_DIFFERENT_BASE", "Image at different base."),
+    (0x40000037, "STATUS_PATCH_DEFERRED", "Patch deferred."),
+    (0x40000038, "STATUS_EMULATION_BREAKPOINT", "Emulation breakpoint."),
+    (0x40000039, "STATUS_EMULATION_SYSCALL", "Emulation syscall."),
+    (0x40000294, "STATUS_WAKE_SYSTEM", "Wake system."),
+    (0x40000370, "STATUS_DS_SHUTTING_DOWN", "DS shutting down."),
+    (0x40000807, "STATUS_DISK_REPAIR_REDIRECTED", "Disk repair redirected."),
+    (0x4000A144, "STATUS_SERVICES_FAILED_AUTOSTART", "Services failed autostart."),
+    (0x40010001, "DBG_REPLY_LATER", "Reply later."),
+    (0x40010002, "DBG_UNABLE_TO_PROVIDE_HANDLE", "Unable to provide handle."),
+    (0x40010003, "DBG_TERMINATE_THREAD", "Terminate thread."),
+    (0x40010004, "DBG_TERMINATE_PROCESS", "Terminate process."),
+    (0x40010005, "DBG_CONTROL_C", "Control C."),
+    (0x40010006, "DBG_PRINTEXCEPTION_C", "Printexception C."),
+    (0x40010007, "DBG_RIPEXCEPTION", "Ripexception."),
+    (0x40010008, "DBG_CONTROL_BREAK", "Control break."),
+    (0x40010009, "DBG_COMMAND_EXCEPTION", "Command exception."),
+    (0x4001000A, "DBG_PRINTEXCEPTION_WIDE_C", "Printexception wide C."),
+    (0x40020056, "RPC_NT_UUID_LOCAL_ONLY", "Uuid local only."),
+    (0x400200AF, "RPC_NT_SEND_INCOMPLETE", "Send incomplete."),
+    (0x400A0004, "STATUS_CTX_CDM_CONNECT", "CTX CDM connect."),
+    (0x400A0005, "STATUS_CTX_CDM_DISCONNECT", "CTX CDM disconnect."),
+    (0x4015000D, "STATUS_SXS_RELEASE_ACTIVATION_CONTEXT", "SXS release activation context."),
+    (0x40190001, "STATUS_HEURISTIC_DAMAGE_POSSIBLE", "Heuristic damage possible."),
+    (0x40190034, "STATUS_RECOVERY_NOT_NEEDED", "Recovery not needed."),
+    (0x40190035, "STATUS_RM_ALREADY_STARTED", "RM already started."),
+    (0x401A000C, "STATUS_LOG_NO_RESTART", "Log no restart."),
+    (0x401B00EC, "STATUS_VIDEO_DRIVER_DEBUG_REPORT_REQUEST", "Video driver debug report request."),
+    (0x401E000A, "STATUS_GRAPHICS_PARTIAL_DATA_POPULATED", "Graphics partial data populated."),
+    (0x401E0201, "STATUS_GRAPHICS_SKIP_ALLOCATION_PREPARATION", "Graphics skip allocation preparation."),
+    (0x401E0307, "STATUS_GRAPHICS_MODE_NOT_PINNED", "Graphics mode not pinned."),
+    (0x401E031E, "STATUS_GRAPHICS_NO_PREFERRED_MODE", "Graphics no preferred mode."),
+    (0x401E034B, "STATUS_GRAPHICS_DATASET_IS_EMPTY", "Graphics dataset is empty."),
+    (0x401E034C, "STATUS_GRAPHICS_NO_MORE_ELEMENTS_IN_DATASET", "Graphics no more elements in dataset."),
+    (0x401E0351, "STATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_PINNED", "Graphics path content geometry transformation not pinned."),
+    (0x401E042F, "STATUS_GRAPHICS_UNKNOWN_CHILD_STATUS", "Graphics unknown child status."),
+    (0x401E0437, "STATUS_GRAPHICS_LEADLINK_START_DEFERRED", "Graphics leadlink start deferred."),
+    (0x401E0439, "STATUS_GRAPHICS_POLLING_TOO_FREQUENTLY", "Graphics polling too frequently."),
+    (0x401E043A, "STATUS_GRAPHICS_START_DEFERRED", "Graphics start deferred."),
+    (0x401E043C, "STATUS_GRAPHICS_DEPENDABLE_CHILD_STATUS", "Graphics dependable child status."),
+    (0x40230001, "STATUS_NDIS_INDICATION_REQUIRED", "NDIS indication required."),
+    (0x40292023, "STATUS_PCP_UNSUPPORTED_PSS_SALT", "PCP unsupported PSS salt."),
+    (0x80000001, "STATUS_GUARD_PAGE_VIOLATION", "Guard page violation."),
+    (0x80000002, "STATUS_DATATYPE_MISALIGNMENT", "Datatype misalignment."),
+    (0x80000003, "STATUS_BREAKPOINT", "Breakpoint."),
+    (0x80000004, "STATUS_SINGLE_STEP", "Single step."),
+    (0x80000005, "STATUS_BUFFER_OVERFLOW", "{Buffer Overflow}\nThe data was too large to fit into the specified buffer."),
+    (0x80000006, "STATUS_NO_MORE_FILES", "No more files."),
+    (0x80000007, "STATUS_WAKE_SYSTEM_DEBUGGER", "Wake system debugger."),
+    (0x8000000A, "STATUS_HANDLES_CLOSED", "Handles closed."),
+    (0x8000000B, "STATUS_NO_INHERITANCE", "No inheritance."),
+    (0x8000000C, "STATUS_GUID_SUBSTITUTION_MADE", "GUID substitution made."),
+    (0x8000000D, "STATUS_PARTIAL_COPY", "{Partial Copy}\nDue to protection conflicts not all the requested bytes could be copied."),
+    (0x8000000E, "STATUS_DEVICE_PAPER_EMPTY", "Device paper empty."),
+    (0x8000000F, "STATUS_DEVICE_POWERED_OFF", "Device powered off."),
+    (0x80000010, "STATUS_DEVICE_OFF_LINE", "Device off line."),
+    (0x80000011, "STATUS_DEVICE_BUSY", "Device busy."),
+    (0x80000012, "STATUS_NO_MORE_EAS", "No more EAS."),
+    (0x80000013, "STATUS_INVALID_EA_NAME", "Invalid EA name."),
+    (0x80000014, "STATUS_EA_LIST_INCONSISTENT", "EA list inconsistent."),
+    (0x80000015, "STATUS_INVALID_EA_FLAG", "Invalid EA flag."),
+    (0x80000016, "STATUS_VERIFY_REQUIRED", "Verify required."),
+    (0x80000017, "STATUS_EXTRANEOUS_INFORMATION", "Extraneous information."),
+    (0x80000018, "STATUS_RXACT_COMMIT_NECESSARY", "RXACT commit necessary."),
+    (0x8000001A, "STATUS_NO_MORE_ENTRIES", "{No More Entries}\nNo more entries are available from an enumeration operation."),
+    (0x8000001B, "STATUS_FILEMARK_DETECTED", "Filemark detected."),
+    (0x8000001C, "STATUS_MEDIA_CHANGED", "Media changed."),
+    (0x8000001D, "STATUS_BUS_RESET", "Bus reset."),
+    (0x8000001E, "STATUS_END_OF_MEDIA", "End of media."),
+    (0x8000001F, "STATUS_BEGINNING_OF_MEDIA", "Beginning of media."),
+    (0x80000020, "STATUS_MEDIA_CHECK", "Media check."),
+    (0x80000021, "STATUS_SETMARK_DETECTED", "Setmark detected."),
+    (0x80000022, "STATUS_NO_DATA_DETECTED", "No data detected."),
+    (0x80000023, "STATUS_REDIRECTOR_HAS_OPEN_HANDLES", "Redirector has open handles."),
+    (0x80000024, "STATUS_SERVER_HAS_OPEN_HANDLES", "Server has open handles."),
+    (0x80000025, "STATUS_ALREADY_DISCONNECTED", "Already disconnected."),
+    (0x80000026, "STATUS_LONGJUMP", "Longjump."),
+    (0x80000027, "STATUS_CLEANER_CARTRIDGE_INSTALLED", "Cleaner cartridge installed."),
+    (0x80000028, "STATUS_PLUGPLAY_QUERY_VETOED", "Plugplay query vetoed."),
+    (0x80000029, "STATUS_UNWIND_CONSOLIDATE", "Unwind consolidate."),
+    (0x8000002A, "STATUS_REGISTRY_HIVE_RECOVERED", "Registry hive recovered."),
+    (0x8000002B, "STATUS_DLL_MIGHT_BE_INSECURE", "DLL might be insecure."),
+    (0x8000002C, "STATUS_DLL_MIGHT_BE_INCOMPATIBLE", "DLL might be incompatible."),
+    (0x8000002D, "STATUS_STOPPED_ON_SYMLINK", "Stopped on symlink."),
+    (0x8000002E, "STATUS_CANNOT_GRANT_REQUESTED_OPLOCK", "Cannot grant requested oplock."),
+    (0x8000002F, "STATUS_NO_ACE_CONDITION", "No ace condition."),
+    (0x80000030, "STATUS_DEVICE_SUPPORT_IN_PROGRESS", "Device support in progress."),
+    (0x80000031, "STATUS_DEVICE_POWER_CYCLE_REQUIRED", "Device power cycle required."),
+    (0x80000032, "STATUS_NO_WORK_DONE", "No work done."),
+    (0x80000033, "STATUS_RETURN_ADDRESS_HIJACK_ATTEMPT", "Return address hijack attempt."),
+    (0x80000034, "STATUS_RECOVERABLE_BUGCHECK", "Recoverable bugcheck."),
+    (0x800001B6, "STATUS_DEVICE_RESET_REQUIRED", "Device reset required."),
+    (0x80000288, "STATUS_DEVICE_REQUIRES_CLEANING", "Device requires cleaning."),
+    (0x80000289, "STATUS_DEVICE_DOOR_OPEN", "Device door open."),
+    (0x80000803, "STATUS_DATA_LOST_REPAIR", "Data lost repair."),
+    (0x8000A127, "STATUS_GPIO_INTERRUPT_ALREADY_UNMASKED", "GPIO interrupt already unmasked."),
+    (0x8000CF00, "STATUS_CLOUD_FILE_PROPERTY_BLOB_CHECKSUM_MISMATCH", "Cloud file property blob checksum mismatch."),
+    (0x8000CF04, "STATUS_CLOUD_FILE_PROPERTY_BLOB_TOO_LARGE", "Cloud file property blob too large."),
+    (0x8000CF05, "STATUS_CLOUD_FILE_TOO_MANY_PROPERTY_BLOBS", "Cloud file too many property blobs."),
+    (0x80010001, "DBG_EXCEPTION_NOT_HANDLED", "Exception not handled."),
+    (0x80130001, "STATUS_CLUSTER_NODE_ALREADY_UP", "Cluster node already up."),
+    (0x80130002, "STATUS_CLUSTER_NODE_ALREADY_DOWN", "Cluster node already down."),
+    (0x80130003, "STATUS_CLUSTER_NETWORK_ALREADY_ONLINE", "Cluster network already online."),
+    (0x80130004, "STATUS_CLUSTER_NETWORK_ALREADY_OFFLINE", "Cluster network already offline."),
+    (0x80130005, "STATUS_CLUSTER_NODE_ALREADY_MEMBER", "Cluster node already member."),
+    (0x80190009, "STATUS_COULD_NOT_RESIZE_LOG", "Could not resize log."),
+    (0x80190029, "STATUS_NO_TXF_METADATA", "No TXF metadata."),
+    (0x80190031, "STATUS_CANT_RECOVER_WITH_HANDLE_OPEN", "Cant recover with handle open."),
+    (0x80190041, "STATUS_TXF_METADATA_ALREADY_PRESENT", "TXF metadata already present."),
+    (0x80190042, "STATUS_TRANSACTION_SCOPE_CALLBACKS_NOT_SET", "Transaction scope callbacks not set."),
+    (0x801B00EB, "STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD_RECOVERED", "Video hung display driver thread recovered."),
+    (0x801C0001, "STATUS_FLT_BUFFER_TOO_SMALL", "FLT buffer too small."),
+    (0x801E0000, "STATUS_GRAPHICS_LINK_CONFIGURATION_IN_PROGRESS", "Graphics link configuration in progress."),
+    (0x80210001, "STATUS_FVE_PARTIAL_METADATA", "FVE partial metadata."),
+    (0x80210002, "STATUS_FVE_TRANSIENT_STATE", "FVE transient state."),
+    (0x80370001, "STATUS_VID_REMOTE_NODE_PARENT_GPA_PAGES_USED", "VID remote node parent gpa pages used."),
+    (0x80380001, "STATUS_VOLMGR_INCOMPLETE_REGENERATION", "VOLMGR incomplete regeneration."),
+    (0x80380002, "STATUS_VOLMGR_INCOMPLETE_DISK_MIGRATION", "VOLMGR incomplete disk migration."),
+    (0x80390001, "STATUS_BCD_NOT_ALL_ENTRIES_IMPORTED", "BCD not all entries imported."),
+    (0x80390003, "STATUS_BCD_NOT_ALL_ENTRIES_SYNCHRONIZED", "BCD not all entries synchronized."),
+    (0x803A0001, "STATUS_QUERY_STORAGE_ERROR", "Query storage error."),
+    (0x803F0001, "STATUS_GDI_HANDLE_LEAK", "Gdi handle leak."),
+    (0x80430006, "STATUS_SECUREBOOT_NOT_ENABLED", "Secureboot not enabled."),
+    (0xC0000001, "STATUS_UNSUCCESSFUL", "{Operation Failed}\nThe requested operation was unsuccessful."),
+    (0xC0000002, "STATUS_NOT_IMPLEMENTED", "{Not Implemented}\nThe requested operation is not implemented."),
+    (0xC0000003, "STATUS_INVALID_INFO_CLASS", "Invalid info class."),
+    (0xC0000004, "STATUS_INFO_LENGTH_MISMATCH", "The specified information record length does not match the length required for the specified information class."),
+    (0xC0000005, "STATUS_ACCESS_VIOLATION", "The instruction at 0x%p referenced memory at 0x%p. The memory could not be %s."),
+    (0xC0000006, "STATUS_IN_PAGE_ERROR", "In page error."),
+    (0xC0000007, "STATUS_PAGEFILE_QUOTA", "Pagefile quota."),
+    (0xC0000008, "STATUS_INVALID_HANDLE", "An invalid HANDLE was specified."),
+    (0xC0000009, "STATUS_BAD_INITIAL_STACK", "Bad initial stack."),
+    (0xC000000A, "STATUS_BAD_INITIAL_PC", "Bad initial PC."),
+    (0xC000000B, "STATUS_INVALID_CID", "Invalid cid."),
+    (0xC000000C, "STATUS_TIMER_NOT_CANCELED", "Timer not canceled."),
+    (0xC000000D, "STATUS_INVALID_PARAMETER", "An invalid parameter was passed to a service or function."),
+    (0xC000000E, "STATUS_NO_SUCH_DEVICE", "No such device."),
+    (0xC000000F, "STATUS_NO_SUCH_FILE", "No such file."),
+    (0xC0000010, "STATUS_INVALID_DEVICE_REQUEST", "The specified request is not a valid operation for the target device."),
+    (0xC0000011, "STATUS_END_OF_FILE", "End of file."),
+    (0xC0000012, "STATUS_WRONG_VOLUME", "Wrong volume."),
+    (0xC0000013, "STATUS_NO_MEDIA_IN_DEVICE", "No media in device."),
+    (0xC0000014, "STATUS_UNRECOGNIZED_MEDIA", "Unrecognized media."),
+    (0xC0000015, "STATUS_NONEXISTENT_SECTOR", "Nonexistent sector."),
+    (0xC0000016, "STATUS_MORE_PROCESSING_REQUIRED", "{Still Busy}\nThe specified I/O request packet (IRP) cannot be disposed of because the I/O operation is not complete."),
+    (0xC0000017, "STATUS_NO_MEMORY", "{Not Enough Quota}\nNot enough virtual memory or paging file quota is available to complete the specified operation."),
+    (0xC0000018, "STATUS_CONFLICTING_ADDRESSES", "Conflicting addresses."),
+    (0xC0000019, "STATUS_NOT_MAPPED_VIEW", "Not mapped view."),
+    (0xC000001A, "STATUS_UNABLE_TO_FREE_VM", "Unable to free VM."),
+    (0xC000001B, "STATUS_UNABLE_TO_DELETE_SECTION", "Unable to delete section."),
+    (0xC000001C, "STATUS_INVALID_SYSTEM_SERVICE", "Invalid system service."),
+    (0xC000001D, "STATUS_ILLEGAL_INSTRUCTION", "Illegal instruction."),
+    (0xC000001E, "STATUS_INVALID_LOCK_SEQUENCE", "Invalid lock sequence."),
+    (0xC000001F, "STATUS_INVALID_VIEW_SIZE", "Invalid view size."),
+    (0xC0000020, "STATUS_INVALID_FILE_FOR_SECTION", "Invalid file for section."),
+    (0xC0000021, "STATUS_ALREADY_COMMITTED", "Already committed."),
+    (0xC0000022, "STATUS_ACCESS_DENIED", "{Access Denied}\nA process has requested access to an object, but has not been granted those access rights."),
+    (0xC0000023, "STATUS_BUFFER_TOO_SMALL", "{Buffer Too Small}\nThe buffer is too small to contain the entry. No information has been written to the buffer."),
+    (0xC0000024, "STATUS_OBJECT_TYPE_MISMATCH", "{Wrong Type}\nThere is a mismatch between the type of object required by the requested operation and the type of object that is specified in the request."),
+    (0xC0000025, "STATUS_NONCONTINUABLE_EXCEPTION", "Noncontinuable exception."),
+    (0xC0000026, "STATUS_INVALID_DISPOSITION", "Invalid disposition."),
+    (0xC0000027, "STATUS_UNWIND", "Unwind."),
+    (0xC0000028, "STATUS_BAD_STACK", "Bad stack."),
+    (0xC0000029, "STATUS_INVALID_UNWIND_TARGET", "Invalid unwind target."),
+    (0xC000002A, "STATUS_NOT_LOCKED", "Not locked."),
+    (0xC000002B, "STATUS_PARITY_ERROR", "Parity error."),
+    (0xC000002C, "STATUS_UNABLE_TO_DECOMMIT_VM", "Unable to decommit VM."),
+    (0xC000002D, "STATUS_NOT_COMMITTED", "Not committed."),
+    (0xC000002E, "STATUS_INVALID_PORT_ATTRIBUTES", "Invalid port attributes."),
+    (0xC000002F, "STATUS_PORT_MESSAGE_TOO_LONG", "Port message too long."),
+    (0xC0000030, "STATUS_INVALID_PARAMETER_MIX", "Invalid parameter mix."),
+    (0xC0000031, "STATUS_INVALID_QUOTA_LOWER", "Invalid quota lower."),
+    (0xC0000032, "STATUS_DISK_CORRUPT_ERROR", "Disk corrupt error."),
+    (0xC0000033, "STATUS_OBJECT_NAME_INVALID", "Object Name invalid."),
+    (0xC0000034, "STATUS_OBJECT_NAME_NOT_FOUND", "Object Name not found."),
+    (0xC0000035, "STATUS_OBJECT_NAME_COLLISION", "Object Name already exists."),
+    (0xC0000036, "STATUS_PORT_DO_NOT_DISTURB", "Port do not disturb."),
+    (0xC0000037, "STATUS_PORT_DISCONNECTED", "Port disconnected."),
+    (0xC0000038, "STATUS_DEVICE_ALREADY_ATTACHED", "Device already attached."),
+    (0xC0000039, "STATUS_OBJECT_PATH_INVALID", "Object path invalid."),
+    (0xC000003A, "STATUS_OBJECT_PATH_NOT_FOUND", "Object path not found."),
+    (0xC000003B, "STATUS_OBJECT_PATH_SYNTAX_BAD", "Object path syntax bad."),
+    (0xC000003C, "STATUS_DATA_OVERRUN", "Data overrun."),
+    (0xC000003D, "STATUS_DATA_LATE_ERROR", "Data late error."),
+    (0xC000003E, "STATUS_DATA_ERROR", "Data error."),
+    (0xC000003F, "STATUS_CRC_ERROR", "CRC error."),
+    (0xC0000040, "STATUS_SECTION_TOO_BIG", "Section too big."),
+    (0xC0000041, "STATUS_PORT_CONNECTION_REFUSED", "Port connection refused."),
+    (0xC0000042, "STATUS_INVALID_PORT_HANDLE", "Invalid port handle."),
+    (0xC0000043, "STATUS_SHARING_VIOLATION", "Sharing violation."),
+    (0xC0000044, "STATUS_QUOTA_EXCEEDED", "Quota exceeded."),
+    (0xC0000045, "STATUS_INVALID_PAGE_PROTECTION", "Invalid page protection."),
+    (0xC0000046, "STATUS_MUTANT_NOT_OWNED", "Mutant not owned."),
+    (0xC0000047, "STATUS_SEMAPHORE_LIMIT_EXCEEDED", "An attempt was made to release a semaphore such that its maximum count would have been exceeded."),
+    (0xC0000048, "STATUS_PORT_ALREADY_SET", "Port already set."),
+    (0xC0000049, "STATUS_SECTION_NOT_IMAGE", "Section not image."),
+    (0xC000004A, "STATUS_SUSPEND_COUNT_EXCEEDED", "Suspend count exceeded."),
+    (0xC000004B, "STATUS_THREAD_IS_TERMINATING", "Thread is terminating."),
+    (0xC000004C, "STATUS_BAD_WORKING_SET_LIMIT", "Bad working set limit."),
+    (0xC000004D, "STATUS_INCOMPATIBLE_FILE_MAP", "Incompatible file map."),
+    (0xC000004E, "STATUS_SECTION_PROTECTION", "Section protection."),
+    (0xC000004F, "STATUS_EAS_NOT_SUPPORTED", "EAS not supported."),
+    (0xC0000050, "STATUS_EA_TOO_LARGE", "EA too large."),
+    (0xC0000051, "STATUS_NONEXISTENT_EA_ENTRY", "Nonexistent EA entry."),
+    (0xC0000052, "STATUS_NO_EAS_ON_FILE", "No EAS on file."),
+    (0xC0000053, "STATUS_EA_CORRUPT_ERROR", "EA corrupt error."),
+    (0xC0000054, "STATUS_FILE_LOCK_CONFLICT", "File lock conflict."),
+    (0xC0000055, "STATUS_LOCK_NOT_GRANTED", "Lock not granted."),
+    (0xC0000056, "STATUS_DELETE_PENDING", "Delete pending."),
+    (0xC0000057, "STATUS_CTL_FILE_NOT_SUPPORTED", "CTL file not supported."),
+    (0xC0000058, "STATUS_UNKNOWN_REVISION", "Unknown revision."),
+    (0xC0000059, "STATUS_REVISION_MISMATCH", "Revision mismatch."),
+    (0xC000005A, "STATUS_INVALID_OWNER", "Invalid owner."),
+    (0xC000005B, "STATUS_INVALID_PRIMARY_GROUP", "Invalid primary group."),
+    (0xC000005C, "STATUS_NO_IMPERSONATION_TOKEN", "No impersonation token."),
+    (0xC000005D, "STATUS_CANT_DISABLE_MANDATORY", "Cant disable mandatory."),
+    (0xC000005E, "STATUS_NO_LOGON_SERVERS", "No logon servers."),
+    (0xC0000060, "STATUS_NO_SUCH_PRIVILEGE", "No such privilege."),
+    (0xC0000061, "STATUS_PRIVILEGE_NOT_HELD", "Privilege not held."),
+    (0xC0000062, "STATUS_INVALID_ACCOUNT_NAME", "Invalid account name."),
+    (0xC0000063, "STATUS_USER_EXISTS", "User exists."),
+    (0xC0000065, "STATUS_GROUP_EXISTS", "Group exists."),
+    (0xC0000066, "STATUS_NO_SUCH_GROUP", "No such group."),
+    (0xC0000067, "STATUS_MEMBER_IN_GROUP", "Member in group."),
+    (0xC0000068, "STATUS_MEMBER_NOT_IN_GROUP", "Member not in group."),
+    (0xC0000069, "STATUS_LAST_ADMIN", "Last admin."),
+    (0xC000006B, "STATUS_ILL_FORMED_PASSWORD", "Ill formed password."),
+    (0xC000006C, "STATUS_PASSWORD_RESTRICTION", "Password restriction."),
+    (0xC000006F, "STATUS_INVALID_LOGON_HOURS", "Invalid logon hours."),
+    (0xC0000070, "STATUS_INVALID_WORKSTATION", "Invalid workstation."),
+    (0xC0000073, "STATUS_NONE_MAPPED", "None mapped."),
+    (0xC0000074, "STATUS_TOO_MANY_LUIDS_REQUESTED", "Too many luids requested."),
+    (0xC0000075, "STATUS_LUIDS_EXHAUSTED", "Luids exhausted."),
+    (0xC0000076, "STATUS_INVALID_SUB_AUTHORITY", "Invalid sub authority."),
+    (0xC0000077, "STATUS_INVALID_ACL", "Invalid ACL."),
+    (0xC0000078, "STATUS_INVALID_SID", "Invalid SID."),
+    (0xC0000079, "STATUS_INVALID_SECURITY_DESCR", "Invalid security descr."),
+    (0xC000007A, "STATUS_PROCEDURE_NOT_FOUND", "Procedure not found."),
+    (0xC000007B, "STATUS_INVALID_IMAGE_FORMAT", "Invalid image format."),
+    (0xC000007C, "STATUS_NO_TOKEN", "No token."),
+    (0xC000007D, "STATUS_BAD_INHERITANCE_ACL", "Bad inheritance ACL."),
+    (0xC000007E, "STATUS_RANGE_NOT_LOCKED", "Range not locked."),
+    (0xC000007F, "STATUS_DISK_FULL", "Disk full."),
+    (0xC0000080, "STATUS_SERVER_DISABLED", "Server disabled."),
+    (0xC0000081, "STATUS_SERVER_NOT_DISABLED", "Server not disabled."),
+    (0xC0000082, "STATUS_TOO_MANY_GUIDS_REQUESTED", "Too many guids requested."),
+    (0xC0000083, "STATUS_GUIDS_EXHAUSTED", "Guids exhausted."),
+    (0xC0000084, "STATUS_INVALID_ID_AUTHORITY", "Invalid ID authority."),
+    (0xC0000085, "STATUS_AGENTS_EXHAUSTED", "Agents exhausted."),
+    (0xC0000086, "STATUS_INVALID_VOLUME_LABEL", "Invalid volume label."),
+    (0xC0000087, "STATUS_SECTION_NOT_EXTENDED", "Section not extended."),
+    (0xC0000088, "STATUS_NOT_MAPPED_DATA", "Not mapped data."),
+    (0xC0000089, "STATUS_RESOURCE_DATA_NOT_FOUND", "Resource data not found."),
+    (0xC000008A, "STATUS_RESOURCE_TYPE_NOT_FOUND", "Resource type not found."),
+    (0xC000008B, "STATUS_RESOURCE_NAME_NOT_FOUND", "Resource name not found."),
+    (0xC000008C, "STATUS_ARRAY_BOUNDS_EXCEEDED", "Array bounds exceeded."),
+    (0xC000008D, "STATUS_FLOAT_DENORMAL_OPERAND", "Float denormal operand."),
+    (0xC000008E, "STATUS_FLOAT_DIVIDE_BY_ZERO", "Float divide by zero."),
+    (0xC000008F, "STATUS_FLOAT_INEXACT_RESULT", "Float inexact result."),
+    (0xC0000090, "STATUS_FLOAT_INVALID_OPERATION", "Float invalid operation."),
+    (0xC0000091, "STATUS_FLOAT_OVERFLOW", "Float overflow."),
+    (0xC0000092, "STATUS_FLOAT_STACK_CHECK", "Float stack check."),
+    (0xC0000093, "STATUS_FLOAT_UNDERFLOW", "Float underflow."),
+    (0xC0000094, "STATUS_INTEGER_DIVIDE_BY_ZERO", "Integer divide by zero."),
+    (0xC0000095, "STATUS_INTEGER_OVERFLOW", "Integer overflow."),
+    (0xC0000096, "STATUS_PRIVILEGED_INSTRUCTION", "Privileged instruction."),
+    (0xC0000097, "STATUS_TOO_MANY_PAGING_FILES", "Too many paging files."),
+    (0xC0000098, "STATUS_FILE_INVALID", "File invalid."),
+    (0xC0000099, "STATUS_ALLOTTED_SPACE_EXCEEDED", "Allotted space exceeded."),
+    (0xC000009A, "STATUS_INSUFFICIENT_RESOURCES", "Insufficient system resources exist to complete the API."),
+    (0xC000009B, "STATUS_DFS_EXIT_PATH_FOUND", "DFS exit path found."),
+    (0xC000009C, "STATUS_DEVICE_DATA_ERROR", "Device data error."),
+    (0xC000009D, "STATUS_DEVICE_NOT_CONNECTED", "Device not connected."),
+    (0xC000009E, "STATUS_DEVICE_POWER_FAILURE", "Device power failure."),
+    (0xC000009F, "STATUS_FREE_VM_NOT_AT_BASE", "Free VM not at base."),
+    (0xC00000A0, "STATUS_MEMORY_NOT_ALLOCATED", "Memory not allocated."),
+    (0xC00000A1, "STATUS_WORKING_SET_QUOTA", "Working set quota."),
+    (0xC00000A2, "STATUS_MEDIA_WRITE_PROTECTED", "Media write protected."),
+    (0xC00000A3, "STATUS_DEVICE_NOT_READY", "Device not ready."),
+    (0xC00000A4, "STATUS_INVALID_GROUP_ATTRIBUTES", "Invalid group attributes."),
+    (0xC00000A5, "STATUS_BAD_IMPERSONATION_LEVEL", "Bad impersonation level."),
+    (0xC00000A6, "STATUS_CANT_OPEN_ANONYMOUS", "Cant open anonymous."),
+    (0xC00000A7, "STATUS_BAD_VALIDATION_CLASS", "Bad validation class."),
+    (0xC00000A8, "STATUS_BAD_TOKEN_TYPE", "Bad token type."),
+    (0xC00000A9, "STATUS_BAD_MASTER_BOOT_RECORD", "Bad master boot record."),
+    (0xC00000AA, "STATUS_INSTRUCTION_MISALIGNMENT", "Instruction misalignment."),
+    (0xC00000AB, "STATUS_INSTANCE_NOT_AVAILABLE", "Instance not available."),
+    (0xC00000AC, "STATUS_PIPE_NOT_AVAILABLE", "Pipe not available."),
+    (0xC00000AD, "STATUS_INVALID_PIPE_STATE", "Invalid pipe state."),
+    (0xC00000AE, "STATUS_PIPE_BUSY", "Pipe busy."),
+    (0xC00000AF, "STATUS_ILLEGAL_FUNCTION", "Illegal function."),
+    (0xC00000B0, "STATUS_PIPE_DISCONNECTED", "Pipe disconnected."),
+    (0xC00000B1, "STATUS_PIPE_CLOSING", "Pipe closing."),
+    (0xC00000B2, "STATUS_PIPE_CONNECTED", "Pipe connected."),
+    (0xC00000B3, "STATUS_PIPE_LISTENING", "Pipe listening."),
+    (0xC00000B4, "STATUS_INVALID_READ_MODE", "Invalid read mode."),
+    (0xC00000B5, "STATUS_IO_TIMEOUT", "{Device Timeout}\nThe specified I/O operation on %hs was not completed before the time-out period expired."),
+    (0xC00000B6, "STATUS_FILE_FORCED_CLOSED", "File forced closed."),
+    (0xC00000B7, "STATUS_PROFILING_NOT_STARTED", "Profiling not started."),
+    (0xC00000B8, "STATUS_PROFILING_NOT_STOPPED", "Profiling not stopped."),
+    (0xC00000B9, "STATUS_COULD_NOT_INTERPRET", "Could not interpret."),
+    (0xC00000BA, "STATUS_FILE_IS_A_DIRECTORY", "File is a directory."),
+    (0xC00000BB, "STATUS_NOT_SUPPORTED", "The request is not supported."),
+    (0xC00000BC, "STATUS_REMOTE_NOT_LISTENING", "Remote not listening."),
+    (0xC00000BD, "STATUS_DUPLICATE_NAME", "Duplicate name."),
+    (0xC00000BE, "STATUS_BAD_NETWORK_PATH", "Bad network path."),
+    (0xC00000BF, "STATUS_NETWORK_BUSY", "Network busy."),
+    (0xC00000C0, "STATUS_DEVICE_DOES_NOT_EXIST", "Device does not exist."),
+    (0xC00000C1, "STATUS_TOO_MANY_COMMANDS", "Too many commands."),
+    (0xC00000C2, "STATUS_ADAPTER_HARDWARE_ERROR", "Adapter hardware error."),
+    (0xC00000C3, "STATUS_INVALID_NETWORK_RESPONSE", "Invalid network response."),
+    (0xC00000C4, "STATUS_UNEXPECTED_NETWORK_ERROR", "Unexpected network error."),
+    (0xC00000C5, "STATUS_BAD_REMOTE_ADAPTER", "Bad remote adapter."),
+    (0xC00000C6, "STATUS_PRINT_QUEUE_FULL", "Print queue full."),
+    (0xC00000C7, "STATUS_NO_SPOOL_SPACE", "No spool space."),
+    (0xC00000C8, "STATUS_PRINT_CANCELLED", "Print cancelled."),
+    (0xC00000C9, "STATUS_NETWORK_NAME_DELETED", "Network name deleted."),
+    (0xC00000CA, "STATUS_NETWORK_ACCESS_DENIED", "Network access denied."),
+    (0xC00000CB, "STATUS_BAD_DEVICE_TYPE", "Bad device type."),
+    (0xC00000CC, "STATUS_BAD_NETWORK_NAME", "Bad network name."),
+    (0xC00000CD, "STATUS_TOO_MANY_NAMES", "Too many names."),
+    (0xC00000CE, "STATUS_TOO_MANY_SESSIONS", "Too many sessions."),
+    (0xC00000CF, "STATUS_SHARING_PAUSED", "Sharing paused."),
+    (0xC00000D0, "STATUS_REQUEST_NOT_ACCEPTED", "Request not accepted."),
+    (0xC00000D1, "STATUS_REDIRECTOR_PAUSED", "Redirector paused."),
+    (0xC00000D2, "STATUS_NET_WRITE_FAULT", "Net write fault."),
+    (0xC00000D3, "STATUS_PROFILING_AT_LIMIT", "Profiling at limit."),
+    (0xC00000D4, "STATUS_NOT_SAME_DEVICE", "Not same device."),
+    (0xC00000D5, "STATUS_FILE_RENAMED", "File renamed."),
+    (0xC00000D6, "STATUS_VIRTUAL_CIRCUIT_CLOSED", "Virtual circuit closed."),
+    (0xC00000D7, "STATUS_NO_SECURITY_ON_OBJECT", "No security on object."),
+    (0xC00000D8, "STATUS_CANT_WAIT", "Cant wait."),
+    (0xC00000D9, "STATUS_PIPE_EMPTY", "Pipe empty."),
+    (0xC00000DA, "STATUS_CANT_ACCESS_DOMAIN_INFO", "Cant access domain info."),
+    (0xC00000DB, "STATUS_CANT_TERMINATE_SELF", "Cant terminate self."),
+    (0xC00000DC, "STATUS_INVALID_SERVER_STATE", "Invalid server state."),
+    (0xC00000DD, "STATUS_INVALID_DOMAIN_STATE", "Invalid domain state."),
+    (0xC00000DE, "STATUS_INVALID_DOMAIN_ROLE", "Invalid domain role."),
+    (0xC00000DF, "STATUS_NO_SUCH_DOMAIN", "No such domain."),
+    (0xC00000E0, "STATUS_DOMAIN_EXISTS", "Domain exists."),
+    (0xC00000E1, "STATUS_DOMAIN_LIMIT_EXCEEDED", "Domain limit exceeded."),
+    (0xC00000E2, "STATUS_OPLOCK_NOT_GRANTED", "Oplock not granted."),
+    (0xC00000E3, "STATUS_INVALID_OPLOCK_PROTOCOL", "Invalid oplock protocol."),
+    (0xC00000E4, "STATUS_INTERNAL_DB_CORRUPTION", "Internal DB corruption."),
+    (0xC00000E5, "STATUS_INTERNAL_ERROR", "An internal error occurred."),
+    (0xC00000E6, "STATUS_GENERIC_NOT_MAPPED", "Generic not mapped."),
+    (0xC00000E7, "STATUS_BAD_DESCRIPTOR_FORMAT", "Bad descriptor format."),
+    (0xC00000E8, "STATUS_INVALID_USER_BUFFER", "Invalid user buffer."),
+    (0xC00000E9, "STATUS_UNEXPECTED_IO_ERROR", "Unexpected IO error."),
+    (0xC00000EA, "STATUS_UNEXPECTED_MM_CREATE_ERR", "Unexpected MM create err."),
+    (0xC00000EB, "STATUS_UNEXPECTED_MM_MAP_ERROR", "Unexpected MM map error."),
+    (0xC00000EC, "STATUS_UNEXPECTED_MM_EXTEND_ERR", "Unexpected MM extend err."),
+    (0xC00000ED, "STATUS_NOT_LOGON_PROCESS", "Not logon process."),
+    (0xC00000EE, "STATUS_LOGON_SESSION_EXISTS", "Logon session exists."),
+    (0xC00000EF, "STATUS_INVALID_PARAMETER_1", "Invalid parameter 1."),
+    (0xC00000F0, "STATUS_INVALID_PARAMETER_2", "Invalid parameter 2."),
+    (0xC00000F1, "STATUS_INVALID_PARAMETER_3", "Invalid parameter 3."),
+    (0xC00000F2, "STATUS_INVALID_PARAMETER_4", "Invalid parameter 4."),
+    (0xC00000F3, "STATUS_INVALID_PARAMETER_5", "Invalid parameter 5."),
+    (0xC00000F4, "STATUS_INVALID_PARAMETER_6", "Invalid parameter 6."),
+    (0xC00000F5, "STATUS_INVALID_PARAMETER_7", "Invalid parameter 7."),
+    (0xC00000F6, "STATUS_INVALID_PARAMETER_8", "Invalid parameter 8."),
+    (0xC00000F7, "STATUS_INVALID_PARAMETER_9", "Invalid parameter 9."),
+    (0xC00000F8, "STATUS_INVALID_PARAMETER_10", "Invalid parameter 10."),
+    (0xC00000F9, "STATUS_INVALID_PARAMETER_11", "Invalid parameter 11."),
+    (0xC00000FA, "STATUS_INVALID_PARAMETER_12", "Invalid parameter 12."),
+    (0xC00000FB, "STATUS_REDIRECTOR_NOT_STARTED", "Redirector not started."),
+    (0xC00000FC, "STATUS_REDIRECTOR_STARTED", "Redirector started."),
+    (0xC00000FD, "STATUS_STACK_OVERFLOW", "Stack overflow."),
+    (0xC00000FE, "STATUS_NO_SUCH_PACKAGE", "No such package."),
+    (0xC00000FF, "STATUS_BAD_FUNCTION_TABLE", "Bad function table."),
+    (0xC0000100, "STATUS_VARIABLE_NOT_FOUND", "Variable not found."),
+    (0xC0000101, "STATUS_DIRECTORY_NOT_EMPTY", "Directory not empty."),
+    (0xC0000102, "STATUS_FILE_CORRUPT_ERROR", "File corrupt error."),
+    (0xC0000103, "STATUS_NOT_A_DIRECTORY", "Not a directory."),
+    (0xC0000104, "STATUS_BAD_LOGON_SESSION_STATE", "Bad logon session state."),
+    (0xC0000105, "STATUS_LOGON_SESSION_COLLISION", "Logon session collision."),
+    (0xC0000106, "STATUS_NAME_TOO_LONG", "A specified name string is too long for its intended use."),
+    (0xC0000107, "STATUS_FILES_OPEN", "Files open."),
+    (0xC0000108, "STATUS_CONNECTION_IN_USE", "Connection in use."),
+    (0xC0000109, "STATUS_MESSAGE_NOT_FOUND", "Message not found."),
+    (0xC000010A, "STATUS_PROCESS_IS_TERMINATING", "Process is terminating."),
+    (0xC000010B, "STATUS_INVALID_LOGON_TYPE", "Invalid logon type."),
+    (0xC000010C, "STATUS_NO_GUID_TRANSLATION", "No GUID translation."),
+    (0xC000010D, "STATUS_CANNOT_IMPERSONATE", "Cannot impersonate."),
+    (0xC000010E, "STATUS_IMAGE_ALREADY_LOADED", "Image already loaded."),
+    (0xC000010F, "STATUS_ABIOS_NOT_PRESENT", "ABIOS not present."),
+    (0xC0000110, "STATUS_ABIOS_LID_NOT_EXIST", "ABIOS lid not exist."),
+    (0xC0000111, "STATUS_ABIOS_LID_ALREADY_OWNED", "ABIOS lid already owned."),
+    (0xC0000112, "STATUS_ABIOS_NOT_LID_OWNER", "ABIOS not lid owner."),
+    (0xC0000113, "STATUS_ABIOS_INVALID_COMMAND", "ABIOS invalid command."),
+    (0xC0000114, "STATUS_ABIOS_INVALID_LID", "ABIOS invalid lid."),
+    (0xC0000115, "STATUS_ABIOS_SELECTOR_NOT_AVAILABLE", "ABIOS selector not available."),
+    (0xC0000116, "STATUS_ABIOS_INVALID_SELECTOR", "ABIOS invalid selector."),
+    (0xC0000117, "STATUS_NO_LDT", "No LDT."),
+    (0xC0000118, "STATUS_INVALID_LDT_SIZE", "Invalid LDT size."),
+    (0xC0000119, "STATUS_INVALID_LDT_OFFSET", "Invalid LDT offset."),
+    (0xC000011A, "STATUS_INVALID_LDT_DESCRIPTOR", "Invalid LDT descriptor."),
+    (0xC000011B, "STATUS_INVALID_IMAGE_NE_FORMAT", "Invalid image ne format."),
+    (0xC000011C, "STATUS_RXACT_INVALID_STATE", "RXACT invalid state."),
+    (0xC000011D, "STATUS_RXACT_COMMIT_FAILURE", "RXACT commit failure."),
+    (0xC000011E, "STATUS_MAPPED_FILE_SIZE_ZERO", "Mapped file size zero."),
+    (0xC000011F, "STATUS_TOO_MANY_OPENED_FILES", "Too many opened files."),
+    (0xC0000120, "STATUS_CANCELLED", "The I/O request was canceled."),
+    (0xC0000121, "STATUS_CANNOT_DELETE", "Cannot delete."),
+    (0xC0000122, "STATUS_INVALID_COMPUTER_NAME", "Invalid computer name."),
+    (0xC0000123, "STATUS_FILE_DELETED", "File deleted."),
+    (0xC0000124, "STATUS_SPECIAL_ACCOUNT", "Special account."),
+    (0xC0000125, "STATUS_SPECIAL_GROUP", "Special group."),
+    (0xC0000126, "STATUS_SPECIAL_USER", "Special user."),
+    (0xC0000127, "STATUS_MEMBERS_PRIMARY_GROUP", "Members primary group."),
+    (0xC0000128, "STATUS_FILE_CLOSED", "File closed."),
+    (0xC0000129, "STATUS_TOO_MANY_THREADS", "Too many threads."),
+    (0xC000012A, "STATUS_THREAD_NOT_IN_PROCESS", "Thread not in process."),
+    (0xC000012B, "STATUS_TOKEN_ALREADY_IN_USE", "Token already in use."),
+    (0xC000012C, "STATUS_PAGEFILE_QUOTA_EXCEEDED", "Pagefile quota exceeded."),
+    (0xC000012D, "STATUS_COMMITMENT_LIMIT", "Commitment limit."),
+    (0xC000012E, "STATUS_INVALID_IMAGE_LE_FORMAT", "Invalid image le format."),
+    (0xC000012F, "STATUS_INVALID_IMAGE_NOT_MZ", "Invalid image not MZ."),
+    (0xC0000130, "STATUS_INVALID_IMAGE_PROTECT", "Invalid image protect."),
+    (0xC0000131, "STATUS_INVALID_IMAGE_WIN_16", "Invalid image win 16."),
+    (0xC0000132, "STATUS_LOGON_SERVER_CONFLICT", "Logon server conflict."),
+    (0xC0000133, "STATUS_TIME_DIFFERENCE_AT_DC", "Time difference at DC."),
+    (0xC0000134, "STATUS_SYNCHRONIZATION_REQUIRED", "Synchronization required."),
+    (0xC0000135, "STATUS_DLL_NOT_FOUND", "DLL not found."),
+    (0xC0000136, "STATUS_OPEN_FAILED", "Open failed."),
+    (0xC0000137, "STATUS_IO_PRIVILEGE_FAILED", "IO privilege failed."),
+    (0xC0000138, "STATUS_ORDINAL_NOT_FOUND", "Ordinal not found."),
+    (0xC0000139, "STATUS_ENTRYPOINT_NOT_FOUND", "Entrypoint not found."),
+    (0xC000013A, "STATUS_CONTROL_C_EXIT", "Control C exit."),
+    (0xC000013B, "STATUS_LOCAL_DISCONNECT", "Local disconnect."),
+    (0xC000013C, "STATUS_REMOTE_DISCONNECT", "Remote disconnect."),
+    (0xC000013D, "STATUS_REMOTE_RESOURCES", "Remote resources."),
+    (0xC000013E, "STATUS_LINK_FAILED", "Link failed."),
+    (0xC000013F, "STATUS_LINK_TIMEOUT", "Link timeout."),
+    (0xC0000140, "STATUS_INVALID_CONNECTION", "Invalid connection."),
+    (0xC0000141, "STATUS_INVALID_ADDRESS", "Invalid address."),
+    (0xC0000142, "STATUS_DLL_INIT_FAILED", "DLL init failed."),
+    (0xC0000143, "STATUS_MISSING_SYSTEMFILE", "Missing systemfile."),
+    (0xC0000144, "STATUS_UNHANDLED_EXCEPTION", "Unhandled exception."),
+    (0xC0000145, "STATUS_APP_INIT_FAILURE", "App init failure."),
+    (0xC0000146, "STATUS_PAGEFILE_CREATE_FAILED", "Pagefile create failed."),
+    (0xC0000147, "STATUS_NO_PAGEFILE", "No pagefile."),
+    (0xC0000148, "STATUS_INVALID_LEVEL", "Invalid level."),
+    (0xC0000149, "STATUS_WRONG_PASSWORD_CORE", "Wrong password core."),
+    (0xC000014A, "STATUS_ILLEGAL_FLOAT_CONTEXT", "Illegal float context."),
+    (0xC000014B, "STATUS_PIPE_BROKEN", "Pipe broken."),
+    (0xC000014C, "STATUS_REGISTRY_CORRUPT", "Registry corrupt."),
+    (0xC000014D, "STATUS_REGISTRY_IO_FAILED", "Registry IO failed."),
+    (0xC000014E, "STATUS_NO_EVENT_PAIR", "No event pair."),
+    (0xC000014F, "STATUS_UNRECOGNIZED_VOLUME", "Unrecognized volume."),
+    (0xC0000150, "STATUS_SERIAL_NO_DEVICE_INITED", "Serial no device inited."),
+    (0xC0000151, "STATUS_NO_SUCH_ALIAS", "No such alias."),
+    (0xC0000152, "STATUS_MEMBER_NOT_IN_ALIAS", "Member not in alias."),
+    (0xC0000153, "STATUS_MEMBER_IN_ALIAS", "Member in alias."),
+    (0xC0000154, "STATUS_ALIAS_EXISTS", "Alias exists."),
+    (0xC0000155, "STATUS_LOGON_NOT_GRANTED", "Logon not granted."),
+    (0xC0000156, "STATUS_TOO_MANY_SECRETS", "Too many secrets."),
+    (0xC0000157, "STATUS_SECRET_TOO_LONG", "Secret too long."),
+    (0xC0000158, "STATUS_INTERNAL_DB_ERROR", "Internal DB error."),
+    (0xC0000159, "STATUS_FULLSCREEN_MODE", "Fullscreen mode."),
+    (0xC000015A, "STATUS_TOO_MANY_CONTEXT_IDS", "Too many context ids."),
+    (0xC000015C, "STATUS_NOT_REGISTRY_FILE", "Not registry file."),
+    (0xC000015D, "STATUS_NT_CROSS_ENCRYPTION_REQUIRED", "NT cross encryption required."),
+    (0xC000015E, "STATUS_DOMAIN_CTRLR_CONFIG_ERROR", "Domain CTRLR config error."),
+    (0xC000015F, "STATUS_FT_MISSING_MEMBER", "FT missing member."),
+    (0xC0000160, "STATUS_ILL_FORMED_SERVICE_ENTRY", "Ill formed service entry."),
+    (0xC0000161, "STATUS_ILLEGAL_CHARACTER", "Illegal character."),
+    (0xC0000162, "STATUS_UNMAPPABLE_CHARACTER", "Unmappable character."),
+    (0xC0000163, "STATUS_UNDEFINED_CHARACTER", "Undefined character."),
+    (0xC0000164, "STATUS_FLOPPY_VOLUME", "Floppy volume."),
+    (0xC0000165, "STATUS_FLOPPY_ID_MARK_NOT_FOUND", "Floppy ID mark not found."),
+    (0xC0000166, "STATUS_FLOPPY_WRONG_CYLINDER", "Floppy wrong cylinder."),
+    (0xC0000167, "STATUS_FLOPPY_UNKNOWN_ERROR", "Floppy unknown error."),
+    (0xC0000168, "STATUS_FLOPPY_BAD_REGISTERS", "Floppy bad registers."),
+    (0xC0000169, "STATUS_DISK_RECALIBRATE_FAILED", "Disk recalibrate failed."),
+    (0xC000016A, "STATUS_DISK_OPERATION_FAILED", "Disk operation failed."),
+    (0xC000016B, "STATUS_DISK_RESET_FAILED", "Disk reset failed."),
+    (0xC000016C, "STATUS_SHARED_IRQ_BUSY", "Shared irq busy."),
+    (0xC000016D, "STATUS_FT_ORPHANING", "FT orphaning."),
+    (0xC000016E, "STATUS_BIOS_FAILED_TO_CONNECT_INTERRUPT", "Bios failed to connect interrupt."),
+    (0xC0000172, "STATUS_PARTITION_FAILURE", "Partition failure."),
+    (0xC0000173, "STATUS_INVALID_BLOCK_LENGTH", "Invalid block length."),
+    (0xC0000174, "STATUS_DEVICE_NOT_PARTITIONED", "Device not partitioned."),
+    (0xC0000175, "STATUS_UNABLE_TO_LOCK_MEDIA", "Unable to lock media."),
+    (0xC0000176, "STATUS_UNABLE_TO_UNLOAD_MEDIA", "Unable to unload media."),
+    (0xC0000177, "STATUS_EOM_OVERFLOW", "Eom overflow."),
+    (0xC0000178, "STATUS_NO_MEDIA", "No media."),
+    (0xC000017A, "STATUS_NO_SUCH_MEMBER", "No such member."),
+    (0xC000017B, "STATUS_INVALID_MEMBER", "Invalid member."),
+    (0xC000017C, "STATUS_KEY_DELETED", "Key deleted."),
+    (0xC000017D, "STATUS_NO_LOG_SPACE", "No log space."),
+    (0xC000017E, "STATUS_TOO_MANY_SIDS", "Too many sids."),
+    (0xC000017F, "STATUS_LM_CROSS_ENCRYPTION_REQUIRED", "LM cross encryption required."),
+    (0xC0000180, "STATUS_KEY_HAS_CHILDREN", "Key has children."),
+    (0xC0000181, "STATUS_CHILD_MUST_BE_VOLATILE", "Child must be volatile."),
+    (0xC0000182, "STATUS_DEVICE_CONFIGURATION_ERROR", "Device configuration error."),
+    (0xC0000183, "STATUS_DRIVER_INTERNAL_ERROR", "Driver internal error."),
+    (0xC0000184, "STATUS_INVALID_DEVICE_STATE", "Invalid device state."),
+    (0xC0000185, "STATUS_IO_DEVICE_ERROR", "IO device error."),
+    (0xC0000186, "STATUS_DEVICE_PROTOCOL_ERROR", "Device protocol error."),
+    (0xC0000187, "STATUS_BACKUP_CONTROLLER", "Backup controller."),
+    (0xC0000188, "STATUS_LOG_FILE_FULL", "Log file full."),
+    (0xC0000189, "STATUS_TOO_LATE", "Too late."),
+    (0xC000018A, "STATUS_NO_TRUST_LSA_SECRET", "No trust LSA secret."),
+    (0xC000018B, "STATUS_NO_TRUST_SAM_ACCOUNT", "No trust SAM account."),
+    (0xC000018C, "STATUS_TRUSTED_DOMAIN_FAILURE", "Trusted domain failure."),
+    (0xC000018D, "STATUS_TRUSTED_RELATIONSHIP_FAILURE", "Trusted relationship failure."),
+    (0xC000018E, "STATUS_EVENTLOG_FILE_CORRUPT", "Eventlog file corrupt."),
+    (0xC000018F, "STATUS_EVENTLOG_CANT_START", "Eventlog cant start."),
+    (0xC0000190, "STATUS_TRUST_FAILURE", "Trust failure."),
+    (0xC0000191, "STATUS_MUTANT_LIMIT_EXCEEDED", "Mutant limit exceeded."),
+    (0xC0000192, "STATUS_NETLOGON_NOT_STARTED", "Netlogon not started."),
+    (0xC0000194, "STATUS_POSSIBLE_DEADLOCK", "Possible deadlock."),
+    (0xC0000195, "STATUS_NETWORK_CREDENTIAL_CONFLICT", "Network credential conflict."),
+    (0xC0000196, "STATUS_REMOTE_SESSION_LIMIT", "Remote session limit."),
+    (0xC0000197, "STATUS_EVENTLOG_FILE_CHANGED", "Eventlog file changed."),
+    (0xC0000198, "STATUS_NOLOGON_INTERDOMAIN_TRUST_ACCOUNT", "Nologon interdomain trust account."),
+    (0xC0000199, "STATUS_NOLOGON_WORKSTATION_TRUST_ACCOUNT", "Nologon workstation trust account."),
+    (0xC000019A, "STATUS_NOLOGON_SERVER_TRUST_ACCOUNT", "Nologon server trust account."),
+    (0xC000019B, "STATUS_DOMAIN_TRUST_INCONSISTENT", "Domain trust inconsistent."),
+    (0xC000019C, "STATUS_FS_DRIVER_REQUIRED", "FS driver required."),
+    (0xC000019D, "STATUS_IMAGE_ALREADY_LOADED_AS_DLL", "Image already loaded as DLL."),
+    (0xC000019E, "STATUS_INCOMPATIBLE_WITH_GLOBAL_SHORT_NAME_REGISTRY_SETTING", "Incompatible with global short name registry setting."),
+    (0xC000019F, "STATUS_SHORT_NAMES_NOT_ENABLED_ON_VOLUME", "Short names not enabled on volume."),
+    (0xC00001A0, "STATUS_SECURITY_STREAM_IS_INCONSISTENT", "Security stream is inconsistent."),
+    (0xC00001A1, "STATUS_INVALID_LOCK_RANGE", "Invalid lock range."),
+    (0xC00001A2, "STATUS_INVALID_ACE_CONDITION", "Invalid ace condition."),
+    (0xC00001A3, "STATUS_IMAGE_SUBSYSTEM_NOT_PRESENT", "Image subsystem not present."),
+    (0xC00001A4, "STATUS_NOTIFICATION_GUID_ALREADY_DEFINED", "Notification GUID already defined."),
+    (0xC00001A5, "STATUS_INVALID_EXCEPTION_HANDLER", "Invalid exception handler."),
+    (0xC00001A6, "STATUS_DUPLICATE_PRIVILEGES", "Duplicate privileges."),
+    (0xC00001A7, "STATUS_NOT_ALLOWED_ON_SYSTEM_FILE", "Not allowed on system file."),
+    (0xC00001A8, "STATUS_REPAIR_NEEDED", "Repair needed."),
+    (0xC00001A9, "STATUS_QUOTA_NOT_ENABLED", "Quota not enabled."),
+    (0xC00001AA, "STATUS_NO_APPLICATION_PACKAGE", "No application package."),
+    (0xC00001AB, "STATUS_FILE_METADATA_OPTIMIZATION_IN_PROGRESS", "File metadata optimization in progress."),
+    (0xC00001AC, "STATUS_NOT_SAME_OBJECT", "Not same object."),
+    (0xC00001AD, "STATUS_FATAL_MEMORY_EXHAUSTION", "Fatal memory exhaustion."),
+    (0xC00001AE, "STATUS_ERROR_PROCESS_NOT_IN_JOB", "Error process not in job."),
+    (0xC00001AF, "STATUS_CPU_SET_INVALID", "CPU set invalid."),
+    (0xC00001B0, "STATUS_IO_DEVICE_INVALID_DATA", "IO device invalid data."),
+    (0xC00001B1, "STATUS_IO_UNALIGNED_WRITE", "IO unaligned write."),
+    (0xC00001B2, "STATUS_CONTROL_STACK_VIOLATION", "Control stack violation."),
+    (0xC00001B3, "STATUS_WEAK_WHFBKEY_BLOCKED", "Weak whfbkey blocked."),
+    (0xC00001B4, "STATUS_SERVER_TRANSPORT_CONFLICT", "Server transport conflict."),
+    (0xC00001B5, "STATUS_CERTIFICATE_VALIDATION_PREFERENCE_CONFLICT", "Certificate validation preference conflict."),
+    (0xC0000201, "STATUS_NETWORK_OPEN_RESTRICTION", "Network open restriction."),
+    (0xC0000202, "STATUS_NO_USER_SESSION_KEY", "No user session key."),
+    (0xC0000203, "STATUS_USER_SESSION_DELETED", "User session deleted."),
+    (0xC0000204, "STATUS_RESOURCE_LANG_NOT_FOUND", "Resource lang not found."),
+    (0xC0000205, "STATUS_INSUFF_SERVER_RESOURCES", "Insuff server resources."),
+    (0xC0000206, "STATUS_INVALID_BUFFER_SIZE", "Invalid buffer size."),
+    (0xC0000207, "STATUS_INVALID_ADDRESS_COMPONENT", "Invalid address component."),
+    (0xC0000208, "STATUS_INVALID_ADDRESS_WILDCARD", "Invalid address wildcard."),
+    (0xC0000209, "STATUS_TOO_MANY_ADDRESSES", "Too many addresses."),
+    (0xC000020A, "STATUS_ADDRESS_ALREADY_EXISTS", "Address already exists."),
+    (0xC000020B, "STATUS_ADDRESS_CLOSED", "Address closed."),
+    (0xC000020C, "STATUS_CONNECTION_DISCONNECTED", "Connection disconnected."),
+    (0xC000020D, "STATUS_CONNECTION_RESET", "Connection reset."),
+    (0xC000020E, "STATUS_TOO_MANY_NODES", "Too many nodes."),
+    (0xC000020F, "STATUS_TRANSACTION_ABORTED", "Transaction aborted."),
+    (0xC0000210, "STATUS_TRANSACTION_TIMED_OUT", "Transaction timed out."),
+    (0xC0000211, "STATUS_TRANSACTION_NO_RELEASE", "Transaction no release."),
+    (0xC0000212, "STATUS_TRANSACTION_NO_MATCH", "Transaction no match."),
+    (0xC0000213, "STATUS_TRANSACTION_RESPONDED", "Transaction responded."),
+    (0xC0000214, "STATUS_TRANSACTION_INVALID_ID", "Transaction invalid ID."),
+    (0xC0000215, "STATUS_TRANSACTION_INVALID_TYPE", "Transaction invalid type."),
+    (0xC0000216, "STATUS_NOT_SERVER_SESSION", "Not server session."),
+    (0xC0000217, "STATUS_NOT_CLIENT_SESSION", "Not client session."),
+    (0xC0000218, "STATUS_CANNOT_LOAD_REGISTRY_FILE", "Cannot load registry file."),
+    (0xC0000219, "STATUS_DEBUG_ATTACH_FAILED", "Debug attach failed."),
+    (0xC000021A, "STATUS_SYSTEM_PROCESS_TERMINATED", "System process terminated."),
+    (0xC000021B, "STATUS_DATA_NOT_ACCEPTED", "Data not accepted."),
+    (0xC000021C, "STATUS_NO_BROWSER_SERVERS_FOUND", "No browser servers found."),
+    (0xC000021D, "STATUS_VDM_HARD_ERROR", "VDM hard error."),
+    (0xC000021E, "STATUS_DRIVER_CANCEL_TIMEOUT", "Driver cancel timeout."),
+    (0xC000021F, "STATUS_REPLY_MESSAGE_MISMATCH", "Reply message mismatch."),
+    (0xC0000220, "STATUS_MAPPED_ALIGNMENT", "Mapped alignment."),
+    (0xC0000221, "STATUS_IMAGE_CHECKSUM_MISMATCH", "Image checksum mismatch."),
+    (0xC0000222, "STATUS_LOST_WRITEBEHIND_DATA", "Lost writebehind data."),
+    (0xC0000223, "STATUS_CLIENT_SERVER_PARAMETERS_INVALID", "Client server parameters invalid."),
+    (0xC0000225, "STATUS_NOT_FOUND", "The object was not found."),
+    (0xC0000226, "STATUS_NOT_TINY_STREAM", "Not tiny stream."),
+    (0xC0000227, "STATUS_RECOVERY_FAILURE", "Recovery failure."),
+    (0xC0000228, "STATUS_STACK_OVERFLOW_READ", "Stack overflow read."),
+    (0xC0000229, "STATUS_FAIL_CHECK", "Fail check."),
+    (0xC000022A, "STATUS_DUPLICATE_OBJECTID", "Duplicate objectid."),
+    (0xC000022B, "STATUS_OBJECTID_EXISTS", "Objectid exists."),
+    (0xC000022C, "STATUS_CONVERT_TO_LARGE", "Convert to large."),
+    (0xC000022D, "STATUS_RETRY", "Retry."),
+    (0xC000022E, "STATUS_FOUND_OUT_OF_SCOPE", "Found out of scope."),
+    (0xC000022F, "STATUS_ALLOCATE_BUCKET", "Allocate bucket."),
+    (0xC0000230, "STATUS_PROPSET_NOT_FOUND", "Propset not found."),
+    (0xC0000231, "STATUS_MARSHALL_OVERFLOW", "Marshall overflow."),
+    (0xC0000232, "STATUS_INVALID_VARIANT", "Invalid variant."),
+    (0xC0000233, "STATUS_DOMAIN_CONTROLLER_NOT_FOUND", "Domain controller not found."),
+    (0xC0000235, "STATUS_HANDLE_NOT_CLOSABLE", "Handle not closable."),
+    (0xC0000236, "STATUS_CONNECTION_REFUSED", "Connection refused."),
+    (0xC0000237, "STATUS_GRACEFUL_DISCONNECT", "Graceful disconnect."),
+    (0xC0000238, "STATUS_ADDRESS_ALREADY_ASSOCIATED", "Address already associated."),
+    (0xC0000239, "STATUS_ADDRESS_NOT_ASSOCIATED", "Address not associated."),
+    (0xC000023A, "STATUS_CONNECTION_INVALID", "Connection invalid."),
+    (0xC000023B, "STATUS_CONNECTION_ACTIVE", "Connection active."),
+    (0xC000023C, "STATUS_NETWORK_UNREACHABLE", "Network unreachable."),
+    (0xC000023D, "STATUS_HOST_UNREACHABLE", "Host unreachable."),
+    (0xC000023E, "STATUS_PROTOCOL_UNREACHABLE", "Protocol unreachable."),
+    (0xC000023F, "STATUS_PORT_UNREACHABLE", "Port unreachable."),
+    (0xC0000240, "STATUS_REQUEST_ABORTED", "Request aborted."),
+    (0xC0000241, "STATUS_CONNECTION_ABORTED", "Connection aborted."),
+    (0xC0000242, "STATUS_BAD_COMPRESSION_BUFFER", "Bad compression buffer."),
+    (0xC0000243, "STATUS_USER_MAPPED_FILE", "User mapped file."),
+    (0xC0000244, "STATUS_AUDIT_FAILED", "Audit failed."),
+    (0xC0000245, "STATUS_TIMER_RESOLUTION_NOT_SET", "Timer resolution not set."),
+    (0xC0000246, "STATUS_CONNECTION_COUNT_LIMIT", "Connection count limit."),
+    (0xC0000247, "STATUS_LOGIN_TIME_RESTRICTION", "Login time restriction."),
+    (0xC0000248, "STATUS_LOGIN_WKSTA_RESTRICTION", "Login wksta restriction."),
+    (0xC0000249, "STATUS_IMAGE_MP_UP_MISMATCH", "Image MP up mismatch."),
+    (0xC0000250, "STATUS_INSUFFICIENT_LOGON_INFO", "Insufficient logon info."),
+    (0xC0000251, "STATUS_BAD_DLL_ENTRYPOINT", "Bad DLL entrypoint."),
+    (0xC0000252, "STATUS_BAD_SERVICE_ENTRYPOINT", "Bad service entrypoint."),
+    (0xC0000253, "STATUS_LPC_REPLY_LOST", "LPC reply lost."),
+    (0xC0000254, "STATUS_IP_ADDRESS_CONFLICT1", "IP address CONFLICT1."),
+    (0xC0000255, "STATUS_IP_ADDRESS_CONFLICT2", "IP address CONFLICT2."),
+    (0xC0000256, "STATUS_REGISTRY_QUOTA_LIMIT", "Registry quota limit."),
+    (0xC0000257, "STATUS_PATH_NOT_COVERED", "Path not covered."),
+    (0xC0000258, "STATUS_NO_CALLBACK_ACTIVE", "No callback active."),
+    (0xC0000259, "STATUS_LICENSE_QUOTA_EXCEEDED", "License quota exceeded."),
+    (0xC000025A, "STATUS_PWD_TOO_SHORT", "PWD too short."),
+    (0xC000025B, "STATUS_PWD_TOO_RECENT", "PWD too recent."),
+    (0xC000025C, "STATUS_PWD_HISTORY_CONFLICT", "PWD history conflict."),
+    (0xC000025E, "STATUS_PLUGPLAY_NO_DEVICE", "Plugplay no device."),
+    (0xC000025F, "STATUS_UNSUPPORTED_COMPRESSION", "Unsupported compression."),
+    (0xC0000260, "STATUS_INVALID_HW_PROFILE", "Invalid HW profile."),
+    (0xC0000261, "STATUS_INVALID_PLUGPLAY_DEVICE_PATH", "Invalid plugplay device path."),
+    (0xC0000262, "STATUS_DRIVER_ORDINAL_NOT_FOUND", "Driver ordinal not found."),
+    (0xC0000263, "STATUS_DRIVER_ENTRYPOINT_NOT_FOUND", "Driver entrypoint not found."),
+    (0xC0000264, "STATUS_RESOURCE_NOT_OWNED", "Resource not owned."),
+    (0xC0000265, "STATUS_TOO_MANY_LINKS", "Too many links."),
+    (0xC0000266, "STATUS_QUOTA_LIST_INCONSISTENT", "Quota list inconsistent."),
+    (0xC0000267, "STATUS_FILE_IS_OFFLINE", "File is offline."),
+    (0xC0000268, "STATUS_EVALUATION_EXPIRATION", "Evaluation expiration."),
+    (0xC0000269, "STATUS_ILLEGAL_DLL_RELOCATION", "Illegal DLL relocation."),
+    (0xC000026A, "STATUS_LICENSE_VIOLATION", "License violation."),
+    (0xC000026B, "STATUS_DLL_INIT_FAILED_LOGOFF", "DLL init failed logoff."),
+    (0xC000026C, "STATUS_DRIVER_UNABLE_TO_LOAD", "Driver unable to load."),
+    (0xC000026D, "STATUS_DFS_UNAVAILABLE", "DFS unavailable."),
+    (0xC000026E, "STATUS_VOLUME_DISMOUNTED", "Volume dismounted."),
+    (0xC000026F, "STATUS_WX86_INTERNAL_ERROR", "WX86 internal error."),
+    (0xC0000270, "STATUS_WX86_FLOAT_STACK_CHECK", "WX86 float stack check."),
+    (0xC0000271, "STATUS_VALIDATE_CONTINUE", "Validate continue."),
+    (0xC0000272, "STATUS_NO_MATCH", "No match."),
+    (0xC0000273, "STATUS_NO_MORE_MATCHES", "No more matches."),
+    (0xC0000275, "STATUS_NOT_A_REPARSE_POINT", "Not a reparse point."),
+    (0xC0000276, "STATUS_IO_REPARSE_TAG_INVALID", "IO reparse tag invalid."),
+    (0xC0000277, "STATUS_IO_REPARSE_TAG_MISMATCH", "IO reparse tag mismatch."),
+    (0xC0000278, "STATUS_IO_REPARSE_DATA_INVALID", "IO reparse data invalid."),
+    (0xC0000279, "STATUS_IO_REPARSE_TAG_NOT_HANDLED", "IO reparse tag not handled."),
+    (0xC000027A, "STATUS_PWD_TOO_LONG", "PWD too long."),
+    (0xC000027B, "STATUS_STOWED_EXCEPTION", "Stowed exception."),
+    (0xC000027C, "STATUS_CONTEXT_STOWED_EXCEPTION", "Context stowed exception."),
+    (0xC0000280, "STATUS_REPARSE_POINT_NOT_RESOLVED", "Reparse point not resolved."),
+    (0xC0000281, "STATUS_DIRECTORY_IS_A_REPARSE_POINT", "Directory is a reparse point."),
+    (0xC0000282, "STATUS_RANGE_LIST_CONFLICT", "Range list conflict."),
+    (0xC0000283, "STATUS_SOURCE_ELEMENT_EMPTY", "Source element empty."),
+    (0xC0000284, "STATUS_DESTINATION_ELEMENT_FULL", "Destination element full."),
+    (0xC0000285, "STATUS_ILLEGAL_ELEMENT_ADDRESS", "Illegal element address."),
+    (0xC0000286, "STATUS_MAGAZINE_NOT_PRESENT", "Magazine not present."),
+    (0xC0000287, "STATUS_REINITIALIZATION_NEEDED", "Reinitialization needed."),
+    (0xC000028A, "STATUS_ENCRYPTION_FAILED", "Encryption failed."),
+    (0xC000028B, "STATUS_DECRYPTION_FAILED", "Decryption failed."),
+    (0xC000028C, "STATUS_RANGE_NOT_FOUND", "Range not found."),
+    (0xC000028D, "STATUS_NO_RECOVERY_POLICY", "No recovery policy."),
+    (0xC000028E, "STATUS_NO_EFS", "No EFS."),
+    (0xC000028F, "STATUS_WRONG_EFS", "Wrong EFS."),
+    (0xC0000290, "STATUS_NO_USER_KEYS", "No user keys."),
+    (0xC0000291, "STATUS_FILE_NOT_ENCRYPTED", "File not encrypted."),
+    (0xC0000292, "STATUS_NOT_EXPORT_FORMAT", "Not export format."),
+    (0xC0000293, "STATUS_FILE_ENCRYPTED", "File encrypted."),
+    (0xC0000295, "STATUS_WMI_GUID_NOT_FOUND", "WMI GUID not found."),
+    (0xC0000296, "STATUS_WMI_INSTANCE_NOT_FOUND", "WMI instance not found."),
+    (0xC0000297, "STATUS_WMI_ITEMID_NOT_FOUND", "WMI itemid not found."),
+    (0xC0000298, "STATUS_WMI_TRY_AGAIN", "WMI try again."),
+    (0xC0000299, "STATUS_SHARED_POLICY", "Shared policy."),
+    (0xC000029A, "STATUS_POLICY_OBJECT_NOT_FOUND", "Policy object not found."),
+    (0xC000029B, "STATUS_POLICY_ONLY_IN_DS", "Policy only in DS."),
+    (0xC000029C, "STATUS_VOLUME_NOT_UPGRADED", "Volume not upgraded."),
+    (0xC000029D, "STATUS_REMOTE_STORAGE_NOT_ACTIVE", "Remote storage not active."),
+    (0xC000029E, "STATUS_REMOTE_STORAGE_MEDIA_ERROR", "Remote storage media error."),
+    (0xC000029F, "STATUS_NO_TRACKING_SERVICE", "No tracking service."),
+    (0xC00002A0, "STATUS_SERVER_SID_MISMATCH", "Server SID mismatch."),
+    (0xC00002A1, "STATUS_DS_NO_ATTRIBUTE_OR_VALUE", "DS no attribute or value."),
+    (0xC00002A2, "STATUS_DS_INVALID_ATTRIBUTE_SYNTAX", "DS invalid attribute syntax."),
+    (0xC00002A3, "STATUS_DS_ATTRIBUTE_TYPE_UNDEFINED", "DS attribute type undefined."),
+    (0xC00002A4, "STATUS_DS_ATTRIBUTE_OR_VALUE_EXISTS", "DS attribute or value exists."),
+    (0xC00002A5, "STATUS_DS_BUSY", "DS busy."),
+    (0xC00002A6, "STATUS_DS_UNAVAILABLE", "DS unavailable."),
+    (0xC00002A7, "STATUS_DS_NO_RIDS_ALLOCATED", "DS no rids allocated."),
+    (0xC00002A8, "STATUS_DS_NO_MORE_RIDS", "DS no more rids."),
+    (0xC00002A9, "STATUS_DS_INCORRECT_ROLE_OWNER", "DS incorrect role owner."),
+    (0xC00002AA, "STATUS_DS_RIDMGR_INIT_ERROR", "DS ridmgr init error."),
+    (0xC00002AB, "STATUS_DS_OBJ_CLASS_VIOLATION", "DS obj class violation."),
+    (0xC00002AC, "STATUS_DS_CANT_ON_NON_LEAF", "DS cant on non leaf."),
+    (0xC00002AD, "STATUS_DS_CANT_ON_RDN", "DS cant on RDN."),
+    (0xC00002AE, "STATUS_DS_CANT_MOD_OBJ_CLASS", "DS cant mod obj class."),
+    (0xC00002AF, "STATUS_DS_CROSS_DOM_MOVE_FAILED", "DS cross dom move failed."),
+    (0xC00002B0, "STATUS_DS_GC_NOT_AVAILABLE", "DS GC not available."),
+    (0xC00002B1, "STATUS_DIRECTORY_SERVICE_REQUIRED", "Directory service required."),
+    (0xC00002B2, "STATUS_REPARSE_ATTRIBUTE_CONFLICT", "Reparse attribute conflict."),
+    (0xC00002B3, "STATUS_CANT_ENABLE_DENY_ONLY", "Cant enable deny only."),
+    (0xC00002B4, "STATUS_FLOAT_MULTIPLE_FAULTS", "Float multiple faults."),
+    (0xC00002B5, "STATUS_FLOAT_MULTIPLE_TRAPS", "Float multiple traps."),
+    (0xC00002B6, "STATUS_DEVICE_REMOVED", "Device removed."),
+    (0xC00002B7, "STATUS_JOURNAL_DELETE_IN_PROGRESS", "Journal delete in progress."),
+    (0xC00002B8, "STATUS_JOURNAL_NOT_ACTIVE", "Journal not active."),
+    (0xC00002B9, "STATUS_NOINTERFACE", "Nointerface."),
+    (0xC00002BA, "STATUS_DS_RIDMGR_DISABLED", "DS ridmgr disabled."),
+    (0xC00002C1, "STATUS_DS_ADMIN_LIMIT_EXCEEDED", "DS admin limit exceeded."),
+    (0xC00002C2, "STATUS_DRIVER_FAILED_SLEEP", "Driver failed sleep."),
+    (0xC00002C3, "STATUS_MUTUAL_AUTHENTICATION_FAILED", "Mutual authentication failed."),
+    (0xC00002C4, "STATUS_CORRUPT_SYSTEM_FILE", "Corrupt system file."),
+    (0xC00002C5, "STATUS_DATATYPE_MISALIGNMENT_ERROR", "Datatype misalignment error."),
+    (0xC00002C6, "STATUS_WMI_READ_ONLY", "WMI read only."),
+    (0xC00002C7, "STATUS_WMI_SET_FAILURE", "WMI set failure."),
+    (0xC00002C8, "STATUS_COMMITMENT_MINIMUM", "Commitment minimum."),
+    (0xC00002C9, "STATUS_REG_NAT_CONSUMPTION", "Reg nat consumption."),
+    (0xC00002CA, "STATUS_TRANSPORT_FULL", "Transport full."),
+    (0xC00002CB, "STATUS_DS_SAM_INIT_FAILURE", "DS SAM init failure."),
+    (0xC00002CC, "STATUS_ONLY_IF_CONNECTED", "Only if connected."),
+    (0xC00002CD, "STATUS_DS_SENSITIVE_GROUP_VIOLATION", "DS sensitive group violation."),
+    (0xC00002CE, "STATUS_PNP_RESTART_ENUMERATION", "PNP restart enumeration."),
+    (0xC00002CF, "STATUS_JOURNAL_ENTRY_DELETED", "Journal entry deleted."),
+    (0xC00002D0, "STATUS_DS_CANT_MOD_PRIMARYGROUPID", "DS cant mod primarygroupid."),
+    (0xC00002D1, "STATUS_SYSTEM_IMAGE_BAD_SIGNATURE", "System image bad signature."),
+    (0xC00002D2, "STATUS_PNP_REBOOT_REQUIRED", "PNP reboot required."),
+    (0xC00002D3, "STATUS_POWER_STATE_INVALID", "Power state invalid."),
+    (0xC00002D4, "STATUS_DS_INVALID_GROUP_TYPE", "DS invalid group type."),
+    (0xC00002D5, "STATUS_DS_NO_NEST_GLOBALGROUP_IN_MIXEDDOMAIN", "DS no nest globalgroup in mixeddomain."),
+    (0xC00002D6, "STATUS_DS_NO_NEST_LOCALGROUP_IN_MIXEDDOMAIN", "DS no nest localgroup in mixeddomain."),
+    (0xC00002D7, "STATUS_DS_GLOBAL_CANT_HAVE_LOCAL_MEMBER", "DS global cant have local member."),
+    (0xC00002D8, "STATUS_DS_GLOBAL_CANT_HAVE_UNIVERSAL_MEMBER", "DS global cant have universal member."),
+    (0xC00002D9, "STATUS_DS_UNIVERSAL_CANT_HAVE_LOCAL_MEMBER", "DS universal cant have local member."),
+    (0xC00002DA, "STATUS_DS_GLOBAL_CANT_HAVE_CROSSDOMAIN_MEMBER", "DS global cant have crossdomain member."),
+    (0xC00002DB, "STATUS_DS_LOCAL_CANT_HAVE_CROSSDOMAIN_LOCAL_MEMBER", "DS local cant have crossdomain local member."),
+    (0xC00002DC, "STATUS_DS_HAVE_PRIMARY_MEMBERS", "DS have primary members."),
+    (0xC00002DD, "STATUS_WMI_NOT_SUPPORTED", "WMI not supported."),
+    (0xC00002DE, "STATUS_INSUFFICIENT_POWER", "Insufficient power."),
+    (0xC00002DF, "STATUS_SAM_NEED_BOOTKEY_PASSWORD", "SAM need bootkey password."),
+    (0xC00002E0, "STATUS_SAM_NEED_BOOTKEY_FLOPPY", "SAM need bootkey floppy."),
+    (0xC00002E1, "STATUS_DS_CANT_START", "DS cant start."),
+    (0xC00002E2, "STATUS_DS_INIT_FAILURE", "DS init failure."),
+    (0xC00002E3, "STATUS_SAM_INIT_FAILURE", "SAM init failure."),
+    (0xC00002E4, "STATUS_DS_GC_REQUIRED", "DS GC required."),
+    (0xC00002E5, "STATUS_DS_LOCAL_MEMBER_OF_LOCAL_ONLY", "DS local member of local only."),
+    (0xC00002E6, "STATUS_DS_NO_FPO_IN_UNIVERSAL_GROUPS", "DS no fpo in universal groups."),
+    (0xC00002E7, "STATUS_DS_MACHINE_ACCOUNT_QUOTA_EXCEEDED", "DS machine account quota exceeded."),
+    (0xC00002E8, "STATUS_MULTIPLE_FAULT_VIOLATION", "Multiple fault violation."),
+    (0xC00002E9, "STATUS_CURRENT_DOMAIN_NOT_ALLOWED", "Current domain not allowed."),
+    (0xC00002EA, "STATUS_CANNOT_MAKE", "Cannot make."),
+    (0xC00002EB, "STATUS_SYSTEM_SHUTDOWN", "System shutdown."),
+    (0xC00002EC, "STATUS_DS_INIT_FAILURE_CONSOLE", "DS init failure console."),
+    (0xC00002ED, "STATUS_DS_SAM_INIT_FAILURE_CONSOLE", "DS SAM init failure console."),
+    (0xC00002EE, "STATUS_UNFINISHED_CONTEXT_DELETED", "Unfinished context deleted."),
+    (0xC00002EF, "STATUS_NO_TGT_REPLY", "No TGT reply."),
+    (0xC00002F0, "STATUS_OBJECTID_NOT_FOUND", "Objectid not found."),
+    (0xC00002F1, "STATUS_NO_IP_ADDRESSES", "No IP addresses."),
+    (0xC00002F2, "STATUS_WRONG_CREDENTIAL_HANDLE", "Wrong credential handle."),
+    (0xC00002F3, "STATUS_CRYPTO_SYSTEM_INVALID", "Crypto system invalid."),
+    (0xC00002F4, "STATUS_MAX_REFERRALS_EXCEEDED", "Max referrals exceeded."),
+    (0xC00002F5, "STATUS_MUST_BE_KDC", "Must be KDC."),
+    (0xC00002F6, "STATUS_STRONG_CRYPTO_NOT_SUPPORTED", "Strong crypto not supported."),
+    (0xC00002F7, "STATUS_TOO_MANY_PRINCIPALS", "Too many principals."),
+    (0xC00002F8, "STATUS_NO_PA_DATA", "No pa data."),
+    (0xC00002F9, "STATUS_PKINIT_NAME_MISMATCH", "Pkinit name mismatch."),
+    (0xC00002FA, "STATUS_SMARTCARD_LOGON_REQUIRED", "Smartcard logon required."),
+    (0xC00002FB, "STATUS_KDC_INVALID_REQUEST", "KDC invalid request."),
+    (0xC00002FC, "STATUS_KDC_UNABLE_TO_REFER", "KDC unable to refer."),
+    (0xC00002FD, "STATUS_KDC_UNKNOWN_ETYPE", "KDC unknown etype."),
+    (0xC00002FE, "STATUS_SHUTDOWN_IN_PROGRESS", "Shutdown in progress."),
+    (0xC00002FF, "STATUS_SERVER_SHUTDOWN_IN_PROGRESS", "Server shutdown in progress."),
+    (0xC0000300, "STATUS_NOT_SUPPORTED_ON_SBS", "Not supported on SBS."),
+    (0xC0000301, "STATUS_WMI_GUID_DISCONNECTED", "WMI GUID disconnected."),
+    (0xC0000302, "STATUS_WMI_ALREADY_DISABLED", "WMI already disabled."),
+    (0xC0000303, "STATUS_WMI_ALREADY_ENABLED", "WMI already enabled."),
+    (0xC0000304, "STATUS_MFT_TOO_FRAGMENTED", "MFT too fragmented."),
+    (0xC0000305, "STATUS_COPY_PROTECTION_FAILURE", "Copy protection failure."),
+    (0xC0000306, "STATUS_CSS_AUTHENTICATION_FAILURE", "CSS authentication failure."),
+    (0xC0000307, "STATUS_CSS_KEY_NOT_PRESENT", "CSS key not present."),
+    (0xC0000308, "STATUS_CSS_KEY_NOT_ESTABLISHED", "CSS key not established."),
+    (0xC0000309, "STATUS_CSS_SCRAMBLED_SECTOR", "CSS scrambled sector."),
+    (0xC000030A, "STATUS_CSS_REGION_MISMATCH", "CSS region mismatch."),
+    (0xC000030B, "STATUS_CSS_RESETS_EXHAUSTED", "CSS resets exhausted."),
+    (0xC000030C, "STATUS_PASSWORD_CHANGE_REQUIRED", "Password change required."),
+    (0xC000030D, "STATUS_LOST_MODE_LOGON_RESTRICTION", "Lost mode logon restriction."),
+    (0xC0000320, "STATUS_PKINIT_FAILURE", "Pkinit failure."),
+    (0xC0000321, "STATUS_SMARTCARD_SUBSYSTEM_FAILURE", "Smartcard subsystem failure."),
+    (0xC0000322, "STATUS_NO_KERB_KEY", "No kerb key."),
+    (0xC0000350, "STATUS_HOST_DOWN", "Host down."),
+    (0xC0000351, "STATUS_UNSUPPORTED_PREAUTH", "Unsupported preauth."),
+    (0xC0000352, "STATUS_EFS_ALG_BLOB_TOO_BIG", "EFS alg blob too big."),
+    (0xC0000353, "STATUS_PORT_NOT_SET", "Port not set."),
+    (0xC0000354, "STATUS_DEBUGGER_INACTIVE", "Debugger inactive."),
+    (0xC0000355, "STATUS_DS_VERSION_CHECK_FAILURE", "DS version check failure."),
+    (0xC0000356, "STATUS_AUDITING_DISABLED", "Auditing disabled."),
+    (0xC0000357, "STATUS_PRENT4_MACHINE_ACCOUNT", "PRENT4 machine account."),
+    (0xC0000358, "STATUS_DS_AG_CANT_HAVE_UNIVERSAL_MEMBER", "DS ag cant have universal member."),
+    (0xC0000359, "STATUS_INVALID_IMAGE_WIN_32", "Invalid image win 32."),
+    (0xC000035A, "STATUS_INVALID_IMAGE_WIN_64", "Invalid image win 64."),
+    (0xC000035B, "STATUS_BAD_BINDINGS", "Bad bindings."),
+    (0xC000035C, "STATUS_NETWORK_SESSION_EXPIRED", "Network session expired."),
+    (0xC000035D, "STATUS_APPHELP_BLOCK", "Apphelp block."),
+    (0xC000035E, "STATUS_ALL_SIDS_FILTERED", "All sids filtered."),
+    (0xC000035F, "STATUS_NOT_SAFE_MODE_DRIVER", "Not safe mode driver."),
+    (0xC0000361, "STATUS_ACCESS_DISABLED_BY_POLICY_DEFAULT", "Access disabled by policy default."),
+    (0xC0000362, "STATUS_ACCESS_DISABLED_BY_POLICY_PATH", "Access disabled by policy path."),
+    (0xC0000363, "STATUS_ACCESS_DISABLED_BY_POLICY_PUBLISHER", "Access disabled by policy publisher."),
+    (0xC0000364, "STATUS_ACCESS_DISABLED_BY_POLICY_OTHER", "Access disabled by policy other."),
+    (0xC0000365, "STATUS_FAILED_DRIVER_ENTRY", "Failed driver entry."),
+    (0xC0000366, "STATUS_DEVICE_ENUMERATION_ERROR", "Device enumeration error."),
+    (0xC0000368, "STATUS_MOUNT_POINT_NOT_RESOLVED", "Mount point not resolved."),
+    (0xC0000369, "STATUS_INVALID_DEVICE_OBJECT_PARAMETER", "Invalid device object parameter."),
+    (0xC000036A, "STATUS_MCA_OCCURED", "MCA occured."),
+    (0xC000036B, "STATUS_DRIVER_BLOCKED_CRITICAL", "Driver blocked critical."),
+    (0xC000036C, "STATUS_DRIVER_BLOCKED", "Driver blocked."),
+    (0xC000036D, "STATUS_DRIVER_DATABASE_ERROR", "Driver database error."),
+    (0xC000036E, "STATUS_SYSTEM_HIVE_TOO_LARGE", "System hive too large."),
+    (0xC000036F, "STATUS_INVALID_IMPORT_OF_NON_DLL", "Invalid import of non DLL."),
+    (0xC0000371, "STATUS_NO_SECRETS", "No secrets."),
+    (0xC0000372, "STATUS_ACCESS_DISABLED_NO_SAFER_UI_BY_POLICY", "Access disabled no safer UI by policy."),
+    (0xC0000373, "STATUS_FAILED_STACK_SWITCH", "Failed stack switch."),
+    (0xC0000374, "STATUS_HEAP_CORRUPTION", "Heap corruption."),
+    (0xC0000380, "STATUS_SMARTCARD_WRONG_PIN", "Smartcard wrong pin."),
+    (0xC0000381, "STATUS_SMARTCARD_CARD_BLOCKED", "Smartcard card blocked."),
+    (0xC0000382, "STATUS_SMARTCARD_CARD_NOT_AUTHENTICATED", "Smartcard card not authenticated."),
+    (0xC0000383, "STATUS_SMARTCARD_NO_CARD", "Smartcard no card."),
+    (0xC0000384, "STATUS_SMARTCARD_NO_KEY_CONTAINER", "Smartcard no key container."),
+    (0xC0000385, "STATUS_SMARTCARD_NO_CERTIFICATE", "Smartcard no certificate."),
+    (0xC0000386, "STATUS_SMARTCARD_NO_KEYSET", "Smartcard no keyset."),
+    (0xC0000387, "STATUS_SMARTCARD_IO_ERROR", "Smartcard IO error."),
+    (0xC0000389, "STATUS_SMARTCARD_CERT_REVOKED", "Smartcard cert revoked."),
+    (0xC000038A, "STATUS_ISSUING_CA_UNTRUSTED", "Issuing ca untrusted."),
+    (0xC000038B, "STATUS_REVOCATION_OFFLINE_C", "Revocation offline C."),
+    (0xC000038C, "STATUS_PKINIT_CLIENT_FAILURE", "Pkinit client failure."),
+    (0xC000038D, "STATUS_SMARTCARD_CERT_EXPIRED", "Smartcard cert expired."),
+    (0xC000038E, "STATUS_DRIVER_FAILED_PRIOR_UNLOAD", "Driver failed prior unload."),
+    (0xC000038F, "STATUS_SMARTCARD_SILENT_CONTEXT", "Smartcard silent context."),
+    (0xC0000401, "STATUS_PER_USER_TRUST_QUOTA_EXCEEDED", "Per user trust quota exceeded."),
+    (0xC0000402, "STATUS_ALL_USER_TRUST_QUOTA_EXCEEDED", "All user trust quota exceeded."),
+    (0xC0000403, "STATUS_USER_DELETE_TRUST_QUOTA_EXCEEDED", "User delete trust quota exceeded."),
+    (0xC0000404, "STATUS_DS_NAME_NOT_UNIQUE", "DS name not unique."),
+    (0xC0000405, "STATUS_DS_DUPLICATE_ID_FOUND", "DS duplicate ID found."),
+    (0xC0000406, "STATUS_DS_GROUP_CONVERSION_ERROR", "DS group conversion error."),
+    (0xC0000407, "STATUS_VOLSNAP_PREPARE_HIBERNATE", "Volsnap prepare hibernate."),
+    (0xC0000408, "STATUS_USER2USER_REQUIRED", "USER2USER required."),
+    (0xC0000409, "STATUS_STACK_BUFFER_OVERRUN", "Stack buffer overrun."),
+    (0xC000040A, "STATUS_NO_S4U_PROT_SUPPORT", "No S4U prot support."),
+    (0xC000040B, "STATUS_CROSSREALM_DELEGATION_FAILURE", "Crossrealm delegation failure."),
+    (0xC000040C, "STATUS_REVOCATION_OFFLINE_KDC", "Revocation offline KDC."),
+    (0xC000040D, "STATUS_ISSUING_CA_UNTRUSTED_KDC", "Issuing ca untrusted KDC."),
+    (0xC000040E, "STATUS_KDC_CERT_EXPIRED", "KDC cert expired."),
+    (0xC000040F, "STATUS_KDC_CERT_REVOKED", "KDC cert revoked."),
+    (0xC0000410, "STATUS_PARAMETER_QUOTA_EXCEEDED", "Parameter quota exceeded."),
+    (0xC0000411, "STATUS_HIBERNATION_FAILURE", "Hibernation failure."),
+    (0xC0000412, "STATUS_DELAY_LOAD_FAILED", "Delay load failed."),
+    (0xC0000414, "STATUS_VDM_DISALLOWED", "VDM disallowed."),
+    (0xC0000415, "STATUS_HUNG_DISPLAY_DRIVER_THREAD", "Hung display driver thread."),
+    (0xC0000416, "STATUS_INSUFFICIENT_RESOURCE_FOR_SPECIFIED_SHARED_SECTION_SIZE", "Insufficient resource for specified shared section size."),
+    (0xC0000417, "STATUS_INVALID_CRUNTIME_PARAMETER", "Invalid cruntime parameter."),
+    (0xC0000418, "STATUS_NTLM_BLOCKED", "NTLM blocked."),
+    (0xC0000419, "STATUS_DS_SRC_SID_EXISTS_IN_FOREST", "DS SRC SID exists in forest."),
+    (0xC000041A, "STATUS_DS_DOMAIN_NAME_EXISTS_IN_FOREST", "DS domain name exists in forest."),
+    (0xC000041B, "STATUS_DS_FLAT_NAME_EXISTS_IN_FOREST", "DS flat name exists in forest."),
+    (0xC000041C, "STATUS_INVALID_USER_PRINCIPAL_NAME", "Invalid user principal name."),
+    (0xC000041D, "STATUS_FATAL_USER_CALLBACK_EXCEPTION", "Fatal user callback exception."),
+    (0xC0000420, "STATUS_ASSERTION_FAILURE", "Assertion failure."),
+    (0xC0000421, "STATUS_VERIFIER_STOP", "Verifier stop."),
+    (0xC0000423, "STATUS_CALLBACK_POP_STACK", "Callback pop stack."),
+    (0xC0000424, "STATUS_INCOMPATIBLE_DRIVER_BLOCKED", "Incompatible driver blocked."),
+    (0xC0000425, "STATUS_HIVE_UNLOADED", "Hive unloaded."),
+    (0xC0000426, "STATUS_COMPRESSION_DISABLED", "Compression disabled."),
+    (0xC0000427, "STATUS_FILE_SYSTEM_LIMITATION", "File system limitation."),
+    (0xC0000428, "STATUS_INVALID_IMAGE_HASH", "Invalid image hash."),
+    (0xC0000429, "STATUS_NOT_CAPABLE", "Not capable."),
+    (0xC000042A, "STATUS_REQUEST_OUT_OF_SEQUENCE", "Request out of sequence."),
+    (0xC000042B, "STATUS_IMPLEMENTATION_LIMIT", "Implementation limit."),
+    (0xC000042C, "STATUS_ELEVATION_REQUIRED", "Elevation required."),
+    (0xC000042D, "STATUS_NO_SECURITY_CONTEXT", "No security context."),
+    (0xC000042F, "STATUS_PKU2U_CERT_FAILURE", "PKU2U cert failure."),
+    (0xC0000432, "STATUS_BEYOND_VDL", "Beyond VDL."),
+    (0xC0000433, "STATUS_ENCOUNTERED_WRITE_IN_PROGRESS", "Encountered write in progress."),
+    (0xC0000434, "STATUS_PTE_CHANGED", "Pte changed."),
+    (0xC0000435, "STATUS_PURGE_FAILED", "Purge failed."),
+    (0xC0000440, "STATUS_CRED_REQUIRES_CONFIRMATION", "Cred requires confirmation."),
+    (0xC0000441, "STATUS_CS_ENCRYPTION_INVALID_SERVER_RESPONSE", "CS encryption invalid server response."),
+    (0xC0000442, "STATUS_CS_ENCRYPTION_UNSUPPORTED_SERVER", "CS encryption unsupported server."),
+    (0xC0000443, "STATUS_CS_ENCRYPTION_EXISTING_ENCRYPTED_FILE", "CS encryption existing encrypted file."),
+    (0xC0000444, "STATUS_CS_ENCRYPTION_NEW_ENCRYPTED_FILE", "CS encryption new encrypted file."),
+    (0xC0000445, "STATUS_CS_ENCRYPTION_FILE_NOT_CSE", "CS encryption file not cse."),
+    (0xC0000446, "STATUS_INVALID_LABEL", "Invalid label."),
+    (0xC0000450, "STATUS_DRIVER_PROCESS_TERMINATED", "Driver process terminated."),
+    (0xC0000451, "STATUS_AMBIGUOUS_SYSTEM_DEVICE", "Ambiguous system device."),
+    (0xC0000452, "STATUS_SYSTEM_DEVICE_NOT_FOUND", "System device not found."),
+    (0xC0000453, "STATUS_RESTART_BOOT_APPLICATION", "Restart boot application."),
+    (0xC0000454, "STATUS_INSUFFICIENT_NVRAM_RESOURCES", "Insufficient nvram resources."),
+    (0xC0000455, "STATUS_INVALID_SESSION", "Invalid session."),
+    (0xC0000456, "STATUS_THREAD_ALREADY_IN_SESSION", "Thread already in session."),
+    (0xC0000457, "STATUS_THREAD_NOT_IN_SESSION", "Thread not in session."),
+    (0xC0000458, "STATUS_INVALID_WEIGHT", "Invalid weight."),
+    (0xC0000459, "STATUS_REQUEST_PAUSED", "Request paused."),
+    (0xC0000460, "STATUS_NO_RANGES_PROCESSED", "No ranges processed."),
+    (0xC0000461, "STATUS_DISK_RESOURCES_EXHAUSTED", "Disk resources exhausted."),
+    (0xC0000462, "STATUS_NEEDS_REMEDIATION", "Needs remediation."),
+    (0xC0000463, "STATUS_DEVICE_FEATURE_NOT_SUPPORTED", "Device feature not supported."),
+    (0xC0000464, "STATUS_DEVICE_UNREACHABLE", "Device unreachable."),
+    (0xC0000465, "STATUS_INVALID_TOKEN", "Invalid token."),
+    (0xC0000466, "STATUS_SERVER_UNAVAILABLE", "Server unavailable."),
+    (0xC0000467, "STATUS_FILE_NOT_AVAILABLE", "File not available."),
+    (0xC0000468, "STATUS_DEVICE_INSUFFICIENT_RESOURCES", "Device insufficient resources."),
+    (0xC0000469, "STATUS_PACKAGE_UPDATING", "Package updating."),
+    (0xC000046A, "STATUS_NOT_READ_FROM_COPY", "Not read from copy."),
+    (0xC000046B, "STATUS_FT_WRITE_FAILURE", "FT write failure."),
+    (0xC000046C, "STATUS_FT_DI_SCAN_REQUIRED", "FT di scan required."),
+    (0xC000046D, "STATUS_OBJECT_NOT_EXTERNALLY_BACKED", "Object not externally backed."),
+    (0xC000046E, "STATUS_EXTERNAL_BACKING_PROVIDER_UNKNOWN", "External backing provider unknown."),
+    (0xC000046F, "STATUS_COMPRESSION_NOT_BENEFICIAL", "Compression not beneficial."),
+    (0xC0000470, "STATUS_DATA_CHECKSUM_ERROR", "Data checksum error."),
+    (0xC0000471, "STATUS_INTERMIXED_KERNEL_EA_OPERATION", "Intermixed kernel EA operation."),
+    (0xC0000472, "STATUS_TRIM_READ_ZERO_NOT_SUPPORTED", "Trim read zero not supported."),
+    (0xC0000473, "STATUS_TOO_MANY_SEGMENT_DESCRIPTORS", "Too many segment descriptors."),
+    (0xC0000474, "STATUS_INVALID_OFFSET_ALIGNMENT", "Invalid offset alignment."),
+    (0xC0000475, "STATUS_INVALID_FIELD_IN_PARAMETER_LIST", "Invalid field in parameter list."),
+    (0xC0000476, "STATUS_OPERATION_IN_PROGRESS", "Operation in progress."),
+    (0xC0000477, "STATUS_INVALID_INITIATOR_TARGET_PATH", "Invalid initiator target path."),
+    (0xC0000478, "STATUS_SCRUB_DATA_DISABLED", "Scrub data disabled."),
+    (0xC0000479, "STATUS_NOT_REDUNDANT_STORAGE", "Not redundant storage."),
+    (0xC000047A, "STATUS_RESIDENT_FILE_NOT_SUPPORTED", "Resident file not supported."),
+    (0xC000047B, "STATUS_COMPRESSED_FILE_NOT_SUPPORTED", "Compressed file not supported."),
+    (0xC000047C, "STATUS_DIRECTORY_NOT_SUPPORTED", "Directory not supported."),
+    (0xC000047D, "STATUS_IO_OPERATION_TIMEOUT", "IO operation timeout."),
+    (0xC000047E, "STATUS_SYSTEM_NEEDS_REMEDIATION", "System needs remediation."),
+    (0xC000047F, "STATUS_APPX_INTEGRITY_FAILURE_CLR_NGEN", "Appx integrity failure CLR ngen."),
+    (0xC0000480, "STATUS_SHARE_UNAVAILABLE", "Share unavailable."),
+    (0xC0000481, "STATUS_APISET_NOT_HOSTED", "Apiset not hosted."),
+    (0xC0000482, "STATUS_APISET_NOT_PRESENT", "Apiset not present."),
+    (0xC0000483, "STATUS_DEVICE_HARDWARE_ERROR", "Device hardware error."),
+    (0xC0000484, "STATUS_FIRMWARE_SLOT_INVALID", "Firmware slot invalid."),
+    (0xC0000485, "STATUS_FIRMWARE_IMAGE_INVALID", "Firmware image invalid."),
+    (0xC0000486, "STATUS_STORAGE_TOPOLOGY_ID_MISMATCH", "Storage topology ID mismatch."),
+    (0xC0000487, "STATUS_WIM_NOT_BOOTABLE", "WIM not bootable."),
+    (0xC0000488, "STATUS_BLOCKED_BY_PARENTAL_CONTROLS", "Blocked by parental controls."),
+    (0xC0000489, "STATUS_NEEDS_REGISTRATION", "Needs registration."),
+    (0xC000048A, "STATUS_QUOTA_ACTIVITY", "Quota activity."),
+    (0xC000048B, "STATUS_CALLBACK_INVOKE_INLINE", "Callback invoke inline."),
+    (0xC000048C, "STATUS_BLOCK_TOO_MANY_REFERENCES", "Block too many references."),
+    (0xC000048D, "STATUS_MARKED_TO_DISALLOW_WRITES", "Marked to disallow writes."),
+    (0xC000048E, "STATUS_NETWORK_ACCESS_DENIED_EDP", "Network access denied edp."),
+    (0xC000048F, "STATUS_ENCLAVE_FAILURE", "Enclave failure."),
+    (0xC0000490, "STATUS_PNP_NO_COMPAT_DRIVERS", "PNP no compat drivers."),
+    (0xC0000491, "STATUS_PNP_DRIVER_PACKAGE_NOT_FOUND", "PNP driver package not found."),
+    (0xC0000492, "STATUS_PNP_DRIVER_CONFIGURATION_NOT_FOUND", "PNP driver configuration not found."),
+    (0xC0000493, "STATUS_PNP_DRIVER_CONFIGURATION_INCOMPLETE", "PNP driver configuration incomplete."),
+    (0xC0000494, "STATUS_PNP_FUNCTION_DRIVER_REQUIRED", "PNP function driver required."),
+    (0xC0000495, "STATUS_PNP_DEVICE_CONFIGURATION_PENDING", "PNP device configuration pending."),
+    (0xC0000496, "STATUS_DEVICE_HINT_NAME_BUFFER_TOO_SMALL", "Device hint name buffer too small."),
+    (0xC0000497, "STATUS_PACKAGE_NOT_AVAILABLE", "Package not available."),
+    (0xC0000499, "STATUS_DEVICE_IN_MAINTENANCE", "Device in maintenance."),
+    (0xC000049A, "STATUS_NOT_SUPPORTED_ON_DAX", "Not supported on DAX."),
+    (0xC000049B, "STATUS_FREE_SPACE_TOO_FRAGMENTED", "Free space too fragmented."),
+    (0xC000049C, "STATUS_DAX_MAPPING_EXISTS", "DAX mapping exists."),
+    (0xC000049D, "STATUS_CHILD_PROCESS_BLOCKED", "Child process blocked."),
+    (0xC000049E, "STATUS_STORAGE_LOST_DATA_PERSISTENCE", "Storage lost data persistence."),
+    (0xC00004A0, "STATUS_PARTITION_TERMINATING", "Partition terminating."),
+    (0xC00004A1, "STATUS_EXTERNAL_SYSKEY_NOT_SUPPORTED", "External syskey not supported."),
+    (0xC00004A2, "STATUS_ENCLAVE_VIOLATION", "Enclave violation."),
+    (0xC00004A3, "STATUS_FILE_PROTECTED_UNDER_DPL", "File protected under DPL."),
+    (0xC00004A4, "STATUS_VOLUME_NOT_CLUSTER_ALIGNED", "Volume not cluster aligned."),
+    (0xC00004A5, "STATUS_NO_PHYSICALLY_ALIGNED_FREE_SPACE_FOUND", "No physically aligned free space found."),
+    (0xC00004A6, "STATUS_APPX_FILE_NOT_ENCRYPTED", "Appx file not encrypted."),
+    (0xC00004A7, "STATUS_RWRAW_ENCRYPTED_FILE_NOT_ENCRYPTED", "RWRAW encrypted file not encrypted."),
+    (0xC00004A8, "STATUS_RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILEOFFSET", "RWRAW encrypted invalid edatainfo fileoffset."),
+    (0xC00004A9, "STATUS_RWRAW_ENCRYPTED_INVALID_EDATAINFO_FILERANGE", "RWRAW encrypted invalid edatainfo filerange."),
+    (0xC00004AA, "STATUS_RWRAW_ENCRYPTED_INVALID_EDATAINFO_PARAMETER", "RWRAW encrypted invalid edatainfo parameter."),
+    (0xC00004AB, "STATUS_FT_READ_FAILURE", "FT read failure."),
+    (0xC00004AC, "STATUS_PATCH_CONFLICT", "Patch conflict."),
+    (0xC00004AD, "STATUS_STORAGE_RESERVE_ID_INVALID", "Storage reserve ID invalid."),
+    (0xC00004AE, "STATUS_STORAGE_RESERVE_DOES_NOT_EXIST", "Storage reserve does not exist."),
+    (0xC00004AF, "STATUS_STORAGE_RESERVE_ALREADY_EXISTS", "Storage reserve already exists."),
+    (0xC00004B0, "STATUS_STORAGE_RESERVE_NOT_EMPTY", "Storage reserve not empty."),
+    (0xC00004B1, "STATUS_NOT_A_DAX_VOLUME", "Not a DAX volume."),
+    (0xC00004B2, "STATUS_NOT_DAX_MAPPABLE", "Not DAX mappable."),
+    (0xC00004B3, "STATUS_CASE_DIFFERING_NAMES_IN_DIR", "Case differing names in dir."),
+    (0xC00004B4, "STATUS_FILE_NOT_SUPPORTED", "File not supported."),
+    (0xC00004B5, "STATUS_NOT_SUPPORTED_WITH_BTT", "Not supported with BTT."),
+    (0xC00004B6, "STATUS_ENCRYPTION_DISABLED", "Encryption disabled."),
+    (0xC00004B7, "STATUS_ENCRYPTING_METADATA_DISALLOWED", "Encrypting metadata disallowed."),
+    (0xC00004B8, "STATUS_CANT_CLEAR_ENCRYPTION_FLAG", "Cant clear encryption flag."),
+    (0xC00004B9, "STATUS_UNSATISFIED_DEPENDENCIES", "Unsatisfied dependencies."),
+    (0xC00004BA, "STATUS_CASE_SENSITIVE_PATH", "Case sensitive path."),
+    (0xC00004BB, "STATUS_UNSUPPORTED_PAGING_MODE", "Unsupported paging mode."),
+    (0xC00004BC, "STATUS_UNTRUSTED_MOUNT_POINT", "Untrusted mount point."),
+    (0xC00004BD, "STATUS_HAS_SYSTEM_CRITICAL_FILES", "Has system critical files."),
+    (0xC00004BE, "STATUS_OBJECT_IS_IMMUTABLE", "Object is immutable."),
+    (0xC00004BF, "STATUS_FT_READ_FROM_COPY_FAILURE", "FT read from copy failure."),
+    (0xC00004C0, "STATUS_IMAGE_LOADED_AS_PATCH_IMAGE", "Image loaded as patch image."),
+    (0xC00004C1, "STATUS_STORAGE_STACK_ACCESS_DENIED", "Storage stack access denied."),
+    (0xC00004C2, "STATUS_INSUFFICIENT_VIRTUAL_ADDR_RESOURCES", "Insufficient virtual addr resources."),
+    (0xC00004C3, "STATUS_ENCRYPTED_FILE_NOT_SUPPORTED", "Encrypted file not supported."),
+    (0xC00004C4, "STATUS_SPARSE_FILE_NOT_SUPPORTED", "Sparse file not supported."),
+    (0xC00004C5, "STATUS_PAGEFILE_NOT_SUPPORTED", "Pagefile not supported."),
+    (0xC00004C6, "STATUS_VOLUME_NOT_SUPPORTED", "Volume not supported."),
+    (0xC00004C7, "STATUS_NOT_SUPPORTED_WITH_BYPASSIO", "Not supported with bypassio."),
+    (0xC00004C8, "STATUS_NO_BYPASSIO_DRIVER_SUPPORT", "No bypassio driver support."),
+    (0xC00004C9, "STATUS_NOT_SUPPORTED_WITH_ENCRYPTION", "Not supported with encryption."),
+    (0xC00004CA, "STATUS_NOT_SUPPORTED_WITH_COMPRESSION", "Not supported with compression."),
+    (0xC00004CB, "STATUS_NOT_SUPPORTED_WITH_REPLICATION", "Not supported with replication."),
+    (0xC00004CC, "STATUS_NOT_SUPPORTED_WITH_DEDUPLICATION", "Not supported with deduplication."),
+    (0xC00004CD, "STATUS_NOT_SUPPORTED_WITH_AUDITING", "Not supported with auditing."),
+    (0xC00004CE, "STATUS_NOT_SUPPORTED_WITH_MONITORING", "Not supported with monitoring."),
+    (0xC00004CF, "STATUS_NOT_SUPPORTED_WITH_SNAPSHOT", "Not supported with snapshot."),
+    (0xC00004D0, "STATUS_NOT_SUPPORTED_WITH_VIRTUALIZATION", "Not supported with virtualization."),
+    (0xC00004D1, "STATUS_INDEX_OUT_OF_BOUNDS", "Index out of bounds."),
+    (0xC00004D2, "STATUS_BYPASSIO_FLT_NOT_SUPPORTED", "Bypassio FLT not supported."),
+    (0xC00004D3, "STATUS_VOLUME_WRITE_ACCESS_DENIED", "Volume write access denied."),
+    (0xC00004D4, "STATUS_PATCH_NOT_REGISTERED", "Patch not registered."),
+    (0xC00004D5, "STATUS_NOT_SUPPORTED_WITH_CACHED_HANDLE", "Not supported with cached handle."),
+    (0xC00004D9, "STATUS_VOLUME_UPGRADE_NOT_NEEDED", "Volume upgrade not needed."),
+    (0xC00004DA, "STATUS_VOLUME_UPGRADE_PENDING", "Volume upgrade pending."),
+    (0xC00004DB, "STATUS_VOLUME_UPGRADE_DISABLED", "Volume upgrade disabled."),
+    (0xC00004DC, "STATUS_VOLUME_UPGRADE_DISABLED_TILL_OS_DOWNGRADE_EXPIRED", "Volume upgrade disabled till OS downgrade expired."),
+    (0xC00004DD, "STATUS_NOT_A_DEV_VOLUME", "Not a dev volume."),
+    (0xC00004DE, "STATUS_FS_GUID_MISMATCH", "FS GUID mismatch."),
+    (0xC00004DF, "STATUS_CANT_ATTACH_TO_DEV_VOLUME", "Cant attach to dev volume."),
+    (0xC00004E0, "STATUS_INVALID_CONFIG_VALUE", "Invalid config value."),
+    (0xC0000500, "STATUS_INVALID_TASK_NAME", "Invalid task name."),
+    (0xC0000501, "STATUS_INVALID_TASK_INDEX", "Invalid task index."),
+    (0xC0000502, "STATUS_THREAD_ALREADY_IN_TASK", "Thread already in task."),
+    (0xC0000503, "STATUS_CALLBACK_BYPASS", "Callback bypass."),
+    (0xC0000504, "STATUS_UNDEFINED_SCOPE", "Undefined scope."),
+    (0xC0000505, "STATUS_INVALID_CAP", "Invalid cap."),
+    (0xC0000506, "STATUS_NOT_GUI_PROCESS", "Not gui process."),
+    (0xC0000507, "STATUS_DEVICE_HUNG", "Device hung."),
+    (0xC0000508, "STATUS_CONTAINER_ASSIGNED", "Container assigned."),
+    (0xC0000509, "STATUS_JOB_NO_CONTAINER", "Job no container."),
+    (0xC000050A, "STATUS_DEVICE_UNRESPONSIVE", "Device unresponsive."),
+    (0xC000050B, "STATUS_REPARSE_POINT_ENCOUNTERED", "Reparse point encountered."),
+    (0xC000050C, "STATUS_ATTRIBUTE_NOT_PRESENT", "Attribute not present."),
+    (0xC000050D, "STATUS_NOT_A_TIERED_VOLUME", "Not a tiered volume."),
+    (0xC000050E, "STATUS_ALREADY_HAS_STREAM_ID", "Already has stream ID."),
+    (0xC000050F, "STATUS_JOB_NOT_EMPTY", "Job not empty."),
+    (0xC0000510, "STATUS_ALREADY_INITIALIZED", "Already initialized."),
+    (0xC0000511, "STATUS_ENCLAVE_NOT_TERMINATED", "Enclave not terminated."),
+    (0xC0000512, "STATUS_ENCLAVE_IS_TERMINATING", "Enclave is terminating."),
+    (0xC0000513, "STATUS_SMB1_NOT_AVAILABLE", "SMB1 not available."),
+    (0xC0000514, "STATUS_SMR_GARBAGE_COLLECTION_REQUIRED", "SMR garbage collection required."),
+    (0xC0000515, "STATUS_INTERRUPTED", "Interrupted."),
+    (0xC0000516, "STATUS_THREAD_NOT_RUNNING", "Thread not running."),
+    (0xC0000517, "STATUS_SESSION_KEY_TOO_SHORT", "Session key too short."),
+    (0xC0000518, "STATUS_FS_METADATA_INCONSISTENT", "FS metadata inconsistent."),
+    (0xC0000602, "STATUS_FAIL_FAST_EXCEPTION", "Fail fast exception."),
+    (0xC0000603, "STATUS_IMAGE_CERT_REVOKED", "Image cert revoked."),
+    (0xC0000604, "STATUS_DYNAMIC_CODE_BLOCKED", "Dynamic code blocked."),
+    (0xC0000605, "STATUS_IMAGE_CERT_EXPIRED", "Image cert expired."),
+    (0xC0000606, "STATUS_STRICT_CFG_VIOLATION", "Strict CFG violation."),
+    (0xC000060A, "STATUS_SET_CONTEXT_DENIED", "Set context denied."),
+    (0xC000060B, "STATUS_CROSS_PARTITION_VIOLATION", "Cross partition violation."),
+    (0xC0000700, "STATUS_PORT_CLOSED", "Port closed."),
+    (0xC0000701, "STATUS_MESSAGE_LOST", "Message lost."),
+    (0xC0000702, "STATUS_INVALID_MESSAGE", "Invalid message."),
+    (0xC0000703, "STATUS_REQUEST_CANCELED", "Request canceled."),
+    (0xC0000704, "STATUS_RECURSIVE_DISPATCH", "Recursive dispatch."),
+    (0xC0000705, "STATUS_LPC_RECEIVE_BUFFER_EXPECTED", "LPC receive buffer expected."),
+    (0xC0000706, "STATUS_LPC_INVALID_CONNECTION_USAGE", "LPC invalid connection usage."),
+    (0xC0000707, "STATUS_LPC_REQUESTS_NOT_ALLOWED", "LPC requests not allowed."),
+    (0xC0000708, "STATUS_RESOURCE_IN_USE", "Resource in use."),
+    (0xC0000709, "STATUS_HARDWARE_MEMORY_ERROR", "Hardware memory error."),
+    (0xC000070A, "STATUS_THREADPOOL_HANDLE_EXCEPTION", "Threadpool handle exception."),
+    (0xC000070B, "STATUS_THREADPOOL_SET_EVENT_ON_COMPLETION_FAILED", "Threadpool set event on completion failed."),
+    (0xC000070C, "STATUS_THREADPOOL_RELEASE_SEMAPHORE_ON_COMPLETION_FAILED", "Threadpool release semaphore on completion failed."),
+    (0xC000070D, "STATUS_THREADPOOL_RELEASE_MUTEX_ON_COMPLETION_FAILED", "Threadpool release mutex on completion failed."),
+    (0xC000070E, "STATUS_THREADPOOL_FREE_LIBRARY_ON_COMPLETION_FAILED", "Threadpool free library on completion failed."),
+    (0xC000070F, "STATUS_THREADPOOL_RELEASED_DURING_OPERATION", "Threadpool released during operation."),
+    (0xC0000710, "STATUS_CALLBACK_RETURNED_WHILE_IMPERSONATING", "Callback returned while impersonating."),
+    (0xC0000711, "STATUS_APC_RETURNED_WHILE_IMPERSONATING", "Apc returned while impersonating."),
+    (0xC0000712, "STATUS_PROCESS_IS_PROTECTED", "Process is protected."),
+    (0xC0000713, "STATUS_MCA_EXCEPTION", "MCA exception."),
+    (0xC0000714, "STATUS_CERTIFICATE_MAPPING_NOT_UNIQUE", "Certificate mapping not unique."),
+    (0xC0000715, "STATUS_SYMLINK_CLASS_DISABLED", "Symlink class disabled."),
+    (0xC0000716, "STATUS_INVALID_IDN_NORMALIZATION", "Invalid idn normalization."),
+    (0xC0000717, "STATUS_NO_UNICODE_TRANSLATION", "No unicode translation."),
+    (0xC0000718, "STATUS_ALREADY_REGISTERED", "Already registered."),
+    (0xC0000719, "STATUS_CONTEXT_MISMATCH", "Context mismatch."),
+    (0xC000071A, "STATUS_PORT_ALREADY_HAS_COMPLETION_LIST", "Port already has completion list."),
+    (0xC000071B, "STATUS_CALLBACK_RETURNED_THREAD_PRIORITY", "Callback returned thread priority."),
+    (0xC000071C, "STATUS_INVALID_THREAD", "Invalid thread."),
+    (0xC000071D, "STATUS_CALLBACK_RETURNED_TRANSACTION", "Callback returned transaction."),
+    (0xC000071E, "STATUS_CALLBACK_RETURNED_LDR_LOCK", "Callback returned LDR lock."),
+    (0xC000071F, "STATUS_CALLBACK_RETURNED_LANG", "Callback returned lang."),
+    (0xC0000720, "STATUS_CALLBACK_RETURNED_PRI_BACK", "Callback returned pri back."),
+    (0xC0000721, "STATUS_CALLBACK_RETURNED_THREAD_AFFINITY", "Callback returned thread affinity."),
+    (0xC0000722, "STATUS_LPC_HANDLE_COUNT_EXCEEDED", "LPC handle count exceeded."),
+    (0xC0000723, "STATUS_EXECUTABLE_MEMORY_WRITE", "Executable memory write."),
+    (0xC0000724, "STATUS_KERNEL_EXECUTABLE_MEMORY_WRITE", "Kernel executable memory write."),
+    (0xC0000725, "STATUS_ATTACHED_EXECUTABLE_MEMORY_WRITE", "Attached executable memory write."),
+    (0xC0000726, "STATUS_TRIGGERED_EXECUTABLE_MEMORY_WRITE", "Triggered executable memory write."),
+    (0xC0000800, "STATUS_DISK_REPAIR_DISABLED", "Disk repair disabled."),
+    (0xC0000801, "STATUS_DS_DOMAIN_RENAME_IN_PROGRESS", "DS domain rename in progress."),
+    (0xC0000802, "STATUS_DISK_QUOTA_EXCEEDED", "Disk quota exceeded."),
+    (0xC0000804, "STATUS_CONTENT_BLOCKED", "Content blocked."),
+    (0xC0000805, "STATUS_BAD_CLUSTERS", "Bad clusters."),
+    (0xC0000806, "STATUS_VOLUME_DIRTY", "Volume dirty."),
+    (0xC0000808, "STATUS_DISK_REPAIR_UNSUCCESSFUL", "Disk repair unsuccessful."),
+    (0xC0000809, "STATUS_CORRUPT_LOG_OVERFULL", "Corrupt log overfull."),
+    (0xC000080A, "STATUS_CORRUPT_LOG_CORRUPTED", "Corrupt log corrupted."),
+    (0xC000080B, "STATUS_CORRUPT_LOG_UNAVAILABLE", "Corrupt log unavailable."),
+    (0xC000080C, "STATUS_CORRUPT_LOG_DELETED_FULL", "Corrupt log deleted full."),
+    (0xC000080D, "STATUS_CORRUPT_LOG_CLEARED", "Corrupt log cleared."),
+    (0xC000080E, "STATUS_ORPHAN_NAME_EXHAUSTED", "Orphan name exhausted."),
+    (0xC000080F, "STATUS_PROACTIVE_SCAN_IN_PROGRESS", "Proactive scan in progress."),
+    (0xC0000810, "STATUS_ENCRYPTED_IO_NOT_POSSIBLE", "Encrypted IO not possible."),
+    (0xC0000811, "STATUS_CORRUPT_LOG_UPLEVEL_RECORDS", "Corrupt log uplevel records."),
+    (0xC0000901, "STATUS_FILE_CHECKED_OUT", "File checked out."),
+    (0xC0000902, "STATUS_CHECKOUT_REQUIRED", "Checkout required."),
+    (0xC0000903, "STATUS_BAD_FILE_TYPE", "Bad file type."),
+    (0xC0000904, "STATUS_FILE_TOO_LARGE", "File too large."),
+    (0xC0000905, "STATUS_FORMS_AUTH_REQUIRED", "Forms auth required."),
+    (0xC0000906, "STATUS_VIRUS_INFECTED", "Virus infected."),
+    (0xC0000907, "STATUS_VIRUS_DELETED", "Virus deleted."),
+    (0xC0000908, "STATUS_BAD_MCFG_TABLE", "Bad MCFG table."),
+    (0xC0000909, "STATUS_CANNOT_BREAK_OPLOCK", "Cannot break oplock."),
+    (0xC000090A, "STATUS_BAD_KEY", "Bad key."),
+    (0xC000090B, "STATUS_BAD_DATA", "Bad data."),
+    (0xC000090C, "STATUS_NO_KEY", "No key."),
+    (0xC0000910, "STATUS_FILE_HANDLE_REVOKED", "File handle revoked."),
+    (0xC0000911, "STATUS_SECTION_DIRECT_MAP_ONLY", "Section direct map only."),
+    (0xC0000912, "STATUS_BLOCK_WEAK_REFERENCE_INVALID", "Block weak reference invalid."),
+    (0xC0000913, "STATUS_BLOCK_SOURCE_WEAK_REFERENCE_INVALID", "Block source weak reference invalid."),
+    (0xC0000914, "STATUS_BLOCK_TARGET_WEAK_REFERENCE_INVALID", "Block target weak reference invalid."),
+    (0xC0000915, "STATUS_BLOCK_SHARED", "Block shared."),
+    (0xC0000C08, "STATUS_VRF_VOLATILE_CFG_AND_IO_ENABLED", "VRF volatile CFG and IO enabled."),
+    (0xC0000C09, "STATUS_VRF_VOLATILE_NOT_STOPPABLE", "VRF volatile not stoppable."),
+    (0xC0000C0A, "STATUS_VRF_VOLATILE_SAFE_MODE", "VRF volatile safe mode."),
+    (0xC0000C0B, "STATUS_VRF_VOLATILE_NOT_RUNNABLE_SYSTEM", "VRF volatile not runnable system."),
+    (0xC0000C0C, "STATUS_VRF_VOLATILE_NOT_SUPPORTED_RULECLASS", "VRF volatile not supported ruleclass."),
+    (0xC0000C0D, "STATUS_VRF_VOLATILE_PROTECTED_DRIVER", "VRF volatile protected driver."),
+    (0xC0000C0E, "STATUS_VRF_VOLATILE_NMI_REGISTERED", "VRF volatile nmi registered."),
+    (0xC0000C0F, "STATUS_VRF_VOLATILE_SETTINGS_CONFLICT", "VRF volatile settings conflict."),
+    (0xC0000C76, "STATUS_DIF_IOCALLBACK_NOT_REPLACED", "DIF iocallback not replaced."),
+    (0xC0000C77, "STATUS_DIF_LIVEDUMP_LIMIT_EXCEEDED", "DIF livedump limit exceeded."),
+    (0xC0000C78, "STATUS_DIF_VOLATILE_SECTION_NOT_LOCKED", "DIF volatile section not locked."),
+    (0xC0000C79, "STATUS_DIF_VOLATILE_DRIVER_HOTPATCHED", "DIF volatile driver hotpatched."),
+    (0xC0000C7A, "STATUS_DIF_VOLATILE_INVALID_INFO", "DIF volatile invalid info."),
+    (0xC0000C7B, "STATUS_DIF_VOLATILE_DRIVER_IS_NOT_RUNNING", "DIF volatile driver is not running."),
+    (0xC0000C7C, "STATUS_DIF_VOLATILE_PLUGIN_IS_NOT_RUNNING", "DIF volatile plugin is not running."),
+    (0xC0000C7D, "STATUS_DIF_VOLATILE_PLUGIN_CHANGE_NOT_ALLOWED", "DIF volatile plugin change not allowed."),
+    (0xC0000C7E, "STATUS_DIF_VOLATILE_NOT_ALLOWED", "DIF volatile not allowed."),
+    (0xC0000C7F, "STATUS_DIF_BINDING_API_NOT_FOUND", "DIF binding API not found."),
+    (0xC0009898, "STATUS_WOW_ASSERTION", "WOW assertion."),
+    (0xC000A000, "STATUS_INVALID_SIGNATURE", "Invalid signature."),
+    (0xC000A001, "STATUS_HMAC_NOT_SUPPORTED", "Hmac not supported."),
+    (0xC000A002, "STATUS_AUTH_TAG_MISMATCH", "Auth tag mismatch."),
+    (0xC000A003, "STATUS_INVALID_STATE_TRANSITION", "Invalid state transition."),
+    (0xC000A004, "STATUS_INVALID_KERNEL_INFO_VERSION", "Invalid kernel info version."),
+    (0xC000A005, "STATUS_INVALID_PEP_INFO_VERSION", "Invalid pep info version."),
+    (0xC000A006, "STATUS_HANDLE_REVOKED", "Handle revoked."),
+    (0xC000A007, "STATUS_EOF_ON_GHOSTED_RANGE", "Eof on ghosted range."),
+    (0xC000A008, "STATUS_CC_NEEDS_CALLBACK_SECTION_DRAIN", "CC needs callback section drain."),
+    (0xC000A010, "STATUS_IPSEC_QUEUE_OVERFLOW", "IPSEC queue overflow."),
+    (0xC000A011, "STATUS_ND_QUEUE_OVERFLOW", "ND queue overflow."),
+    (0xC000A012, "STATUS_HOPLIMIT_EXCEEDED", "Hoplimit exceeded."),
+    (0xC000A013, "STATUS_PROTOCOL_NOT_SUPPORTED", "Protocol not supported."),
+    (0xC000A014, "STATUS_FASTPATH_REJECTED", "Fastpath rejected."),
+    (0xC000A080, "STATUS_LOST_WRITEBEHIND_DATA_NETWORK_DISCONNECTED", "Lost writebehind data network disconnected."),
+    (0xC000A081, "STATUS_LOST_WRITEBEHIND_DATA_NETWORK_SERVER_ERROR", "Lost writebehind data network server error."),
+    (0xC000A082, "STATUS_LOST_WRITEBEHIND_DATA_LOCAL_DISK_ERROR", "Lost writebehind data local disk error."),
+    (0xC000A083, "STATUS_XML_PARSE_ERROR", "XML parse error."),
+    (0xC000A084, "STATUS_XMLDSIG_ERROR", "Xmldsig error."),
+    (0xC000A085, "STATUS_WRONG_COMPARTMENT", "Wrong compartment."),
+    (0xC000A086, "STATUS_AUTHIP_FAILURE", "Authip failure."),
+    (0xC000A087, "STATUS_DS_OID_MAPPED_GROUP_CANT_HAVE_MEMBERS", "DS oid mapped group cant have members."),
+    (0xC000A088, "STATUS_DS_OID_NOT_FOUND", "DS oid not found."),
+    (0xC000A089, "STATUS_INCORRECT_ACCOUNT_TYPE", "Incorrect account type."),
+    (0xC000A08A, "STATUS_LOCAL_POLICY_MODIFICATION_NOT_SUPPORTED", "Local policy modification not supported."),
+    (0xC000A08B, "STATUS_POLICY_CONTROLLED_ACCOUNT", "Policy controlled account."),
+    (0xC000A08C, "STATUS_LAPS_LEGACY_SCHEMA_MISSING", "Laps legacy schema missing."),
+    (0xC000A08D, "STATUS_LAPS_SCHEMA_MISSING", "Laps schema missing."),
+    (0xC000A08E, "STATUS_LAPS_ENCRYPTION_REQUIRES_2016_DFL", "Laps encryption requires 2016 DFL."),
+    (0xC000A100, "STATUS_HASH_NOT_SUPPORTED", "Hash not supported."),
+    (0xC000A101, "STATUS_HASH_NOT_PRESENT", "Hash not present."),
+    (0xC000A121, "STATUS_SECONDARY_IC_PROVIDER_NOT_REGISTERED", "Secondary ic provider not registered."),
+    (0xC000A122, "STATUS_GPIO_CLIENT_INFORMATION_INVALID", "GPIO client information invalid."),
+    (0xC000A123, "STATUS_GPIO_VERSION_NOT_SUPPORTED", "GPIO version not supported."),
+    (0xC000A124, "STATUS_GPIO_INVALID_REGISTRATION_PACKET", "GPIO invalid registration packet."),
+    (0xC000A125, "STATUS_GPIO_OPERATION_DENIED", "GPIO operation denied."),
+    (0xC000A126, "STATUS_GPIO_INCOMPATIBLE_CONNECT_MODE", "GPIO incompatible connect mode."),
+    (0xC000A141, "STATUS_CANNOT_SWITCH_RUNLEVEL", "Cannot switch runlevel."),
+    (0xC000A142, "STATUS_INVALID_RUNLEVEL_SETTING", "Invalid runlevel setting."),
+    (0xC000A143, "STATUS_RUNLEVEL_SWITCH_TIMEOUT", "Runlevel switch timeout."),
+    (0xC000A145, "STATUS_RUNLEVEL_SWITCH_AGENT_TIMEOUT", "Runlevel switch agent timeout."),
+    (0xC000A146, "STATUS_RUNLEVEL_SWITCH_IN_PROGRESS", "Runlevel switch in progress."),
+    (0xC000A200, "STATUS_NOT_APPCONTAINER", "Not appcontainer."),
+    (0xC000A201, "STATUS_NOT_SUPPORTED_IN_APPCONTAINER", "Not supported in appcontainer."),
+    (0xC000A202, "STATUS_INVALID_PACKAGE_SID_LENGTH", "Invalid package SID length."),
+    (0xC000A203, "STATUS_LPAC_ACCESS_DENIED", "Lpac access denied."),
+    (0xC000A204, "STATUS_ADMINLESS_ACCESS_DENIED", "Adminless access denied."),
+    (0xC000A281, "STATUS_APP_DATA_NOT_FOUND", "App data not found."),
+    (0xC000A282, "STATUS_APP_DATA_EXPIRED", "App data expired."),
+    (0xC000A283, "STATUS_APP_DATA_CORRUPT", "App data corrupt."),
+    (0xC000A284, "STATUS_APP_DATA_LIMIT_EXCEEDED", "App data limit exceeded."),
+    (0xC000A285, "STATUS_APP_DATA_REBOOT_REQUIRED", "App data reboot required."),
+    (0xC000A2A1, "STATUS_OFFLOAD_READ_FLT_NOT_SUPPORTED", "Offload read FLT not supported."),
+    (0xC000A2A2, "STATUS_OFFLOAD_WRITE_FLT_NOT_SUPPORTED", "Offload write FLT not supported."),
+    (0xC000A2A3, "STATUS_OFFLOAD_READ_FILE_NOT_SUPPORTED", "Offload read file not supported."),
+    (0xC000A2A4, "STATUS_OFFLOAD_WRITE_FILE_NOT_SUPPORTED", "Offload write file not supported."),
+    (0xC000A2A5, "STATUS_WOF_WIM_HEADER_CORRUPT", "Wof WIM header corrupt."),
+    (0xC000A2A6, "STATUS_WOF_WIM_RESOURCE_TABLE_CORRUPT", "Wof WIM resource table corrupt."),
+    (0xC000A2A7, "STATUS_WOF_FILE_RESOURCE_TABLE_CORRUPT", "Wof file resource table corrupt."),
+    (0xC000C001, "STATUS_CIMFS_IMAGE_CORRUPT", "Cimfs image corrupt."),
+    (0xC000C002, "STATUS_CIMFS_IMAGE_VERSION_NOT_SUPPORTED", "Cimfs image version not supported."),
+    (0xC000CE01, "STATUS_FILE_SYSTEM_VIRTUALIZATION_UNAVAILABLE", "File system virtualization unavailable."),
+    (0xC000CE02, "STATUS_FILE_SYSTEM_VIRTUALIZATION_METADATA_CORRUPT", "File system virtualization metadata corrupt."),
+    (0xC000CE03, "STATUS_FILE_SYSTEM_VIRTUALIZATION_BUSY", "File system virtualization busy."),
+    (0xC000CE04, "STATUS_FILE_SYSTEM_VIRTUALIZATION_PROVIDER_UNKNOWN", "File system virtualization provider unknown."),
+    (0xC000CE05, "STATUS_FILE_SYSTEM_VIRTUALIZATION_INVALID_OPERATION", "File system virtualization invalid operation."),
+    (0xC000CF00, "STATUS_CLOUD_FILE_SYNC_ROOT_METADATA_CORRUPT", "Cloud file sync root metadata corrupt."),
+    (0xC000CF01, "STATUS_CLOUD_FILE_PROVIDER_NOT_RUNNING", "Cloud file provider not running."),
+    (0xC000CF02, "STATUS_CLOUD_FILE_METADATA_CORRUPT", "Cloud file metadata corrupt."),
+    (0xC000CF03, "STATUS_CLOUD_FILE_METADATA_TOO_LARGE", "Cloud file metadata too large."),
+    (0xC000CF06, "STATUS_CLOUD_FILE_PROPERTY_VERSION_NOT_SUPPORTED", "Cloud file property version not supported."),
+    (0xC000CF07, "STATUS_NOT_A_CLOUD_FILE", "Not a cloud file."),
+    (0xC000CF08, "STATUS_CLOUD_FILE_NOT_IN_SYNC", "Cloud file not in sync."),
+    (0xC000CF09, "STATUS_CLOUD_FILE_ALREADY_CONNECTED", "Cloud file already connected."),
+    (0xC000CF0A, "STATUS_CLOUD_FILE_NOT_SUPPORTED", "Cloud file not supported."),
+    (0xC000CF0B, "STATUS_CLOUD_FILE_INVALID_REQUEST", "Cloud file invalid request."),
+    (0xC000CF0C, "STATUS_CLOUD_FILE_READ_ONLY_VOLUME", "Cloud file read only volume."),
+    (0xC000CF0D, "STATUS_CLOUD_FILE_CONNECTED_PROVIDER_ONLY", "Cloud file connected provider only."),
+    (0xC000CF0E, "STATUS_CLOUD_FILE_VALIDATION_FAILED", "Cloud file validation failed."),
+    (0xC000CF0F, "STATUS_CLOUD_FILE_AUTHENTICATION_FAILED", "Cloud file authentication failed."),
+    (0xC000CF10, "STATUS_CLOUD_FILE_INSUFFICIENT_RESOURCES", "Cloud file insufficient resources."),
+    (0xC000CF11, "STATUS_CLOUD_FILE_NETWORK_UNAVAILABLE", "Cloud file network unavailable."),
+    (0xC000CF12, "STATUS_CLOUD_FILE_UNSUCCESSFUL", "Cloud file unsuccessful."),
+    (0xC000CF13, "STATUS_CLOUD_FILE_NOT_UNDER_SYNC_ROOT", "Cloud file not under sync root."),
+    (0xC000CF14, "STATUS_CLOUD_FILE_IN_USE", "Cloud file in use."),
+    (0xC000CF15, "STATUS_CLOUD_FILE_PINNED", "Cloud file pinned."),
+    (0xC000CF16, "STATUS_CLOUD_FILE_REQUEST_ABORTED", "Cloud file request aborted."),
+    (0xC000CF17, "STATUS_CLOUD_FILE_PROPERTY_CORRUPT", "Cloud file property corrupt."),
+    (0xC000CF18, "STATUS_CLOUD_FILE_ACCESS_DENIED", "Cloud file access denied."),
+    (0xC000CF19, "STATUS_CLOUD_FILE_INCOMPATIBLE_HARDLINKS", "Cloud file incompatible hardlinks."),
+    (0xC000CF1A, "STATUS_CLOUD_FILE_PROPERTY_LOCK_CONFLICT", "Cloud file property lock conflict."),
+    (0xC000CF1B, "STATUS_CLOUD_FILE_REQUEST_CANCELED", "Cloud file request canceled."),
+    (0xC000CF1D, "STATUS_CLOUD_FILE_PROVIDER_TERMINATED", "Cloud file provider terminated."),
+    (0xC000CF1E, "STATUS_NOT_A_CLOUD_SYNC_ROOT", "Not a cloud sync root."),
+    (0xC000CF1F, "STATUS_CLOUD_FILE_REQUEST_TIMEOUT", "Cloud file request timeout."),
+    (0xC000CF20, "STATUS_CLOUD_FILE_DEHYDRATION_DISALLOWED", "Cloud file dehydration disallowed."),
+    (0xC000CF21, "STATUS_CLOUD_FILE_US_MESSAGE_TIMEOUT", "Cloud file us message timeout."),
+    (0xC000F500, "STATUS_FILE_SNAP_IN_PROGRESS", "File snap in progress."),
+    (0xC000F501, "STATUS_FILE_SNAP_USER_SECTION_NOT_SUPPORTED", "File snap user section not supported."),
+    (0xC000F502, "STATUS_FILE_SNAP_MODIFY_NOT_SUPPORTED", "File snap modify not supported."),
+    (0xC000F503, "STATUS_FILE_SNAP_IO_NOT_COORDINATED", "File snap IO not coordinated."),
+    (0xC000F504, "STATUS_FILE_SNAP_UNEXPECTED_ERROR", "File snap unexpected error."),
+    (0xC000F505, "STATUS_FILE_SNAP_INVALID_PARAMETER", "File snap invalid parameter."),
+    (0xC0010001, "DBG_NO_STATE_CHANGE", "No state change."),
+    (0xC0010002, "DBG_APP_NOT_IDLE", "App not idle."),
+    (0xC0020001, "RPC_NT_INVALID_STRING_BINDING", "Invalid string binding."),
+    (0xC0020002, "RPC_NT_WRONG_KIND_OF_BINDING", "Wrong kind of binding."),
+    (0xC0020003, "RPC_NT_INVALID_BINDING", "Invalid binding."),
+    (0xC0020004, "RPC_NT_PROTSEQ_NOT_SUPPORTED", "Protseq not supported."),
+    (0xC0020005, "RPC_NT_INVALID_RPC_PROTSEQ", "Invalid RPC protseq."),
+    (0xC0020006, "RPC_NT_INVALID_STRING_UUID", "Invalid string uuid."),
+    (0xC0020007, "RPC_NT_INVALID_ENDPOINT_FORMAT", "Invalid endpoint format."),
+    (0xC0020008, "RPC_NT_INVALID_NET_ADDR", "Invalid net addr."),
+    (0xC0020009, "RPC_NT_NO_ENDPOINT_FOUND", "No endpoint found."),
+    (0xC002000A, "RPC_NT_INVALID_TIMEOUT", "Invalid timeout."),
+    (0xC002000B, "RPC_NT_OBJECT_NOT_FOUND", "Object not found."),
+    (0xC002000C, "RPC_NT_ALREADY_REGISTERED", "Already registered."),
+    (0xC002000D, "RPC_NT_TYPE_ALREADY_REGISTERED", "Type already registered."),
+    (0xC002000E, "RPC_NT_ALREADY_LISTENING", "Already listening."),
+    (0xC002000F, "RPC_NT_NO_PROTSEQS_REGISTERED", "No protseqs registered."),
+    (0xC0020010, "RPC_NT_NOT_LISTENING", "Not listening."),
+    (0xC0020011, "RPC_NT_UNKNOWN_MGR_TYPE", "Unknown MGR type."),
+    (0xC0020012, "RPC_NT_UNKNOWN_IF", "Unknown if."),
+    (0xC0020013, "RPC_NT_NO_BINDINGS", "No bindings."),
+    (0xC0020014, "RPC_NT_NO_PROTSEQS", "No protseqs."),
+    (0xC0020015, "RPC_NT_CANT_CREATE_ENDPOINT", "Cant create endpoint."),
+    (0xC0020016, "RPC_NT_OUT_OF_RESOURCES", "Out of resources."),
+    (0xC0020017, "RPC_NT_SERVER_UNAVAILABLE", "Server unavailable."),
+    (0xC0020018, "RPC_NT_SERVER_TOO_BUSY", "Server too busy."),
+    (0xC0020019, "RPC_NT_INVALID_NETWORK_OPTIONS", "Invalid network options."),
+    (0xC002001A, "RPC_NT_NO_CALL_ACTIVE", "No call active."),
+    (0xC002001B, "RPC_NT_CALL_FAILED", "Call failed."),
+    (0xC002001C, "RPC_NT_CALL_FAILED_DNE", "Call failed dne."),
+    (0xC002001D, "RPC_NT_PROTOCOL_ERROR", "Protocol error."),
+    (0xC002001F, "RPC_NT_UNSUPPORTED_TRANS_SYN", "Unsupported trans syn."),
+    (0xC0020021, "RPC_NT_UNSUPPORTED_TYPE", "Unsupported type."),
+    (0xC0020022, "RPC_NT_INVALID_TAG", "Invalid tag."),
+    (0xC0020023, "RPC_NT_INVALID_BOUND", "Invalid bound."),
+    (0xC0020024, "RPC_NT_NO_ENTRY_NAME", "No entry name."),
+    (0xC0020025, "RPC_NT_INVALID_NAME_SYNTAX", "Invalid name syntax."),
+    (0xC0020026, "RPC_NT_UNSUPPORTED_NAME_SYNTAX", "Unsupported name syntax."),
+    (0xC0020028, "RPC_NT_UUID_NO_ADDRESS", "Uuid no address."),
+    (0xC0020029, "RPC_NT_DUPLICATE_ENDPOINT", "Duplicate endpoint."),
+    (0xC002002A, "RPC_NT_UNKNOWN_AUTHN_TYPE", "Unknown authn type."),
+    (0xC002002B, "RPC_NT_MAX_CALLS_TOO_SMALL", "Max calls too small."),
+    (0xC002002C, "RPC_NT_STRING_TOO_LONG", "String too long."),
+    (0xC002002D, "RPC_NT_PROTSEQ_NOT_FOUND", "Protseq not found."),
+    (0xC002002E, "RPC_NT_PROCNUM_OUT_OF_RANGE", "Procnum out of range."),
+    (0xC002002F, "RPC_NT_BINDING_HAS_NO_AUTH", "Binding has no auth."),
+    (0xC0020030, "RPC_NT_UNKNOWN_AUTHN_SERVICE", "Unknown authn service."),
+    (0xC0020031, "RPC_NT_UNKNOWN_AUTHN_LEVEL", "Unknown authn level."),
+    (0xC0020032, "RPC_NT_INVALID_AUTH_IDENTITY", "Invalid auth identity."),
+    (0xC0020033, "RPC_NT_UNKNOWN_AUTHZ_SERVICE", "Unknown authz service."),
+    (0xC0020034, "EPT_NT_INVALID_ENTRY", "Invalid entry."),
+    (0xC0020035, "EPT_NT_CANT_PERFORM_OP", "Cant perform op."),
+    (0xC0020036, "EPT_NT_NOT_REGISTERED", "Not registered."),
+    (0xC0020037, "RPC_NT_NOTHING_TO_EXPORT", "Nothing to export."),
+    (0xC0020038, "RPC_NT_INCOMPLETE_NAME", "Incomplete name."),
+    (0xC0020039, "RPC_NT_INVALID_VERS_OPTION", "Invalid vers option."),
+    (0xC002003A, "RPC_NT_NO_MORE_MEMBERS", "No more members."),
+    (0xC002003B, "RPC_NT_NOT_ALL_OBJS_UNEXPORTED", "Not all objs unexported."),
+    (0xC002003C, "RPC_NT_INTERFACE_NOT_FOUND", "Interface not found."),
+    (0xC002003D, "RPC_NT_ENTRY_ALREADY_EXISTS", "Entry already exists."),
+    (0xC002003E, "RPC_NT_ENTRY_NOT_FOUND", "Entry not found."),
+    (0xC002003F, "RPC_NT_NAME_SERVICE_UNAVAILABLE", "Name service unavailable."),
+    (0xC0020040, "RPC_NT_INVALID_NAF_ID", "Invalid naf ID."),
+    (0xC0020041, "RPC_NT_CANNOT_SUPPORT", "Cannot support."),
+    (0xC0020042, "RPC_NT_NO_CONTEXT_AVAILABLE", "No context available."),
+    (0xC0020043, "RPC_NT_INTERNAL_ERROR", "Internal error."),
+    (0xC0020044, "RPC_NT_ZERO_DIVIDE", "Zero divide."),
+    (0xC0020045, "RPC_NT_ADDRESS_ERROR", "Address error."),
+    (0xC0020046, "RPC_NT_FP_DIV_ZERO", "FP div zero."),
+    (0xC0020047, "RPC_NT_FP_UNDERFLOW", "FP underflow."),
+    (0xC0020048, "RPC_NT_FP_OVERFLOW", "FP overflow."),
+    (0xC0020049, "RPC_NT_CALL_IN_PROGRESS", "Call in progress."),
+    (0xC002004A, "RPC_NT_NO_MORE_BINDINGS", "No more bindings."),
+    (0xC002004B, "RPC_NT_GROUP_MEMBER_NOT_FOUND", "Group member not found."),
+    (0xC002004C, "EPT_NT_CANT_CREATE", "Cant create."),
+    (0xC002004D, "RPC_NT_INVALID_OBJECT", "Invalid object."),
+    (0xC002004F, "RPC_NT_NO_INTERFACES", "No interfaces."),
+    (0xC0020050, "RPC_NT_CALL_CANCELLED", "Call cancelled."),
+    (0xC0020051, "RPC_NT_BINDING_INCOMPLETE", "Binding incomplete."),
+    (0xC0020052, "RPC_NT_COMM_FAILURE", "Comm failure."),
+    (0xC0020053, "RPC_NT_UNSUPPORTED_AUTHN_LEVEL", "Unsupported authn level."),
+    (0xC0020054, "RPC_NT_NO_PRINC_NAME", "No princ name."),
+    (0xC0020055, "RPC_NT_NOT_RPC_ERROR", "Not RPC error."),
+    (0xC0020057, "RPC_NT_SEC_PKG_ERROR", "Sec PKG error."),
+    (0xC0020058, "RPC_NT_NOT_CANCELLED", "Not cancelled."),
+    (0xC0020062, "RPC_NT_INVALID_ASYNC_HANDLE", "Invalid async handle."),
+    (0xC0020063, "RPC_NT_INVALID_ASYNC_CALL", "Invalid async call."),
+    (0xC0020064, "RPC_NT_PROXY_ACCESS_DENIED", "Proxy access denied."),
+    (0xC0020065, "RPC_NT_COOKIE_AUTH_FAILED", "Cookie auth failed."),
+    (0xC0030001, "RPC_NT_NO_MORE_ENTRIES", "No more entries."),
+    (0xC0030002, "RPC_NT_SS_CHAR_TRANS_OPEN_FAIL", "SS char trans open fail."),
+    (0xC0030003, "RPC_NT_SS_CHAR_TRANS_SHORT_FILE", "SS char trans short file."),
+    (0xC0030004, "RPC_NT_SS_IN_NULL_CONTEXT", "SS in null context."),
+    (0xC0030005, "RPC_NT_SS_CONTEXT_MISMATCH", "SS context mismatch."),
+    (0xC0030006, "RPC_NT_SS_CONTEXT_DAMAGED", "SS context damaged."),
+    (0xC0030007, "RPC_NT_SS_HANDLES_MISMATCH", "SS handles mismatch."),
+    (0xC0030008, "RPC_NT_SS_CANNOT_GET_CALL_HANDLE", "SS cannot get call handle."),
+    (0xC0030009, "RPC_NT_NULL_REF_POINTER", "Null ref pointer."),
+    (0xC003000A, "RPC_NT_ENUM_VALUE_OUT_OF_RANGE", "Enum value out of range."),
+    (0xC003000B, "RPC_NT_BYTE_COUNT_TOO_SMALL", "Byte count too small."),
+    (0xC003000C, "RPC_NT_BAD_STUB_DATA", "Bad stub data."),
+    (0xC0030059, "RPC_NT_INVALID_ES_ACTION", "Invalid es action."),
+    (0xC003005A, "RPC_NT_WRONG_ES_VERSION", "Wrong es version."),
+    (0xC003005B, "RPC_NT_WRONG_STUB_VERSION", "Wrong stub version."),
+    (0xC003005C, "RPC_NT_INVALID_PIPE_OBJECT", "Invalid pipe object."),
+    (0xC003005D, "RPC_NT_INVALID_PIPE_OPERATION", "Invalid pipe operation."),
+    (0xC003005E, "RPC_NT_WRONG_PIPE_VERSION", "Wrong pipe version."),
+    (0xC003005F, "RPC_NT_PIPE_CLOSED", "Pipe closed."),
+    (0xC0030060, "RPC_NT_PIPE_DISCIPLINE_ERROR", "Pipe discipline error."),
+    (0xC0030061, "RPC_NT_PIPE_EMPTY", "Pipe empty."),
+    (0xC0040035, "STATUS_PNP_BAD_MPS_TABLE", "PNP bad MPS table."),
+    (0xC0040036, "STATUS_PNP_TRANSLATION_FAILED", "PNP translation failed."),
+    (0xC0040037, "STATUS_PNP_IRQ_TRANSLATION_FAILED", "PNP irq translation failed."),
+    (0xC0040038, "STATUS_PNP_INVALID_ID", "PNP invalid ID."),
+    (0xC0040039, "STATUS_IO_REISSUE_AS_CACHED", "IO reissue as cached."),
+    (0xC00A0001, "STATUS_CTX_WINSTATION_NAME_INVALID", "CTX winstation name invalid."),
+    (0xC00A0002, "STATUS_CTX_INVALID_PD", "CTX invalid PD."),
+    (0xC00A0003, "STATUS_CTX_PD_NOT_FOUND", "CTX PD not found."),
+    (0xC00A0006, "STATUS_CTX_CLOSE_PENDING", "CTX close pending."),
+    (0xC00A0007, "STATUS_CTX_NO_OUTBUF", "CTX no outbuf."),
+    (0xC00A0008, "STATUS_CTX_MODEM_INF_NOT_FOUND", "CTX modem inf not found."),
+    (0xC00A0009, "STATUS_CTX_INVALID_MODEMNAME", "CTX invalid modemname."),
+    (0xC00A000A, "STATUS_CTX_RESPONSE_ERROR", "CTX response error."),
+    (0xC00A000B, "STATUS_CTX_MODEM_RESPONSE_TIMEOUT", "CTX modem response timeout."),
+    (0xC00A000C, "STATUS_CTX_MODEM_RESPONSE_NO_CARRIER", "CTX modem response no carrier."),
+    (0xC00A000D, "STATUS_CTX_MODEM_RESPONSE_NO_DIALTONE", "CTX modem response no dialtone."),
+    (0xC00A000E, "STATUS_CTX_MODEM_RESPONSE_BUSY", "CTX modem response busy."),
+    (0xC00A000F, "STATUS_CTX_MODEM_RESPONSE_VOICE", "CTX modem response voice."),
+    (0xC00A0010, "STATUS_CTX_TD_ERROR", "CTX TD error."),
+    (0xC00A0012, "STATUS_CTX_LICENSE_CLIENT_INVALID", "CTX license client invalid."),
+    (0xC00A0013, "STATUS_CTX_LICENSE_NOT_AVAILABLE", "CTX license not available."),
+    (0xC00A0014, "STATUS_CTX_LICENSE_EXPIRED", "CTX license expired."),
+    (0xC00A0015, "STATUS_CTX_WINSTATION_NOT_FOUND", "CTX winstation not found."),
+    (0xC00A0016, "STATUS_CTX_WINSTATION_NAME_COLLISION", "CTX winstation name collision."),
+    (0xC00A0017, "STATUS_CTX_WINSTATION_BUSY", "CTX winstation busy."),
+    (0xC00A0018, "STATUS_CTX_BAD_VIDEO_MODE", "CTX bad video mode."),
+    (0xC00A0022, "STATUS_CTX_GRAPHICS_INVALID", "CTX graphics invalid."),
+    (0xC00A0024, "STATUS_CTX_NOT_CONSOLE", "CTX not console."),
+    (0xC00A0026, "STATUS_CTX_CLIENT_QUERY_TIMEOUT", "CTX client query timeout."),
+    (0xC00A0027, "STATUS_CTX_CONSOLE_DISCONNECT", "CTX console disconnect."),
+    (0xC00A0028, "STATUS_CTX_CONSOLE_CONNECT", "CTX console connect."),
+    (0xC00A002A, "STATUS_CTX_SHADOW_DENIED", "CTX shadow denied."),
+    (0xC00A002B, "STATUS_CTX_WINSTATION_ACCESS_DENIED", "CTX winstation access denied."),
+    (0xC00A002E, "STATUS_CTX_INVALID_WD", "CTX invalid WD."),
+    (0xC00A002F, "STATUS_CTX_WD_NOT_FOUND", "CTX WD not found."),
+    (0xC00A0030, "STATUS_CTX_SHADOW_INVALID", "CTX shadow invalid."),
+    (0xC00A0031, "STATUS_CTX_SHADOW_DISABLED", "CTX shadow disabled."),
+    (0xC00A0032, "STATUS_RDP_PROTOCOL_ERROR", "RDP protocol error."),
+    (0xC00A0033, "STATUS_CTX_CLIENT_LICENSE_NOT_SET", "CTX client license not set."),
+    (0xC00A0034, "STATUS_CTX_CLIENT_LICENSE_IN_USE", "CTX client license in use."),
+    (0xC00A0035, "STATUS_CTX_SHADOW_ENDED_BY_MODE_CHANGE", "CTX shadow ended by mode change."),
+    (0xC00A0036, "STATUS_CTX_SHADOW_NOT_RUNNING", "CTX shadow not running."),
+    (0xC00A0037, "STATUS_CTX_LOGON_DISABLED", "CTX logon disabled."),
+    (0xC00A0038, "STATUS_CTX_SECURITY_LAYER_ERROR", "CTX security layer error."),
+    (0xC00A0039, "STATUS_TS_INCOMPATIBLE_SESSIONS", "TS incompatible sessions."),
+    (0xC00A003A, "STATUS_TS_VIDEO_SUBSYSTEM_ERROR", "TS video subsystem error."),
+    (0xC00B0001, "STATUS_MUI_FILE_NOT_FOUND", "MUI file not found."),
+    (0xC00B0002, "STATUS_MUI_INVALID_FILE", "MUI invalid file."),
+    (0xC00B0003, "STATUS_MUI_INVALID_RC_CONFIG", "MUI invalid RC config."),
+    (0xC00B0004, "STATUS_MUI_INVALID_LOCALE_NAME", "MUI invalid locale name."),
+    (0xC00B0005, "STATUS_MUI_INVALID_ULTIMATEFALLBACK_NAME", "MUI invalid ultimatefallback name."),
+    (0xC00B0006, "STATUS_MUI_FILE_NOT_LOADED", "MUI file not loaded."),
+    (0xC00B0007, "STATUS_RESOURCE_ENUM_USER_STOP", "Resource enum user stop."),
+    (0xC0130001, "STATUS_CLUSTER_INVALID_NODE", "Cluster invalid node."),
+    (0xC0130002, "STATUS_CLUSTER_NODE_EXISTS", "Cluster node exists."),
+    (0xC0130003, "STATUS_CLUSTER_JOIN_IN_PROGRESS", "Cluster join in progress."),
+    (0xC0130004, "STATUS_CLUSTER_NODE_NOT_FOUND", "Cluster node not found."),
+    (0xC0130005, "STATUS_CLUSTER_LOCAL_NODE_NOT_FOUND", "Cluster local node not found."),
+    (0xC0130006, "STATUS_CLUSTER_NETWORK_EXISTS", "Cluster network exists."),
+    (0xC0130007, "STATUS_CLUSTER_NETWORK_NOT_FOUND", "Cluster network not found."),
+    (0xC0130008, "STATUS_CLUSTER_NETINTERFACE_EXISTS", "Cluster netinterface exists."),
+    (0xC0130009, "STATUS_CLUSTER_NETINTERFACE_NOT_FOUND", "Cluster netinterface not found."),
+    (0xC013000A, "STATUS_CLUSTER_INVALID_REQUEST", "Cluster invalid request."),
+    (0xC013000B, "STATUS_CLUSTER_INVALID_NETWORK_PROVIDER", "Cluster invalid network provider."),
+    (0xC013000C, "STATUS_CLUSTER_NODE_DOWN", "Cluster node down."),
+    (0xC013000D, "STATUS_CLUSTER_NODE_UNREACHABLE", "Cluster node unreachable."),
+    (0xC013000E, "STATUS_CLUSTER_NODE_NOT_MEMBER", "Cluster node not member."),
+    (0xC013000F, "STATUS_CLUSTER_JOIN_NOT_IN_PROGRESS", "Cluster join not in progress."),
+    (0xC0130010, "STATUS_CLUSTER_INVALID_NETWORK", "Cluster invalid network."),
+    (0xC0130011, "STATUS_CLUSTER_NO_NET_ADAPTERS", "Cluster no net adapters."),
+    (0xC0130012, "STATUS_CLUSTER_NODE_UP", "Cluster node up."),
+    (0xC0130013, "STATUS_CLUSTER_NODE_PAUSED", "Cluster node paused."),
+    (0xC0130014, "STATUS_CLUSTER_NODE_NOT_PAUSED", "Cluster node not paused."),
+    (0xC0130015, "STATUS_CLUSTER_NO_SECURITY_CONTEXT", "Cluster no security context."),
+    (0xC0130016, "STATUS_CLUSTER_NETWORK_NOT_INTERNAL", "Cluster network not internal."),
+    (0xC0130017, "STATUS_CLUSTER_POISONED", "Cluster poisoned."),
+    (0xC0130018, "STATUS_CLUSTER_NON_CSV_PATH", "Cluster non CSV path."),
+    (0xC0130019, "STATUS_CLUSTER_CSV_VOLUME_NOT_LOCAL", "Cluster CSV volume not local."),
+    (0xC0130020, "STATUS_CLUSTER_CSV_READ_OPLOCK_BREAK_IN_PROGRESS", "Cluster CSV read oplock break in progress."),
+    (0xC0130021, "STATUS_CLUSTER_CSV_AUTO_PAUSE_ERROR", "Cluster CSV auto pause error."),
+    (0xC0130022, "STATUS_CLUSTER_CSV_REDIRECTED", "Cluster CSV redirected."),
+    (0xC0130023, "STATUS_CLUSTER_CSV_NOT_REDIRECTED", "Cluster CSV not redirected."),
+    (0xC0130024, "STATUS_CLUSTER_CSV_VOLUME_DRAINING", "Cluster CSV volume draining."),
+    (0xC0130025, "STATUS_CLUSTER_CSV_SNAPSHOT_CREATION_IN_PROGRESS", "Cluster CSV snapshot creation in progress."),
+    (0xC0130026, "STATUS_CLUSTER_CSV_VOLUME_DRAINING_SUCCEEDED_DOWNLEVEL", "Cluster CSV volume draining succeeded downlevel."),
+    (0xC0130027, "STATUS_CLUSTER_CSV_NO_SNAPSHOTS", "Cluster CSV no snapshots."),
+    (0xC0130028, "STATUS_CSV_IO_PAUSE_TIMEOUT", "CSV IO pause timeout."),
+    (0xC0130029, "STATUS_CLUSTER_CSV_INVALID_HANDLE", "Cluster CSV invalid handle."),
+    (0xC0130030, "STATUS_CLUSTER_CSV_SUPPORTED_ONLY_ON_COORDINATOR", "Cluster CSV supported only on coordinator."),
+    (0xC0130031, "STATUS_CLUSTER_CAM_TICKET_REPLAY_DETECTED", "Cluster cam ticket replay detected."),
+    (0xC0140001, "STATUS_ACPI_INVALID_OPCODE", "ACPI invalid opcode."),
+    (0xC0140002, "STATUS_ACPI_STACK_OVERFLOW", "ACPI stack overflow."),
+    (0xC0140003, "STATUS_ACPI_ASSERT_FAILED", "ACPI assert failed."),
+    (0xC0140004, "STATUS_ACPI_INVALID_INDEX", "ACPI invalid index."),
+    (0xC0140005, "STATUS_ACPI_INVALID_ARGUMENT", "ACPI invalid argument."),
+    (0xC0140006, "STATUS_ACPI_FATAL", "ACPI fatal."),
+    (0xC0140007, "STATUS_ACPI_INVALID_SUPERNAME", "ACPI invalid supername."),
+    (0xC0140008, "STATUS_ACPI_INVALID_ARGTYPE", "ACPI invalid argtype."),
+    (0xC0140009, "STATUS_ACPI_INVALID_OBJTYPE", "ACPI invalid objtype."),
+    (0xC014000A, "STATUS_ACPI_INVALID_TARGETTYPE", "ACPI invalid targettype."),
+    (0xC014000B, "STATUS_ACPI_INCORRECT_ARGUMENT_COUNT", "ACPI incorrect argument count."),
+    (0xC014000C, "STATUS_ACPI_ADDRESS_NOT_MAPPED", "ACPI address not mapped."),
+    (0xC014000D, "STATUS_ACPI_INVALID_EVENTTYPE", "ACPI invalid eventtype."),
+    (0xC014000E, "STATUS_ACPI_HANDLER_COLLISION", "ACPI handler collision."),
+    (0xC014000F, "STATUS_ACPI_INVALID_DATA", "ACPI invalid data."),
+    (0xC0140010, "STATUS_ACPI_INVALID_REGION", "ACPI invalid region."),
+    (0xC0140011, "STATUS_ACPI_INVALID_ACCESS_SIZE", "ACPI invalid access size."),
+    (0xC0140012, "STATUS_ACPI_ACQUIRE_GLOBAL_LOCK", "ACPI acquire global lock."),
+    (0xC0140013, "STATUS_ACPI_ALREADY_INITIALIZED", "ACPI already initialized."),
+    (0xC0140014, "STATUS_ACPI_NOT_INITIALIZED", "ACPI not initialized."),
+    (0xC0140015, "STATUS_ACPI_INVALID_MUTEX_LEVEL", "ACPI invalid mutex level."),
+    (0xC0140016, "STATUS_ACPI_MUTEX_NOT_OWNED", "ACPI mutex not owned."),
+    (0xC0140017, "STATUS_ACPI_MUTEX_NOT_OWNER", "ACPI mutex not owner."),
+    (0xC0140018, "STATUS_ACPI_RS_ACCESS", "ACPI RS access."),
+    (0xC0140019, "STATUS_ACPI_INVALID_TABLE", "ACPI invalid table."),
+    (0xC0140020, "STATUS_ACPI_REG_HANDLER_FAILED", "ACPI reg handler failed."),
+    (0xC0140021, "STATUS_ACPI_POWER_REQUEST_FAILED", "ACPI power request failed."),
+    (0xC0150001, "STATUS_SXS_SECTION_NOT_FOUND", "SXS section not found."),
+    (0xC0150002, "STATUS_SXS_CANT_GEN_ACTCTX", "SXS cant gen actctx."),
+    (0xC0150003, "STATUS_SXS_INVALID_ACTCTXDATA_FORMAT", "SXS invalid actctxdata format."),
+    (0xC0150004, "STATUS_SXS_ASSEMBLY_NOT_FOUND", "SXS assembly not found."),
+    (0xC0150005, "STATUS_SXS_MANIFEST_FORMAT_ERROR", "SXS manifest format error."),
+    (0xC0150006, "STATUS_SXS_MANIFEST_PARSE_ERROR", "SXS manifest parse error."),
+    (0xC0150007, "STATUS_SXS_ACTIVATION_CONTEXT_DISABLED", "SXS activation context disabled."),
+    (0xC0150008, "STATUS_SXS_KEY_NOT_FOUND", "SXS key not found."),
+    (0xC0150009, "STATUS_SXS_VERSION_CONFLICT", "SXS version conflict."),
+    (0xC015000A, "STATUS_SXS_WRONG_SECTION_TYPE", "SXS wrong section type."),
+    (0xC015000B, "STATUS_SXS_THREAD_QUERIES_DISABLED", "SXS thread queries disabled."),
+    (0xC015000C, "STATUS_SXS_ASSEMBLY_MISSING", "SXS assembly missing."),
+    (0xC015000E, "STATUS_SXS_PROCESS_DEFAULT_ALREADY_SET", "SXS process default already set."),
+    (0xC015000F, "STATUS_SXS_EARLY_DEACTIVATION", "SXS early deactivation."),
+    (0xC0150010, "STATUS_SXS_INVALID_DEACTIVATION", "SXS invalid deactivation."),
+    (0xC0150011, "STATUS_SXS_MULTIPLE_DEACTIVATION", "SXS multiple deactivation."),
+    (0xC0150012, "STATUS_SXS_SYSTEM_DEFAULT_ACTIVATION_CONTEXT_EMPTY", "SXS system default activation context empty."),
+    (0xC0150013, "STATUS_SXS_PROCESS_TERMINATION_REQUESTED", "SXS process termination requested."),
+    (0xC0150014, "STATUS_SXS_CORRUPT_ACTIVATION_STACK", "SXS corrupt activation stack."),
+    (0xC0150015, "STATUS_SXS_CORRUPTION", "SXS corruption."),
+    (0xC0150016, "STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_VALUE", "SXS invalid identity attribute value."),
+    (0xC0150017, "STATUS_SXS_INVALID_IDENTITY_ATTRIBUTE_NAME", "SXS invalid identity attribute name."),
+    (0xC0150018, "STATUS_SXS_IDENTITY_DUPLICATE_ATTRIBUTE", "SXS identity duplicate attribute."),
+    (0xC0150019, "STATUS_SXS_IDENTITY_PARSE_ERROR", "SXS identity parse error."),
+    (0xC015001A, "STATUS_SXS_COMPONENT_STORE_CORRUPT", "SXS component store corrupt."),
+    (0xC015001B, "STATUS_SXS_FILE_HASH_MISMATCH", "SXS file hash mismatch."),
+    (0xC015001C, "STATUS_SXS_MANIFEST_IDENTITY_SAME_BUT_CONTENTS_DIFFERENT", "SXS manifest identity same but contents different."),
+    (0xC015001D, "STATUS_SXS_IDENTITIES_DIFFERENT", "SXS identities different."),
+    (0xC015001E, "STATUS_SXS_ASSEMBLY_IS_NOT_A_DEPLOYMENT", "SXS assembly is not a deployment."),
+    (0xC015001F, "STATUS_SXS_FILE_NOT_PART_OF_ASSEMBLY", "SXS file not part of assembly."),
+    (0xC0150020, "STATUS_ADVANCED_INSTALLER_FAILED", "Advanced installer failed."),
+    (0xC0150021, "STATUS_XML_ENCODING_MISMATCH", "XML encoding mismatch."),
+    (0xC0150022, "STATUS_SXS_MANIFEST_TOO_BIG", "SXS manifest too big."),
+    (0xC0150023, "STATUS_SXS_SETTING_NOT_REGISTERED", "SXS setting not registered."),
+    (0xC0150024, "STATUS_SXS_TRANSACTION_CLOSURE_INCOMPLETE", "SXS transaction closure incomplete."),
+    (0xC0150025, "STATUS_SMI_PRIMITIVE_INSTALLER_FAILED", "Smi primitive installer failed."),
+    (0xC0150026, "STATUS_GENERIC_COMMAND_FAILED", "Generic command failed."),
+    (0xC0150027, "STATUS_SXS_FILE_HASH_MISSING", "SXS file hash missing."),
+    (0xC0190001, "STATUS_TRANSACTIONAL_CONFLICT", "Transactional conflict."),
+    (0xC0190002, "STATUS_INVALID_TRANSACTION", "Invalid transaction."),
+    (0xC0190003, "STATUS_TRANSACTION_NOT_ACTIVE", "Transaction not active."),
+    (0xC0190004, "STATUS_TM_INITIALIZATION_FAILED", "TM initialization failed."),
+    (0xC0190005, "STATUS_RM_NOT_ACTIVE", "RM not active."),
+    (0xC0190006, "STATUS_RM_METADATA_CORRUPT", "RM metadata corrupt."),
+    (0xC0190007, "STATUS_TRANSACTION_NOT_JOINED", "Transaction not joined."),
+    (0xC0190008, "STATUS_DIRECTORY_NOT_RM", "Directory not RM."),
+    (0xC019000A, "STATUS_TRANSACTIONS_UNSUPPORTED_REMOTE", "Transactions unsupported remote."),
+    (0xC019000B, "STATUS_LOG_RESIZE_INVALID_SIZE", "Log resize invalid size."),
+    (0xC019000C, "STATUS_REMOTE_FILE_VERSION_MISMATCH", "Remote file version mismatch."),
+    (0xC019000F, "STATUS_CRM_PROTOCOL_ALREADY_EXISTS", "CRM protocol already exists."),
+    (0xC0190010, "STATUS_TRANSACTION_PROPAGATION_FAILED", "Transaction propagation failed."),
+    (0xC0190011, "STATUS_CRM_PROTOCOL_NOT_FOUND", "CRM protocol not found."),
+    (0xC0190012, "STATUS_TRANSACTION_SUPERIOR_EXISTS", "Transaction superior exists."),
+    (0xC0190013, "STATUS_TRANSACTION_REQUEST_NOT_VALID", "Transaction request not valid."),
+    (0xC0190014, "STATUS_TRANSACTION_NOT_REQUESTED", "Transaction not requested."),
+    (0xC0190015, "STATUS_TRANSACTION_ALREADY_ABORTED", "Transaction already aborted."),
+    (0xC0190016, "STATUS_TRANSACTION_ALREADY_COMMITTED", "Transaction already committed."),
+    (0xC0190017, "STATUS_TRANSACTION_INVALID_MARSHALL_BUFFER", "Transaction invalid marshall buffer."),
+    (0xC0190018, "STATUS_CURRENT_TRANSACTION_NOT_VALID", "Current transaction not valid."),
+    (0xC0190019, "STATUS_LOG_GROWTH_FAILED", "Log growth failed."),
+    (0xC0190021, "STATUS_OBJECT_NO_LONGER_EXISTS", "Object no longer exists."),
+    (0xC0190022, "STATUS_STREAM_MINIVERSION_NOT_FOUND", "Stream miniversion not found."),
+    (0xC0190023, "STATUS_STREAM_MINIVERSION_NOT_VALID", "Stream miniversion not valid."),
+    (0xC0190024, "STATUS_MINIVERSION_INACCESSIBLE_FROM_SPECIFIED_TRANSACTION", "Miniversion inaccessible from specified transaction."),
+    (0xC0190025, "STATUS_CANT_OPEN_MINIVERSION_WITH_MODIFY_INTENT", "Cant open miniversion with modify intent."),
+    (0xC0190026, "STATUS_CANT_CREATE_MORE_STREAM_MINIVERSIONS", "Cant create more stream miniversions."),
+    (0xC0190028, "STATUS_HANDLE_NO_LONGER_VALID", "Handle no longer valid."),
+    (0xC0190030, "STATUS_LOG_CORRUPTION_DETECTED", "Log corruption detected."),
+    (0xC0190032, "STATUS_RM_DISCONNECTED", "RM disconnected."),
+    (0xC0190033, "STATUS_ENLISTMENT_NOT_SUPERIOR", "Enlistment not superior."),
+    (0xC0190036, "STATUS_FILE_IDENTITY_NOT_PERSISTENT", "File identity not persistent."),
+    (0xC0190037, "STATUS_CANT_BREAK_TRANSACTIONAL_DEPENDENCY", "Cant break transactional dependency."),
+    (0xC0190038, "STATUS_CANT_CROSS_RM_BOUNDARY", "Cant cross RM boundary."),
+    (0xC0190039, "STATUS_TXF_DIR_NOT_EMPTY", "TXF dir not empty."),
+    (0xC019003A, "STATUS_INDOUBT_TRANSACTIONS_EXIST", "Indoubt transactions exist."),
+    (0xC019003B, "STATUS_TM_VOLATILE", "TM volatile."),
+    (0xC019003C, "STATUS_ROLLBACK_TIMER_EXPIRED", "Rollback timer expired."),
+    (0xC019003D, "STATUS_TXF_ATTRIBUTE_CORRUPT", "TXF attribute corrupt."),
+    (0xC019003E, "STATUS_EFS_NOT_ALLOWED_IN_TRANSACTION", "EFS not allowed in transaction."),
+    (0xC019003F, "STATUS_TRANSACTIONAL_OPEN_NOT_ALLOWED", "Transactional open not allowed."),
+    (0xC0190040, "STATUS_TRANSACTED_MAPPING_UNSUPPORTED_REMOTE", "Transacted mapping unsupported remote."),
+    (0xC0190043, "STATUS_TRANSACTION_REQUIRED_PROMOTION", "Transaction required promotion."),
+    (0xC0190044, "STATUS_CANNOT_EXECUTE_FILE_IN_TRANSACTION", "Cannot execute file in transaction."),
+    (0xC0190045, "STATUS_TRANSACTIONS_NOT_FROZEN", "Transactions not frozen."),
+    (0xC0190046, "STATUS_TRANSACTION_FREEZE_IN_PROGRESS", "Transaction freeze in progress."),
+    (0xC0190047, "STATUS_NOT_SNAPSHOT_VOLUME", "Not snapshot volume."),
+    (0xC0190048, "STATUS_NO_SAVEPOINT_WITH_OPEN_FILES", "No savepoint with open files."),
+    (0xC0190049, "STATUS_SPARSE_NOT_ALLOWED_IN_TRANSACTION", "Sparse not allowed in transaction."),
+    (0xC019004A, "STATUS_TM_IDENTITY_MISMATCH", "TM identity mismatch."),
+    (0xC019004B, "STATUS_FLOATED_SECTION", "Floated section."),
+    (0xC019004C, "STATUS_CANNOT_ACCEPT_TRANSACTED_WORK", "Cannot accept transacted work."),
+    (0xC019004D, "STATUS_CANNOT_ABORT_TRANSACTIONS", "Cannot abort transactions."),
+    (0xC019004E, "STATUS_TRANSACTION_NOT_FOUND", "Transaction not found."),
+    (0xC019004F, "STATUS_RESOURCEMANAGER_NOT_FOUND", "Resourcemanager not found."),
+    (0xC0190050, "STATUS_ENLISTMENT_NOT_FOUND", "Enlistment not found."),
+    (0xC0190051, "STATUS_TRANSACTIONMANAGER_NOT_FOUND", "Transactionmanager not found."),
+    (0xC0190052, "STATUS_TRANSACTIONMANAGER_NOT_ONLINE", "Transactionmanager not online."),
+    (0xC0190053, "STATUS_TRANSACTIONMANAGER_RECOVERY_NAME_COLLISION", "Transactionmanager recovery name collision."),
+    (0xC0190054, "STATUS_TRANSACTION_NOT_ROOT", "Transaction not root."),
+    (0xC0190055, "STATUS_TRANSACTION_OBJECT_EXPIRED", "Transaction object expired."),
+    (0xC0190056, "STATUS_COMPRESSION_NOT_ALLOWED_IN_TRANSACTION", "Compression not allowed in transaction."),
+    (0xC0190057, "STATUS_TRANSACTION_RESPONSE_NOT_ENLISTED", "Transaction response not enlisted."),
+    (0xC0190058, "STATUS_TRANSACTION_RECORD_TOO_LONG", "Transaction record too long."),
+    (0xC0190059, "STATUS_NO_LINK_TRACKING_IN_TRANSACTION", "No link tracking in transaction."),
+    (0xC019005A, "STATUS_OPERATION_NOT_SUPPORTED_IN_TRANSACTION", "Operation not supported in transaction."),
+    (0xC019005B, "STATUS_TRANSACTION_INTEGRITY_VIOLATED", "Transaction integrity violated."),
+    (0xC019005C, "STATUS_TRANSACTIONMANAGER_IDENTITY_MISMATCH", "Transactionmanager identity mismatch."),
+    (0xC019005D, "STATUS_RM_CANNOT_BE_FROZEN_FOR_SNAPSHOT", "RM cannot be frozen for snapshot."),
+    (0xC019005E, "STATUS_TRANSACTION_MUST_WRITETHROUGH", "Transaction must writethrough."),
+    (0xC019005F, "STATUS_TRANSACTION_NO_SUPERIOR", "Transaction no superior."),
+    (0xC0190060, "STATUS_EXPIRED_HANDLE", "Expired handle."),
+    (0xC0190061, "STATUS_TRANSACTION_NOT_ENLISTED", "Transaction not enlisted."),
+    (0xC01A0001, "STATUS_LOG_SECTOR_INVALID", "Log sector invalid."),
+    (0xC01A0002, "STATUS_LOG_SECTOR_PARITY_INVALID", "Log sector parity invalid."),
+    (0xC01A0003, "STATUS_LOG_SECTOR_REMAPPED", "Log sector remapped."),
+    (0xC01A0004, "STATUS_LOG_BLOCK_INCOMPLETE", "Log block incomplete."),
+    (0xC01A0005, "STATUS_LOG_INVALID_RANGE", "Log invalid range."),
+    (0xC01A0006, "STATUS_LOG_BLOCKS_EXHAUSTED", "Log blocks exhausted."),
+    (0xC01A0007, "STATUS_LOG_READ_CONTEXT_INVALID", "Log read context invalid."),
+    (0xC01A0008, "STATUS_LOG_RESTART_INVALID", "Log restart invalid."),
+    (0xC01A0009, "STATUS_LOG_BLOCK_VERSION", "Log block version."),
+    (0xC01A000A, "STATUS_LOG_BLOCK_INVALID", "Log block invalid."),
+    (0xC01A000B, "STATUS_LOG_READ_MODE_INVALID", "Log read mode invalid."),
+    (0xC01A000D, "STATUS_LOG_METADATA_CORRUPT", "Log metadata corrupt."),
+    (0xC01A000E, "STATUS_LOG_METADATA_INVALID", "Log metadata invalid."),
+    (0xC01A000F, "STATUS_LOG_METADATA_INCONSISTENT", "Log metadata inconsistent."),
+    (0xC01A0010, "STATUS_LOG_RESERVATION_INVALID", "Log reservation invalid."),
+    (0xC01A0011, "STATUS_LOG_CANT_DELETE", "Log cant delete."),
+    (0xC01A0012, "STATUS_LOG_CONTAINER_LIMIT_EXCEEDED", "Log container limit exceeded."),
+    (0xC01A0013, "STATUS_LOG_START_OF_LOG", "Log start of log."),
+    (0xC01A0014, "STATUS_LOG_POLICY_ALREADY_INSTALLED", "Log policy already installed."),
+    (0xC01A0015, "STATUS_LOG_POLICY_NOT_INSTALLED", "Log policy not installed."),
+    (0xC01A0016, "STATUS_LOG_POLICY_INVALID", "Log policy invalid."),
+    (0xC01A0017, "STATUS_LOG_POLICY_CONFLICT", "Log policy conflict."),
+    (0xC01A0018, "STATUS_LOG_PINNED_ARCHIVE_TAIL", "Log pinned archive tail."),
+    (0xC01A0019, "STATUS_LOG_RECORD_NONEXISTENT", "Log record nonexistent."),
+    (0xC01A001A, "STATUS_LOG_RECORDS_RESERVED_INVALID", "Log records reserved invalid."),
+    (0xC01A001B, "STATUS_LOG_SPACE_RESERVED_INVALID", "Log space reserved invalid."),
+    (0xC01A001C, "STATUS_LOG_TAIL_INVALID", "Log tail invalid."),
+    (0xC01A001D, "STATUS_LOG_FULL", "Log full."),
+    (0xC01A001E, "STATUS_LOG_MULTIPLEXED", "Log multiplexed."),
+    (0xC01A001F, "STATUS_LOG_DEDICATED", "Log dedicated."),
+    (0xC01A0020, "STATUS_LOG_ARCHIVE_NOT_IN_PROGRESS", "Log archive not in progress."),
+    (0xC01A0021, "STATUS_LOG_ARCHIVE_IN_PROGRESS", "Log archive in progress."),
+    (0xC01A0022, "STATUS_LOG_EPHEMERAL", "Log ephemeral."),
+    (0xC01A0023, "STATUS_LOG_NOT_ENOUGH_CONTAINERS", "Log not enough containers."),
+    (0xC01A0024, "STATUS_LOG_CLIENT_ALREADY_REGISTERED", "Log client already registered."),
+    (0xC01A0025, "STATUS_LOG_CLIENT_NOT_REGISTERED", "Log client not registered."),
+    (0xC01A0026, "STATUS_LOG_FULL_HANDLER_IN_PROGRESS", "Log full handler in progress."),
+    (0xC01A0027, "STATUS_LOG_CONTAINER_READ_FAILED", "Log container read failed."),
+    (0xC01A0028, "STATUS_LOG_CONTAINER_WRITE_FAILED", "Log container write failed."),
+    (0xC01A0029, "STATUS_LOG_CONTAINER_OPEN_FAILED", "Log container open failed."),
+    (0xC01A002A, "STATUS_LOG_CONTAINER_STATE_INVALID", "Log container state invalid."),
+    (0xC01A002B, "STATUS_LOG_STATE_INVALID", "Log state invalid."),
+    (0xC01A002C, "STATUS_LOG_PINNED", "Log pinned."),
+    (0xC01A002D, "STATUS_LOG_METADATA_FLUSH_FAILED", "Log metadata flush failed."),
+    (0xC01A002E, "STATUS_LOG_INCONSISTENT_SECURITY", "Log inconsistent security."),
+    (0xC01A002F, "STATUS_LOG_APPENDED_FLUSH_FAILED", "Log appended flush failed."),
+    (0xC01A0030, "STATUS_LOG_PINNED_RESERVATION", "Log pinned reservation."),
+    (0xC01B00EA, "STATUS_VIDEO_HUNG_DISPLAY_DRIVER_THREAD", "Video hung display driver thread."),
+    (0xC01C0001, "STATUS_FLT_NO_HANDLER_DEFINED", "FLT no handler defined."),
+    (0xC01C0002, "STATUS_FLT_CONTEXT_ALREADY_DEFINED", "FLT context already defined."),
+    (0xC01C0003, "STATUS_FLT_INVALID_ASYNCHRONOUS_REQUEST", "FLT invalid asynchronous request."),
+    (0xC01C0004, "STATUS_FLT_DISALLOW_FAST_IO", "FLT disallow fast IO."),
+    (0xC01C0005, "STATUS_FLT_INVALID_NAME_REQUEST", "FLT invalid name request."),
+    (0xC01C0006, "STATUS_FLT_NOT_SAFE_TO_POST_OPERATION", "FLT not safe to post operation."),
+    (0xC01C0007, "STATUS_FLT_NOT_INITIALIZED", "FLT not initialized."),
+    (0xC01C0008, "STATUS_FLT_FILTER_NOT_READY", "FLT filter not ready."),
+    (0xC01C0009, "STATUS_FLT_POST_OPERATION_CLEANUP", "FLT post operation cleanup."),
+    (0xC01C000A, "STATUS_FLT_INTERNAL_ERROR", "FLT internal error."),
+    (0xC01C000B, "STATUS_FLT_DELETING_OBJECT", "FLT deleting object."),
+    (0xC01C000C, "STATUS_FLT_MUST_BE_NONPAGED_POOL", "FLT must be nonpaged pool."),
+    (0xC01C000D, "STATUS_FLT_DUPLICATE_ENTRY", "FLT duplicate entry."),
+    (0xC01C000E, "STATUS_FLT_CBDQ_DISABLED", "FLT CBDQ disabled."),
+    (0xC01C000F, "STATUS_FLT_DO_NOT_ATTACH", "FLT do not attach."),
+    (0xC01C0010, "STATUS_FLT_DO_NOT_DETACH", "FLT do not detach."),
+    (0xC01C0011, "STATUS_FLT_INSTANCE_ALTITUDE_COLLISION", "FLT instance altitude collision."),
+    (0xC01C0012, "STATUS_FLT_INSTANCE_NAME_COLLISION", "FLT instance name collision."),
+    (0xC01C0013, "STATUS_FLT_FILTER_NOT_FOUND", "FLT filter not found."),
+    (0xC01C0014, "STATUS_FLT_VOLUME_NOT_FOUND", "FLT volume not found."),
+    (0xC01C0015, "STATUS_FLT_INSTANCE_NOT_FOUND", "FLT instance not found."),
+    (0xC01C0016, "STATUS_FLT_CONTEXT_ALLOCATION_NOT_FOUND", "FLT context allocation not found."),
+    (0xC01C0017, "STATUS_FLT_INVALID_CONTEXT_REGISTRATION", "FLT invalid context registration."),
+    (0xC01C0018, "STATUS_FLT_NAME_CACHE_MISS", "FLT name cache miss."),
+    (0xC01C0019, "STATUS_FLT_NO_DEVICE_OBJECT", "FLT no device object."),
+    (0xC01C001A, "STATUS_FLT_VOLUME_ALREADY_MOUNTED", "FLT volume already mounted."),
+    (0xC01C001B, "STATUS_FLT_ALREADY_ENLISTED", "FLT already enlisted."),
+    (0xC01C001C, "STATUS_FLT_CONTEXT_ALREADY_LINKED", "FLT context already linked."),
+    (0xC01C0020, "STATUS_FLT_NO_WAITER_FOR_REPLY", "FLT no waiter for reply."),
+    (0xC01C0023, "STATUS_FLT_REGISTRATION_BUSY", "FLT registration busy."),
+    (0xC01C0024, "STATUS_FLT_WCOS_NOT_SUPPORTED", "FLT wcos not supported."),
+    (0xC01D0001, "STATUS_MONITOR_NO_DESCRIPTOR", "Monitor no descriptor."),
+    (0xC01D0002, "STATUS_MONITOR_UNKNOWN_DESCRIPTOR_FORMAT", "Monitor unknown descriptor format."),
+    (0xC01D0003, "STATUS_MONITOR_INVALID_DESCRIPTOR_CHECKSUM", "Monitor invalid descriptor checksum."),
+    (0xC01D0004, "STATUS_MONITOR_INVALID_STANDARD_TIMING_BLOCK", "Monitor invalid standard timing block."),
+    (0xC01D0005, "STATUS_MONITOR_WMI_DATABLOCK_REGISTRATION_FAILED", "Monitor WMI datablock registration failed."),
+    (0xC01D0006, "STATUS_MONITOR_INVALID_SERIAL_NUMBER_MONDSC_BLOCK", "Monitor invalid serial number mondsc block."),
+    (0xC01D0007, "STATUS_MONITOR_INVALID_USER_FRIENDLY_MONDSC_BLOCK", "Monitor invalid user friendly mondsc block."),
+    (0xC01D0008, "STATUS_MONITOR_NO_MORE_DESCRIPTOR_DATA", "Monitor no more descriptor data."),
+    (0xC01D0009, "STATUS_MONITOR_INVALID_DETAILED_TIMING_BLOCK", "Monitor invalid detailed timing block."),
+    (0xC01D000A, "STATUS_MONITOR_INVALID_MANUFACTURE_DATE", "Monitor invalid manufacture date."),
+    (0xC01E0000, "STATUS_GRAPHICS_NOT_EXCLUSIVE_MODE_OWNER", "Graphics not exclusive mode owner."),
+    (0xC01E0001, "STATUS_GRAPHICS_INSUFFICIENT_DMA_BUFFER", "Graphics insufficient dma buffer."),
+    (0xC01E0002, "STATUS_GRAPHICS_INVALID_DISPLAY_ADAPTER", "Graphics invalid display adapter."),
+    (0xC01E0003, "STATUS_GRAPHICS_ADAPTER_WAS_RESET", "Graphics adapter was reset."),
+    (0xC01E0004, "STATUS_GRAPHICS_INVALID_DRIVER_MODEL", "Graphics invalid driver model."),
+    (0xC01E0005, "STATUS_GRAPHICS_PRESENT_MODE_CHANGED", "Graphics present mode changed."),
+    (0xC01E0006, "STATUS_GRAPHICS_PRESENT_OCCLUDED", "Graphics present occluded."),
+    (0xC01E0007, "STATUS_GRAPHICS_PRESENT_DENIED", "Graphics present denied."),
+    (0xC01E0008, "STATUS_GRAPHICS_CANNOTCOLORCONVERT", "Graphics cannotcolorconvert."),
+    (0xC01E0009, "STATUS_GRAPHICS_DRIVER_MISMATCH", "Graphics driver mismatch."),
+    (0xC01E000B, "STATUS_GRAPHICS_PRESENT_REDIRECTION_DISABLED", "Graphics present redirection disabled."),
+    (0xC01E000C, "STATUS_GRAPHICS_PRESENT_UNOCCLUDED", "Graphics present unoccluded."),
+    (0xC01E000D, "STATUS_GRAPHICS_WINDOWDC_NOT_AVAILABLE", "Graphics windowdc not available."),
+    (0xC01E000E, "STATUS_GRAPHICS_WINDOWLESS_PRESENT_DISABLED", "Graphics windowless present disabled."),
+    (0xC01E000F, "STATUS_GRAPHICS_PRESENT_INVALID_WINDOW", "Graphics present invalid window."),
+    (0xC01E0010, "STATUS_GRAPHICS_PRESENT_BUFFER_NOT_BOUND", "Graphics present buffer not bound."),
+    (0xC01E0011, "STATUS_GRAPHICS_VAIL_STATE_CHANGED", "Graphics vail state changed."),
+    (0xC01E0012, "STATUS_GRAPHICS_INDIRECT_DISPLAY_ABANDON_SWAPCHAIN", "Graphics indirect display abandon swapchain."),
+    (0xC01E0013, "STATUS_GRAPHICS_INDIRECT_DISPLAY_DEVICE_STOPPED", "Graphics indirect display device stopped."),
+    (0xC01E0018, "STATUS_GRAPHICS_MPO_ALLOCATION_UNPINNED", "Graphics mpo allocation unpinned."),
+    (0xC01E0100, "STATUS_GRAPHICS_NO_VIDEO_MEMORY", "Graphics no video memory."),
+    (0xC01E0101, "STATUS_GRAPHICS_CANT_LOCK_MEMORY", "Graphics cant lock memory."),
+    (0xC01E0102, "STATUS_GRAPHICS_ALLOCATION_BUSY", "Graphics allocation busy."),
+    (0xC01E0103, "STATUS_GRAPHICS_TOO_MANY_REFERENCES", "Graphics too many references."),
+    (0xC01E0104, "STATUS_GRAPHICS_TRY_AGAIN_LATER", "Graphics try again later."),
+    (0xC01E0105, "STATUS_GRAPHICS_TRY_AGAIN_NOW", "Graphics try again now."),
+    (0xC01E0106, "STATUS_GRAPHICS_ALLOCATION_INVALID", "Graphics allocation invalid."),
+    (0xC01E0107, "STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNAVAILABLE", "Graphics unswizzling aperture unavailable."),
+    (0xC01E0108, "STATUS_GRAPHICS_UNSWIZZLING_APERTURE_UNSUPPORTED", "Graphics unswizzling aperture unsupported."),
+    (0xC01E0109, "STATUS_GRAPHICS_CANT_EVICT_PINNED_ALLOCATION", "Graphics cant evict pinned allocation."),
+    (0xC01E0110, "STATUS_GRAPHICS_INVALID_ALLOCATION_USAGE", "Graphics invalid allocation usage."),
+    (0xC01E0111, "STATUS_GRAPHICS_CANT_RENDER_LOCKED_ALLOCATION", "Graphics cant render locked allocation."),
+    (0xC01E0112, "STATUS_GRAPHICS_ALLOCATION_CLOSED", "Graphics allocation closed."),
+    (0xC01E0113, "STATUS_GRAPHICS_INVALID_ALLOCATION_INSTANCE", "Graphics invalid allocation instance."),
+    (0xC01E0114, "STATUS_GRAPHICS_INVALID_ALLOCATION_HANDLE", "Graphics invalid allocation handle."),
+    (0xC01E0115, "STATUS_GRAPHICS_WRONG_ALLOCATION_DEVICE", "Graphics wrong allocation device."),
+    (0xC01E0116, "STATUS_GRAPHICS_ALLOCATION_CONTENT_LOST", "Graphics allocation content lost."),
+    (0xC01E0200, "STATUS_GRAPHICS_GPU_EXCEPTION_ON_DEVICE", "Graphics gpu exception on device."),
+    (0xC01E0300, "STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY", "Graphics invalid vidpn topology."),
+    (0xC01E0301, "STATUS_GRAPHICS_VIDPN_TOPOLOGY_NOT_SUPPORTED", "Graphics vidpn topology not supported."),
+    (0xC01E0302, "STATUS_GRAPHICS_VIDPN_TOPOLOGY_CURRENTLY_NOT_SUPPORTED", "Graphics vidpn topology currently not supported."),
+    (0xC01E0303, "STATUS_GRAPHICS_INVALID_VIDPN", "Graphics invalid vidpn."),
+    (0xC01E0304, "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE", "Graphics invalid video present source."),
+    (0xC01E0305, "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET", "Graphics invalid video present target."),
+    (0xC01E0306, "STATUS_GRAPHICS_VIDPN_MODALITY_NOT_SUPPORTED", "Graphics vidpn modality not supported."),
+    (0xC01E0308, "STATUS_GRAPHICS_INVALID_VIDPN_SOURCEMODESET", "Graphics invalid vidpn sourcemodeset."),
+    (0xC01E0309, "STATUS_GRAPHICS_INVALID_VIDPN_TARGETMODESET", "Graphics invalid vidpn targetmodeset."),
+    (0xC01E030A, "STATUS_GRAPHICS_INVALID_FREQUENCY", "Graphics invalid frequency."),
+    (0xC01E030B, "STATUS_GRAPHICS_INVALID_ACTIVE_REGION", "Graphics invalid active region."),
+    (0xC01E030C, "STATUS_GRAPHICS_INVALID_TOTAL_REGION", "Graphics invalid total region."),
+    (0xC01E0310, "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_SOURCE_MODE", "Graphics invalid video present source mode."),
+    (0xC01E0311, "STATUS_GRAPHICS_INVALID_VIDEO_PRESENT_TARGET_MODE", "Graphics invalid video present target mode."),
+    (0xC01E0312, "STATUS_GRAPHICS_PINNED_MODE_MUST_REMAIN_IN_SET", "Graphics pinned mode must remain in set."),
+    (0xC01E0313, "STATUS_GRAPHICS_PATH_ALREADY_IN_TOPOLOGY", "Graphics path already in topology."),
+    (0xC01E0314, "STATUS_GRAPHICS_MODE_ALREADY_IN_MODESET", "Graphics mode already in modeset."),
+    (0xC01E0315, "STATUS_GRAPHICS_INVALID_VIDEOPRESENTSOURCESET", "Graphics invalid videopresentsourceset."),
+    (0xC01E0316, "STATUS_GRAPHICS_INVALID_VIDEOPRESENTTARGETSET", "Graphics invalid videopresenttargetset."),
+    (0xC01E0317, "STATUS_GRAPHICS_SOURCE_ALREADY_IN_SET", "Graphics source already in set."),
+    (0xC01E0318, "STATUS_GRAPHICS_TARGET_ALREADY_IN_SET", "Graphics target already in set."),
+    (0xC01E0319, "STATUS_GRAPHICS_INVALID_VIDPN_PRESENT_PATH", "Graphics invalid vidpn present path."),
+    (0xC01E031A, "STATUS_GRAPHICS_NO_RECOMMENDED_VIDPN_TOPOLOGY", "Graphics no recommended vidpn topology."),
+    (0xC01E031B, "STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGESET", "Graphics invalid monitor frequencyrangeset."),
+    (0xC01E031C, "STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE", "Graphics invalid monitor frequencyrange."),
+    (0xC01E031D, "STATUS_GRAPHICS_FREQUENCYRANGE_NOT_IN_SET", "Graphics frequencyrange not in set."),
+    (0xC01E031F, "STATUS_GRAPHICS_FREQUENCYRANGE_ALREADY_IN_SET", "Graphics frequencyrange already in set."),
+    (0xC01E0320, "STATUS_GRAPHICS_STALE_MODESET", "Graphics stale modeset."),
+    (0xC01E0321, "STATUS_GRAPHICS_INVALID_MONITOR_SOURCEMODESET", "Graphics invalid monitor sourcemodeset."),
+    (0xC01E0322, "STATUS_GRAPHICS_INVALID_MONITOR_SOURCE_MODE", "Graphics invalid monitor source mode."),
+    (0xC01E0323, "STATUS_GRAPHICS_NO_RECOMMENDED_FUNCTIONAL_VIDPN", "Graphics no recommended functional vidpn."),
+    (0xC01E0324, "STATUS_GRAPHICS_MODE_ID_MUST_BE_UNIQUE", "Graphics mode ID must be unique."),
+    (0xC01E0325, "STATUS_GRAPHICS_EMPTY_ADAPTER_MONITOR_MODE_SUPPORT_INTERSECTION", "Graphics empty adapter monitor mode support intersection."),
+    (0xC01E0326, "STATUS_GRAPHICS_VIDEO_PRESENT_TARGETS_LESS_THAN_SOURCES", "Graphics video present targets less than sources."),
+    (0xC01E0327, "STATUS_GRAPHICS_PATH_NOT_IN_TOPOLOGY", "Graphics path not in topology."),
+    (0xC01E0328, "STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_SOURCE", "Graphics adapter must have at least one source."),
+    (0xC01E0329, "STATUS_GRAPHICS_ADAPTER_MUST_HAVE_AT_LEAST_ONE_TARGET", "Graphics adapter must have at least one target."),
+    (0xC01E032A, "STATUS_GRAPHICS_INVALID_MONITORDESCRIPTORSET", "Graphics invalid monitordescriptorset."),
+    (0xC01E032B, "STATUS_GRAPHICS_INVALID_MONITORDESCRIPTOR", "Graphics invalid monitordescriptor."),
+    (0xC01E032C, "STATUS_GRAPHICS_MONITORDESCRIPTOR_NOT_IN_SET", "Graphics monitordescriptor not in set."),
+    (0xC01E032D, "STATUS_GRAPHICS_MONITORDESCRIPTOR_ALREADY_IN_SET", "Graphics monitordescriptor already in set."),
+    (0xC01E032E, "STATUS_GRAPHICS_MONITORDESCRIPTOR_ID_MUST_BE_UNIQUE", "Graphics monitordescriptor ID must be unique."),
+    (0xC01E032F, "STATUS_GRAPHICS_INVALID_VIDPN_TARGET_SUBSET_TYPE", "Graphics invalid vidpn target subset type."),
+    (0xC01E0330, "STATUS_GRAPHICS_RESOURCES_NOT_RELATED", "Graphics resources not related."),
+    (0xC01E0331, "STATUS_GRAPHICS_SOURCE_ID_MUST_BE_UNIQUE", "Graphics source ID must be unique."),
+    (0xC01E0332, "STATUS_GRAPHICS_TARGET_ID_MUST_BE_UNIQUE", "Graphics target ID must be unique."),
+    (0xC01E0333, "STATUS_GRAPHICS_NO_AVAILABLE_VIDPN_TARGET", "Graphics no available vidpn target."),
+    (0xC01E0334, "STATUS_GRAPHICS_MONITOR_COULD_NOT_BE_ASSOCIATED_WITH_ADAPTER", "Graphics monitor could not be associated with adapter."),
+    (0xC01E0335, "STATUS_GRAPHICS_NO_VIDPNMGR", "Graphics no vidpnmgr."),
+    (0xC01E0336, "STATUS_GRAPHICS_NO_ACTIVE_VIDPN", "Graphics no active vidpn."),
+    (0xC01E0337, "STATUS_GRAPHICS_STALE_VIDPN_TOPOLOGY", "Graphics stale vidpn topology."),
+    (0xC01E0338, "STATUS_GRAPHICS_MONITOR_NOT_CONNECTED", "Graphics monitor not connected."),
+    (0xC01E0339, "STATUS_GRAPHICS_SOURCE_NOT_IN_TOPOLOGY", "Graphics source not in topology."),
+    (0xC01E033A, "STATUS_GRAPHICS_INVALID_PRIMARYSURFACE_SIZE", "Graphics invalid primarysurface size."),
+    (0xC01E033B, "STATUS_GRAPHICS_INVALID_VISIBLEREGION_SIZE", "Graphics invalid visibleregion size."),
+    (0xC01E033C, "STATUS_GRAPHICS_INVALID_STRIDE", "Graphics invalid stride."),
+    (0xC01E033D, "STATUS_GRAPHICS_INVALID_PIXELFORMAT", "Graphics invalid pixelformat."),
+    (0xC01E033E, "STATUS_GRAPHICS_INVALID_COLORBASIS", "Graphics invalid colorbasis."),
+    (0xC01E033F, "STATUS_GRAPHICS_INVALID_PIXELVALUEACCESSMODE", "Graphics invalid pixelvalueaccessmode."),
+    (0xC01E0340, "STATUS_GRAPHICS_TARGET_NOT_IN_TOPOLOGY", "Graphics target not in topology."),
+    (0xC01E0341, "STATUS_GRAPHICS_NO_DISPLAY_MODE_MANAGEMENT_SUPPORT", "Graphics no display mode management support."),
+    (0xC01E0342, "STATUS_GRAPHICS_VIDPN_SOURCE_IN_USE", "Graphics vidpn source in use."),
+    (0xC01E0343, "STATUS_GRAPHICS_CANT_ACCESS_ACTIVE_VIDPN", "Graphics cant access active vidpn."),
+    (0xC01E0344, "STATUS_GRAPHICS_INVALID_PATH_IMPORTANCE_ORDINAL", "Graphics invalid path importance ordinal."),
+    (0xC01E0345, "STATUS_GRAPHICS_INVALID_PATH_CONTENT_GEOMETRY_TRANSFORMATION", "Graphics invalid path content geometry transformation."),
+    (0xC01E0346, "STATUS_GRAPHICS_PATH_CONTENT_GEOMETRY_TRANSFORMATION_NOT_SUPPORTED", "Graphics path content geometry transformation not supported."),
+    (0xC01E0347, "STATUS_GRAPHICS_INVALID_GAMMA_RAMP", "Graphics invalid gamma ramp."),
+    (0xC01E0348, "STATUS_GRAPHICS_GAMMA_RAMP_NOT_SUPPORTED", "Graphics gamma ramp not supported."),
+    (0xC01E0349, "STATUS_GRAPHICS_MULTISAMPLING_NOT_SUPPORTED", "Graphics multisampling not supported."),
+    (0xC01E034A, "STATUS_GRAPHICS_MODE_NOT_IN_MODESET", "Graphics mode not in modeset."),
+    (0xC01E034D, "STATUS_GRAPHICS_INVALID_VIDPN_TOPOLOGY_RECOMMENDATION_REASON", "Graphics invalid vidpn topology recommendation reason."),
+    (0xC01E034E, "STATUS_GRAPHICS_INVALID_PATH_CONTENT_TYPE", "Graphics invalid path content type."),
+    (0xC01E034F, "STATUS_GRAPHICS_INVALID_COPYPROTECTION_TYPE", "Graphics invalid copyprotection type."),
+    (0xC01E0350, "STATUS_GRAPHICS_UNASSIGNED_MODESET_ALREADY_EXISTS", "Graphics unassigned modeset already exists."),
+    (0xC01E0352, "STATUS_GRAPHICS_INVALID_SCANLINE_ORDERING", "Graphics invalid scanline ordering."),
+    (0xC01E0353, "STATUS_GRAPHICS_TOPOLOGY_CHANGES_NOT_ALLOWED", "Graphics topology changes not allowed."),
+    (0xC01E0354, "STATUS_GRAPHICS_NO_AVAILABLE_IMPORTANCE_ORDINALS", "Graphics no available importance ordinals."),
+    (0xC01E0355, "STATUS_GRAPHICS_INCOMPATIBLE_PRIVATE_FORMAT", "Graphics incompatible private format."),
+    (0xC01E0356, "STATUS_GRAPHICS_INVALID_MODE_PRUNING_ALGORITHM", "Graphics invalid mode pruning algorithm."),
+    (0xC01E0357, "STATUS_GRAPHICS_INVALID_MONITOR_CAPABILITY_ORIGIN", "Graphics invalid monitor capability origin."),
+    (0xC01E0358, "STATUS_GRAPHICS_INVALID_MONITOR_FREQUENCYRANGE_CONSTRAINT", "Graphics invalid monitor frequencyrange constraint."),
+    (0xC01E0359, "STATUS_GRAPHICS_MAX_NUM_PATHS_REACHED", "Graphics max num paths reached."),
+    (0xC01E035A, "STATUS_GRAPHICS_CANCEL_VIDPN_TOPOLOGY_AUGMENTATION", "Graphics cancel vidpn topology augmentation."),
+    (0xC01E035B, "STATUS_GRAPHICS_INVALID_CLIENT_TYPE", "Graphics invalid client type."),
+    (0xC01E035C, "STATUS_GRAPHICS_CLIENTVIDPN_NOT_SET", "Graphics clientvidpn not set."),
+    (0xC01E0400, "STATUS_GRAPHICS_SPECIFIED_CHILD_ALREADY_CONNECTED", "Graphics specified child already connected."),
+    (0xC01E0401, "STATUS_GRAPHICS_CHILD_DESCRIPTOR_NOT_SUPPORTED", "Graphics child descriptor not supported."),
+    (0xC01E0430, "STATUS_GRAPHICS_NOT_A_LINKED_ADAPTER", "Graphics not a linked adapter."),
+    (0xC01E0431, "STATUS_GRAPHICS_LEADLINK_NOT_ENUMERATED", "Graphics leadlink not enumerated."),
+    (0xC01E0432, "STATUS_GRAPHICS_CHAINLINKS_NOT_ENUMERATED", "Graphics chainlinks not enumerated."),
+    (0xC01E0433, "STATUS_GRAPHICS_ADAPTER_CHAIN_NOT_READY", "Graphics adapter chain not ready."),
+    (0xC01E0434, "STATUS_GRAPHICS_CHAINLINKS_NOT_STARTED", "Graphics chainlinks not started."),
+    (0xC01E0435, "STATUS_GRAPHICS_CHAINLINKS_NOT_POWERED_ON", "Graphics chainlinks not powered on."),
+    (0xC01E0436, "STATUS_GRAPHICS_INCONSISTENT_DEVICE_LINK_STATE", "Graphics inconsistent device link state."),
+    (0xC01E0438, "STATUS_GRAPHICS_NOT_POST_DEVICE_DRIVER", "Graphics not post device driver."),
+    (0xC01E043B, "STATUS_GRAPHICS_ADAPTER_ACCESS_NOT_EXCLUDED", "Graphics adapter access not excluded."),
+    (0xC01E0500, "STATUS_GRAPHICS_OPM_NOT_SUPPORTED", "Graphics opm not supported."),
+    (0xC01E0501, "STATUS_GRAPHICS_COPP_NOT_SUPPORTED", "Graphics copp not supported."),
+    (0xC01E0502, "STATUS_GRAPHICS_UAB_NOT_SUPPORTED", "Graphics uab not supported."),
+    (0xC01E0503, "STATUS_GRAPHICS_OPM_INVALID_ENCRYPTED_PARAMETERS", "Graphics opm invalid encrypted parameters."),
+    (0xC01E0505, "STATUS_GRAPHICS_OPM_NO_PROTECTED_OUTPUTS_EXIST", "Graphics opm no protected outputs exist."),
+    (0xC01E050B, "STATUS_GRAPHICS_OPM_INTERNAL_ERROR", "Graphics opm internal error."),
+    (0xC01E050C, "STATUS_GRAPHICS_OPM_INVALID_HANDLE", "Graphics opm invalid handle."),
+    (0xC01E050E, "STATUS_GRAPHICS_PVP_INVALID_CERTIFICATE_LENGTH", "Graphics PVP invalid certificate length."),
+    (0xC01E050F, "STATUS_GRAPHICS_OPM_SPANNING_MODE_ENABLED", "Graphics opm spanning mode enabled."),
+    (0xC01E0510, "STATUS_GRAPHICS_OPM_THEATER_MODE_ENABLED", "Graphics opm theater mode enabled."),
+    (0xC01E0511, "STATUS_GRAPHICS_PVP_HFS_FAILED", "Graphics PVP HFS failed."),
+    (0xC01E0512, "STATUS_GRAPHICS_OPM_INVALID_SRM", "Graphics opm invalid SRM."),
+    (0xC01E0513, "STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_HDCP", "Graphics opm output does not support HDCP."),
+    (0xC01E0514, "STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_ACP", "Graphics opm output does not support acp."),
+    (0xC01E0515, "STATUS_GRAPHICS_OPM_OUTPUT_DOES_NOT_SUPPORT_CGMSA", "Graphics opm output does not support cgmsa."),
+    (0xC01E0516, "STATUS_GRAPHICS_OPM_HDCP_SRM_NEVER_SET", "Graphics opm HDCP SRM never set."),
+    (0xC01E0517, "STATUS_GRAPHICS_OPM_RESOLUTION_TOO_HIGH", "Graphics opm resolution too high."),
+    (0xC01E0518, "STATUS_GRAPHICS_OPM_ALL_HDCP_HARDWARE_ALREADY_IN_USE", "Graphics opm all HDCP hardware already in use."),
+    (0xC01E051A, "STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_NO_LONGER_EXISTS", "Graphics opm protected output no longer exists."),
+    (0xC01E051C, "STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_COPP_SEMANTICS", "Graphics opm protected output does not have copp semantics."),
+    (0xC01E051D, "STATUS_GRAPHICS_OPM_INVALID_INFORMATION_REQUEST", "Graphics opm invalid information request."),
+    (0xC01E051E, "STATUS_GRAPHICS_OPM_DRIVER_INTERNAL_ERROR", "Graphics opm driver internal error."),
+    (0xC01E051F, "STATUS_GRAPHICS_OPM_PROTECTED_OUTPUT_DOES_NOT_HAVE_OPM_SEMANTICS", "Graphics opm protected output does not have opm semantics."),
+    (0xC01E0520, "STATUS_GRAPHICS_OPM_SIGNALING_NOT_SUPPORTED", "Graphics opm signaling not supported."),
+    (0xC01E0521, "STATUS_GRAPHICS_OPM_INVALID_CONFIGURATION_REQUEST", "Graphics opm invalid configuration request."),
+    (0xC01E0580, "STATUS_GRAPHICS_I2C_NOT_SUPPORTED", "Graphics I2C not supported."),
+    (0xC01E0581, "STATUS_GRAPHICS_I2C_DEVICE_DOES_NOT_EXIST", "Graphics I2C device does not exist."),
+    (0xC01E0582, "STATUS_GRAPHICS_I2C_ERROR_TRANSMITTING_DATA", "Graphics I2C error transmitting data."),
+    (0xC01E0583, "STATUS_GRAPHICS_I2C_ERROR_RECEIVING_DATA", "Graphics I2C error receiving data."),
+    (0xC01E0584, "STATUS_GRAPHICS_DDCCI_VCP_NOT_SUPPORTED", "Graphics ddcci VCP not supported."),
+    (0xC01E0585, "STATUS_GRAPHICS_DDCCI_INVALID_DATA", "Graphics ddcci invalid data."),
+    (0xC01E0586, "STATUS_GRAPHICS_DDCCI_MONITOR_RETURNED_INVALID_TIMING_STATUS_BYTE", "Graphics ddcci monitor returned invalid timing status byte."),
+    (0xC01E0587, "STATUS_GRAPHICS_DDCCI_INVALID_CAPABILITIES_STRING", "Graphics ddcci invalid capabilities string."),
+    (0xC01E0588, "STATUS_GRAPHICS_MCA_INTERNAL_ERROR", "Graphics MCA internal error."),
+    (0xC01E0589, "STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_COMMAND", "Graphics ddcci invalid message command."),
+    (0xC01E058A, "STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_LENGTH", "Graphics ddcci invalid message length."),
+    (0xC01E058B, "STATUS_GRAPHICS_DDCCI_INVALID_MESSAGE_CHECKSUM", "Graphics ddcci invalid message checksum."),
+    (0xC01E058C, "STATUS_GRAPHICS_INVALID_PHYSICAL_MONITOR_HANDLE", "Graphics invalid physical monitor handle."),
+    (0xC01E058D, "STATUS_GRAPHICS_MONITOR_NO_LONGER_EXISTS", "Graphics monitor no longer exists."),
+    (0xC01E05E0, "STATUS_GRAPHICS_ONLY_CONSOLE_SESSION_SUPPORTED", "Graphics only console session supported."),
+    (0xC01E05E1, "STATUS_GRAPHICS_NO_DISPLAY_DEVICE_CORRESPONDS_TO_NAME", "Graphics no display device corresponds to name."),
+    (0xC01E05E2, "STATUS_GRAPHICS_DISPLAY_DEVICE_NOT_ATTACHED_TO_DESKTOP", "Graphics display device not attached to desktop."),
+    (0xC01E05E3, "STATUS_GRAPHICS_MIRRORING_DEVICES_NOT_SUPPORTED", "Graphics mirroring devices not supported."),
+    (0xC01E05E4, "STATUS_GRAPHICS_INVALID_POINTER", "Graphics invalid pointer."),
+    (0xC01E05E5, "STATUS_GRAPHICS_NO_MONITORS_CORRESPOND_TO_DISPLAY_DEVICE", "Graphics no monitors correspond to display device."),
+    (0xC01E05E6, "STATUS_GRAPHICS_PARAMETER_ARRAY_TOO_SMALL", "Graphics parameter array too small."),
+    (0xC01E05E7, "STATUS_GRAPHICS_INTERNAL_ERROR", "Graphics internal error."),
+    (0xC01E05E8, "STATUS_GRAPHICS_SESSION_TYPE_CHANGE_IN_PROGRESS", "Graphics session type change in progress."),
+    (0xC0210000, "STATUS_FVE_LOCKED_VOLUME", "FVE locked volume."),
+    (0xC0210001, "STATUS_FVE_NOT_ENCRYPTED", "FVE not encrypted."),
+    (0xC0210002, "STATUS_FVE_BAD_INFORMATION", "FVE bad information."),
+    (0xC0210003, "STATUS_FVE_TOO_SMALL", "FVE too small."),
+    (0xC0210004, "STATUS_FVE_FAILED_WRONG_FS", "FVE failed wrong FS."),
+    (0xC0210005, "STATUS_FVE_BAD_PARTITION_SIZE", "FVE bad partition size."),
+    (0xC0210006, "STATUS_FVE_FS_NOT_EXTENDED", "FVE FS not extended."),
+    (0xC0210007, "STATUS_FVE_FS_MOUNTED", "FVE FS mounted."),
+    (0xC0210008, "STATUS_FVE_NO_LICENSE", "FVE no license."),
+    (0xC0210009, "STATUS_FVE_ACTION_NOT_ALLOWED", "FVE action not allowed."),
+    (0xC021000A, "STATUS_FVE_BAD_DATA", "FVE bad data."),
+    (0xC021000B, "STATUS_FVE_VOLUME_NOT_BOUND", "FVE volume not bound."),
+    (0xC021000C, "STATUS_FVE_NOT_DATA_VOLUME", "FVE not data volume."),
+    (0xC021000D, "STATUS_FVE_CONV_READ_ERROR", "FVE conv read error."),
+    (0xC021000E, "STATUS_FVE_CONV_WRITE_ERROR", "FVE conv write error."),
+    (0xC021000F, "STATUS_FVE_OVERLAPPED_UPDATE", "FVE overlapped update."),
+    (0xC0210010, "STATUS_FVE_FAILED_SECTOR_SIZE", "FVE failed sector size."),
+    (0xC0210011, "STATUS_FVE_FAILED_AUTHENTICATION", "FVE failed authentication."),
+    (0xC0210012, "STATUS_FVE_NOT_OS_VOLUME", "FVE not OS volume."),
+    (0xC0210013, "STATUS_FVE_KEYFILE_NOT_FOUND", "FVE keyfile not found."),
+    (0xC0210014, "STATUS_FVE_KEYFILE_INVALID", "FVE keyfile invalid."),
+    (0xC0210015, "STATUS_FVE_KEYFILE_NO_VMK", "FVE keyfile no VMK."),
+    (0xC0210016, "STATUS_FVE_TPM_DISABLED", "FVE TPM disabled."),
+    (0xC0210017, "STATUS_FVE_TPM_SRK_AUTH_NOT_ZERO", "FVE TPM SRK auth not zero."),
+    (0xC0210018, "STATUS_FVE_TPM_INVALID_PCR", "FVE TPM invalid PCR."),
+    (0xC0210019, "STATUS_FVE_TPM_NO_VMK", "FVE TPM no VMK."),
+    (0xC021001A, "STATUS_FVE_PIN_INVALID", "FVE pin invalid."),
+    (0xC021001B, "STATUS_FVE_AUTH_INVALID_APPLICATION", "FVE auth invalid application."),
+    (0xC021001C, "STATUS_FVE_AUTH_INVALID_CONFIG", "FVE auth invalid config."),
+    (0xC021001D, "STATUS_FVE_DEBUGGER_ENABLED", "FVE debugger enabled."),
+    (0xC021001E, "STATUS_FVE_DRY_RUN_FAILED", "FVE dry run failed."),
+    (0xC021001F, "STATUS_FVE_BAD_METADATA_POINTER", "FVE bad metadata pointer."),
+    (0xC0210020, "STATUS_FVE_OLD_METADATA_COPY", "FVE old metadata copy."),
+    (0xC0210021, "STATUS_FVE_REBOOT_REQUIRED", "FVE reboot required."),
+    (0xC0210022, "STATUS_FVE_RAW_ACCESS", "FVE raw access."),
+    (0xC0210023, "STATUS_FVE_RAW_BLOCKED", "FVE raw blocked."),
+    (0xC0210024, "STATUS_FVE_NO_AUTOUNLOCK_MASTER_KEY", "FVE no autounlock master key."),
+    (0xC0210025, "STATUS_FVE_MOR_FAILED", "FVE mor failed."),
+    (0xC0210026, "STATUS_FVE_NO_FEATURE_LICENSE", "FVE no feature license."),
+    (0xC0210027, "STATUS_FVE_POLICY_USER_DISABLE_RDV_NOT_ALLOWED", "FVE policy user disable RDV not allowed."),
+    (0xC0210028, "STATUS_FVE_CONV_RECOVERY_FAILED", "FVE conv recovery failed."),
+    (0xC0210029, "STATUS_FVE_VIRTUALIZED_SPACE_TOO_BIG", "FVE virtualized space too big."),
+    (0xC021002A, "STATUS_FVE_INVALID_DATUM_TYPE", "FVE invalid datum type."),
+    (0xC0210030, "STATUS_FVE_VOLUME_TOO_SMALL", "FVE volume too small."),
+    (0xC0210031, "STATUS_FVE_ENH_PIN_INVALID", "FVE enh pin invalid."),
+    (0xC0210032, "STATUS_FVE_FULL_ENCRYPTION_NOT_ALLOWED_ON_TP_STORAGE", "FVE full encryption not allowed on TP storage."),
+    (0xC0210033, "STATUS_FVE_WIPE_NOT_ALLOWED_ON_TP_STORAGE", "FVE wipe not allowed on TP storage."),
+    (0xC0210034, "STATUS_FVE_NOT_ALLOWED_ON_CSV_STACK", "FVE not allowed on CSV stack."),
+    (0xC0210035, "STATUS_FVE_NOT_ALLOWED_ON_CLUSTER", "FVE not allowed on cluster."),
+    (0xC0210036, "STATUS_FVE_NOT_ALLOWED_TO_UPGRADE_WHILE_CONVERTING", "FVE not allowed to upgrade while converting."),
+    (0xC0210037, "STATUS_FVE_WIPE_CANCEL_NOT_APPLICABLE", "FVE wipe cancel not applicable."),
+    (0xC0210038, "STATUS_FVE_EDRIVE_DRY_RUN_FAILED", "FVE edrive dry run failed."),
+    (0xC0210039, "STATUS_FVE_SECUREBOOT_DISABLED", "FVE secureboot disabled."),
+    (0xC021003A, "STATUS_FVE_SECUREBOOT_CONFIG_CHANGE", "FVE secureboot config change."),
+    (0xC021003B, "STATUS_FVE_DEVICE_LOCKEDOUT", "FVE device lockedout."),
+    (0xC021003C, "STATUS_FVE_VOLUME_EXTEND_PREVENTS_EOW_DECRYPT", "FVE volume extend prevents eow decrypt."),
+    (0xC021003D, "STATUS_FVE_NOT_DE_VOLUME", "FVE not de volume."),
+    (0xC021003E, "STATUS_FVE_PROTECTION_DISABLED", "FVE protection disabled."),
+    (0xC021003F, "STATUS_FVE_PROTECTION_CANNOT_BE_DISABLED", "FVE protection cannot be disabled."),
+    (0xC0210040, "STATUS_FVE_OSV_KSR_NOT_ALLOWED", "FVE osv KSR not allowed."),
+    (0xC0210041, "STATUS_FVE_EDRIVE_BAND_ENUMERATION_FAILED", "FVE edrive band enumeration failed."),
+    (0xC0210042, "STATUS_FVE_POLICY_ON_RDV_EXCLUSION_LIST", "FVE policy on RDV exclusion list."),
+    (0xC0210043, "STATUS_FVE_DATASET_FULL", "FVE dataset full."),
+    (0xC0210044, "STATUS_FVE_METADATA_FULL", "FVE metadata full."),
+    (0xC0220001, "STATUS_FWP_CALLOUT_NOT_FOUND", "FWP callout not found."),
+    (0xC0220002, "STATUS_FWP_CONDITION_NOT_FOUND", "FWP condition not found."),
+    (0xC0220003, "STATUS_FWP_FILTER_NOT_FOUND", "FWP filter not found."),
+    (0xC0220004, "STATUS_FWP_LAYER_NOT_FOUND", "FWP layer not found."),
+    (0xC0220005, "STATUS_FWP_PROVIDER_NOT_FOUND", "FWP provider not found."),
+    (0xC0220006, "STATUS_FWP_PROVIDER_CONTEXT_NOT_FOUND", "FWP provider context not found."),
+    (0xC0220007, "STATUS_FWP_SUBLAYER_NOT_FOUND", "FWP sublayer not found."),
+    (0xC0220008, "STATUS_FWP_NOT_FOUND", "FWP not found."),
+    (0xC0220009, "STATUS_FWP_ALREADY_EXISTS", "FWP already exists."),
+    (0xC022000A, "STATUS_FWP_IN_USE", "FWP in use."),
+    (0xC022000B, "STATUS_FWP_DYNAMIC_SESSION_IN_PROGRESS", "FWP dynamic session in progress."),
+    (0xC022000C, "STATUS_FWP_WRONG_SESSION", "FWP wrong session."),
+    (0xC022000D, "STATUS_FWP_NO_TXN_IN_PROGRESS", "FWP no TXN in progress."),
+    (0xC022000E, "STATUS_FWP_TXN_IN_PROGRESS", "FWP TXN in progress."),
+    (0xC022000F, "STATUS_FWP_TXN_ABORTED", "FWP TXN aborted."),
+    (0xC0220010, "STATUS_FWP_SESSION_ABORTED", "FWP session aborted."),
+    (0xC0220011, "STATUS_FWP_INCOMPATIBLE_TXN", "FWP incompatible TXN."),
+    (0xC0220012, "STATUS_FWP_TIMEOUT", "FWP timeout."),
+    (0xC0220013, "STATUS_FWP_NET_EVENTS_DISABLED", "FWP net events disabled."),
+    (0xC0220014, "STATUS_FWP_INCOMPATIBLE_LAYER", "FWP incompatible layer."),
+    (0xC0220015, "STATUS_FWP_KM_CLIENTS_ONLY", "FWP KM clients only."),
+    (0xC0220016, "STATUS_FWP_LIFETIME_MISMATCH", "FWP lifetime mismatch."),
+    (0xC0220017, "STATUS_FWP_BUILTIN_OBJECT", "FWP builtin object."),
+    (0xC0220018, "STATUS_FWP_TOO_MANY_CALLOUTS", "FWP too many callouts."),
+    (0xC0220019, "STATUS_FWP_NOTIFICATION_DROPPED", "FWP notification dropped."),
+    (0xC022001A, "STATUS_FWP_TRAFFIC_MISMATCH", "FWP traffic mismatch."),
+    (0xC022001B, "STATUS_FWP_INCOMPATIBLE_SA_STATE", "FWP incompatible sa state."),
+    (0xC022001C, "STATUS_FWP_NULL_POINTER", "FWP null pointer."),
+    (0xC022001D, "STATUS_FWP_INVALID_ENUMERATOR", "FWP invalid enumerator."),
+    (0xC022001E, "STATUS_FWP_INVALID_FLAGS", "FWP invalid flags."),
+    (0xC022001F, "STATUS_FWP_INVALID_NET_MASK", "FWP invalid net mask."),
+    (0xC0220020, "STATUS_FWP_INVALID_RANGE", "FWP invalid range."),
+    (0xC0220021, "STATUS_FWP_INVALID_INTERVAL", "FWP invalid interval."),
+    (0xC0220022, "STATUS_FWP_ZERO_LENGTH_ARRAY", "FWP zero length array."),
+    (0xC0220023, "STATUS_FWP_NULL_DISPLAY_NAME", "FWP null display name."),
+    (0xC0220024, "STATUS_FWP_INVALID_ACTION_TYPE", "FWP invalid action type."),
+    (0xC0220025, "STATUS_FWP_INVALID_WEIGHT", "FWP invalid weight."),
+    (0xC0220026, "STATUS_FWP_MATCH_TYPE_MISMATCH", "FWP match type mismatch."),
+    (0xC0220027, "STATUS_FWP_TYPE_MISMATCH", "FWP type mismatch."),
+    (0xC0220028, "STATUS_FWP_OUT_OF_BOUNDS", "FWP out of bounds."),
+    (0xC0220029, "STATUS_FWP_RESERVED", "FWP reserved."),
+    (0xC022002A, "STATUS_FWP_DUPLICATE_CONDITION", "FWP duplicate condition."),
+    (0xC022002B, "STATUS_FWP_DUPLICATE_KEYMOD", "FWP duplicate keymod."),
+    (0xC022002C, "STATUS_FWP_ACTION_INCOMPATIBLE_WITH_LAYER", "FWP action incompatible with layer."),
+    (0xC022002D, "STATUS_FWP_ACTION_INCOMPATIBLE_WITH_SUBLAYER", "FWP action incompatible with sublayer."),
+    (0xC022002E, "STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_LAYER", "FWP context incompatible with layer."),
+    (0xC022002F, "STATUS_FWP_CONTEXT_INCOMPATIBLE_WITH_CALLOUT", "FWP context incompatible with callout."),
+    (0xC0220030, "STATUS_FWP_INCOMPATIBLE_AUTH_METHOD", "FWP incompatible auth method."),
+    (0xC0220031, "STATUS_FWP_INCOMPATIBLE_DH_GROUP", "FWP incompatible DH group."),
+    (0xC0220032, "STATUS_FWP_EM_NOT_SUPPORTED", "FWP em not supported."),
+    (0xC0220033, "STATUS_FWP_NEVER_MATCH", "FWP never match."),
+    (0xC0220034, "STATUS_FWP_PROVIDER_CONTEXT_MISMATCH", "FWP provider context mismatch."),
+    (0xC0220035, "STATUS_FWP_INVALID_PARAMETER", "FWP invalid parameter."),
+    (0xC0220036, "STATUS_FWP_TOO_MANY_SUBLAYERS", "FWP too many sublayers."),
+    (0xC0220037, "STATUS_FWP_CALLOUT_NOTIFICATION_FAILED", "FWP callout notification failed."),
+    (0xC0220038, "STATUS_FWP_INVALID_AUTH_TRANSFORM", "FWP invalid auth transform."),
+    (0xC0220039, "STATUS_FWP_INVALID_CIPHER_TRANSFORM", "FWP invalid cipher transform."),
+    (0xC022003A, "STATUS_FWP_INCOMPATIBLE_CIPHER_TRANSFORM", "FWP incompatible cipher transform."),
+    (0xC022003B, "STATUS_FWP_INVALID_TRANSFORM_COMBINATION", "FWP invalid transform combination."),
+    (0xC022003C, "STATUS_FWP_DUPLICATE_AUTH_METHOD", "FWP duplicate auth method."),
+    (0xC022003D, "STATUS_FWP_INVALID_TUNNEL_ENDPOINT", "FWP invalid tunnel endpoint."),
+    (0xC022003E, "STATUS_FWP_L2_DRIVER_NOT_READY", "FWP L2 driver not ready."),
+    (0xC022003F, "STATUS_FWP_KEY_DICTATOR_ALREADY_REGISTERED", "FWP key dictator already registered."),
+    (0xC0220040, "STATUS_FWP_KEY_DICTATION_INVALID_KEYING_MATERIAL", "FWP key dictation invalid keying material."),
+    (0xC0220041, "STATUS_FWP_CONNECTIONS_DISABLED", "FWP connections disabled."),
+    (0xC0220042, "STATUS_FWP_INVALID_DNS_NAME", "FWP invalid DNS name."),
+    (0xC0220043, "STATUS_FWP_STILL_ON", "FWP still on."),
+    (0xC0220044, "STATUS_FWP_IKEEXT_NOT_RUNNING", "FWP ikeext not running."),
+    (0xC0220100, "STATUS_FWP_TCPIP_NOT_READY", "FWP tcpip not ready."),
+    (0xC0220101, "STATUS_FWP_INJECT_HANDLE_CLOSING", "FWP inject handle closing."),
+    (0xC0220102, "STATUS_FWP_INJECT_HANDLE_STALE", "FWP inject handle stale."),
+    (0xC0220103, "STATUS_FWP_CANNOT_PEND", "FWP cannot pend."),
+    (0xC0220104, "STATUS_FWP_DROP_NOICMP", "FWP drop noicmp."),
+    (0xC0230002, "STATUS_NDIS_CLOSING", "NDIS closing."),
+    (0xC0230004, "STATUS_NDIS_BAD_VERSION", "NDIS bad version."),
+    (0xC0230005, "STATUS_NDIS_BAD_CHARACTERISTICS", "NDIS bad characteristics."),
+    (0xC0230006, "STATUS_NDIS_ADAPTER_NOT_FOUND", "NDIS adapter not found."),
+    (0xC0230007, "STATUS_NDIS_OPEN_FAILED", "NDIS open failed."),
+    (0xC0230008, "STATUS_NDIS_DEVICE_FAILED", "NDIS device failed."),
+    (0xC0230009, "STATUS_NDIS_MULTICAST_FULL", "NDIS multicast full."),
+    (0xC023000A, "STATUS_NDIS_MULTICAST_EXISTS", "NDIS multicast exists."),
+    (0xC023000B, "STATUS_NDIS_MULTICAST_NOT_FOUND", "NDIS multicast not found."),
+    (0xC023000C, "STATUS_NDIS_REQUEST_ABORTED", "NDIS request aborted."),
+    (0xC023000D, "STATUS_NDIS_RESET_IN_PROGRESS", "NDIS reset in progress."),
+    (0xC023000F, "STATUS_NDIS_INVALID_PACKET", "NDIS invalid packet."),
+    (0xC0230010, "STATUS_NDIS_INVALID_DEVICE_REQUEST", "NDIS invalid device request."),
+    (0xC0230011, "STATUS_NDIS_ADAPTER_NOT_READY", "NDIS adapter not ready."),
+    (0xC0230014, "STATUS_NDIS_INVALID_LENGTH", "NDIS invalid length."),
+    (0xC0230015, "STATUS_NDIS_INVALID_DATA", "NDIS invalid data."),
+    (0xC0230016, "STATUS_NDIS_BUFFER_TOO_SHORT", "NDIS buffer too short."),
+    (0xC0230017, "STATUS_NDIS_INVALID_OID", "NDIS invalid oid."),
+    (0xC0230018, "STATUS_NDIS_ADAPTER_REMOVED", "NDIS adapter removed."),
+    (0xC0230019, "STATUS_NDIS_UNSUPPORTED_MEDIA", "NDIS unsupported media."),
+    (0xC023001A, "STATUS_NDIS_GROUP_ADDRESS_IN_USE", "NDIS group address in use."),
+    (0xC023001B, "STATUS_NDIS_FILE_NOT_FOUND", "NDIS file not found."),
+    (0xC023001C, "STATUS_NDIS_ERROR_READING_FILE", "NDIS error reading file."),
+    (0xC023001D, "STATUS_NDIS_ALREADY_MAPPED", "NDIS already mapped."),
+    (0xC023001E, "STATUS_NDIS_RESOURCE_CONFLICT", "NDIS resource conflict."),
+    (0xC023001F, "STATUS_NDIS_MEDIA_DISCONNECTED", "NDIS media disconnected."),
+    (0xC0230022, "STATUS_NDIS_INVALID_ADDRESS", "NDIS invalid address."),
+    (0xC023002A, "STATUS_NDIS_PAUSED", "NDIS paused."),
+    (0xC023002B, "STATUS_NDIS_INTERFACE_NOT_FOUND", "NDIS interface not found."),
+    (0xC023002C, "STATUS_NDIS_UNSUPPORTED_REVISION", "NDIS unsupported revision."),
+    (0xC023002D, "STATUS_NDIS_INVALID_PORT", "NDIS invalid port."),
+    (0xC023002E, "STATUS_NDIS_INVALID_PORT_STATE", "NDIS invalid port state."),
+    (0xC023002F, "STATUS_NDIS_LOW_POWER_STATE", "NDIS low power state."),
+    (0xC0230030, "STATUS_NDIS_REINIT_REQUIRED", "NDIS reinit required."),
+    (0xC0230031, "STATUS_NDIS_NO_QUEUES", "NDIS no queues."),
+    (0xC02300BB, "STATUS_NDIS_NOT_SUPPORTED", "NDIS not supported."),
+    (0xC023100F, "STATUS_NDIS_OFFLOAD_POLICY", "NDIS offload policy."),
+    (0xC0231012, "STATUS_NDIS_OFFLOAD_CONNECTION_REJECTED", "NDIS offload connection rejected."),
+    (0xC0231013, "STATUS_NDIS_OFFLOAD_PATH_REJECTED", "NDIS offload path rejected."),
+    (0xC0232000, "STATUS_NDIS_DOT11_AUTO_CONFIG_ENABLED", "NDIS DOT11 auto config enabled."),
+    (0xC0232001, "STATUS_NDIS_DOT11_MEDIA_IN_USE", "NDIS DOT11 media in use."),
+    (0xC0232002, "STATUS_NDIS_DOT11_POWER_STATE_INVALID", "NDIS DOT11 power state invalid."),
+    (0xC0232003, "STATUS_NDIS_PM_WOL_PATTERN_LIST_FULL", "NDIS PM wol pattern list full."),
+    (0xC0232004, "STATUS_NDIS_PM_PROTOCOL_OFFLOAD_LIST_FULL", "NDIS PM protocol offload list full."),
+    (0xC0232005, "STATUS_NDIS_DOT11_AP_CHANNEL_CURRENTLY_NOT_AVAILABLE", "NDIS DOT11 ap channel currently not available."),
+    (0xC0232006, "STATUS_NDIS_DOT11_AP_BAND_CURRENTLY_NOT_AVAILABLE", "NDIS DOT11 ap band currently not available."),
+    (0xC0232007, "STATUS_NDIS_DOT11_AP_CHANNEL_NOT_ALLOWED", "NDIS DOT11 ap channel not allowed."),
+    (0xC0232008, "STATUS_NDIS_DOT11_AP_BAND_NOT_ALLOWED", "NDIS DOT11 ap band not allowed."),
+    (0xC0240000, "STATUS_QUIC_HANDSHAKE_FAILURE", "QUIC handshake failure."),
+    (0xC0240001, "STATUS_QUIC_VER_NEG_FAILURE", "QUIC ver neg failure."),
+    (0xC0240002, "STATUS_QUIC_USER_CANCELED", "QUIC user canceled."),
+    (0xC0240003, "STATUS_QUIC_INTERNAL_ERROR", "QUIC internal error."),
+    (0xC0240004, "STATUS_QUIC_PROTOCOL_VIOLATION", "QUIC protocol violation."),
+    (0xC0240005, "STATUS_QUIC_CONNECTION_IDLE", "QUIC connection idle."),
+    (0xC0240006, "STATUS_QUIC_CONNECTION_TIMEOUT", "QUIC connection timeout."),
+    (0xC0240007, "STATUS_QUIC_ALPN_NEG_FAILURE", "QUIC alpn neg failure."),
+    (0xC0290000, "STATUS_TPM_ERROR_MASK", "TPM error mask."),
+    (0xC0290001, "STATUS_TPM_AUTHFAIL", "TPM authfail."),
+    (0xC0290002, "STATUS_TPM_BADINDEX", "TPM badindex."),
+    (0xC0290003, "STATUS_TPM_BAD_PARAMETER", "TPM bad parameter."),
+    (0xC0290004, "STATUS_TPM_AUDITFAILURE", "TPM auditfailure."),
+    (0xC0290005, "STATUS_TPM_CLEAR_DISABLED", "TPM clear disabled."),
+    (0xC0290006, "STATUS_TPM_DEACTIVATED", "TPM deactivated."),
+    (0xC0290007, "STATUS_TPM_DISABLED", "TPM disabled."),
+    (0xC0290008, "STATUS_TPM_DISABLED_CMD", "TPM disabled CMD."),
+    (0xC0290009, "STATUS_TPM_FAIL", "TPM fail."),
+    (0xC029000A, "STATUS_TPM_BAD_ORDINAL", "TPM bad ordinal."),
+    (0xC029000B, "STATUS_TPM_INSTALL_DISABLED", "TPM install disabled."),
+    (0xC029000C, "STATUS_TPM_INVALID_KEYHANDLE", "TPM invalid keyhandle."),
+    (0xC029000D, "STATUS_TPM_KEYNOTFOUND", "TPM keynotfound."),
+    (0xC029000E, "STATUS_TPM_INAPPROPRIATE_ENC", "TPM inappropriate enc."),
+    (0xC029000F, "STATUS_TPM_MIGRATEFAIL", "TPM migratefail."),
+    (0xC0290010, "STATUS_TPM_INVALID_PCR_INFO", "TPM invalid PCR info."),
+    (0xC0290011, "STATUS_TPM_NOSPACE", "TPM nospace."),
+    (0xC0290012, "STATUS_TPM_NOSRK", "TPM nosrk."),
+    (0xC0290013, "STATUS_TPM_NOTSEALED_BLOB", "TPM notsealed blob."),
+    (0xC0290014, "STATUS_TPM_OWNER_SET", "TPM owner set."),
+    (0xC0290015, "STATUS_TPM_RESOURCES", "TPM resources."),
+    (0xC0290016, "STATUS_TPM_SHORTRANDOM", "TPM shortrandom."),
+    (0xC0290017, "STATUS_TPM_SIZE", "TPM size."),
+    (0xC0290018, "STATUS_TPM_WRONGPCRVAL", "TPM wrongpcrval."),
+    (0xC0290019, "STATUS_TPM_BAD_PARAM_SIZE", "TPM bad param size."),
+    (0xC029001A, "STATUS_TPM_SHA_THREAD", "TPM sha thread."),
+    (0xC029001B, "STATUS_TPM_SHA_ERROR", "TPM sha error."),
+    (0xC029001C, "STATUS_TPM_FAILEDSELFTEST", "TPM failedselftest."),
+    (0xC029001D, "STATUS_TPM_AUTH2FAIL", "TPM AUTH2FAIL."),
+    (0xC029001E, "STATUS_TPM_BADTAG", "TPM badtag."),
+    (0xC029001F, "STATUS_TPM_IOERROR", "TPM ioerror."),
+    (0xC0290020, "STATUS_TPM_ENCRYPT_ERROR", "TPM encrypt error."),
+    (0xC0290021, "STATUS_TPM_DECRYPT_ERROR", "TPM decrypt error."),
+    (0xC0290022, "STATUS_TPM_INVALID_AUTHHANDLE", "TPM invalid authhandle."),
+    (0xC0290023, "STATUS_TPM_NO_ENDORSEMENT", "TPM no endorsement."),
+    (0xC0290024, "STATUS_TPM_INVALID_KEYUSAGE", "TPM invalid keyusage."),
+    (0xC0290025, "STATUS_TPM_WRONG_ENTITYTYPE", "TPM wrong entitytype."),
+    (0xC0290026, "STATUS_TPM_INVALID_POSTINIT", "TPM invalid postinit."),
+    (0xC0290027, "STATUS_TPM_INAPPROPRIATE_SIG", "TPM inappropriate sig."),
+    (0xC0290028, "STATUS_TPM_BAD_KEY_PROPERTY", "TPM bad key property."),
+    (0xC0290029, "STATUS_TPM_BAD_MIGRATION", "TPM bad migration."),
+    (0xC029002A, "STATUS_TPM_BAD_SCHEME", "TPM bad scheme."),
+    (0xC029002B, "STATUS_TPM_BAD_DATASIZE", "TPM bad datasize."),
+    (0xC029002C, "STATUS_TPM_BAD_MODE", "TPM bad mode."),
+    (0xC029002D, "STATUS_TPM_BAD_PRESENCE", "TPM bad presence."),
+    (0xC029002E, "STATUS_TPM_BAD_VERSION", "TPM bad version."),
+    (0xC029002F, "STATUS_TPM_NO_WRAP_TRANSPORT", "TPM no wrap transport."),
+    (0xC0290030, "STATUS_TPM_AUDITFAIL_UNSUCCESSFUL", "TPM auditfail unsuccessful."),
+    (0xC0290031, "STATUS_TPM_AUDITFAIL_SUCCESSFUL", "TPM auditfail successful."),
+    (0xC0290032, "STATUS_TPM_NOTRESETABLE", "TPM notresetable."),
+    (0xC0290033, "STATUS_TPM_NOTLOCAL", "TPM notlocal."),
+    (0xC0290034, "STATUS_TPM_BAD_TYPE", "TPM bad type."),
+    (0xC0290035, "STATUS_TPM_INVALID_RESOURCE", "TPM invalid resource."),
+    (0xC0290036, "STATUS_TPM_NOTFIPS", "TPM notfips."),
+    (0xC0290037, "STATUS_TPM_INVALID_FAMILY", "TPM invalid family."),
+    (0xC0290038, "STATUS_TPM_NO_NV_PERMISSION", "TPM no NV permission."),
+    (0xC0290039, "STATUS_TPM_REQUIRES_SIGN", "TPM requires sign."),
+    (0xC029003A, "STATUS_TPM_KEY_NOTSUPPORTED", "TPM key notsupported."),
+    (0xC029003B, "STATUS_TPM_AUTH_CONFLICT", "TPM auth conflict."),
+    (0xC029003C, "STATUS_TPM_AREA_LOCKED", "TPM area locked."),
+    (0xC029003D, "STATUS_TPM_BAD_LOCALITY", "TPM bad locality."),
+    (0xC029003E, "STATUS_TPM_READ_ONLY", "TPM read only."),
+    (0xC029003F, "STATUS_TPM_PER_NOWRITE", "TPM per nowrite."),
+    (0xC0290040, "STATUS_TPM_FAMILYCOUNT", "TPM familycount."),
+    (0xC0290041, "STATUS_TPM_WRITE_LOCKED", "TPM write locked."),
+    (0xC0290042, "STATUS_TPM_BAD_ATTRIBUTES", "TPM bad attributes."),
+    (0xC0290043, "STATUS_TPM_INVALID_STRUCTURE", "TPM invalid structure."),
+    (0xC0290044, "STATUS_TPM_KEY_OWNER_CONTROL", "TPM key owner control."),
+    (0xC0290045, "STATUS_TPM_BAD_COUNTER", "TPM bad counter."),
+    (0xC0290046, "STATUS_TPM_NOT_FULLWRITE", "TPM not fullwrite."),
+    (0xC0290047, "STATUS_TPM_CONTEXT_GAP", "TPM context gap."),
+    (0xC0290048, "STATUS_TPM_MAXNVWRITES", "TPM maxnvwrites."),
+    (0xC0290049, "STATUS_TPM_NOOPERATOR", "TPM nooperator."),
+    (0xC029004A, "STATUS_TPM_RESOURCEMISSING", "TPM resourcemissing."),
+    (0xC029004B, "STATUS_TPM_DELEGATE_LOCK", "TPM delegate lock."),
+    (0xC029004C, "STATUS_TPM_DELEGATE_FAMILY", "TPM delegate family."),
+    (0xC029004D, "STATUS_TPM_DELEGATE_ADMIN", "TPM delegate admin."),
+    (0xC029004E, "STATUS_TPM_TRANSPORT_NOTEXCLUSIVE", "TPM transport notexclusive."),
+    (0xC029004F, "STATUS_TPM_OWNER_CONTROL", "TPM owner control."),
+    (0xC0290050, "STATUS_TPM_DAA_RESOURCES", "TPM daa resources."),
+    (0xC0290051, "STATUS_TPM_DAA_INPUT_DATA0", "TPM daa input DATA0."),
+    (0xC0290052, "STATUS_TPM_DAA_INPUT_DATA1", "TPM daa input DATA1."),
+    (0xC0290053, "STATUS_TPM_DAA_ISSUER_SETTINGS", "TPM daa issuer settings."),
+    (0xC0290054, "STATUS_TPM_DAA_TPM_SETTINGS", "TPM daa TPM settings."),
+    (0xC0290055, "STATUS_TPM_DAA_STAGE", "TPM daa stage."),
+    (0xC0290056, "STATUS_TPM_DAA_ISSUER_VALIDITY", "TPM daa issuer validity."),
+    (0xC0290057, "STATUS_TPM_DAA_WRONG_W", "TPM daa wrong W."),
+    (0xC0290058, "STATUS_TPM_BAD_HANDLE", "TPM bad handle."),
+    (0xC0290059, "STATUS_TPM_BAD_DELEGATE", "TPM bad delegate."),
+    (0xC029005A, "STATUS_TPM_BADCONTEXT", "TPM badcontext."),
+    (0xC029005B, "STATUS_TPM_TOOMANYCONTEXTS", "TPM toomanycontexts."),
+    (0xC029005C, "STATUS_TPM_MA_TICKET_SIGNATURE", "TPM ma ticket signature."),
+    (0xC029005D, "STATUS_TPM_MA_DESTINATION", "TPM ma destination."),
+    (0xC029005E, "STATUS_TPM_MA_SOURCE", "TPM ma source."),
+    (0xC029005F, "STATUS_TPM_MA_AUTHORITY", "TPM ma authority."),
+    (0xC0290061, "STATUS_TPM_PERMANENTEK", "TPM permanentek."),
+    (0xC0290062, "STATUS_TPM_BAD_SIGNATURE", "TPM bad signature."),
+    (0xC0290063, "STATUS_TPM_NOCONTEXTSPACE", "TPM nocontextspace."),
+    (0xC0290081, "STATUS_TPM_20_E_ASYMMETRIC", "TPM 20 e asymmetric."),
+    (0xC0290082, "STATUS_TPM_20_E_ATTRIBUTES", "TPM 20 e attributes."),
+    (0xC0290083, "STATUS_TPM_20_E_HASH", "TPM 20 e hash."),
+    (0xC0290084, "STATUS_TPM_20_E_VALUE", "TPM 20 e value."),
+    (0xC0290085, "STATUS_TPM_20_E_HIERARCHY", "TPM 20 e hierarchy."),
+    (0xC0290087, "STATUS_TPM_20_E_KEY_SIZE", "TPM 20 e key size."),
+    (0xC0290088, "STATUS_TPM_20_E_MGF", "TPM 20 e MGF."),
+    (0xC0290089, "STATUS_TPM_20_E_MODE", "TPM 20 e mode."),
+    (0xC029008A, "STATUS_TPM_20_E_TYPE", "TPM 20 e type."),
+    (0xC029008B, "STATUS_TPM_20_E_HANDLE", "TPM 20 e handle."),
+    (0xC029008C, "STATUS_TPM_20_E_KDF", "TPM 20 e KDF."),
+    (0xC029008D, "STATUS_TPM_20_E_RANGE", "TPM 20 e range."),
+    (0xC029008E, "STATUS_TPM_20_E_AUTH_FAIL", "TPM 20 e auth fail."),
+    (0xC029008F, "STATUS_TPM_20_E_NONCE", "TPM 20 e nonce."),
+    (0xC0290090, "STATUS_TPM_20_E_PP", "TPM 20 e PP."),
+    (0xC0290092, "STATUS_TPM_20_E_SCHEME", "TPM 20 e scheme."),
+    (0xC0290095, "STATUS_TPM_20_E_SIZE", "TPM 20 e size."),
+    (0xC0290096, "STATUS_TPM_20_E_SYMMETRIC", "TPM 20 e symmetric."),
+    (0xC0290097, "STATUS_TPM_20_E_TAG", "TPM 20 e tag."),
+    (0xC0290098, "STATUS_TPM_20_E_SELECTOR", "TPM 20 e selector."),
+    (0xC029009A, "STATUS_TPM_20_E_INSUFFICIENT", "TPM 20 e insufficient."),
+    (0xC029009B, "STATUS_TPM_20_E_SIGNATURE", "TPM 20 e signature."),
+    (0xC029009C, "STATUS_TPM_20_E_KEY", "TPM 20 e key."),
+    (0xC029009D, "STATUS_TPM_20_E_POLICY_FAIL", "TPM 20 e policy fail."),
+    (0xC029009F, "STATUS_TPM_20_E_INTEGRITY", "TPM 20 e integrity."),
+    (0xC02900A0, "STATUS_TPM_20_E_TICKET", "TPM 20 e ticket."),
+    (0xC02900A1, "STATUS_TPM_20_E_RESERVED_BITS", "TPM 20 e reserved bits."),
+    (0xC02900A2, "STATUS_TPM_20_E_BAD_AUTH", "TPM 20 e bad auth."),
+    (0xC02900A3, "STATUS_TPM_20_E_EXPIRED", "TPM 20 e expired."),
+    (0xC02900A4, "STATUS_TPM_20_E_POLICY_CC", "TPM 20 e policy CC."),
+    (0xC02900A5, "STATUS_TPM_20_E_BINDING", "TPM 20 e binding."),
+    (0xC02900A6, "STATUS_TPM_20_E_CURVE", "TPM 20 e curve."),
+    (0xC02900A7, "STATUS_TPM_20_E_ECC_POINT", "TPM 20 e ecc point."),
+    (0xC0290100, "STATUS_TPM_20_E_INITIALIZE", "TPM 20 e initialize."),
+    (0xC0290101, "STATUS_TPM_20_E_FAILURE", "TPM 20 e failure."),
+    (0xC0290103, "STATUS_TPM_20_E_SEQUENCE", "TPM 20 e sequence."),
+    (0xC029010B, "STATUS_TPM_20_E_PRIVATE", "TPM 20 e private."),
+    (0xC0290119, "STATUS_TPM_20_E_HMAC", "TPM 20 e hmac."),
+    (0xC0290120, "STATUS_TPM_20_E_DISABLED", "TPM 20 e disabled."),
+    (0xC0290121, "STATUS_TPM_20_E_EXCLUSIVE", "TPM 20 e exclusive."),
+    (0xC0290123, "STATUS_TPM_20_E_ECC_CURVE", "TPM 20 e ecc curve."),
+    (0xC0290124, "STATUS_TPM_20_E_AUTH_TYPE", "TPM 20 e auth type."),
+    (0xC0290125, "STATUS_TPM_20_E_AUTH_MISSING", "TPM 20 e auth missing."),
+    (0xC0290126, "STATUS_TPM_20_E_POLICY", "TPM 20 e policy."),
+    (0xC0290127, "STATUS_TPM_20_E_PCR", "TPM 20 e PCR."),
+    (0xC0290128, "STATUS_TPM_20_E_PCR_CHANGED", "TPM 20 e PCR changed."),
+    (0xC029012D, "STATUS_TPM_20_E_UPGRADE", "TPM 20 e upgrade."),
+    (0xC029012E, "STATUS_TPM_20_E_TOO_MANY_CONTEXTS", "TPM 20 e too many contexts."),
+    (0xC029012F, "STATUS_TPM_20_E_AUTH_UNAVAILABLE", "TPM 20 e auth unavailable."),
+    (0xC0290130, "STATUS_TPM_20_E_REBOOT", "TPM 20 e reboot."),
+    (0xC0290131, "STATUS_TPM_20_E_UNBALANCED", "TPM 20 e unbalanced."),
+    (0xC0290142, "STATUS_TPM_20_E_COMMAND_SIZE", "TPM 20 e command size."),
+    (0xC0290143, "STATUS_TPM_20_E_COMMAND_CODE", "TPM 20 e command code."),
+    (0xC0290144, "STATUS_TPM_20_E_AUTHSIZE", "TPM 20 e authsize."),
+    (0xC0290145, "STATUS_TPM_20_E_AUTH_CONTEXT", "TPM 20 e auth context."),
+    (0xC0290146, "STATUS_TPM_20_E_NV_RANGE", "TPM 20 e NV range."),
+    (0xC0290147, "STATUS_TPM_20_E_NV_SIZE", "TPM 20 e NV size."),
+    (0xC0290148, "STATUS_TPM_20_E_NV_LOCKED", "TPM 20 e NV locked."),
+    (0xC0290149, "STATUS_TPM_20_E_NV_AUTHORIZATION", "TPM 20 e NV authorization."),
+    (0xC029014A, "STATUS_TPM_20_E_NV_UNINITIALIZED", "TPM 20 e NV uninitialized."),
+    (0xC029014B, "STATUS_TPM_20_E_NV_SPACE", "TPM 20 e NV space."),
+    (0xC029014C, "STATUS_TPM_20_E_NV_DEFINED", "TPM 20 e NV defined."),
+    (0xC0290150, "STATUS_TPM_20_E_BAD_CONTEXT", "TPM 20 e bad context."),
+    (0xC0290151, "STATUS_TPM_20_E_CPHASH", "TPM 20 e cphash."),
+    (0xC0290152, "STATUS_TPM_20_E_PARENT", "TPM 20 e parent."),
+    (0xC0290153, "STATUS_TPM_20_E_NEEDS_TEST", "TPM 20 e needs test."),
+    (0xC0290154, "STATUS_TPM_20_E_NO_RESULT", "TPM 20 e no result."),
+    (0xC0290155, "STATUS_TPM_20_E_SENSITIVE", "TPM 20 e sensitive."),
+    (0xC0290400, "STATUS_TPM_COMMAND_BLOCKED", "TPM command blocked."),
+    (0xC0290401, "STATUS_TPM_INVALID_HANDLE", "TPM invalid handle."),
+    (0xC0290402, "STATUS_TPM_DUPLICATE_VHANDLE", "TPM duplicate vhandle."),
+    (0xC0290403, "STATUS_TPM_EMBEDDED_COMMAND_BLOCKED", "TPM embedded command blocked."),
+    (0xC0290404, "STATUS_TPM_EMBEDDED_COMMAND_UNSUPPORTED", "TPM embedded command unsupported."),
+    (0xC0290800, "STATUS_TPM_RETRY", "TPM retry."),
+    (0xC0290801, "STATUS_TPM_NEEDS_SELFTEST", "TPM needs selftest."),
+    (0xC0290802, "STATUS_TPM_DOING_SELFTEST", "TPM doing selftest."),
+    (0xC0290803, "STATUS_TPM_DEFEND_LOCK_RUNNING", "TPM defend lock running."),
+    (0xC0291001, "STATUS_TPM_COMMAND_CANCELED", "TPM command canceled."),
+    (0xC0291002, "STATUS_TPM_TOO_MANY_CONTEXTS", "TPM too many contexts."),
+    (0xC0291003, "STATUS_TPM_NOT_FOUND", "TPM not found."),
+    (0xC0291004, "STATUS_TPM_ACCESS_DENIED", "TPM access denied."),
+    (0xC0291005, "STATUS_TPM_INSUFFICIENT_BUFFER", "TPM insufficient buffer."),
+    (0xC0291006, "STATUS_TPM_PPI_FUNCTION_UNSUPPORTED", "TPM ppi function unsupported."),
+    (0xC0292000, "STATUS_PCP_ERROR_MASK", "PCP error mask."),
+    (0xC0292001, "STATUS_PCP_DEVICE_NOT_READY", "PCP device not ready."),
+    (0xC0292002, "STATUS_PCP_INVALID_HANDLE", "PCP invalid handle."),
+    (0xC0292003, "STATUS_PCP_INVALID_PARAMETER", "PCP invalid parameter."),
+    (0xC0292004, "STATUS_PCP_FLAG_NOT_SUPPORTED", "PCP flag not supported."),
+    (0xC0292005, "STATUS_PCP_NOT_SUPPORTED", "PCP not supported."),
+    (0xC0292006, "STATUS_PCP_BUFFER_TOO_SMALL", "PCP buffer too small."),
+    (0xC0292007, "STATUS_PCP_INTERNAL_ERROR", "PCP internal error."),
+    (0xC0292008, "STATUS_PCP_AUTHENTICATION_FAILED", "PCP authentication failed."),
+    (0xC0292009, "STATUS_PCP_AUTHENTICATION_IGNORED", "PCP authentication ignored."),
+    (0xC029200A, "STATUS_PCP_POLICY_NOT_FOUND", "PCP policy not found."),
+    (0xC029200B, "STATUS_PCP_PROFILE_NOT_FOUND", "PCP profile not found."),
+    (0xC029200C, "STATUS_PCP_VALIDATION_FAILED", "PCP validation failed."),
+    (0xC029200D, "STATUS_PCP_DEVICE_NOT_FOUND", "PCP device not found."),
+    (0xC029200E, "STATUS_PCP_WRONG_PARENT", "PCP wrong parent."),
+    (0xC029200F, "STATUS_PCP_KEY_NOT_LOADED", "PCP key not loaded."),
+    (0xC0292010, "STATUS_PCP_NO_KEY_CERTIFICATION", "PCP no key certification."),
+    (0xC0292011, "STATUS_PCP_KEY_NOT_FINALIZED", "PCP key not finalized."),
+    (0xC0292012, "STATUS_PCP_ATTESTATION_CHALLENGE_NOT_SET", "PCP attestation challenge not set."),
+    (0xC0292013, "STATUS_PCP_NOT_PCR_BOUND", "PCP not PCR bound."),
+    (0xC0292014, "STATUS_PCP_KEY_ALREADY_FINALIZED", "PCP key already finalized."),
+    (0xC0292015, "STATUS_PCP_KEY_USAGE_POLICY_NOT_SUPPORTED", "PCP key usage policy not supported."),
+    (0xC0292016, "STATUS_PCP_KEY_USAGE_POLICY_INVALID", "PCP key usage policy invalid."),
+    (0xC0292017, "STATUS_PCP_SOFT_KEY_ERROR", "PCP soft key error."),
+    (0xC0292018, "STATUS_PCP_KEY_NOT_AUTHENTICATED", "PCP key not authenticated."),
+    (0xC0292019, "STATUS_PCP_KEY_NOT_AIK", "PCP key not aik."),
+    (0xC029201A, "STATUS_PCP_KEY_NOT_SIGNING_KEY", "PCP key not signing key."),
+    (0xC029201B, "STATUS_PCP_LOCKED_OUT", "PCP locked out."),
+    (0xC029201C, "STATUS_PCP_CLAIM_TYPE_NOT_SUPPORTED", "PCP claim type not supported."),
+    (0xC029201D, "STATUS_PCP_TPM_VERSION_NOT_SUPPORTED", "PCP TPM version not supported."),
+    (0xC029201E, "STATUS_PCP_BUFFER_LENGTH_MISMATCH", "PCP buffer length mismatch."),
+    (0xC029201F, "STATUS_PCP_IFX_RSA_KEY_CREATION_BLOCKED", "PCP ifx rsa key creation blocked."),
+    (0xC0292020, "STATUS_PCP_TICKET_MISSING", "PCP ticket missing."),
+    (0xC0292021, "STATUS_PCP_RAW_POLICY_NOT_SUPPORTED", "PCP raw policy not supported."),
+    (0xC0292022, "STATUS_PCP_KEY_HANDLE_INVALIDATED", "PCP key handle invalidated."),
+    (0xC0293002, "STATUS_RTPM_NO_RESULT", "RTPM no result."),
+    (0xC0293003, "STATUS_RTPM_PCR_READ_INCOMPLETE", "RTPM PCR read incomplete."),
+    (0xC0293004, "STATUS_RTPM_INVALID_CONTEXT", "RTPM invalid context."),
+    (0xC0293005, "STATUS_RTPM_UNSUPPORTED_CMD", "RTPM unsupported CMD."),
+    (0xC0294000, "STATUS_TPM_ZERO_EXHAUST_ENABLED", "TPM zero exhaust enabled."),
+    (0xC0350002, "STATUS_HV_INVALID_HYPERCALL_CODE", "HV invalid hypercall code."),
+    (0xC0350003, "STATUS_HV_INVALID_HYPERCALL_INPUT", "HV invalid hypercall input."),
+    (0xC0350004, "STATUS_HV_INVALID_ALIGNMENT", "HV invalid alignment."),
+    (0xC0350005, "STATUS_HV_INVALID_PARAMETER", "HV invalid parameter."),
+    (0xC0350006, "STATUS_HV_ACCESS_DENIED", "HV access denied."),
+    (0xC0350007, "STATUS_HV_INVALID_PARTITION_STATE", "HV invalid partition state."),
+    (0xC0350008, "STATUS_HV_OPERATION_DENIED", "HV operation denied."),
+    (0xC0350009, "STATUS_HV_UNKNOWN_PROPERTY", "HV unknown property."),
+    (0xC035000A, "STATUS_HV_PROPERTY_VALUE_OUT_OF_RANGE", "HV property value out of range."),
+    (0xC035000B, "STATUS_HV_INSUFFICIENT_MEMORY", "HV insufficient memory."),
+    (0xC035000C, "STATUS_HV_PARTITION_TOO_DEEP", "HV partition too deep."),
+    (0xC035000D, "STATUS_HV_INVALID_PARTITION_ID", "HV invalid partition ID."),
+    (0xC035000E, "STATUS_HV_INVALID_VP_INDEX", "HV invalid VP index."),
+    (0xC0350011, "STATUS_HV_INVALID_PORT_ID", "HV invalid port ID."),
+    (0xC0350012, "STATUS_HV_INVALID_CONNECTION_ID", "HV invalid connection ID."),
+    (0xC0350013, "STATUS_HV_INSUFFICIENT_BUFFERS", "HV insufficient buffers."),
+    (0xC0350014, "STATUS_HV_NOT_ACKNOWLEDGED", "HV not acknowledged."),
+    (0xC0350015, "STATUS_HV_INVALID_VP_STATE", "HV invalid VP state."),
+    (0xC0350016, "STATUS_HV_ACKNOWLEDGED", "HV acknowledged."),
+    (0xC0350017, "STATUS_HV_INVALID_SAVE_RESTORE_STATE", "HV invalid save restore state."),
+    (0xC0350018, "STATUS_HV_INVALID_SYNIC_STATE", "HV invalid synic state."),
+    (0xC0350019, "STATUS_HV_OBJECT_IN_USE", "HV object in use."),
+    (0xC035001A, "STATUS_HV_INVALID_PROXIMITY_DOMAIN_INFO", "HV invalid proximity domain info."),
+    (0xC035001B, "STATUS_HV_NO_DATA", "HV no data."),
+    (0xC035001C, "STATUS_HV_INACTIVE", "HV inactive."),
+    (0xC035001D, "STATUS_HV_NO_RESOURCES", "HV no resources."),
+    (0xC035001E, "STATUS_HV_FEATURE_UNAVAILABLE", "HV feature unavailable."),
+    (0xC0350033, "STATUS_HV_INSUFFICIENT_BUFFER", "HV insufficient buffer."),
+    (0xC0350038, "STATUS_HV_INSUFFICIENT_DEVICE_DOMAINS", "HV insufficient device domains."),
+    (0xC035003C, "STATUS_HV_CPUID_FEATURE_VALIDATION_ERROR", "HV cpuid feature validation error."),
+    (0xC035003D, "STATUS_HV_CPUID_XSAVE_FEATURE_VALIDATION_ERROR", "HV cpuid xsave feature validation error."),
+    (0xC035003E, "STATUS_HV_PROCESSOR_STARTUP_TIMEOUT", "HV processor startup timeout."),
+    (0xC035003F, "STATUS_HV_SMX_ENABLED", "HV SMX enabled."),
+    (0xC0350041, "STATUS_HV_INVALID_LP_INDEX", "HV invalid LP index."),
+    (0xC0350050, "STATUS_HV_INVALID_REGISTER_VALUE", "HV invalid register value."),
+    (0xC0350051, "STATUS_HV_INVALID_VTL_STATE", "HV invalid VTL state."),
+    (0xC0350055, "STATUS_HV_NX_NOT_DETECTED", "HV NX not detected."),
+    (0xC0350057, "STATUS_HV_INVALID_DEVICE_ID", "HV invalid device ID."),
+    (0xC0350058, "STATUS_HV_INVALID_DEVICE_STATE", "HV invalid device state."),
+    (0xC0350060, "STATUS_HV_PAGE_REQUEST_INVALID", "HV page request invalid."),
+    (0xC035006F, "STATUS_HV_INVALID_CPU_GROUP_ID", "HV invalid CPU group ID."),
+    (0xC0350070, "STATUS_HV_INVALID_CPU_GROUP_STATE", "HV invalid CPU group state."),
+    (0xC0350071, "STATUS_HV_OPERATION_FAILED", "HV operation failed."),
+    (0xC0350072, "STATUS_HV_NOT_ALLOWED_WITH_NESTED_VIRT_ACTIVE", "HV not allowed with nested virt active."),
+    (0xC0350073, "STATUS_HV_INSUFFICIENT_ROOT_MEMORY", "HV insufficient root memory."),
+    (0xC0350074, "STATUS_HV_EVENT_BUFFER_ALREADY_FREED", "HV event buffer already freed."),
+    (0xC0350075, "STATUS_HV_INSUFFICIENT_CONTIGUOUS_MEMORY", "HV insufficient contiguous memory."),
+    (0xC0350076, "STATUS_HV_DEVICE_NOT_IN_DOMAIN", "HV device not in domain."),
+    (0xC0350077, "STATUS_HV_NESTED_VM_EXIT", "HV nested VM exit."),
+    (0xC0350079, "STATUS_HV_CALL_PENDING", "HV call pending."),
+    (0xC0350080, "STATUS_HV_MSR_ACCESS_FAILED", "HV MSR access failed."),
+    (0xC0350081, "STATUS_HV_INSUFFICIENT_MEMORY_MIRRORING", "HV insufficient memory mirroring."),
+    (0xC0350082, "STATUS_HV_INSUFFICIENT_CONTIGUOUS_MEMORY_MIRRORING", "HV insufficient contiguous memory mirroring."),
+    (0xC0350083, "STATUS_HV_INSUFFICIENT_CONTIGUOUS_ROOT_MEMORY", "HV insufficient contiguous root memory."),
+    (0xC0350084, "STATUS_HV_INSUFFICIENT_ROOT_MEMORY_MIRRORING", "HV insufficient root memory mirroring."),
+    (0xC0350085, "STATUS_HV_INSUFFICIENT_CONTIGUOUS_ROOT_MEMORY_MIRRORING", "HV insufficient contiguous root memory mirroring."),
+    (0xC0351000, "STATUS_HV_NOT_PRESENT", "HV not present."),
+    (0xC0360001, "STATUS_IPSEC_BAD_SPI", "IPSEC bad spi."),
+    (0xC0360002, "STATUS_IPSEC_SA_LIFETIME_EXPIRED", "IPSEC sa lifetime expired."),
+    (0xC0360003, "STATUS_IPSEC_WRONG_SA", "IPSEC wrong sa."),
+    (0xC0360004, "STATUS_IPSEC_REPLAY_CHECK_FAILED", "IPSEC replay check failed."),
+    (0xC0360005, "STATUS_IPSEC_INVALID_PACKET", "IPSEC invalid packet."),
+    (0xC0360006, "STATUS_IPSEC_INTEGRITY_CHECK_FAILED", "IPSEC integrity check failed."),
+    (0xC0360007, "STATUS_IPSEC_CLEAR_TEXT_DROP", "IPSEC clear text drop."),
+    (0xC0360008, "STATUS_IPSEC_AUTH_FIREWALL_DROP", "IPSEC auth firewall drop."),
+    (0xC0360009, "STATUS_IPSEC_THROTTLE_DROP", "IPSEC throttle drop."),
+    (0xC0368000, "STATUS_IPSEC_DOSP_BLOCK", "IPSEC dosp block."),
+    (0xC0368001, "STATUS_IPSEC_DOSP_RECEIVED_MULTICAST", "IPSEC dosp received multicast."),
+    (0xC0368002, "STATUS_IPSEC_DOSP_INVALID_PACKET", "IPSEC dosp invalid packet."),
+    (0xC0368003, "STATUS_IPSEC_DOSP_STATE_LOOKUP_FAILED", "IPSEC dosp state lookup failed."),
+    (0xC0368004, "STATUS_IPSEC_DOSP_MAX_ENTRIES", "IPSEC dosp max entries."),
+    (0xC0368005, "STATUS_IPSEC_DOSP_KEYMOD_NOT_ALLOWED", "IPSEC dosp keymod not allowed."),
+    (0xC0368006, "STATUS_IPSEC_DOSP_MAX_PER_IP_RATELIMIT_QUEUES", "IPSEC dosp max per IP ratelimit queues."),
+    (0xC0370001, "STATUS_VID_DUPLICATE_HANDLER", "VID duplicate handler."),
+    (0xC0370002, "STATUS_VID_TOO_MANY_HANDLERS", "VID too many handlers."),
+    (0xC0370003, "STATUS_VID_QUEUE_FULL", "VID queue full."),
+    (0xC0370004, "STATUS_VID_HANDLER_NOT_PRESENT", "VID handler not present."),
+    (0xC0370005, "STATUS_VID_INVALID_OBJECT_NAME", "VID invalid object name."),
+    (0xC0370006, "STATUS_VID_PARTITION_NAME_TOO_LONG", "VID partition name too long."),
+    (0xC0370007, "STATUS_VID_MESSAGE_QUEUE_NAME_TOO_LONG", "VID message queue name too long."),
+    (0xC0370008, "STATUS_VID_PARTITION_ALREADY_EXISTS", "VID partition already exists."),
+    (0xC0370009, "STATUS_VID_PARTITION_DOES_NOT_EXIST", "VID partition does not exist."),
+    (0xC037000A, "STATUS_VID_PARTITION_NAME_NOT_FOUND", "VID partition name not found."),
+    (0xC037000B, "STATUS_VID_MESSAGE_QUEUE_ALREADY_EXISTS", "VID message queue already exists."),
+    (0xC037000C, "STATUS_VID_EXCEEDED_MBP_ENTRY_MAP_LIMIT", "VID exceeded MBP entry map limit."),
+    (0xC037000D, "STATUS_VID_MB_STILL_REFERENCED", "VID MB still referenced."),
+    (0xC037000E, "STATUS_VID_CHILD_GPA_PAGE_SET_CORRUPTED", "VID child gpa page set corrupted."),
+    (0xC037000F, "STATUS_VID_INVALID_NUMA_SETTINGS", "VID invalid NUMA settings."),
+    (0xC0370010, "STATUS_VID_INVALID_NUMA_NODE_INDEX", "VID invalid NUMA node index."),
+    (0xC0370011, "STATUS_VID_NOTIFICATION_QUEUE_ALREADY_ASSOCIATED", "VID notification queue already associated."),
+    (0xC0370012, "STATUS_VID_INVALID_MEMORY_BLOCK_HANDLE", "VID invalid memory block handle."),
+    (0xC0370013, "STATUS_VID_PAGE_RANGE_OVERFLOW", "VID page range overflow."),
+    (0xC0370014, "STATUS_VID_INVALID_MESSAGE_QUEUE_HANDLE", "VID invalid message queue handle."),
+    (0xC0370015, "STATUS_VID_INVALID_GPA_RANGE_HANDLE", "VID invalid gpa range handle."),
+    (0xC0370016, "STATUS_VID_NO_MEMORY_BLOCK_NOTIFICATION_QUEUE", "VID no memory block notification queue."),
+    (0xC0370017, "STATUS_VID_MEMORY_BLOCK_LOCK_COUNT_EXCEEDED", "VID memory block lock count exceeded."),
+    (0xC0370018, "STATUS_VID_INVALID_PPM_HANDLE", "VID invalid PPM handle."),
+    (0xC0370019, "STATUS_VID_MBPS_ARE_LOCKED", "VID MBPS are locked."),
+    (0xC037001A, "STATUS_VID_MESSAGE_QUEUE_CLOSED", "VID message queue closed."),
+    (0xC037001B, "STATUS_VID_VIRTUAL_PROCESSOR_LIMIT_EXCEEDED", "VID virtual processor limit exceeded."),
+    (0xC037001C, "STATUS_VID_STOP_PENDING", "VID stop pending."),
+    (0xC037001D, "STATUS_VID_INVALID_PROCESSOR_STATE", "VID invalid processor state."),
+    (0xC037001E, "STATUS_VID_EXCEEDED_KM_CONTEXT_COUNT_LIMIT", "VID exceeded KM context count limit."),
+    (0xC037001F, "STATUS_VID_KM_INTERFACE_ALREADY_INITIALIZED", "VID KM interface already initialized."),
+    (0xC0370020, "STATUS_VID_MB_PROPERTY_ALREADY_SET_RESET", "VID MB property already set reset."),
+    (0xC0370021, "STATUS_VID_MMIO_RANGE_DESTROYED", "VID mmio range destroyed."),
+    (0xC0370022, "STATUS_VID_INVALID_CHILD_GPA_PAGE_SET", "VID invalid child gpa page set."),
+    (0xC0370023, "STATUS_VID_RESERVE_PAGE_SET_IS_BEING_USED", "VID reserve page set is being used."),
+    (0xC0370024, "STATUS_VID_RESERVE_PAGE_SET_TOO_SMALL", "VID reserve page set too small."),
+    (0xC0370025, "STATUS_VID_MBP_ALREADY_LOCKED_USING_RESERVED_PAGE", "VID MBP already locked using reserved page."),
+    (0xC0370026, "STATUS_VID_MBP_COUNT_EXCEEDED_LIMIT", "VID MBP count exceeded limit."),
+    (0xC0370027, "STATUS_VID_SAVED_STATE_CORRUPT", "VID saved state corrupt."),
+    (0xC0370028, "STATUS_VID_SAVED_STATE_UNRECOGNIZED_ITEM", "VID saved state unrecognized item."),
+    (0xC0370029, "STATUS_VID_SAVED_STATE_INCOMPATIBLE", "VID saved state incompatible."),
+    (0xC037002A, "STATUS_VID_VTL_ACCESS_DENIED", "VID VTL access denied."),
+    (0xC037002B, "STATUS_VID_INSUFFICIENT_RESOURCES_RESERVE", "VID insufficient resources reserve."),
+    (0xC037002C, "STATUS_VID_INSUFFICIENT_RESOURCES_PHYSICAL_BUFFER", "VID insufficient resources physical buffer."),
+    (0xC037002D, "STATUS_VID_INSUFFICIENT_RESOURCES_HV_DEPOSIT", "VID insufficient resources HV deposit."),
+    (0xC037002E, "STATUS_VID_MEMORY_TYPE_NOT_SUPPORTED", "VID memory type not supported."),
+    (0xC037002F, "STATUS_VID_INSUFFICIENT_RESOURCES_WITHDRAW", "VID insufficient resources withdraw."),
+    (0xC0370030, "STATUS_VID_PROCESS_ALREADY_SET", "VID process already set."),
+    (0xC0370600, "STATUS_DM_OPERATION_LIMIT_EXCEEDED", "DM operation limit exceeded."),
+    (0xC0380001, "STATUS_VOLMGR_DATABASE_FULL", "VOLMGR database full."),
+    (0xC0380002, "STATUS_VOLMGR_DISK_CONFIGURATION_CORRUPTED", "VOLMGR disk configuration corrupted."),
+    (0xC0380003, "STATUS_VOLMGR_DISK_CONFIGURATION_NOT_IN_SYNC", "VOLMGR disk configuration not in sync."),
+    (0xC0380004, "STATUS_VOLMGR_PACK_CONFIG_UPDATE_FAILED", "VOLMGR pack config update failed."),
+    (0xC0380005, "STATUS_VOLMGR_DISK_CONTAINS_NON_SIMPLE_VOLUME", "VOLMGR disk contains non simple volume."),
+    (0xC0380006, "STATUS_VOLMGR_DISK_DUPLICATE", "VOLMGR disk duplicate."),
+    (0xC0380007, "STATUS_VOLMGR_DISK_DYNAMIC", "VOLMGR disk dynamic."),
+    (0xC0380008, "STATUS_VOLMGR_DISK_ID_INVALID", "VOLMGR disk ID invalid."),
+    (0xC0380009, "STATUS_VOLMGR_DISK_INVALID", "VOLMGR disk invalid."),
+    (0xC038000A, "STATUS_VOLMGR_DISK_LAST_VOTER", "VOLMGR disk last voter."),
+    (0xC038000B, "STATUS_VOLMGR_DISK_LAYOUT_INVALID", "VOLMGR disk layout invalid."),
+    (0xC038000C, "STATUS_VOLMGR_DISK_LAYOUT_NON_BASIC_BETWEEN_BASIC_PARTITIONS", "VOLMGR disk layout non basic between basic partitions."),
+    (0xC038000D, "STATUS_VOLMGR_DISK_LAYOUT_NOT_CYLINDER_ALIGNED", "VOLMGR disk layout not cylinder aligned."),
+    (0xC038000E, "STATUS_VOLMGR_DISK_LAYOUT_PARTITIONS_TOO_SMALL", "VOLMGR disk layout partitions too small."),
+    (0xC038000F, "STATUS_VOLMGR_DISK_LAYOUT_PRIMARY_BETWEEN_LOGICAL_PARTITIONS", "VOLMGR disk layout primary between logical partitions."),
+    (0xC0380010, "STATUS_VOLMGR_DISK_LAYOUT_TOO_MANY_PARTITIONS", "VOLMGR disk layout too many partitions."),
+    (0xC0380011, "STATUS_VOLMGR_DISK_MISSING", "VOLMGR disk missing."),
+    (0xC0380012, "STATUS_VOLMGR_DISK_NOT_EMPTY", "VOLMGR disk not empty."),
+    (0xC0380013, "STATUS_VOLMGR_DISK_NOT_ENOUGH_SPACE", "VOLMGR disk not enough space."),
+    (0xC0380014, "STATUS_VOLMGR_DISK_REVECTORING_FAILED", "VOLMGR disk revectoring failed."),
+    (0xC0380015, "STATUS_VOLMGR_DISK_SECTOR_SIZE_INVALID", "VOLMGR disk sector size invalid."),
+    (0xC0380016, "STATUS_VOLMGR_DISK_SET_NOT_CONTAINED", "VOLMGR disk set not contained."),
+    (0xC0380017, "STATUS_VOLMGR_DISK_USED_BY_MULTIPLE_MEMBERS", "VOLMGR disk used by multiple members."),
+    (0xC0380018, "STATUS_VOLMGR_DISK_USED_BY_MULTIPLE_PLEXES", "VOLMGR disk used by multiple plexes."),
+    (0xC0380019, "STATUS_VOLMGR_DYNAMIC_DISK_NOT_SUPPORTED", "VOLMGR dynamic disk not supported."),
+    (0xC038001A, "STATUS_VOLMGR_EXTENT_ALREADY_USED", "VOLMGR extent already used."),
+    (0xC038001B, "STATUS_VOLMGR_EXTENT_NOT_CONTIGUOUS", "VOLMGR extent not contiguous."),
+    (0xC038001C, "STATUS_VOLMGR_EXTENT_NOT_IN_PUBLIC_REGION", "VOLMGR extent not in public region."),
+    (0xC038001D, "STATUS_VOLMGR_EXTENT_NOT_SECTOR_ALIGNED", "VOLMGR extent not sector aligned."),
+    (0xC038001E, "STATUS_VOLMGR_EXTENT_OVERLAPS_EBR_PARTITION", "VOLMGR extent overlaps ebr partition."),
+    (0xC038001F, "STATUS_VOLMGR_EXTENT_VOLUME_LENGTHS_DO_NOT_MATCH", "VOLMGR extent volume lengths do not match."),
+    (0xC0380020, "STATUS_VOLMGR_FAULT_TOLERANT_NOT_SUPPORTED", "VOLMGR fault tolerant not supported."),
+    (0xC0380021, "STATUS_VOLMGR_INTERLEAVE_LENGTH_INVALID", "VOLMGR interleave length invalid."),
+    (0xC0380022, "STATUS_VOLMGR_MAXIMUM_REGISTERED_USERS", "VOLMGR maximum registered users."),
+    (0xC0380023, "STATUS_VOLMGR_MEMBER_IN_SYNC", "VOLMGR member in sync."),
+    (0xC0380024, "STATUS_VOLMGR_MEMBER_INDEX_DUPLICATE", "VOLMGR member index duplicate."),
+    (0xC0380025, "STATUS_VOLMGR_MEMBER_INDEX_INVALID", "VOLMGR member index invalid."),
+    (0xC0380026, "STATUS_VOLMGR_MEMBER_MISSING", "VOLMGR member missing."),
+    (0xC0380027, "STATUS_VOLMGR_MEMBER_NOT_DETACHED", "VOLMGR member not detached."),
+    (0xC0380028, "STATUS_VOLMGR_MEMBER_REGENERATING", "VOLMGR member regenerating."),
+    (0xC0380029, "STATUS_VOLMGR_ALL_DISKS_FAILED", "VOLMGR all disks failed."),
+    (0xC038002A, "STATUS_VOLMGR_NO_REGISTERED_USERS", "VOLMGR no registered users."),
+    (0xC038002B, "STATUS_VOLMGR_NO_SUCH_USER", "VOLMGR no such user."),
+    (0xC038002C, "STATUS_VOLMGR_NOTIFICATION_RESET", "VOLMGR notification reset."),
+    (0xC038002D, "STATUS_VOLMGR_NUMBER_OF_MEMBERS_INVALID", "VOLMGR number of members invalid."),
+    (0xC038002E, "STATUS_VOLMGR_NUMBER_OF_PLEXES_INVALID", "VOLMGR number of plexes invalid."),
+    (0xC038002F, "STATUS_VOLMGR_PACK_DUPLICATE", "VOLMGR pack duplicate."),
+    (0xC0380030, "STATUS_VOLMGR_PACK_ID_INVALID", "VOLMGR pack ID invalid."),
+    (0xC0380031, "STATUS_VOLMGR_PACK_INVALID", "VOLMGR pack invalid."),
+    (0xC0380032, "STATUS_VOLMGR_PACK_NAME_INVALID", "VOLMGR pack name invalid."),
+    (0xC0380033, "STATUS_VOLMGR_PACK_OFFLINE", "VOLMGR pack offline."),
+    (0xC0380034, "STATUS_VOLMGR_PACK_HAS_QUORUM", "VOLMGR pack has quorum."),
+    (0xC0380035, "STATUS_VOLMGR_PACK_WITHOUT_QUORUM", "VOLMGR pack without quorum."),
+    (0xC0380036, "STATUS_VOLMGR_PARTITION_STYLE_INVALID", "VOLMGR partition style invalid."),
+    (0xC0380037, "STATUS_VOLMGR_PARTITION_UPDATE_FAILED", "VOLMGR partition update failed."),
+    (0xC0380038, "STATUS_VOLMGR_PLEX_IN_SYNC", "VOLMGR plex in sync."),
+    (0xC0380039, "STATUS_VOLMGR_PLEX_INDEX_DUPLICATE", "VOLMGR plex index duplicate."),
+    (0xC038003A, "STATUS_VOLMGR_PLEX_INDEX_INVALID", "VOLMGR plex index invalid."),
+    (0xC038003B, "STATUS_VOLMGR_PLEX_LAST_ACTIVE", "VOLMGR plex last active."),
+    (0xC038003C, "STATUS_VOLMGR_PLEX_MISSING", "VOLMGR plex missing."),
+    (0xC038003D, "STATUS_VOLMGR_PLEX_REGENERATING", "VOLMGR plex regenerating."),
+    (0xC038003E, "STATUS_VOLMGR_PLEX_TYPE_INVALID", "VOLMGR plex type invalid."),
+    (0xC038003F, "STATUS_VOLMGR_PLEX_NOT_RAID5", "VOLMGR plex not RAID5."),
+    (0xC0380040, "STATUS_VOLMGR_PLEX_NOT_SIMPLE", "VOLMGR plex not simple."),
+    (0xC0380041, "STATUS_VOLMGR_STRUCTURE_SIZE_INVALID", "VOLMGR structure size invalid."),
+    (0xC0380042, "STATUS_VOLMGR_TOO_MANY_NOTIFICATION_REQUESTS", "VOLMGR too many notification requests."),
+    (0xC0380043, "STATUS_VOLMGR_TRANSACTION_IN_PROGRESS", "VOLMGR transaction in progress."),
+    (0xC0380044, "STATUS_VOLMGR_UNEXPECTED_DISK_LAYOUT_CHANGE", "VOLMGR unexpected disk layout change."),
+    (0xC0380045, "STATUS_VOLMGR_VOLUME_CONTAINS_MISSING_DISK", "VOLMGR volume contains missing disk."),
+    (0xC0380046, "STATUS_VOLMGR_VOLUME_ID_INVALID", "VOLMGR volume ID invalid."),
+    (0xC0380047, "STATUS_VOLMGR_VOLUME_LENGTH_INVALID", "VOLMGR volume length invalid."),
+    (0xC0380048, "STATUS_VOLMGR_VOLUME_LENGTH_NOT_SECTOR_SIZE_MULTIPLE", "VOLMGR volume length not sector size multiple."),
+    (0xC0380049, "STATUS_VOLMGR_VOLUME_NOT_MIRRORED", "VOLMGR volume not mirrored."),
+    (0xC038004A, "STATUS_VOLMGR_VOLUME_NOT_RETAINED", "VOLMGR volume not retained."),
+    (0xC038004B, "STATUS_VOLMGR_VOLUME_OFFLINE", "VOLMGR volume offline."),
+    (0xC038004C, "STATUS_VOLMGR_VOLUME_RETAINED", "VOLMGR volume retained."),
+    (0xC038004D, "STATUS_VOLMGR_NUMBER_OF_EXTENTS_INVALID", "VOLMGR number of extents invalid."),
+    (0xC038004E, "STATUS_VOLMGR_DIFFERENT_SECTOR_SIZE", "VOLMGR different sector size."),
+    (0xC038004F, "STATUS_VOLMGR_BAD_BOOT_DISK", "VOLMGR bad boot disk."),
+    (0xC0380050, "STATUS_VOLMGR_PACK_CONFIG_OFFLINE", "VOLMGR pack config offline."),
+    (0xC0380051, "STATUS_VOLMGR_PACK_CONFIG_ONLINE", "VOLMGR pack config online."),
+    (0xC0380052, "STATUS_VOLMGR_NOT_PRIMARY_PACK", "VOLMGR not primary pack."),
+    (0xC0380053, "STATUS_VOLMGR_PACK_LOG_UPDATE_FAILED", "VOLMGR pack log update failed."),
+    (0xC0380054, "STATUS_VOLMGR_NUMBER_OF_DISKS_IN_PLEX_INVALID", "VOLMGR number of disks in plex invalid."),
+    (0xC0380055, "STATUS_VOLMGR_NUMBER_OF_DISKS_IN_MEMBER_INVALID", "VOLMGR number of disks in member invalid."),
+    (0xC0380056, "STATUS_VOLMGR_VOLUME_MIRRORED", "VOLMGR volume mirrored."),
+    (0xC0380057, "STATUS_VOLMGR_PLEX_NOT_SIMPLE_SPANNED", "VOLMGR plex not simple spanned."),
+    (0xC0380058, "STATUS_VOLMGR_NO_VALID_LOG_COPIES", "VOLMGR no valid log copies."),
+    (0xC0380059, "STATUS_VOLMGR_PRIMARY_PACK_PRESENT", "VOLMGR primary pack present."),
+    (0xC038005A, "STATUS_VOLMGR_NUMBER_OF_DISKS_INVALID", "VOLMGR number of disks invalid."),
+    (0xC038005B, "STATUS_VOLMGR_MIRROR_NOT_SUPPORTED", "VOLMGR mirror not supported."),
+    (0xC038005C, "STATUS_VOLMGR_RAID5_NOT_SUPPORTED", "VOLMGR RAID5 not supported."),
+    (0xC0390002, "STATUS_BCD_TOO_MANY_ELEMENTS", "BCD too many elements."),
+    (0xC03A0001, "STATUS_VHD_DRIVE_FOOTER_MISSING", "VHD drive footer missing."),
+    (0xC03A0002, "STATUS_VHD_DRIVE_FOOTER_CHECKSUM_MISMATCH", "VHD drive footer checksum mismatch."),
+    (0xC03A0003, "STATUS_VHD_DRIVE_FOOTER_CORRUPT", "VHD drive footer corrupt."),
+    (0xC03A0004, "STATUS_VHD_FORMAT_UNKNOWN", "VHD format unknown."),
+    (0xC03A0005, "STATUS_VHD_FORMAT_UNSUPPORTED_VERSION", "VHD format unsupported version."),
+    (0xC03A0006, "STATUS_VHD_SPARSE_HEADER_CHECKSUM_MISMATCH", "VHD sparse header checksum mismatch."),
+    (0xC03A0007, "STATUS_VHD_SPARSE_HEADER_UNSUPPORTED_VERSION", "VHD sparse header unsupported version."),
+    (0xC03A0008, "STATUS_VHD_SPARSE_HEADER_CORRUPT", "VHD sparse header corrupt."),
+    (0xC03A0009, "STATUS_VHD_BLOCK_ALLOCATION_FAILURE", "VHD block allocation failure."),
+    (0xC03A000A, "STATUS_VHD_BLOCK_ALLOCATION_TABLE_CORRUPT", "VHD block allocation table corrupt."),
+    (0xC03A000B, "STATUS_VHD_INVALID_BLOCK_SIZE", "VHD invalid block size."),
+    (0xC03A000C, "STATUS_VHD_BITMAP_MISMATCH", "VHD bitmap mismatch."),
+    (0xC03A000D, "STATUS_VHD_PARENT_VHD_NOT_FOUND", "VHD parent VHD not found."),
+    (0xC03A000E, "STATUS_VHD_CHILD_PARENT_ID_MISMATCH", "VHD child parent ID mismatch."),
+    (0xC03A000F, "STATUS_VHD_CHILD_PARENT_TIMESTAMP_MISMATCH", "VHD child parent timestamp mismatch."),
+    (0xC03A0010, "STATUS_VHD_METADATA_READ_FAILURE", "VHD metadata read failure."),
+    (0xC03A0011, "STATUS_VHD_METADATA_WRITE_FAILURE", "VHD metadata write failure."),
+    (0xC03A0012, "STATUS_VHD_INVALID_SIZE", "VHD invalid size."),
+    (0xC03A0013, "STATUS_VHD_INVALID_FILE_SIZE", "VHD invalid file size."),
+    (0xC03A0014, "STATUS_VIRTDISK_PROVIDER_NOT_FOUND", "Virtdisk provider not found."),
+    (0xC03A0015, "STATUS_VIRTDISK_NOT_VIRTUAL_DISK", "Virtdisk not virtual disk."),
+    (0xC03A0016, "STATUS_VHD_PARENT_VHD_ACCESS_DENIED", "VHD parent VHD access denied."),
+    (0xC03A0017, "STATUS_VHD_CHILD_PARENT_SIZE_MISMATCH", "VHD child parent size mismatch."),
+    (0xC03A0018, "STATUS_VHD_DIFFERENCING_CHAIN_CYCLE_DETECTED", "VHD differencing chain cycle detected."),
+    (0xC03A0019, "STATUS_VHD_DIFFERENCING_CHAIN_ERROR_IN_PARENT", "VHD differencing chain error in parent."),
+    (0xC03A001A, "STATUS_VIRTUAL_DISK_LIMITATION", "Virtual disk limitation."),
+    (0xC03A001B, "STATUS_VHD_INVALID_TYPE", "VHD invalid type."),
+    (0xC03A001C, "STATUS_VHD_INVALID_STATE", "VHD invalid state."),
+    (0xC03A001D, "STATUS_VIRTDISK_UNSUPPORTED_DISK_SECTOR_SIZE", "Virtdisk unsupported disk sector size."),
+    (0xC03A001E, "STATUS_VIRTDISK_DISK_ALREADY_OWNED", "Virtdisk disk already owned."),
+    (0xC03A001F, "STATUS_VIRTDISK_DISK_ONLINE_AND_WRITABLE", "Virtdisk disk online and writable."),
+    (0xC03A0020, "STATUS_CTLOG_TRACKING_NOT_INITIALIZED", "CTLOG tracking not initialized."),
+    (0xC03A0021, "STATUS_CTLOG_LOGFILE_SIZE_EXCEEDED_MAXSIZE", "CTLOG logfile size exceeded maxsize."),
+    (0xC03A0022, "STATUS_CTLOG_VHD_CHANGED_OFFLINE", "CTLOG VHD changed offline."),
+    (0xC03A0023, "STATUS_CTLOG_INVALID_TRACKING_STATE", "CTLOG invalid tracking state."),
+    (0xC03A0024, "STATUS_CTLOG_INCONSISTENT_TRACKING_FILE", "CTLOG inconsistent tracking file."),
+    (0xC03A0028, "STATUS_VHD_METADATA_FULL", "VHD metadata full."),
+    (0xC03A0029, "STATUS_VHD_INVALID_CHANGE_TRACKING_ID", "VHD invalid change tracking ID."),
+    (0xC03A002A, "STATUS_VHD_CHANGE_TRACKING_DISABLED", "VHD change tracking disabled."),
+    (0xC03A0030, "STATUS_VHD_MISSING_CHANGE_TRACKING_INFORMATION", "VHD missing change tracking information."),
+    (0xC03A0031, "STATUS_VHD_RESIZE_WOULD_TRUNCATE_DATA", "VHD resize would truncate data."),
+    (0xC03A0032, "STATUS_VHD_COULD_NOT_COMPUTE_MINIMUM_VIRTUAL_SIZE", "VHD could not compute minimum virtual size."),
+    (0xC03A0033, "STATUS_VHD_ALREADY_AT_OR_BELOW_MINIMUM_VIRTUAL_SIZE", "VHD already at or below minimum virtual size."),
+    (0xC03A0034, "STATUS_VHD_UNEXPECTED_ID", "VHD unexpected ID."),
+    (0xC0400001, "STATUS_RKF_KEY_NOT_FOUND", "RKF key not found."),
+    (0xC0400002, "STATUS_RKF_DUPLICATE_KEY", "RKF duplicate key."),
+    (0xC0400003, "STATUS_RKF_BLOB_FULL", "RKF blob full."),
+    (0xC0400004, "STATUS_RKF_STORE_FULL", "RKF store full."),
+    (0xC0400005, "STATUS_RKF_FILE_BLOCKED", "RKF file blocked."),
+    (0xC0400006, "STATUS_RKF_ACTIVE_KEY", "RKF active key."),
+    (0xC0410001, "STATUS_RDBSS_RESTART_OPERATION", "RDBSS restart operation."),
+    (0xC0410002, "STATUS_RDBSS_CONTINUE_OPERATION", "RDBSS continue operation."),
+    (0xC0410003, "STATUS_RDBSS_POST_OPERATION", "RDBSS post operation."),
+    (0xC0410004, "STATUS_RDBSS_RETRY_LOOKUP", "RDBSS retry lookup."),
+    (0xC0420001, "STATUS_BTH_ATT_INVALID_HANDLE", "BTH att invalid handle."),
+    (0xC0420002, "STATUS_BTH_ATT_READ_NOT_PERMITTED", "BTH att read not permitted."),
+    (0xC0420003, "STATUS_BTH_ATT_WRITE_NOT_PERMITTED", "BTH att write not permitted."),
+    (0xC0420004, "STATUS_BTH_ATT_INVALID_PDU", "BTH att invalid pdu."),
+    (0xC0420005, "STATUS_BTH_ATT_INSUFFICIENT_AUTHENTICATION", "BTH att insufficient authentication."),
+    (0xC0420006, "STATUS_BTH_ATT_REQUEST_NOT_SUPPORTED", "BTH att request not supported."),
+    (0xC0420007, "STATUS_BTH_ATT_INVALID_OFFSET", "BTH att invalid offset."),
+    (0xC0420008, "STATUS_BTH_ATT_INSUFFICIENT_AUTHORIZATION", "BTH att insufficient authorization."),
+    (0xC0420009, "STATUS_BTH_ATT_PREPARE_QUEUE_FULL", "BTH att prepare queue full."),
+    (0xC042000A, "STATUS_BTH_ATT_ATTRIBUTE_NOT_FOUND", "BTH att attribute not found."),
+    (0xC042000B, "STATUS_BTH_ATT_ATTRIBUTE_NOT_LONG", "BTH att attribute not long."),
+    (0xC042000C, "STATUS_BTH_ATT_INSUFFICIENT_ENCRYPTION_KEY_SIZE", "BTH att insufficient encryption key size."),
+    (0xC042000D, "STATUS_BTH_ATT_INVALID_ATTRIBUTE_VALUE_LENGTH", "BTH att invalid attribute value length."),
+    (0xC042000E, "STATUS_BTH_ATT_UNLIKELY", "BTH att unlikely."),
+    (0xC042000F, "STATUS_BTH_ATT_INSUFFICIENT_ENCRYPTION", "BTH att insufficient encryption."),
+    (0xC0420010, "STATUS_BTH_ATT_UNSUPPORTED_GROUP_TYPE", "BTH att unsupported group type."),
+    (0xC0420011, "STATUS_BTH_ATT_INSUFFICIENT_RESOURCES", "BTH att insufficient resources."),
+    (0xC0421000, "STATUS_BTH_ATT_UNKNOWN_ERROR", "BTH att unknown error."),
+    (0xC0430001, "STATUS_SECUREBOOT_ROLLBACK_DETECTED", "Secureboot rollback detected."),
+    (0xC0430002, "STATUS_SECUREBOOT_POLICY_VIOLATION", "Secureboot policy violation."),
+    (0xC0430003, "STATUS_SECUREBOOT_INVALID_POLICY", "Secureboot invalid policy."),
+    (0xC0430004, "STATUS_SECUREBOOT_POLICY_PUBLISHER_NOT_FOUND", "Secureboot policy publisher not found."),
+    (0xC0430005, "STATUS_SECUREBOOT_POLICY_NOT_SIGNED", "Secureboot policy not signed."),
+    (0xC0430007, "STATUS_SECUREBOOT_FILE_REPLACED", "Secureboot file replaced."),
+    (0xC0430008, "STATUS_SECUREBOOT_POLICY_NOT_AUTHORIZED", "Secureboot policy not authorized."),
+    (0xC0430009, "STATUS_SECUREBOOT_POLICY_UNKNOWN", "Secureboot policy unknown."),
+    (0xC043000A, "STATUS_SECUREBOOT_POLICY_MISSING_ANTIROLLBACKVERSION", "Secureboot policy missing antirollbackversion."),
+    (0xC043000B, "STATUS_SECUREBOOT_PLATFORM_ID_MISMATCH", "Secureboot platform ID mismatch."),
+    (0xC043000C, "STATUS_SECUREBOOT_POLICY_ROLLBACK_DETECTED", "Secureboot policy rollback detected."),
+    (0xC043000D, "STATUS_SECUREBOOT_POLICY_UPGRADE_MISMATCH", "Secureboot policy upgrade mismatch."),
+    (0xC043000E, "STATUS_SECUREBOOT_REQUIRED_POLICY_FILE_MISSING", "Secureboot required policy file missing."),
+    (0xC043000F, "STATUS_SECUREBOOT_NOT_BASE_POLICY", "Secureboot not base policy."),
+    (0xC0430010, "STATUS_SECUREBOOT_NOT_SUPPLEMENTAL_POLICY", "Secureboot not supplemental policy."),
+    (0xC0440001, "STATUS_AUDIO_ENGINE_NODE_NOT_FOUND", "Audio engine node not found."),
+    (0xC0440002, "STATUS_HDAUDIO_EMPTY_CONNECTION_LIST", "Hdaudio empty connection list."),
+    (0xC0440003, "STATUS_HDAUDIO_CONNECTION_LIST_NOT_SUPPORTED", "Hdaudio connection list not supported."),
+    (0xC0440004, "STATUS_HDAUDIO_NO_LOGICAL_DEVICES_CREATED", "Hdaudio no logical devices created."),
+    (0xC0440005, "STATUS_HDAUDIO_NULL_LINKED_LIST_ENTRY", "Hdaudio null linked list entry."),
+    (0xC0450000, "STATUS_VSM_NOT_INITIALIZED", "VSM not initialized."),
+    (0xC0450001, "STATUS_VSM_DMA_PROTECTION_NOT_IN_USE", "VSM dma protection not in use."),
+    (0xC0460001, "STATUS_IORING_REQUIRED_FLAG_NOT_SUPPORTED", "IORING required flag not supported."),
+    (0xC0460002, "STATUS_IORING_SUBMISSION_QUEUE_FULL", "IORING submission queue full."),
+    (0xC0460003, "STATUS_IORING_VERSION_NOT_SUPPORTED", "IORING version not supported."),
+    (0xC0460004, "STATUS_IORING_SUBMISSION_QUEUE_TOO_BIG", "IORING submission queue too big."),
+    (0xC0460005, "STATUS_IORING_COMPLETION_QUEUE_TOO_BIG", "IORING completion queue too big."),
+    (0xC0460006, "STATUS_IORING_SUBMIT_IN_PROGRESS", "IORING submit in progress."),
+    (0xC0460007, "STATUS_IORING_CORRUPT", "IORING corrupt."),
+    (0xC0460008, "STATUS_IORING_COMPLETION_QUEUE_TOO_FULL", "IORING completion queue too full."),
+    (0xC0500003, "STATUS_VOLSNAP_BOOTFILE_NOT_VALID", "Volsnap bootfile not valid."),
+    (0xC0500004, "STATUS_VOLSNAP_ACTIVATION_TIMEOUT", "Volsnap activation timeout."),
+    (0xC0500005, "STATUS_VOLSNAP_NO_BYPASSIO_WITH_SNAPSHOT", "Volsnap no bypassio with snapshot."),
+    (0xC0510001, "STATUS_IO_PREEMPTED", "IO preempted."),
+    (0xC05C0000, "STATUS_SVHDX_ERROR_STORED", "SVHDX error stored."),
+    (0xC05CFF00, "STATUS_SVHDX_ERROR_NOT_AVAILABLE", "SVHDX error not available."),
+    (0xC05CFF01, "STATUS_SVHDX_UNIT_ATTENTION_AVAILABLE", "SVHDX unit attention available."),
+    (0xC05CFF02, "STATUS_SVHDX_UNIT_ATTENTION_CAPACITY_DATA_CHANGED", "SVHDX unit attention capacity data changed."),
+    (0xC05CFF03, "STATUS_SVHDX_UNIT_ATTENTION_RESERVATIONS_PREEMPTED", "SVHDX unit attention reservations preempted."),
+    (0xC05CFF04, "STATUS_SVHDX_UNIT_ATTENTION_RESERVATIONS_RELEASED", "SVHDX unit attention reservations released."),
+    (0xC05CFF05, "STATUS_SVHDX_UNIT_ATTENTION_REGISTRATIONS_PREEMPTED", "SVHDX unit attention registrations preempted."),
+    (0xC05CFF06, "STATUS_SVHDX_UNIT_ATTENTION_OPERATING_DEFINITION_CHANGED", "SVHDX unit attention operating definition changed."),
+    (0xC05CFF07, "STATUS_SVHDX_RESERVATION_CONFLICT", "SVHDX reservation conflict."),
+    (0xC05CFF08, "STATUS_SVHDX_WRONG_FILE_TYPE", "SVHDX wrong file type."),
+    (0xC05CFF09, "STATUS_SVHDX_VERSION_MISMATCH", "SVHDX version mismatch."),
+    (0xC05CFF0A, "STATUS_VHD_SHARED", "VHD shared."),
+    (0xC05CFF0B, "STATUS_SVHDX_NO_INITIATOR", "SVHDX no initiator."),
+    (0xC05CFF0C, "STATUS_VHDSET_BACKING_STORAGE_NOT_FOUND", "Vhdset backing storage not found."),
+    (0xC05D0000, "STATUS_SMB_NO_PREAUTH_INTEGRITY_HASH_OVERLAP", "SMB no preauth integrity hash overlap."),
+    (0xC05D0001, "STATUS_SMB_BAD_CLUSTER_DIALECT", "SMB bad cluster dialect."),
+    (0xC05D0002, "STATUS_SMB_GUEST_LOGON_BLOCKED", "SMB guest logon blocked."),
+    (0xC05D0003, "STATUS_SMB_NO_SIGNING_ALGORITHM_OVERLAP", "SMB no signing algorithm overlap."),
+    (0xC05D0004, "STATUS_NETWORK_AUTHENTICATION_PROMPT_CANCELED", "Network authentication prompt canceled."),
+    (0xC0E70001, "STATUS_SPACES_FAULT_DOMAIN_TYPE_INVALID", "Spaces fault domain type invalid."),
+    (0xC0E70003, "STATUS_SPACES_RESILIENCY_TYPE_INVALID", "Spaces resiliency type invalid."),
+    (0xC0E70004, "STATUS_SPACES_DRIVE_SECTOR_SIZE_INVALID", "Spaces drive sector size invalid."),
+    (0xC0E70006, "STATUS_SPACES_DRIVE_REDUNDANCY_INVALID", "Spaces drive redundancy invalid."),
+    (0xC0E70007, "STATUS_SPACES_NUMBER_OF_DATA_COPIES_INVALID", "Spaces number of data copies invalid."),
+    (0xC0E70009, "STATUS_SPACES_INTERLEAVE_LENGTH_INVALID", "Spaces interleave length invalid."),
+    (0xC0E7000A, "STATUS_SPACES_NUMBER_OF_COLUMNS_INVALID", "Spaces number of columns invalid."),
+    (0xC0E7000B, "STATUS_SPACES_NOT_ENOUGH_DRIVES", "Spaces not enough drives."),
+    (0xC0E7000C, "STATUS_SPACES_EXTENDED_ERROR", "Spaces extended error."),
+    (0xC0E7000D, "STATUS_SPACES_PROVISIONING_TYPE_INVALID", "Spaces provisioning type invalid."),
+    (0xC0E7000E, "STATUS_SPACES_ALLOCATION_SIZE_INVALID", "Spaces allocation size invalid."),
+    (0xC0E7000F, "STATUS_SPACES_ENCLOSURE_AWARE_INVALID", "Spaces enclosure aware invalid."),
+    (0xC0E70010, "STATUS_SPACES_WRITE_CACHE_SIZE_INVALID", "Spaces write cache size invalid."),
+    (0xC0E70011, "STATUS_SPACES_NUMBER_OF_GROUPS_INVALID", "Spaces number of groups invalid."),
+    (0xC0E70012, "STATUS_SPACES_DRIVE_OPERATIONAL_STATE_INVALID", "Spaces drive operational state invalid."),
+    (0xC0E70013, "STATUS_SPACES_UPDATE_COLUMN_STATE", "Spaces update column state."),
+    (0xC0E70014, "STATUS_SPACES_MAP_REQUIRED", "Spaces map required."),
+    (0xC0E70015, "STATUS_SPACES_UNSUPPORTED_VERSION", "Spaces unsupported version."),
+    (0xC0E70016, "STATUS_SPACES_CORRUPT_METADATA", "Spaces corrupt metadata."),
+    (0xC0E70017, "STATUS_SPACES_DRT_FULL", "Spaces DRT full."),
+    (0xC0E70018, "STATUS_SPACES_INCONSISTENCY", "Spaces inconsistency."),
+    (0xC0E70019, "STATUS_SPACES_LOG_NOT_READY", "Spaces log not ready."),
+    (0xC0E7001A, "STATUS_SPACES_NO_REDUNDANCY", "Spaces no redundancy."),
+    (0xC0E7001B, "STATUS_SPACES_DRIVE_NOT_READY", "Spaces drive not ready."),
+    (0xC0E7001C, "STATUS_SPACES_DRIVE_SPLIT", "Spaces drive split."),
+    (0xC0E7001D, "STATUS_SPACES_DRIVE_LOST_DATA", "Spaces drive lost data."),
+    (0xC0E7001E, "STATUS_SPACES_ENTRY_INCOMPLETE", "Spaces entry incomplete."),
+    (0xC0E7001F, "STATUS_SPACES_ENTRY_INVALID", "Spaces entry invalid."),
+    (0xC0E70020, "STATUS_SPACES_MARK_DIRTY", "Spaces mark dirty."),
+    (0xC0E70021, "STATUS_SPACES_PD_NOT_FOUND", "Spaces PD not found."),
+    (0xC0E70022, "STATUS_SPACES_PD_LENGTH_MISMATCH", "Spaces PD length mismatch."),
+    (0xC0E70023, "STATUS_SPACES_PD_UNSUPPORTED_VERSION", "Spaces PD unsupported version."),
+    (0xC0E70024, "STATUS_SPACES_PD_INVALID_DATA", "Spaces PD invalid data."),
+    (0xC0E70025, "STATUS_SPACES_FLUSH_METADATA", "Spaces flush metadata."),
+    (0xC0E70026, "STATUS_SPACES_CACHE_FULL", "Spaces cache full."),
+    (0xC0E70027, "STATUS_SPACES_REPAIR_IN_PROGRESS", "Spaces repair in progress."),
+    (0xC0E80000, "STATUS_SECCORE_INVALID_COMMAND", "Seccore invalid command."),
+    (0xC0E90001, "STATUS_SYSTEM_INTEGRITY_ROLLBACK_DETECTED", "System integrity rollback detected."),
+    (0xC0E90002, "STATUS_SYSTEM_INTEGRITY_POLICY_VIOLATION", "System integrity policy violation."),
+    (0xC0E90003, "STATUS_SYSTEM_INTEGRITY_INVALID_POLICY", "System integrity invalid policy."),
+    (0xC0E90004, "STATUS_SYSTEM_INTEGRITY_POLICY_NOT_SIGNED", "System integrity policy not signed."),
+    (0xC0E90005, "STATUS_SYSTEM_INTEGRITY_TOO_MANY_POLICIES", "System integrity too many policies."),
+    (0xC0E90006, "STATUS_SYSTEM_INTEGRITY_SUPPLEMENTAL_POLICY_NOT_AUTHORIZED", "System integrity supplemental policy not authorized."),
+    (0xC0E90007, "STATUS_SYSTEM_INTEGRITY_REPUTATION_MALICIOUS", "System integrity reputation malicious."),
+    (0xC0E90008, "STATUS_SYSTEM_INTEGRITY_REPUTATION_PUA", "System integrity reputation pua."),
+    (0xC0E90009, "STATUS_SYSTEM_INTEGRITY_REPUTATION_DANGEROUS_EXT", "System integrity reputation dangerous ext."),
+    (0xC0E9000A, "STATUS_SYSTEM_INTEGRITY_REPUTATION_OFFLINE", "System integrity reputation offline."),
+    (0xC0E9000B, "STATUS_SYSTEM_INTEGRITY_REPUTATION_UNFRIENDLY_FILE", "System integrity reputation unfriendly file."),
+    (0xC0E9000C, "STATUS_SYSTEM_INTEGRITY_REPUTATION_UNATTAINABLE", "System integrity reputation unattainable."),
+    (0xC0E9000D, "STATUS_SYSTEM_INTEGRITY_REPUTATION_EXPLICIT_DENY_FILE", "System integrity reputation explicit deny file."),
+    (0xC0EA0001, "STATUS_NO_APPLICABLE_APP_LICENSES_FOUND", "No applicable app licenses found."),
+    (0xC0EA0002, "STATUS_CLIP_LICENSE_NOT_FOUND", "Clip license not found."),
+    (0xC0EA0003, "STATUS_CLIP_DEVICE_LICENSE_MISSING", "Clip device license missing."),
+    (0xC0EA0004, "STATUS_CLIP_LICENSE_INVALID_SIGNATURE", "Clip license invalid signature."),
+    (0xC0EA0005, "STATUS_CLIP_KEYHOLDER_LICENSE_MISSING_OR_INVALID", "Clip keyholder license missing or invalid."),
+    (0xC0EA0006, "STATUS_CLIP_LICENSE_EXPIRED", "Clip license expired."),
+    (0xC0EA0007, "STATUS_CLIP_LICENSE_SIGNED_BY_UNKNOWN_SOURCE", "Clip license signed by unknown source."),
+    (0xC0EA0008, "STATUS_CLIP_LICENSE_NOT_SIGNED", "Clip license not signed."),
+    (0xC0EA0009, "STATUS_CLIP_LICENSE_HARDWARE_ID_OUT_OF_TOLERANCE", "Clip license hardware ID out of tolerance."),
+    (0xC0EA000A, "STATUS_CLIP_LICENSE_DEVICE_ID_MISMATCH", "Clip license device ID mismatch."),
+    (0xC0EB0001, "STATUS_PLATFORM_MANIFEST_NOT_AUTHORIZED", "Platform manifest not authorized."),
+    (0xC0EB0002, "STATUS_PLATFORM_MANIFEST_INVALID", "Platform manifest invalid."),
+    (0xC0EB0003, "STATUS_PLATFORM_MANIFEST_FILE_NOT_AUTHORIZED", "Platform manifest file not authorized."),
+    (0xC0EB0004, "STATUS_PLATFORM_MANIFEST_CATALOG_NOT_AUTHORIZED", "Platform manifest catalog not authorized."),
+    (0xC0EB0005, "STATUS_PLATFORM_MANIFEST_BINARY_ID_NOT_FOUND", "Platform manifest binary ID not found."),
+    (0xC0EB0006, "STATUS_PLATFORM_MANIFEST_NOT_ACTIVE", "Platform manifest not active."),
+    (0xC0EB0007, "STATUS_PLATFORM_MANIFEST_NOT_SIGNED", "Platform manifest not signed."),
+    (0xC0EC0000, "STATUS_APPEXEC_CONDITION_NOT_SATISFIED", "APPEXEC condition not satisfied."),
+    (0xC0EC0001, "STATUS_APPEXEC_HANDLE_INVALIDATED", "APPEXEC handle invalidated."),
+    (0xC0EC0002, "STATUS_APPEXEC_INVALID_HOST_GENERATION", "APPEXEC invalid host generation."),
+    (0xC0EC0003, "STATUS_APPEXEC_UNEXPECTED_PROCESS_REGISTRATION", "APPEXEC unexpected process registration."),
+    (0xC0EC0004, "STATUS_APPEXEC_INVALID_HOST_STATE", "APPEXEC invalid host state."),
+    (0xC0EC0005, "STATUS_APPEXEC_NO_DONOR", "APPEXEC no donor."),
+    (0xC0EC0006, "STATUS_APPEXEC_HOST_ID_MISMATCH", "APPEXEC host ID mismatch."),
+    (0xC0EC0007, "STATUS_APPEXEC_UNKNOWN_USER", "APPEXEC unknown user."),
+    (0xC0EC0008, "STATUS_APPEXEC_APP_COMPAT_BLOCK", "APPEXEC app compat block."),
+    (0xC0EC0009, "STATUS_APPEXEC_CALLER_WAIT_TIMEOUT", "APPEXEC caller wait timeout."),
+    (0xC0EC000A, "STATUS_APPEXEC_CALLER_WAIT_TIMEOUT_TERMINATION", "APPEXEC caller wait timeout termination."),
+    (0xC0EC000B, "STATUS_APPEXEC_CALLER_WAIT_TIMEOUT_LICENSING", "APPEXEC caller wait timeout licensing."),
+    (0xC0EC000C, "STATUS_APPEXEC_CALLER_WAIT_TIMEOUT_RESOURCES", "APPEXEC caller wait timeout resources."),
+];
@@ -0,0 +1,153 @@
+use core::cell::UnsafeCell;
+use crate::*;
+use crate::irql::{self, BelowDispatch, Irql, DISPATCH_LEVEL};
+use crate::sync::InitAt;
+use crate::sync::wait::{self, Timeout, WaitResult, Waitable};
+
+
+/// A `KEVENT`. Notification events stay signaled until reset and release every waiter;
+/// synchronization events release one waiter and reset themselves.
+pub struct KEvent {
+    init: InitAt,
+    kind: EVENT_TYPE,
+    initial: bool,
+    event: UnsafeCell<KEVENT>,
+}
+
+unsafe impl Send for KEvent {}
+unsafe impl Sync for KEvent {}
+
+impl KEvent {
+    pub const fn notification(signaled: bool) -> Self {
+        KEvent::new(_EVENT_TYPE_NotificationEvent, signaled)
+    }
+
+    pub const fn synchronization(signaled: bool) -> Self {
+        KEvent::new(_EVENT_TYPE_SynchronizationEvent, signaled)
+    }
+
+    const fn new(kind: EVENT_TYPE, initial: bool) -> Self {
+        KEvent { init: InitAt::new(), kind, initial, event: UnsafeCell::new(unsafe { core::mem::zeroed() }) }
+    }
+
+    /// The initialized `PRKEVENT`, for APIs that signal an event themselves.
+    pub fn as_ptr(&self) -> PRKEVENT {
+        let event = self.event.get();
+        self.init.ensure(event as *const (), |first| unsafe {
+            let state = (*event).Header.SignalState;
+            KeInitializeEvent(event, self.kind, self.initial as _);
+            if !first {
+                (*event).Header.SignalState = state;
+            }
+        });
+        event
+    }
+
+    /// `KeSetEvent`; returns whether the event was already signaled. IRQL <= DISPATCH_LEVEL.
+    pub fn set(&self) -> bool {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
+        unsafe { KeSetEvent(self.as_ptr(), IO_NO_INCREMENT as _, FALSE as _) != 0 }
+    }
+
+    /// `KeResetEvent`; returns whether the event was signaled. IRQL <= DISPATCH_LEVEL.
+    pub fn reset(&self) -> bool {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
+        unsafe { KeResetEvent(self.as_ptr()) != 0 }
+    }
+
+    /// `KeReadStateEvent`.
+    pub fn is_set(&self) -> bool {
+        unsafe { KeReadStateEvent(self.as_ptr()) != 0 }
+    }
+
+    pub fn wait(&self, timeout: impl Into<Timeout>, _irql: &impl BelowDispatch) -> Result<WaitResult, NtStatus> {
+        wait::wait_single(self.as_ptr() as _, timeout.into(), false)
+    }
+
+    pub fn wait_alertable(&self, timeout: impl Into<Timeout>, _irql: &impl BelowDispatch) -> Result<WaitResult, NtStatus> {
+        wait::wait_single(self.as_ptr() as _, timeout.into(), true)
+    }
+
+    /// Zero-timeout wait, allowed at DISPATCH_LEVEL. Consumes the signal of a synchronization event.
+    pub fn try_wait(&self, _irql: &impl Irql) -> bool {
+        matches!(wait::wait_single(self.as_ptr() as _, Timeout::ZERO, false), Ok(WaitResult::Signaled(_)))
+    }
+}
+
+unsafe impl Waitable for KEvent {
+    fn dispatcher_object(&self) -> PVOID {
+        self.as_ptr() as _
+    }
+}
+
+
+/// A `KSEMAPHORE` counting up to `limit`.
+pub struct KSemaphore {
+    init: InitAt,
+    count: i32,
+    limit: i32,
+    semaphore: UnsafeCell<KSEMAPHORE>,
+}
+
+unsafe impl Send for KSemaphore {}
+unsafe impl Sync for KSemaphore {}
+
+impl KSemaphore {
+    /// Panics unless `0 <= count <= limit` and `limit > 0`, at compile time for a `static`.
+    pub const fn new(count: i32, limit: i32) -> Self {
+        assert!(limit > 0 && count >= 0 && count <= limit, "KSemaphore needs 0 <= count <= limit and limit > 0");
+        KSemaphore { init: InitAt::new(), count, limit, semaphore: UnsafeCell::new(unsafe { core::mem::zeroed() }) }
+    }
+
+    pub fn as_ptr(&self) -> PRKSEMAPHORE {
+        let semaphore = self.semaphore.get();
+        self.init.ensure(semaphore as *const (), |first| unsafe {
+            let count = if first { self.count } else { (*semaphore).Header.SignalState };
+            KeInitializeSemaphore(semaphore, count, self.limit);
+        });
+        semaphore
+    }
+
+    /// `KeReleaseSemaphore`, adding `n` to the count and returning the previous one.
+    /// `STATUS_SEMAPHORE_LIMIT_EXCEEDED` instead of the exception the kernel raises past the limit.
+    pub fn release(&self, n: i32) -> Result<i32, NtStatus> {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
+        if n <= 0 {
+            return Err(NtStatus::INVALID_PARAMETER);
+        }
+        let sem = self.as_ptr();
+        // racy by nature, but releasing past the limit is a caller bug either way
+        if self.count().saturating_add(n) > self.limit {
+            return Err(NtStatus::SEMAPHORE_LIMIT_EXCEEDED);
+        }
+        Ok(unsafe { KeReleaseSemaphore(sem, IO_NO_INCREMENT as _, n, FALSE as _) })
+    }
+
+    /// `KeReadStateSemaphore`.
+    pub fn count(&self) -> i32 {
+        unsafe { KeReadStateSemaphore(self.as_ptr()) }
+    }
+
+    pub fn limit(&self) -> i32 {
+        self.limit
+    }
+
+    /// Waits for the count to be non-zero and decrements it.
+    pub fn wait(&self, timeout: impl Into<Timeout>, _irql: &impl BelowDispatch) -> Result<WaitResult, NtStatus> {
+        wait::wait_single(self.as_ptr() as _, timeout.into(), false)
+    }
+
+    pub fn wait_alertable(&self, timeout: impl Into<Timeout>, _irql: &impl BelowDispatch) -> Result<WaitResult, NtStatus> {
+        wait::wait_single(self.as_ptr() as _, timeout.into(), true)
+    }
+
+    pub fn try_wait(&self, _irql: &impl Irql) -> bool {
+        matches!(wait::wait_single(self.as_ptr() as _, Timeout::ZERO, false), Ok(WaitResult::Signaled(_)))
+    }
+}
+
+unsafe impl Waitable for KSemaphore {
+    fn dispatcher_object(&self) -> PVOID {
+        self.as_ptr() as _
+    }
+}
@@ -0,0 +1,54 @@
+use core::hint;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+pub mod spin;
+pub mod mutex;
+pub mod push_lock;
+pub mod resource;
+pub mod wait;
+pub mod event;
+pub mod timer;
+
+pub use spin::{KSpinLock, KSpinLockGuard, LockQueueHandle, QueuedSpinLock, QueuedSpinLockGuard};
+pub use mutex::{GuardedMutex, GuardedMutexGuard};
+pub use push_lock::{PushLock, PushLockExclusiveGuard, PushLockSharedGuard};
+pub use resource::{Resource, ResourceExclusiveGuard, ResourceSharedGuard};
+pub use wait::{sleep, sleep_alertable, wait_all, wait_any, Timeout, WaitResult, Waitable};
+pub use event::{KEvent, KSemaphore};
+pub use timer::KTimer;
+
+
+const BUSY: usize = 1;
+
+/// Runs an initializer once per address, so objects with self-referencing kernel state (wait list
+/// heads) can be built with a `const fn` and set up on first use. Moving the owner needs `&mut`
+/// or ownership, which means nobody holds it, so it is simply initialized again at the new address;
+/// the initializer is told whether it runs for the first time so it can carry state over.
+pub(crate) struct InitAt {
+    at: AtomicUsize,
+}
+
+impl InitAt {
+    pub(crate) const fn new() -> Self {
+        InitAt { at: AtomicUsize::new(0) }
+    }
+
+    pub(crate) fn ensure(&self, addr: *const (), init: impl FnOnce(bool)) {
+        let addr = addr as usize;
+        loop {
+            let cur = self.at.load(Ordering::Acquire);
+            if cur == addr {
+                return;
+            }
+            if cur == BUSY {
+                hint::spin_loop();
+                continue;
+            }
+            if self.at.compare_exchange(cur, BUSY, Ordering::Acquire, Ordering::Acquire).is_ok() {
+                init(cur == 0);
+                self.at.store(addr, Ordering::Release);
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use crate::*;
+use crate::irql::{self, BelowDispatch, APC_LEVEL};
+use crate::sync::InitAt;
+
+
+/// Data protected by a `KGUARDED_MUTEX`. The owner runs inside a guarded region (all APCs
+/// disabled) until the guard drops. Usable at IRQL <= APC_LEVEL.
+pub struct GuardedMutex<T: ?Sized> {
+    init: InitAt,
+    mutex: UnsafeCell<KGUARDED_MUTEX>,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for GuardedMutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for GuardedMutex<T> {}
+
+impl<T> GuardedMutex<T> {
+    /// The kernel object is initialized with `KeInitializeGuardedMutex` on first lock.
+    pub const fn new(value: T) -> Self {
+        GuardedMutex { init: InitAt::new(), mutex: UnsafeCell::new(unsafe { core::mem::zeroed() }), data: UnsafeCell::new(value) }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> GuardedMutex<T> {
+    fn raw(&self) -> PKGUARDED_MUTEX {
+        let mutex = self.mutex.get();
+        self.init.ensure(mutex as *const (), |_| unsafe { KeInitializeGuardedMutex(mutex) });
+        mutex
+    }
+
+    /// `KeAcquireGuardedMutex`; waits until the mutex is free.
+    pub fn lock(&self, _irql: &impl BelowDispatch) -> GuardedMutexGuard<'_, T> {
+        irql::debug_assert_at_most(APC_LEVEL);
+        unsafe { KeAcquireGuardedMutex(self.raw()) };
+        GuardedMutexGuard { lock: self, _not_send: PhantomData }
+    }
+
+    /// `KeTryToAcquireGuardedMutex`.
+    pub fn try_lock(&self, _irql: &impl BelowDispatch) -> Option<GuardedMutexGuard<'_, T>> {
+        irql::debug_assert_at_most(APC_LEVEL);
+        if unsafe { KeTryToAcquireGuardedMutex(self.raw()) } != 0 {
+            Some(GuardedMutexGuard { lock: self, _not_send: PhantomData })
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T: Default> Default for GuardedMutex<T> {
+    fn default() -> Self {
+        GuardedMutex::new(T::default())
+    }
+}
+
+
+pub struct GuardedMutexGuard<'a, T: ?Sized> {
+    lock: &'a GuardedMutex<T>,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl<T: ?Sized> Deref for GuardedMutexGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for GuardedMutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for GuardedMutexGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe { KeReleaseGuardedMutex(self.lock.mutex.get()) };
+    }
+}
@@ -0,0 +1,109 @@
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use crate::*;
+use crate::irql::{self, BelowDispatch, APC_LEVEL};
+
+
+/// Reader/writer lock over an `EX_PUSH_LOCK`. Holders run inside a critical region (normal
+/// kernel APCs disabled) until the guard drops. Usable at IRQL <= APC_LEVEL.
+pub struct PushLock<T: ?Sized> {
+    // a zeroed push lock is an initialized, free one
+    lock: UnsafeCell<ULONG_PTR>,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for PushLock<T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for PushLock<T> {}
+
+impl<T> PushLock<T> {
+    pub const fn new(value: T) -> Self {
+        PushLock { lock: UnsafeCell::new(0), data: UnsafeCell::new(value) }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> PushLock<T> {
+    /// `ExAcquirePushLockExclusiveEx` inside `KeEnterCriticalRegion`.
+    pub fn write(&self, _irql: &impl BelowDispatch) -> PushLockExclusiveGuard<'_, T> {
+        irql::debug_assert_at_most(APC_LEVEL);
+        unsafe {
+            KeEnterCriticalRegion();
+            ExAcquirePushLockExclusiveEx(self.lock.get(), 0);
+        }
+        PushLockExclusiveGuard { lock: self, _not_send: PhantomData }
+    }
+
+    /// `ExAcquirePushLockSharedEx` inside `KeEnterCriticalRegion`.
+    pub fn read(&self, _irql: &impl BelowDispatch) -> PushLockSharedGuard<'_, T> {
+        irql::debug_assert_at_most(APC_LEVEL);
+        unsafe {
+            KeEnterCriticalRegion();
+            ExAcquirePushLockSharedEx(self.lock.get(), 0);
+        }
+        PushLockSharedGuard { lock: self, _not_send: PhantomData }
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T: Default> Default for PushLock<T> {
+    fn default() -> Self {
+        PushLock::new(T::default())
+    }
+}
+
+
+pub struct PushLockExclusiveGuard<'a, T: ?Sized> {
+    lock: &'a PushLock<T>,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl<T: ?Sized> Deref for PushLockExclusiveGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for PushLockExclusiveGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for PushLockExclusiveGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ExReleasePushLockExclusiveEx(self.lock.lock.get(), 0);
+            KeLeaveCriticalRegion();
+        }
+    }
+}
+
+
+pub struct PushLockSharedGuard<'a, T: ?Sized> {
+    lock: &'a PushLock<T>,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl<T: ?Sized> Deref for PushLockSharedGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for PushLockSharedGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ExReleasePushLockSharedEx(self.lock.lock.get(), 0);
+            KeLeaveCriticalRegion();
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use alloc::boxed::Box;
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use crate::*;
+use crate::irql::{self, BelowDispatch, APC_LEVEL};
+
+
+/// Reader/writer lock over an `ERESOURCE`. Holders run inside a critical region until the guard
+/// drops. Usable at IRQL <= APC_LEVEL.
+///
+/// The kernel lets the owning thread acquire an `ERESOURCE` again, which would hand out a second
+/// guard aliasing the first. So `write` while this thread holds the resource in any mode, or `read`
+/// while it holds it exclusively, panics, and the `try_` variants return `None`. Nested `read`s are
+/// allowed.
+///
+/// The kernel keeps every `ERESOURCE` in a global list by address, so the object is allocated
+/// from non-paged pool on first use rather than stored inline. A `Resource` in a `static` is never
+/// dropped: call [`Resource::delete`] from the unload routine.
+pub struct Resource<T: ?Sized> {
+    resource: AtomicPtr<ERESOURCE>,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for Resource<T> {}
+unsafe impl<T: ?Sized + Send + Sync> Sync for Resource<T> {}
+
+impl<T> Resource<T> {
+    pub const fn new(value: T) -> Self {
+        Resource { resource: AtomicPtr::new(ptr::null_mut()), data: UnsafeCell::new(value) }
+    }
+
+    pub fn into_inner(self) -> T {
+        let this = core::mem::ManuallyDrop::new(self);
+        unsafe {
+            this.delete();
+            ptr::read(&this.data).into_inner()
+        }
+    }
+}
+
+impl<T: ?Sized> Resource<T> {
+    fn raw(&self) -> PERESOURCE {
+        let cur = self.resource.load(Ordering::Acquire);
+        if !cur.is_null() {
+            return cur;
+        }
+
+        let fresh = Box::into_raw(Box::new(unsafe { core::mem::zeroed::<ERESOURCE>() }));
+        unsafe { ExInitializeResourceLite(fresh) };
+        match self.resource.compare_exchange(ptr::null_mut(), fresh, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => fresh,
+            Err(winner) => {
+                unsafe {
+                    ExDeleteResourceLite(fresh);
+                    drop(Box::from_raw(fresh));
+                }
+                winner
+            }
+        }
+    }
+
+    // owner tracking of the `ERESOURCE` itself: acquisitions by the current thread in either mode
+    fn held_by_current_thread(&self) -> bool {
+        unsafe { ExIsResourceAcquiredSharedLite(self.raw()) != 0 }
+    }
+
+    fn held_exclusive_by_current_thread(&self) -> bool {
+        unsafe { ExIsResourceAcquiredExclusiveLite(self.raw()) != 0 }
+    }
+
+    /// `ExAcquireResourceExclusiveLite` inside `KeEnterCriticalRegion`, waiting if needed. Panics
+    /// if the current thread already holds the resource.
+    #[track_caller]
+    pub fn write(&self, _irql: &impl BelowDispatch) -> ResourceExclusiveGuard<'_, T> {
+        irql::debug_assert_at_most(APC_LEVEL);
+        assert!(!self.held_by_current_thread(), "Resource::write while the current thread holds the resource");
+        unsafe {
+            KeEnterCriticalRegion();
+            ExAcquireResourceExclusiveLite(self.raw(), TRUE as _);
+        }
+        ResourceExclusiveGuard { lock: self, _not_send: PhantomData }
+    }
+
+    pub fn try_write(&self, _irql: &impl BelowDispatch) -> Option<ResourceExclusiveGuard<'_, T>> {
+        irql::debug_assert_at_most(APC_LEVEL);
+        if self.held_by_current_thread() {
+            return None;
+        }
+        unsafe {
+            KeEnterCriticalRegion();
+            if ExAcquireResourceExclusiveLite(self.raw(), FALSE as _) == 0 {
+                KeLeaveCriticalRegion();
+                return None;
+            }
+        }
+        Some(ResourceExclusiveGuard { lock: self, _not_send: PhantomData })
+    }
+
+    /// `ExAcquireResourceSharedLite` inside `KeEnterCriticalRegion`, waiting if needed. Panics if
+    /// the current thread holds the resource exclusively.
+    #[track_caller]
+    pub fn read(&self, _irql: &impl BelowDispatch) -> ResourceSharedGuard<'_, T> {
+        irql::debug_assert_at_most(APC_LEVEL);
+        assert!(!self.held_exclusive_by_current_thread(), "Resource::read while the current thread holds the resource exclusively");
+        unsafe {
+            KeEnterCriticalRegion();
+            ExAcquireResourceSharedLite(self.raw(), TRUE as _);
+        }
+        ResourceSharedGuard { lock: self, _not_send: PhantomData }
+    }
+
+    pub fn try_read(&self, _irql: &impl BelowDispatch) -> Option<ResourceSharedGuard<'_, T>> {
+        irql::debug_assert_at_most(APC_LEVEL);
+        if self.held_exclusive_by_current_thread() {
+            return None;
+        }
+        unsafe {
+            KeEnterCriticalRegion();
+            if ExAcquireResourceSharedLite(self.raw(), FALSE as _) == 0 {
+                KeLeaveCriticalRegion();
+                return None;
+            }
+        }
+        Some(ResourceSharedGuard { lock: self, _not_send: PhantomData })
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+
+    /// `ExDeleteResourceLite` and frees the kernel object; the next lock allocates a new one.
+    ///
+    /// # Safety
+    ///
+    /// No guard may be alive and no other thread may use the resource concurrently.
+    pub unsafe fn delete(&self) {
+        let res = self.resource.swap(ptr::null_mut(), Ordering::AcqRel);
+        if !res.is_null() {
+            unsafe {
+                ExDeleteResourceLite(res);
+                drop(Box::from_raw(res));
+            }
+        }
+    }
+}
+
+impl<T: ?Sized> Drop for Resource<T> {
+    fn drop(&mut self) {
+        unsafe { self.delete() };
+    }
+}
+
+impl<T: Default> Default for Resource<T> {
+    fn default() -> Self {
+        Resource::new(T::default())
+    }
+}
+
+
+fn release(res: &AtomicPtr<ERESOURCE>) {
+    unsafe {
+        ExReleaseResourceLite(res.load(Ordering::Acquire));
+        KeLeaveCriticalRegion();
+    }
+}
+
+pub struct ResourceExclusiveGuard<'a, T: ?Sized> {
+    lock: &'a Resource<T>,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl<T: ?Sized> Deref for ResourceExclusiveGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for ResourceExclusiveGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for ResourceExclusiveGuard<'_, T> {
+    fn drop(&mut self) {
+        release(&self.lock.resource);
+    }
+}
+
+
+pub struct ResourceSharedGuard<'a, T: ?Sized> {
+    lock: &'a Resource<T>,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl<T: ?Sized> Deref for ResourceSharedGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for ResourceSharedGuard<'_, T> {
+    fn drop(&mut self) {
+        release(&self.lock.resource);
+    }
+}
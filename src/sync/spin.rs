@@ -0,0 +1,212 @@
+use core::cell::UnsafeCell;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use crate::*;
+use crate::irql::{self, AtDispatch, Dispatch, Irql, DISPATCH_LEVEL};
+
+
+/// Data protected by a `KSPIN_LOCK`. Locking raises to DISPATCH_LEVEL until the guard drops.
+pub struct KSpinLock<T: ?Sized> {
+    lock: UnsafeCell<KSPIN_LOCK>,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for KSpinLock<T> {}
+unsafe impl<T: ?Sized + Send> Sync for KSpinLock<T> {}
+
+impl<T> KSpinLock<T> {
+    pub const fn new(value: T) -> Self {
+        KSpinLock { lock: UnsafeCell::new(0), data: UnsafeCell::new(value) }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> KSpinLock<T> {
+    /// `KeAcquireSpinLockRaiseToDpc`. The token stays borrowed while the lock is held, since the
+    /// IRQL is then above what it proves.
+    pub fn lock<'a>(&'a self, _irql: &'a mut impl Irql) -> KSpinLockGuard<'a, T> {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
+        let old_irql = unsafe { KeAcquireSpinLockRaiseToDpc(self.lock.get()) };
+        KSpinLockGuard { lock: self, old_irql: Some(old_irql), _not_send: PhantomData }
+    }
+
+    /// `KeAcquireSpinLockAtDpcLevel`, skipping the IRQL change.
+    ///
+    /// # Safety
+    ///
+    /// The caller must already run at exactly DISPATCH_LEVEL, e.g. in a DPC routine.
+    pub unsafe fn lock_at_dpc_level(&self) -> KSpinLockGuard<'_, T> {
+        debug_assert_eq!(irql::current(), DISPATCH_LEVEL);
+        unsafe { KeAcquireSpinLockAtDpcLevel(self.lock.get()) };
+        KSpinLockGuard { lock: self, old_irql: None, _not_send: PhantomData }
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T: Default> Default for KSpinLock<T> {
+    fn default() -> Self {
+        KSpinLock::new(T::default())
+    }
+}
+
+
+pub struct KSpinLockGuard<'a, T: ?Sized> {
+    lock: &'a KSpinLock<T>,
+    // `None` when acquired with `lock_at_dpc_level`
+    old_irql: Option<KIRQL>,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl<T: ?Sized> KSpinLockGuard<'_, T> {
+    /// The guard holds the CPU at DISPATCH_LEVEL.
+    pub fn irql(&self) -> Dispatch {
+        unsafe { Dispatch::assume() }
+    }
+}
+
+impl<T: ?Sized> irql::sealed::Sealed for KSpinLockGuard<'_, T> {}
+impl<T: ?Sized> AtDispatch for KSpinLockGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for KSpinLockGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for KSpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for KSpinLockGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            match self.old_irql {
+                Some(old) => KeReleaseSpinLock(self.lock.lock.get(), old),
+                None => KeReleaseSpinLockFromDpcLevel(self.lock.lock.get()),
+            }
+        }
+    }
+}
+
+
+/// Stack storage for a queued spin lock acquisition. It is linked into the lock's waiter queue,
+/// so the guard borrows it mutably to keep it in place until release.
+pub struct LockQueueHandle(KLOCK_QUEUE_HANDLE);
+
+impl LockQueueHandle {
+    pub const fn new() -> Self {
+        LockQueueHandle(unsafe { core::mem::zeroed() })
+    }
+}
+
+impl Default for LockQueueHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+/// Data protected by an in-stack queued spin lock, which is fair and scales better under contention.
+///
+/// ```ignore
+/// let mut handle = LockQueueHandle::new();
+/// let mut list = LIST.lock(&mut handle, &mut irql);
+/// ```
+pub struct QueuedSpinLock<T: ?Sized> {
+    lock: UnsafeCell<KSPIN_LOCK>,
+    data: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for QueuedSpinLock<T> {}
+unsafe impl<T: ?Sized + Send> Sync for QueuedSpinLock<T> {}
+
+impl<T> QueuedSpinLock<T> {
+    pub const fn new(value: T) -> Self {
+        QueuedSpinLock { lock: UnsafeCell::new(0), data: UnsafeCell::new(value) }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.data.into_inner()
+    }
+}
+
+impl<T: ?Sized> QueuedSpinLock<T> {
+    /// `KeAcquireInStackQueuedSpinLock`.
+    pub fn lock<'a>(&'a self, handle: &'a mut LockQueueHandle, _irql: &'a mut impl Irql) -> QueuedSpinLockGuard<'a, T> {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
+        unsafe { KeAcquireInStackQueuedSpinLock(self.lock.get(), &mut handle.0) };
+        QueuedSpinLockGuard { lock: self, handle, at_dpc_level: false, _not_send: PhantomData }
+    }
+
+    /// `KeAcquireInStackQueuedSpinLockAtDpcLevel`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must already run at exactly DISPATCH_LEVEL.
+    pub unsafe fn lock_at_dpc_level<'a>(&'a self, handle: &'a mut LockQueueHandle) -> QueuedSpinLockGuard<'a, T> {
+        debug_assert_eq!(irql::current(), DISPATCH_LEVEL);
+        unsafe { KeAcquireInStackQueuedSpinLockAtDpcLevel(self.lock.get(), &mut handle.0) };
+        QueuedSpinLockGuard { lock: self, handle, at_dpc_level: true, _not_send: PhantomData }
+    }
+
+    pub fn get_mut(&mut self) -> &mut T {
+        self.data.get_mut()
+    }
+}
+
+impl<T: Default> Default for QueuedSpinLock<T> {
+    fn default() -> Self {
+        QueuedSpinLock::new(T::default())
+    }
+}
+
+
+pub struct QueuedSpinLockGuard<'a, T: ?Sized> {
+    lock: &'a QueuedSpinLock<T>,
+    handle: &'a mut LockQueueHandle,
+    at_dpc_level: bool,
+    _not_send: PhantomData<*mut ()>,
+}
+
+impl<T: ?Sized> QueuedSpinLockGuard<'_, T> {
+    pub fn irql(&self) -> Dispatch {
+        unsafe { Dispatch::assume() }
+    }
+}
+
+impl<T: ?Sized> irql::sealed::Sealed for QueuedSpinLockGuard<'_, T> {}
+impl<T: ?Sized> AtDispatch for QueuedSpinLockGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for QueuedSpinLockGuard<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for QueuedSpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for QueuedSpinLockGuard<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            if self.at_dpc_level {
+                KeReleaseInStackQueuedSpinLockFromDpcLevel(&mut self.handle.0);
+            } else {
+                KeReleaseInStackQueuedSpinLock(&mut self.handle.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use alloc::boxed::Box;
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use core::time::Duration;
+use crate::*;
+use crate::irql::{self, BelowDispatch, Irql, DISPATCH_LEVEL};
+use crate::sync::wait::{self, Timeout, WaitResult, Waitable};
+
+
+/// A `KTIMER` without a DPC. Notification timers stay signaled until set again; synchronization
+/// timers release one waiter and reset themselves.
+///
+/// A set timer sits in the kernel timer table by address, so the object is allocated from
+/// non-paged pool on first use. Dropping cancels it; a `KTimer` in a `static` must be
+/// [`KTimer::delete`]d from the unload routine.
+pub struct KTimer {
+    kind: TIMER_TYPE,
+    timer: AtomicPtr<KTIMER>,
+}
+
+unsafe impl Send for KTimer {}
+unsafe impl Sync for KTimer {}
+
+impl KTimer {
+    pub const fn notification() -> Self {
+        KTimer { kind: _TIMER_TYPE_NotificationTimer, timer: AtomicPtr::new(ptr::null_mut()) }
+    }
+
+    pub const fn synchronization() -> Self {
+        KTimer { kind: _TIMER_TYPE_SynchronizationTimer, timer: AtomicPtr::new(ptr::null_mut()) }
+    }
+
+    fn raw(&self) -> PKTIMER {
+        let cur = self.timer.load(Ordering::Acquire);
+        if !cur.is_null() {
+            return cur;
+        }
+
+        let fresh = Box::into_raw(Box::new(unsafe { core::mem::zeroed::<KTIMER>() }));
+        unsafe { KeInitializeTimerEx(fresh, self.kind) };
+        match self.timer.compare_exchange(ptr::null_mut(), fresh, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => fresh,
+            Err(winner) => {
+                drop(unsafe { Box::from_raw(fresh) });
+                winner
+            }
+        }
+    }
+
+    /// `KeSetTimerEx`, signaling at `due` and then every `period` (millisecond granularity).
+    /// Returns whether the timer was already pending. IRQL <= DISPATCH_LEVEL.
+    pub fn set(&self, due: impl Into<Timeout>, period: Option<Duration>) -> Result<bool, NtStatus> {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
+        let Some(due) = due.into().to_due_time() else {
+            return Err(NtStatus::INVALID_PARAMETER);
+        };
+        let period = match period {
+            Some(p) => i32::try_from(p.as_millis()).map_err(|_| NtStatus::INVALID_PARAMETER)?,
+            None => 0,
+        };
+        Ok(unsafe { KeSetTimerEx(self.raw(), due, period, ptr::null_mut()) } != 0)
+    }
+
+    /// `KeCancelTimer`; returns whether the timer was pending. IRQL <= DISPATCH_LEVEL.
+    pub fn cancel(&self) -> bool {
+        irql::debug_assert_at_most(DISPATCH_LEVEL);
+        let timer = self.timer.load(Ordering::Acquire);
+        !timer.is_null() && unsafe { KeCancelTimer(timer) } != 0
+    }
+
+    /// `KeReadStateTimer`.
+    pub fn is_signaled(&self) -> bool {
+        unsafe { KeReadStateTimer(self.raw()) != 0 }
+    }
+
+    pub fn wait(&self, timeout: impl Into<Timeout>, _irql: &impl BelowDispatch) -> Result<WaitResult, NtStatus> {
+        wait::wait_single(self.raw() as _, timeout.into(), false)
+    }
+
+    pub fn wait_alertable(&self, timeout: impl Into<Timeout>, _irql: &impl BelowDispatch) -> Result<WaitResult, NtStatus> {
+        wait::wait_single(self.raw() as _, timeout.into(), true)
+    }
+
+    pub fn try_wait(&self, _irql: &impl Irql) -> bool {
+        matches!(wait::wait_single(self.raw() as _, Timeout::ZERO, false), Ok(WaitResult::Signaled(_)))
+    }
+
+    /// Cancels the timer and frees the kernel object; the next use allocates a new one.
+    ///
+    /// # Safety
+    ///
+    /// No other thread may use or wait on the timer concurrently.
+    pub unsafe fn delete(&self) {
+        let timer = self.timer.swap(ptr::null_mut(), Ordering::AcqRel);
+        if !timer.is_null() {
+            unsafe {
+                KeCancelTimer(timer);
+                drop(Box::from_raw(timer));
+            }
+        }
+    }
+}
+
+impl Drop for KTimer {
+    fn drop(&mut self) {
+        unsafe { self.delete() };
+    }
+}
+
+unsafe impl Waitable for KTimer {
+    fn dispatcher_object(&self) -> PVOID {
+        self.raw() as _
+    }
+}
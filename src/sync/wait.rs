@@ -0,0 +1,153 @@
+use alloc::vec::Vec;
+use core::ptr;
+use core::time::Duration;
+use crate::*;
+use crate::irql::{self, BelowDispatch, APC_LEVEL, DISPATCH_LEVEL};
+
+
+/// When a wait gives up. Kernel times are in 100ns units.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Timeout {
+    Infinite,
+    /// From now, unaffected by system time changes.
+    Relative(Duration),
+    /// System time since 1601-01-01 UTC, as returned by `KeQuerySystemTime`.
+    Absolute(Duration),
+}
+
+impl Timeout {
+    /// A zero timeout only polls, which is also allowed at DISPATCH_LEVEL.
+    pub const ZERO: Timeout = Timeout::Relative(Duration::ZERO);
+
+    /// The `LARGE_INTEGER` due time; `None` for an infinite wait.
+    pub fn to_due_time(self) -> Option<LARGE_INTEGER> {
+        let ticks = |d: Duration| i64::try_from(d.as_nanos() / 100).unwrap_or(i64::MAX);
+        match self {
+            Timeout::Infinite => None,
+            Timeout::Relative(d) => Some(LARGE_INTEGER { QuadPart: -ticks(d) }),
+            Timeout::Absolute(d) => Some(LARGE_INTEGER { QuadPart: ticks(d) }),
+        }
+    }
+
+    fn is_poll(self) -> bool {
+        self == Timeout::ZERO
+    }
+}
+
+impl From<Duration> for Timeout {
+    fn from(d: Duration) -> Self {
+        Timeout::Relative(d)
+    }
+}
+
+
+/// Why a wait returned.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WaitResult {
+    /// The object at this index was signaled (always 0 for single waits and `wait_all`).
+    Signaled(usize),
+    /// The mutant at this index was abandoned by its owner.
+    Abandoned(usize),
+    Timeout,
+    /// An alertable wait was interrupted by `KeAlertThread`.
+    Alerted,
+    /// An alertable wait was interrupted to deliver a user APC.
+    UserApc,
+}
+
+impl WaitResult {
+    fn from_status(status: NTSTATUS, count: usize) -> Result<WaitResult, NtStatus> {
+        let raw = status as u32;
+        let abandoned = NtStatus::ABANDONED.raw() as u32;
+        match NtStatus::from_raw(status) {
+            NtStatus::TIMEOUT => Ok(WaitResult::Timeout),
+            NtStatus::ALERTED => Ok(WaitResult::Alerted),
+            NtStatus::USER_APC => Ok(WaitResult::UserApc),
+            _ if (raw as usize) < count => Ok(WaitResult::Signaled(raw as usize)),
+            _ if raw >= abandoned && ((raw - abandoned) as usize) < count => Ok(WaitResult::Abandoned((raw - abandoned) as usize)),
+            s => Err(s),
+        }
+    }
+
+    pub fn is_signaled(self) -> bool {
+        matches!(self, WaitResult::Signaled(_))
+    }
+}
+
+
+/// A kernel dispatcher object that `KeWaitFor*Object(s)` accepts.
+///
+/// # Safety
+///
+/// `dispatcher_object` must return a pointer to an initialized dispatcher object (event,
+/// semaphore, timer, mutex, thread or process) that stays valid while `self` is borrowed.
+pub unsafe trait Waitable {
+    fn dispatcher_object(&self) -> PVOID;
+}
+
+
+fn check_irql(timeout: Timeout) {
+    irql::debug_assert_at_most(if timeout.is_poll() { DISPATCH_LEVEL } else { APC_LEVEL });
+}
+
+pub(crate) fn wait_single(object: PVOID, timeout: Timeout, alertable: bool) -> Result<WaitResult, NtStatus> {
+    check_irql(timeout);
+    let mut due = timeout.to_due_time();
+    let due_ptr = due.as_mut().map_or(ptr::null_mut(), |d| d as *mut LARGE_INTEGER);
+    let status = unsafe {
+        KeWaitForSingleObject(object, _KWAIT_REASON_Executive, _MODE_KernelMode as _, alertable as _, due_ptr)
+    };
+    WaitResult::from_status(status, 1)
+}
+
+fn wait_multiple(objects: &[&dyn Waitable], wait_type: WAIT_TYPE, timeout: Timeout, alertable: bool) -> Result<WaitResult, NtStatus> {
+    check_irql(timeout);
+    if objects.is_empty() || objects.len() > MAXIMUM_WAIT_OBJECTS as usize {
+        return Err(NtStatus::INVALID_PARAMETER);
+    }
+
+    let mut pointers: Vec<PVOID> = objects.iter().map(|o| o.dispatcher_object()).collect();
+    // up to THREAD_WAIT_OBJECTS the thread's built-in wait blocks are used
+    let mut blocks: Vec<KWAIT_BLOCK> = Vec::new();
+    if objects.len() > THREAD_WAIT_OBJECTS as usize {
+        blocks.resize(objects.len(), unsafe { core::mem::zeroed() });
+    }
+    let blocks_ptr = if blocks.is_empty() { ptr::null_mut() } else { blocks.as_mut_ptr() };
+
+    let mut due = timeout.to_due_time();
+    let due_ptr = due.as_mut().map_or(ptr::null_mut(), |d| d as *mut LARGE_INTEGER);
+    let status = unsafe {
+        KeWaitForMultipleObjects(pointers.len() as _, pointers.as_mut_ptr(), wait_type, _KWAIT_REASON_Executive,
+                                 _MODE_KernelMode as _, alertable as _, due_ptr, blocks_ptr)
+    };
+    WaitResult::from_status(status, objects.len())
+}
+
+/// Waits until any of `objects` (at most 64) is signaled; `Signaled(i)` names the first one.
+pub fn wait_any(objects: &[&dyn Waitable], timeout: impl Into<Timeout>, _irql: &impl BelowDispatch) -> Result<WaitResult, NtStatus> {
+    wait_multiple(objects, _WAIT_TYPE_WaitAny, timeout.into(), false)
+}
+
+/// Waits until all of `objects` (at most 64) are signaled at the same time.
+pub fn wait_all(objects: &[&dyn Waitable], timeout: impl Into<Timeout>, _irql: &impl BelowDispatch) -> Result<WaitResult, NtStatus> {
+    wait_multiple(objects, _WAIT_TYPE_WaitAll, timeout.into(), false)
+}
+
+/// `KeDelayExecutionThread`, not alertable.
+pub fn sleep(duration: Duration, _irql: &impl BelowDispatch) {
+    irql::debug_assert_at_most(APC_LEVEL);
+    let mut interval = Timeout::Relative(duration).to_due_time().unwrap();
+    unsafe { KeDelayExecutionThread(_MODE_KernelMode as _, FALSE as _, &mut interval) };
+}
+
+/// Alertable `KeDelayExecutionThread`; returns `Alerted` or `UserApc` when cut short.
+pub fn sleep_alertable(duration: Duration, _irql: &impl BelowDispatch) -> WaitResult {
+    irql::debug_assert_at_most(APC_LEVEL);
+    let mut interval = Timeout::Relative(duration).to_due_time().unwrap();
+    let status = unsafe { KeDelayExecutionThread(_MODE_KernelMode as _, TRUE as _, &mut interval) };
+    match NtStatus::from_raw(status) {
+        NtStatus::ALERTED => WaitResult::Alerted,
+        NtStatus::USER_APC => WaitResult::UserApc,
+        _ => WaitResult::Timeout,
+    }
+}
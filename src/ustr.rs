@@ -0,0 +1,521 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use crate::*;
+
+// UNICODE_STRING lengths are byte counts in a USHORT
+const MAX_UNITS: usize = 0xFFFE / 2;
+
+
+/// Uppercases one UTF-16 unit the way `RtlUpcaseUnicodeChar` does for the BMP.
+pub fn upcase(unit: u16) -> u16 {
+    if unit < 0x80 {
+        return (unit as u8).to_ascii_uppercase() as u16;
+    }
+    let Some(c) = char::from_u32(unit as u32) else { return unit };
+    let mut upper = c.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(u), None) if (u as u32) <= 0xFFFF => u as u16,
+        _ => unit,
+    }
+}
+
+fn eq_ignore_case(a: &[u16], b: &[u16]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(&x, &y)| x == y || upcase(x) == upcase(y))
+}
+
+
+/// Borrowed UTF-16 string, the safe counterpart of a `UNICODE_STRING` view. Not NUL terminated.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct UnicodeStr<'a> {
+    units: &'a [u16],
+}
+
+impl<'a> UnicodeStr<'a> {
+    pub const fn from_slice(units: &'a [u16]) -> Self {
+        UnicodeStr { units }
+    }
+
+    /// Views the characters of a `UNICODE_STRING`.
+    ///
+    /// # Safety
+    ///
+    /// `s.Buffer` must point to `s.Length` readable bytes that stay valid and unchanged for `'a`.
+    #[cfg(windows)]
+    pub unsafe fn from_unicode_string(s: &'a UNICODE_STRING) -> Self {
+        if s.Buffer.is_null() || s.Length == 0 {
+            return UnicodeStr { units: &[] };
+        }
+        UnicodeStr { units: unsafe { core::slice::from_raw_parts(s.Buffer, s.Length as usize / 2) } }
+    }
+
+    pub const fn as_slice(&self) -> &'a [u16] {
+        self.units
+    }
+
+    /// Length in UTF-16 units.
+    pub const fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    /// A read-only `UNICODE_STRING` over this string; callees must not write through `Buffer`.
+    /// `STATUS_NAME_TOO_LONG` past 32767 units.
+    #[cfg(windows)]
+    pub fn as_unicode_string(&self) -> Result<UNICODE_STRING, NtStatus> {
+        if self.units.len() > MAX_UNITS {
+            return Err(NtStatus::NAME_TOO_LONG);
+        }
+        let len = (self.units.len() * 2) as u16;
+        Ok(UNICODE_STRING { Length: len, MaximumLength: len, Buffer: self.units.as_ptr() as *mut u16 })
+    }
+
+    /// Decodes to `char`s, replacing unpaired surrogates with U+FFFD.
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a {
+        char::decode_utf16(self.units.iter().copied()).map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    pub fn eq_ignore_case(&self, other: UnicodeStr) -> bool {
+        eq_ignore_case(self.units, other.units)
+    }
+
+    pub fn starts_with(&self, prefix: UnicodeStr) -> bool {
+        self.units.starts_with(prefix.units)
+    }
+
+    pub fn ends_with(&self, suffix: UnicodeStr) -> bool {
+        self.units.ends_with(suffix.units)
+    }
+
+    pub fn starts_with_ignore_case(&self, prefix: UnicodeStr) -> bool {
+        self.units.len() >= prefix.len() && eq_ignore_case(&self.units[..prefix.len()], prefix.units)
+    }
+
+    pub fn ends_with_ignore_case(&self, suffix: UnicodeStr) -> bool {
+        self.units.len() >= suffix.len() && eq_ignore_case(&self.units[self.units.len() - suffix.len()..], suffix.units)
+    }
+
+    /// The part after the last `\` or `/`, e.g. the file name of an image path.
+    pub fn file_name(&self) -> UnicodeStr<'a> {
+        match self.units.iter().rposition(|&u| u == b'\\' as u16 || u == b'/' as u16) {
+            Some(at) => UnicodeStr { units: &self.units[at + 1..] },
+            None => *self,
+        }
+    }
+
+    pub fn to_owned(&self) -> OwnedUnicodeString {
+        OwnedUnicodeString::from_slice(self.units)
+    }
+}
+
+impl PartialEq<str> for UnicodeStr<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.units.iter().copied().eq(other.encode_utf16())
+    }
+}
+
+impl PartialEq<&str> for UnicodeStr<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl fmt::Display for UnicodeStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.chars() {
+            f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for UnicodeStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for c in self.chars() {
+            for e in c.escape_debug() {
+                f.write_char(e)?;
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+
+/// Owned, NUL terminated UTF-16 string backed by the global (pool) allocator.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct OwnedUnicodeString {
+    // always ends with a NUL that is not part of the string
+    units: Vec<u16>,
+}
+
+impl OwnedUnicodeString {
+    pub fn new() -> Self {
+        OwnedUnicodeString { units: alloc::vec![0] }
+    }
+
+    pub fn from_slice(units: &[u16]) -> Self {
+        let mut owned = Vec::with_capacity(units.len() + 1);
+        owned.extend_from_slice(units);
+        owned.push(0);
+        OwnedUnicodeString { units: owned }
+    }
+
+    /// Widens ANSI bytes (Latin-1), as found in module paths from `ZwQuerySystemInformation`.
+    pub fn from_ansi(bytes: &[u8]) -> Self {
+        let mut units: Vec<u16> = bytes.iter().map(|&b| b as u16).collect();
+        units.push(0);
+        OwnedUnicodeString { units }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut units = Vec::with_capacity(capacity + 1);
+        units.push(0);
+        OwnedUnicodeString { units }
+    }
+
+    pub fn as_ustr(&self) -> UnicodeStr<'_> {
+        UnicodeStr { units: &self.units[..self.units.len() - 1] }
+    }
+
+    /// Pointer to the NUL terminated characters, for APIs that take a `PCWSTR`.
+    pub fn as_ptr(&self) -> *const u16 {
+        self.units.as_ptr()
+    }
+
+    pub fn len(&self) -> usize {
+        self.units.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        self.units.pop();
+        self.units.extend(s.encode_utf16());
+        self.units.push(0);
+    }
+
+    pub fn push_ustr(&mut self, s: UnicodeStr) {
+        self.units.pop();
+        self.units.extend_from_slice(s.units);
+        self.units.push(0);
+    }
+
+    /// A `UNICODE_STRING` over this string whose `MaximumLength` covers the terminating NUL.
+    #[cfg(windows)]
+    pub fn as_unicode_string(&self) -> Result<UNICODE_STRING, NtStatus> {
+        let mut s = self.as_ustr().as_unicode_string()?;
+        s.MaximumLength = s.Length + 2;
+        Ok(s)
+    }
+}
+
+impl Default for OwnedUnicodeString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&str> for OwnedUnicodeString {
+    fn from(s: &str) -> Self {
+        let mut units: Vec<u16> = s.encode_utf16().collect();
+        units.push(0);
+        OwnedUnicodeString { units }
+    }
+}
+
+impl From<UnicodeStr<'_>> for OwnedUnicodeString {
+    fn from(s: UnicodeStr<'_>) -> Self {
+        s.to_owned()
+    }
+}
+
+impl<'a> From<&'a OwnedUnicodeString> for UnicodeStr<'a> {
+    fn from(s: &'a OwnedUnicodeString) -> Self {
+        s.as_ustr()
+    }
+}
+
+impl fmt::Display for OwnedUnicodeString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_ustr(), f)
+    }
+}
+
+impl fmt::Debug for OwnedUnicodeString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_ustr(), f)
+    }
+}
+
+
+/// A string argument that is either borrowed UTF-16 or converted from a `&str` on the way in.
+/// The string-taking helpers accept `impl Into<UnicodeCow>`.
+pub enum UnicodeCow<'a> {
+    Borrowed(UnicodeStr<'a>),
+    Owned(OwnedUnicodeString),
+}
+
+impl UnicodeCow<'_> {
+    pub fn as_ustr(&self) -> UnicodeStr<'_> {
+        match self {
+            UnicodeCow::Borrowed(s) => *s,
+            UnicodeCow::Owned(s) => s.as_ustr(),
+        }
+    }
+}
+
+impl<'a> From<UnicodeStr<'a>> for UnicodeCow<'a> {
+    fn from(s: UnicodeStr<'a>) -> Self {
+        UnicodeCow::Borrowed(s)
+    }
+}
+
+impl<'a> From<&'a OwnedUnicodeString> for UnicodeCow<'a> {
+    fn from(s: &'a OwnedUnicodeString) -> Self {
+        UnicodeCow::Borrowed(s.as_ustr())
+    }
+}
+
+impl From<OwnedUnicodeString> for UnicodeCow<'_> {
+    fn from(s: OwnedUnicodeString) -> Self {
+        UnicodeCow::Owned(s)
+    }
+}
+
+impl From<&str> for UnicodeCow<'_> {
+    fn from(s: &str) -> Self {
+        UnicodeCow::Owned(s.into())
+    }
+}
+
+
+/// Borrowed ANSI string, the safe counterpart of an `ANSI_STRING` view.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AnsiStr<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> AnsiStr<'a> {
+    pub const fn from_bytes(bytes: &'a [u8]) -> Self {
+        AnsiStr { bytes }
+    }
+
+    /// # Safety
+    ///
+    /// `s.Buffer` must point to `s.Length` readable bytes that stay valid and unchanged for `'a`.
+    #[cfg(windows)]
+    pub unsafe fn from_ansi_string(s: &'a ANSI_STRING) -> Self {
+        if s.Buffer.is_null() || s.Length == 0 {
+            return AnsiStr { bytes: &[] };
+        }
+        AnsiStr { bytes: unsafe { core::slice::from_raw_parts(s.Buffer as *const u8, s.Length as usize) } }
+    }
+
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    pub const fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// A read-only `ANSI_STRING` over these bytes; `STATUS_NAME_TOO_LONG` past 65535 bytes.
+    #[cfg(windows)]
+    pub fn as_ansi_string(&self) -> Result<ANSI_STRING, NtStatus> {
+        let len = u16::try_from(self.bytes.len()).map_err(|_| NtStatus::NAME_TOO_LONG)?;
+        Ok(ANSI_STRING { Length: len, MaximumLength: len, Buffer: self.bytes.as_ptr() as *mut _ })
+    }
+
+    pub fn to_unicode(&self) -> OwnedUnicodeString {
+        OwnedUnicodeString::from_ansi(self.bytes)
+    }
+}
+
+impl fmt::Display for AnsiStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for &b in self.bytes {
+            f.write_char(b as char)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for AnsiStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self.bytes.escape_ascii())
+    }
+}
+
+
+/// Number of UTF-16 units in `s`; used by [`u!`](crate::u).
+#[doc(hidden)]
+pub const fn utf16_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    let mut len = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let (width, units) = if b < 0x80 { (1, 1) } else if b < 0xE0 { (2, 1) } else if b < 0xF0 { (3, 1) } else { (4, 2) };
+        i += width;
+        len += units;
+    }
+    len
+}
+
+/// UTF-8 to UTF-16 at compile time; used by [`u!`](crate::u).
+#[doc(hidden)]
+pub const fn encode_utf16<const N: usize>(s: &str) -> [u16; N] {
+    let bytes = s.as_bytes();
+    let mut out = [0u16; N];
+    let mut i = 0;
+    let mut n = 0;
+    while i < bytes.len() {
+        let b = bytes[i] as u32;
+        let (c, width) = if b < 0x80 {
+            (b, 1)
+        } else if b < 0xE0 {
+            (((b & 0x1F) << 6) | (bytes[i + 1] as u32 & 0x3F), 2)
+        } else if b < 0xF0 {
+            (((b & 0x0F) << 12) | ((bytes[i + 1] as u32 & 0x3F) << 6) | (bytes[i + 2] as u32 & 0x3F), 3)
+        } else {
+            (((b & 0x07) << 18) | ((bytes[i + 1] as u32 & 0x3F) << 12) | ((bytes[i + 2] as u32 & 0x3F) << 6)
+                | (bytes[i + 3] as u32 & 0x3F), 4)
+        };
+        if c >= 0x10000 {
+            let c = c - 0x10000;
+            out[n] = 0xD800 | (c >> 10) as u16;
+            out[n + 1] = 0xDC00 | (c & 0x3FF) as u16;
+            n += 2;
+        } else {
+            out[n] = c as u16;
+            n += 1;
+        }
+        i += width;
+    }
+    out
+}
+
+
+/// A `UnicodeStr<'static>` from a string literal, encoded to UTF-16 at compile time.
+///
+/// ```ignore
+/// let name = u!("\\Device\\MyDriver");
+/// let us = name.as_unicode_string()?;
+/// ```
+#[macro_export]
+macro_rules! u {
+    ($s:literal) => {{
+        const S: &str = $s;
+        const UNITS: [u16; $crate::ustr::utf16_len(S)] = $crate::ustr::encode_utf16(S);
+        $crate::ustr::UnicodeStr::from_slice(&UNITS)
+    }};
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    fn units(s: &str) -> Vec<u16> {
+        s.encode_utf16().collect()
+    }
+
+    const SAMPLES: [&str; 6] = ["", "abc", "Gr\u{fc}\u{df}e", "\u{20ac}100", "a\u{1f600}b", "\u{10437}\u{24b62}\u{7f}\u{80}\u{7ff}\u{800}\u{ffff}\u{10000}\u{10ffff}"];
+
+    #[test]
+    fn utf16_len_matches_std() {
+        for s in SAMPLES {
+            assert_eq!(utf16_len(s), s.encode_utf16().count(), "{s:?}");
+        }
+        assert_eq!(utf16_len("\u{1f600}"), 2);
+        assert_eq!(utf16_len("\u{e9}"), 1);
+    }
+
+    #[test]
+    fn encode_utf16_matches_std() {
+        const PAIR: [u16; 2] = encode_utf16("\u{1f600}");
+        assert_eq!(PAIR, [0xD83D, 0xDE00]);
+        const EDGES: [u16; 7] = encode_utf16("\u{7f}\u{80}\u{ffff}\u{10000}\u{10ffff}");
+        assert_eq!(EDGES, [0x7F, 0x80, 0xFFFF, 0xD800, 0xDC00, 0xDBFF, 0xDFFF]);
+        for s in SAMPLES {
+            assert_eq!(encode_utf16::<32>(s)[..utf16_len(s)], units(s)[..], "{s:?}");
+        }
+        let s = crate::u!("\\Device\\Dr\u{e9}\u{1f600}");
+        assert_eq!(s.as_slice(), &units("\\Device\\Dr\u{e9}\u{1f600}")[..]);
+        assert_eq!(s, "\\Device\\Dr\u{e9}\u{1f600}");
+    }
+
+    #[test]
+    fn eq_ignore_case_non_ascii() {
+        let eq = |a: &str, b: &str| UnicodeStr::from_slice(&units(a)).eq_ignore_case(UnicodeStr::from_slice(&units(b)));
+        assert!(eq("Driver.SYS", "dRIVER.sys"));
+        assert!(eq("\u{e9}t\u{e9}", "\u{c9}T\u{c9}"));
+        assert!(eq("\u{436}\u{443}\u{43a}", "\u{416}\u{423}\u{41a}"));
+        // both sigmas upcase to U+03A3
+        assert!(eq("\u{3c3}", "\u{3c2}"));
+        assert!(!eq("abc", "abd"));
+        assert!(!eq("abc", "ab"));
+        // one unit at a time like RtlUpcaseUnicodeChar: no multi-char mappings, nothing outside the BMP
+        assert!(!eq("\u{df}", "SS"));
+        assert!(eq("\u{df}", "\u{df}"));
+        assert!(!eq("\u{10428}", "\u{10400}"));
+        assert!(eq("\u{1f600}", "\u{1f600}"));
+    }
+
+    #[test]
+    fn upcase_units() {
+        assert_eq!(upcase(b'a' as u16), b'A' as u16);
+        assert_eq!(upcase(b'1' as u16), b'1' as u16);
+        assert_eq!(upcase(0xE9), 0xC9);
+        assert_eq!(upcase(0xDF), 0xDF);
+        // lone surrogates are left alone
+        assert_eq!(upcase(0xD83D), 0xD83D);
+        assert_eq!(upcase(0xDE00), 0xDE00);
+    }
+
+    #[test]
+    fn affixes_ignore_case() {
+        let path = OwnedUnicodeString::from("\\SystemRoot\\System32\\DRIVERS\\\u{c9}tude.sys");
+        let path = path.as_ustr();
+        assert!(path.starts_with_ignore_case(u!("\\systemroot\\")));
+        assert!(path.ends_with_ignore_case(u!("\u{e9}TUDE.SYS")));
+        assert!(!path.ends_with(u!("\u{e9}tude.sys")));
+        assert!(!u!("ab").starts_with_ignore_case(u!("abc")));
+        assert_eq!(path.file_name(), "\u{c9}tude.sys");
+        assert_eq!(u!("a/b").file_name(), "b");
+        assert_eq!(u!("name").file_name(), "name");
+    }
+
+    #[test]
+    fn decoding() {
+        let pair = units("x\u{1f600}");
+        assert_eq!(format!("{}", UnicodeStr::from_slice(&pair)), "x\u{1f600}");
+        // unpaired surrogates become U+FFFD
+        assert_eq!(format!("{}", UnicodeStr::from_slice(&[0xD83D, b'a' as u16, 0xDE00])), "\u{fffd}a\u{fffd}");
+        assert_eq!(format!("{:?}", u!("a\"\n")), "\"a\\\"\\n\"");
+    }
+
+    #[test]
+    fn owned() {
+        let mut s = OwnedUnicodeString::new();
+        assert!(s.is_empty());
+        s.push_str("ab");
+        s.push_ustr(u!("\u{1f600}"));
+        assert_eq!(s.len(), 4);
+        assert_eq!(s.as_ustr(), "ab\u{1f600}");
+        // still NUL terminated for PCWSTR callers
+        assert_eq!(unsafe { *s.as_ptr().add(4) }, 0);
+        assert_eq!(OwnedUnicodeString::from_ansi(b"caf\xe9").as_ustr(), "caf\u{e9}");
+        assert_eq!(AnsiStr::from_bytes(b"caf\xe9").to_unicode(), OwnedUnicodeString::from("caf\u{e9}"));
+    }
+}
@@ -3091,13 +3091,87 @@ pub const _EXCEPTION_DISPOSITION_ExceptionContinueSearch: _EXCEPTION_DISPOSITION
 pub const _EXCEPTION_DISPOSITION_ExceptionNestedException: _EXCEPTION_DISPOSITION = 2;
 pub const _EXCEPTION_DISPOSITION_ExceptionCollidedUnwind: _EXCEPTION_DISPOSITION = 3;
 pub type _EXCEPTION_DISPOSITION = core::ffi::c_int;
-
-use alloc::vec::Vec;
 pub use self::_EXCEPTION_DISPOSITION as EXCEPTION_DISPOSITION;
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[repr(align(16))]
+#[derive(Copy, Clone)]
 pub struct _CONTEXT {
-    _unused: [u8; 0],
+    pub P1Home: ULONG64,
+    pub P2Home: ULONG64,
+    pub P3Home: ULONG64,
+    pub P4Home: ULONG64,
+    pub P5Home: ULONG64,
+    pub P6Home: ULONG64,
+    pub ContextFlags: ULONG,
+    pub MxCsr: ULONG,
+    pub SegCs: USHORT,
+    pub SegDs: USHORT,
+    pub SegEs: USHORT,
+    pub SegFs: USHORT,
+    pub SegGs: USHORT,
+    pub SegSs: USHORT,
+    pub EFlags: ULONG,
+    pub Dr0: ULONG64,
+    pub Dr1: ULONG64,
+    pub Dr2: ULONG64,
+    pub Dr3: ULONG64,
+    pub Dr6: ULONG64,
+    pub Dr7: ULONG64,
+    pub Rax: ULONG64,
+    pub Rcx: ULONG64,
+    pub Rdx: ULONG64,
+    pub Rbx: ULONG64,
+    pub Rsp: ULONG64,
+    pub Rbp: ULONG64,
+    pub Rsi: ULONG64,
+    pub Rdi: ULONG64,
+    pub R8: ULONG64,
+    pub R9: ULONG64,
+    pub R10: ULONG64,
+    pub R11: ULONG64,
+    pub R12: ULONG64,
+    pub R13: ULONG64,
+    pub R14: ULONG64,
+    pub R15: ULONG64,
+    pub Rip: ULONG64,
+    pub __bindgen_anon_1: _CONTEXT__bindgen_ty_1,
+    pub VectorRegister: [M128A; 26usize],
+    pub VectorControl: ULONG64,
+    pub DebugControl: ULONG64,
+    pub LastBranchToRip: ULONG64,
+    pub LastBranchFromRip: ULONG64,
+    pub LastExceptionToRip: ULONG64,
+    pub LastExceptionFromRip: ULONG64,
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Copy, Clone)]
+pub union _CONTEXT__bindgen_ty_1 {
+    pub FltSave: XMM_SAVE_AREA32,
+    pub __bindgen_anon_1: _CONTEXT__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C)]
+#[repr(align(16))]
+#[derive(Debug, Copy, Clone)]
+pub struct _CONTEXT__bindgen_ty_1__bindgen_ty_1 {
+    pub Header: [M128A; 2usize],
+    pub Legacy: [M128A; 8usize],
+    pub Xmm0: M128A,
+    pub Xmm1: M128A,
+    pub Xmm2: M128A,
+    pub Xmm3: M128A,
+    pub Xmm4: M128A,
+    pub Xmm5: M128A,
+    pub Xmm6: M128A,
+    pub Xmm7: M128A,
+    pub Xmm8: M128A,
+    pub Xmm9: M128A,
+    pub Xmm10: M128A,
+    pub Xmm11: M128A,
+    pub Xmm12: M128A,
+    pub Xmm13: M128A,
+    pub Xmm14: M128A,
+    pub Xmm15: M128A,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -3777,25 +3851,6 @@ pub struct _UNICODE_STRING {
     pub MaximumLength: USHORT,
     pub Buffer: PWCH,
 }
-
-impl _UNICODE_STRING {
-    pub fn from_str(s: &str) -> Self {
-        let mut wide: Vec<u16> = s.encode_utf16().chain(Some(0)).collect();
-
-        let length = ((wide.len() - 1) * 2) as u16;
-        let max_length = (wide.len() * 2) as u16;
-
-        let buffer = wide.as_mut_ptr();
-        core::mem::forget(wide);
-
-        Self {
-            Length: length,
-            MaximumLength: max_length,
-            Buffer: buffer,
-        }
-    }
-}
-
 pub type UNICODE_STRING = _UNICODE_STRING;
 pub type PUNICODE_STRING = *mut UNICODE_STRING;
 pub type PCUNICODE_STRING = *const UNICODE_STRING;
@@ -4889,7 +4944,24 @@ pub type PVPB = *mut _VPB;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _FILE_GET_QUOTA_INFORMATION {
-    _unused: [u8; 0],
+    pub NextEntryOffset: ULONG,
+    pub SidLength: ULONG,
+    pub Sid: SID,
+}
+pub type SID = _SID;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SID {
+    pub Revision: UCHAR,
+    pub SubAuthorityCount: UCHAR,
+    pub IdentifierAuthority: SID_IDENTIFIER_AUTHORITY,
+    pub SubAuthority: [ULONG; 1usize],
+}
+pub type SID_IDENTIFIER_AUTHORITY = _SID_IDENTIFIER_AUTHORITY;
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct _SID_IDENTIFIER_AUTHORITY {
+    pub Value: [UCHAR; 6usize],
 }
 pub type PFILE_GET_QUOTA_INFORMATION = *mut _FILE_GET_QUOTA_INFORMATION;
 unsafe extern "C" {
@@ -15519,6 +15591,7 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn KeQuerySystemTimePrecise(CurrentTime: PLARGE_INTEGER);
 }
+#[cfg(feature = "win10-10240")]
 unsafe extern "C" {
     pub fn KeQueryInterruptTimePrecise(QpcTimeStamp: PULONG64) -> ULONG64;
 }
@@ -15971,9 +16044,11 @@ pub union _POOL_EXTENDED_PARAMETER__bindgen_ty_2 {
 pub type POOL_EXTENDED_PARAMETER = _POOL_EXTENDED_PARAMETER;
 pub type PPOOL_EXTENDED_PARAMETER = *mut _POOL_EXTENDED_PARAMETER;
 pub type PCPOOL_EXTENDED_PARAMETER = *const POOL_EXTENDED_PARAMETER;
+#[cfg(feature = "win10-19041")]
 unsafe extern "C" {
     pub fn ExAllocatePool2(Flags: POOL_FLAGS, NumberOfBytes: SIZE_T, Tag: ULONG) -> PVOID;
 }
+#[cfg(feature = "win10-19041")]
 unsafe extern "C" {
     pub fn ExAllocatePool3(
         Flags: POOL_FLAGS,
@@ -15983,6 +16058,7 @@ unsafe extern "C" {
         ExtendedParametersCount: ULONG,
     ) -> PVOID;
 }
+#[cfg(feature = "win10-19041")]
 unsafe extern "C" {
     pub fn ExFreePool2(
         P: PVOID,
@@ -16587,6 +16663,7 @@ pub struct _EXT_DELETE_PARAMETERS {
 }
 pub type EXT_DELETE_PARAMETERS = _EXT_DELETE_PARAMETERS;
 pub type PEXT_DELETE_PARAMETERS = *mut _EXT_DELETE_PARAMETERS;
+#[cfg(feature = "win81")]
 unsafe extern "C" {
     pub fn ExAllocateTimer(
         Callback: PEXT_CALLBACK,
@@ -16594,6 +16671,7 @@ unsafe extern "C" {
         Attributes: ULONG,
     ) -> PEX_TIMER;
 }
+#[cfg(feature = "win81")]
 unsafe extern "C" {
     pub fn ExSetTimer(
         Timer: PEX_TIMER,
@@ -16602,9 +16680,11 @@ unsafe extern "C" {
         Parameters: PEXT_SET_PARAMETERS,
     ) -> BOOLEAN;
 }
+#[cfg(feature = "win81")]
 unsafe extern "C" {
     pub fn ExCancelTimer(Timer: PEX_TIMER, Parameters: PEXT_CANCEL_PARAMETERS) -> BOOLEAN;
 }
+#[cfg(feature = "win81")]
 unsafe extern "C" {
     pub fn ExDeleteTimer(
         Timer: PEX_TIMER,
@@ -17798,8 +17878,27 @@ pub type PSE_IMAGE_TYPE = *mut _SE_IMAGE_TYPE;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct _BDCB_IMAGE_INFORMATION {
-    _unused: [u8; 0],
-}
+    pub Classification: BDCB_CLASSIFICATION,
+    pub ImageFlags: ULONG,
+    pub ImageName: UNICODE_STRING,
+    pub RegistryPath: UNICODE_STRING,
+    pub CertificatePublisher: UNICODE_STRING,
+    pub CertificateIssuer: UNICODE_STRING,
+    pub ImageHash: PVOID,
+    pub CertificateThumbprint: PVOID,
+    pub ImageHashAlgorithm: ULONG,
+    pub ThumbprintHashAlgorithm: ULONG,
+    pub ImageHashLength: ULONG,
+    pub CertificateThumbprintLength: ULONG,
+}
+pub use self::_BDCB_CLASSIFICATION as BDCB_CLASSIFICATION;
+pub type _BDCB_CLASSIFICATION = core::ffi::c_int;
+pub const _BDCB_CLASSIFICATION_BdCbClassificationUnknownImage: _BDCB_CLASSIFICATION = 0;
+pub const _BDCB_CLASSIFICATION_BdCbClassificationKnownGoodImage: _BDCB_CLASSIFICATION = 1;
+pub const _BDCB_CLASSIFICATION_BdCbClassificationKnownBadImage: _BDCB_CLASSIFICATION = 2;
+pub const _BDCB_CLASSIFICATION_BdCbClassificationKnownBadImageBootCritical: _BDCB_CLASSIFICATION =
+    3;
+pub const _BDCB_CLASSIFICATION_BdCbClassificationEnd: _BDCB_CLASSIFICATION = 4;
 pub type PBDCB_IMAGE_INFORMATION = *mut _BDCB_IMAGE_INFORMATION;
 pub type SE_IMAGE_VERIFICATION_CALLBACK_FUNCTION = ::core::option::Option<
     unsafe extern "C" fn(
@@ -42066,9 +42165,13 @@ pub struct __crt_multibyte_data {
     pub _address: u8,
 }
 #[repr(C)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
 pub struct _KQUEUE {
-    pub _address: u8,
+    pub Header: DISPATCHER_HEADER,
+    pub EntryListHead: LIST_ENTRY,
+    pub CurrentCount: ULONG,
+    pub MaximumCount: ULONG,
+    pub ThreadListHead: LIST_ENTRY,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
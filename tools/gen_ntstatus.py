@@ -0,0 +1,141 @@
+#!/usr/bin/env python3
+"""Regenerates src/status/table.rs from tools/ntstatus.txt.
+
+Usage: python3 tools/gen_ntstatus.py [path/to/ntstatus.h]
+
+With a header path, tools/ntstatus.txt is first rewritten from the header's `#define`s and their
+`MessageText` blocks, e.g. from `Include/<version>/shared/ntstatus.h` of the WDK.
+
+Codes the snapshot has no MessageText for get one spelled out from their name instead
+(`STATUS_DEVICE_NOT_READY` -> "Device not ready."), so every entry of the table has a message.
+Importing the header replaces those with the real text.
+"""
+import os
+import re
+import sys
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+SNAPSHOT = os.path.join(ROOT, "tools", "ntstatus.txt")
+OUTPUT = os.path.join(ROOT, "src", "status", "table.rs")
+
+SNAPSHOT_HEADER = """\
+# NTSTATUS values from ntstatus.h (STATUS_*, DBG_*, RPC_NT_*, EPT_NT_*), one `NAME VALUE [MESSAGE]` line each.
+# MESSAGE is the MessageText of the code with `\\\\` and `\\n` escaped as in a Rust string; codes without one have none.
+# Consumed by tools/gen_ntstatus.py to produce src/status/table.rs.
+"""
+
+DEFINE = re.compile(r"#define\s+((?:STATUS|DBG|RPC_NT|EPT_NT)_\w+)\s+\(\(NTSTATUS\)(0x[0-9A-Fa-f]+)L?\)")
+MESSAGE_ID = re.compile(r"//\s*MessageId:\s*(\w+)")
+
+
+# names that share their value with a more common one and should never be reported first
+ALIASES = {"STATUS_WAIT_0", "STATUS_ABANDONED_WAIT_0"}
+
+
+# name parts kept in capitals when spelling out a message from a name
+ACRONYMS = {
+    "ABIOS", "ACL", "ACPI", "ALPC", "API", "APPEXEC", "CIM", "CPU", "CRL", "CS", "CSS", "CTLOG", "CTX",
+    "DAX", "DFS", "DIF", "DLL", "DNS", "DS", "EA", "EAS", "EFS", "FAT", "FVE", "GPIO", "GUID", "HAL",
+    "ID", "IO", "IORING", "IP", "IPSEC", "IRP", "KDC", "LDR", "LPC", "LSA", "LUID", "MCA", "MDL", "MFT",
+    "MUI", "NDIS", "NLS", "NTFS", "NUMA", "OS", "PE", "PWD", "QUIC", "RDBSS", "RDP", "RKF", "RM", "RTPM",
+    "RWRAW", "RXACT", "SAM", "SID", "SMB", "SSPI", "SVHDX", "TCP", "TS", "UDP", "UI", "URL", "USB",
+    "USN", "VDM", "VID", "VM", "VOLMGR", "VSM", "WIM", "WMI", "WOW", "XML",
+}
+
+
+def spell_out(name):
+    """A stand-in message for codes without a MessageText, made from the words of the name."""
+    words = re.sub(r"^(STATUS|DBG|RPC_NT|EPT_NT)_", "", name).split("_")
+    words = [w if w in ACRONYMS or not re.search("[AEIOUY]", w) or re.search("[0-9]", w) else w.lower()
+             for w in words if w]
+    words[0] = words[0][0].upper() + words[0][1:]
+    return " ".join(words) + "."
+
+
+def rank(name):
+    return (name in ALIASES, name)
+
+
+def import_header(path):
+    """Rewrites the snapshot from ntstatus.h, where each code reads
+
+        // MessageId: STATUS_BUFFER_OVERFLOW
+        //
+        // MessageText:
+        //
+        // {Buffer Overflow}
+        // The data was too large to fit into the specified buffer.
+        //
+        #define STATUS_BUFFER_OVERFLOW           ((NTSTATUS)0x80000005L)
+    """
+    messages = {}
+    rows = []
+    message_id = text = None
+    with open(path, encoding="utf-8", errors="replace") as f:
+        for line in f:
+            line = line.strip()
+            m = MESSAGE_ID.match(line)
+            if m:
+                message_id, text = m.group(1), None
+            elif message_id and line == "// MessageText:":
+                text = []
+            elif line.startswith("//"):
+                if text is not None and line[2:].strip():
+                    text.append(line[2:].strip().replace("\\", "\\\\"))
+            elif line:
+                m = DEFINE.match(line)
+                if m:
+                    name = m.group(1)
+                    if name == message_id and text:
+                        messages[name] = "\\n".join(text)
+                    rows.append((name, int(m.group(2), 16)))
+                message_id = text = None
+
+    with open(SNAPSHOT, "w", encoding="utf-8", newline="\n") as out:
+        out.write(SNAPSHOT_HEADER)
+        for name, value in rows:
+            message = messages.get(name)
+            if message:
+                out.write("%s 0x%08X %s\n" % (name, value, message))
+            else:
+                out.write("%s 0x%08X\n" % (name, value))
+
+
+def escape(message):
+    # the snapshot already escapes backslashes and line breaks the way a Rust literal does
+    return message.replace('"', '\\"')
+
+
+def main():
+    if len(sys.argv) > 1:
+        import_header(sys.argv[1])
+
+    entries = []
+    seen = set()
+    with open(SNAPSHOT, encoding="utf-8") as f:
+        for line in f:
+            line = line.strip()
+            if not line or line.startswith("#"):
+                continue
+            fields = line.split(None, 2)
+            name, value = fields[0], fields[1]
+            message = fields[2] if len(fields) > 2 else spell_out(name)
+            if name in seen:
+                continue
+            seen.add(name)
+            entries.append((int(value, 16), rank(name), name, message))
+
+    entries.sort()
+
+    with open(OUTPUT, "w", encoding="utf-8", newline="\n") as out:
+        out.write("/* automatically generated by tools/gen_ntstatus.py, do not edit */\n\n")
+        out.write("/// `(value, name, message)` sorted by value; the first entry of a value is its canonical name.\n")
+        out.write("/// `message` is the ntstatus.h MessageText, or spelled out from the name where the snapshot has none.\n")
+        out.write("pub(crate) static NTSTATUS_TABLE: [(u32, &str, &str); %d] = [\n" % len(entries))
+        for value, _, name, message in entries:
+            out.write('    (0x%08X, "%s", "%s"),\n' % (value, name, escape(message)))
+        out.write("];\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,100 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::config::Config;
+use crate::items::{self, Key};
+use crate::layer;
+
+/// Runs bindgen over the WDK headers at `ntddi` and returns the raw output per module.
+fn bindgen(cfg: &Config, include: &Path, ntddi: &str) -> Result<Vec<String>, String> {
+    let shared = include.join("shared");
+    let km = include.join("km");
+
+    let mut out = Vec::new();
+    for header in &cfg.headers {
+        let path = km.join(&header.file);
+        let output = Command::new("bindgen")
+            .arg(&path)
+            .args(["--use-core", "--ctypes-prefix", "core::ffi", "--no-layout-tests", "--no-doc-comments"])
+            .args(["--rust-edition", "2024", "--raw-line", "#![allow(warnings)]"])
+            .arg("--")
+            .arg(format!("-I{}", km.display()))
+            .arg(format!("-I{}", shared.display()))
+            .args(["-D_AMD64_", "-DAMD64", "-D_WIN64", "--target=x86_64-pc-windows-msvc"])
+            .args(["-fms-extensions", "-fms-compatibility"])
+            .arg(format!("-DNTDDI_VERSION={ntddi}"))
+            .output()
+            .map_err(|e| format!("bindgen: {e}"))?;
+        if !output.status.success() {
+            return Err(format!("bindgen {}: {}", header.file, String::from_utf8_lossy(&output.stderr)));
+        }
+        let text = String::from_utf8(output.stdout).map_err(|e| format!("bindgen {}: {e}", header.file))?;
+        out.push(text.replace('\n', "\r\n"));
+    }
+    Ok(out)
+}
+
+fn names(texts: &[String]) -> HashSet<String> {
+    texts
+        .iter()
+        .flat_map(|t| items::split(t).items)
+        .map(|i| items::key(&i))
+        .filter(|k| matches!(k, Key::Fn(_) | Key::Val(_) | Key::Ty(_)))
+        .map(|k| k.name().to_string())
+        .collect()
+}
+
+/// Snapshots the WDK headers under `wdk` (the `Include/<version>` directory) at the newest NTDDI
+/// level, then reruns bindgen at every level in `ntddi.txt` to rewrite `availability.txt`.
+pub fn run(cfg: &Config, wdk: &Path, version: Option<&str>) -> Result<(), String> {
+    let version = match version {
+        Some(v) => v.to_string(),
+        None => wdk
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(str::to_string)
+            .ok_or("pass --version when the WDK path does not end in the version")?,
+    };
+    let newest = cfg.levels.last().ok_or("ntddi.txt is empty")?;
+
+    let latest = bindgen(cfg, wdk, &newest.ntddi)?;
+    let names_latest = names(&latest);
+
+    let mut modules: Vec<_> = latest.iter().map(|t| items::split(t)).collect();
+    let module_names: Vec<String> = cfg.headers.iter().map(|h| h.module.clone()).collect();
+    layer::layer_all(&module_names, &mut modules);
+
+    let dir: PathBuf = cfg.snapshot_dir(&version);
+    fs::create_dir_all(&dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    for (name, module) in module_names.iter().zip(&modules) {
+        let path = dir.join(format!("{name}.rs"));
+        fs::write(&path, items::render(module)).map_err(|e| format!("{}: {e}", path.display()))?;
+    }
+
+    // first level each item shows up at; items already in the baseline are never gated
+    let mut first: BTreeMap<String, String> = BTreeMap::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    for (i, level) in cfg.levels.iter().enumerate() {
+        let at = if i + 1 == cfg.levels.len() { names_latest.clone() } else { names(&bindgen(cfg, wdk, &level.ntddi)?) };
+        for name in at {
+            if seen.insert(name.clone()) && i > 0 && names_latest.contains(&name) {
+                first.insert(name, level.feature.clone());
+            }
+        }
+        eprintln!("{}: {} items", level.feature, seen.len());
+    }
+
+    let mut text = String::from(
+        "# Items introduced after the baseline in ntddi.txt, as `name feature`. Generated items with a\n\
+         # matching name get `#[cfg(feature = \"...\")]`.\n\
+         #\n\
+         # Written by `cargo xtask bindings capture`.\n",
+    );
+    for (name, feature) in &first {
+        text.push_str(&format!("{name:<35} {feature}\n"));
+    }
+    let path = cfg.root.join("bindings").join("availability.txt");
+    fs::write(&path, text).map_err(|e| format!("{}: {e}", path.display()))
+}
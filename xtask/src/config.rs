@@ -0,0 +1,92 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub struct Header {
+    pub module: String,
+    pub file: String,
+}
+
+pub struct Level {
+    pub feature: String,
+    pub ntddi: String,
+}
+
+pub struct Config {
+    pub root: PathBuf,
+    pub headers: Vec<Header>,
+    /// Oldest first; the first entry is the baseline.
+    pub levels: Vec<Level>,
+    /// `(item name, feature)`.
+    pub availability: Vec<(String, String)>,
+    pub allowlist: Vec<String>,
+    pub blocklist: Vec<String>,
+}
+
+fn rows(path: &Path) -> Result<Vec<Vec<String>>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok(text
+        .lines()
+        .map(|l| l.split('#').next().unwrap_or("").trim())
+        .filter(|l| !l.is_empty())
+        .map(|l| l.split_whitespace().map(str::to_string).collect())
+        .collect())
+}
+
+impl Config {
+    pub fn load(root: &Path) -> Result<Config, String> {
+        let dir = root.join("bindings");
+        let headers = rows(&dir.join("headers.txt"))?
+            .into_iter()
+            .map(|r| match r.as_slice() {
+                [module, file, _] => Ok(Header { module: module.clone(), file: file.clone() }),
+                _ => Err(format!("headers.txt: bad line `{}`", r.join(" "))),
+            })
+            .collect::<Result<_, _>>()?;
+        let levels: Vec<Level> = rows(&dir.join("ntddi.txt"))?
+            .into_iter()
+            .map(|r| match r.as_slice() {
+                [feature, ntddi, ..] => Ok(Level { feature: feature.clone(), ntddi: ntddi.clone() }),
+                _ => Err(format!("ntddi.txt: bad line `{}`", r.join(" "))),
+            })
+            .collect::<Result<_, _>>()?;
+        let availability = rows(&dir.join("availability.txt"))?
+            .into_iter()
+            .map(|r| match r.as_slice() {
+                [name, feature] if levels.iter().any(|l| &l.feature == feature) => Ok((name.clone(), feature.clone())),
+                _ => Err(format!("availability.txt: bad line `{}`", r.join(" "))),
+            })
+            .collect::<Result<_, _>>()?;
+        let allowlist = rows(&dir.join("allowlist.txt"))?.into_iter().flatten().collect();
+        let blocklist = rows(&dir.join("blocklist.txt"))?.into_iter().flatten().collect();
+
+        Ok(Config { root: root.to_path_buf(), headers, levels, availability, allowlist, blocklist })
+    }
+
+    pub fn snapshot_dir(&self, version: &str) -> PathBuf {
+        self.root.join("bindings").join("snapshot").join(version)
+    }
+
+    /// The newest snapshot, by WDK version.
+    pub fn latest_snapshot(&self) -> Result<String, String> {
+        let dir = self.root.join("bindings").join("snapshot");
+        let mut versions: Vec<String> = fs::read_dir(&dir)
+            .map_err(|e| format!("{}: {e}", dir.display()))?
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir())
+            .filter_map(|e| e.file_name().into_string().ok())
+            .collect();
+        versions.sort_by_key(|v| v.split('.').map(|p| p.parse::<u32>().unwrap_or(0)).collect::<Vec<_>>());
+        versions.pop().ok_or_else(|| format!("no snapshots in {}", dir.display()))
+    }
+}
+
+/// `*` matches any run of characters.
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((head, tail)) => {
+            let Some(rest) = name.strip_prefix(head) else { return false };
+            (0..=rest.len()).any(|i| rest.is_char_boundary(i) && glob_match(tail, &rest[i..]))
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+
+use crate::config::{self, Config};
+use crate::items::{self, Key, Module};
+use crate::layer;
+
+/// Builds `src/<module>.rs` from a snapshot without touching the WDK.
+pub fn run(cfg: &Config, version: Option<&str>) -> Result<(), String> {
+    let version = match version {
+        Some(v) => v.to_string(),
+        None => cfg.latest_snapshot()?,
+    };
+    let dir = cfg.snapshot_dir(&version);
+
+    let names: Vec<String> = cfg.headers.iter().map(|h| h.module.clone()).collect();
+    let mut modules = Vec::new();
+    for name in &names {
+        let path = dir.join(format!("{name}.rs"));
+        let text = fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        modules.push(items::split(&text));
+    }
+
+    filter(cfg, &mut modules);
+    layer::layer_all(&names, &mut modules);
+    gate(cfg, &mut modules)?;
+
+    for (name, module) in names.iter().zip(&modules) {
+        let path = cfg.root.join("src").join(format!("{name}.rs"));
+        fs::write(&path, items::render(module)).map_err(|e| format!("{}: {e}", path.display()))?;
+        eprintln!("{name}.rs: {} items", module.items.len());
+    }
+    Ok(())
+}
+
+/// Applies the allowlist (plus everything the allowed items reference) and then the blocklist.
+fn filter(cfg: &Config, modules: &mut [Module]) {
+    let matches = |list: &[String], name: &str| list.iter().any(|p| config::glob_match(p, name));
+
+    if !cfg.allowlist.is_empty() {
+        let mut defined: HashMap<String, Vec<String>> = HashMap::new();
+        for item in modules.iter().flat_map(|m| &m.items) {
+            defined.entry(items::key(item).name().to_string()).or_default().push(item.clone());
+        }
+
+        let mut keep: HashSet<String> = HashSet::new();
+        let mut todo: Vec<String> = defined.keys().filter(|n| matches(&cfg.allowlist, n)).cloned().collect();
+        while let Some(name) = todo.pop() {
+            if !keep.insert(name.clone()) {
+                continue;
+            }
+            for item in &defined[&name] {
+                for ident in items::idents(item) {
+                    if defined.contains_key(ident) && !keep.contains(ident) {
+                        todo.push(ident.to_string());
+                    }
+                }
+            }
+        }
+
+        for module in modules.iter_mut() {
+            module.items.retain(|i| match items::key(i) {
+                Key::Other(_) => true,
+                key => keep.contains(key.name()),
+            });
+        }
+    }
+
+    for module in modules.iter_mut() {
+        module.items.retain(|i| match items::key(i) {
+            Key::Other(_) => true,
+            key => !matches(&cfg.blocklist, key.name()),
+        });
+    }
+}
+
+/// Puts `#[cfg(feature = "...")]` on items newer than the baseline. An item referencing a gated
+/// item inherits the newer of the two gates so every feature combination still compiles.
+fn gate(cfg: &Config, modules: &mut [Module]) -> Result<(), String> {
+    let rank: HashMap<&str, usize> = cfg.levels.iter().enumerate().map(|(i, l)| (l.feature.as_str(), i)).collect();
+
+    let mut level: HashMap<String, usize> = HashMap::new();
+    for (name, feature) in &cfg.availability {
+        level.insert(name.clone(), rank[feature.as_str()]);
+    }
+
+    let keyed: Vec<(Key, &String)> = modules.iter().flat_map(|m| &m.items).map(|i| (items::key(i), i)).collect();
+    loop {
+        let mut changed = false;
+        for (key, item) in &keyed {
+            if matches!(key, Key::Other(_)) {
+                continue;
+            }
+            let own = level.get(key.name()).copied().unwrap_or(0);
+            let needed = items::idents(item)
+                .filter(|i| *i != key.name())
+                .filter_map(|i| level.get(i).copied())
+                .max()
+                .unwrap_or(0);
+            if needed > own {
+                level.insert(key.name().to_string(), needed);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    for module in modules.iter_mut() {
+        for item in module.items.iter_mut() {
+            let key = items::key(item);
+            if matches!(key, Key::Other(_)) {
+                continue;
+            }
+            if let Some(&l) = level.get(key.name())
+                && l > 0
+            {
+                *item = format!("#[cfg(feature = \"{}\")]\n{item}", cfg.levels[l].feature);
+            }
+        }
+    }
+
+    let manifest = fs::read_to_string(cfg.root.join("Cargo.toml")).map_err(|e| format!("Cargo.toml: {e}"))?;
+    for l in &cfg.levels {
+        if !manifest.contains(&format!("\n{} = ", l.feature)) {
+            return Err(format!("Cargo.toml has no `{}` feature", l.feature));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+/// One top-level bindgen item, attributes included.
+pub type Item = String;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Key {
+    /// `unsafe extern "C" { pub fn X }` or `pub static X`.
+    Fn(String),
+    /// `impl [Trait for] X`, stored as `(trait, type)`.
+    Impl(String, String),
+    /// `pub const X` and `pub static X`.
+    Val(String),
+    /// `pub type`, `struct`, `union`, `enum` and `pub use ... as X`.
+    Ty(String),
+    Other(String),
+}
+
+impl Key {
+    /// The item name an impl or alias is attached to.
+    pub fn name(&self) -> &str {
+        match self {
+            Key::Fn(n) | Key::Val(n) | Key::Ty(n) | Key::Other(n) => n,
+            Key::Impl(_, ty) => ty,
+        }
+    }
+}
+
+pub struct Module {
+    pub header: Vec<String>,
+    pub items: Vec<Item>,
+    pub newline: &'static str,
+}
+
+pub fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn ident_at(s: &str) -> String {
+    s.chars().take_while(|c| is_ident_char(*c)).collect()
+}
+
+/// All identifiers appearing in `text`.
+pub fn idents(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !is_ident_char(c))
+        .filter(|w| w.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_'))
+}
+
+/// Splits a bindgen file into its header lines (comment, inner attributes, `use`) and its items.
+pub fn split(text: &str) -> Module {
+    let newline = if text.contains("\r\n") { "\r\n" } else { "\n" };
+    let text = text.replace("\r\n", "\n");
+    let lines: Vec<&str> = text.split('\n').collect();
+
+    let mut header = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let l = lines[i];
+        if l.starts_with("/*") || l.starts_with("#![") || l.starts_with("use ") || l.starts_with("pub use crate::") {
+            header.push(l.to_string());
+            i += 1;
+        } else {
+            break;
+        }
+    }
+
+    let mut items = Vec::new();
+    let mut cur: Vec<&str> = Vec::new();
+    let mut depth = 0i64;
+    for &line in &lines[i..] {
+        cur.push(line);
+        depth += line.matches('{').count() as i64 - line.matches('}').count() as i64;
+        if depth != 0 {
+            continue;
+        }
+        let s = line.trim();
+        if s.starts_with("#[") && !s.ends_with(';') && !s.ends_with('}') {
+            continue;
+        }
+        if s.is_empty() && cur.len() == 1 {
+            cur.clear();
+            continue;
+        }
+        if s.ends_with(';') || s.ends_with('}') || s.ends_with("*/") {
+            items.push(cur.join("\n"));
+            cur.clear();
+        }
+    }
+    if cur.iter().any(|l| !l.trim().is_empty()) {
+        items.push(cur.join("\n"));
+    }
+
+    Module { header, items, newline }
+}
+
+pub fn render(module: &Module) -> String {
+    let mut out = module.header.join("\n");
+    for item in &module.items {
+        out.push('\n');
+        out.push_str(item);
+    }
+    out.push('\n');
+    out.replace('\n', module.newline)
+}
+
+fn strip_generics(s: &str) -> String {
+    let mut out = String::new();
+    let mut depth = 0;
+    for c in s.chars() {
+        match c {
+            '<' => depth += 1,
+            '>' if depth > 0 => depth -= 1,
+            _ if depth == 0 => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+pub fn key(item: &str) -> Key {
+    let body: Vec<&str> = item.lines().filter(|l| !l.trim_start().starts_with("#[")).collect();
+    let body = body.join("\n");
+    let body = body.trim();
+
+    if body.starts_with("unsafe extern") {
+        for marker in ["pub fn ", "pub static mut ", "pub static "] {
+            if let Some(at) = body.find(marker) {
+                return Key::Fn(ident_at(&body[at + marker.len()..]));
+            }
+        }
+    }
+    if let Some(rest) = body.strip_prefix("impl") {
+        let head = rest.split(['{']).next().unwrap_or("");
+        let head = head.split("where").next().unwrap_or("");
+        let head = strip_generics(head);
+        let words: Vec<&str> = head.split_whitespace().collect();
+        return match words.iter().position(|w| *w == "for") {
+            Some(p) if p > 0 && p + 1 < words.len() => Key::Impl(words[p - 1].to_string(), words[p + 1].to_string()),
+            _ => Key::Impl(String::new(), words.first().map(|w| w.to_string()).unwrap_or_default()),
+        };
+    }
+    for (prefix, val) in [("pub const ", true), ("pub static ", true), ("pub type ", false), ("pub struct ", false),
+                          ("pub union ", false), ("pub enum ", false)] {
+        if let Some(rest) = body.strip_prefix(prefix) {
+            let name = ident_at(rest);
+            return if val { Key::Val(name) } else { Key::Ty(name) };
+        }
+    }
+    if body.starts_with("pub use ")
+        && let Some(at) = body.rfind(" as ")
+    {
+        return Key::Ty(ident_at(&body[at + 4..]));
+    }
+    Key::Other(body.to_string())
+}
+
+/// For `pub const X: T = ...;`, the type `T`.
+pub fn const_type(item: &str) -> Option<String> {
+    let body = item.trim();
+    let rest = body.strip_prefix("pub const ")?;
+    let colon = rest.find(':')?;
+    Some(ident_at(rest[colon + 1..].trim_start()))
+}
+
+pub fn group(items: &[Item]) -> HashMap<Key, Vec<Item>> {
+    let mut by_key: HashMap<Key, Vec<Item>> = HashMap::new();
+    for item in items {
+        by_key.entry(key(item)).or_default().push(item.clone());
+    }
+    by_key
+}
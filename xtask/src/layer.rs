@@ -0,0 +1,145 @@
+//! Rewrites a stack of bindgen outputs so each module keeps only its delta over the ones below.
+//!
+//! Items declared by several layers are kept once, in the lowest one. When the lower layer only
+//! has an opaque placeholder (`_unused: [u8; 0]`) and an upper one has the full definition, the
+//! full definition and whatever it needs are moved down so there is one type per structure.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::items::{self, Item, Key, Module};
+
+fn is_opaque(text: &str) -> bool {
+    let squashed: String = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    ["{ _unused: [u8; 0] }", "{ _unused: [u8; 0], }", "{ pub _address: u8 }", "{ pub _address: u8, }"]
+        .iter()
+        .any(|p| squashed.contains(p))
+}
+
+/// Items of `upper` needed to move the full definition of `name` into the lower layer.
+fn closure(name: &str, upper: &HashMap<Key, Vec<Item>>, lower_names: &HashSet<String>) -> Vec<Key> {
+    let mut by_name: HashMap<&str, Vec<&Key>> = HashMap::new();
+    for key in upper.keys() {
+        if matches!(key, Key::Ty(_) | Key::Impl(..)) {
+            by_name.entry(key.name()).or_default().push(key);
+        }
+    }
+
+    let mut keys = Vec::new();
+    let mut todo = vec![name.to_string()];
+    let mut seen = HashSet::new();
+    while let Some(n) = todo.pop() {
+        if !seen.insert(n.clone()) {
+            continue;
+        }
+        let Some(found) = by_name.get(n.as_str()) else { continue };
+        let mut found = found.clone();
+        found.sort();
+        for key in found {
+            keys.push(key.clone());
+            for item in &upper[key] {
+                for ident in items::idents(item) {
+                    if by_name.contains_key(ident) && !lower_names.contains(ident) && !seen.contains(ident) {
+                        todo.push(ident.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    // enum variants are emitted as `pub const _ENUM_Variant: _ENUM = ...;`
+    let moved: HashSet<&str> = keys.iter().filter(|k| matches!(k, Key::Ty(_))).map(Key::name).collect();
+    let mut variants: Vec<Key> = upper
+        .iter()
+        .filter(|(key, its)| {
+            matches!(key, Key::Val(n) if !lower_names.contains(n))
+                && its.iter().any(|i| items::const_type(i).is_some_and(|t| moved.contains(t.as_str())))
+        })
+        .map(|(key, _)| key.clone())
+        .collect();
+    variants.sort();
+    keys.extend(variants);
+    keys
+}
+
+/// Layers `upper_items` on top of `lower_items`, returning the new lower and upper item lists
+/// and the keys whose full definitions were moved down.
+fn layer(lower_items: &[Item], upper_items: &[Item]) -> (Vec<Item>, Vec<Item>, Vec<Key>) {
+    let lower = items::group(lower_items);
+    let upper = items::group(upper_items);
+    let lower_names: HashSet<String> = lower.keys().map(|k| k.name().to_string()).collect();
+
+    let mut promote: HashMap<Key, Vec<Key>> = HashMap::new();
+    let mut dropped: HashSet<Key> = HashSet::new();
+    for (key, its) in &upper {
+        let Some(below) = lower.get(key) else { continue };
+        dropped.insert(key.clone());
+        if its == below || !matches!(key, Key::Ty(_)) {
+            continue;
+        }
+        if is_opaque(&below.join("\n")) && !is_opaque(&its.join("\n")) {
+            let keys = closure(key.name(), &upper, &lower_names);
+            dropped.extend(keys.iter().cloned());
+            promote.insert(key.clone(), keys);
+        }
+    }
+
+    let mut new_lower = Vec::new();
+    let mut done = HashSet::new();
+    for item in lower_items {
+        let key = items::key(item);
+        if let Some(keys) = promote.get(&key) {
+            if done.insert(key) {
+                for k in keys {
+                    new_lower.extend(upper[k].iter().cloned());
+                }
+            }
+            continue;
+        }
+        new_lower.push(item.clone());
+    }
+
+    let new_upper = upper_items.iter().filter(|i| !dropped.contains(&items::key(i))).cloned().collect();
+    let mut promoted: Vec<Key> = promote.into_keys().collect();
+    promoted.sort();
+    (new_lower, new_upper, promoted)
+}
+
+/// Layers `modules` (lowest first) in place. `names[i]` is the module name of `modules[i]`.
+pub fn layer_all(names: &[String], modules: &mut [Module]) {
+    for upper in 1..modules.len() {
+        // compare against everything below, not only the layer directly underneath
+        let mut owner: HashMap<Key, usize> = HashMap::new();
+        let mut tagged = Vec::new();
+        for (m, module) in modules[..upper].iter().enumerate() {
+            for item in &module.items {
+                owner.entry(items::key(item)).or_insert(m);
+                tagged.push(item.clone());
+            }
+        }
+
+        let (lower_items, upper_items, promoted) = layer(&tagged, &modules[upper].items);
+
+        let mut rebuilt: Vec<Vec<Item>> = vec![Vec::new(); upper];
+        let mut current = 0;
+        for item in lower_items {
+            if let Some(&m) = owner.get(&items::key(&item)) {
+                current = m;
+            }
+            rebuilt[current].push(item);
+        }
+        for (m, its) in rebuilt.into_iter().enumerate() {
+            modules[m].items = its;
+        }
+        for key in promoted {
+            eprintln!("{}: moved full definition of {} down into {}", names[upper], key.name(), names[owner[&key]]);
+        }
+        modules[upper].items = upper_items;
+    }
+
+    for (i, module) in modules.iter_mut().enumerate() {
+        module.header.retain(|l| !l.starts_with("use crate::") && !l.starts_with("pub use crate::"));
+        if i > 0 {
+            module.header.push(format!("pub use crate::{}::*;", names[i - 1]));
+        }
+    }
+}
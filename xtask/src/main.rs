@@ -0,0 +1,47 @@
+//! `cargo xtask bindings generate [--wdk-version <ver>]`
+//!     Rebuilds `src/{wdm,ntddk,ntifs}.rs` from `bindings/snapshot/<ver>` (newest by default).
+//!     Works offline and is deterministic, so CI can check the checked-in modules are up to date.
+//!
+//! `cargo xtask bindings capture --wdk <Include/ver> [--version <ver>]`
+//!     Runs bindgen over the WDK headers, writes a new snapshot and recomputes `availability.txt`
+//!     by rerunning at every NTDDI level in `ntddi.txt`. Needs the `bindgen` CLI and libclang.
+
+mod capture;
+mod config;
+mod generate;
+mod items;
+mod layer;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_path_buf()
+}
+
+fn flag<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    args.iter().position(|a| a == name).and_then(|i| args.get(i + 1)).map(String::as_str)
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let cfg = config::Config::load(&root())?;
+    match args.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+        ["bindings", "generate", ..] => generate::run(&cfg, flag(args, "--wdk-version")),
+        ["bindings", "capture", ..] => {
+            let wdk = flag(args, "--wdk").ok_or("capture needs --wdk <path to Include/<version>>")?;
+            capture::run(&cfg, Path::new(wdk), flag(args, "--version"))
+        }
+        _ => Err("usage: cargo xtask bindings <generate [--wdk-version <ver>] | capture --wdk <dir> [--version <ver>]>".into()),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}